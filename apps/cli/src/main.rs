@@ -169,6 +169,11 @@ impl Export {
             compression: cap_export::mp4::ExportCompression::Maximum,
             custom_bpp: None,
             force_ffmpeg_decoder: false,
+            lead_in_ms: 0,
+            lead_out_ms: 0,
+            audio_stems: false,
+            timelapse: None,
+            frame_interpolation: false,
         }
         .export(exporter_base, move |_f| {
             // print!("\rrendered frame {f}");
@@ -1,4 +1,6 @@
 use cap_audio::AudioData;
+use serde::Serialize;
+use specta::Type;
 
 fn play_audio(bytes: &'static [u8]) {
     use rodio::{Decoder, OutputStream, Sink};
@@ -69,3 +71,19 @@ pub fn get_waveform(audio: &AudioData) -> Vec<f32> {
 
     waveform
 }
+
+#[derive(Serialize, Type, Debug, Clone, Copy)]
+pub struct VoiceActivity {
+    pub start: f64,
+    pub end: f64,
+}
+
+pub fn get_voice_activity(audio: &AudioData) -> Vec<VoiceActivity> {
+    cap_audio::detect_voice_activity(audio.samples(), audio.channels(), AudioData::SAMPLE_RATE)
+        .into_iter()
+        .map(|segment| VoiceActivity {
+            start: segment.start,
+            end: segment.end,
+        })
+        .collect()
+}
@@ -0,0 +1,260 @@
+use std::{path::Path, time::Duration};
+
+use cap_audio::{AudioData, CalibrationStore, SyncAnalyzer, calculate_frame_motion_score};
+use cap_project::{MultipleSegment, StudioRecordingMeta};
+use cap_recording::{
+    feeds::microphone, sources::screen_capture::ScreenCaptureTarget, studio_recording,
+};
+use ffmpeg::{
+    format::Pixel,
+    software::scaling::{Context as Scaler, flag::Flags},
+};
+use scap_targets::Display;
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use tracing::{info, warn};
+
+use crate::{App, MutableState};
+
+const PULSE_COUNT: u32 = 6;
+const PULSE_INTERVAL: Duration = Duration::from_secs(1);
+const WARMUP: Duration = Duration::from_millis(1500);
+const ANALYSIS_WIDTH: u32 = 320;
+const ANALYSIS_HEIGHT: u32 = 180;
+
+#[derive(Serialize, Type, tauri_specta::Event, Clone, Debug)]
+pub struct AvSyncTestPulse {
+    pub index: u32,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AvSyncTestResult {
+    pub offset_secs: f64,
+    pub confidence: f64,
+    pub pulses_detected: u32,
+}
+
+fn calibration_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    app.path().app_data_dir().map_err(|e| e.to_string())
+}
+
+pub fn load_offset(app: &AppHandle) -> Option<f32> {
+    let dir = calibration_dir(app).ok()?;
+    CalibrationStore::load(&dir)
+        .capture_offset()
+        .map(|offset| offset as f32)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_av_sync_calibration(app: AppHandle) -> Result<Option<f64>, String> {
+    Ok(CalibrationStore::load(&calibration_dir(&app)?).capture_offset())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_av_sync_calibration(app: AppHandle) -> Result<(), String> {
+    let dir = calibration_dir(&app)?;
+    let mut store = CalibrationStore::load(&dir);
+    store.set_capture_offset(None);
+    store.save(&dir).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_av_sync_test(
+    app: AppHandle,
+    state: MutableState<'_, App>,
+) -> Result<AvSyncTestResult, String> {
+    let mic_feed = {
+        let state = state.read().await;
+        if state.is_recording_active_or_pending() {
+            return Err("Stop the current recording before running the sync test".to_string());
+        }
+
+        match state.mic_feed.ask(microphone::Lock).await {
+            Ok(lock) => Some(std::sync::Arc::new(lock)),
+            Err(e) => {
+                warn!("Running sync test without a microphone: {e}");
+                None
+            }
+        }
+    };
+
+    let data_dir = calibration_dir(&app)?;
+    let recording_dir = data_dir.join("av-sync-test");
+    let _ = std::fs::remove_dir_all(&recording_dir);
+
+    let display = Display::get_containing_cursor().unwrap_or_else(Display::primary);
+    let target = ScreenCaptureTarget::Display { id: display.id() };
+
+    #[cfg(target_os = "macos")]
+    let shareable_content = crate::recording::acquire_shareable_content_for_target(&target)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut builder =
+        studio_recording::Actor::builder(recording_dir.clone(), target).with_system_audio(true);
+    if let Some(mic_feed) = mic_feed {
+        builder = builder.with_mic_feed(mic_feed);
+    }
+
+    let handle = builder
+        .build(
+            #[cfg(target_os = "macos")]
+            shareable_content,
+        )
+        .await
+        .map_err(|e| format!("Failed to start sync test recording: {e}"))?;
+
+    tokio::time::sleep(WARMUP).await;
+    for index in 0..PULSE_COUNT {
+        AvSyncTestPulse { index }.emit(&app).ok();
+        tokio::time::sleep(PULSE_INTERVAL).await;
+    }
+
+    let completed = handle.stop().await.map_err(|e| e.to_string())?;
+
+    let result = tokio::task::spawn_blocking(move || {
+        let segment = match &completed.meta {
+            StudioRecordingMeta::MultipleSegments { inner } => inner
+                .segments
+                .first()
+                .ok_or_else(|| "Sync test recorded no segments".to_string())?,
+            StudioRecordingMeta::SingleSegment { .. } => {
+                return Err("Sync test produced an unexpected recording layout".to_string());
+            }
+        };
+        analyze(&completed.project_path, segment)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let _ = std::fs::remove_dir_all(&recording_dir);
+    let result = result?;
+
+    let mut store = CalibrationStore::load(&data_dir);
+    store.set_capture_offset(Some(result.offset_secs));
+    store.save(&data_dir).map_err(|e| e.to_string())?;
+
+    info!(
+        offset_secs = result.offset_secs,
+        confidence = result.confidence,
+        "Saved A/V sync calibration"
+    );
+
+    Ok(result)
+}
+
+fn analyze(project_path: &Path, segment: &MultipleSegment) -> Result<AvSyncTestResult, String> {
+    let audio = segment
+        .mic
+        .as_ref()
+        .or(segment.system_audio.as_ref())
+        .ok_or_else(|| "Sync test recorded no audio".to_string())?;
+
+    let video_offset = segment.display.start_time.unwrap_or(0.0) - audio.start_time.unwrap_or(0.0);
+    let motion = motion_scores(&segment.display.path.to_path(project_path), video_offset)?;
+
+    let audio = AudioData::from_file(audio.path.to_path(project_path))?;
+    let channels = audio.channels().max(1) as usize;
+    let mono = audio
+        .samples()
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect::<Vec<_>>();
+
+    let mut analyzer = SyncAnalyzer::new(AudioData::SAMPLE_RATE, segment.display.fps as f64);
+    analyzer.add_audio_samples(&mono, 0.0);
+
+    let peak = motion.iter().map(|(_, score)| *score).fold(0.0, f64::max);
+    if peak <= 0.0 {
+        return Err("No flashes were detected in the sync test recording".to_string());
+    }
+    for (time, score) in motion {
+        analyzer.add_video_frame_motion(time, score / peak);
+    }
+
+    let result = analyzer
+        .calculate_sync_offset()
+        .ok_or_else(|| "Couldn't match any beeps to flashes".to_string())?;
+
+    Ok(AvSyncTestResult {
+        offset_secs: result.offset_secs,
+        confidence: result.confidence,
+        pulses_detected: result.detected_events.len() as u32,
+    })
+}
+
+fn motion_scores(path: &Path, time_offset: f64) -> Result<Vec<(f64, f64)>, String> {
+    let mut input = ffmpeg::format::input(&path).map_err(|e| e.to_string())?;
+    let stream = input
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or_else(|| "Sync test recording has no video".to_string())?;
+    let stream_index = stream.index();
+    let time_base = f64::from(stream.time_base());
+
+    let mut decoder = ffmpeg::codec::Context::from_parameters(stream.parameters())
+        .and_then(|context| context.decoder().video())
+        .map_err(|e| e.to_string())?;
+    let mut scaler = Scaler::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        Pixel::RGBA,
+        ANALYSIS_WIDTH,
+        ANALYSIS_HEIGHT,
+        Flags::FAST_BILINEAR,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut scores = Vec::new();
+    let mut previous: Option<Vec<u8>> = None;
+    let mut decoded = ffmpeg::frame::Video::empty();
+    let mut scaled = ffmpeg::frame::Video::empty();
+
+    let mut receive = |decoder: &mut ffmpeg::decoder::Video| -> Result<(), String> {
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let Some(pts) = decoded.timestamp() else {
+                continue;
+            };
+            scaler
+                .run(&decoded, &mut scaled)
+                .map_err(|e| e.to_string())?;
+
+            let row_len = ANALYSIS_WIDTH as usize * 4;
+            let stride = scaled.stride(0);
+            let rgba = scaled
+                .data(0)
+                .chunks(stride)
+                .take(ANALYSIS_HEIGHT as usize)
+                .flat_map(|row| &row[..row_len])
+                .copied()
+                .collect::<Vec<_>>();
+
+            if let Some(previous) = &previous {
+                let score =
+                    calculate_frame_motion_score(&rgba, previous, ANALYSIS_WIDTH, ANALYSIS_HEIGHT);
+                scores.push((pts as f64 * time_base + time_offset, score));
+            }
+            previous = Some(rgba);
+        }
+        Ok(())
+    };
+
+    for (stream, packet) in input.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        decoder.send_packet(&packet).map_err(|e| e.to_string())?;
+        receive(&mut decoder)?;
+    }
+    decoder.send_eof().map_err(|e| e.to_string())?;
+    receive(&mut decoder)?;
+
+    Ok(scores)
+}
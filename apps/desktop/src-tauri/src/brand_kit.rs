@@ -0,0 +1,267 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use cap_rendering::{BrandAssets, Watermark, WatermarkCorner};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use specta::Type;
+use tauri::{AppHandle, Manager, Wry};
+use tauri_plugin_store::StoreExt;
+use tracing::{error, instrument, warn};
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandKit {
+    #[serde(default)]
+    pub logos: Vec<BrandLogo>,
+    #[serde(default)]
+    pub fonts: Vec<BrandFont>,
+    #[serde(default)]
+    pub colors: Vec<BrandColor>,
+    #[serde(default)]
+    pub watermark: BrandWatermark,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandLogo {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandFont {
+    pub id: String,
+    pub name: String,
+    pub families: Vec<String>,
+    pub path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandColor {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandWatermark {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub logo_id: Option<String>,
+    #[serde(default)]
+    pub corner: WatermarkCorner,
+    #[serde(default = "BrandWatermark::default_scale")]
+    pub scale: f32,
+    #[serde(default = "BrandWatermark::default_opacity")]
+    pub opacity: f32,
+}
+
+impl BrandWatermark {
+    fn default_scale() -> f32 {
+        0.12
+    }
+
+    fn default_opacity() -> f32 {
+        0.8
+    }
+}
+
+impl Default for BrandWatermark {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            logo_id: None,
+            corner: WatermarkCorner::default(),
+            scale: Self::default_scale(),
+            opacity: Self::default_opacity(),
+        }
+    }
+}
+
+impl BrandKit {
+    pub fn get(app: &AppHandle<Wry>) -> Result<Option<Self>, String> {
+        match app.store("store").map(|s| s.get("brand_kit")) {
+            Ok(Some(store)) => match serde_json::from_value(store.clone()) {
+                Ok(kit) => Ok(Some(kit)),
+                Err(e) => {
+                    error!("Failed to deserialize brand kit: {e}");
+                    Ok(None)
+                }
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn update(app: &AppHandle, update: impl FnOnce(&mut Self)) -> Result<Self, String> {
+        let Ok(store) = app.store("store") else {
+            return Err("Store not found".to_string());
+        };
+
+        let mut kit = Self::get(app)?.unwrap_or_default();
+        update(&mut kit);
+        store.set("brand_kit", json!(kit));
+        store.save().map_err(|e| e.to_string())?;
+        Ok(kit)
+    }
+}
+
+fn assets_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("brand-kit"))
+        .map_err(|e| e.to_string())
+}
+
+fn import_asset(app: &AppHandle, path: &Path) -> Result<(String, String, PathBuf), String> {
+    let dir = assets_dir(app)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| id.clone());
+    let mut destination = dir.join(&id);
+    if let Some(extension) = path.extension() {
+        destination.set_extension(extension);
+    }
+
+    std::fs::copy(path, &destination).map_err(|e| e.to_string())?;
+    Ok((id, name, destination))
+}
+
+pub fn load_render_assets(app: &AppHandle) -> BrandAssets {
+    let Some(kit) = BrandKit::get(app).ok().flatten() else {
+        return BrandAssets::default();
+    };
+
+    let watermark = kit
+        .watermark
+        .logo_id
+        .as_ref()
+        .filter(|_| kit.watermark.enabled)
+        .and_then(|id| kit.logos.iter().find(|logo| logo.id == *id))
+        .and_then(|logo| {
+            image::open(&logo.path)
+                .map_err(|e| warn!("Failed to load watermark '{}': {e}", logo.name))
+                .ok()
+        })
+        .map(|image| Watermark {
+            image: Arc::new(image.into_rgba8()),
+            corner: kit.watermark.corner,
+            scale: kit.watermark.scale,
+            opacity: kit.watermark.opacity,
+        });
+
+    BrandAssets {
+        fonts: kit.fonts.into_iter().map(|font| font.path).collect(),
+        watermark,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn get_brand_kit(app: AppHandle) -> Result<BrandKit, String> {
+    Ok(BrandKit::get(&app)?.unwrap_or_default())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn add_brand_logo(app: AppHandle, path: PathBuf) -> Result<BrandKit, String> {
+    image::open(&path).map_err(|e| format!("Not a supported image: {e}"))?;
+
+    let (id, name, path) = import_asset(&app, &path)?;
+    BrandKit::update(&app, |kit| kit.logos.push(BrandLogo { id, name, path }))
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn add_brand_font(app: AppHandle, path: PathBuf) -> Result<BrandKit, String> {
+    let families = cap_rendering::font_families(&path).map_err(|e| e.to_string())?;
+    if families.is_empty() {
+        return Err("Not a supported font file".to_string());
+    }
+
+    let (id, name, path) = import_asset(&app, &path)?;
+    BrandKit::update(&app, |kit| {
+        kit.fonts.push(BrandFont {
+            id,
+            name,
+            families,
+            path,
+        })
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn remove_brand_asset(app: AppHandle, id: String) -> Result<BrandKit, String> {
+    let mut removed = vec![];
+    let kit = BrandKit::update(&app, |kit| {
+        removed.extend(
+            kit.logos
+                .iter()
+                .filter(|logo| logo.id == id)
+                .map(|logo| logo.path.clone()),
+        );
+        removed.extend(
+            kit.fonts
+                .iter()
+                .filter(|font| font.id == id)
+                .map(|font| font.path.clone()),
+        );
+        kit.logos.retain(|logo| logo.id != id);
+        kit.fonts.retain(|font| font.id != id);
+
+        if kit.watermark.logo_id.as_ref() == Some(&id) {
+            kit.watermark.logo_id = None;
+            kit.watermark.enabled = false;
+        }
+    })?;
+
+    for path in removed {
+        if let Err(e) = std::fs::remove_file(&path) {
+            warn!("Failed to delete brand asset {}: {e}", path.display());
+        }
+    }
+
+    Ok(kit)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn set_brand_colors(app: AppHandle, colors: Vec<BrandColor>) -> Result<BrandKit, String> {
+    if let Some(color) = colors.iter().find(|color| !is_hex_color(&color.value)) {
+        return Err(format!("'{}' isn't a #rrggbb color", color.value));
+    }
+
+    BrandKit::update(&app, |kit| kit.colors = colors)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn set_brand_watermark(
+    app: AppHandle,
+    watermark: BrandWatermark,
+) -> Result<BrandKit, String> {
+    BrandKit::update(&app, |kit| kit.watermark = watermark)
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
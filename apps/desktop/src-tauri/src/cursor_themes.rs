@@ -0,0 +1,82 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use cap_cursor_info::CursorRole;
+use cap_rendering::CursorTheme;
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tracing::{instrument, warn};
+
+use crate::general_settings::GeneralSettingsStore;
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorThemeInfo {
+    pub id: String,
+    pub name: String,
+    pub roles: Vec<CursorRole>,
+    pub active: bool,
+}
+
+pub fn themes_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("cursor-themes"))
+        .map_err(|e| e.to_string())
+}
+
+pub fn load_selected(app: &AppHandle) -> Option<Arc<CursorTheme>> {
+    let id = GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()?
+        .cursor_theme?;
+    let dir = themes_dir(app).ok()?.join(&id);
+
+    CursorTheme::load(&dir)
+        .map(Arc::new)
+        .map_err(|e| warn!("Failed to load cursor theme '{id}': {e}"))
+        .ok()
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn list_cursor_themes(app: AppHandle) -> Result<Vec<CursorThemeInfo>, String> {
+    let dir = themes_dir(&app)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let active = GeneralSettingsStore::get(&app)?.and_then(|s| s.cursor_theme);
+
+    Ok(CursorTheme::list(&dir)
+        .into_iter()
+        .map(|theme| {
+            let mut roles = theme.sprites.keys().copied().collect::<Vec<_>>();
+            roles.sort();
+
+            CursorThemeInfo {
+                active: active.as_deref() == Some(theme.id.as_str()),
+                id: theme.id,
+                name: theme.name,
+                roles,
+            }
+        })
+        .collect())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn set_cursor_theme(app: AppHandle, id: Option<String>) -> Result<(), String> {
+    if let Some(id) = &id {
+        if Path::new(id).file_name() != Some(id.as_ref()) {
+            return Err(format!("Invalid cursor theme id '{id}'"));
+        }
+
+        CursorTheme::load(&themes_dir(&app)?.join(id)).map_err(|e| e.to_string())?;
+    }
+
+    GeneralSettingsStore::update(&app, |s| s.cursor_theme = id)
+}
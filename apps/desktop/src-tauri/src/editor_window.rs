@@ -7,6 +7,7 @@ use cap_rendering::GpuOutputFormat;
 
 use crate::{
     create_editor_instance_impl,
+    frame_inspection::FrameHistory,
     frame_ws::{WSFrame, WSFrameFormat, create_watch_frame_ws},
 };
 
@@ -14,6 +15,7 @@ pub struct EditorInstance {
     inner: Arc<cap_editor::EditorInstance>,
     pub ws_port: u16,
     pub ws_shutdown_token: CancellationToken,
+    pub frame_history: FrameHistory,
 }
 
 type PendingResult = Result<Arc<EditorInstance>, String>;
@@ -22,43 +24,52 @@ type PendingReceiver = tokio::sync::watch::Receiver<Option<PendingResult>>;
 #[derive(Clone, Default)]
 pub struct PendingEditorInstances(Arc<RwLock<HashMap<String, PendingReceiver>>>);
 
+fn ws_frame(output: cap_editor::EditorFrameOutput) -> WSFrame {
+    match output {
+        cap_editor::EditorFrameOutput::Nv12(frame) => {
+            let ws_format = match frame.format {
+                GpuOutputFormat::Nv12 => WSFrameFormat::Nv12,
+                GpuOutputFormat::Rgba => WSFrameFormat::Rgba,
+            };
+            WSFrame {
+                data: frame.data,
+                width: frame.width,
+                height: frame.height,
+                stride: frame.y_stride,
+                frame_number: frame.frame_number,
+                target_time_ns: frame.target_time_ns,
+                format: ws_format,
+                created_at: Instant::now(),
+            }
+        }
+        cap_editor::EditorFrameOutput::Rgba(frame) => WSFrame {
+            data: frame.data,
+            width: frame.width,
+            height: frame.height,
+            stride: frame.padded_bytes_per_row,
+            frame_number: frame.frame_number,
+            target_time_ns: frame.target_time_ns,
+            format: WSFrameFormat::Rgba,
+            created_at: Instant::now(),
+        },
+    }
+}
+
 async fn do_prewarm(app: AppHandle, path: PathBuf) -> PendingResult {
     let (frame_tx, frame_rx) = watch::channel(None);
+    let frame_history = FrameHistory::from_settings(&app);
 
     let (ws_port, ws_shutdown_token) = create_watch_frame_ws(frame_rx).await;
     let inner = create_editor_instance_impl(
         &app,
         path,
-        Box::new(move |output| {
-            let ws_frame = match output {
-                cap_editor::EditorFrameOutput::Nv12(frame) => {
-                    let ws_format = match frame.format {
-                        GpuOutputFormat::Nv12 => WSFrameFormat::Nv12,
-                        GpuOutputFormat::Rgba => WSFrameFormat::Rgba,
-                    };
-                    WSFrame {
-                        data: frame.data,
-                        width: frame.width,
-                        height: frame.height,
-                        stride: frame.y_stride,
-                        frame_number: frame.frame_number,
-                        target_time_ns: frame.target_time_ns,
-                        format: ws_format,
-                        created_at: Instant::now(),
-                    }
-                }
-                cap_editor::EditorFrameOutput::Rgba(frame) => WSFrame {
-                    data: frame.data,
-                    width: frame.width,
-                    height: frame.height,
-                    stride: frame.padded_bytes_per_row,
-                    frame_number: frame.frame_number,
-                    target_time_ns: frame.target_time_ns,
-                    format: WSFrameFormat::Rgba,
-                    created_at: Instant::now(),
-                },
-            };
-            let _ = frame_tx.send(Some(std::sync::Arc::new(ws_frame)));
+        Box::new({
+            let frame_history = frame_history.clone();
+            move |output| {
+                let ws_frame = Arc::new(ws_frame(output));
+                frame_history.push(ws_frame.clone());
+                let _ = frame_tx.send(Some(ws_frame));
+            }
         }),
     )
     .await?;
@@ -67,6 +78,7 @@ async fn do_prewarm(app: AppHandle, path: PathBuf) -> PendingResult {
         inner,
         ws_port,
         ws_shutdown_token,
+        frame_history,
     }))
 }
 
@@ -241,41 +253,19 @@ impl EditorInstances {
                 }
 
                 let (frame_tx, frame_rx) = watch::channel(None);
+                let frame_history = FrameHistory::from_settings(window.app_handle());
 
                 let (ws_port, ws_shutdown_token) = create_watch_frame_ws(frame_rx).await;
                 let inner = create_editor_instance_impl(
                     window.app_handle(),
                     path,
-                    Box::new(move |output| {
-                        let ws_frame = match output {
-                            cap_editor::EditorFrameOutput::Nv12(frame) => {
-                                let ws_format = match frame.format {
-                                    GpuOutputFormat::Nv12 => WSFrameFormat::Nv12,
-                                    GpuOutputFormat::Rgba => WSFrameFormat::Rgba,
-                                };
-                                WSFrame {
-                                    data: frame.data,
-                                    width: frame.width,
-                                    height: frame.height,
-                                    stride: frame.y_stride,
-                                    frame_number: frame.frame_number,
-                                    target_time_ns: frame.target_time_ns,
-                                    format: ws_format,
-                                    created_at: Instant::now(),
-                                }
-                            }
-                            cap_editor::EditorFrameOutput::Rgba(frame) => WSFrame {
-                                data: frame.data,
-                                width: frame.width,
-                                height: frame.height,
-                                stride: frame.padded_bytes_per_row,
-                                frame_number: frame.frame_number,
-                                target_time_ns: frame.target_time_ns,
-                                format: WSFrameFormat::Rgba,
-                                created_at: Instant::now(),
-                            },
-                        };
-                        let _ = frame_tx.send(Some(std::sync::Arc::new(ws_frame)));
+                    Box::new({
+                        let frame_history = frame_history.clone();
+                        move |output| {
+                            let ws_frame = Arc::new(ws_frame(output));
+                            frame_history.push(ws_frame.clone());
+                            let _ = frame_tx.send(Some(ws_frame));
+                        }
                     }),
                 )
                 .await?;
@@ -284,6 +274,7 @@ impl EditorInstances {
                     inner,
                     ws_port,
                     ws_shutdown_token,
+                    frame_history,
                 });
 
                 entry.insert(instance.clone());
@@ -0,0 +1,110 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use cap_rendering::EffectPlugin;
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tracing::{instrument, warn};
+
+use crate::general_settings::GeneralSettingsStore;
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectPluginInfo {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub params: Vec<EffectParamInfo>,
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectParamInfo {
+    pub name: String,
+    pub default: f32,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+pub fn plugins_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("effect-plugins"))
+        .map_err(|e| e.to_string())
+}
+
+pub fn load_enabled(app: &AppHandle) -> Vec<Arc<EffectPlugin>> {
+    let Some(settings) = GeneralSettingsStore::get(app).ok().flatten() else {
+        return vec![];
+    };
+    let Ok(dir) = plugins_dir(app) else {
+        return vec![];
+    };
+
+    settings
+        .enabled_effects
+        .iter()
+        .filter_map(|id| {
+            EffectPlugin::load(&dir.join(id))
+                .map(Arc::new)
+                .map_err(|e| warn!("Failed to load effect '{id}': {e}"))
+                .ok()
+        })
+        .collect()
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn list_effect_plugins(app: AppHandle) -> Result<Vec<EffectPluginInfo>, String> {
+    let dir = plugins_dir(&app)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let enabled = GeneralSettingsStore::get(&app)?
+        .map(|s| s.enabled_effects)
+        .unwrap_or_default();
+
+    Ok(EffectPlugin::list(&dir)
+        .into_iter()
+        .map(|effect| EffectPluginInfo {
+            enabled: enabled.contains(&effect.id),
+            id: effect.id,
+            name: effect.name,
+            description: effect.description,
+            params: effect
+                .params
+                .into_iter()
+                .map(|param| EffectParamInfo {
+                    name: param.name,
+                    default: param.default,
+                    min: param.min,
+                    max: param.max,
+                })
+                .collect(),
+        })
+        .collect())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn enable_effect(app: AppHandle, id: String, enabled: bool) -> Result<(), String> {
+    if Path::new(&id).file_name() != Some(id.as_ref()) {
+        return Err(format!("Invalid effect id '{id}'"));
+    }
+
+    if enabled {
+        EffectPlugin::load(&plugins_dir(&app)?.join(&id)).map_err(|e| e.to_string())?;
+    }
+
+    GeneralSettingsStore::update(&app, |s| {
+        s.enabled_effects.retain(|enabled_id| *enabled_id != id);
+        if enabled {
+            s.enabled_effects.push(id);
+        }
+    })
+}
@@ -1,10 +1,25 @@
+use crate::av_sync;
+use crate::brand_kit;
+use crate::cursor_themes;
 use crate::editor_window::{OptionalWindowEditorInstance, WindowEditorInstance};
-use crate::{FramesRendered, get_video_metadata};
-use cap_export::ExporterBase;
-use cap_project::{RecordingMeta, XY};
+use crate::effect_plugins;
+use crate::export_actions::{self, ExportCompletionActions};
+use crate::export_priority::{self, ExportThrottle};
+use crate::general_settings::ExportPriority;
+use crate::license::{self, PaidFeature};
+use crate::recorder_state::{ExportGuard, ExportJob, RecorderStateMachine};
+use crate::telemetry::{self, ErrorCategory, Feature};
+use crate::user_message::{MessageKey, UserMessage};
+use crate::webhooks;
+use crate::{ExportCompleted, FramesRendered, get_video_metadata};
+use cap_export::{ExporterBase, comparison::ComparisonExportSettings};
+use cap_project::{
+    ExportSplit, ProjectConfiguration, RecordingMeta, XY, timelapse::TimelapseSettings,
+};
 use cap_rendering::{
-    FrameRenderer, ProjectRecordingsMeta, ProjectUniforms, RenderSegment, RenderVideoConstants,
-    RendererLayers, ZoomFocusInterpolator, spring_mass_damper::SpringMassDamperSimulationConfig,
+    BrandAssets, CursorTheme, EffectPlugin, FrameRenderer, ProjectRecordingsMeta, ProjectUniforms,
+    RenderSegment, RenderVideoConstants, RendererLayers, ZoomFocusInterpolator,
+    spring_mass_damper::SpringMassDamperSimulationConfig,
 };
 use image::codecs::jpeg::JpegEncoder;
 use serde::{Deserialize, Serialize};
@@ -16,7 +31,9 @@ use std::{
         atomic::{AtomicBool, Ordering},
     },
 };
-use tracing::{info, instrument};
+use tauri::Manager;
+use tauri_specta::Event;
+use tracing::{info, instrument, warn};
 
 struct ExportActiveGuard<'a>(&'a AtomicBool);
 
@@ -27,7 +44,7 @@ impl Drop for ExportActiveGuard<'_> {
     }
 }
 
-#[derive(Deserialize, Clone, Copy, Debug, Type)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Type)]
 #[serde(tag = "format")]
 pub enum ExportSettings {
     Mp4(cap_export::mp4::Mp4ExportSettings),
@@ -41,55 +58,344 @@ impl ExportSettings {
             ExportSettings::Gif(settings) => settings.fps,
         }
     }
+
+    fn resolution_base(&self) -> XY<u32> {
+        match self {
+            ExportSettings::Mp4(settings) => settings.resolution_base,
+            ExportSettings::Gif(settings) => settings.render_resolution_base(),
+        }
+    }
+
+    fn timelapse(&self) -> Option<TimelapseSettings> {
+        match self {
+            ExportSettings::Mp4(settings) => settings.timelapse,
+            ExportSettings::Gif(_) => None,
+        }
+    }
+
+    fn check_license(&self, app: &tauri::AppHandle) -> Result<(), UserMessage> {
+        check_resolution_license(app, self.resolution_base())
+    }
+
+    fn output_duration(&self, base: &ExporterBase) -> f64 {
+        match self {
+            ExportSettings::Mp4(settings) => settings.output_duration(base),
+            ExportSettings::Gif(_) => base.duration(),
+        }
+    }
+
+    fn render_adjustment(&self, base: &ExporterBase) -> Option<UserMessage> {
+        match self {
+            ExportSettings::Mp4(settings) => {
+                render_adjustment(base.render_size(settings.resolution_base))
+            }
+            ExportSettings::Gif(_) => None,
+        }
+    }
+
+    fn estimated_size_mb(&self, duration_seconds: f64) -> f64 {
+        let resolution = self.resolution_base();
+        let total_pixels = (resolution.x * resolution.y) as f64;
+        let fps = self.fps() as f64;
+
+        match self {
+            ExportSettings::Mp4(mp4_settings) => {
+                let bits_per_pixel = mp4_settings.compression.bits_per_pixel() as f64;
+                let effective_fps = ((fps - 30.0).max(0.0) * 0.6) + fps.min(30.0);
+                let video_bitrate = total_pixels * bits_per_pixel * effective_fps;
+                let audio_bitrate = 192_000.0;
+                let total_bitrate = video_bitrate + audio_bitrate;
+                let encoder_efficiency = 0.5;
+                (total_bitrate * encoder_efficiency * duration_seconds) / (8.0 * 1024.0 * 1024.0)
+            }
+            ExportSettings::Gif(_) => {
+                let total_frames = (duration_seconds * fps).ceil();
+                let bytes_per_frame = total_pixels * 0.5;
+                let gif_efficiency = 0.07;
+                (bytes_per_frame * gif_efficiency * total_frames) / (1024.0 * 1024.0)
+            }
+        }
+    }
+}
+
+fn check_resolution_license(
+    app: &tauri::AppHandle,
+    resolution: XY<u32>,
+) -> Result<(), UserMessage> {
+    if !cfg!(feature = "license-check")
+        || (resolution.x.max(resolution.y) <= 2560 && resolution.x.min(resolution.y) <= 1440)
+    {
+        return Ok(());
+    }
+
+    license::require(app, PaidFeature::HighResolutionExport)
+        .map_err(|_| UserMessage::new(MessageKey::ExportRequiresLicense))
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportPreset {
+    pub settings: ExportSettings,
+    #[serde(default)]
+    pub vertical: bool,
+}
+
+impl ExportPreset {
+    fn output_path(&self, destination: &Path) -> PathBuf {
+        let stem = destination
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "Export".to_string());
+        let mut name = format!("{stem} - {}p", self.settings.resolution_base().y);
+        if self.vertical {
+            name.push_str(" Vertical");
+        }
+        name.push_str(match self.settings {
+            ExportSettings::Mp4(_) => ".mp4",
+            ExportSettings::Gif(_) => ".gif",
+        });
+        destination.with_file_name(name)
+    }
+
+    fn target(&self, output_path: PathBuf) -> cap_export::multi::ExportTarget {
+        use cap_export::multi::{ExportTarget, ExportTargetFormat};
+
+        ExportTarget {
+            format: match self.settings {
+                ExportSettings::Mp4(settings) => ExportTargetFormat::Mp4 {
+                    bpp: settings.effective_bpp(),
+                },
+                ExportSettings::Gif(settings) => ExportTargetFormat::Gif {
+                    quality: settings.encoder_quality(),
+                    repeat: settings.repeat,
+                },
+            },
+            fps: self.settings.fps(),
+            resolution_base: self.settings.resolution_base(),
+            vertical: self.vertical,
+            output_path,
+        }
+    }
+}
+
+const EXPORT_SPACE_HEADROOM: f64 = 1.5;
+const DURATION_TOLERANCE_SECS: f64 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ExportResult {
+    pub path: PathBuf,
+    pub sha256: String,
+    pub verification: ExportVerification,
+    pub adjustment: Option<UserMessage>,
+}
+
+struct ExportPart {
+    config: ProjectConfiguration,
+    output_path: PathBuf,
+    frames_before: u32,
+    total_frames: u32,
+}
+
+struct FrameGate {
+    cancelled: Arc<AtomicBool>,
+    job: Arc<ExportJob>,
+    throttle: Option<ExportThrottle>,
+}
+
+impl FrameGate {
+    fn new(recorder: &ExportGuard, priority: ExportPriority) -> Self {
+        Self {
+            cancelled: recorder.cancelled(),
+            job: recorder.job(),
+            throttle: ExportThrottle::new(priority),
+        }
+    }
+
+    fn pass(&mut self, mut on_pause: impl FnMut(MessageKey)) -> bool {
+        if self.job.is_paused() {
+            on_pause(MessageKey::ExportPausedByUser);
+            let job = &self.job;
+            let cancelled = &self.cancelled;
+            if !tokio::task::block_in_place(|| job.wait_while_paused(cancelled)) {
+                return false;
+            }
+        }
+
+        match &mut self.throttle {
+            Some(throttle) => throttle.wait(&self.cancelled, || on_pause(MessageKey::ExportPaused)),
+            None => !self.cancelled.load(Ordering::Acquire),
+        }
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn do_export(
     project_path: &Path,
     settings: &ExportSettings,
     progress: &tauri::ipc::Channel<FramesRendered>,
     force_ffmpeg: bool,
-) -> Result<PathBuf, String> {
-    let exporter_base = ExporterBase::builder(project_path.to_path_buf())
+    cursor_theme: Option<Arc<CursorTheme>>,
+    effects: Vec<Arc<EffectPlugin>>,
+    brand: BrandAssets,
+    av_sync_offset: Option<f32>,
+    priority: ExportPriority,
+    part: Option<&ExportPart>,
+    recorder: &ExportGuard,
+) -> Result<ExportResult, UserMessage> {
+    let output_path = match part {
+        Some(part) => part.output_path.clone(),
+        None => default_output_path(project_path, settings)?,
+    };
+
+    let output = cap_utils::atomic_file::AtomicFile::new(output_path).map_err(|e| {
+        UserMessage::new(MessageKey::ExportPrepareOutputFailed).with("detail", e.to_string())
+    })?;
+
+    let mut builder = ExporterBase::builder(project_path.to_path_buf())
         .with_force_ffmpeg_decoder(force_ffmpeg)
-        .build()
-        .await
-        .map_err(|e| e.to_string())?;
+        .with_output_path(output.path().to_path_buf())
+        .with_output_target(output.target().to_path_buf())
+        .with_cursor_theme(cursor_theme)
+        .with_effects(effects)
+        .with_brand(brand)
+        .with_av_sync_offset(av_sync_offset)
+        .with_timelapse(settings.timelapse());
+    if let Some(part) = part {
+        builder = builder.with_config(part.config.clone());
+    }
+    let exporter_base = builder.build().await.map_err(UserMessage::export_failed)?;
 
     let total_frames = exporter_base.total_frames(settings.fps());
-
-    let _ = progress.send(FramesRendered {
-        rendered_count: 0,
-        total_frames,
+    let expected_duration = settings.output_duration(&exporter_base);
+    let (frames_before, reported_total) = part.map_or((0, total_frames), |part| {
+        (part.frames_before, part.total_frames)
     });
+    let timings = exporter_base.timings();
+    let encoder_log = exporter_base.encoder_log();
+    let job_id = recorder.id();
+    let report = move |rendered: u32| {
+        let rendered = frames_before + rendered.min(total_frames);
+        FramesRendered::new(job_id, rendered, reported_total)
+            .with_timings(timings.breakdown(reported_total.saturating_sub(rendered)))
+            .with_warnings(encoder_log.take())
+    };
+
+    cap_utils::disk_space::ensure_available_space(
+        output.target(),
+        required_space(settings, total_frames),
+    )
+    .map_err(not_enough_space)?;
+
+    let adjustment = settings.render_adjustment(&exporter_base);
+    let _ = progress.send(report(0));
+
+    let mut gate = FrameGate::new(recorder, priority);
+    let progress = progress.clone();
+    let on_frame = move |frame_index: u32| {
+        gate.pass(|reason| {
+            let _ = progress.send(report(frame_index).paused(reason));
+        }) && progress.send(report(frame_index + 1)).is_ok()
+    };
 
     match settings {
-        ExportSettings::Mp4(mp4_settings) => {
-            let progress = progress.clone();
-            mp4_settings
-                .export(exporter_base, move |frame_index| {
-                    progress
-                        .send(FramesRendered {
-                            rendered_count: (frame_index + 1).min(total_frames),
-                            total_frames,
-                        })
-                        .is_ok()
-                })
-                .await
-        }
-        ExportSettings::Gif(gif_settings) => {
-            let progress = progress.clone();
-            gif_settings
-                .export(exporter_base, move |frame_index| {
-                    progress
-                        .send(FramesRendered {
-                            rendered_count: (frame_index + 1).min(total_frames),
-                            total_frames,
-                        })
-                        .is_ok()
-                })
-                .await
-        }
+        ExportSettings::Mp4(mp4_settings) => mp4_settings.export(exporter_base, on_frame).await,
+        ExportSettings::Gif(gif_settings) => gif_settings.export(exporter_base, on_frame).await,
     }
+    .map_err(exporter_error_message)?;
+
+    let result = check_export(output.path().to_path_buf(), expected_duration).await?;
+    let path = output.commit().map_err(|e| {
+        UserMessage::new(MessageKey::ExportFinalizeFailed).with("detail", e.to_string())
+    })?;
+
+    Ok(ExportResult {
+        path,
+        adjustment,
+        ..result
+    })
+}
+
+fn default_output_path(
+    project_path: &Path,
+    settings: &ExportSettings,
+) -> Result<PathBuf, UserMessage> {
+    let mut output_path = RecordingMeta::load_for_project(project_path)
+        .map_err(UserMessage::export_failed)?
+        .output_path();
+    if let ExportSettings::Gif(_) = settings {
+        output_path.set_extension("gif");
+    }
+    Ok(output_path)
+}
+
+fn required_space(settings: &ExportSettings, total_frames: u32) -> u64 {
+    let duration_seconds = total_frames as f64 / settings.fps() as f64;
+    (settings.estimated_size_mb(duration_seconds) * EXPORT_SPACE_HEADROOM * 1024.0 * 1024.0) as u64
+}
+
+fn not_enough_space(e: cap_utils::disk_space::InsufficientDiskSpace) -> UserMessage {
+    UserMessage::new(MessageKey::ExportNotEnoughSpace)
+        .with("availableMb", e.available_mb())
+        .with("requiredMb", e.required_mb())
+}
+
+async fn run_pre_export_hook(
+    app: &tauri::AppHandle,
+    project_path: &Path,
+) -> Result<(), UserMessage> {
+    export_actions::run_pre_export(app, project_path)
+        .await
+        .map_err(|detail| {
+            UserMessage::new(MessageKey::ExportPreExportCommandFailed).with("detail", detail)
+        })
+        .inspect_err(|e| {
+            telemetry::error_occurred(app, ErrorCategory::Export);
+            webhooks::export_failed(app, project_path, &e.text);
+        })
+}
+
+fn render_adjustment(size: cap_export::RenderSize) -> Option<UserMessage> {
+    size.is_reduced().then(|| {
+        warn!(?size, "Compositing export below its output size");
+        UserMessage::new(MessageKey::ExportRenderDownscaled)
+            .with("renderWidth", size.render.0)
+            .with("renderHeight", size.render.1)
+            .with("outputWidth", size.output.0)
+            .with("outputHeight", size.output.1)
+    })
+}
+
+async fn check_export(path: PathBuf, expected_duration: f64) -> Result<ExportResult, UserMessage> {
+    tokio::task::spawn_blocking(move || {
+        let verification = probe_export(&path)?;
+        if (verification.duration_seconds - expected_duration).abs() > DURATION_TOLERANCE_SECS {
+            return Err(format!(
+                "Export is {:.2}s long, expected {expected_duration:.2}s",
+                verification.duration_seconds
+            ));
+        }
+
+        let sha256 = hash_file(&path).map_err(|e| format!("Failed to hash export: {e}"))?;
+        info!(%sha256, ?verification, "Verified export");
+
+        Ok(ExportResult {
+            path,
+            sha256,
+            verification,
+            adjustment: None,
+        })
+    })
+    .await
+    .map_err(UserMessage::export_failed)?
+    .map_err(UserMessage::export_failed)
+}
+
+fn hash_file(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut std::fs::File::open(path)?, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
 }
 
 fn is_frame_decode_error(error: &str) -> bool {
@@ -97,17 +403,34 @@ fn is_frame_decode_error(error: &str) -> bool {
         || error.contains("Too many consecutive frame failures")
 }
 
+fn exporter_error_message(error: String) -> UserMessage {
+    if error.ends_with("Export cancelled") {
+        UserMessage::new(MessageKey::ExportCancelled)
+    } else if is_frame_decode_error(&error) {
+        UserMessage::new(MessageKey::ExportDecodeFailed)
+    } else {
+        UserMessage::export_failed(error)
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
-#[instrument(skip(progress, editor))]
+#[instrument(skip(app, progress, editor))]
 pub async fn export_video(
+    app: tauri::AppHandle,
     project_path: PathBuf,
     progress: tauri::ipc::Channel<FramesRendered>,
     settings: ExportSettings,
+    completion_actions: Option<ExportCompletionActions>,
     editor: OptionalWindowEditorInstance,
-) -> Result<PathBuf, String> {
+) -> Result<ExportResult, UserMessage> {
+    settings.check_license(&app)?;
+    run_pre_export_hook(&app, &project_path).await?;
+
     let force_ffmpeg = false;
 
+    let recorder = RecorderStateMachine::begin_export(&app, &[&project_path])
+        .map_err(UserMessage::export_failed)?;
     let _guard = if let Some(ref ed) = *editor {
         ed.export_active.store(true, Ordering::Release);
         tracing::info!("Pausing editor preview during export");
@@ -116,42 +439,621 @@ pub async fn export_video(
         None
     };
 
-    let result = do_export(&project_path, &settings, &progress, force_ffmpeg).await;
+    let cursor_theme = cursor_themes::load_selected(&app);
+    let effects = effect_plugins::load_enabled(&app);
+    let brand = brand_kit::load_render_assets(&app);
+    let av_sync_offset = av_sync::load_offset(&app);
+    let priority = export_priority::load(&app);
+
+    let result = do_export(
+        &project_path,
+        &settings,
+        &progress,
+        force_ffmpeg,
+        cursor_theme.clone(),
+        effects.clone(),
+        brand.clone(),
+        av_sync_offset,
+        priority,
+        None,
+        &recorder,
+    )
+    .await;
 
     match result {
-        Ok(path) => {
-            info!("Exported to {} completed", path.display());
-            Ok(path)
+        Ok(result) => {
+            info!("Exported to {} completed", result.path.display());
+            telemetry::feature_used(&app, Feature::Export);
+            let _ = ExportCompleted {
+                path: result.path.clone(),
+            }
+            .emit(&app);
+            webhooks::export_complete(&app, &result.path, result.verification.duration_seconds);
+            export_actions::run(&app, &result.path, &project_path, completion_actions).await;
+            Ok(result)
         }
-        Err(e) if !force_ffmpeg && is_frame_decode_error(&e) => {
+        Err(e) if !force_ffmpeg && e.key == MessageKey::ExportDecodeFailed => {
             info!(
                 "Export failed with frame decode error, retrying with FFmpeg decoder: {}",
                 e
             );
 
-            let retry_result = do_export(&project_path, &settings, &progress, true).await;
+            let retry_result = do_export(
+                &project_path,
+                &settings,
+                &progress,
+                true,
+                cursor_theme,
+                effects,
+                brand,
+                av_sync_offset,
+                priority,
+                None,
+                &recorder,
+            )
+            .await;
 
             match retry_result {
-                Ok(path) => {
+                Ok(result) => {
                     info!(
                         "Export succeeded with FFmpeg decoder fallback: {}",
-                        path.display()
+                        result.path.display()
                     );
-                    Ok(path)
+                    telemetry::feature_used(&app, Feature::Export);
+                    let _ = ExportCompleted {
+                        path: result.path.clone(),
+                    }
+                    .emit(&app);
+                    webhooks::export_complete(
+                        &app,
+                        &result.path,
+                        result.verification.duration_seconds,
+                    );
+                    export_actions::run(&app, &result.path, &project_path, completion_actions)
+                        .await;
+                    Ok(result)
                 }
                 Err(retry_e) => {
-                    sentry::capture_message(&retry_e, sentry::Level::Error);
+                    sentry::capture_message(&retry_e.text, sentry::Level::Error);
+                    telemetry::error_occurred(&app, ErrorCategory::Export);
+                    webhooks::export_failed(&app, &project_path, &retry_e.text);
                     Err(retry_e)
                 }
             }
         }
+        Err(e) if e.key == MessageKey::ExportCancelled => Err(e),
         Err(e) => {
-            sentry::capture_message(&e, sentry::Level::Error);
+            sentry::capture_message(&e.text, sentry::Level::Error);
+            telemetry::error_occurred(&app, ErrorCategory::Export);
+            webhooks::export_failed(&app, &project_path, &e.text);
             Err(e)
         }
     }
 }
 
+fn part_path(destination: &Path, number: usize, count: usize) -> PathBuf {
+    let stem = destination
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Export".to_string());
+    let width = count.to_string().len().max(2);
+    let mut name = format!("{stem} - Part {number:0width$}");
+    if let Some(extension) = destination.extension() {
+        name.push('.');
+        name.push_str(&extension.to_string_lossy());
+    }
+    destination.with_file_name(name)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, progress, editor))]
+pub async fn export_video_parts(
+    app: tauri::AppHandle,
+    project_path: PathBuf,
+    progress: tauri::ipc::Channel<FramesRendered>,
+    settings: ExportSettings,
+    split: ExportSplit,
+    destination: PathBuf,
+    editor: OptionalWindowEditorInstance,
+) -> Result<Vec<ExportResult>, UserMessage> {
+    settings.check_license(&app)?;
+    if settings.timelapse().is_some() {
+        return Err(UserMessage::export_failed(
+            "Timelapses can't be split into parts",
+        ));
+    }
+    run_pre_export_hook(&app, &project_path).await?;
+
+    let recorder = RecorderStateMachine::begin_export(&app, &[&project_path])
+        .map_err(UserMessage::export_failed)?;
+    let _guard = if let Some(ref ed) = *editor {
+        ed.export_active.store(true, Ordering::Release);
+        tracing::info!("Pausing editor preview during export");
+        Some(ExportActiveGuard(&ed.export_active))
+    } else {
+        None
+    };
+
+    let project = RecordingMeta::load_for_project(&project_path)
+        .map_err(UserMessage::export_failed)?
+        .project_config();
+    let Some(timeline) = &project.timeline else {
+        return Err(UserMessage::export_failed(
+            "The project has no timeline to split",
+        ));
+    };
+
+    let ranges = split.ranges(timeline);
+    let fps = f64::from(settings.fps());
+    let part_frames = ranges
+        .iter()
+        .map(|(start, end)| ((end - start) * fps).ceil() as u32)
+        .collect::<Vec<_>>();
+    let total_frames = part_frames.iter().sum();
+    info!(parts = ranges.len(), total_frames, "Exporting in parts");
+
+    let cursor_theme = cursor_themes::load_selected(&app);
+    let effects = effect_plugins::load_enabled(&app);
+    let brand = brand_kit::load_render_assets(&app);
+    let av_sync_offset = av_sync::load_offset(&app);
+    let priority = export_priority::load(&app);
+
+    let mut results = Vec::with_capacity(ranges.len());
+    let mut frames_before = 0;
+    for (index, (range, frames)) in ranges.iter().zip(part_frames).enumerate() {
+        let part = ExportPart {
+            config: project.slice(*range),
+            output_path: part_path(&destination, index + 1, ranges.len()),
+            frames_before,
+            total_frames,
+        };
+
+        let result = do_export(
+            &project_path,
+            &settings,
+            &progress,
+            false,
+            cursor_theme.clone(),
+            effects.clone(),
+            brand.clone(),
+            av_sync_offset,
+            priority,
+            Some(&part),
+            &recorder,
+        )
+        .await
+        .inspect_err(|e| {
+            if e.key != MessageKey::ExportCancelled {
+                sentry::capture_message(&e.text, sentry::Level::Error);
+                webhooks::export_failed(&app, &project_path, &e.text);
+            }
+        })?;
+
+        let _ = ExportCompleted {
+            path: result.path.clone(),
+        }
+        .emit(&app);
+        webhooks::export_complete(&app, &result.path, result.verification.duration_seconds);
+        results.push(result);
+        frames_before += frames;
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, progress, editor))]
+pub async fn export_video_presets(
+    app: tauri::AppHandle,
+    project_path: PathBuf,
+    progress: tauri::ipc::Channel<FramesRendered>,
+    presets: Vec<ExportPreset>,
+    destination: PathBuf,
+    editor: OptionalWindowEditorInstance,
+) -> Result<Vec<ExportResult>, UserMessage> {
+    let recorder = RecorderStateMachine::begin_export(&app, &[&project_path])
+        .map_err(UserMessage::export_failed)?;
+    let _guard = if let Some(ref ed) = *editor {
+        ed.export_active.store(true, Ordering::Release);
+        tracing::info!("Pausing editor preview during export");
+        Some(ExportActiveGuard(&ed.export_active))
+    } else {
+        None
+    };
+
+    for preset in &presets {
+        preset.settings.check_license(&app)?;
+    }
+    run_pre_export_hook(&app, &project_path).await?;
+
+    let Some(fps) = presets.iter().map(|preset| preset.settings.fps()).max() else {
+        return Ok(vec![]);
+    };
+
+    let outputs = presets
+        .iter()
+        .map(|preset| cap_utils::atomic_file::AtomicFile::new(preset.output_path(&destination)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            UserMessage::new(MessageKey::ExportPrepareOutputFailed).with("detail", e.to_string())
+        })?;
+
+    let exporter_base = ExporterBase::builder(project_path.clone())
+        .with_output_path(destination.clone())
+        .with_cursor_theme(cursor_themes::load_selected(&app))
+        .with_effects(effect_plugins::load_enabled(&app))
+        .with_brand(brand_kit::load_render_assets(&app))
+        .with_av_sync_offset(av_sync::load_offset(&app))
+        .build()
+        .await
+        .map_err(UserMessage::export_failed)?;
+
+    let total_frames = exporter_base.total_frames(fps);
+    let expected_duration = exporter_base.duration();
+    let adjustment = presets
+        .iter()
+        .map(|preset| preset.settings.resolution_base())
+        .max_by_key(|base| u64::from(base.x) * u64::from(base.y))
+        .and_then(|base| render_adjustment(exporter_base.render_size(base)));
+    let duration_seconds = total_frames as f64 / fps as f64;
+    let required_mb = presets
+        .iter()
+        .map(|preset| preset.settings.estimated_size_mb(duration_seconds))
+        .sum::<f64>();
+    cap_utils::disk_space::ensure_available_space(
+        &destination,
+        (required_mb * EXPORT_SPACE_HEADROOM * 1024.0 * 1024.0) as u64,
+    )
+    .map_err(not_enough_space)?;
+
+    info!(presets = presets.len(), total_frames, "Exporting presets");
+    let job_id = recorder.id();
+    let _ = progress.send(FramesRendered::new(job_id, 0, total_frames));
+
+    let targets = presets
+        .iter()
+        .zip(&outputs)
+        .map(|(preset, output)| preset.target(output.path().to_path_buf()))
+        .collect();
+    let timings = exporter_base.timings();
+    let encoder_log = exporter_base.encoder_log();
+    cap_export::multi::export(exporter_base, targets, {
+        let progress = progress.clone();
+        let mut gate = FrameGate::new(&recorder, export_priority::load(&app));
+        move |frame_index| {
+            let rendered = (frame_index + 1).min(total_frames);
+            gate.pass(|reason| {
+                let _ = progress.send(
+                    FramesRendered::new(job_id, frame_index.min(total_frames), total_frames)
+                        .paused(reason),
+                );
+            }) && progress
+                .send(
+                    FramesRendered::new(job_id, rendered, total_frames)
+                        .with_timings(timings.breakdown(total_frames - rendered))
+                        .with_warnings(encoder_log.take()),
+                )
+                .is_ok()
+        }
+    })
+    .await
+    .map_err(exporter_error_message)
+    .inspect_err(|e| {
+        if e.key != MessageKey::ExportCancelled {
+            sentry::capture_message(&e.text, sentry::Level::Error);
+            webhooks::export_failed(&app, &project_path, &e.text);
+        }
+    })?;
+
+    let mut checked = Vec::with_capacity(outputs.len());
+    for output in &outputs {
+        checked.push(check_export(output.path().to_path_buf(), expected_duration).await?);
+    }
+
+    let mut results = Vec::with_capacity(outputs.len());
+    for (output, result) in outputs.into_iter().zip(checked) {
+        let path = output.commit().map_err(|e| {
+            UserMessage::new(MessageKey::ExportFinalizeFailed).with("detail", e.to_string())
+        })?;
+        let _ = ExportCompleted { path: path.clone() }.emit(&app);
+        webhooks::export_complete(&app, &path, result.verification.duration_seconds);
+        results.push(ExportResult {
+            path,
+            adjustment: adjustment.clone(),
+            ..result
+        });
+    }
+
+    Ok(results)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, progress))]
+pub async fn export_comparison(
+    app: tauri::AppHandle,
+    before: PathBuf,
+    after: PathBuf,
+    progress: tauri::ipc::Channel<FramesRendered>,
+    settings: ComparisonExportSettings,
+    destination: PathBuf,
+) -> Result<ExportResult, UserMessage> {
+    check_resolution_license(&app, settings.resolution_base)?;
+    let recorder = RecorderStateMachine::begin_export(&app, &[&before, &after])
+        .map_err(UserMessage::export_failed)?;
+
+    let output = cap_utils::atomic_file::AtomicFile::new(destination).map_err(|e| {
+        UserMessage::new(MessageKey::ExportPrepareOutputFailed).with("detail", e.to_string())
+    })?;
+    let build = |project_path: PathBuf| {
+        ExporterBase::builder(project_path)
+            .with_output_path(output.path().to_path_buf())
+            .with_output_target(output.target().to_path_buf())
+            .with_cursor_theme(cursor_themes::load_selected(&app))
+            .with_effects(effect_plugins::load_enabled(&app))
+            .with_brand(brand_kit::load_render_assets(&app))
+            .with_av_sync_offset(av_sync::load_offset(&app))
+            .build()
+    };
+    let (before, after) =
+        tokio::try_join!(build(before), build(after)).map_err(UserMessage::export_failed)?;
+
+    let fps = settings.fps;
+    let total_frames = before.total_frames(fps).max(after.total_frames(fps));
+    let expected_duration = ComparisonExportSettings::duration(&before, &after);
+    let (width, height) = settings.output_size();
+    let estimated_bytes = f64::from(width * height)
+        * f64::from(settings.compression_bpp)
+        * f64::from(fps)
+        * expected_duration
+        * 0.5
+        / 8.0;
+    cap_utils::disk_space::ensure_available_space(
+        output.target(),
+        (estimated_bytes * EXPORT_SPACE_HEADROOM) as u64,
+    )
+    .map_err(not_enough_space)?;
+
+    let job_id = recorder.id();
+    let _ = progress.send(FramesRendered::new(job_id, 0, total_frames));
+
+    let mut gate = FrameGate::new(&recorder, export_priority::load(&app));
+    let timings = before.timings();
+    let encoder_log = before.encoder_log();
+    settings
+        .export(before, after, output.path().to_path_buf(), {
+            let progress = progress.clone();
+            move |frame_index| {
+                let rendered = (frame_index + 1).min(total_frames);
+                gate.pass(|reason| {
+                    let _ = progress.send(
+                        FramesRendered::new(job_id, frame_index.min(total_frames), total_frames)
+                            .paused(reason),
+                    );
+                }) && progress
+                    .send(
+                        FramesRendered::new(job_id, rendered, total_frames)
+                            .with_timings(timings.breakdown(total_frames - rendered))
+                            .with_warnings(encoder_log.take()),
+                    )
+                    .is_ok()
+            }
+        })
+        .await
+        .map_err(exporter_error_message)?;
+
+    let result = check_export(output.path().to_path_buf(), expected_duration).await?;
+    let path = output.commit().map_err(|e| {
+        UserMessage::new(MessageKey::ExportFinalizeFailed).with("detail", e.to_string())
+    })?;
+    let _ = ExportCompleted { path: path.clone() }.emit(&app);
+
+    Ok(ExportResult { path, ..result })
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub fn pause_export(app: tauri::AppHandle, job_id: u32) -> Result<(), String> {
+    app.state::<RecorderStateMachine>()
+        .set_export_paused(job_id, true)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub fn resume_export(app: tauri::AppHandle, job_id: u32) -> Result<(), String> {
+    app.state::<RecorderStateMachine>()
+        .set_export_paused(job_id, false)
+}
+
+const H264_MAX_FRAME_MACROBLOCKS: u32 = 36_864;
+const GIF_MAX_FPS: u32 = 50;
+
+#[derive(Debug, Clone, Default, Serialize, Type)]
+pub struct ExportValidation {
+    pub problems: Vec<UserMessage>,
+    pub warnings: Vec<UserMessage>,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn validate_export(
+    app: tauri::AppHandle,
+    project_path: PathBuf,
+    settings: ExportSettings,
+) -> Result<ExportValidation, String> {
+    let mut validation = ExportValidation::default();
+
+    if let Err(e) = settings.check_license(&app) {
+        validation.problems.push(e);
+    }
+
+    if let ExportSettings::Mp4(_) = settings {
+        let encoders = [
+            ("H.264", ffmpeg::encoder::find(ffmpeg::codec::Id::H264)),
+            ("AAC", ffmpeg::encoder::find_by_name("aac")),
+        ];
+        for (codec, _) in encoders.iter().filter(|(_, encoder)| encoder.is_none()) {
+            validation
+                .problems
+                .push(UserMessage::new(MessageKey::ExportEncoderUnavailable).with("codec", *codec));
+        }
+    }
+
+    let output_path = match default_output_path(&project_path, &settings) {
+        Ok(output_path) => output_path,
+        Err(e) => {
+            validation.problems.push(e);
+            return Ok(validation);
+        }
+    };
+
+    if let Err(e) = cap_utils::atomic_file::AtomicFile::new(output_path.clone())
+        .and_then(|output| std::fs::File::create(output.path()).map(drop))
+    {
+        validation.problems.push(
+            UserMessage::new(MessageKey::ExportOutputNotWritable)
+                .with("path", output_path.display().to_string())
+                .with("detail", e.to_string()),
+        );
+    }
+
+    let base = match ExporterBase::builder(project_path)
+        .with_output_path(output_path.clone())
+        .with_cursor_theme(cursor_themes::load_selected(&app))
+        .with_effects(effect_plugins::load_enabled(&app))
+        .with_brand(brand_kit::load_render_assets(&app))
+        .with_av_sync_offset(av_sync::load_offset(&app))
+        .with_timelapse(settings.timelapse())
+        .build()
+        .await
+    {
+        Ok(base) => base,
+        Err(e) => {
+            validation.problems.push(UserMessage::export_failed(e));
+            return Ok(validation);
+        }
+    };
+
+    let total_frames = base.total_frames(settings.fps());
+    if total_frames == 0 {
+        validation
+            .problems
+            .push(UserMessage::new(MessageKey::ExportEmptyTimeline));
+    }
+
+    if let Err(e) = cap_utils::disk_space::ensure_available_space(
+        &output_path,
+        required_space(&settings, total_frames),
+    ) {
+        validation.problems.push(not_enough_space(e));
+    }
+
+    let (width, height) = base.render_size(settings.resolution_base()).output;
+    match settings {
+        ExportSettings::Mp4(_)
+            if width.div_ceil(16) * height.div_ceil(16) > H264_MAX_FRAME_MACROBLOCKS =>
+        {
+            validation.warnings.push(
+                UserMessage::new(MessageKey::ExportResolutionCompatibility)
+                    .with("width", width)
+                    .with("height", height),
+            );
+        }
+        ExportSettings::Gif(settings) if settings.fps > GIF_MAX_FPS => {
+            validation
+                .warnings
+                .push(UserMessage::new(MessageKey::ExportGifFrameRate).with("maxFps", GIF_MAX_FPS));
+        }
+        _ => {}
+    }
+    validation
+        .warnings
+        .extend(settings.render_adjustment(&base));
+
+    Ok(validation)
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct ExportVerification {
+    pub duration_seconds: f64,
+    pub width: u32,
+    pub height: u32,
+    pub video_streams: u32,
+    pub audio_streams: u32,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+pub async fn verify_export(path: PathBuf) -> Result<ExportVerification, String> {
+    tokio::task::spawn_blocking(move || probe_export(&path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn probe_export(path: &Path) -> Result<ExportVerification, String> {
+    use ffmpeg::media::Type;
+
+    let mut input =
+        ffmpeg::format::input(path).map_err(|e| format!("Failed to open export: {e}"))?;
+
+    let raw_duration = input.duration();
+
+    let count_streams = |medium: Type| {
+        input
+            .streams()
+            .filter(|s| s.parameters().medium() == medium)
+            .count() as u32
+    };
+    let video_streams = count_streams(Type::Video);
+    let audio_streams = count_streams(Type::Audio);
+
+    let video = input
+        .streams()
+        .best(Type::Video)
+        .ok_or_else(|| "Export has no video stream".to_string())?;
+    let video_index = video.index();
+    let time_base = f64::from(video.time_base());
+    let decoder = ffmpeg::codec::context::Context::from_parameters(video.parameters())
+        .and_then(|c| c.decoder().video())
+        .map_err(|e| format!("Export video stream can't be decoded: {e}"))?;
+    let (width, height) = (decoder.width(), decoder.height());
+
+    let mut video_packets = input
+        .packets()
+        .filter(|(stream, _)| stream.index() == video_index)
+        .map(|(_, packet)| packet);
+
+    let duration_seconds = if raw_duration > 0 {
+        if video_packets.next().is_none() {
+            return Err("Export contains no video frames".to_string());
+        }
+        raw_duration as f64 / ffmpeg::ffi::AV_TIME_BASE as f64
+    } else {
+        let end = video_packets
+            .map(|packet| packet.pts().unwrap_or(0) + packet.duration())
+            .max()
+            .ok_or_else(|| "Export contains no video frames".to_string())?;
+        if end <= 0 {
+            return Err(format!("Export has no duration: {}", path.display()));
+        }
+        end as f64 * time_base
+    };
+
+    Ok(ExportVerification {
+        duration_seconds,
+        width,
+        height,
+        video_streams,
+        audio_streams,
+    })
+}
+
 #[derive(Debug, serde::Serialize, specta::Type)]
 pub struct ExportEstimates {
     pub duration_seconds: f64,
@@ -170,54 +1072,35 @@ pub async fn get_export_estimates(
 
     let meta = RecordingMeta::load_for_project(&path).map_err(|e| e.to_string())?;
     let project_config = meta.project_config();
-    let duration_seconds = if let Some(timeline) = &project_config.timeline {
+    let mut duration_seconds = if let Some(timeline) = &project_config.timeline {
         timeline.segments.iter().map(|s| s.duration()).sum()
     } else {
         metadata.duration
     };
+    if let Some(timelapse) = settings.timelapse() {
+        duration_seconds /= timelapse.speed.max(1.0);
+    }
 
-    let (resolution, fps) = match &settings {
-        ExportSettings::Mp4(s) => (s.resolution_base, s.fps),
-        ExportSettings::Gif(s) => (s.resolution_base, s.fps),
-    };
-
+    let resolution = settings.resolution_base();
     let (width, height) = (resolution.x, resolution.y);
-    let total_pixels = (width * height) as f64;
-    let fps_f64 = fps as f64;
-    let total_frames = (duration_seconds * fps_f64).ceil();
-
-    let (estimated_size_mb, estimated_time_seconds) = match &settings {
-        ExportSettings::Mp4(mp4_settings) => {
-            let bits_per_pixel = mp4_settings.compression.bits_per_pixel() as f64;
-            let effective_fps = ((fps_f64 - 30.0).max(0.0) * 0.6) + fps_f64.min(30.0);
-            let video_bitrate = total_pixels * bits_per_pixel * effective_fps;
-            let audio_bitrate = 192_000.0;
-            let total_bitrate = video_bitrate + audio_bitrate;
-            let encoder_efficiency = 0.5;
-            let size_mb =
-                (total_bitrate * encoder_efficiency * duration_seconds) / (8.0 * 1024.0 * 1024.0);
+    let total_frames = (duration_seconds * settings.fps() as f64).ceil();
 
+    let estimated_size_mb = settings.estimated_size_mb(duration_seconds);
+    let estimated_time_seconds = match &settings {
+        ExportSettings::Mp4(_) => {
             let effective_render_fps = match (width, height) {
                 (w, _) if w >= 3840 => 175.0,
                 _ => 290.0,
             };
-            let time_estimate = total_frames / effective_render_fps;
-
-            (size_mb, time_estimate)
+            total_frames / effective_render_fps
         }
         ExportSettings::Gif(_) => {
-            let bytes_per_frame = total_pixels * 0.5;
-            let gif_efficiency = 0.07;
-            let size_mb = (bytes_per_frame * gif_efficiency * total_frames) / (1024.0 * 1024.0);
-
             let frames_per_sec = match (width, height) {
                 (w, h) if w <= 1280 && h <= 720 => 10.0,
                 (w, h) if w <= 1920 && h <= 1080 => 5.0,
                 _ => 2.0,
             };
-            let time_estimate = total_frames / frames_per_sec;
-
-            (size_mb, time_estimate)
+            total_frames / frames_per_sec
         }
     };
 
@@ -253,6 +1136,7 @@ fn bpp_to_jpeg_quality(bpp: f32) -> u8 {
 #[specta::specta]
 #[instrument(skip_all)]
 pub async fn generate_export_preview(
+    app: tauri::AppHandle,
     project_path: PathBuf,
     frame_time: f64,
     settings: ExportPreviewSettings,
@@ -282,7 +1166,10 @@ pub async fn generate_export_preview(
             (**studio_meta).clone(),
         )
         .await
-        .map_err(|e| format!("Failed to create render constants: {e}"))?,
+        .map_err(|e| format!("Failed to create render constants: {e}"))?
+        .with_cursor_theme(cursor_themes::load_selected(&app))
+        .with_effects(effect_plugins::load_enabled(&app))
+        .with_brand(brand_kit::load_render_assets(&app)),
     );
 
     let segments = create_segments(&recording_meta, studio_meta, false)
@@ -0,0 +1,171 @@
+use std::{path::Path, time::Duration};
+
+use clipboard_rs::{Clipboard, ClipboardContext};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{info, warn};
+
+use crate::{ArcLock, general_settings::GeneralSettingsStore};
+
+#[derive(Default, Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCompletionActions {
+    #[serde(default)]
+    pub reveal_in_file_manager: bool,
+    #[serde(default)]
+    pub copy_path: bool,
+    #[serde(default)]
+    pub copy_file: bool,
+    #[serde(default)]
+    pub open_with_default_player: bool,
+    #[serde(default)]
+    pub run_command: Option<String>,
+}
+
+const PRE_EXPORT_TIMEOUT: Duration = Duration::from_secs(120);
+
+pub async fn run_pre_export(app: &AppHandle, project_path: &Path) -> Result<(), String> {
+    let Some(command) = GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()
+        .and_then(|s| s.pre_export_command)
+        .filter(|c| !c.trim().is_empty())
+    else {
+        return Ok(());
+    };
+
+    let mut cmd = tokio::process::Command::from(shell_command(command.trim(), project_path));
+    cmd.env("DRIFT_PROJECT_PATH", project_path)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true);
+
+    let output = tokio::time::timeout(PRE_EXPORT_TIMEOUT, cmd.output())
+        .await
+        .map_err(|_| format!("timed out after {}s", PRE_EXPORT_TIMEOUT.as_secs()))?
+        .map_err(|e| format!("failed to start: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(match stderr.trim().lines().last() {
+            Some(line) => format!("exited with {}: {line}", output.status),
+            None => format!("exited with {}", output.status),
+        });
+    }
+
+    info!("Pre-export command finished");
+    Ok(())
+}
+
+pub async fn run(
+    app: &AppHandle,
+    path: &Path,
+    project_path: &Path,
+    overrides: Option<ExportCompletionActions>,
+) {
+    let actions = match overrides {
+        Some(actions) => actions,
+        None => GeneralSettingsStore::get(app)
+            .ok()
+            .flatten()
+            .map(|s| s.export_completion_actions)
+            .unwrap_or_default(),
+    };
+
+    if actions.copy_path || actions.copy_file {
+        let clipboard = app.state::<ArcLock<ClipboardContext>>();
+        let clipboard = clipboard.write().await;
+
+        let result = if actions.copy_file {
+            clipboard.set_files(vec![path.to_string_lossy().into_owned()])
+        } else {
+            clipboard.set_text(path.to_string_lossy().into_owned())
+        };
+
+        if let Err(e) = result {
+            warn!("Failed to copy export to clipboard: {e}");
+        }
+    }
+
+    if actions.reveal_in_file_manager
+        && let Err(e) = app.opener().reveal_item_in_dir(path)
+    {
+        warn!("Failed to reveal export: {e}");
+    }
+
+    if actions.open_with_default_player
+        && let Err(e) = app
+            .opener()
+            .open_path(path.to_string_lossy(), None::<String>)
+    {
+        warn!("Failed to open export: {e}");
+    }
+
+    if let Some(command) = actions
+        .run_command
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+    {
+        run_command(command, path, project_path);
+    }
+}
+
+fn shell_command(command: &str, path: &Path) -> std::process::Command {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.raw_arg(format!("/S /C \"{}\"", windows_command(command, path)));
+        cmd
+    }
+
+    #[cfg(not(windows))]
+    {
+        let mut cmd = std::process::Command::new("sh");
+        cmd.arg("-c").arg(command).arg("drift-export").arg(path);
+        cmd
+    }
+}
+
+#[cfg(any(windows, test))]
+fn windows_command(command: &str, path: &Path) -> String {
+    let quoted = format!("\"{}\"", path.display());
+    command.replace("\"%1\"", "%1").replace("%1", &quoted)
+}
+
+fn run_command(command: &str, path: &Path, project_path: &Path) {
+    let mut cmd = shell_command(command, path);
+    cmd.env("DRIFT_EXPORT_PATH", path)
+        .env("DRIFT_PROJECT_PATH", project_path);
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            warn!("Failed to start post-export command: {e}");
+            return;
+        }
+    };
+
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if status.success() => info!("Post-export command finished"),
+        Ok(status) => warn!("Post-export command exited with {status}"),
+        Err(e) => warn!("Failed to wait for post-export command: {e}"),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_paths_are_quoted_once() {
+        let path = Path::new(r"C:\Users\Jo Smith\Videos\My Export.mp4");
+        let quoted = r#"copy "C:\Users\Jo Smith\Videos\My Export.mp4" D:\"#;
+
+        assert_eq!(windows_command(r"copy %1 D:\", path), quoted);
+        assert_eq!(windows_command(r#"copy "%1" D:\"#, path), quoted);
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+
+use cap_project::RecordingMeta;
+use tauri::AppHandle;
+use tauri_plugin_dialog::DialogExt;
+use tracing::instrument;
+
+use crate::general_settings::{ExportDirectoryMode, GeneralSettingsStore};
+
+fn export_directory(app: &AppHandle, meta: &RecordingMeta) -> Result<Option<PathBuf>, String> {
+    if let Some(dir) = meta.project_config().export_directory {
+        return Ok(Some(dir));
+    }
+
+    let settings = GeneralSettingsStore::get(app)?.unwrap_or_default();
+    Ok(match settings.export_directory_mode {
+        ExportDirectoryMode::AskEveryTime => None,
+        ExportDirectoryMode::SameAsProject => meta.project_path.parent().map(Path::to_path_buf),
+        ExportDirectoryMode::Custom => settings.export_directory,
+    })
+}
+
+fn unique_path(dir: &Path, name: &str, extension: &str) -> PathBuf {
+    let path = dir.join(format!("{name}.{extension}"));
+    if !path.exists() {
+        return path;
+    }
+
+    (2..)
+        .map(|n| dir.join(format!("{name} ({n}).{extension}")))
+        .find(|path| !path.exists())
+        .unwrap_or(path)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn resolve_output_path(
+    app: AppHandle,
+    project_path: PathBuf,
+    extension: String,
+) -> Result<Option<PathBuf>, String> {
+    let meta = RecordingMeta::load_for_project(&project_path).map_err(|e| e.to_string())?;
+    let Some(dir) = export_directory(&app, &meta)? else {
+        return Ok(None);
+    };
+
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create export directory {}: {e}", dir.display()))?;
+
+    let name = sanitize_filename::sanitize(meta.pretty_name.replace(":", "."));
+    Ok(Some(unique_path(&dir, &name, &extension)))
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn choose_default_directory(app: AppHandle) -> Result<Option<PathBuf>, String> {
+    let dialog_app = app.clone();
+    let picked = tokio::task::spawn_blocking(move || {
+        dialog_app
+            .dialog()
+            .file()
+            .set_title("Default export folder")
+            .blocking_pick_folder()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let Some(picked) = picked else {
+        return Ok(None);
+    };
+    let dir = picked.into_path().map_err(|e| e.to_string())?;
+
+    GeneralSettingsStore::update(&app, |s| {
+        s.export_directory_mode = ExportDirectoryMode::Custom;
+        s.export_directory = Some(dir.clone());
+    })?;
+
+    Ok(Some(dir))
+}
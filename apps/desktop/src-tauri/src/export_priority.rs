@@ -0,0 +1,173 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use device_query::{DeviceQuery, DeviceState, Keycode, MouseState};
+use tauri::AppHandle;
+use tracing::{debug, info, warn};
+
+use crate::general_settings::{ExportPriority, GeneralSettingsStore};
+
+const PACING_RATIO: f64 = 0.5;
+const MAX_PACING: Duration = Duration::from_millis(250);
+const IDLE_AFTER: Duration = Duration::from_secs(10);
+const PAUSED_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub fn load(app: &AppHandle) -> ExportPriority {
+    GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()
+        .map(|settings| settings.export_priority)
+        .unwrap_or_default()
+}
+
+pub struct ExportThrottle {
+    last_frame: Option<Instant>,
+    activity: Option<ActivityMonitor>,
+    _priority: Option<BackgroundPriority>,
+}
+
+impl ExportThrottle {
+    pub fn new(priority: ExportPriority) -> Option<Self> {
+        let pause_while_active = match priority {
+            ExportPriority::Normal => return None,
+            ExportPriority::Background => false,
+            ExportPriority::BackgroundWhenIdle => true,
+        };
+
+        Some(Self {
+            last_frame: None,
+            activity: pause_while_active.then(ActivityMonitor::new),
+            _priority: Some(BackgroundPriority::enter()),
+        })
+    }
+
+    pub fn wait(&mut self, cancelled: &AtomicBool, on_pause: impl FnOnce()) -> bool {
+        let pacing = self
+            .last_frame
+            .map(|last| last.elapsed().mul_f64(PACING_RATIO).min(MAX_PACING))
+            .unwrap_or_default();
+
+        let not_cancelled = tokio::task::block_in_place(|| {
+            std::thread::sleep(pacing);
+
+            let Some(activity) = &mut self.activity else {
+                return !cancelled.load(Ordering::Acquire);
+            };
+
+            let mut on_pause = Some(on_pause);
+            while activity.user_active() {
+                if let Some(on_pause) = on_pause.take() {
+                    debug!("Pausing background export while the user is active");
+                    on_pause();
+                }
+                if cancelled.load(Ordering::Acquire) {
+                    return false;
+                }
+                std::thread::sleep(PAUSED_POLL_INTERVAL);
+            }
+            if on_pause.is_none() {
+                debug!("Resuming background export");
+            }
+
+            !cancelled.load(Ordering::Acquire)
+        });
+
+        self.last_frame = Some(Instant::now());
+        not_cancelled
+    }
+}
+
+struct ActivityMonitor {
+    device_state: DeviceState,
+    last_mouse: MouseState,
+    last_keys: Vec<Keycode>,
+    last_input: Option<Instant>,
+}
+
+impl ActivityMonitor {
+    fn new() -> Self {
+        let device_state = DeviceState::new();
+        Self {
+            last_mouse: device_state.get_mouse(),
+            last_keys: device_state.get_keys(),
+            device_state,
+            last_input: None,
+        }
+    }
+
+    fn user_active(&mut self) -> bool {
+        let mouse = self.device_state.get_mouse();
+        let keys = self.device_state.get_keys();
+        if mouse != self.last_mouse || keys != self.last_keys || !keys.is_empty() {
+            self.last_input = Some(Instant::now());
+        }
+        self.last_mouse = mouse;
+        self.last_keys = keys;
+
+        self.last_input
+            .is_some_and(|last| last.elapsed() < IDLE_AFTER)
+    }
+}
+
+static BACKGROUND_EXPORTS: Mutex<usize> = Mutex::new(0);
+
+struct BackgroundPriority;
+
+impl BackgroundPriority {
+    fn enter() -> Self {
+        let mut count = BACKGROUND_EXPORTS.lock().unwrap();
+        if *count == 0 {
+            info!("Lowering process priority for a background export");
+            set_background(true);
+        }
+        *count += 1;
+        Self
+    }
+}
+
+impl Drop for BackgroundPriority {
+    fn drop(&mut self) {
+        let mut count = BACKGROUND_EXPORTS.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            info!("Restoring process priority");
+            set_background(false);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn set_background(background: bool) {
+    let priority = if background { libc::PRIO_DARWIN_BG } else { 0 };
+    if unsafe { libc::setpriority(libc::PRIO_DARWIN_PROCESS, 0, priority) } != 0 {
+        warn!(
+            "Failed to change process priority: {}",
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+#[cfg(windows)]
+fn set_background(background: bool) {
+    use ::windows::Win32::System::Threading::{
+        GetCurrentProcess, PROCESS_MODE_BACKGROUND_BEGIN, PROCESS_MODE_BACKGROUND_END,
+        SetPriorityClass,
+    };
+
+    let mode = if background {
+        PROCESS_MODE_BACKGROUND_BEGIN
+    } else {
+        PROCESS_MODE_BACKGROUND_END
+    };
+    if let Err(e) = unsafe { SetPriorityClass(GetCurrentProcess(), mode) } {
+        warn!("Failed to change process priority: {e}");
+    }
+}
+
+#[cfg(not(any(target_os = "macos", windows)))]
+fn set_background(_background: bool) {}
@@ -0,0 +1,328 @@
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+use tracing::instrument;
+
+use crate::{
+    editor_window::WindowEditorInstance,
+    frame_ws::{WSFrame, WSFrameFormat},
+    general_settings::GeneralSettingsStore,
+};
+
+const FRAME_HISTORY_LEN: usize = 30;
+
+#[derive(Serialize, Type, thiserror::Error, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum FrameHistoryError {
+    #[error("Frame {frame_number} isn't buffered. Buffered frames: {buffered:?}")]
+    #[serde(rename_all = "camelCase")]
+    NotBuffered {
+        frame_number: u32,
+        buffered: Vec<u32>,
+    },
+    #[error("The frame buffer changed from generation {expected} to {current}")]
+    Stale { expected: u64, current: u64 },
+    #[error("The frame buffer can't be cleared while the preview is playing")]
+    Playing,
+    #[error("Frame inspection is turned off")]
+    Disabled,
+    #[error("{message}")]
+    Failed { message: String },
+}
+
+impl FrameHistoryError {
+    fn failed(message: impl ToString) -> Self {
+        Self::Failed {
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Frames {
+    generation: u64,
+    frames: VecDeque<Arc<WSFrame>>,
+}
+
+#[derive(Clone, Default)]
+pub struct FrameHistory {
+    enabled: bool,
+    frames: Arc<Mutex<Frames>>,
+}
+
+pub struct FrameSnapshot {
+    pub generation: u64,
+    pub frames: Vec<Arc<WSFrame>>,
+}
+
+impl FrameHistory {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            frames: Default::default(),
+        }
+    }
+
+    pub fn from_settings(app: &AppHandle) -> Self {
+        Self::new(
+            GeneralSettingsStore::get(app)
+                .ok()
+                .flatten()
+                .is_some_and(|settings| settings.frame_inspection),
+        )
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Frames>, FrameHistoryError> {
+        if !self.enabled {
+            return Err(FrameHistoryError::Disabled);
+        }
+        self.frames.lock().map_err(FrameHistoryError::failed)
+    }
+
+    pub fn push(&self, frame: Arc<WSFrame>) {
+        if !self.enabled {
+            return;
+        }
+        let Ok(mut history) = self.frames.lock() else {
+            return;
+        };
+        if history.frames.len() == FRAME_HISTORY_LEN {
+            history.frames.pop_front();
+        }
+        history.frames.push_back(frame);
+    }
+
+    pub fn snapshot(&self) -> Result<FrameSnapshot, FrameHistoryError> {
+        let history = self.lock()?;
+        Ok(FrameSnapshot {
+            generation: history.generation,
+            frames: history.frames.iter().cloned().collect(),
+        })
+    }
+
+    fn clear(&self) -> Result<u64, FrameHistoryError> {
+        let mut history = self.lock()?;
+        history.frames.clear();
+        history.generation += 1;
+        Ok(history.generation)
+    }
+
+    fn get(
+        &self,
+        frame_number: u32,
+        generation: Option<u64>,
+    ) -> Result<(u64, Arc<WSFrame>), FrameHistoryError> {
+        let history = self.lock()?;
+        if let Some(expected) = generation
+            && expected != history.generation
+        {
+            return Err(FrameHistoryError::Stale {
+                expected,
+                current: history.generation,
+            });
+        }
+
+        history
+            .frames
+            .iter()
+            .rev()
+            .find(|frame| frame.frame_number == frame_number)
+            .map(|frame| (history.generation, frame.clone()))
+            .ok_or_else(|| FrameHistoryError::NotBuffered {
+                frame_number,
+                buffered: history
+                    .frames
+                    .iter()
+                    .map(|frame| frame.frame_number)
+                    .collect(),
+            })
+    }
+}
+
+#[derive(Serialize, Type, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum FramePixelFormat {
+    Rgba,
+    Nv12,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameInfo {
+    pub generation: u64,
+    pub frame_number: u32,
+    pub width: u32,
+    pub height: u32,
+    pub stride: u32,
+    pub format: FramePixelFormat,
+    pub timestamp_secs: f64,
+    pub data_len: u32,
+    pub average_color: [u8; 3],
+    pub black_fraction: f64,
+}
+
+fn to_rgba(frame: &WSFrame) -> Result<Vec<u8>, String> {
+    let width = frame.width as usize;
+    let height = frame.height as usize;
+    let stride = frame.stride as usize;
+
+    match frame.format {
+        WSFrameFormat::Rgba => {
+            if stride < width * 4 || frame.data.len() < stride * height {
+                return Err("Frame data is smaller than its dimensions".to_string());
+            }
+            Ok(frame
+                .data
+                .chunks(stride)
+                .take(height)
+                .flat_map(|row| &row[..width * 4])
+                .copied()
+                .collect())
+        }
+        WSFrameFormat::Nv12 => {
+            let y_len = stride * height;
+            if stride < width || frame.data.len() < y_len + stride * height.div_ceil(2) {
+                return Err("Frame data is smaller than its dimensions".to_string());
+            }
+            let (y_data, uv_data) = frame.data.split_at(y_len);
+            let mut rgba = vec![0; width * height * 4];
+            cap_rendering::cpu_yuv::nv12_to_rgba(
+                y_data,
+                uv_data,
+                frame.width,
+                frame.height,
+                frame.stride,
+                frame.stride,
+                &mut rgba,
+            );
+            Ok(rgba)
+        }
+    }
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameHistoryDump {
+    pub generation: u64,
+    pub paths: Vec<PathBuf>,
+}
+
+fn frame_info(generation: u64, frame: &WSFrame) -> Result<FrameInfo, String> {
+    let rgba = to_rgba(frame)?;
+    let pixels = (rgba.len() / 4).max(1);
+
+    let mut sums = [0u64; 3];
+    let mut black = 0usize;
+    for pixel in rgba.chunks_exact(4) {
+        for (sum, value) in sums.iter_mut().zip(pixel) {
+            *sum += u64::from(*value);
+        }
+        if pixel[..3] == [0, 0, 0] {
+            black += 1;
+        }
+    }
+
+    Ok(FrameInfo {
+        generation,
+        frame_number: frame.frame_number,
+        width: frame.width,
+        height: frame.height,
+        stride: frame.stride,
+        format: match frame.format {
+            WSFrameFormat::Rgba => FramePixelFormat::Rgba,
+            WSFrameFormat::Nv12 => FramePixelFormat::Nv12,
+        },
+        timestamp_secs: frame.target_time_ns as f64 / 1_000_000_000.0,
+        data_len: frame.data.len() as u32,
+        average_color: sums.map(|sum| (sum / pixels as u64) as u8),
+        black_fraction: black as f64 / pixels as f64,
+    })
+}
+
+fn write_png(frame: &WSFrame, path: &Path) -> Result<(), String> {
+    let rgba = to_rgba(frame)?;
+    image::RgbaImage::from_raw(frame.width, frame.height, rgba)
+        .ok_or_else(|| "Frame data doesn't match its dimensions".to_string())?
+        .save_with_format(path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+pub async fn get_frame_info(
+    editor_instance: WindowEditorInstance,
+    index: u32,
+    generation: Option<u64>,
+) -> Result<FrameInfo, FrameHistoryError> {
+    let (generation, frame) = editor_instance.frame_history.get(index, generation)?;
+    tokio::task::spawn_blocking(move || frame_info(generation, &frame))
+        .await
+        .map_err(FrameHistoryError::failed)?
+        .map_err(FrameHistoryError::failed)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+pub async fn dump_frame_png(
+    editor_instance: WindowEditorInstance,
+    index: u32,
+    generation: Option<u64>,
+    path: PathBuf,
+) -> Result<(), FrameHistoryError> {
+    let (_, frame) = editor_instance.frame_history.get(index, generation)?;
+    tokio::task::spawn_blocking(move || write_png(&frame, &path))
+        .await
+        .map_err(FrameHistoryError::failed)?
+        .map_err(FrameHistoryError::failed)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+pub async fn dump_frame_history(
+    editor_instance: WindowEditorInstance,
+    dir: PathBuf,
+) -> Result<FrameHistoryDump, FrameHistoryError> {
+    let snapshot = editor_instance.frame_history.snapshot()?;
+    tokio::task::spawn_blocking(move || -> Result<_, FrameHistoryError> {
+        std::fs::create_dir_all(&dir).map_err(FrameHistoryError::failed)?;
+        let paths = snapshot
+            .frames
+            .iter()
+            .map(|frame| {
+                let path = dir.join(format!("frame-{}.png", frame.frame_number));
+                write_png(frame, &path).map(|_| path)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(FrameHistoryError::failed)?;
+
+        Ok(FrameHistoryDump {
+            generation: snapshot.generation,
+            paths,
+        })
+    })
+    .await
+    .map_err(FrameHistoryError::failed)?
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+pub async fn clear_frame_history(
+    editor_instance: WindowEditorInstance,
+) -> Result<u64, FrameHistoryError> {
+    let state = editor_instance.state.lock().await;
+    if state.playback_task.is_some() {
+        return Err(FrameHistoryError::Playing);
+    }
+
+    editor_instance.frame_history.clear()
+}
@@ -1,9 +1,13 @@
+use crate::export_actions::ExportCompletionActions;
+use crate::webhooks::WebhookUrls;
 use crate::window_exclusion::WindowExclusion;
+use cap_project::KeystrokeCapture;
+use cap_recording::sources::screen_capture::ScreenCaptureTarget;
 use scap_targets::DisplayId;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use specta::Type;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, path::PathBuf};
 use tauri::{AppHandle, Wry};
 use tauri_plugin_store::StoreExt;
 use tracing::{error, instrument};
@@ -23,6 +27,7 @@ pub enum MainWindowRecordingStartBehaviour {
     #[default]
     Close,
     Minimise,
+    MinimiseIfCaptured,
 }
 
 #[derive(Default, Serialize, Deserialize, Type, Debug, Clone, Copy)]
@@ -33,6 +38,24 @@ pub enum PostDeletionBehaviour {
     ReopenRecordingWindow,
 }
 
+#[derive(Default, Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportDirectoryMode {
+    #[default]
+    AskEveryTime,
+    SameAsProject,
+    Custom,
+}
+
+#[derive(Default, Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportPriority {
+    #[default]
+    Normal,
+    Background,
+    BackgroundWhenIdle,
+}
+
 #[derive(Default, Serialize, Deserialize, Type, Debug, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum EditorPreviewQuality {
@@ -42,11 +65,35 @@ pub enum EditorPreviewQuality {
     Full,
 }
 
+#[derive(Default, Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
 impl MainWindowRecordingStartBehaviour {
-    pub fn perform(&self, window: &tauri::WebviewWindow) -> tauri::Result<()> {
+    pub fn perform(
+        &self,
+        window: &tauri::WebviewWindow,
+        target: &ScreenCaptureTarget,
+    ) -> tauri::Result<()> {
         match self {
             Self::Close => window.hide(),
             Self::Minimise => window.minimize(),
+            Self::MinimiseIfCaptured => {
+                let on_captured_display = target
+                    .display()
+                    .map(|display| crate::windows::is_window_on_display(window, &display))
+                    .unwrap_or(false);
+
+                if on_captured_display {
+                    window.minimize()
+                } else {
+                    Ok(())
+                }
+            }
         }
     }
 }
@@ -124,6 +171,12 @@ pub struct GeneralSettingsStore {
     #[serde(default)]
     pub auto_zoom_on_clicks: bool,
     #[serde(default)]
+    pub snap_zoom_to_windows: bool,
+    #[serde(default)]
+    pub zoom_on_narration_cues: bool,
+    #[serde(default)]
+    pub zoom_cue_keywords: Vec<String>,
+    #[serde(default)]
     pub post_deletion_behaviour: PostDeletionBehaviour,
     #[serde(default = "default_excluded_windows")]
     pub excluded_windows: Vec<WindowExclusion>,
@@ -135,16 +188,50 @@ pub struct GeneralSettingsStore {
     pub default_project_name_template: Option<String>,
     #[serde(default = "default_true")]
     pub crash_recovery_recording: bool,
+    #[serde(default)]
+    pub anonymize_input: bool,
+    #[serde(default)]
+    pub keystroke_capture: KeystrokeCapture,
+    #[serde(default = "default_true")]
+    pub prevent_sleep_while_recording: bool,
+    #[serde(default)]
+    pub suppress_notifications_while_recording: bool,
+    #[serde(default)]
+    pub media_keys_control_recording: bool,
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    #[serde(default)]
+    pub telemetry_enabled: bool,
     #[serde(default = "default_max_fps")]
     pub max_fps: u32,
     #[serde(default)]
     pub editor_preview_quality: EditorPreviewQuality,
     #[serde(default)]
+    pub native_editor_preview: bool,
+    #[serde(default)]
+    pub frame_inspection: bool,
+    #[serde(default)]
     pub main_window_position: Option<WindowPosition>,
     #[serde(default)]
     pub camera_window_position: Option<WindowPosition>,
     #[serde(default)]
     pub camera_window_positions_by_monitor_name: BTreeMap<String, WindowPosition>,
+    #[serde(default)]
+    pub export_completion_actions: ExportCompletionActions,
+    #[serde(default)]
+    pub pre_export_command: Option<String>,
+    #[serde(default)]
+    pub webhooks: WebhookUrls,
+    #[serde(default)]
+    pub cursor_theme: Option<String>,
+    #[serde(default)]
+    pub enabled_effects: Vec<String>,
+    #[serde(default)]
+    pub export_directory_mode: ExportDirectoryMode,
+    #[serde(default)]
+    pub export_directory: Option<PathBuf>,
+    #[serde(default)]
+    pub export_priority: ExportPriority,
 }
 
 fn default_enable_native_camera_preview() -> bool {
@@ -203,17 +290,37 @@ impl Default for GeneralSettingsStore {
             recording_countdown: Some(3),
             enable_native_camera_preview: default_enable_native_camera_preview(),
             auto_zoom_on_clicks: false,
+            snap_zoom_to_windows: false,
+            zoom_on_narration_cues: false,
+            zoom_cue_keywords: Vec::new(),
             post_deletion_behaviour: PostDeletionBehaviour::DoNothing,
             excluded_windows: default_excluded_windows(),
             delete_instant_recordings_after_upload: false,
             instant_mode_max_resolution: 1920,
             default_project_name_template: None,
             crash_recovery_recording: true,
+            anonymize_input: false,
+            keystroke_capture: KeystrokeCapture::Off,
+            prevent_sleep_while_recording: true,
+            suppress_notifications_while_recording: false,
+            media_keys_control_recording: false,
+            update_channel: UpdateChannel::Stable,
+            telemetry_enabled: false,
             max_fps: 60,
             editor_preview_quality: EditorPreviewQuality::Half,
+            native_editor_preview: false,
+            frame_inspection: false,
             main_window_position: None,
             camera_window_position: None,
             camera_window_positions_by_monitor_name: BTreeMap::new(),
+            export_completion_actions: ExportCompletionActions::default(),
+            pre_export_command: None,
+            webhooks: WebhookUrls::default(),
+            cursor_theme: None,
+            enabled_effects: vec![],
+            export_directory_mode: ExportDirectoryMode::AskEveryTime,
+            export_directory: None,
+            export_priority: ExportPriority::Normal,
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
     RequestOpenRecordingPicker, RequestStartRecording, recording,
     recording_settings::{RecordingSettingsStore, RecordingTargetMode},
-    tray,
+    teleprompter, tray,
     windows::ShowCapWindow,
 };
 use cap_recording::screen_capture::ScreenCaptureTarget;
@@ -64,6 +64,9 @@ pub enum HotkeyAction {
     ScreenshotDisplay,
     ScreenshotWindow,
     ScreenshotArea,
+    ToggleTeleprompter,
+    TeleprompterFaster,
+    TeleprompterSlower,
     #[serde(other)]
     Other,
 }
@@ -81,12 +84,43 @@ impl HotkeysStore {
 
         serde_json::from_value(store).map_err(|e| e.to_string())
     }
+
+    fn is_bound(&self, shortcut: &Shortcut) -> bool {
+        self.hotkeys
+            .values()
+            .any(|hotkey| &Shortcut::from(*hotkey) == shortcut)
+    }
 }
 
 #[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
 pub struct OnEscapePress;
 
 pub type HotkeysState = Mutex<HotkeysStore>;
+
+const RECORDING_MEDIA_KEYS: [(Code, HotkeyAction); 2] = [
+    (Code::MediaPlayPause, HotkeyAction::TogglePauseRecording),
+    (Code::MediaStop, HotkeyAction::StopRecording),
+];
+
+pub fn set_recording_media_keys(app: &AppHandle, active: bool) {
+    let global_shortcut = app.global_shortcut();
+    let state = app.state::<HotkeysState>();
+    let store = state.lock().unwrap();
+
+    for (code, _) in RECORDING_MEDIA_KEYS {
+        let shortcut = Shortcut::new(None, code);
+        if store.is_bound(&shortcut) {
+            continue;
+        }
+
+        if active {
+            global_shortcut.register(shortcut).ok();
+        } else {
+            global_shortcut.unregister(shortcut).ok();
+        }
+    }
+}
+
 pub fn init(app: &AppHandle) {
     app.plugin(
         tauri_plugin_global_shortcut::Builder::new()
@@ -114,6 +148,14 @@ pub fn init(app: &AppHandle) {
                         tokio::spawn(handle_hotkey(app.clone(), *action));
                     }
                 }
+
+                if shortcut.mods.is_empty() && !store.is_bound(shortcut) {
+                    for (code, action) in RECORDING_MEDIA_KEYS {
+                        if shortcut.key == code {
+                            tokio::spawn(handle_hotkey(app.clone(), action));
+                        }
+                    }
+                }
             })
             .build(),
     )
@@ -247,6 +289,18 @@ async fn handle_hotkey(app: AppHandle, action: HotkeyAction) -> Result<(), Strin
             .emit(&app);
             Ok(())
         }
+        HotkeyAction::ToggleTeleprompter => {
+            teleprompter::toggle_playing(&app);
+            Ok(())
+        }
+        HotkeyAction::TeleprompterFaster => {
+            teleprompter::adjust_speed(&app, teleprompter::SPEED_STEP);
+            Ok(())
+        }
+        HotkeyAction::TeleprompterSlower => {
+            teleprompter::adjust_speed(&app, -teleprompter::SPEED_STEP);
+            Ok(())
+        }
         HotkeyAction::Other => Ok(()),
     }
 }
@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use cap_project::{
+    CursorEvents, RecordingMeta, StudioRecordingMeta,
+    idle::{self, DEFAULT_MIN_IDLE_SECS, IdleRange},
+};
+use cap_rendering::ProjectRecordingsMeta;
+use tracing::{instrument, warn};
+
+const DIFF_SIZE: (u32, u32) = (64, 36);
+const DIFF_INTERVAL_SECS: f64 = 0.25;
+const DIFF_THRESHOLD: f64 = 1.5;
+
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+pub async fn detect_idle_sections(
+    project_path: PathBuf,
+    min_idle_secs: Option<f64>,
+) -> Result<Vec<IdleRange>, String> {
+    let min_idle_secs = min_idle_secs.unwrap_or(DEFAULT_MIN_IDLE_SECS);
+
+    tokio::task::spawn_blocking(move || detect(&project_path, min_idle_secs))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn detect(project_path: &Path, min_idle_secs: f64) -> Result<Vec<IdleRange>, String> {
+    let recording_meta = RecordingMeta::load_for_project(project_path)
+        .map_err(|e| format!("Failed to load recording meta: {e}"))?;
+    let studio_meta = recording_meta
+        .studio_meta()
+        .ok_or("Only studio recordings can be tightened")?;
+    let recordings = ProjectRecordingsMeta::new(&recording_meta.project_path, studio_meta)?;
+
+    let clips: Vec<(PathBuf, CursorEvents)> = match studio_meta {
+        StudioRecordingMeta::SingleSegment { segment } => vec![(
+            recording_meta.path(&segment.display.path),
+            segment
+                .cursor
+                .as_ref()
+                .and_then(|path| {
+                    CursorEvents::load_from_file(&recording_meta.path(path))
+                        .map_err(|e| warn!("{e}"))
+                        .ok()
+                })
+                .unwrap_or_default(),
+        )],
+        StudioRecordingMeta::MultipleSegments { inner } => inner
+            .segments
+            .iter()
+            .map(|s| {
+                (
+                    recording_meta.path(&s.display.path),
+                    s.cursor_events(&recording_meta),
+                )
+            })
+            .collect(),
+    };
+
+    let mut ranges = vec![];
+
+    for (index, ((display_path, cursor), recording)) in
+        clips.iter().zip(&recordings.segments).enumerate()
+    {
+        let mut activity = idle::cursor_activity(cursor);
+
+        match visual_activity(display_path) {
+            Ok(changes) => activity.extend(changes),
+            Err(e) => {
+                warn!(
+                    "Skipping idle detection for {}: {e}",
+                    display_path.display()
+                );
+                continue;
+            }
+        }
+
+        ranges.extend(idle::detect_idle_ranges(
+            index as u32,
+            activity,
+            recording.duration(),
+            min_idle_secs,
+        ));
+    }
+
+    Ok(ranges)
+}
+
+fn visual_activity(path: &Path) -> Result<Vec<f64>, String> {
+    let mut input =
+        ffmpeg::format::input(path).map_err(|e| format!("Failed to open video: {e}"))?;
+    let stream = input
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or("No video stream")?;
+    let stream_index = stream.index();
+    let time_base = stream.time_base();
+    let mut decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())
+        .and_then(|c| c.decoder().video())
+        .map_err(|e| format!("Failed to create decoder: {e}"))?;
+
+    let mut differ = FrameDiffer::default();
+    let mut frame = ffmpeg::frame::Video::empty();
+    let mut receive_frames = |decoder: &mut ffmpeg::decoder::Video| -> Result<(), String> {
+        while decoder.receive_frame(&mut frame).is_ok() {
+            let time = frame.pts().unwrap_or(0) as f64 * f64::from(time_base);
+            differ.push(&frame, time)?;
+        }
+        Ok(())
+    };
+
+    for (stream, packet) in input.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        decoder
+            .send_packet(&packet)
+            .map_err(|e| format!("Failed to decode: {e}"))?;
+        receive_frames(&mut decoder)?;
+    }
+
+    decoder
+        .send_eof()
+        .map_err(|e| format!("Failed to decode: {e}"))?;
+    receive_frames(&mut decoder)?;
+
+    Ok(differ.changes)
+}
+
+#[derive(Default)]
+struct FrameDiffer {
+    scaler: Option<ffmpeg::software::scaling::Context>,
+    scaled: Option<ffmpeg::frame::Video>,
+    previous: Option<Vec<u8>>,
+    last_time: Option<f64>,
+    changes: Vec<f64>,
+}
+
+impl FrameDiffer {
+    fn push(&mut self, frame: &ffmpeg::frame::Video, time: f64) -> Result<(), String> {
+        if self
+            .last_time
+            .is_some_and(|last| time - last < DIFF_INTERVAL_SECS)
+        {
+            return Ok(());
+        }
+        self.last_time = Some(time);
+
+        let (width, height) = DIFF_SIZE;
+        if self.scaler.is_none() {
+            self.scaler = Some(
+                ffmpeg::software::scaling::Context::get(
+                    frame.format(),
+                    frame.width(),
+                    frame.height(),
+                    ffmpeg::format::Pixel::GRAY8,
+                    width,
+                    height,
+                    ffmpeg::software::scaling::Flags::FAST_BILINEAR,
+                )
+                .map_err(|e| format!("Failed to create scaler: {e}"))?,
+            );
+        }
+        let scaler = self.scaler.as_mut().unwrap();
+        let scaled = self.scaled.get_or_insert_with(ffmpeg::frame::Video::empty);
+        scaler
+            .run(frame, scaled)
+            .map_err(|e| format!("Failed to scale frame: {e}"))?;
+
+        let stride = scaled.stride(0);
+        let luma = scaled
+            .data(0)
+            .chunks(stride)
+            .take(height as usize)
+            .flat_map(|row| &row[..width as usize])
+            .copied()
+            .collect::<Vec<_>>();
+
+        if let Some(previous) = &self.previous {
+            let diff = previous
+                .iter()
+                .zip(&luma)
+                .map(|(a, b)| u32::from(a.abs_diff(*b)))
+                .sum::<u32>() as f64
+                / luma.len() as f64;
+
+            if diff > DIFF_THRESHOLD {
+                self.changes.push(time);
+            }
+        }
+        self.previous = Some(luma);
+
+        Ok(())
+    }
+}
@@ -4,36 +4,60 @@ mod api;
 mod audio;
 mod audio_meter;
 mod auth;
+mod av_sync;
+mod brand_kit;
 mod camera;
 mod camera_legacy;
 mod captions;
+mod cursor_themes;
 mod deeplink_actions;
 mod editor_window;
+mod effect_plugins;
 mod export;
+mod export_actions;
+mod export_location;
+mod export_priority;
 mod fake_window;
 mod flags;
+mod frame_inspection;
 mod frame_ws;
 mod general_settings;
 mod hotkeys;
 mod http_client;
+mod idle_detection;
 mod import;
+mod license;
 mod logging;
+mod native_preview;
+mod notification_suppressor;
 mod notifications;
 mod panel_manager;
 mod permissions;
 mod platform;
 mod posthog;
 mod presets;
+mod preview_proxy;
+mod project_templates;
+mod recorder_state;
 mod recording;
+mod recording_analysis;
 mod recording_settings;
 mod recovery;
+mod screen_text;
 mod screenshot_editor;
+mod sensitive_content;
+mod sleep_inhibitor;
 mod target_select_overlay;
+mod telemetry;
+mod teleprompter;
 mod thumbnails;
 mod tray;
 mod update_project_names;
+mod updates;
 mod upload;
+mod user_message;
 mod web_api;
+mod webhooks;
 mod window_exclusion;
 mod windows;
 
@@ -54,7 +78,10 @@ use cap_recording::{
     },
     sources::screen_capture::ScreenCaptureTarget,
 };
-use cap_rendering::ProjectRecordingsMeta;
+use cap_rendering::{
+    PreviewGuides, ProjectRecordingsMeta,
+    spring_mass_damper::{self, SpringMassDamperSimulationConfig, SpringSample, SpringScenario},
+};
 use clipboard_rs::common::RustImage;
 use clipboard_rs::{Clipboard, ClipboardContext};
 use cpal::StreamError;
@@ -63,6 +90,7 @@ use ffmpeg::ffi::AV_TIME_BASE;
 use general_settings::GeneralSettingsStore;
 use kameo::{Actor, actor::ActorRef};
 use notifications::NotificationType;
+use recorder_state::{RecorderState, RecorderStateMachine};
 use recording::{InProgressRecording, RecordingEvent, RecordingInputKind};
 use scap_targets::{Display, DisplayId, WindowId, bounds::LogicalBounds};
 use screenshot_editor::{
@@ -158,7 +186,8 @@ impl AppExitState {
 
 const APP_EXIT_STEP_TIMEOUT: Duration = Duration::from_millis(750);
 const APP_EXIT_CAMERA_SHUTDOWN_TIMEOUT: Duration = Duration::from_millis(1200);
-const APP_EXIT_TOTAL_TIMEOUT: Duration = Duration::from_secs(3);
+const APP_EXIT_RECORDING_STOP_TIMEOUT: Duration = Duration::from_secs(4);
+const APP_EXIT_TOTAL_TIMEOUT: Duration = Duration::from_secs(6);
 const APP_EXIT_FORCE_TIMEOUT: Duration = Duration::from_secs(8);
 
 async fn await_exit_step<T, E, F>(name: &'static str, timeout: Duration, fut: F) -> Option<T>
@@ -295,6 +324,8 @@ pub struct App {
     logs_dir: PathBuf,
     disconnected_inputs: HashSet<RecordingInputKind>,
     was_camera_only_recording: bool,
+    sleep_inhibitor: Option<sleep_inhibitor::SleepInhibitor>,
+    notification_suppressor: Option<notification_suppressor::NotificationSuppressor>,
 }
 
 #[derive(specta::Type, Serialize, Deserialize, Clone, Debug)]
@@ -327,16 +358,64 @@ impl App {
 
     pub fn set_current_recording(&mut self, actor: InProgressRecording) {
         self.recording_state = RecordingState::Active(actor);
+        if let Err(e) = recorder_state::transition_from(
+            &self.handle,
+            &[RecorderState::Countdown],
+            RecorderState::Recording,
+        ) {
+            warn!("Recording started without a countdown: {e}");
+            recorder_state::force(&self.handle, RecorderState::Recording);
+        }
+
+        let settings = GeneralSettingsStore::get(&self.handle)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        if settings.prevent_sleep_while_recording {
+            self.sleep_inhibitor
+                .get_or_insert_with(|| sleep_inhibitor::SleepInhibitor::acquire("Recording"));
+        }
+        if settings.suppress_notifications_while_recording {
+            self.notification_suppressor
+                .get_or_insert_with(notification_suppressor::NotificationSuppressor::acquire);
+        }
+        if settings.media_keys_control_recording {
+            hotkeys::set_recording_media_keys(&self.handle, true);
+        }
+
         CurrentRecordingChanged.emit(&self.handle).ok();
     }
 
     pub fn clear_current_recording(&mut self) -> Option<InProgressRecording> {
+        self.sleep_inhibitor = None;
+        self.notification_suppressor = None;
+        hotkeys::set_recording_media_keys(&self.handle, false);
         match std::mem::replace(&mut self.recording_state, RecordingState::None) {
             RecordingState::Active(recording) => {
                 self.close_occluder_windows();
+                if let Err(e) = recorder_state::transition_from(
+                    &self.handle,
+                    &[RecorderState::Recording, RecorderState::Paused],
+                    RecorderState::Stopped,
+                ) {
+                    warn!("Recording stopped while the recorder wasn't recording: {e}");
+                    recorder_state::force(&self.handle, RecorderState::Stopped);
+                }
                 Some(recording)
             }
-            _ => {
+            RecordingState::Pending { .. } => {
+                self.close_occluder_windows();
+                if let Err(e) = recorder_state::transition_from(
+                    &self.handle,
+                    &[RecorderState::Countdown],
+                    RecorderState::Idle,
+                ) {
+                    warn!("Pending recording cleared outside its countdown: {e}");
+                    recorder_state::force(&self.handle, RecorderState::Idle);
+                }
+                None
+            }
+            RecordingState::None => {
                 self.close_occluder_windows();
                 None
             }
@@ -1059,7 +1138,42 @@ async fn cleanup_camera_after_overlay_close(app: AppHandle, captured_session_id:
     app_state.camera_in_use = false;
 }
 
+async fn stop_recording_for_exit(app: &AppHandle) {
+    let recording = app
+        .state::<ArcLock<App>>()
+        .write()
+        .await
+        .clear_current_recording();
+    let Some(recording) = recording else {
+        return;
+    };
+
+    let project_path = recording.project_path().clone();
+    info!(
+        "Stopping recording at {} before exit",
+        project_path.display()
+    );
+
+    if await_exit_step(
+        "stop_recording",
+        APP_EXIT_RECORDING_STOP_TIMEOUT,
+        recording.stop(),
+    )
+    .await
+    .is_some()
+        && let Err(e) = cap_recording::recovery::RecoveryManager::mark_needs_remux(&project_path)
+    {
+        warn!(
+            "Failed to mark {} for recovery: {e}",
+            project_path.display()
+        );
+    }
+}
+
 async fn cleanup_app_resources_for_exit(app: &AppHandle) {
+    app.state::<RecorderStateMachine>().cancel_exports();
+    stop_recording_for_exit(app).await;
+
     let (mic_feed, camera_feed, camera_shutdown) = {
         let state = app.state::<ArcLock<App>>();
         let mut app_state = state.write().await;
@@ -1102,7 +1216,38 @@ async fn cleanup_app_resources_for_exit(app: &AppHandle) {
     }
 }
 
+async fn confirm_exit(app: &AppHandle) -> bool {
+    use tauri_plugin_dialog::{MessageDialogButtons, MessageDialogKind};
+
+    let message = match app.state::<RecorderStateMachine>().state() {
+        RecorderState::Countdown | RecorderState::Recording | RecorderState::Paused => {
+            "A recording is in progress. Quitting stops it, and you can recover it the next time Drift opens."
+        }
+        RecorderState::Exporting => "An export is in progress. Quitting cancels it.",
+        RecorderState::Idle | RecorderState::Stopped => return true,
+    };
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .message(message)
+        .title("Quit Drift?")
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            "Quit".to_string(),
+            "Keep Running".to_string(),
+        ))
+        .show(move |quit| {
+            let _ = tx.send(quit);
+        });
+
+    rx.await.unwrap_or(false)
+}
+
 pub async fn request_app_exit(app: AppHandle) {
+    if app_is_exiting(&app) || !confirm_exit(&app).await {
+        return;
+    }
+
     if !app.state::<AppExitState>().begin() {
         return;
     }
@@ -1141,6 +1286,52 @@ fn find_mic_by_label_or_fuzzy(
         .cloned()
 }
 
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+pub struct DisplaysChanged {
+    displays: Vec<cap_recording::sources::screen_capture::CaptureDisplay>,
+}
+
+fn spawn_displays_watcher(app_handle: AppHandle) {
+    use cap_recording::sources::screen_capture;
+
+    tokio::spawn(async move {
+        let mut last_displays = None;
+
+        loop {
+            if app_is_exiting(&app_handle) {
+                break;
+            }
+
+            let displays = screen_capture::list_displays();
+            let snapshot = displays
+                .iter()
+                .map(|(capture, display)| {
+                    (
+                        capture.id.clone(),
+                        capture.name.clone(),
+                        capture.refresh_rate,
+                        display.physical_size(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            if last_displays.as_ref() != Some(&snapshot) {
+                if last_displays.is_some() {
+                    debug!("Displays changed: {} connected", snapshot.len());
+                    DisplaysChanged {
+                        displays: displays.into_iter().map(|(capture, _)| capture).collect(),
+                    }
+                    .emit(&app_handle)
+                    .ok();
+                }
+                last_displays = Some(snapshot);
+            }
+
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+    });
+}
+
 fn spawn_microphone_watcher(app_handle: AppHandle) {
     tokio::spawn(async move {
         let state = app_handle.state::<ArcLock<App>>();
@@ -1301,6 +1492,11 @@ pub struct NewScreenshotAdded {
     path: PathBuf,
 }
 
+#[derive(Deserialize, specta::Type, Serialize, tauri_specta::Event, Debug, Clone)]
+pub struct ExportCompleted {
+    path: PathBuf,
+}
+
 #[derive(Deserialize, specta::Type, Serialize, tauri_specta::Event, Debug, Clone)]
 pub struct RecordingStarted;
 
@@ -2056,8 +2252,62 @@ fn focus_captures_panel(_app: AppHandle) {
 #[derive(Serialize, Deserialize, specta::Type, Clone)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct FramesRendered {
+    job_id: u32,
     rendered_count: u32,
     total_frames: u32,
+    message: user_message::UserMessage,
+    timings: Option<RenderTimings>,
+    warnings: Vec<String>,
+}
+
+impl FramesRendered {
+    pub fn new(job_id: u32, rendered_count: u32, total_frames: u32) -> Self {
+        Self {
+            job_id,
+            rendered_count,
+            total_frames,
+            message: user_message::UserMessage::new(user_message::MessageKey::ExportRenderingFrame)
+                .with("current", rendered_count)
+                .with("total", total_frames),
+            timings: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn paused(mut self, key: user_message::MessageKey) -> Self {
+        self.message = user_message::UserMessage::new(key)
+            .with("current", self.rendered_count)
+            .with("total", self.total_frames);
+        self
+    }
+
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    pub fn with_timings(mut self, timings: cap_export::timings::TimingBreakdown) -> Self {
+        self.timings = Some(RenderTimings {
+            decode_us: timings.decode_us,
+            composite_us: timings.composite_us,
+            readback_us: timings.readback_us,
+            encode_us: timings.encode_us,
+            fps: timings.fps,
+            eta_secs: timings.eta_secs,
+        });
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, specta::Type, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderTimings {
+    decode_us: u32,
+    composite_us: u32,
+    readback_us: u32,
+    encode_us: u32,
+    fps: f64,
+    eta_secs: Option<f64>,
 }
 
 #[tauri::command]
@@ -2080,12 +2330,14 @@ async fn set_playhead_position(
 #[specta::specta]
 #[instrument(skip(editor_instance))]
 async fn set_project_config(
+    app: AppHandle,
     editor_instance: WindowEditorInstance,
     config: ProjectConfiguration,
 ) -> Result<(), String> {
     config.write(&editor_instance.project_path).unwrap();
 
     editor_instance.project_config.0.send(config).ok();
+    preview_proxy::spawn_build(&app, editor_instance.project_path.clone());
 
     Ok(())
 }
@@ -2112,17 +2364,148 @@ async fn update_project_config_in_memory(
 #[tauri::command]
 #[specta::specta]
 #[instrument(skip(editor_instance))]
+async fn set_preview_guides(
+    editor_instance: WindowEditorInstance,
+    guides: PreviewGuides,
+) -> Result<(), String> {
+    editor_instance.set_preview_guides(guides);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+fn simulate_spring(
+    config: SpringMassDamperSimulationConfig,
+    scenario: SpringScenario,
+) -> Vec<SpringSample> {
+    spring_mass_damper::simulate_spring(config, scenario)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, editor_instance))]
 async fn generate_zoom_segments_from_clicks(
+    app: AppHandle,
     editor_instance: WindowEditorInstance,
 ) -> Result<Vec<ZoomSegment>, String> {
     let meta = editor_instance.meta();
     let recordings = &editor_instance.recordings;
+    let settings = GeneralSettingsStore::get(&app).ok().flatten();
+    let snap_to_windows = settings.as_ref().is_some_and(|s| s.snap_zoom_to_windows);
+
+    let mut zoom_segments =
+        recording::generate_zoom_segments_for_project(meta, recordings, snap_to_windows);
+
+    if let Some(settings) = settings.filter(|s| s.zoom_on_narration_cues) {
+        let captions = editor_instance.project_config.1.borrow().captions.clone();
+        if let Some(captions) = captions {
+            recording::bias_zoom_segments_to_narration(
+                &mut zoom_segments,
+                &captions.segments,
+                &settings.zoom_cue_keywords,
+                recordings.duration(),
+            );
+        }
+    }
 
-    let zoom_segments = recording::generate_zoom_segments_for_project(meta, recordings);
+    Ok(zoom_segments)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, editor_instance))]
+async fn generate_beat_synced_zoom_segments(
+    app: AppHandle,
+    editor_instance: WindowEditorInstance,
+    music_path: PathBuf,
+) -> Result<Vec<ZoomSegment>, String> {
+    let beats = tokio::task::spawn_blocking(move || {
+        let audio = cap_audio::AudioData::from_file(&music_path)?;
+        Ok::<_, String>(cap_audio::detect_beats(
+            audio.samples(),
+            audio.channels(),
+            cap_audio::AudioData::SAMPLE_RATE,
+        ))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let mut zoom_segments = generate_zoom_segments_from_clicks(app, editor_instance).await?;
+    recording::sync_zoom_segments_to_beats(&mut zoom_segments, &beats);
 
     Ok(zoom_segments)
 }
 
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+async fn get_window_focus_changes(
+    editor_instance: WindowEditorInstance,
+) -> Result<Vec<cap_project::WindowFocusEvent>, String> {
+    Ok(recording::window_focus_changes_for_project(
+        editor_instance.meta(),
+        &editor_instance.recordings,
+    ))
+}
+
+#[derive(Serialize, specta::Type, Debug, Clone)]
+pub struct TimelineClick {
+    time: f64,
+    position: XY<f64>,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+async fn get_timeline_clicks(
+    editor_instance: WindowEditorInstance,
+) -> Result<Vec<TimelineClick>, String> {
+    let project = editor_instance.project_config.1.borrow().clone();
+
+    let mut clicks = vec![];
+    for (clip_index, (media, recording)) in editor_instance
+        .segment_medias
+        .iter()
+        .zip(&editor_instance.recordings.segments)
+        .enumerate()
+    {
+        let size = XY::new(
+            f64::from(recording.display.width),
+            f64::from(recording.display.height),
+        );
+        let (crop_position, crop_size) = match &project.background.crop {
+            Some(crop) => (
+                XY::new(f64::from(crop.position.x), f64::from(crop.position.y)),
+                XY::new(f64::from(crop.size.x), f64::from(crop.size.y)),
+            ),
+            None => (XY::new(0.0, 0.0), size),
+        };
+
+        for click in media.cursor.clicks.iter().filter(|click| click.down) {
+            let Some(cursor) = media
+                .cursor
+                .moves
+                .iter()
+                .take_while(|event| event.time_ms <= click.time_ms)
+                .last()
+            else {
+                continue;
+            };
+            let position = XY::new(
+                ((cursor.x * size.x - crop_position.x) / crop_size.x).clamp(0.0, 1.0),
+                ((cursor.y * size.y - crop_position.y) / crop_size.y).clamp(0.0, 1.0),
+            );
+
+            for time in project.timeline_times(clip_index as u32, click.time_ms / 1000.0) {
+                clicks.push(TimelineClick { time, position });
+            }
+        }
+    }
+    clicks.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+    Ok(clicks)
+}
+
 #[tauri::command]
 #[specta::specta]
 #[instrument]
@@ -2137,6 +2520,15 @@ async fn list_audio_devices() -> Result<Vec<String>, ()> {
     Ok(MicrophoneFeed::list().keys().cloned().collect())
 }
 
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+async fn list_system_fonts() -> Result<Vec<String>, String> {
+    tokio::task::spawn_blocking(cap_rendering::list_system_fonts)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[derive(Serialize, Type, Debug, Clone)]
 pub struct UploadProgress {
     progress: f64,
@@ -2692,17 +3084,34 @@ async fn seek_to(editor_instance: WindowEditorInstance, frame_number: u32) -> Re
     Ok(())
 }
 
+#[derive(Deserialize, Type, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameImageOptions {
+    #[serde(default)]
+    max_size: Option<u32>,
+    #[serde(default)]
+    jpeg_quality: Option<u8>,
+    #[serde(default)]
+    to_file: bool,
+}
+
+#[derive(Serialize, Type, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum FrameImage {
+    Bytes { data: Vec<u8> },
+    File { path: PathBuf },
+}
+
 #[tauri::command]
 #[specta::specta]
 #[instrument(skip(editor_instance))]
 async fn get_display_frame_for_cropping(
     editor_instance: WindowEditorInstance,
     fps: u32,
-) -> Result<Vec<u8>, String> {
+    options: Option<FrameImageOptions>,
+) -> Result<FrameImage, String> {
     use cap_project::ClipOffsets;
     use cap_rendering::{PixelFormat, cpu_yuv};
-    use image::{ImageEncoder, codecs::png::PngEncoder};
-    use std::io::Cursor;
 
     let frame_number = editor_instance.state.lock().await.playhead_position;
     let time_secs = frame_number as f64 / fps as f64;
@@ -2731,53 +3140,118 @@ async fn get_display_frame_for_cropping(
         .await
         .ok_or_else(|| "Failed to get frame".to_string())?;
 
-    let screen_frame = segment_frames.screen_frame;
-    let width = screen_frame.width();
-    let height = screen_frame.height();
-
-    let rgba_data = match screen_frame.format() {
-        PixelFormat::Rgba => screen_frame.data().to_vec(),
-        PixelFormat::Nv12 => {
-            let y_plane = screen_frame.y_plane().ok_or("Missing Y plane")?;
-            let uv_plane = screen_frame.uv_plane().ok_or("Missing UV plane")?;
-            let mut rgba = vec![0u8; (width * height * 4) as usize];
-            cpu_yuv::nv12_to_rgba(
-                y_plane,
-                uv_plane,
-                width,
-                height,
-                screen_frame.y_stride(),
-                screen_frame.uv_stride(),
-                &mut rgba,
-            );
-            rgba
-        }
-        PixelFormat::Yuv420p => {
-            let y_plane = screen_frame.y_plane().ok_or("Missing Y plane")?;
-            let u_plane = screen_frame.u_plane().ok_or("Missing U plane")?;
-            let v_plane = screen_frame.v_plane().ok_or("Missing V plane")?;
-            let mut rgba = vec![0u8; (width * height * 4) as usize];
-            cpu_yuv::yuv420p_to_rgba(
-                y_plane,
-                u_plane,
-                v_plane,
-                width,
-                height,
-                screen_frame.y_stride(),
-                screen_frame.uv_stride(),
-                &mut rgba,
-            );
-            rgba
+    let options = options.unwrap_or_default();
+
+    tokio::task::spawn_blocking(move || -> Result<FrameImage, String> {
+        let screen_frame = segment_frames.screen_frame;
+        let width = screen_frame.width();
+        let height = screen_frame.height();
+
+        let rgba_data = match screen_frame.format() {
+            PixelFormat::Rgba => screen_frame.data().to_vec(),
+            PixelFormat::Nv12 => {
+                let y_plane = screen_frame.y_plane().ok_or("Missing Y plane")?;
+                let uv_plane = screen_frame.uv_plane().ok_or("Missing UV plane")?;
+                let mut rgba = vec![0u8; (width * height * 4) as usize];
+                cpu_yuv::nv12_to_rgba(
+                    y_plane,
+                    uv_plane,
+                    width,
+                    height,
+                    screen_frame.y_stride(),
+                    screen_frame.uv_stride(),
+                    &mut rgba,
+                );
+                rgba
+            }
+            PixelFormat::Yuv420p => {
+                let y_plane = screen_frame.y_plane().ok_or("Missing Y plane")?;
+                let u_plane = screen_frame.u_plane().ok_or("Missing U plane")?;
+                let v_plane = screen_frame.v_plane().ok_or("Missing V plane")?;
+                let mut rgba = vec![0u8; (width * height * 4) as usize];
+                cpu_yuv::yuv420p_to_rgba(
+                    y_plane,
+                    u_plane,
+                    v_plane,
+                    width,
+                    height,
+                    screen_frame.y_stride(),
+                    screen_frame.uv_stride(),
+                    &mut rgba,
+                );
+                rgba
+            }
+        };
+
+        let image = image::RgbaImage::from_raw(width, height, rgba_data)
+            .ok_or_else(|| "Frame data doesn't match its dimensions".to_string())?;
+        let data = encode_frame_image(image, &options)?;
+
+        if !options.to_file {
+            return Ok(FrameImage::Bytes { data });
         }
+
+        let extension = if options.jpeg_quality.is_some() {
+            "jpg"
+        } else {
+            "png"
+        };
+        let path =
+            std::env::temp_dir().join(format!("drift-frame-{}.{extension}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, data)
+            .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+
+        Ok(FrameImage::File { path })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+fn encode_frame_image(
+    image: image::RgbaImage,
+    options: &FrameImageOptions,
+) -> Result<Vec<u8>, String> {
+    use image::{
+        DynamicImage, ImageEncoder,
+        codecs::{jpeg::JpegEncoder, png::PngEncoder},
+        imageops::FilterType,
     };
+    use std::io::Cursor;
 
-    let mut png_data = Cursor::new(Vec::new());
-    let encoder = PngEncoder::new(&mut png_data);
-    encoder
-        .write_image(&rgba_data, width, height, image::ExtendedColorType::Rgba8)
-        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+    let mut image = DynamicImage::ImageRgba8(image);
+    if let Some(max_size) = options.max_size.filter(|&max| max > 0)
+        && (image.width() > max_size || image.height() > max_size)
+    {
+        image = image.resize(max_size, max_size, FilterType::Triangle);
+    }
 
-    Ok(png_data.into_inner())
+    let mut data = Cursor::new(Vec::new());
+    match options.jpeg_quality {
+        Some(quality) => {
+            let rgb = image.to_rgb8();
+            JpegEncoder::new_with_quality(&mut data, quality.clamp(1, 100))
+                .write_image(
+                    &rgb,
+                    rgb.width(),
+                    rgb.height(),
+                    image::ExtendedColorType::Rgb8,
+                )
+                .map_err(|e| format!("Failed to encode JPEG: {e}"))?;
+        }
+        None => {
+            let rgba = image.to_rgba8();
+            PngEncoder::new(&mut data)
+                .write_image(
+                    &rgba,
+                    rgba.width(),
+                    rgba.height(),
+                    image::ExtendedColorType::Rgba8,
+                )
+                .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+        }
+    }
+
+    Ok(data.into_inner())
 }
 
 #[tauri::command]
@@ -2797,6 +3271,30 @@ async fn get_mic_waveforms(editor_instance: WindowEditorInstance) -> Result<Vec<
     Ok(out)
 }
 
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+async fn get_voice_activity(
+    editor_instance: WindowEditorInstance,
+) -> Result<Vec<Vec<audio::VoiceActivity>>, String> {
+    let segment_medias = editor_instance.segment_medias.clone();
+
+    tokio::task::spawn_blocking(move || {
+        segment_medias
+            .iter()
+            .map(|segment| {
+                segment
+                    .audio
+                    .as_deref()
+                    .map(audio::get_voice_activity)
+                    .unwrap_or_default()
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 #[instrument(skip(editor_instance))]
@@ -3041,19 +3539,8 @@ type FilteredRegistry = tracing_subscriber::layer::Layered<
 pub type DynLoggingLayer = Box<dyn tracing_subscriber::Layer<FilteredRegistry> + Send + Sync>;
 type LoggingHandle = tracing_subscriber::reload::Handle<Option<DynLoggingLayer>, FilteredRegistry>;
 
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
-    ffmpeg::init()
-        .map_err(|e| {
-            error!("Failed to initialize ffmpeg: {e}");
-        })
-        .ok();
-
-    posthog::init();
-
-    let tauri_context = tauri::generate_context!();
-
-    let specta_builder = tauri_specta::Builder::new()
+fn specta_builder() -> tauri_specta::Builder {
+    tauri_specta::Builder::new()
         .commands(tauri_specta::collect_commands![
             set_mic_input,
             set_camera_input,
@@ -3068,6 +3555,7 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             recording::restart_recording,
             recording::delete_recording,
             recording::take_screenshot,
+            recording::capture_from_clipboard,
             recording::list_cameras,
             recording::get_camera_formats,
             recording::get_microphone_info,
@@ -3084,9 +3572,32 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             focus_captures_panel,
             get_current_recording,
             export::export_video,
+            export::export_video_parts,
+            export::export_video_presets,
+            export::export_comparison,
+            export::pause_export,
+            export::resume_export,
             export::get_export_estimates,
+            export::verify_export,
+            export::validate_export,
             export::generate_export_preview,
             export::generate_export_preview_fast,
+            export_location::resolve_output_path,
+            export_location::choose_default_directory,
+            av_sync::run_av_sync_test,
+            av_sync::get_av_sync_calibration,
+            av_sync::clear_av_sync_calibration,
+            frame_inspection::get_frame_info,
+            frame_inspection::dump_frame_png,
+            frame_inspection::dump_frame_history,
+            frame_inspection::clear_frame_history,
+            cursor_themes::list_cursor_themes,
+            cursor_themes::set_cursor_theme,
+            recording_analysis::analyze_recording,
+            screen_text::index_screen_text,
+            screen_text::search_recordings,
+            sensitive_content::scan_for_sensitive_content,
+            idle_detection::detect_idle_sections,
             import::start_video_import,
             import::check_import_ready,
             copy_file_to_path,
@@ -3104,6 +3615,10 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             set_playhead_position,
             set_project_config,
             update_project_config_in_memory,
+            set_preview_guides,
+            simulate_spring,
+            native_preview::show_native_preview,
+            native_preview::hide_native_preview,
             generate_zoom_segments_from_clicks,
             permissions::open_permission_settings,
             permissions::do_permissions_check,
@@ -3113,6 +3628,7 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             upload_screenshot,
             create_screenshot_editor_instance,
             update_screenshot_config,
+            screenshot_editor::beautify_screenshot,
             get_recording_meta,
             save_file_dialog,
             list_recordings,
@@ -3165,6 +3681,36 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             recovery::find_incomplete_recordings,
             recovery::recover_recording,
             recovery::discard_incomplete_recording,
+            updates::check_for_updates,
+            updates::has_beta_channel,
+            updates::install_update,
+            license::get_license_status,
+            license::activate_license,
+            effect_plugins::list_effect_plugins,
+            effect_plugins::enable_effect,
+            project_templates::list_project_templates,
+            project_templates::save_project_template,
+            project_templates::new_project_from_template,
+            brand_kit::get_brand_kit,
+            brand_kit::add_brand_logo,
+            brand_kit::add_brand_font,
+            brand_kit::remove_brand_asset,
+            brand_kit::set_brand_colors,
+            brand_kit::set_brand_watermark,
+            list_system_fonts,
+            recorder_state::get_recorder_state,
+            get_window_focus_changes,
+            get_timeline_clicks,
+            teleprompter::get_teleprompter,
+            teleprompter::show_teleprompter,
+            teleprompter::close_teleprompter,
+            teleprompter::set_teleprompter_script,
+            teleprompter::set_teleprompter_speed,
+            teleprompter::set_teleprompter_playing,
+            teleprompter::rewind_teleprompter,
+            get_voice_activity,
+            generate_beat_synced_zoom_segments,
+            preview_proxy::get_preview_proxy,
         ])
         .events(tauri_specta::collect_events![
             RecordingOptionsChanged,
@@ -3175,6 +3721,7 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             CurrentRecordingChanged,
             RecordingStarted,
             RecordingStopped,
+            ExportCompleted,
             RequestStartRecording,
             RequestOpenRecordingPicker,
             RequestSetTargetMode,
@@ -3182,6 +3729,7 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             RequestScreenCapturePrewarm,
             NewNotification,
             audio_meter::AudioInputLevelChange,
+            av_sync::AvSyncTestPulse,
             captions::DownloadProgress,
             recording::RecordingEvent,
             RecordingDeleted,
@@ -3191,6 +3739,13 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             import::VideoImportProgress,
             SetCaptureAreaPending,
             DevicesUpdated,
+            updates::UpdateDownloadProgress,
+            DisplaysChanged,
+            recorder_state::RecorderStateChanged,
+            platform::WindowFullscreenChanged,
+            teleprompter::TeleprompterState,
+            teleprompter::TeleprompterRewind,
+            preview_proxy::PreviewProxyReady,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
         .typ::<ProjectConfiguration>()
@@ -3200,15 +3755,40 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
         .typ::<general_settings::GeneralSettingsStore>()
         .typ::<recording_settings::RecordingSettingsStore>()
         .typ::<cap_flags::Flags>()
-        .typ::<crate::window_exclusion::WindowExclusion>();
+        .typ::<crate::window_exclusion::WindowExclusion>()
+}
 
-    #[cfg(debug_assertions)]
-    specta_builder
+#[cfg(any(debug_assertions, test))]
+const TYPESCRIPT_BINDINGS_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../src/utils/tauri.ts");
+
+#[cfg(any(debug_assertions, test))]
+fn export_typescript_bindings(builder: &tauri_specta::Builder) {
+    builder
         .export(
             specta_typescript::Typescript::default(),
-            "../src/utils/tauri.ts",
+            TYPESCRIPT_BINDINGS_PATH,
         )
         .expect("Failed to export typescript bindings");
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
+    ffmpeg::init()
+        .map_err(|e| {
+            error!("Failed to initialize ffmpeg: {e}");
+        })
+        .ok();
+    cap_enc_ffmpeg::log::install_log_forwarder();
+
+    posthog::init();
+
+    let tauri_context = tauri::generate_context!();
+
+    let specta_builder = specta_builder();
+
+    #[cfg(debug_assertions)]
+    export_typescript_bindings(&specta_builder);
 
     let (camera_tx, camera_ws_port, _shutdown) = camera_legacy::create_camera_preview_ws().await;
     let camera_ws_sender = camera_tx.clone();
@@ -3336,6 +3916,13 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             app.manage(http_client::RetryableHttpClient::default());
             app.manage(PendingScreenshots::default());
             app.manage(FinalizingRecordings::default());
+            app.manage(RecorderStateMachine::default());
+            app.manage(teleprompter::Teleprompter::default());
+            app.manage(preview_proxy::PreviewProxyBuilds::default());
+            app.manage(telemetry::Telemetry::new(
+                &app,
+                telemetry::Telemetry::default_sink(&app),
+            ));
 
             gpu_context::prewarm_gpu();
 
@@ -3429,10 +4016,13 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
                     logs_dir: logs_dir.clone(),
                     disconnected_inputs: HashSet::new(),
                     was_camera_only_recording: false,
+                    sleep_inhibitor: None,
+                    notification_suppressor: None,
                 })));
 
                 app.manage(camera_session_id_handle);
                 app.manage(CameraWindowCloseGate::default());
+                app.manage(updates::PendingUpdate::default());
                 app.manage(CameraWindowPositionGuard::default());
                 app.manage(CameraWindowOperationLock::default());
                 app.manage(AppExitState::default());
@@ -3445,6 +4035,8 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
             spawn_mic_error_handler(app.clone(), mic_error_rx);
             spawn_device_watchers(app.clone());
             spawn_devices_snapshot_emitter(app.clone());
+            spawn_displays_watcher(app.clone());
+            telemetry::spawn_flusher(app.clone());
 
             tokio::spawn(check_notification_permissions(app.clone()));
 
@@ -3573,6 +4165,41 @@ pub async fn run(recording_logging_handle: LoggingHandle, logs_dir: PathBuf) {
                                     cleanup_camera_window(app, session_id).await;
                                 });
                             }
+                            CapWindowId::Editor { id }
+                                if EditorWindowIds::get(app).project_path(id).is_some_and(
+                                    |path| {
+                                        app.state::<RecorderStateMachine>()
+                                            .is_exporting_project(&path)
+                                    },
+                                ) =>
+                            {
+                                api.prevent_close();
+                                let project_path = EditorWindowIds::get(app).project_path(id);
+                                let app = app.clone();
+                                let window = window.clone();
+                                app.clone()
+                                    .dialog()
+                                    .message(
+                                        "An export is in progress. Closing the editor cancels it.",
+                                    )
+                                    .title("Cancel export?")
+                                    .kind(tauri_plugin_dialog::MessageDialogKind::Warning)
+                                    .buttons(
+                                        tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
+                                            "Cancel Export".to_string(),
+                                            "Keep Exporting".to_string(),
+                                        ),
+                                    )
+                                    .show(move |cancel| {
+                                        if cancel {
+                                            if let Some(path) = &project_path {
+                                                app.state::<RecorderStateMachine>()
+                                                    .cancel_project_exports(path);
+                                            }
+                                            let _ = window.destroy();
+                                        }
+                                    });
+                            }
                             CapWindowId::Main => {
                                 api.prevent_close();
                                 let _ = window.hide();
@@ -4079,6 +4706,9 @@ async fn create_editor_instance_impl(
                 is_software_adapter: shared.is_software_adapter,
             });
 
+    let effects = effect_plugins::load_enabled(&app);
+    let brand = brand_kit::load_render_assets(&app);
+
     let instance = {
         let app = app.clone();
         EditorInstance::new(
@@ -4088,6 +4718,8 @@ async fn create_editor_instance_impl(
             },
             frame_cb,
             shared_device,
+            effects,
+            brand,
         )
         .await?
     };
@@ -4293,3 +4925,26 @@ fn open_project_from_path(path: &Path, app: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn bindings_are_up_to_date() {
+        let expected = super::specta_builder()
+            .export_str(specta_typescript::Typescript::default())
+            .expect("Failed to generate typescript bindings");
+        let committed = std::fs::read_to_string(super::TYPESCRIPT_BINDINGS_PATH)
+            .expect("Failed to read typescript bindings");
+
+        assert!(
+            committed == expected,
+            "src/utils/tauri.ts is out of date, regenerate it with `pnpm bindings`"
+        );
+    }
+
+    #[test]
+    #[ignore = "rewrites src/utils/tauri.ts"]
+    fn export_bindings() {
+        super::export_typescript_bindings(&super::specta_builder());
+    }
+}
@@ -0,0 +1,225 @@
+use minisign_verify::{PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+use crate::{auth::AuthStore, general_settings::GeneralSettingsStore, web_api::ManagerExt};
+
+const LICENSE_PUBLIC_KEY: Option<&str> = option_env!("DRIFT_LICENSE_PUBLIC_KEY");
+const TRIAL_DAYS: i64 = 14;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaidFeature {
+    HighResolutionExport,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct LicensePayload {
+    instance_id: Uuid,
+    expires_at: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SignedLicense {
+    payload: String,
+    signature: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct LicenseStore {
+    license: Option<SignedLicense>,
+    trial_started_at: Option<i64>,
+}
+
+impl LicenseStore {
+    fn get<R: Runtime>(app: &AppHandle<R>) -> Self {
+        let Ok(Some(store)) = app.store("store").map(|s| s.get("license")) else {
+            return Self::default();
+        };
+
+        serde_json::from_value(store).unwrap_or_default()
+    }
+
+    fn set<R: Runtime>(&self, app: &AppHandle<R>) -> Result<(), String> {
+        let store = app.store("store").map_err(|e| e.to_string())?;
+        store.set(
+            "license",
+            serde_json::to_value(self).map_err(|e| e.to_string())?,
+        );
+        store.save().map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Serialize, Type, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum LicenseStatus {
+    #[serde(rename_all = "camelCase")]
+    Licensed {
+        expires_at: Option<f64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Trial {
+        days_remaining: u32,
+    },
+    Unlicensed,
+}
+
+impl LicenseStatus {
+    fn allows_paid_features(&self) -> bool {
+        !matches!(self, Self::Unlicensed)
+    }
+}
+
+fn instance_id(app: &AppHandle) -> Result<Uuid, String> {
+    GeneralSettingsStore::get(app)?
+        .map(|settings| settings.instance_id)
+        .ok_or_else(|| "Settings haven't been created yet".to_string())
+}
+
+fn verify(license: &SignedLicense, instance_id: Uuid, now: i64) -> Result<LicensePayload, String> {
+    let public_key = LICENSE_PUBLIC_KEY.ok_or("This build can't verify licenses")?;
+    let public_key = PublicKey::from_base64(public_key).map_err(|e| e.to_string())?;
+    let signature = Signature::decode(&license.signature).map_err(|e| e.to_string())?;
+    public_key
+        .verify(license.payload.as_bytes(), &signature, false)
+        .map_err(|_| "The license's signature is invalid".to_string())?;
+
+    let payload = serde_json::from_str::<LicensePayload>(&license.payload)
+        .map_err(|e| format!("The license is malformed: {e}"))?;
+
+    if payload.instance_id != instance_id {
+        return Err("The license was activated on another device".to_string());
+    }
+
+    if payload
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        return Err("The license has expired".to_string());
+    }
+
+    Ok(payload)
+}
+
+pub fn status(app: &AppHandle) -> LicenseStatus {
+    let now = chrono::Utc::now().timestamp();
+    let store = LicenseStore::get(app);
+
+    if let (Some(license), Ok(instance_id)) = (&store.license, instance_id(app)) {
+        match verify(license, instance_id, now) {
+            Ok(payload) => {
+                return LicenseStatus::Licensed {
+                    expires_at: payload.expires_at.map(|at| at as f64 * 1000.0),
+                };
+            }
+            Err(e) => warn!("Stored license isn't valid: {e}"),
+        }
+    }
+
+    let has_commercial_license = GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()
+        .is_some_and(|settings| settings.commercial_license.is_some());
+    let has_pro_plan = AuthStore::get(app)
+        .ok()
+        .flatten()
+        .and_then(|auth| auth.plan)
+        .is_some_and(|plan| plan.upgraded || plan.manual);
+    if has_commercial_license || has_pro_plan {
+        return LicenseStatus::Licensed { expires_at: None };
+    }
+
+    let trial_started_at = store.trial_started_at.unwrap_or(now);
+
+    let elapsed_days = if now < trial_started_at {
+        TRIAL_DAYS
+    } else {
+        (now - trial_started_at) / SECONDS_PER_DAY
+    };
+
+    match TRIAL_DAYS - elapsed_days {
+        days_remaining if days_remaining > 0 => LicenseStatus::Trial {
+            days_remaining: days_remaining as u32,
+        },
+        _ => LicenseStatus::Unlicensed,
+    }
+}
+
+fn start_trial(app: &AppHandle) {
+    let mut store = LicenseStore::get(app);
+    if store.trial_started_at.is_some() {
+        return;
+    }
+
+    store.trial_started_at = Some(chrono::Utc::now().timestamp());
+    if let Err(e) = store.set(app) {
+        warn!("Failed to save trial start: {e}");
+    }
+}
+
+pub fn require(app: &AppHandle, feature: PaidFeature) -> Result<(), String> {
+    start_trial(app);
+    if status(app).allows_paid_features() {
+        return Ok(());
+    }
+
+    warn!(?feature, "Paid feature used without a license");
+    Err(match feature {
+        PaidFeature::HighResolutionExport => "Exporting above 1440p requires a license",
+    }
+    .to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub fn get_license_status(app: AppHandle) -> LicenseStatus {
+    status(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app, license_key))]
+pub async fn activate_license(
+    app: AppHandle,
+    license_key: String,
+) -> Result<LicenseStatus, String> {
+    let instance_id = instance_id(&app)?;
+
+    let response = app
+        .api_request("/api/desktop/license/activate", |client, url| {
+            client.post(url).json(&serde_json::json!({
+                "licenseKey": license_key.trim(),
+                "instanceId": instance_id,
+            }))
+        })
+        .await
+        .map_err(|e| format!("Failed to activate license: {e}"))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to activate license ({status}): {body}"));
+    }
+
+    let license = response
+        .json::<SignedLicense>()
+        .await
+        .map_err(|e| format!("Failed to read license: {e}"))?;
+
+    verify(&license, instance_id, chrono::Utc::now().timestamp())?;
+
+    let mut store = LicenseStore::get(&app);
+    store.license = Some(license);
+    store.set(&app)?;
+
+    info!("License activated");
+
+    Ok(status(&app))
+}
@@ -0,0 +1,133 @@
+use cap_rendering::PreviewSurface;
+use serde::Deserialize;
+use specta::Type;
+use tauri::{LogicalPosition, LogicalSize, Manager, WebviewUrl, WebviewWindow, Window};
+use tokio::sync::oneshot;
+use tracing::instrument;
+
+use crate::editor_window::WindowEditorInstance;
+
+#[derive(Deserialize, Type, Debug, Clone, Copy)]
+pub struct NativePreviewBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+fn preview_label(editor: &Window) -> String {
+    format!("native-preview-{}", editor.label())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(window, editor_instance))]
+pub async fn show_native_preview(
+    window: Window,
+    editor_instance: WindowEditorInstance,
+    bounds: NativePreviewBounds,
+) -> Result<(), String> {
+    let scale_factor = window.scale_factor().map_err(|e| e.to_string())?;
+    let origin = window
+        .inner_position()
+        .map_err(|e| e.to_string())?
+        .to_logical::<f64>(scale_factor);
+    let position = LogicalPosition::new(origin.x + bounds.x, origin.y + bounds.y);
+    let size = LogicalSize::new(bounds.width.max(1.0), bounds.height.max(1.0));
+
+    let app = window.app_handle();
+
+    if let Some(preview) = app.get_webview_window(&preview_label(&window)) {
+        preview.set_position(position).map_err(|e| e.to_string())?;
+        preview.set_size(size).map_err(|e| e.to_string())?;
+
+        let physical = preview.inner_size().map_err(|e| e.to_string())?;
+        editor_instance
+            .resize_preview_surface((physical.width, physical.height))
+            .await;
+        return Ok(());
+    }
+
+    let editor_window = app
+        .get_webview_window(window.label())
+        .ok_or("editor window unavailable")?;
+
+    let preview = WebviewWindow::builder(
+        app,
+        preview_label(&window),
+        WebviewUrl::App("/native-preview".into()),
+    )
+    .parent(&editor_window)
+    .map_err(|e| e.to_string())?
+    .decorations(false)
+    .resizable(false)
+    .skip_taskbar(true)
+    .shadow(false)
+    .focused(false)
+    .transparent(true)
+    .inner_size(size.width, size.height)
+    .position(position.x, position.y)
+    .build()
+    .map_err(|e| e.to_string())?;
+
+    preview.set_ignore_cursor_events(true).ok();
+
+    match create_surface(&preview, &editor_instance).await {
+        Ok(surface) => {
+            editor_instance.set_preview_surface(Some(surface)).await;
+            Ok(())
+        }
+        Err(e) => {
+            preview.destroy().ok();
+            Err(e)
+        }
+    }
+}
+
+async fn create_surface(
+    preview: &WebviewWindow,
+    editor_instance: &WindowEditorInstance,
+) -> Result<PreviewSurface, String> {
+    let (tx, rx) = oneshot::channel();
+    preview
+        .run_on_main_thread({
+            let preview = preview.clone();
+            let instance = editor_instance.render_constants._instance.clone();
+            move || {
+                tx.send(instance.create_surface(preview)).ok();
+            }
+        })
+        .map_err(|e| e.to_string())?;
+
+    let surface = rx
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to create preview surface: {e}"))?;
+
+    let physical = preview.inner_size().map_err(|e| e.to_string())?;
+    PreviewSurface::new(
+        &editor_instance.render_constants,
+        surface,
+        (physical.width, physical.height),
+    )
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(window, editor_instance))]
+pub async fn hide_native_preview(
+    window: Window,
+    editor_instance: WindowEditorInstance,
+) -> Result<(), String> {
+    editor_instance.set_preview_surface(None).await;
+
+    if let Some(preview) = window
+        .app_handle()
+        .get_webview_window(&preview_label(&window))
+    {
+        preview.destroy().map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
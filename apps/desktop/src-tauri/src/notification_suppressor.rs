@@ -0,0 +1,148 @@
+use tracing::{debug, warn};
+
+#[cfg(target_os = "macos")]
+const FOCUS_ON_SHORTCUT: &str = "Drift Focus On";
+#[cfg(target_os = "macos")]
+const FOCUS_OFF_SHORTCUT: &str = "Drift Focus Off";
+
+pub struct NotificationSuppressor {
+    restore: Option<Restore>,
+}
+
+enum Restore {
+    #[cfg(windows)]
+    ToastsEnabled(Option<u32>),
+    #[cfg(target_os = "macos")]
+    FocusOff,
+    #[cfg(all(unix, not(target_os = "macos")))]
+    ShowBanners(String),
+}
+
+impl NotificationSuppressor {
+    pub fn acquire() -> Self {
+        let restore = Self::acquire_platform();
+        if restore.is_some() {
+            debug!("Suppressed notifications for recording");
+        }
+        Self { restore }
+    }
+
+    #[cfg(windows)]
+    fn acquire_platform() -> Option<Restore> {
+        use winreg::{RegKey, enums::HKEY_CURRENT_USER};
+
+        let (key, _) = RegKey::predef(HKEY_CURRENT_USER)
+            .create_subkey(WINDOWS_NOTIFICATION_SETTINGS)
+            .map_err(|e| warn!("Failed to open notification settings: {e}"))
+            .ok()?;
+
+        let previous = key.get_value::<u32, _>(WINDOWS_TOASTS_ENABLED).ok();
+        if previous == Some(0) {
+            return None;
+        }
+
+        key.set_value(WINDOWS_TOASTS_ENABLED, &0u32)
+            .map_err(|e| warn!("Failed to disable toast notifications: {e}"))
+            .ok()?;
+
+        Some(Restore::ToastsEnabled(previous))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn acquire_platform() -> Option<Restore> {
+        run_shortcut(FOCUS_ON_SHORTCUT).then_some(Restore::FocusOff)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn acquire_platform() -> Option<Restore> {
+        let previous = gsettings(&["get", GNOME_NOTIFICATIONS_SCHEMA, "show-banners"])?;
+        if previous == "false" {
+            return None;
+        }
+
+        gsettings(&["set", GNOME_NOTIFICATIONS_SCHEMA, "show-banners", "false"])?;
+
+        Some(Restore::ShowBanners(previous))
+    }
+}
+
+impl Drop for NotificationSuppressor {
+    fn drop(&mut self) {
+        let Some(restore) = self.restore.take() else {
+            return;
+        };
+
+        match restore {
+            #[cfg(windows)]
+            Restore::ToastsEnabled(previous) => {
+                use winreg::{RegKey, enums::HKEY_CURRENT_USER};
+
+                let result = RegKey::predef(HKEY_CURRENT_USER)
+                    .open_subkey_with_flags(
+                        WINDOWS_NOTIFICATION_SETTINGS,
+                        winreg::enums::KEY_SET_VALUE,
+                    )
+                    .and_then(|key| match previous {
+                        Some(value) => key.set_value(WINDOWS_TOASTS_ENABLED, &value),
+                        None => key.delete_value(WINDOWS_TOASTS_ENABLED),
+                    });
+
+                if let Err(e) = result {
+                    warn!("Failed to restore toast notifications: {e}");
+                }
+            }
+            #[cfg(target_os = "macos")]
+            Restore::FocusOff => {
+                run_shortcut(FOCUS_OFF_SHORTCUT);
+            }
+            #[cfg(all(unix, not(target_os = "macos")))]
+            Restore::ShowBanners(previous) => {
+                gsettings(&["set", GNOME_NOTIFICATIONS_SCHEMA, "show-banners", &previous]);
+            }
+        }
+
+        debug!("Restored notifications after recording");
+    }
+}
+
+#[cfg(windows)]
+const WINDOWS_NOTIFICATION_SETTINGS: &str =
+    "Software\\Microsoft\\Windows\\CurrentVersion\\Notifications\\Settings";
+#[cfg(windows)]
+const WINDOWS_TOASTS_ENABLED: &str = "NOC_GLOBAL_SETTING_TOASTS_ENABLED";
+
+#[cfg(target_os = "macos")]
+fn run_shortcut(name: &str) -> bool {
+    std::process::Command::new("/usr/bin/shortcuts")
+        .args(["run", name])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| warn!("Failed to run shortcut \"{name}\": {e}"))
+        .is_ok()
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+const GNOME_NOTIFICATIONS_SCHEMA: &str = "org.gnome.desktop.notifications";
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn gsettings(args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("gsettings")
+        .args(args)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .map_err(|e| warn!("Failed to run gsettings: {e}"))
+        .ok()?;
+
+    if !output.status.success() {
+        warn!(
+            "gsettings {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
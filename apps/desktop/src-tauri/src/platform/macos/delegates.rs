@@ -13,7 +13,10 @@
 ///
 use objc::{msg_send, sel, sel_impl};
 use rand::{Rng, distributions::Alphanumeric};
-use tauri::{Emitter, LogicalPosition, Runtime, Window};
+use tauri::{LogicalPosition, Runtime, Window};
+use tauri_specta::Event;
+
+use crate::platform::{FullscreenTransition, WindowFullscreenChanged};
 
 pub struct UnsafeWindowHandle(pub *mut std::ffi::c_void);
 unsafe impl Send for UnsafeWindowHandle {}
@@ -208,7 +211,11 @@ pub fn setup<R: Runtime>(window: Window<R>, controls_inset: LogicalPosition<f64>
         ) {
             unsafe {
                 with_window_state(this, |state: &mut WindowState<R>| {
-                    if let Err(err) = state.window.emit("did-enter-fullscreen", ()) {
+                    if let Err(err) = (WindowFullscreenChanged {
+                        transition: FullscreenTransition::DidEnter,
+                    })
+                    .emit(&state.window)
+                    {
                         tracing::warn!("Failed to emit did-enter-fullscreen: {err}");
                     }
                 });
@@ -224,7 +231,11 @@ pub fn setup<R: Runtime>(window: Window<R>, controls_inset: LogicalPosition<f64>
         ) {
             unsafe {
                 with_window_state(this, |state: &mut WindowState<R>| {
-                    if let Err(err) = state.window.emit("will-enter-fullscreen", ()) {
+                    if let Err(err) = (WindowFullscreenChanged {
+                        transition: FullscreenTransition::WillEnter,
+                    })
+                    .emit(&state.window)
+                    {
                         tracing::warn!("Failed to emit will-enter-fullscreen: {err}");
                     }
                 });
@@ -240,7 +251,11 @@ pub fn setup<R: Runtime>(window: Window<R>, controls_inset: LogicalPosition<f64>
         ) {
             unsafe {
                 with_window_state(this, |state: &mut WindowState<R>| {
-                    if let Err(err) = state.window.emit("did-exit-fullscreen", ()) {
+                    if let Err(err) = (WindowFullscreenChanged {
+                        transition: FullscreenTransition::DidExit,
+                    })
+                    .emit(&state.window)
+                    {
                         tracing::warn!("Failed to emit did-exit-fullscreen: {err}");
                     }
 
@@ -265,7 +280,11 @@ pub fn setup<R: Runtime>(window: Window<R>, controls_inset: LogicalPosition<f64>
         ) {
             unsafe {
                 with_window_state(this, |state: &mut WindowState<R>| {
-                    if let Err(err) = state.window.emit("will-exit-fullscreen", ()) {
+                    if let Err(err) = (WindowFullscreenChanged {
+                        transition: FullscreenTransition::WillExit,
+                    })
+                    .emit(&state.window)
+                    {
                         tracing::warn!("Failed to emit will-exit-fullscreen: {err}");
                     }
                 });
@@ -30,6 +30,20 @@ pub enum HapticPerformanceTime {
     DrawCompleted = 2,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum FullscreenTransition {
+    WillEnter,
+    DidEnter,
+    WillExit,
+    DidExit,
+}
+
+#[derive(Debug, Clone, Serialize, Type, tauri_specta::Event)]
+pub struct WindowFullscreenChanged {
+    pub transition: FullscreenTransition,
+}
+
 #[tauri::command]
 #[specta::specta]
 #[instrument]
@@ -0,0 +1,125 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use cap_export::{
+    ExporterBase,
+    preview_proxy::{PreviewProxyManifest, PreviewProxySettings, config_hash},
+};
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use tracing::{debug, instrument, warn};
+
+use crate::{brand_kit, cursor_themes, editor_window::WindowEditorInstance, effect_plugins};
+
+const PROXY_DIR: &str = "preview-proxy";
+const BUILD_DELAY: Duration = Duration::from_secs(3);
+
+pub fn proxy_dir(project_path: &Path) -> PathBuf {
+    project_path.join(PROXY_DIR)
+}
+
+#[derive(Default)]
+pub struct PreviewProxyBuilds(Mutex<HashMap<PathBuf, Arc<AtomicBool>>>);
+
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewProxyReady {
+    pub project_path: PathBuf,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+pub struct PreviewProxy {
+    pub dir: PathBuf,
+    pub manifest: PreviewProxyManifest,
+}
+
+pub fn spawn_build(app: &AppHandle, project_path: PathBuf) {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    if let Some(previous) = app
+        .state::<PreviewProxyBuilds>()
+        .0
+        .lock()
+        .unwrap()
+        .insert(project_path.clone(), cancelled.clone())
+    {
+        previous.store(true, Ordering::Release);
+    }
+
+    let app = app.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(BUILD_DELAY).await;
+        let result = if cancelled.load(Ordering::Acquire) {
+            Ok(())
+        } else {
+            build(&app, &project_path, cancelled.clone()).await
+        };
+
+        {
+            let builds = app.state::<PreviewProxyBuilds>();
+            let mut builds = builds.0.lock().unwrap();
+            if builds
+                .get(&project_path)
+                .is_some_and(|flag| Arc::ptr_eq(flag, &cancelled))
+            {
+                builds.remove(&project_path);
+            }
+        }
+
+        match result {
+            _ if cancelled.load(Ordering::Acquire) => {
+                debug!(
+                    "Preview proxy build superseded for {}",
+                    project_path.display()
+                );
+            }
+            Ok(()) => {
+                debug!("Preview proxy built for {}", project_path.display());
+                PreviewProxyReady { project_path }.emit(&app).ok();
+            }
+            Err(e) => warn!(
+                "Failed to build preview proxy for {}: {e}",
+                project_path.display()
+            ),
+        }
+    });
+}
+
+async fn build(
+    app: &AppHandle,
+    project_path: &Path,
+    cancelled: Arc<AtomicBool>,
+) -> Result<(), String> {
+    let base = ExporterBase::builder(project_path.to_path_buf())
+        .with_output_path(proxy_dir(project_path))
+        .with_cursor_theme(cursor_themes::load_selected(app))
+        .with_effects(effect_plugins::load_enabled(app))
+        .with_brand(brand_kit::load_render_assets(app))
+        .build()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    PreviewProxySettings::default()
+        .export(base, move |_| !cancelled.load(Ordering::Acquire))
+        .await
+        .map(|_| ())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(editor_instance))]
+pub async fn get_preview_proxy(editor_instance: WindowEditorInstance) -> Option<PreviewProxy> {
+    let dir = proxy_dir(&editor_instance.project_path);
+    let manifest = PreviewProxyManifest::load(&dir)?;
+    let current = config_hash(&editor_instance.project_config.1.borrow());
+
+    (manifest.config_hash == current).then_some(PreviewProxy { dir, manifest })
+}
@@ -0,0 +1,172 @@
+use std::path::{Path, PathBuf};
+
+use cap_project::{
+    AspectRatio, BackgroundConfiguration, Camera, CursorConfiguration, ProjectConfiguration,
+    RecordingMeta, RecordingMetaInner, ScreenMovementSpring,
+};
+use cap_rendering::ProjectRecordingsMeta;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tracing::{instrument, warn};
+
+use crate::{export::ExportSettings, general_settings::GeneralSettingsStore, recording};
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplate {
+    #[serde(skip_deserializing)]
+    pub id: String,
+    pub name: String,
+    pub aspect_ratio: Option<AspectRatio>,
+    pub background: BackgroundConfiguration,
+    pub camera: Camera,
+    pub cursor: CursorConfiguration,
+    pub screen_motion_blur: f32,
+    pub screen_movement_spring: ScreenMovementSpring,
+    #[serde(default)]
+    pub auto_zoom_on_clicks: bool,
+    #[serde(default)]
+    pub export_settings: Option<ExportSettings>,
+}
+
+impl ProjectTemplate {
+    fn apply(&self, config: &mut ProjectConfiguration) {
+        config.aspect_ratio = self.aspect_ratio.clone();
+        config.background = self.background.clone();
+        config.camera = self.camera.clone();
+        config.cursor = self.cursor.clone();
+        config.screen_motion_blur = self.screen_motion_blur;
+        config.screen_movement_spring = self.screen_movement_spring;
+    }
+}
+
+pub fn templates_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("project-templates"))
+        .map_err(|e| e.to_string())
+}
+
+fn load(path: &Path) -> Result<ProjectTemplate, String> {
+    let id = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let contents = std::fs::read(path).map_err(|e| e.to_string())?;
+    let mut template: ProjectTemplate =
+        serde_json::from_slice(&contents).map_err(|e| format!("Invalid template '{id}': {e}"))?;
+    template.id = id;
+    Ok(template)
+}
+
+fn id_for_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | ' ' => c,
+            _ => '-',
+        })
+        .collect()
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn list_project_templates(app: AppHandle) -> Result<Vec<ProjectTemplate>, String> {
+    let dir = templates_dir(&app)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let mut templates = std::fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            load(&path)
+                .map_err(|e| warn!("Skipping project template {}: {e}", path.display()))
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn save_project_template(
+    app: AppHandle,
+    project_path: PathBuf,
+    name: String,
+    export_settings: Option<ExportSettings>,
+) -> Result<ProjectTemplate, String> {
+    let id = id_for_name(&name);
+    if id.trim().is_empty() {
+        return Err("Template name can't be empty".to_string());
+    }
+
+    let config = ProjectConfiguration::load(&project_path).map_err(|e| e.to_string())?;
+    let auto_zoom_on_clicks = GeneralSettingsStore::get(&app)?
+        .map(|s| s.auto_zoom_on_clicks)
+        .unwrap_or_default();
+
+    let template = ProjectTemplate {
+        id: id.clone(),
+        name: name.trim().to_string(),
+        aspect_ratio: config.aspect_ratio,
+        background: config.background,
+        camera: config.camera,
+        cursor: config.cursor,
+        screen_motion_blur: config.screen_motion_blur,
+        screen_movement_spring: config.screen_movement_spring,
+        auto_zoom_on_clicks,
+        export_settings,
+    };
+
+    let dir = templates_dir(&app)?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let contents = serde_json::to_vec_pretty(&template).map_err(|e| e.to_string())?;
+    std::fs::write(dir.join(format!("{id}.json")), contents).map_err(|e| e.to_string())?;
+
+    Ok(template)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn new_project_from_template(
+    app: AppHandle,
+    project_path: PathBuf,
+    template_id: String,
+) -> Result<ProjectConfiguration, String> {
+    if Path::new(&template_id).file_name() != Some(template_id.as_ref()) {
+        return Err(format!("Invalid template id '{template_id}'"));
+    }
+
+    let template = load(&templates_dir(&app)?.join(format!("{template_id}.json")))?;
+    let recording_meta =
+        RecordingMeta::load_for_project(&project_path).map_err(|e| e.to_string())?;
+    let mut config = recording_meta.project_config();
+    template.apply(&mut config);
+
+    if template.auto_zoom_on_clicks
+        && let RecordingMetaInner::Studio(studio_meta) = &recording_meta.inner
+        && let Some(timeline) = &mut config.timeline
+        && timeline.zoom_segments.is_empty()
+    {
+        let recordings = ProjectRecordingsMeta::new(&recording_meta.project_path, studio_meta)?;
+        let snap_to_windows = GeneralSettingsStore::get(&app)?
+            .map(|s| s.snap_zoom_to_windows)
+            .unwrap_or_default();
+        timeline.zoom_segments = recording::generate_zoom_segments_for_project(
+            &recording_meta,
+            &recordings,
+            snap_to_windows,
+        );
+    }
+
+    config.write(&project_path).map_err(|e| e.to_string())?;
+    Ok(config)
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Condvar, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+use tracing::{debug, warn};
+
+#[derive(Serialize, Type, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum RecorderState {
+    #[default]
+    Idle,
+    Countdown,
+    Recording,
+    Paused,
+    Stopped,
+    Exporting,
+}
+
+impl RecorderState {
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use RecorderState::*;
+
+        matches!(
+            (self, next),
+            (Idle | Stopped, Countdown)
+                | (Countdown | Paused, Recording)
+                | (Recording, Paused)
+                | (Countdown | Recording | Paused, Stopped)
+                | (Countdown | Recording | Paused | Stopped | Exporting, Idle)
+                | (Idle | Stopped, Exporting)
+        )
+    }
+
+    fn check_transition(self, from: Option<&[Self]>, next: Self) -> Result<(), String> {
+        if from.is_some_and(|from| !from.contains(&self)) || !self.can_transition_to(next) {
+            return Err(match self {
+                RecorderState::Exporting => "An export is in progress".to_string(),
+                RecorderState::Countdown | RecorderState::Recording | RecorderState::Paused
+                    if matches!(next, RecorderState::Countdown | RecorderState::Exporting) =>
+                {
+                    "A recording is in progress".to_string()
+                }
+                _ => format!("Can't go from {self:?} to {next:?}"),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+pub struct RecorderStateChanged {
+    pub state: RecorderState,
+}
+
+#[derive(Default)]
+pub struct RecorderStateMachine(Mutex<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    state: RecorderState,
+    exports: usize,
+    export_jobs: HashMap<u32, Arc<ExportJob>>,
+    next_export_job: u32,
+}
+
+impl RecorderStateMachine {
+    pub fn state(&self) -> RecorderState {
+        self.0.lock().unwrap().state
+    }
+
+    pub fn transition(&self, app: &AppHandle, next: RecorderState) -> Result<(), String> {
+        self.transition_inner(app, None, next)
+    }
+
+    pub fn transition_from(
+        &self,
+        app: &AppHandle,
+        from: &[RecorderState],
+        next: RecorderState,
+    ) -> Result<(), String> {
+        self.transition_inner(app, Some(from), next)
+    }
+
+    fn transition_inner(
+        &self,
+        app: &AppHandle,
+        from: Option<&[RecorderState]>,
+        next: RecorderState,
+    ) -> Result<(), String> {
+        let mut inner = self.0.lock().unwrap();
+        let current = inner.state;
+        if current == next {
+            return Ok(());
+        }
+
+        current.check_transition(from, next)?;
+
+        if next != RecorderState::Exporting {
+            inner.exports = 0;
+        }
+        inner.state = next;
+        drop(inner);
+
+        debug!("Recorder state: {current:?} -> {next:?}");
+        RecorderStateChanged { state: next }.emit(app).ok();
+        Ok(())
+    }
+
+    pub fn force(&self, app: &AppHandle, next: RecorderState) {
+        if let Err(e) = self.transition(app, next) {
+            warn!("Forcing recorder state to {next:?}: {e}");
+            let mut inner = self.0.lock().unwrap();
+            inner.state = next;
+            inner.exports = 0;
+            drop(inner);
+            RecorderStateChanged { state: next }.emit(app).ok();
+        }
+    }
+
+    pub fn begin_export(app: &AppHandle, projects: &[&Path]) -> Result<ExportGuard, String> {
+        let machine = app.state::<Self>();
+        let running = {
+            let mut inner = machine.0.lock().unwrap();
+            inner.exports += 1;
+            inner.state == RecorderState::Exporting
+        };
+
+        if !running && let Err(e) = machine.transition(app, RecorderState::Exporting) {
+            machine.0.lock().unwrap().exports -= 1;
+            return Err(e);
+        }
+
+        let mut inner = machine.0.lock().unwrap();
+        let id = inner.next_export_job;
+        inner.next_export_job = id.wrapping_add(1);
+        let job = Arc::new(ExportJob {
+            projects: projects.iter().map(|path| path.to_path_buf()).collect(),
+            ..Default::default()
+        });
+        inner.export_jobs.insert(id, job.clone());
+
+        Ok(ExportGuard {
+            app: app.clone(),
+            id,
+            job,
+        })
+    }
+
+    pub fn set_export_paused(&self, id: u32, paused: bool) -> Result<(), String> {
+        let job = self
+            .0
+            .lock()
+            .unwrap()
+            .export_jobs
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| format!("No export with id {id} is running"))?;
+        job.set_paused(paused);
+        debug!("Export {id} paused: {paused}");
+        Ok(())
+    }
+
+    pub fn is_exporting_project(&self, project_path: &Path) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .export_jobs
+            .values()
+            .any(|job| job.exports_project(project_path))
+    }
+
+    pub fn cancel_exports(&self) {
+        for job in self.0.lock().unwrap().export_jobs.values() {
+            job.cancel();
+        }
+    }
+
+    pub fn cancel_project_exports(&self, project_path: &Path) {
+        for job in self.0.lock().unwrap().export_jobs.values() {
+            if job.exports_project(project_path) {
+                job.cancel();
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ExportJob {
+    projects: Vec<PathBuf>,
+    cancelled: Arc<AtomicBool>,
+    paused: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl ExportJob {
+    const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+    fn exports_project(&self, project_path: &Path) -> bool {
+        self.projects.iter().any(|path| path == project_path)
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        self.changed.notify_all();
+    }
+
+    fn set_paused(&self, paused: bool) {
+        *self.paused.lock().unwrap() = paused;
+        self.changed.notify_all();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.lock().unwrap()
+    }
+
+    pub fn wait_while_paused(&self, cancelled: &AtomicBool) -> bool {
+        let mut paused = self.paused.lock().unwrap();
+        while *paused {
+            if cancelled.load(Ordering::Acquire) {
+                return false;
+            }
+            paused = self
+                .changed
+                .wait_timeout(paused, Self::CANCEL_POLL_INTERVAL)
+                .unwrap()
+                .0;
+        }
+
+        !cancelled.load(Ordering::Acquire)
+    }
+}
+
+pub struct ExportGuard {
+    app: AppHandle,
+    id: u32,
+    job: Arc<ExportJob>,
+}
+
+impl ExportGuard {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn job(&self) -> Arc<ExportJob> {
+        self.job.clone()
+    }
+
+    pub fn cancelled(&self) -> Arc<AtomicBool> {
+        self.job.cancelled.clone()
+    }
+}
+
+impl Drop for ExportGuard {
+    fn drop(&mut self) {
+        let machine = self.app.state::<RecorderStateMachine>();
+        let last = {
+            let mut inner = machine.0.lock().unwrap();
+            inner.export_jobs.remove(&self.id);
+            inner.exports = inner.exports.saturating_sub(1);
+            inner.exports == 0 && inner.state == RecorderState::Exporting
+        };
+
+        if last {
+            machine.force(&self.app, RecorderState::Idle);
+        }
+    }
+}
+
+pub fn transition(app: &AppHandle, next: RecorderState) -> Result<(), String> {
+    app.state::<RecorderStateMachine>().transition(app, next)
+}
+
+pub fn transition_from(
+    app: &AppHandle,
+    from: &[RecorderState],
+    next: RecorderState,
+) -> Result<(), String> {
+    app.state::<RecorderStateMachine>()
+        .transition_from(app, from, next)
+}
+
+pub fn force(app: &AppHandle, next: RecorderState) {
+    app.state::<RecorderStateMachine>().force(app, next)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_recorder_state(app: AppHandle) -> RecorderState {
+    app.state::<RecorderStateMachine>().state()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecorderState::{self, *};
+
+    fn allowed(current: RecorderState, from: &[RecorderState], next: RecorderState) -> bool {
+        current.check_transition(Some(from), next).is_ok()
+    }
+
+    #[test]
+    fn pausing_is_rejected_unless_recording() {
+        assert!(allowed(Recording, &[Recording], Paused));
+        assert!(!allowed(Idle, &[Recording], Paused));
+        assert!(!allowed(Exporting, &[Recording], Paused));
+    }
+
+    #[test]
+    fn resuming_is_rejected_unless_paused() {
+        assert!(allowed(Paused, &[Paused], Recording));
+        assert!(!allowed(Idle, &[Paused], Recording));
+        assert!(!allowed(Stopped, &[Paused], Recording));
+    }
+
+    #[test]
+    fn stopping_is_rejected_without_a_recording() {
+        assert!(allowed(Paused, &[Recording, Paused], Stopped));
+        assert!(!allowed(Idle, &[Recording, Paused], Stopped));
+        assert!(!allowed(Exporting, &[Recording, Paused], Stopped));
+    }
+
+    #[test]
+    fn expected_states_still_follow_the_allowed_transitions() {
+        assert!(!allowed(Idle, &[Idle], Paused));
+        assert_eq!(
+            Recording.check_transition(None, Exporting),
+            Err("A recording is in progress".to_string())
+        );
+    }
+}
@@ -3,11 +3,11 @@ use cap_fail::fail;
 use cap_project::CursorMoveEvent;
 use cap_project::cursor::SHORT_CURSOR_SHAPE_DEBOUNCE_MS;
 use cap_project::{
-    CameraShape, ClickSpringConfig, CursorAnimationStyle, CursorClickEvent, GlideDirection,
-    InstantRecordingMeta, MultipleSegments, Platform, ProjectConfiguration, RecordingMeta,
-    RecordingMetaInner, SharingMeta, StudioRecordingMeta, StudioRecordingStatus,
-    TimelineConfiguration, TimelineSegment, UploadMeta, ZoomMode, ZoomSegment,
-    cursor::CursorEvents,
+    CameraShape, CaptionSegment, ClickSpringConfig, CursorAnimationStyle, CursorClickEvent,
+    GlideDirection, InstantRecordingMeta, MultipleSegments, Platform, ProjectConfiguration,
+    RecordingMeta, RecordingMetaInner, SharingMeta, StudioRecordingMeta, StudioRecordingStatus,
+    TimelineConfiguration, TimelineSegment, UploadMeta, WindowFocusBounds, WindowFocusEvent, XY,
+    ZoomEasing, ZoomMode, ZoomSegment, cursor::CursorEvents,
 };
 #[cfg(target_os = "macos")]
 use cap_recording::SendableShareableContent;
@@ -28,6 +28,7 @@ use cap_recording::{
 };
 use cap_rendering::ProjectRecordingsMeta;
 use cap_utils::{ensure_dir, moment_format_to_chrono, spawn_actor};
+use clipboard_rs::{Clipboard, ClipboardContext, common::RustImage};
 use futures::{FutureExt, stream};
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -57,7 +58,7 @@ use crate::permissions;
 use crate::web_api::AuthedApiError;
 use crate::{
     App, CameraWindowOperationLock, CurrentRecordingChanged, FinalizingRecordings, MutableState,
-    RecordingStarted, RecordingState, RecordingStopped, VideoUploadInfo,
+    NewNotification, RecordingStarted, RecordingState, RecordingStopped, VideoUploadInfo,
     api::PresignedS3PutRequestMethod,
     audio::AppSounds,
     auth::AuthStore,
@@ -65,6 +66,9 @@ use crate::{
     general_settings::{GeneralSettingsStore, PostDeletionBehaviour},
     open_external_link,
     presets::PresetsStore,
+    preview_proxy,
+    recorder_state::{self, RecorderState},
+    telemetry::{self, ErrorCategory, Feature},
     thumbnails::*,
     upload::{
         InstantMultipartUpload, build_video_meta, compress_image, create_or_get_video, upload_video,
@@ -96,7 +100,7 @@ pub enum InProgressRecording {
 }
 
 #[cfg(target_os = "macos")]
-async fn acquire_shareable_content_for_target(
+pub(crate) async fn acquire_shareable_content_for_target(
     capture_target: &ScreenCaptureTarget,
 ) -> anyhow::Result<SendableShareableContent> {
     let mut refreshed = false;
@@ -436,7 +440,7 @@ pub enum RecordingInputKind {
     Camera,
 }
 
-#[derive(tauri_specta::Event, specta::Type, Clone, Debug, serde::Serialize)]
+#[derive(tauri_specta::Event, specta::Type, Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "variant")]
 pub enum RecordingEvent {
     Countdown { value: u32 },
@@ -456,8 +460,13 @@ pub enum RecordingAction {
     Started,
     InvalidAuthentication,
     UpgradeRequired,
+    InsufficientDiskSpace { available_mb: u64, required_mb: u64 },
 }
 
+const RECORDING_BYTES_PER_MINUTE: u64 = 150 * 1024 * 1024;
+const RECORDING_MIN_SPACE_MINUTES: u64 = 2;
+const RECORDING_LOW_SPACE_MINUTES: u64 = 30;
+
 pub fn format_project_name<'a>(
     template: Option<&str>,
     target_name: &'a str,
@@ -579,6 +588,21 @@ pub async fn start_recording(
     app: AppHandle,
     state_mtx: MutableState<'_, App>,
     inputs: StartRecordingInputs,
+) -> Result<RecordingAction, String> {
+    recorder_state::transition(&app, RecorderState::Countdown)?;
+
+    let result = start_recording_inner(app.clone(), state_mtx, inputs).await;
+    if !matches!(result, Ok(RecordingAction::Started)) {
+        recorder_state::force(&app, RecorderState::Idle);
+    }
+
+    result
+}
+
+async fn start_recording_inner(
+    app: AppHandle,
+    state_mtx: MutableState<'_, App>,
+    inputs: StartRecordingInputs,
 ) -> Result<RecordingAction, String> {
     if !matches!(state_mtx.read().await.recording_state, RecordingState::None) {
         return Err("Recording already in progress".to_string());
@@ -639,6 +663,34 @@ pub async fn start_recording(
 
     let recordings_base_dir = app.path().app_data_dir().unwrap().join("recordings");
 
+    match cap_utils::disk_space::ensure_available_space(
+        &recordings_base_dir,
+        RECORDING_MIN_SPACE_MINUTES * RECORDING_BYTES_PER_MINUTE,
+    ) {
+        Ok(Some(available))
+            if available < RECORDING_LOW_SPACE_MINUTES * RECORDING_BYTES_PER_MINUTE =>
+        {
+            warn!(available, "Starting recording with low disk space");
+            let _ = NewNotification {
+                title: "Low disk space".to_string(),
+                body: format!(
+                    "Only about {} minutes of recording space left.",
+                    available / RECORDING_BYTES_PER_MINUTE
+                ),
+                is_error: false,
+            }
+            .emit(&app);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("{e}");
+            return Ok(RecordingAction::InsufficientDiskSpace {
+                available_mb: e.available_mb(),
+                required_mb: e.required_mb(),
+            });
+        }
+    }
+
     let project_file_path = recordings_base_dir.join(&cap_utils::ensure_unique_filename(
         &filename,
         &recordings_base_dir,
@@ -777,7 +829,7 @@ pub async fn start_recording(
         let _ = general_settings
             .map(|v| v.main_window_recording_start_behaviour)
             .unwrap_or_default()
-            .perform(&window);
+            .perform(&window, &inputs.capture_target);
     }
 
     if let Some(countdown) = countdown {
@@ -869,12 +921,18 @@ pub async fn start_recording(
 
             #[cfg(target_os = "macos")]
             let excluded_windows = {
-                let window_exclusions = general_settings
+                let mut window_exclusions = general_settings
                     .as_ref()
                     .map_or_else(general_settings::default_excluded_windows, |settings| {
                         settings.excluded_windows.clone()
                     });
 
+                window_exclusions.push(crate::window_exclusion::WindowExclusion {
+                    bundle_identifier: None,
+                    owner_name: None,
+                    window_title: Some(CapWindowId::RecordingControls.title()),
+                });
+
                 crate::window_exclusion::resolve_window_ids(&window_exclusions)
             };
 
@@ -907,6 +965,18 @@ pub async fn start_recording(
                                     .map(|s| s.crash_recovery_recording)
                                     .unwrap_or_default(),
                             )
+                            .with_anonymized_input(
+                                general_settings
+                                    .as_ref()
+                                    .map(|s| s.anonymize_input)
+                                    .unwrap_or_default(),
+                            )
+                            .with_keystrokes(
+                                general_settings
+                                    .as_ref()
+                                    .map(|s| s.keystroke_capture)
+                                    .unwrap_or_default(),
+                            )
                             .with_max_fps(
                                 general_settings.as_ref().map(|s| s.max_fps).unwrap_or(60),
                             );
@@ -1082,6 +1152,8 @@ pub async fn start_recording(
                 Err(e) => {
                     let mut state = state_mtx.write().await;
 
+                    telemetry::error_occurred(&app, ErrorCategory::Recording);
+
                     let _ = RecordingEvent::Failed {
                         error: e.to_string(),
                     }
@@ -1145,6 +1217,15 @@ pub async fn start_recording(
 
     AppSounds::StartRecording.play();
 
+    telemetry::feature_used(
+        &app,
+        match inputs.mode {
+            RecordingMode::Studio => Feature::StudioRecording,
+            RecordingMode::Instant => Feature::InstantRecording,
+            RecordingMode::Screenshot => Feature::Screenshot,
+        },
+    );
+
     Ok(RecordingAction::Started)
 }
 
@@ -1155,8 +1236,7 @@ pub async fn pause_recording(app: AppHandle, state: MutableState<'_, App>) -> Re
     let mut state = state.write().await;
 
     if let Some(recording) = state.current_recording_mut() {
-        recording.pause().await.map_err(|e| e.to_string())?;
-        RecordingEvent::Paused.emit(&app).ok();
+        pause_in_progress(&app, recording).await?;
     }
 
     Ok(())
@@ -1169,8 +1249,7 @@ pub async fn resume_recording(app: AppHandle, state: MutableState<'_, App>) -> R
     let mut state = state.write().await;
 
     if let Some(recording) = state.current_recording_mut() {
-        recording.resume().await.map_err(|e| e.to_string())?;
-        RecordingEvent::Resumed.emit(&app).ok();
+        resume_in_progress(&app, recording).await?;
     }
 
     Ok(())
@@ -1187,17 +1266,38 @@ pub async fn toggle_pause_recording(
 
     if let Some(recording) = state.current_recording() {
         if recording.is_paused().await.map_err(|e| e.to_string())? {
-            recording.resume().await.map_err(|e| e.to_string())?;
-            RecordingEvent::Resumed.emit(&app).ok();
+            resume_in_progress(&app, recording).await?;
         } else {
-            recording.pause().await.map_err(|e| e.to_string())?;
-            RecordingEvent::Paused.emit(&app).ok();
+            pause_in_progress(&app, recording).await?;
         }
     }
 
     Ok(())
 }
 
+async fn pause_in_progress(app: &AppHandle, recording: &InProgressRecording) -> Result<(), String> {
+    recorder_state::transition_from(app, &[RecorderState::Recording], RecorderState::Paused)?;
+    if let Err(e) = recording.pause().await {
+        recorder_state::force(app, RecorderState::Recording);
+        return Err(e.to_string());
+    }
+    RecordingEvent::Paused.emit(app).ok();
+    Ok(())
+}
+
+async fn resume_in_progress(
+    app: &AppHandle,
+    recording: &InProgressRecording,
+) -> Result<(), String> {
+    recorder_state::transition_from(app, &[RecorderState::Paused], RecorderState::Recording)?;
+    if let Err(e) = recording.resume().await {
+        recorder_state::force(app, RecorderState::Paused);
+        return Err(e.to_string());
+    }
+    RecordingEvent::Resumed.emit(app).ok();
+    Ok(())
+}
+
 async fn handle_spawn_failure(
     app: &AppHandle,
     state_mtx: &MutableState<'_, App>,
@@ -1325,6 +1425,12 @@ pub async fn delete_recording(app: AppHandle, state: MutableState<'_, App>) -> R
         };
 
         let _ = recording.cancel().await;
+        if let Err(e) =
+            recorder_state::transition_from(&app, &[RecorderState::Stopped], RecorderState::Idle)
+        {
+            warn!("Deleted a recording the recorder wasn't stopped for: {e}");
+            recorder_state::force(&app, RecorderState::Idle);
+        }
 
         std::fs::remove_dir_all(&recording_dir).ok();
 
@@ -1369,12 +1475,7 @@ pub async fn take_screenshot(
     app: AppHandle,
     target: ScreenCaptureTarget,
 ) -> Result<PathBuf, String> {
-    use crate::NewScreenshotAdded;
-    use crate::notifications;
-    use crate::{PendingScreenshot, PendingScreenshots};
     use cap_recording::screenshot::capture_screenshot;
-    use image::ImageEncoder;
-    use std::time::Instant;
 
     let general_settings = GeneralSettingsStore::get(&app).ok().flatten();
     let general_settings = general_settings.as_ref();
@@ -1395,6 +1496,56 @@ pub async fn take_screenshot(
 
     AppSounds::Notification.play();
 
+    save_screenshot(&app, image, project_name)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(name = "capture_from_clipboard", skip(app, clipboard))]
+pub async fn capture_from_clipboard(
+    app: AppHandle,
+    clipboard: MutableState<'_, ClipboardContext>,
+) -> Result<PathBuf, String> {
+    let image = clipboard
+        .read()
+        .await
+        .get_image()
+        .map_err(|_| "The clipboard doesn't contain an image".to_string())?
+        .get_dynamic_image()
+        .map_err(|e| format!("Failed to read clipboard image: {e}"))?;
+
+    let general_settings = GeneralSettingsStore::get(&app).ok().flatten();
+    let project_name = format_project_name(
+        general_settings
+            .as_ref()
+            .and_then(|s| s.default_project_name_template.clone())
+            .as_deref(),
+        "Clipboard Image",
+        "Clipboard",
+        RecordingMode::Screenshot,
+        None,
+    );
+
+    let path = save_screenshot(&app, image, project_name)?;
+    ShowCapWindow::ScreenshotEditor { path: path.clone() }
+        .show(&app)
+        .await
+        .map_err(|e| format!("Failed to open screenshot editor: {e}"))?;
+
+    Ok(path)
+}
+
+fn save_screenshot(
+    app: &AppHandle,
+    image: image::DynamicImage,
+    project_name: String,
+) -> Result<PathBuf, String> {
+    use crate::NewScreenshotAdded;
+    use crate::notifications;
+    use crate::{PendingScreenshot, PendingScreenshots};
+    use image::ImageEncoder;
+    use std::time::Instant;
+
     let image_width = image.width();
     let image_height = image.height();
     let channels: u32 = match &image {
@@ -1534,6 +1685,8 @@ pub async fn take_screenshot(
         }
     });
 
+    telemetry::feature_used(&app, Feature::Screenshot);
+
     Ok(image_path)
 }
 
@@ -1599,8 +1752,18 @@ async fn handle_recording_end(
         }
     };
 
+    if let Err(e) =
+        recorder_state::transition_from(&handle, &[RecorderState::Stopped], RecorderState::Idle)
+    {
+        warn!("Finished a recording the recorder wasn't stopped for: {e}");
+        recorder_state::force(&handle, RecorderState::Idle);
+    }
     let _ = RecordingStopped.emit(&handle);
 
+    if let Some(Ok(())) = &res {
+        crate::webhooks::recording_stopped(&handle, &recording_dir);
+    }
+
     let _ = app.recording_logging_handle.reload(None);
 
     if let Some(window) = CapWindowId::RecordingControls.get(&handle) {
@@ -1729,6 +1892,7 @@ async fn handle_recording_finish(
             );
 
             config.write(&recording_dir).map_err(|e| e.to_string())?;
+            preview_proxy::spawn_build(app, recording_dir.clone());
 
             (
                 RecordingMetaInner::Studio(Box::new(updated_studio_meta)),
@@ -1961,6 +2125,7 @@ async fn finalize_studio_recording(
     config
         .write(&recording_dir)
         .map_err(|e| format!("Failed to write project config: {e}"))?;
+    preview_proxy::spawn_build(app, recording_dir);
 
     info!("Background finalization completed for recording");
 
@@ -1987,7 +2152,6 @@ fn generate_zoom_segments_from_clicks_impl(
     const MOVEMENT_WINDOW_SECONDS: f64 = 1.5;
     const MOVEMENT_EVENT_DISTANCE_THRESHOLD: f64 = 0.02;
     const MOVEMENT_WINDOW_DISTANCE_THRESHOLD: f64 = 0.08;
-    const AUTO_ZOOM_AMOUNT: f64 = 1.5;
     const SHAKE_FILTER_THRESHOLD: f64 = 0.33;
     const SHAKE_FILTER_WINDOW_MS: f64 = 150.0;
 
@@ -2217,25 +2381,261 @@ fn generate_zoom_segments_from_clicks_impl(
                 return None;
             }
 
-            Some(ZoomSegment {
-                start,
-                end,
-                amount: AUTO_ZOOM_AMOUNT,
-                mode: ZoomMode::Auto,
-                glide_direction: GlideDirection::None,
-                glide_speed: 0.5,
-                instant_animation: false,
-                edge_snap_ratio: 0.25,
-            })
+            Some(auto_zoom_segment(start, end))
+        })
+        .collect()
+}
+
+const AUTO_ZOOM_AMOUNT: f64 = 1.5;
+
+fn auto_zoom_segment(start: f64, end: f64) -> ZoomSegment {
+    ZoomSegment {
+        start,
+        end,
+        amount: AUTO_ZOOM_AMOUNT,
+        mode: ZoomMode::Auto,
+        glide_direction: GlideDirection::None,
+        glide_speed: 0.5,
+        instant_animation: false,
+        edge_snap_ratio: 0.25,
+        zoom_from_click: false,
+        easing: ZoomEasing::default(),
+    }
+}
+
+const NARRATION_CUE_PHRASES: &[&str] = &[
+    "click here",
+    "click on",
+    "as you can see",
+    "you can see",
+    "look at",
+    "notice",
+    "right here",
+    "over here",
+];
+const NARRATION_CUE_LEAD_SECS: f64 = 1.0;
+const NARRATION_CUE_PRE_PADDING: f64 = 0.25;
+const NARRATION_CUE_POST_PADDING: f64 = 1.5;
+const NARRATION_CUE_ZOOM_DURATION: f64 = 2.5;
+
+fn cue_tokens(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
         })
+        .filter(|word| !word.is_empty())
         .collect()
 }
 
+fn narration_cues(captions: &[CaptionSegment], phrases: &[Vec<String>]) -> Vec<(f64, f64)> {
+    let mut cues = Vec::new();
+
+    for caption in captions {
+        let words = if caption.words.is_empty() {
+            cue_tokens(&caption.text)
+                .into_iter()
+                .map(|word| (word, caption.start as f64, caption.end as f64))
+                .collect::<Vec<_>>()
+        } else {
+            caption
+                .words
+                .iter()
+                .flat_map(|word| {
+                    cue_tokens(&word.text)
+                        .into_iter()
+                        .map(|token| (token, word.start as f64, word.end as f64))
+                })
+                .collect()
+        };
+
+        for i in 0..words.len() {
+            let matched = phrases.iter().find(|phrase| {
+                !phrase.is_empty()
+                    && words.len() - i >= phrase.len()
+                    && words[i..i + phrase.len()]
+                        .iter()
+                        .zip(phrase.iter())
+                        .all(|((word, ..), token)| word == token)
+            });
+
+            if let Some(phrase) = matched {
+                cues.push((words[i].1, words[i + phrase.len() - 1].2));
+            }
+        }
+    }
+
+    cues.sort_by(|a, b| a.0.total_cmp(&b.0));
+    cues.dedup_by(|a, b| a.0 == b.0);
+    cues
+}
+
+pub fn bias_zoom_segments_to_narration(
+    segments: &mut Vec<ZoomSegment>,
+    captions: &[CaptionSegment],
+    keywords: &[String],
+    max_duration: f64,
+) {
+    let phrases = NARRATION_CUE_PHRASES
+        .iter()
+        .copied()
+        .chain(keywords.iter().map(String::as_str))
+        .map(cue_tokens)
+        .collect::<Vec<_>>();
+
+    for (cue_start, cue_end) in narration_cues(captions, &phrases) {
+        if cue_start >= max_duration {
+            continue;
+        }
+        let hold_until = (cue_end + NARRATION_CUE_POST_PADDING).min(max_duration);
+
+        if let Some(segment) = segments.iter_mut().find(|segment| {
+            cue_start >= segment.start - NARRATION_CUE_LEAD_SECS && cue_start <= segment.end
+        }) {
+            segment.end = segment.end.max(hold_until);
+            continue;
+        }
+
+        let start = (cue_start - NARRATION_CUE_PRE_PADDING).max(0.0);
+        let end = hold_until
+            .max(cue_start + NARRATION_CUE_ZOOM_DURATION)
+            .min(max_duration);
+        if end > start {
+            segments.push(auto_zoom_segment(start, end));
+        }
+    }
+
+    segments.sort_by(|a, b| a.start.total_cmp(&b.start));
+    let mut merged: Vec<ZoomSegment> = Vec::with_capacity(segments.len());
+    for segment in segments.drain(..) {
+        if let Some(last) = merged.last_mut()
+            && segment.start <= last.end
+        {
+            last.end = last.end.max(segment.end);
+            continue;
+        }
+        merged.push(segment);
+    }
+    *segments = merged;
+}
+
+const WINDOW_ZOOM_PADDING: f64 = 0.03;
+const MAX_WINDOW_ZOOM_AMOUNT: f64 = 2.5;
+const MIN_WINDOW_ZOOM_AMOUNT: f64 = 1.15;
+
+fn snap_zoom_segments_to_windows(
+    segments: &mut [ZoomSegment],
+    clicks: &[CursorClickEvent],
+    moves: &[CursorMoveEvent],
+    focus_changes: &[WindowFocusEvent],
+) {
+    for segment in segments.iter_mut() {
+        let Some(click) = clicks
+            .iter()
+            .filter(|c| c.down && (segment.start..=segment.end).contains(&(c.time_ms / 1000.0)))
+            .min_by(|a, b| a.time_ms.total_cmp(&b.time_ms))
+        else {
+            continue;
+        };
+
+        let Some(position) = moves
+            .iter()
+            .filter(|m| m.time_ms <= click.time_ms)
+            .max_by(|a, b| a.time_ms.total_cmp(&b.time_ms))
+        else {
+            continue;
+        };
+
+        let Some(bounds) = focus_changes
+            .iter()
+            .filter(|f| f.time_ms <= click.time_ms)
+            .max_by(|a, b| a.time_ms.total_cmp(&b.time_ms))
+            .and_then(|f| f.bounds)
+        else {
+            continue;
+        };
+
+        let inside = (bounds.x..=bounds.x + bounds.width).contains(&position.x)
+            && (bounds.y..=bounds.y + bounds.height).contains(&position.y);
+        if !inside {
+            continue;
+        }
+
+        if let Some((amount, focus)) = window_zoom_framing(bounds) {
+            segment.amount = amount;
+            segment.mode = ZoomMode::Manual {
+                x: focus.x as f32,
+                y: focus.y as f32,
+            };
+        }
+    }
+}
+
+fn window_zoom_framing(bounds: WindowFocusBounds) -> Option<(f64, XY<f64>)> {
+    let width = bounds.width + WINDOW_ZOOM_PADDING * 2.0;
+    let height = bounds.height + WINDOW_ZOOM_PADDING * 2.0;
+    let amount = (1.0 / width.max(height)).min(MAX_WINDOW_ZOOM_AMOUNT);
+    if !amount.is_finite() || amount < MIN_WINDOW_ZOOM_AMOUNT {
+        return None;
+    }
+
+    let viewport = 1.0 / amount;
+    let focus = |start: f64, size: f64| {
+        let visible_start = (start + (size - viewport) / 2.0).clamp(0.0, 1.0 - viewport);
+        visible_start / (1.0 - viewport)
+    };
+
+    Some((
+        amount,
+        XY::new(
+            focus(bounds.x, bounds.width),
+            focus(bounds.y, bounds.height),
+        ),
+    ))
+}
+
+const MAX_BEAT_SHIFT_SECS: f64 = 0.4;
+const BEAT_PULSE_EASING: ZoomEasing = ZoomEasing::CubicBezier {
+    x1: 0.34,
+    y1: 1.56,
+    x2: 0.64,
+    y2: 1.0,
+};
+
+pub fn sync_zoom_segments_to_beats(segments: &mut [ZoomSegment], beats: &[f64]) {
+    let mut previous_end = 0.0_f64;
+
+    for segment in segments.iter_mut() {
+        let nearest = beats
+            .iter()
+            .copied()
+            .filter(|beat| *beat >= previous_end && segment.end - beat >= 1.0)
+            .min_by(|a, b| {
+                (a - segment.start)
+                    .abs()
+                    .total_cmp(&(b - segment.start).abs())
+            });
+
+        if let Some(beat) = nearest
+            && (beat - segment.start).abs() <= MAX_BEAT_SHIFT_SECS
+        {
+            segment.start = beat;
+            segment.instant_animation = false;
+            segment.easing = BEAT_PULSE_EASING;
+        }
+
+        previous_end = segment.end;
+    }
+}
+
 /// Generates zoom segments based on mouse click events during recording.
 /// Used during the recording completion process.
 pub fn generate_zoom_segments_from_clicks(
     recording: &studio_recording::CompletedRecording,
     recordings: &ProjectRecordingsMeta,
+    snap_to_windows: bool,
 ) -> Vec<ZoomSegment> {
     // Build a temporary RecordingMeta so we can use the common implementation
     let recording_meta = RecordingMeta {
@@ -2247,14 +2647,14 @@ pub fn generate_zoom_segments_from_clicks(
         upload: None,
     };
 
-    generate_zoom_segments_for_project(&recording_meta, recordings)
+    generate_zoom_segments_for_project(&recording_meta, recordings, snap_to_windows)
 }
 
 /// Generates zoom segments from clicks for an existing project.
-/// Used in the editor context where we have RecordingMeta.
 pub fn generate_zoom_segments_for_project(
     recording_meta: &RecordingMeta,
     recordings: &ProjectRecordingsMeta,
+    snap_to_windows: bool,
 ) -> Vec<ZoomSegment> {
     let RecordingMetaInner::Studio(studio_meta) = &recording_meta.inner else {
         return Vec::new();
@@ -2262,6 +2662,7 @@ pub fn generate_zoom_segments_for_project(
 
     let mut all_clicks = Vec::new();
     let mut all_moves = Vec::new();
+    let mut all_focus_changes = Vec::new();
 
     match &**studio_meta {
         StudioRecordingMeta::SingleSegment { segment } => {
@@ -2276,6 +2677,7 @@ pub fn generate_zoom_segments_for_project(
                 );
                 all_clicks = events.clicks;
                 all_moves = events.moves;
+                all_focus_changes = events.focus_changes;
             }
         }
         StudioRecordingMeta::MultipleSegments { inner, .. } => {
@@ -2283,11 +2685,61 @@ pub fn generate_zoom_segments_for_project(
                 let events = segment.cursor_events(recording_meta);
                 all_clicks.extend(events.clicks);
                 all_moves.extend(events.moves);
+                all_focus_changes.extend(events.focus_changes);
             }
         }
     }
 
-    generate_zoom_segments_from_clicks_impl(all_clicks, all_moves, recordings.duration())
+    if !snap_to_windows || all_focus_changes.is_empty() {
+        return generate_zoom_segments_from_clicks_impl(
+            all_clicks,
+            all_moves,
+            recordings.duration(),
+        );
+    }
+
+    let mut segments = generate_zoom_segments_from_clicks_impl(
+        all_clicks.clone(),
+        all_moves.clone(),
+        recordings.duration(),
+    );
+    snap_zoom_segments_to_windows(&mut segments, &all_clicks, &all_moves, &all_focus_changes);
+    segments
+}
+
+pub fn window_focus_changes_for_project(
+    recording_meta: &RecordingMeta,
+    recordings: &ProjectRecordingsMeta,
+) -> Vec<WindowFocusEvent> {
+    let RecordingMetaInner::Studio(studio_meta) = &recording_meta.inner else {
+        return Vec::new();
+    };
+
+    match &**studio_meta {
+        StudioRecordingMeta::SingleSegment { segment } => segment
+            .cursor
+            .as_ref()
+            .and_then(|path| CursorEvents::load_from_file(&recording_meta.path(path)).ok())
+            .map(|events| events.focus_changes)
+            .unwrap_or_default(),
+        StudioRecordingMeta::MultipleSegments { inner, .. } => {
+            let mut offset_ms = 0.0;
+            let mut focus_changes = Vec::new();
+
+            for (segment, recording) in inner.segments.iter().zip(&recordings.segments) {
+                let events = segment.cursor_events(recording_meta);
+                focus_changes.extend(events.focus_changes.into_iter().map(|event| {
+                    WindowFocusEvent {
+                        time_ms: event.time_ms + offset_ms,
+                        ..event
+                    }
+                }));
+                offset_ms += recording.duration() * 1000.0;
+            }
+
+            focus_changes
+        }
+    }
 }
 
 fn project_config_from_recording(
@@ -2333,7 +2785,11 @@ fn project_config_from_recording(
         .collect::<Vec<_>>();
 
     let zoom_segments = if settings.auto_zoom_on_clicks {
-        generate_zoom_segments_from_clicks(completed_recording, recordings)
+        generate_zoom_segments_from_clicks(
+            completed_recording,
+            recordings,
+            settings.snap_zoom_to_windows,
+        )
     } else {
         Vec::new()
     };
@@ -2352,6 +2808,9 @@ fn project_config_from_recording(
         scene_segments: Vec::new(),
         mask_segments: Vec::new(),
         text_segments: Vec::new(),
+        reaction_segments: Vec::new(),
+        cursor_segments: Vec::new(),
+        markers: Vec::new(),
     });
 
     config
@@ -2441,6 +2900,142 @@ mod tests {
         assert!(first.end <= 19.5);
     }
 
+    #[test]
+    fn frames_windows_with_padding() {
+        let (amount, focus) = window_zoom_framing(WindowFocusBounds {
+            x: 0.5,
+            y: 0.0,
+            width: 0.5,
+            height: 0.5,
+        })
+        .unwrap();
+
+        assert!((amount - 1.0 / 0.56).abs() < 1e-9);
+        assert!((focus.x - 1.0).abs() < 1e-9);
+        assert!(focus.y.abs() < 1e-9);
+
+        assert!(
+            window_zoom_framing(WindowFocusBounds {
+                x: 0.0,
+                y: 0.0,
+                width: 0.95,
+                height: 0.9,
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn snaps_zooms_to_the_clicked_window() {
+        let focus = |time_ms| WindowFocusEvent {
+            time_ms,
+            app_name: Some("Terminal".to_string()),
+            title: None,
+            bounds: Some(WindowFocusBounds {
+                x: 0.25,
+                y: 0.25,
+                width: 0.5,
+                height: 0.5,
+            }),
+        };
+        let clicks = vec![click_event(1_000.0), click_event(8_000.0)];
+        let moves = vec![move_event(900.0, 0.5, 0.5), move_event(7_900.0, 0.9, 0.9)];
+        let mut segments =
+            generate_zoom_segments_from_clicks_impl(clicks.clone(), moves.clone(), 20.0);
+        assert_eq!(segments.len(), 2);
+
+        snap_zoom_segments_to_windows(&mut segments, &clicks, &moves, &[focus(0.0)]);
+
+        let ZoomMode::Manual { x, y } = segments[0].mode else {
+            panic!("expected the first zoom to frame the window");
+        };
+        assert!((x - 0.5).abs() < 1e-6 && (y - 0.5).abs() < 1e-6);
+        assert!((segments[0].amount - 1.0 / 0.56).abs() < 1e-9);
+        assert!(matches!(segments[1].mode, ZoomMode::Auto));
+    }
+
+    fn caption(start: f32, end: f32, words: &[(&str, f32, f32)]) -> CaptionSegment {
+        CaptionSegment {
+            id: String::new(),
+            start,
+            end,
+            text: words
+                .iter()
+                .map(|(text, ..)| *text)
+                .collect::<Vec<_>>()
+                .join(" "),
+            words: words
+                .iter()
+                .map(|&(text, start, end)| cap_project::CaptionWord {
+                    text: text.to_string(),
+                    start,
+                    end,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn narration_cues_hold_and_start_zooms() {
+        let mut segments = vec![auto_zoom_segment(2.0, 4.0), auto_zoom_segment(8.0, 10.0)];
+        let captions = [
+            caption(
+                1.5,
+                3.5,
+                &[("Now", 1.5, 1.8), ("click", 3.0, 3.2), ("here.", 3.2, 3.5)],
+            ),
+            caption(
+                14.0,
+                16.0,
+                &[
+                    ("Check", 14.0, 14.25),
+                    ("the", 14.25, 14.5),
+                    ("Dashboard", 14.5, 15.0),
+                ],
+            ),
+        ];
+
+        bias_zoom_segments_to_narration(&mut segments, &captions, &["dashboard".into()], 30.0);
+
+        let boundaries = segments
+            .iter()
+            .map(|s| (s.start, s.end))
+            .collect::<Vec<_>>();
+        assert_eq!(boundaries, vec![(2.0, 5.0), (8.0, 10.0), (14.25, 17.0)]);
+    }
+
+    #[test]
+    fn held_zooms_absorb_the_next_one() {
+        let mut segments = vec![auto_zoom_segment(2.0, 4.0), auto_zoom_segment(5.0, 7.0)];
+        let captions = [CaptionSegment {
+            text: "As you can see".to_string(),
+            ..caption(3.0, 4.0, &[])
+        }];
+
+        bias_zoom_segments_to_narration(&mut segments, &captions, &[], 30.0);
+
+        let boundaries = segments
+            .iter()
+            .map(|s| (s.start, s.end))
+            .collect::<Vec<_>>();
+        assert_eq!(boundaries, vec![(2.0, 7.0)]);
+    }
+
+    #[test]
+    fn starts_zooms_on_nearby_beats() {
+        let clicks = vec![click_event(1_000.0), click_event(8_000.0)];
+        let mut segments = generate_zoom_segments_from_clicks_impl(clicks, vec![], 20.0);
+        assert_eq!(segments.len(), 2);
+        let second_start = segments[1].start;
+
+        sync_zoom_segments_to_beats(&mut segments, &[0.5, 2.0, 7.0, 9.0]);
+
+        assert_eq!(segments[0].start, 0.5);
+        assert_eq!(segments[0].easing, BEAT_PULSE_EASING);
+        assert_eq!(segments[1].start, second_start);
+        assert_eq!(segments[1].easing, ZoomEasing::Spring);
+    }
+
     #[test]
     fn ignores_cursor_jitter() {
         let jitter_moves = (0..30)
@@ -2458,4 +3053,129 @@ mod tests {
             "small jitter should not generate segments"
         );
     }
+
+    mod zoom_snapshots {
+        use super::*;
+        use cap_project::XY;
+        use cap_rendering::{Coord, InterpolatedZoom, SegmentsCursor};
+
+        const FIXTURES: &[&str] = &["form_filling", "code_walkthrough", "drag_and_drop"];
+        const SAMPLE_FPS: f64 = 60.0;
+        const MAX_SCALE_RATE: f64 = 3.0;
+
+        #[derive(Deserialize)]
+        struct ZoomFixture {
+            duration: f64,
+            #[serde(flatten)]
+            events: CursorEvents,
+        }
+
+        fn fixture_dir() -> PathBuf {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/zoom")
+        }
+
+        fn load_fixture(name: &str) -> ZoomFixture {
+            let path = fixture_dir().join(format!("{name}.json"));
+            let file = std::fs::File::open(&path).unwrap();
+            serde_json::from_reader(file).unwrap()
+        }
+
+        fn boundaries(segments: &[ZoomSegment]) -> Vec<(f64, f64)> {
+            let round = |t: f64| (t * 1000.0).round() / 1000.0;
+            segments
+                .iter()
+                .map(|s| (round(s.start), round(s.end)))
+                .collect()
+        }
+
+        fn assert_matches_snapshot(name: &str, actual: &[(f64, f64)]) {
+            let path = fixture_dir().join(format!("{name}.segments.json"));
+
+            if !cap_utils::snapshot::require_reference(&path, "UPDATE_SNAPSHOTS", |path| {
+                let json = serde_json::to_string_pretty(actual).unwrap();
+                std::fs::write(path, format!("{json}\n")).unwrap();
+            }) {
+                return;
+            }
+
+            let expected: Vec<(f64, f64)> =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            assert_eq!(
+                expected,
+                actual,
+                "{name}: zoom segments changed, see {}",
+                path.display()
+            );
+        }
+
+        fn sample_scale(fixture: &ZoomFixture, segments: &[ZoomSegment]) -> Vec<f64> {
+            let moves = &fixture.events.moves;
+            let frames = (fixture.duration * SAMPLE_FPS).ceil() as usize;
+
+            (0..=frames)
+                .map(|frame| {
+                    let time = frame as f64 / SAMPLE_FPS;
+                    let focus = moves
+                        .iter()
+                        .rfind(|m| m.time_ms / 1000.0 <= time)
+                        .or(moves.first())
+                        .map(|m| XY::new(m.x, m.y))
+                        .unwrap_or(XY::new(0.5, 0.5));
+
+                    InterpolatedZoom::new(SegmentsCursor::new(time, segments), Coord::new(focus))
+                        .display_amount()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn segments_match_snapshots() {
+            for name in FIXTURES {
+                let fixture = load_fixture(name);
+                let segments = generate_zoom_segments_from_clicks_impl(
+                    fixture.events.clicks.clone(),
+                    fixture.events.moves.clone(),
+                    fixture.duration,
+                );
+
+                assert!(!segments.is_empty(), "{name}: no zoom segments generated");
+                for pair in segments.windows(2) {
+                    assert!(
+                        pair[0].end < pair[1].start,
+                        "{name}: segments overlap or touch"
+                    );
+                }
+                for segment in &segments {
+                    assert!(segment.start >= 0.0 && segment.end <= fixture.duration);
+                    assert!(segment.end - segment.start >= 1.0);
+                }
+
+                assert_matches_snapshot(name, &boundaries(&segments));
+            }
+        }
+
+        #[test]
+        fn zoom_scale_changes_smoothly() {
+            for name in FIXTURES {
+                let fixture = load_fixture(name);
+                let segments = generate_zoom_segments_from_clicks_impl(
+                    fixture.events.clicks.clone(),
+                    fixture.events.moves.clone(),
+                    fixture.duration,
+                );
+                let scales = sample_scale(&fixture, &segments);
+
+                for (frame, pair) in scales.windows(2).enumerate() {
+                    let rate = (pair[1] - pair[0]).abs() * SAMPLE_FPS;
+                    assert!(
+                        rate <= MAX_SCALE_RATE,
+                        "{name}: zoom scale jumped from {} to {} at {:.3}s",
+                        pair[0],
+                        pair[1],
+                        frame as f64 / SAMPLE_FPS
+                    );
+                }
+            }
+        }
+    }
 }
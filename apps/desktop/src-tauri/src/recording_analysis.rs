@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+use cap_project::{
+    CursorEvents, RecordingMeta, StudioRecordingMeta, XY,
+    analysis::{self, AnalysisSegment, RecordingAnalysis},
+};
+use cap_rendering::ProjectRecordingsMeta;
+use image::{Rgba, RgbaImage};
+use serde::Serialize;
+use specta::Type;
+use tracing::{instrument, warn};
+
+const HEATMAP_MAX_WIDTH: u32 = 1920;
+const HEATMAP_RADIUS: f32 = 0.03;
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingAnalysisResult {
+    pub report: RecordingAnalysis,
+    pub report_path: PathBuf,
+    pub heatmap_path: Option<PathBuf>,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+pub async fn analyze_recording(
+    project_path: PathBuf,
+    heatmap: bool,
+) -> Result<RecordingAnalysisResult, String> {
+    tokio::task::spawn_blocking(move || analyze(&project_path, heatmap))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn analyze(project_path: &Path, heatmap: bool) -> Result<RecordingAnalysisResult, String> {
+    let recording_meta = RecordingMeta::load_for_project(project_path)
+        .map_err(|e| format!("Failed to load recording meta: {e}"))?;
+    let studio_meta = recording_meta
+        .studio_meta()
+        .ok_or("Only studio recordings can be analyzed")?;
+    let recordings = ProjectRecordingsMeta::new(&recording_meta.project_path, studio_meta)?;
+
+    let cursors: Vec<CursorEvents> = match studio_meta {
+        StudioRecordingMeta::SingleSegment { segment } => vec![
+            segment
+                .cursor
+                .as_ref()
+                .and_then(|path| {
+                    CursorEvents::load_from_file(&recording_meta.path(path))
+                        .map_err(|e| warn!("{e}"))
+                        .ok()
+                })
+                .unwrap_or_default(),
+        ],
+        StudioRecordingMeta::MultipleSegments { inner } => inner
+            .segments
+            .iter()
+            .map(|s| s.cursor_events(&recording_meta))
+            .collect(),
+    };
+
+    let segments = cursors
+        .iter()
+        .zip(&recordings.segments)
+        .map(|(cursor, recording)| AnalysisSegment {
+            cursor,
+            duration_secs: recording.duration(),
+            display_size: XY::new(recording.display.width, recording.display.height),
+        })
+        .collect::<Vec<_>>();
+
+    let report = analysis::analyze(&segments, &recording_meta.project_config());
+
+    let analysis_dir = project_path.join("analysis");
+    std::fs::create_dir_all(&analysis_dir).map_err(|e| e.to_string())?;
+
+    let report_path = analysis_dir.join("report.json");
+    std::fs::write(
+        &report_path,
+        serde_json::to_vec_pretty(&report).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("Failed to write report: {e}"))?;
+
+    let heatmap_path = if heatmap {
+        let display_size = segments
+            .first()
+            .map(|s| s.display_size)
+            .unwrap_or(XY::new(1920, 1080));
+        let background = image::open(project_path.join("screenshots/display.jpg"))
+            .map(|img| img.to_rgba8())
+            .ok();
+
+        let path = analysis_dir.join("heatmap.png");
+        render_heatmap(&report.clicks, display_size, background)
+            .save(&path)
+            .map_err(|e| format!("Failed to write heatmap: {e}"))?;
+        Some(path)
+    } else {
+        None
+    };
+
+    Ok(RecordingAnalysisResult {
+        report,
+        report_path,
+        heatmap_path,
+    })
+}
+
+fn render_heatmap(
+    clicks: &[XY<f64>],
+    display_size: XY<u32>,
+    background: Option<RgbaImage>,
+) -> RgbaImage {
+    let mut image = background.unwrap_or_else(|| {
+        let width = display_size.x.clamp(1, HEATMAP_MAX_WIDTH);
+        let height = (width as f32 * display_size.y as f32 / display_size.x.max(1) as f32) as u32;
+        RgbaImage::from_pixel(width, height.max(1), Rgba([24, 24, 27, 255]))
+    });
+    let (width, height) = image.dimensions();
+
+    let radius = (width as f32 * HEATMAP_RADIUS).max(4.0);
+    let sigma_sq_2 = 2.0 * (radius / 2.0).powi(2);
+    let mut heat = vec![0.0_f32; (width * height) as usize];
+
+    for click in clicks {
+        let cx = click.x as f32 * width as f32;
+        let cy = click.y as f32 * height as f32;
+
+        let x_range = (cx - radius).max(0.0) as u32..((cx + radius).ceil() as u32).min(width);
+        let y_range = (cy - radius).max(0.0) as u32..((cy + radius).ceil() as u32).min(height);
+
+        for y in y_range {
+            for x in x_range.clone() {
+                let d_sq = (x as f32 - cx).powi(2) + (y as f32 - cy).powi(2);
+                heat[(y * width + x) as usize] += (-d_sq / sigma_sq_2).exp();
+            }
+        }
+    }
+
+    let max_heat = heat.iter().copied().fold(0.0_f32, f32::max);
+
+    for (pixel, &value) in image.pixels_mut().zip(&heat) {
+        for channel in &mut pixel.0[..3] {
+            *channel = (*channel as f32 * 0.6) as u8;
+        }
+
+        if max_heat <= 0.0 || value <= 0.0 {
+            continue;
+        }
+
+        let t = value / max_heat;
+        let color = heat_color(t);
+        let alpha = t.sqrt() * 0.75;
+        for (channel, target) in pixel.0[..3].iter_mut().zip(color) {
+            *channel = (*channel as f32 * (1.0 - alpha) + target * alpha) as u8;
+        }
+    }
+
+    image
+}
+
+fn heat_color(t: f32) -> [f32; 3] {
+    const STOPS: [[f32; 3]; 4] = [
+        [37.0, 99.0, 235.0],
+        [34.0, 197.0, 94.0],
+        [250.0, 204.0, 21.0],
+        [239.0, 68.0, 68.0],
+    ];
+
+    let scaled = t.clamp(0.0, 1.0) * (STOPS.len() - 1) as f32;
+    let index = (scaled as usize).min(STOPS.len() - 2);
+    let local = scaled - index as f32;
+
+    let (from, to) = (STOPS[index], STOPS[index + 1]);
+    [0, 1, 2].map(|i| from[i] + (to[i] - from[i]) * local)
+}
@@ -0,0 +1,342 @@
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "screen-text")]
+use cap_project::sensitive::TextBounds;
+use cap_project::{
+    RecordingMeta, RecordingMetaInner, StudioRecordingMeta,
+    screen_text::{ScreenTextIndex, ScreenTextSpan},
+    sensitive::ScreenWord,
+};
+use image::GrayImage;
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+#[cfg(feature = "screen-text")]
+use tesseract::Tesseract;
+use tracing::{instrument, warn};
+
+pub(crate) const SAMPLE_INTERVAL_SECS: f64 = 2.0;
+const MAX_FRAME_WIDTH: u32 = 1920;
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingSearchResult {
+    pub path: PathBuf,
+    pub pretty_name: String,
+    pub matches: Vec<ScreenTextSpan>,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+pub async fn index_screen_text(project_path: PathBuf) -> Result<ScreenTextIndex, String> {
+    tokio::task::spawn_blocking(move || index(&project_path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn search_recordings(
+    app: AppHandle,
+    query: String,
+) -> Result<Vec<RecordingSearchResult>, String> {
+    let recordings_dir = crate::recordings_path(&app);
+
+    tokio::task::spawn_blocking(move || {
+        let entries = std::fs::read_dir(&recordings_dir)
+            .map_err(|e| format!("Failed to read recordings directory: {e}"))?;
+
+        Ok(entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| {
+                let index = ScreenTextIndex::load(&path).ok()?;
+                let matches = index
+                    .search(&query)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if matches.is_empty() {
+                    return None;
+                }
+
+                let meta = RecordingMeta::load_for_project(&path).ok()?;
+                Some(RecordingSearchResult {
+                    path,
+                    pretty_name: meta.pretty_name,
+                    matches,
+                })
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+fn index(project_path: &Path) -> Result<ScreenTextIndex, String> {
+    let meta = RecordingMeta::load_for_project(project_path)
+        .map_err(|e| format!("Failed to load recording meta: {e}"))?;
+
+    let mut reader = TextReader::new()?;
+    let mut index = ScreenTextIndex::new(SAMPLE_INTERVAL_SECS);
+    let mut offset = 0.0;
+
+    for video in display_videos(&meta) {
+        let duration = sample_frames(&video, SAMPLE_INTERVAL_SECS, |time, frame| {
+            let text = reader.read(&frame)?;
+            index.add_sample(offset + time, text.lines());
+            Ok(())
+        })?;
+        offset += duration;
+    }
+
+    index
+        .write(project_path)
+        .map_err(|e| format!("Failed to write screen text index: {e}"))?;
+
+    Ok(index)
+}
+
+pub(crate) fn display_videos(meta: &RecordingMeta) -> Vec<PathBuf> {
+    match &meta.inner {
+        RecordingMetaInner::Instant(_) => vec![meta.output_path()],
+        RecordingMetaInner::Studio(studio_meta) => match studio_meta.as_ref() {
+            StudioRecordingMeta::SingleSegment { segment } => {
+                vec![meta.path(&segment.display.path)]
+            }
+            StudioRecordingMeta::MultipleSegments { inner } => inner
+                .segments
+                .iter()
+                .map(|segment| meta.path(&segment.display.path))
+                .collect(),
+        },
+    }
+}
+
+#[cfg(feature = "screen-text")]
+pub(crate) struct TextReader(Option<Tesseract>);
+
+#[cfg(not(feature = "screen-text"))]
+pub(crate) struct TextReader(std::convert::Infallible);
+
+#[cfg(not(feature = "screen-text"))]
+impl TextReader {
+    pub(crate) fn new() -> Result<Self, String> {
+        Err("This build can't read on-screen text".to_string())
+    }
+
+    fn read(&mut self, _frame: &GrayImage) -> Result<String, String> {
+        match self.0 {}
+    }
+
+    pub(crate) fn read_words(
+        &mut self,
+        _frame: &GrayImage,
+    ) -> Result<Vec<Vec<ScreenWord>>, String> {
+        match self.0 {}
+    }
+}
+
+#[cfg(feature = "screen-text")]
+impl TextReader {
+    pub(crate) fn new() -> Result<Self, String> {
+        Tesseract::new(None, Some("eng"))
+            .map(|tesseract| Self(Some(tesseract)))
+            .map_err(|e| format!("Failed to start Tesseract: {e}"))
+    }
+
+    fn read(&mut self, frame: &GrayImage) -> Result<String, String> {
+        self.recognize(frame, |tesseract| {
+            tesseract
+                .get_text()
+                .map_err(|e| format!("Failed to read text: {e}"))
+        })
+    }
+
+    pub(crate) fn read_words(&mut self, frame: &GrayImage) -> Result<Vec<Vec<ScreenWord>>, String> {
+        let tsv = self.recognize(frame, |tesseract| {
+            tesseract
+                .get_tsv_text(0)
+                .map_err(|e| format!("Failed to read text: {e}"))
+        })?;
+        Ok(parse_tsv_words(&tsv, frame.dimensions()))
+    }
+
+    fn recognize<T>(
+        &mut self,
+        frame: &GrayImage,
+        output: impl FnOnce(&mut Tesseract) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let tesseract = self
+            .0
+            .take()
+            .ok_or("Tesseract failed on an earlier frame")?;
+        let (width, height) = frame.dimensions();
+
+        let mut tesseract = tesseract
+            .set_frame(frame.as_raw(), width as i32, height as i32, 1, width as i32)
+            .map_err(|e| format!("Failed to pass frame to Tesseract: {e}"))?
+            .recognize()
+            .map_err(|e| format!("Failed to read text: {e}"))?;
+        let output = output(&mut tesseract)?;
+
+        self.0 = Some(tesseract);
+        Ok(output)
+    }
+}
+
+#[cfg(feature = "screen-text")]
+fn parse_tsv_words(tsv: &str, (width, height): (u32, u32)) -> Vec<Vec<ScreenWord>> {
+    const WORD_LEVEL: &str = "5";
+
+    let mut lines: Vec<Vec<ScreenWord>> = vec![];
+    let mut current_line = None;
+
+    for row in tsv.lines() {
+        let columns = row.split('\t').collect::<Vec<_>>();
+        let [
+            level,
+            _page,
+            block,
+            paragraph,
+            line,
+            _word,
+            left,
+            top,
+            w,
+            h,
+            _conf,
+            text,
+        ] = columns[..]
+        else {
+            continue;
+        };
+        if level != WORD_LEVEL || text.trim().is_empty() {
+            continue;
+        }
+        let (Ok(left), Ok(top), Ok(w), Ok(h)) = (
+            left.parse::<f64>(),
+            top.parse::<f64>(),
+            w.parse::<f64>(),
+            h.parse::<f64>(),
+        ) else {
+            continue;
+        };
+
+        let word = ScreenWord {
+            text: text.trim().to_string(),
+            bounds: TextBounds {
+                x: left / f64::from(width),
+                y: top / f64::from(height),
+                width: w / f64::from(width),
+                height: h / f64::from(height),
+            },
+        };
+
+        let line_id = Some((block, paragraph, line));
+        match lines.last_mut() {
+            Some(words) if current_line == line_id => words.push(word),
+            _ => lines.push(vec![word]),
+        }
+        current_line = line_id;
+    }
+
+    lines
+}
+
+pub(crate) fn sample_frames(
+    path: &Path,
+    interval: f64,
+    mut on_frame: impl FnMut(f64, GrayImage) -> Result<(), String>,
+) -> Result<f64, String> {
+    let mut ictx = ffmpeg::format::input(path)
+        .map_err(|e| format!("Failed to open {}: {e}", path.display()))?;
+    let input_stream = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or("No video stream found")?;
+    let video_stream_index = input_stream.index();
+    let time_base = f64::from(input_stream.time_base());
+    let start_pts = input_stream.start_time().max(0);
+
+    let mut decoder = ffmpeg::codec::context::Context::from_parameters(input_stream.parameters())
+        .map_err(|e| e.to_string())?
+        .decoder()
+        .video()
+        .map_err(|e| e.to_string())?;
+
+    let width = decoder.width().min(MAX_FRAME_WIDTH);
+    let height =
+        (u64::from(decoder.height()) * u64::from(width) / u64::from(decoder.width().max(1))) as u32;
+    let mut scaler = ffmpeg::software::scaling::context::Context::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        ffmpeg::format::Pixel::GRAY8,
+        width,
+        height.max(1),
+        ffmpeg::software::scaling::flag::Flags::BILINEAR,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut next_sample = 0.0;
+    let mut duration: f64 = 0.0;
+    let mut frame = ffmpeg::frame::Video::empty();
+
+    let mut receive_frames = |decoder: &mut ffmpeg::decoder::Video| -> Result<(), String> {
+        while decoder.receive_frame(&mut frame).is_ok() {
+            let Some(pts) = frame.timestamp() else {
+                continue;
+            };
+            let time = (pts - start_pts) as f64 * time_base;
+            duration = duration.max(time);
+            if time < next_sample {
+                continue;
+            }
+            while next_sample <= time {
+                next_sample += interval;
+            }
+
+            let mut gray_frame = ffmpeg::frame::Video::empty();
+            scaler
+                .run(&frame, &mut gray_frame)
+                .map_err(|e| e.to_string())?;
+            on_frame(time, to_gray_image(&gray_frame)?)?;
+        }
+        Ok(())
+    };
+
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != video_stream_index {
+            continue;
+        }
+        if let Err(e) = decoder.send_packet(&packet) {
+            warn!("Skipping undecodable packet in {}: {e}", path.display());
+            continue;
+        }
+        receive_frames(&mut decoder)?;
+    }
+
+    decoder.send_eof().map_err(|e| e.to_string())?;
+    receive_frames(&mut decoder)?;
+
+    Ok(duration)
+}
+
+fn to_gray_image(frame: &ffmpeg::frame::Video) -> Result<GrayImage, String> {
+    let width = frame.width() as usize;
+    let stride = frame.stride(0);
+    let data = frame
+        .data(0)
+        .chunks(stride)
+        .take(frame.height() as usize)
+        .flat_map(|row| &row[..width])
+        .copied()
+        .collect();
+
+    GrayImage::from_raw(frame.width(), frame.height(), data)
+        .ok_or_else(|| "Frame data is smaller than its dimensions".to_string())
+}
@@ -3,20 +3,28 @@ use crate::frame_ws::{WSFrame, create_watch_frame_ws};
 use crate::gpu_context;
 use crate::windows::{CapWindowId, ScreenshotEditorWindowIds};
 use cap_project::{
-    ProjectConfiguration, RecordingMeta, RecordingMetaInner, SingleSegment, StudioRecordingMeta,
-    VideoMeta,
+    BackgroundConfiguration, ProjectConfiguration, RecordingMeta, RecordingMetaInner,
+    SingleSegment, StudioRecordingMeta, VideoMeta,
 };
 use cap_rendering::{
     DecodedFrame, DecodedSegmentFrames, FrameRenderer, ProjectUniforms, RenderVideoConstants,
-    RendererLayers, ZoomFocusInterpolator,
+    RenderedFrame, RendererLayers, RenderingError, SharedWgpuDevice, ZoomFocusInterpolator,
+};
+use image::{
+    GenericImageView, ImageEncoder, RgbImage, buffer::ConvertBuffer, codecs::png::PngEncoder,
 };
-use image::{GenericImageView, RgbImage, buffer::ConvertBuffer};
 use relative_path::RelativePathBuf;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::str::FromStr;
 use std::time::Instant;
-use std::{collections::HashMap, ops::Deref, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    io::Cursor,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use tauri::{
     Manager, Runtime, Window,
     ipc::{CommandArg, InvokeError},
@@ -217,93 +225,12 @@ impl ScreenshotEditorInstances {
                     (None, None)
                 };
 
-                let recording_meta = if let Some(meta) = recording_meta {
-                    meta
-                } else {
-                    // Create dummy meta
-                    let filename = path
-                        .file_name()
-                        .ok_or_else(|| "Invalid path".to_string())?
-                        .to_string_lossy();
-                    let relative_path = RelativePathBuf::from(filename.as_ref());
-                    let video_meta = VideoMeta {
-                        path: relative_path.clone(),
-                        fps: 30,
-                        start_time: Some(0.0),
-                        device_id: None,
-                    };
-                    let segment = SingleSegment {
-                        display: video_meta.clone(),
-                        camera: None,
-                        audio: None,
-                        cursor: None,
-                    };
-                    let studio_meta = StudioRecordingMeta::SingleSegment { segment };
-                    RecordingMeta {
-                        platform: None,
-                        project_path: path.parent().unwrap().to_path_buf(),
-                        pretty_name: "Screenshot".to_string(),
-                        sharing: None,
-                        inner: RecordingMetaInner::Studio(Box::new(studio_meta.clone())),
-                        upload: None,
-                    }
-                };
-
-                let shared = if let Some(gpu) = gpu_context::get_shared_gpu().await {
-                    cap_rendering::SharedWgpuDevice {
-                        instance: (*gpu.instance).clone(),
-                        adapter: (*gpu.adapter).clone(),
-                        device: (*gpu.device).clone(),
-                        queue: (*gpu.queue).clone(),
-                        is_software_adapter: gpu.is_software_adapter,
-                    }
-                } else {
-                    let instance = cap_rendering::create_wgpu_instance().await;
-                    let adapter = instance
-                        .request_adapter(&wgpu::RequestAdapterOptions {
-                            power_preference: wgpu::PowerPreference::HighPerformance,
-                            force_fallback_adapter: false,
-                            compatible_surface: None,
-                        })
-                        .await
-                        .map_err(|_| "No GPU adapter found".to_string())?;
-                    let adapter_info = adapter.get_info();
-                    let is_software_adapter =
-                        cap_rendering::is_software_wgpu_adapter(&adapter_info);
-
-                    let (device, queue) = adapter
-                        .request_device(&wgpu::DeviceDescriptor {
-                            label: Some("cap-rendering-device"),
-                            required_features: wgpu::Features::empty(),
-                            ..Default::default()
-                        })
-                        .await
-                        .map_err(|e| e.to_string())?;
-                    cap_rendering::SharedWgpuDevice {
-                        instance,
-                        adapter,
-                        device,
-                        queue,
-                        is_software_adapter,
-                    }
-                };
-
-                let options = cap_rendering::RenderOptions {
-                    screen_size: cap_project::XY::new(width, height),
-                    camera_size: None,
-                };
-
-                let studio_meta = match &recording_meta.inner {
-                    RecordingMetaInner::Studio(meta) => meta.clone(),
-                    _ => return Err("Invalid recording meta for screenshot".to_string()),
+                let recording_meta = match recording_meta {
+                    Some(meta) => meta,
+                    None => placeholder_meta(&path)?,
                 };
 
-                let constants = RenderVideoConstants::from_shared_device(
-                    shared,
-                    options,
-                    *studio_meta,
-                    recording_meta.clone(),
-                );
+                let constants = screenshot_constants(&recording_meta, width, height).await?;
 
                 let (config_tx, mut config_rx) = watch::channel(loaded_config.unwrap_or_default());
 
@@ -338,48 +265,14 @@ impl ScreenshotEditorInstances {
                         if shutdown_token.is_cancelled() {
                             break;
                         }
-                        let segment_frames = DecodedSegmentFrames {
-                            screen_frame: DecodedFrame::new(
-                                decoded_frame.data().to_vec(),
-                                decoded_frame.width(),
-                                decoded_frame.height(),
-                            ),
-                            camera_frame: None,
-                            segment_time: 0.0,
-                            recording_time: 0.0,
-                        };
-
-                        let (base_w, base_h) =
-                            ProjectUniforms::get_base_size(&constants.options, &current_config);
-
-                        let cursor_events = cap_project::CursorEvents::default();
-                        let zoom_focus_interpolator = ZoomFocusInterpolator::new(
-                            &cursor_events,
-                            None,
-                            current_config.screen_movement_spring,
-                            0.0,
-                        );
-
-                        let uniforms = ProjectUniforms::new(
+                        let rendered_frame = render_screenshot(
                             &constants,
+                            &mut frame_renderer,
+                            &mut layers,
+                            &decoded_frame,
                             &current_config,
-                            0,
-                            30,
-                            cap_project::XY::new(base_w, base_h),
-                            &cursor_events,
-                            &segment_frames,
-                            0.0,
-                            &zoom_focus_interpolator,
-                        );
-
-                        let rendered_frame = frame_renderer
-                            .render_immediate(
-                                segment_frames,
-                                uniforms,
-                                &cap_project::CursorEvents::default(),
-                                &mut layers,
-                            )
-                            .await;
+                        )
+                        .await;
 
                         match rendered_frame {
                             Ok(frame) => {
@@ -440,6 +333,134 @@ impl ScreenshotEditorInstances {
     }
 }
 
+async fn shared_device() -> Result<SharedWgpuDevice, String> {
+    Ok(if let Some(gpu) = gpu_context::get_shared_gpu().await {
+        SharedWgpuDevice {
+            instance: (*gpu.instance).clone(),
+            adapter: (*gpu.adapter).clone(),
+            device: (*gpu.device).clone(),
+            queue: (*gpu.queue).clone(),
+            is_software_adapter: gpu.is_software_adapter,
+        }
+    } else {
+        let instance = cap_rendering::create_wgpu_instance().await;
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                force_fallback_adapter: false,
+                compatible_surface: None,
+            })
+            .await
+            .map_err(|_| "No GPU adapter found".to_string())?;
+        let adapter_info = adapter.get_info();
+        let is_software_adapter = cap_rendering::is_software_wgpu_adapter(&adapter_info);
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("cap-rendering-device"),
+                required_features: wgpu::Features::empty(),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        SharedWgpuDevice {
+            instance,
+            adapter,
+            device,
+            queue,
+            is_software_adapter,
+        }
+    })
+}
+
+async fn screenshot_constants(
+    recording_meta: &RecordingMeta,
+    width: u32,
+    height: u32,
+) -> Result<RenderVideoConstants, String> {
+    let RecordingMetaInner::Studio(studio_meta) = &recording_meta.inner else {
+        return Err("Invalid recording meta for screenshot".to_string());
+    };
+
+    let options = cap_rendering::RenderOptions {
+        screen_size: cap_project::XY::new(width, height),
+        camera_size: None,
+    };
+
+    Ok(RenderVideoConstants::from_shared_device(
+        shared_device().await?,
+        options,
+        (**studio_meta).clone(),
+        recording_meta.clone(),
+    ))
+}
+
+fn placeholder_meta(path: &Path) -> Result<RecordingMeta, String> {
+    let filename = path
+        .file_name()
+        .ok_or_else(|| "Invalid path".to_string())?
+        .to_string_lossy();
+    let relative_path = RelativePathBuf::from(filename.as_ref());
+    let video_meta = VideoMeta {
+        path: relative_path.clone(),
+        fps: 30,
+        start_time: Some(0.0),
+        device_id: None,
+    };
+    let segment = SingleSegment {
+        display: video_meta.clone(),
+        camera: None,
+        audio: None,
+        cursor: None,
+    };
+    let studio_meta = StudioRecordingMeta::SingleSegment { segment };
+    Ok(RecordingMeta {
+        platform: None,
+        project_path: path.parent().unwrap_or(Path::new("")).to_path_buf(),
+        pretty_name: "Screenshot".to_string(),
+        sharing: None,
+        inner: RecordingMetaInner::Studio(Box::new(studio_meta)),
+        upload: None,
+    })
+}
+
+async fn render_screenshot(
+    constants: &RenderVideoConstants,
+    frame_renderer: &mut FrameRenderer<'_>,
+    layers: &mut RendererLayers,
+    frame: &DecodedFrame,
+    config: &ProjectConfiguration,
+) -> Result<RenderedFrame, RenderingError> {
+    let segment_frames = DecodedSegmentFrames {
+        screen_frame: DecodedFrame::new(frame.data().to_vec(), frame.width(), frame.height()),
+        camera_frame: None,
+        segment_time: 0.0,
+        recording_time: 0.0,
+    };
+
+    let (base_w, base_h) = ProjectUniforms::get_base_size(&constants.options, config);
+
+    let cursor_events = cap_project::CursorEvents::default();
+    let zoom_focus_interpolator =
+        ZoomFocusInterpolator::new(&cursor_events, None, config.screen_movement_spring, 0.0);
+
+    let uniforms = ProjectUniforms::new(
+        constants,
+        config,
+        0,
+        30,
+        cap_project::XY::new(base_w, base_h),
+        &cursor_events,
+        &segment_frames,
+        0.0,
+        &zoom_focus_interpolator,
+    );
+
+    frame_renderer
+        .render_immediate(segment_frames, uniforms, &cursor_events, layers)
+        .await
+}
+
 #[derive(Serialize, Type, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SerializedScreenshotEditorInstance {
@@ -515,3 +536,77 @@ pub async fn update_screenshot_config(
     }
     Ok(())
 }
+
+#[derive(Deserialize, Type, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScreenshotSource {
+    Path { path: PathBuf },
+    Bytes { data: Vec<u8> },
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn beautify_screenshot(
+    source: ScreenshotSource,
+    background: BackgroundConfiguration,
+) -> Result<Vec<u8>, String> {
+    let (image, recording_meta) = match source {
+        ScreenshotSource::Path { path } => (
+            image::open(&path).map_err(|e| format!("Failed to open image: {e}"))?,
+            placeholder_meta(&path)?,
+        ),
+        ScreenshotSource::Bytes { data } => (
+            image::load_from_memory(&data).map_err(|e| format!("Failed to decode image: {e}"))?,
+            placeholder_meta(Path::new("screenshot.png"))?,
+        ),
+    };
+
+    let (width, height) = image.dimensions();
+    if width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(format!("Image dimensions exceed maximum: {width}x{height}"));
+    }
+
+    let constants = screenshot_constants(&recording_meta, width, height).await?;
+    let mut frame_renderer = FrameRenderer::new(&constants);
+    let mut layers = RendererLayers::new_with_options(
+        &constants.device,
+        &constants.queue,
+        constants.is_software_adapter,
+    );
+
+    let config = ProjectConfiguration {
+        background,
+        ..Default::default()
+    };
+    let frame = DecodedFrame::new(image.to_rgba8().into_raw(), width, height);
+    let rendered = render_screenshot(
+        &constants,
+        &mut frame_renderer,
+        &mut layers,
+        &frame,
+        &config,
+    )
+    .await
+    .map_err(|e| format!("Failed to render screenshot: {e}"))?;
+
+    let row_len = rendered.width as usize * 4;
+    let rgba = rendered
+        .data
+        .chunks(rendered.padded_bytes_per_row as usize)
+        .take(rendered.height as usize)
+        .flat_map(|row| &row[..row_len])
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut png_data = Cursor::new(Vec::new());
+    PngEncoder::new(&mut png_data)
+        .write_image(
+            &rgba,
+            rendered.width,
+            rendered.height,
+            image::ExtendedColorType::Rgba8,
+        )
+        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+
+    Ok(png_data.into_inner())
+}
@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use cap_project::{
+    MaskSegment, ProjectConfiguration, RecordingMeta,
+    sensitive::{self, SensitiveFinding, SensitiveScan},
+};
+use serde::Serialize;
+use specta::Type;
+use tracing::instrument;
+
+use crate::screen_text::{SAMPLE_INTERVAL_SECS, TextReader, display_videos, sample_frames};
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SensitiveContentScan {
+    pub findings: Vec<SensitiveFinding>,
+    pub masks: Vec<MaskSegment>,
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument]
+pub async fn scan_for_sensitive_content(
+    project_path: PathBuf,
+    create_masks: bool,
+) -> Result<SensitiveContentScan, String> {
+    tokio::task::spawn_blocking(move || scan(&project_path, create_masks))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn scan(project_path: &Path, create_masks: bool) -> Result<SensitiveContentScan, String> {
+    let meta = RecordingMeta::load_for_project(project_path)
+        .map_err(|e| format!("Failed to load recording meta: {e}"))?;
+
+    let mut reader = TextReader::new()?;
+    let mut scan = SensitiveScan::new(SAMPLE_INTERVAL_SECS);
+
+    for (clip, video) in display_videos(&meta).iter().enumerate() {
+        sample_frames(video, SAMPLE_INTERVAL_SECS, |time, frame| {
+            scan.add_sample(clip as u32, time, &reader.read_words(&frame)?);
+            Ok(())
+        })?;
+    }
+
+    let findings = scan.into_findings();
+    let masks = sensitive::blur_masks(&findings, &meta.project_config());
+
+    if create_masks && !masks.is_empty() {
+        add_masks(project_path, &masks)?;
+    }
+
+    Ok(SensitiveContentScan { findings, masks })
+}
+
+fn add_masks(project_path: &Path, masks: &[MaskSegment]) -> Result<(), String> {
+    let mut project = ProjectConfiguration::load(project_path)
+        .map_err(|e| format!("Failed to load project: {e}"))?;
+    let timeline = project
+        .timeline
+        .as_mut()
+        .ok_or("Open the recording in the editor before adding blur masks")?;
+    timeline.mask_segments.extend_from_slice(masks);
+
+    project
+        .write(project_path)
+        .map_err(|e| format!("Failed to save project: {e}"))
+}
@@ -0,0 +1,105 @@
+use tracing::{debug, warn};
+
+pub struct SleepInhibitor {
+    #[cfg(windows)]
+    release_tx: Option<std::sync::mpsc::Sender<()>>,
+    #[cfg(not(windows))]
+    child: Option<std::process::Child>,
+}
+
+impl SleepInhibitor {
+    pub fn acquire(reason: &str) -> Self {
+        let inhibitor = Self::acquire_platform(reason);
+        debug!("Acquired sleep inhibitor: {reason}");
+        inhibitor
+    }
+
+    #[cfg(windows)]
+    fn acquire_platform(_reason: &str) -> Self {
+        use ::windows::Win32::System::Power::{
+            ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED, SetThreadExecutionState,
+        };
+
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+
+        let spawned = std::thread::Builder::new()
+            .name("sleep-inhibitor".to_string())
+            .spawn(move || {
+                let previous = unsafe {
+                    SetThreadExecutionState(
+                        ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED,
+                    )
+                };
+                if previous.0 == 0 {
+                    warn!("SetThreadExecutionState failed");
+                    return;
+                }
+
+                let _ = release_rx.recv();
+
+                unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+            });
+
+        match spawned {
+            Ok(_) => Self {
+                release_tx: Some(release_tx),
+            },
+            Err(e) => {
+                warn!("Failed to spawn sleep inhibitor thread: {e}");
+                Self { release_tx: None }
+            }
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn acquire_platform(_reason: &str) -> Self {
+        let child = std::process::Command::new("/usr/bin/caffeinate")
+            .args(["-d", "-i", "-w", &std::process::id().to_string()])
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| warn!("Failed to start caffeinate: {e}"))
+            .ok();
+
+        Self { child }
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn acquire_platform(reason: &str) -> Self {
+        let child = std::process::Command::new("systemd-inhibit")
+            .args([
+                "--what=idle:sleep",
+                "--who=Drift",
+                &format!("--why={reason}"),
+                "--mode=block",
+                "sleep",
+                "infinity",
+            ])
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| warn!("Failed to start systemd-inhibit: {e}"))
+            .ok();
+
+        Self { child }
+    }
+}
+
+impl Drop for SleepInhibitor {
+    fn drop(&mut self) {
+        #[cfg(windows)]
+        if let Some(tx) = self.release_tx.take() {
+            let _ = tx.send(());
+        }
+
+        #[cfg(not(windows))]
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+
+        debug!("Released sleep inhibitor");
+    }
+}
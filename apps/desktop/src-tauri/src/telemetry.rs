@@ -0,0 +1,241 @@
+use std::{collections::BTreeMap, path::PathBuf, sync::Mutex, time::Duration};
+
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tracing::{debug, warn};
+
+use crate::general_settings::GeneralSettingsStore;
+
+const QUEUE_FILE: &str = "telemetry-queue.json";
+const FLUSH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum Feature {
+    StudioRecording,
+    InstantRecording,
+    Screenshot,
+    Export,
+    Upload,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCategory {
+    Recording,
+    Export,
+    Upload,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryBatch {
+    pub app_version: String,
+    pub os: String,
+    #[serde(default)]
+    pub features: BTreeMap<Feature, u64>,
+    #[serde(default)]
+    pub errors: BTreeMap<ErrorCategory, u64>,
+}
+
+impl TelemetryBatch {
+    fn is_empty(&self) -> bool {
+        self.features.is_empty() && self.errors.is_empty()
+    }
+}
+
+pub trait TelemetrySink: Send + Sync {
+    fn send(&self, batch: TelemetryBatch) -> BoxFuture<'static, Result<(), String>>;
+}
+
+#[cfg(feature = "telemetry")]
+pub struct ServerSink(pub AppHandle);
+
+#[cfg(feature = "telemetry")]
+impl TelemetrySink for ServerSink {
+    fn send(&self, batch: TelemetryBatch) -> BoxFuture<'static, Result<(), String>> {
+        use crate::web_api::ManagerExt;
+
+        let app = self.0.clone();
+        Box::pin(async move {
+            app.api_request("/api/desktop/telemetry", |client, url| {
+                client.post(url).json(&batch)
+            })
+            .await
+            .and_then(|response| response.error_for_status())
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+        })
+    }
+}
+
+pub struct Telemetry {
+    queue: Mutex<TelemetryBatch>,
+    queue_path: Option<PathBuf>,
+    sink: Option<Box<dyn TelemetrySink>>,
+}
+
+impl Telemetry {
+    pub fn new(app: &AppHandle, sink: Option<Box<dyn TelemetrySink>>) -> Self {
+        let queue_path = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join(QUEUE_FILE));
+
+        let mut queue = queue_path
+            .as_ref()
+            .and_then(|path| std::fs::read(path).ok())
+            .and_then(|data| serde_json::from_slice::<TelemetryBatch>(&data).ok())
+            .unwrap_or_default();
+        queue.app_version = env!("CARGO_PKG_VERSION").to_string();
+        queue.os = std::env::consts::OS.to_string();
+
+        Self {
+            queue: Mutex::new(queue),
+            queue_path,
+            sink,
+        }
+    }
+
+    pub fn default_sink(app: &AppHandle) -> Option<Box<dyn TelemetrySink>> {
+        #[cfg(feature = "telemetry")]
+        {
+            Some(Box::new(ServerSink(app.clone())))
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            let _ = app;
+            None
+        }
+    }
+
+    fn record(&self, update: impl FnOnce(&mut TelemetryBatch)) {
+        let Ok(mut queue) = self.queue.lock() else {
+            return;
+        };
+        update(&mut queue);
+        self.persist(&queue);
+    }
+
+    fn persist(&self, queue: &TelemetryBatch) {
+        let Some(path) = &self.queue_path else {
+            return;
+        };
+
+        let result = if queue.is_empty() {
+            match std::fs::remove_file(path) {
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                result => result,
+            }
+        } else {
+            serde_json::to_vec(queue)
+                .map_err(std::io::Error::other)
+                .and_then(|data| std::fs::write(path, data))
+        };
+
+        if let Err(e) = result {
+            warn!("Failed to save telemetry queue: {e}");
+        }
+    }
+
+    fn persist_queue(&self) {
+        if let Ok(queue) = self.queue.lock() {
+            self.persist(&queue);
+        }
+    }
+
+    fn take(&self) -> Option<TelemetryBatch> {
+        let mut queue = self.queue.lock().ok()?;
+        if queue.is_empty() {
+            return None;
+        }
+
+        let batch = queue.clone();
+        queue.features.clear();
+        queue.errors.clear();
+        Some(batch)
+    }
+
+    fn restore(&self, batch: TelemetryBatch) {
+        self.record(|queue| {
+            for (feature, count) in batch.features {
+                *queue.features.entry(feature).or_default() += count;
+            }
+            for (category, count) in batch.errors {
+                *queue.errors.entry(category).or_default() += count;
+            }
+        });
+    }
+
+    fn clear(&self) {
+        if self.take().is_some() {
+            self.persist_queue();
+        }
+    }
+
+    async fn flush(&self) {
+        let Some(sink) = &self.sink else {
+            return;
+        };
+        let Some(batch) = self.take() else {
+            return;
+        };
+
+        match sink.send(batch.clone()).await {
+            Ok(()) => {
+                debug!("Sent telemetry batch");
+                self.persist_queue();
+            }
+            Err(e) => {
+                warn!("Failed to send telemetry: {e}");
+                self.restore(batch);
+            }
+        }
+    }
+}
+
+fn enabled(app: &AppHandle) -> bool {
+    GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()
+        .is_some_and(|settings| settings.telemetry_enabled)
+}
+
+fn with_telemetry(app: &AppHandle, f: impl FnOnce(&Telemetry)) {
+    let Some(telemetry) = app.try_state::<Telemetry>() else {
+        return;
+    };
+    if telemetry.sink.is_some() && enabled(app) {
+        f(&telemetry);
+    }
+}
+
+pub fn feature_used(app: &AppHandle, feature: Feature) {
+    with_telemetry(app, |telemetry| {
+        telemetry.record(|queue| *queue.features.entry(feature).or_default() += 1)
+    });
+}
+
+pub fn error_occurred(app: &AppHandle, category: ErrorCategory) {
+    with_telemetry(app, |telemetry| {
+        telemetry.record(|queue| *queue.errors.entry(category).or_default() += 1)
+    });
+}
+
+pub fn spawn_flusher(app: AppHandle) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(FLUSH_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let telemetry = app.state::<Telemetry>();
+            if enabled(&app) {
+                telemetry.flush().await;
+            } else {
+                telemetry.clear();
+            }
+        }
+    });
+}
@@ -0,0 +1,124 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tauri_specta::Event;
+
+use crate::windows::{CapWindowId, ShowCapWindow};
+
+const MIN_SPEED: u32 = 5;
+const MAX_SPEED: u32 = 300;
+const DEFAULT_SPEED: u32 = 40;
+pub const SPEED_STEP: i32 = 10;
+
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeleprompterState {
+    pub script: String,
+    pub speed: u32,
+    pub playing: bool,
+}
+
+impl Default for TeleprompterState {
+    fn default() -> Self {
+        Self {
+            script: String::new(),
+            speed: DEFAULT_SPEED,
+            playing: false,
+        }
+    }
+}
+
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+pub struct TeleprompterRewind;
+
+#[derive(Default)]
+pub struct Teleprompter(Mutex<TeleprompterState>);
+
+impl Teleprompter {
+    fn update(app: &AppHandle, f: impl FnOnce(&mut TeleprompterState)) -> TeleprompterState {
+        let state = {
+            let mut state = app.state::<Self>().0.lock().unwrap();
+            f(&mut state);
+            state.clone()
+        };
+
+        state.clone().emit(app).ok();
+        state
+    }
+}
+
+pub fn toggle_playing(app: &AppHandle) -> TeleprompterState {
+    Teleprompter::update(app, |state| state.playing = !state.playing)
+}
+
+pub fn adjust_speed(app: &AppHandle, delta: i32) -> TeleprompterState {
+    Teleprompter::update(app, |state| {
+        state.speed = state
+            .speed
+            .saturating_add_signed(delta)
+            .clamp(MIN_SPEED, MAX_SPEED)
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_teleprompter(app: AppHandle) -> TeleprompterState {
+    app.state::<Teleprompter>().0.lock().unwrap().clone()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn show_teleprompter(app: AppHandle, script: Option<String>) -> Result<(), String> {
+    if let Some(script) = script {
+        set_teleprompter_script(app.clone(), script);
+    }
+
+    ShowCapWindow::Teleprompter
+        .show(&app)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn close_teleprompter(app: AppHandle) {
+    Teleprompter::update(&app, |state| state.playing = false);
+
+    if let Some(window) = CapWindowId::Teleprompter.get(&app) {
+        window.close().ok();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_teleprompter_script(app: AppHandle, script: String) -> TeleprompterState {
+    let state = Teleprompter::update(&app, |state| {
+        state.script = script;
+        state.playing = false;
+    });
+    TeleprompterRewind.emit(&app).ok();
+    state
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_teleprompter_speed(app: AppHandle, speed: u32) -> TeleprompterState {
+    Teleprompter::update(&app, |state| {
+        state.speed = speed.clamp(MIN_SPEED, MAX_SPEED)
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_teleprompter_playing(app: AppHandle, playing: bool) -> TeleprompterState {
+    Teleprompter::update(&app, |state| state.playing = playing)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn rewind_teleprompter(app: AppHandle) {
+    TeleprompterRewind.emit(&app).ok();
+}
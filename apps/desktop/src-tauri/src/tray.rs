@@ -1,16 +1,17 @@
 use crate::{
-    NewScreenshotAdded, NewStudioRecordingAdded, RecordingStarted, RecordingStopped,
-    RequestOpenSettings, recording,
+    ExportCompleted, NewScreenshotAdded, NewStudioRecordingAdded, RecordingStarted,
+    RecordingStopped, RequestOpenSettings,
+    recording::{self, RecordingEvent},
     recording_settings::{RecordingSettingsStore, RecordingTargetMode},
     windows::ShowCapWindow,
 };
 use cap_recording::RecordingMode;
 
 use cap_project::{RecordingMeta, RecordingMetaInner};
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::{
     path::PathBuf,
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 use tauri::Manager;
 use tauri::menu::{IconMenuItem, MenuId, PredefinedMenuItem, Submenu};
@@ -25,7 +26,10 @@ use tauri_plugin_opener::OpenerExt;
 use tauri_specta::Event;
 
 const PREVIOUS_ITEM_PREFIX: &str = "previous_item_";
+const RECENT_EXPORT_PREFIX: &str = "recent_export_";
 const MAX_PREVIOUS_ITEMS: usize = 6;
+const MAX_RECENT_EXPORTS: usize = 5;
+const ELAPSED_TICK: Duration = Duration::from_secs(1);
 const MAX_TITLE_LENGTH: usize = 30;
 const THUMBNAIL_SIZE: u32 = 32;
 
@@ -43,6 +47,9 @@ pub enum TrayItem {
     UploadLogs,
     Quit,
     PreviousItem(String),
+    RecentExport(String),
+    StopRecording,
+    TogglePauseRecording,
     ModeStudio,
     ModeInstant,
     ModeScreenshot,
@@ -66,6 +73,11 @@ impl From<TrayItem> for MenuId {
             TrayItem::PreviousItem(id) => {
                 return format!("{PREVIOUS_ITEM_PREFIX}{id}").into();
             }
+            TrayItem::RecentExport(path) => {
+                return format!("{RECENT_EXPORT_PREFIX}{path}").into();
+            }
+            TrayItem::StopRecording => "stop_recording",
+            TrayItem::TogglePauseRecording => "toggle_pause_recording",
             TrayItem::ModeStudio => "mode_studio",
             TrayItem::ModeInstant => "mode_instant",
             TrayItem::ModeScreenshot => "mode_screenshot",
@@ -85,6 +97,10 @@ impl TryFrom<MenuId> for TrayItem {
             return Ok(TrayItem::PreviousItem(path.to_string()));
         }
 
+        if let Some(path) = id_str.strip_prefix(RECENT_EXPORT_PREFIX) {
+            return Ok(TrayItem::RecentExport(path.to_string()));
+        }
+
         match id_str {
             "open_cap" => Ok(TrayItem::OpenCap),
             "record_display" => Ok(TrayItem::RecordDisplay),
@@ -97,6 +113,8 @@ impl TryFrom<MenuId> for TrayItem {
             "open_settings" => Ok(TrayItem::OpenSettings),
             "upload_logs" => Ok(TrayItem::UploadLogs),
             "quit" => Ok(TrayItem::Quit),
+            "stop_recording" => Ok(TrayItem::StopRecording),
+            "toggle_pause_recording" => Ok(TrayItem::TogglePauseRecording),
             "mode_studio" => Ok(TrayItem::ModeStudio),
             "mode_instant" => Ok(TrayItem::ModeInstant),
             "mode_screenshot" => Ok(TrayItem::ModeScreenshot),
@@ -130,6 +148,68 @@ struct CachedPreviousItem {
 #[derive(Default)]
 struct PreviousItemsCache {
     items: Vec<CachedPreviousItem>,
+    exports: Vec<PathBuf>,
+}
+
+#[derive(Default)]
+struct TrayRecordingState {
+    started_at: Option<Instant>,
+    paused_since: Option<Instant>,
+    paused_total: Duration,
+}
+
+impl TrayRecordingState {
+    fn is_recording(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused_since.is_some()
+    }
+
+    fn start(&mut self) {
+        *self = Self {
+            started_at: Some(Instant::now()),
+            ..Default::default()
+        };
+    }
+
+    fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    fn pause(&mut self) {
+        if self.is_recording() && self.paused_since.is_none() {
+            self.paused_since = Some(Instant::now());
+        }
+    }
+
+    fn resume(&mut self) {
+        if let Some(paused_since) = self.paused_since.take() {
+            self.paused_total += paused_since.elapsed();
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        let Some(started_at) = self.started_at else {
+            return Duration::ZERO;
+        };
+
+        let now = self.paused_since.unwrap_or_else(Instant::now);
+        now.saturating_duration_since(started_at)
+            .saturating_sub(self.paused_total)
+    }
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
 }
 
 fn recordings_path(app: &AppHandle) -> PathBuf {
@@ -322,6 +402,46 @@ fn create_previous_submenu(
     Ok(submenu)
 }
 
+fn create_recent_exports_submenu(
+    app: &AppHandle,
+    cache: &PreviousItemsCache,
+) -> tauri::Result<Submenu<tauri::Wry>> {
+    let submenu = Submenu::with_id(
+        app,
+        "recent_exports",
+        "Recent Exports",
+        !cache.exports.is_empty(),
+    )?;
+
+    if cache.exports.is_empty() {
+        submenu.append(&MenuItem::with_id(
+            app,
+            "recent_exports_empty",
+            "No recent exports",
+            false,
+            None::<&str>,
+        )?)?;
+        return Ok(submenu);
+    }
+
+    for path in &cache.exports {
+        let title = path
+            .file_name()
+            .map(|name| truncate_title(&name.to_string_lossy()))
+            .unwrap_or_default();
+
+        submenu.append(&MenuItem::with_id(
+            app,
+            TrayItem::RecentExport(path.to_string_lossy().to_string()),
+            title,
+            true,
+            None::<&str>,
+        )?)?;
+    }
+
+    Ok(submenu)
+}
+
 fn get_current_mode(app: &AppHandle) -> RecordingMode {
     RecordingSettingsStore::get(app)
         .ok()
@@ -364,7 +484,11 @@ fn create_mode_submenu(app: &AppHandle) -> tauri::Result<Submenu<tauri::Wry>> {
     Ok(submenu)
 }
 
-fn build_tray_menu(app: &AppHandle, cache: &PreviousItemsCache) -> tauri::Result<Menu<tauri::Wry>> {
+fn build_tray_menu(
+    app: &AppHandle,
+    cache: &PreviousItemsCache,
+    recording: &TrayRecordingState,
+) -> tauri::Result<Menu<tauri::Wry>> {
     if is_setup_window_open(app) {
         return Menu::with_items(
             app,
@@ -390,12 +514,46 @@ fn build_tray_menu(app: &AppHandle, cache: &PreviousItemsCache) -> tauri::Result
     }
 
     let previous_submenu = create_previous_submenu(app, cache)?;
+    let recent_exports_submenu = create_recent_exports_submenu(app, cache)?;
     let mode_submenu = create_mode_submenu(app)?;
     let current_mode = get_current_mode(app);
     let is_screenshot_mode = current_mode == RecordingMode::Screenshot;
 
     let menu = Menu::new(app)?;
 
+    if recording.is_recording() {
+        menu.append(&MenuItem::with_id(
+            app,
+            "recording_status",
+            if recording.is_paused() {
+                "🔴 Recording (Paused)"
+            } else {
+                "🔴 Recording"
+            },
+            false,
+            None::<&str>,
+        )?)?;
+        menu.append(&MenuItem::with_id(
+            app,
+            TrayItem::StopRecording,
+            "Stop Recording",
+            true,
+            None::<&str>,
+        )?)?;
+        menu.append(&MenuItem::with_id(
+            app,
+            TrayItem::TogglePauseRecording,
+            if recording.is_paused() {
+                "Resume Recording"
+            } else {
+                "Pause Recording"
+            },
+            true,
+            None::<&str>,
+        )?)?;
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+    }
+
     menu.append(&MenuItem::with_id(
         app,
         TrayItem::OpenCap,
@@ -468,6 +626,7 @@ fn build_tray_menu(app: &AppHandle, cache: &PreviousItemsCache) -> tauri::Result
     menu.append(&PredefinedMenuItem::separator(app)?)?;
     menu.append(&mode_submenu)?;
     menu.append(&previous_submenu)?;
+    menu.append(&recent_exports_submenu)?;
     menu.append(&PredefinedMenuItem::separator(app)?)?;
 
     menu.append(&MenuItem::with_id(
@@ -534,9 +693,22 @@ fn add_new_item_to_cache(cache: &Arc<Mutex<PreviousItemsCache>>, app: &AppHandle
     cache_guard.items.truncate(MAX_PREVIOUS_ITEMS);
 }
 
-fn refresh_tray_menu(app: &AppHandle, cache: &Arc<Mutex<PreviousItemsCache>>) {
+fn add_recent_export(cache: &Arc<Mutex<PreviousItemsCache>>, path: PathBuf) {
+    let mut cache_guard = cache.lock().unwrap();
+
+    cache_guard.exports.retain(|p| p != &path);
+    cache_guard.exports.insert(0, path);
+    cache_guard.exports.truncate(MAX_RECENT_EXPORTS);
+}
+
+fn refresh_tray_menu(
+    app: &AppHandle,
+    cache: &Arc<Mutex<PreviousItemsCache>>,
+    recording: &Arc<Mutex<TrayRecordingState>>,
+) {
     let app_clone = app.clone();
     let cache_clone = cache.clone();
+    let recording_clone = recording.clone();
 
     let _ = app.run_on_main_thread(move || {
         let Some(tray) = app_clone.tray_by_id("tray") else {
@@ -544,12 +716,58 @@ fn refresh_tray_menu(app: &AppHandle, cache: &Arc<Mutex<PreviousItemsCache>>) {
         };
 
         let cache_guard = cache_clone.lock().unwrap();
-        if let Ok(menu) = build_tray_menu(&app_clone, &cache_guard) {
+        let recording_guard = recording_clone.lock().unwrap();
+        if let Ok(menu) = build_tray_menu(&app_clone, &cache_guard, &recording_guard) {
             let _ = tray.set_menu(Some(menu));
         }
     });
 }
 
+fn update_recording_indicator(app: &AppHandle, recording: &TrayRecordingState) {
+    let Some(tray) = app.tray_by_id("tray") else {
+        return;
+    };
+
+    let name = &app.package_info().name;
+    if !recording.is_recording() {
+        let _ = tray.set_tooltip(Some(name));
+        let _ = tray.set_title(None::<&str>);
+        return;
+    }
+
+    let elapsed = format_elapsed(recording.elapsed());
+    let (tooltip, title) = if recording.is_paused() {
+        (
+            format!("{name} - Paused ({elapsed})"),
+            format!("⏸ {elapsed}"),
+        )
+    } else {
+        (
+            format!("{name} - Recording ({elapsed})"),
+            format!("🔴 {elapsed}"),
+        )
+    };
+
+    let _ = tray.set_tooltip(Some(tooltip));
+    let _ = tray.set_title(Some(title));
+}
+
+fn spawn_elapsed_ticker(app: AppHandle, recording: Arc<Mutex<TrayRecordingState>>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ELAPSED_TICK);
+
+        loop {
+            interval.tick().await;
+
+            let recording_guard = recording.lock().unwrap();
+            if !recording_guard.is_recording() {
+                break;
+            }
+            update_recording_indicator(&app, &recording_guard);
+        }
+    });
+}
+
 fn handle_previous_item_click(app: &AppHandle, path_str: &str) {
     let path = PathBuf::from(path_str);
 
@@ -635,6 +853,7 @@ fn handle_mode_selection(
     app: &AppHandle,
     mode: RecordingMode,
     cache: &Arc<Mutex<PreviousItemsCache>>,
+    recording: &Arc<Mutex<TrayRecordingState>>,
 ) {
     if let Err(e) = RecordingSettingsStore::set_mode(app, mode) {
         tracing::error!("Failed to set recording mode: {e}");
@@ -642,19 +861,23 @@ fn handle_mode_selection(
     }
 
     update_tray_icon_for_mode(app, mode);
-    refresh_tray_menu(app, cache);
+    refresh_tray_menu(app, cache, recording);
 }
 
 pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
     let items = load_all_previous_items(app, false);
-    let cache = Arc::new(Mutex::new(PreviousItemsCache { items }));
+    let cache = Arc::new(Mutex::new(PreviousItemsCache {
+        items,
+        exports: Vec::new(),
+    }));
+    let recording_state = Arc::new(Mutex::new(TrayRecordingState::default()));
 
     let menu = {
         let cache_guard = cache.lock().unwrap();
-        build_tray_menu(app, &cache_guard)?
+        let recording_guard = recording_state.lock().unwrap();
+        build_tray_menu(app, &cache_guard, &recording_guard)?
     };
     let app = app.clone();
-    let is_recording = Arc::new(AtomicBool::new(false));
 
     let current_mode = get_current_mode(&app);
     let initial_icon = Image::from_bytes(get_mode_icon(current_mode))?;
@@ -667,6 +890,7 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
         .on_menu_event({
             let app_handle = app.clone();
             let cache = cache.clone();
+            let recording_state = recording_state.clone();
             move |app: &AppHandle, event| match TrayItem::try_from(event.id) {
                 Ok(TrayItem::OpenCap) => {
                     let app = app.clone();
@@ -797,14 +1021,29 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
                 Ok(TrayItem::PreviousItem(path)) => {
                     handle_previous_item_click(app, &path);
                 }
+                Ok(TrayItem::RecentExport(path)) => {
+                    let _ = app.opener().reveal_item_in_dir(PathBuf::from(path));
+                }
+                Ok(TrayItem::StopRecording) => {
+                    let app = app.clone();
+                    tokio::spawn(async move {
+                        let _ = recording::stop_recording(app.clone(), app.state()).await;
+                    });
+                }
+                Ok(TrayItem::TogglePauseRecording) => {
+                    let app = app.clone();
+                    tokio::spawn(async move {
+                        let _ = recording::toggle_pause_recording(app.clone(), app.state()).await;
+                    });
+                }
                 Ok(TrayItem::ModeStudio) => {
-                    handle_mode_selection(app, RecordingMode::Studio, &cache);
+                    handle_mode_selection(app, RecordingMode::Studio, &cache, &recording_state);
                 }
                 Ok(TrayItem::ModeInstant) => {
-                    handle_mode_selection(app, RecordingMode::Instant, &cache);
+                    handle_mode_selection(app, RecordingMode::Instant, &cache, &recording_state);
                 }
                 Ok(TrayItem::ModeScreenshot) => {
-                    handle_mode_selection(app, RecordingMode::Screenshot, &cache);
+                    handle_mode_selection(app, RecordingMode::Screenshot, &cache, &recording_state);
                 }
                 Ok(TrayItem::RequestPermissions) => {
                     let app = app.clone();
@@ -816,11 +1055,11 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
             }
         })
         .on_tray_icon_event({
-            let is_recording = Arc::clone(&is_recording);
+            let recording_state = Arc::clone(&recording_state);
             let app_handle = app.clone();
             move |tray, event| {
                 if let tauri::tray::TrayIconEvent::Click { .. } = event {
-                    if is_recording.load(Ordering::Relaxed) {
+                    if recording_state.lock().unwrap().is_recording() {
                         let app = app_handle.clone();
                         tokio::spawn(async move {
                             let _ = recording::stop_recording(app.clone(), app.state()).await;
@@ -836,6 +1075,7 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
     {
         let app_clone = app.clone();
         let cache_clone = cache.clone();
+        let recording_clone = recording_state.clone();
         std::thread::spawn(move || {
             let screenshots_dir = screenshots_path(&app_clone);
             let items_needing_thumbnails: Vec<PathBuf> = {
@@ -863,24 +1103,18 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
                 }
             }
 
-            let app_for_refresh = app_clone.clone();
-            let cache_for_refresh = cache_clone.clone();
-            let _ = app_clone.run_on_main_thread(move || {
-                if let Some(tray) = app_for_refresh.tray_by_id("tray") {
-                    let cache_guard = cache_for_refresh.lock().unwrap();
-                    if let Ok(menu) = build_tray_menu(&app_for_refresh, &cache_guard) {
-                        let _ = tray.set_menu(Some(menu));
-                    }
-                }
-            });
+            refresh_tray_menu(&app_clone, &cache_clone, &recording_clone);
         });
     }
 
     RecordingStarted::listen_any(&app, {
         let app = app.clone();
-        let is_recording = is_recording.clone();
+        let cache = cache.clone();
+        let recording_state = recording_state.clone();
         move |_| {
-            is_recording.store(true, Ordering::Relaxed);
+            recording_state.lock().unwrap().start();
+            spawn_elapsed_ticker(app.clone(), recording_state.clone());
+            refresh_tray_menu(&app, &cache, &recording_state);
 
             if cfg!(target_os = "windows") {
                 return;
@@ -899,9 +1133,15 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
 
     RecordingStopped::listen_any(&app, {
         let app_handle = app.clone();
-        let is_recording = is_recording.clone();
+        let cache = cache.clone();
+        let recording_state = recording_state.clone();
         move |_| {
-            is_recording.store(false, Ordering::Relaxed);
+            {
+                let mut recording_guard = recording_state.lock().unwrap();
+                recording_guard.stop();
+                update_recording_indicator(&app_handle, &recording_guard);
+            }
+            refresh_tray_menu(&app_handle, &cache, &recording_state);
 
             if cfg!(target_os = "windows") {
                 return;
@@ -919,18 +1159,48 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
         }
     });
 
+    RecordingEvent::listen_any(&app, {
+        let app_handle = app.clone();
+        let cache = cache.clone();
+        let recording_state = recording_state.clone();
+        move |event| {
+            {
+                let mut recording_guard = recording_state.lock().unwrap();
+                match event.payload {
+                    RecordingEvent::Paused => recording_guard.pause(),
+                    RecordingEvent::Resumed => recording_guard.resume(),
+                    _ => return,
+                }
+                update_recording_indicator(&app_handle, &recording_guard);
+            }
+            refresh_tray_menu(&app_handle, &cache, &recording_state);
+        }
+    });
+
     NewStudioRecordingAdded::listen_any(&app, {
         let app_handle = app.clone();
         let cache_clone = cache.clone();
+        let recording_clone = recording_state.clone();
         move |event| {
             add_new_item_to_cache(&cache_clone, &app_handle, event.payload.path.clone());
-            refresh_tray_menu(&app_handle, &cache_clone);
+            refresh_tray_menu(&app_handle, &cache_clone, &recording_clone);
+        }
+    });
+
+    ExportCompleted::listen_any(&app, {
+        let app_handle = app.clone();
+        let cache_clone = cache.clone();
+        let recording_clone = recording_state.clone();
+        move |event| {
+            add_recent_export(&cache_clone, event.payload.path.clone());
+            refresh_tray_menu(&app_handle, &cache_clone, &recording_clone);
         }
     });
 
     NewScreenshotAdded::listen_any(&app, {
         let app_handle = app.clone();
         let cache_clone = cache.clone();
+        let recording_clone = recording_state.clone();
         move |event| {
             let path = if event.payload.path.extension().and_then(|s| s.to_str()) == Some("png") {
                 event.payload.path.parent().map(|p| p.to_path_buf())
@@ -940,7 +1210,7 @@ pub fn create_tray(app: &AppHandle) -> tauri::Result<()> {
 
             if let Some(path) = path {
                 add_new_item_to_cache(&cache_clone, &app_handle, path);
-                refresh_tray_menu(&app_handle, &cache_clone);
+                refresh_tray_menu(&app_handle, &cache_clone, &recording_clone);
             }
         }
     });
@@ -0,0 +1,112 @@
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager, Url};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tauri_specta::Event;
+use tokio::sync::Mutex;
+use tracing::{info, instrument};
+
+use crate::general_settings::{GeneralSettingsStore, UpdateChannel};
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableUpdate {
+    pub version: String,
+    pub current_version: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Type, tauri_specta::Event, Debug, Clone)]
+pub struct UpdateDownloadProgress {
+    pub progress: f64,
+}
+
+fn beta_endpoints(app: &AppHandle) -> Vec<Url> {
+    app.config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|updater| updater.get("betaEndpoints"))
+        .and_then(|endpoints| serde_json::from_value(endpoints.clone()).ok())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn has_beta_channel(app: AppHandle) -> bool {
+    !beta_endpoints(&app).is_empty()
+}
+
+#[derive(Default)]
+pub struct PendingUpdate(Mutex<Option<Update>>);
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn check_for_updates(app: AppHandle) -> Result<Option<AvailableUpdate>, String> {
+    let channel = GeneralSettingsStore::get(&app)
+        .ok()
+        .flatten()
+        .map(|settings| settings.update_channel)
+        .unwrap_or_default();
+
+    let mut builder = app.updater_builder();
+    let beta_endpoints = beta_endpoints(&app);
+    if channel == UpdateChannel::Beta && !beta_endpoints.is_empty() {
+        builder = builder
+            .endpoints(beta_endpoints)
+            .map_err(|e| e.to_string())?;
+    }
+
+    let update = builder
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {e}"))?;
+
+    let available = update.as_ref().map(|update| AvailableUpdate {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        notes: update.body.clone(),
+    });
+
+    if let Some(available) = &available {
+        info!(?channel, version = available.version, "Update available");
+    }
+
+    *app.state::<PendingUpdate>().0.lock().await = update;
+
+    Ok(available)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[instrument(skip(app))]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let Some(update) = app.state::<PendingUpdate>().0.lock().await.take() else {
+        return Err("No update to install".to_string());
+    };
+
+    let mut downloaded = 0u64;
+    update
+        .download_and_install(
+            |chunk_length, content_length| {
+                downloaded += chunk_length as u64;
+                if let Some(total) = content_length.filter(|total| *total > 0) {
+                    UpdateDownloadProgress {
+                        progress: (downloaded as f64 / total as f64).min(1.0),
+                    }
+                    .emit(&app)
+                    .ok();
+                }
+            },
+            || info!("Update downloaded"),
+        )
+        .await
+        .map_err(|e| format!("Failed to install update: {e}"))?;
+
+    info!(version = update.version, "Update installed");
+
+    Ok(())
+}
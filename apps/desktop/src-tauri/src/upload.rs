@@ -5,6 +5,7 @@ use crate::{
     api::{self, PresignedS3PutRequest, PresignedS3PutRequestMethod, S3VideoMeta, UploadedPart},
     http_client::{HttpClient, RetryableHttpClient},
     posthog::{PostHogEvent, async_capture_event},
+    telemetry::{self, ErrorCategory, Feature},
     web_api::{AuthedApiError, ManagerExt},
 };
 use async_stream::{stream, try_stream};
@@ -146,6 +147,11 @@ pub async fn upload_video(
 
     emit_upload_complete(app, &video_id);
 
+    match &video_result {
+        Ok(_) => telemetry::feature_used(app, Feature::Upload),
+        Err(_) => telemetry::error_occurred(app, ErrorCategory::Upload),
+    }
+
     async_capture_event(match &video_result {
         Ok(meta) => PostHogEvent::MultipartUploadComplete {
             duration: start.elapsed(),
@@ -0,0 +1,158 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKey {
+    #[serde(rename = "export.renderingFrame")]
+    ExportRenderingFrame,
+    #[serde(rename = "export.cancelled")]
+    ExportCancelled,
+    #[serde(rename = "export.notEnoughSpace")]
+    ExportNotEnoughSpace,
+    #[serde(rename = "export.decodeFailed")]
+    ExportDecodeFailed,
+    #[serde(rename = "export.prepareOutputFailed")]
+    ExportPrepareOutputFailed,
+    #[serde(rename = "export.finalizeFailed")]
+    ExportFinalizeFailed,
+    #[serde(rename = "export.failed")]
+    ExportFailed,
+    #[serde(rename = "export.renderDownscaled")]
+    ExportRenderDownscaled,
+    #[serde(rename = "export.requiresLicense")]
+    ExportRequiresLicense,
+    #[serde(rename = "export.preExportCommandFailed")]
+    ExportPreExportCommandFailed,
+    #[serde(rename = "export.encoderUnavailable")]
+    ExportEncoderUnavailable,
+    #[serde(rename = "export.outputNotWritable")]
+    ExportOutputNotWritable,
+    #[serde(rename = "export.emptyTimeline")]
+    ExportEmptyTimeline,
+    #[serde(rename = "export.resolutionCompatibility")]
+    ExportResolutionCompatibility,
+    #[serde(rename = "export.gifFrameRate")]
+    ExportGifFrameRate,
+    #[serde(rename = "export.paused")]
+    ExportPaused,
+    #[serde(rename = "export.pausedByUser")]
+    ExportPausedByUser,
+}
+
+impl MessageKey {
+    fn english(self) -> &'static str {
+        match self {
+            Self::ExportRenderingFrame => "Rendering frame {current}/{total}",
+            Self::ExportCancelled => "Export cancelled",
+            Self::ExportNotEnoughSpace => {
+                "Not enough disk space to export: {availableMb}MB available, about {requiredMb}MB needed"
+            }
+            Self::ExportDecodeFailed => {
+                "Failed to decode video frames. The recording may be corrupted or incomplete. Try re-recording or contact support if the issue persists."
+            }
+            Self::ExportPrepareOutputFailed => "Failed to prepare export output: {detail}",
+            Self::ExportFinalizeFailed => "Failed to finalize export: {detail}",
+            Self::ExportFailed => "Failed to export recording: {detail}",
+            Self::ExportRenderDownscaled => {
+                "Your GPU is short on memory, so frames were rendered at {renderWidth}x{renderHeight} and upscaled to {outputWidth}x{outputHeight}"
+            }
+            Self::ExportRequiresLicense => {
+                "Exporting above 1440p requires a license. Activate one in Settings to continue."
+            }
+            Self::ExportPreExportCommandFailed => "Pre-export command failed: {detail}",
+            Self::ExportEncoderUnavailable => {
+                "No {codec} encoder is available, so this export can't be encoded"
+            }
+            Self::ExportOutputNotWritable => "Can't write the export to {path}: {detail}",
+            Self::ExportEmptyTimeline => "There's nothing to export: the timeline is empty",
+            Self::ExportResolutionCompatibility => {
+                "{width}x{height} is larger than many players support for H.264 video, so the export may not play everywhere"
+            }
+            Self::ExportGifFrameRate => {
+                "Most viewers play GIFs above {maxFps} fps slower than they should"
+            }
+            Self::ExportPaused => {
+                "Paused at frame {current}/{total} while you're using your computer"
+            }
+            Self::ExportPausedByUser => "Paused at frame {current}/{total}",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum MessageParam {
+    Number(f64),
+    Text(String),
+}
+
+impl fmt::Display for MessageParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+impl From<u32> for MessageParam {
+    fn from(value: u32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<u64> for MessageParam {
+    fn from(value: u64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<String> for MessageParam {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for MessageParam {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone, PartialEq)]
+pub struct UserMessage {
+    pub key: MessageKey,
+    pub params: BTreeMap<String, MessageParam>,
+    pub text: String,
+}
+
+impl UserMessage {
+    pub fn new(key: MessageKey) -> Self {
+        Self {
+            key,
+            params: BTreeMap::new(),
+            text: key.english().to_string(),
+        }
+    }
+
+    pub fn with(mut self, name: &str, value: impl Into<MessageParam>) -> Self {
+        let value = value.into();
+        self.text = self
+            .text
+            .replace(&format!("{{{name}}}"), &value.to_string());
+        self.params.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn export_failed(detail: impl fmt::Display) -> Self {
+        Self::new(MessageKey::ExportFailed).with("detail", detail.to_string())
+    }
+}
+
+impl fmt::Display for UserMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::{AppHandle, Manager};
+use tracing::{debug, warn};
+
+use crate::{general_settings::GeneralSettingsStore, http_client::HttpClient};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Default, Serialize, Deserialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookUrls {
+    #[serde(default)]
+    pub recording_stopped: Option<String>,
+    #[serde(default)]
+    pub export_complete: Option<String>,
+    #[serde(default)]
+    pub export_failed: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+enum WebhookEvent {
+    RecordingStopped,
+    ExportComplete,
+    ExportFailed,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct WebhookPayload {
+    event: WebhookEvent,
+    app_version: String,
+    path: PathBuf,
+    duration_secs: Option<f64>,
+    size_bytes: Option<u64>,
+    error: Option<String>,
+}
+
+impl WebhookPayload {
+    fn new(app: &AppHandle, event: WebhookEvent, path: PathBuf) -> Self {
+        Self {
+            event,
+            app_version: app.package_info().version.to_string(),
+            path,
+            duration_secs: None,
+            size_bytes: None,
+            error: None,
+        }
+    }
+}
+
+pub fn recording_stopped(app: &AppHandle, project_path: &Path) {
+    let Some(url) = url_for(app, |urls| urls.recording_stopped) else {
+        return;
+    };
+
+    let app = app.clone();
+    let project_path = project_path.to_path_buf();
+    tokio::spawn(async move {
+        let mut payload =
+            WebhookPayload::new(&app, WebhookEvent::RecordingStopped, project_path.clone());
+        payload.size_bytes = size_on_disk(&project_path);
+        payload.duration_secs = crate::get_video_metadata(project_path)
+            .await
+            .map(|metadata| metadata.duration)
+            .ok();
+        send(&app, &url, payload).await;
+    });
+}
+
+pub fn export_complete(app: &AppHandle, path: &Path, duration_secs: f64) {
+    let Some(url) = url_for(app, |urls| urls.export_complete) else {
+        return;
+    };
+
+    let mut payload = WebhookPayload::new(app, WebhookEvent::ExportComplete, path.to_path_buf());
+    payload.duration_secs = Some(duration_secs);
+    payload.size_bytes = size_on_disk(path);
+
+    let app = app.clone();
+    tokio::spawn(async move { send(&app, &url, payload).await });
+}
+
+pub fn export_failed(app: &AppHandle, project_path: &Path, error: &str) {
+    let Some(url) = url_for(app, |urls| urls.export_failed) else {
+        return;
+    };
+
+    let mut payload =
+        WebhookPayload::new(app, WebhookEvent::ExportFailed, project_path.to_path_buf());
+    payload.error = Some(error.to_string());
+
+    let app = app.clone();
+    tokio::spawn(async move { send(&app, &url, payload).await });
+}
+
+fn url_for(app: &AppHandle, url: impl FnOnce(WebhookUrls) -> Option<String>) -> Option<String> {
+    GeneralSettingsStore::get(app)
+        .ok()
+        .flatten()
+        .and_then(|s| url(s.webhooks))
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+}
+
+async fn send(app: &AppHandle, url: &str, payload: WebhookPayload) {
+    let event = payload.event;
+    let result = app
+        .state::<HttpClient>()
+        .post(url)
+        .timeout(REQUEST_TIMEOUT)
+        .json(&payload)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status());
+
+    match result {
+        Ok(_) => debug!(?event, "Sent webhook"),
+        Err(e) => warn!(?event, "Failed to send webhook: {e}"),
+    }
+}
+
+fn size_on_disk(path: &Path) -> Option<u64> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return Some(metadata.len());
+    }
+
+    Some(
+        std::fs::read_dir(path)
+            .ok()?
+            .flatten()
+            .filter_map(|entry| size_on_disk(&entry.path()))
+            .sum(),
+    )
+}
@@ -369,6 +369,7 @@ pub enum CapWindowId {
     ModeSelect,
     Debug,
     ScreenshotEditor { id: u32 },
+    Teleprompter,
 }
 
 impl FromStr for CapWindowId {
@@ -387,6 +388,7 @@ impl FromStr for CapWindowId {
             "upgrade" => Self::Upgrade,
             "mode-select" => Self::ModeSelect,
             "debug" => Self::Debug,
+            "teleprompter" => Self::Teleprompter,
             s if s.starts_with("editor-") => Self::Editor {
                 id: s
                     .replace("editor-", "")
@@ -437,6 +439,7 @@ impl std::fmt::Display for CapWindowId {
             Self::Editor { id } => write!(f, "editor-{id}"),
             Self::Debug => write!(f, "debug"),
             Self::ScreenshotEditor { id } => write!(f, "screenshot-editor-{id}"),
+            Self::Teleprompter => write!(f, "teleprompter"),
         }
     }
 }
@@ -459,6 +462,7 @@ impl CapWindowId {
             Self::Camera => "Drift Camera".to_string(),
             Self::RecordingsOverlay => "Drift Recordings".to_string(),
             Self::TargetSelectOverlay { .. } => "Drift Target Select".to_string(),
+            Self::Teleprompter => "Drift Teleprompter".to_string(),
             _ => "Drift".to_string(),
         }
     }
@@ -506,7 +510,8 @@ impl CapWindowId {
             | Self::CaptureArea
             | Self::RecordingsOverlay
             | Self::RecordingControls
-            | Self::TargetSelectOverlay { .. } => None,
+            | Self::TargetSelectOverlay { .. }
+            | Self::Teleprompter => None,
             _ => Some(None),
         }
     }
@@ -521,6 +526,7 @@ impl CapWindowId {
             Self::Camera => (200.0, 200.0),
             Self::Upgrade => (950.0, 850.0),
             Self::ModeSelect => (580.0, 340.0),
+            Self::Teleprompter => (320.0, 160.0),
             _ => return None,
         })
     }
@@ -560,6 +566,7 @@ pub enum ShowCapWindow {
     ScreenshotEditor {
         path: PathBuf,
     },
+    Teleprompter,
 }
 
 impl ShowCapWindow {
@@ -1998,6 +2005,26 @@ impl ShowCapWindow {
 
                 fake_window::spawn_fake_window_listener(app.clone(), window.clone());
 
+                window
+            }
+            Self::Teleprompter => {
+                let window = self
+                    .window_builder(app, "/teleprompter")
+                    .inner_size(560.0, 240.0)
+                    .maximized(false)
+                    .maximizable(false)
+                    .fullscreen(false)
+                    .always_on_top(true)
+                    .visible_on_all_workspaces(true)
+                    .content_protected(true)
+                    .skip_taskbar(true)
+                    .focused(false)
+                    .build()?;
+
+                let (pos_x, _) = cursor_monitor.center_position(560.0, 240.0);
+                let _ = window
+                    .set_position(tauri::LogicalPosition::new(pos_x, cursor_monitor.y + 48.0));
+
                 window
             }
         };
@@ -2104,6 +2131,7 @@ impl ShowCapWindow {
             ShowCapWindow::InProgressRecording { .. } => CapWindowId::RecordingControls,
             ShowCapWindow::Upgrade => CapWindowId::Upgrade,
             ShowCapWindow::ModeSelect => CapWindowId::ModeSelect,
+            ShowCapWindow::Teleprompter => CapWindowId::Teleprompter,
             ShowCapWindow::ScreenshotEditor { path } => {
                 let state = app.state::<ScreenshotEditorWindowIds>();
                 let s = state.ids.lock().unwrap();
@@ -2192,6 +2220,12 @@ fn position_traffic_lights_impl(
 }
 
 fn should_protect_window(app: &AppHandle<Wry>, window_title: &str) -> bool {
+    if window_title == CapWindowId::RecordingControls.title()
+        || window_title == CapWindowId::Teleprompter.title()
+    {
+        return true;
+    }
+
     let matches = |list: &[WindowExclusion]| {
         list.iter()
             .any(|entry| entry.matches(None, None, Some(window_title)))
@@ -2315,6 +2349,18 @@ pub fn refresh_window_content_protection(app: AppHandle<Wry>) -> Result<(), Stri
     Ok(())
 }
 
+pub fn is_window_on_display(window: &WebviewWindow, display: &Display) -> bool {
+    let (Ok(outer_pos), Ok(outer_size), Ok(scale_factor)) = (
+        window.outer_position(),
+        window.outer_size(),
+        window.scale_factor(),
+    ) else {
+        return false;
+    };
+
+    display.intersects(outer_pos, outer_size, scale_factor)
+}
+
 // Credits: tauri-plugin-window-state
 trait MonitorExt {
     fn intersects(
@@ -2409,6 +2455,15 @@ impl EditorWindowIds {
     pub fn get(app: &AppHandle) -> Self {
         app.state::<EditorWindowIds>().deref().clone()
     }
+
+    pub fn project_path(&self, id: u32) -> Option<PathBuf> {
+        self.ids
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, window_id)| *window_id == id)
+            .map(|(path, _)| path.clone())
+    }
 }
 
 #[derive(Default, Clone)]
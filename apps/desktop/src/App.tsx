@@ -65,6 +65,7 @@ const ModeSelectPage = lazy(() => import("./routes/mode-select"));
 const NotificationsPage = lazy(() => import("./routes/notifications"));
 const RecordingsOverlayPage = lazy(() => import("./routes/recordings-overlay"));
 const ScreenshotEditorPage = lazy(() => import("./routes/screenshot-editor"));
+const TeleprompterPage = lazy(() => import("./routes/teleprompter"));
 const TargetSelectOverlayPage = lazy(
 	() => import("./routes/target-select-overlay"),
 );
@@ -191,6 +192,7 @@ function Inner() {
 						path="/target-select-overlay"
 						component={TargetSelectOverlayPage}
 					/>
+					<Route path="/teleprompter" component={TeleprompterPage} />
 					<Route
 						path="/window-capture-occluder"
 						component={WindowCaptureOccluderPage}
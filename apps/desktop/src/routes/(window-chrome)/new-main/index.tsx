@@ -11,7 +11,6 @@ import { getCurrentWindow, LogicalSize } from "@tauri-apps/api/window";
 import * as dialog from "@tauri-apps/plugin-dialog";
 import { type as ostype } from "@tauri-apps/plugin-os";
 import * as shell from "@tauri-apps/plugin-shell";
-import * as updater from "@tauri-apps/plugin-updater";
 import { cx } from "cva";
 import {
 	createEffect,
@@ -46,6 +45,7 @@ import {
 	listWindowsWithThumbnails,
 } from "~/utils/queries";
 import {
+	type AvailableUpdate,
 	type CaptureDisplay,
 	type CaptureDisplayWithThumbnail,
 	type CaptureWindow,
@@ -912,9 +912,9 @@ function createUpdateCheck() {
 
 		await new Promise((res) => setTimeout(res, 1000));
 
-		let update: updater.Update | undefined;
+		let update: AvailableUpdate | undefined;
 		try {
-			const result = await updater.check();
+			const result = await commands.checkForUpdates();
 			if (result) update = result;
 		} catch (e) {
 			console.error("Failed to check for updates:", e);
@@ -1052,6 +1052,13 @@ function Page() {
 	const screens = useQuery(() => listScreens);
 	const windows = useQuery(() => listWindows);
 
+	createTauriEventListener(events.displaysChanged, ({ displays }) => {
+		queryClient.setQueryData(listScreens.queryKey, displays);
+		queryClient.invalidateQueries({
+			queryKey: listDisplaysWithThumbnails.queryKey,
+		});
+	});
+
 	const hasDisplayTargetsData = () => displayTargets.status === "success";
 	const hasWindowTargetsData = () => windowTargets.status === "success";
 
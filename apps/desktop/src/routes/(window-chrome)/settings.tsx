@@ -45,6 +45,11 @@ export default function Settings(props: RouteSectionProps) {
 								name: "Screenshots",
 								icon: IconLucideImage,
 							},
+							{
+								href: "brand-kit",
+								name: "Brand Kit",
+								icon: IconLucidePalette,
+							},
 							{
 								href: "integrations",
 								name: "Integrations",
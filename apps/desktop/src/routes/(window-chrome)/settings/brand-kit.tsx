@@ -0,0 +1,233 @@
+import { Button } from "@cap/ui-solid";
+import { CheckMenuItem, Menu } from "@tauri-apps/api/menu";
+import * as dialog from "@tauri-apps/plugin-dialog";
+import { createResource, For, type ParentProps, Show } from "solid-js";
+import toast from "solid-toast";
+import { Input } from "~/routes/editor/ui";
+import {
+	type BrandKit,
+	type BrandWatermark,
+	commands,
+	type WatermarkCorner,
+} from "~/utils/tauri";
+import { SettingItem, ToggleSettingItem } from "./Setting";
+
+const CORNERS: { text: string; value: WatermarkCorner }[] = [
+	{ text: "Top left", value: "topLeft" },
+	{ text: "Top right", value: "topRight" },
+	{ text: "Bottom left", value: "bottomLeft" },
+	{ text: "Bottom right", value: "bottomRight" },
+];
+
+export default function BrandKitSettings() {
+	const [kit, { mutate }] = createResource(() => commands.getBrandKit());
+
+	const update = async (change: Promise<BrandKit>) => {
+		try {
+			mutate(await change);
+		} catch (error) {
+			toast.error(String(error));
+		}
+	};
+
+	const pickFile = async (name: string, extensions: string[]) => {
+		const path = await dialog.open({
+			filters: [{ name, extensions }],
+			multiple: false,
+		});
+		return typeof path === "string" ? path : null;
+	};
+
+	const setWatermark = (change: Partial<BrandWatermark>) =>
+		update(commands.setBrandWatermark({ ...kit()?.watermark, ...change }));
+
+	return (
+		<div class="flex flex-col h-full custom-scroll">
+			<div class="p-4 space-y-6">
+				<div class="flex flex-col pb-4 border-b border-gray-2">
+					<h2 class="text-lg font-medium text-gray-12">Brand Kit</h2>
+					<p class="text-sm text-gray-10">
+						Logos, fonts and colors kept in one place. Fonts are available to
+						text and title cards by family name, and the watermark is added to
+						every export.
+					</p>
+				</div>
+
+				<Show when={kit()}>
+					{(kit) => (
+						<>
+							<Group title="Logos">
+								<For each={kit().logos ?? []}>
+									{(logo) => (
+										<SettingItem label={logo.name} description={logo.path}>
+											<Button
+												size="sm"
+												variant="gray"
+												onClick={() =>
+													update(commands.removeBrandAsset(logo.id))
+												}
+											>
+												Remove
+											</Button>
+										</SettingItem>
+									)}
+								</For>
+								<SettingItem
+									label="Add logo"
+									description="PNG, JPEG or WebP. Logos can be used as backgrounds and watermarks."
+								>
+									<Button
+										size="sm"
+										variant="dark"
+										onClick={async () => {
+											const path = await pickFile("Images", [
+												"png",
+												"jpg",
+												"jpeg",
+												"webp",
+											]);
+											if (path) update(commands.addBrandLogo(path));
+										}}
+									>
+										Choose...
+									</Button>
+								</SettingItem>
+							</Group>
+
+							<Group title="Fonts">
+								<For each={kit().fonts ?? []}>
+									{(font) => (
+										<SettingItem
+											label={font.name}
+											description={font.families.join(", ")}
+										>
+											<Button
+												size="sm"
+												variant="gray"
+												onClick={() =>
+													update(commands.removeBrandAsset(font.id))
+												}
+											>
+												Remove
+											</Button>
+										</SettingItem>
+									)}
+								</For>
+								<SettingItem label="Add font" description="TTF or OTF.">
+									<Button
+										size="sm"
+										variant="dark"
+										onClick={async () => {
+											const path = await pickFile("Fonts", [
+												"ttf",
+												"otf",
+												"ttc",
+											]);
+											if (path) update(commands.addBrandFont(path));
+										}}
+									>
+										Choose...
+									</Button>
+								</SettingItem>
+							</Group>
+
+							<Group title="Colors">
+								<SettingItem
+									label="Palette"
+									description="Comma-separated #rrggbb colors."
+								>
+									<Input
+										class="bg-gray-3"
+										placeholder="#4785ff, #111111"
+										value={(kit().colors ?? [])
+											.map((color) => color.value)
+											.join(", ")}
+										onChange={(e) => {
+											const colors = e.currentTarget.value
+												.split(",")
+												.map((value) => value.trim())
+												.filter(Boolean)
+												.map((value) => ({ name: value, value }));
+											update(commands.setBrandColors(colors));
+										}}
+									/>
+								</SettingItem>
+							</Group>
+
+							<Group title="Watermark">
+								<ToggleSettingItem
+									label="Add watermark to exports"
+									value={!!kit().watermark?.enabled}
+									onChange={(enabled) => setWatermark({ enabled })}
+								/>
+								<MenuSettingItem
+									label="Logo"
+									value={kit().watermark?.logoId ?? null}
+									options={[
+										{ text: "None", value: null },
+										...(kit().logos ?? []).map((logo) => ({
+											text: logo.name,
+											value: logo.id,
+										})),
+									]}
+									onChange={(logoId) => setWatermark({ logoId })}
+								/>
+								<MenuSettingItem
+									label="Corner"
+									value={kit().watermark?.corner ?? "bottomRight"}
+									options={CORNERS}
+									onChange={(corner) => setWatermark({ corner })}
+								/>
+							</Group>
+						</>
+					)}
+				</Show>
+			</div>
+		</div>
+	);
+}
+
+function Group(props: ParentProps<{ title: string }>) {
+	return (
+		<div>
+			<h3 class="mb-3 text-sm text-gray-12 w-fit">{props.title}</h3>
+			<div class="px-3 rounded-xl border divide-y divide-gray-3 border-gray-3 bg-gray-2">
+				{props.children}
+			</div>
+		</div>
+	);
+}
+
+function MenuSettingItem<T>(props: {
+	label: string;
+	value: T;
+	options: { text: string; value: T }[];
+	onChange: (value: T) => void;
+}) {
+	return (
+		<SettingItem label={props.label}>
+			<button
+				type="button"
+				class="flex flex-row gap-1 text-xs bg-gray-3 items-center px-2.5 py-1.5 rounded-md border border-gray-4"
+				onClick={async () => {
+					const menu = await Menu.new({
+						items: await Promise.all(
+							props.options.map((option) =>
+								CheckMenuItem.new({
+									text: option.text,
+									checked: props.value === option.value,
+									action: () => props.onChange(option.value),
+								}),
+							),
+						),
+					});
+					await menu.popup();
+					await menu.close();
+				}}
+			>
+				{props.options.find((option) => option.value === props.value)?.text}
+				<IconCapChevronDown class="size-4" />
+			</button>
+		</SettingItem>
+	);
+}
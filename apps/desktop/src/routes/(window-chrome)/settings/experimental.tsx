@@ -1,11 +1,14 @@
+import { Button } from "@cap/ui-solid";
 import { WebviewWindow } from "@tauri-apps/api/webviewWindow";
 import { type } from "@tauri-apps/plugin-os";
-import { createResource, Show } from "solid-js";
+import { createResource, createSignal, onCleanup, Show } from "solid-js";
 import { createStore } from "solid-js/store";
+import { Portal } from "solid-js/web";
 
+import { Input } from "~/routes/editor/ui";
 import { generalSettingsStore } from "~/store";
-import { commands, type GeneralSettingsStore } from "~/utils/tauri";
-import { ToggleSettingItem } from "./Setting";
+import { commands, events, type GeneralSettingsStore } from "~/utils/tauri";
+import { SettingItem, ToggleSettingItem } from "./Setting";
 
 export default function ExperimentalSettings() {
 	const [store] = createResource(() => generalSettingsStore.get());
@@ -82,6 +85,20 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 								}
 							/>
 						)}
+						{type() !== "windows" && (
+							<ToggleSettingItem
+								label="Native editor preview"
+								description="Draw the editor's preview straight into a GPU surface instead of sending every frame to the webview. Controls drawn over the preview, like mask and text handles, may be hidden beneath it."
+								value={!!settings.nativeEditorPreview}
+								onChange={(value) => handleChange("nativeEditorPreview", value)}
+							/>
+						)}
+						<ToggleSettingItem
+							label="Frame inspection"
+							description="Keep the editor's last few preview frames so they can be inspected or saved when diagnosing black or discoloured frames. Uses extra memory, and applies to editors opened after it's turned on."
+							value={!!settings.frameInspection}
+							onChange={(value) => handleChange("frameInspection", value)}
+						/>
 						<ToggleSettingItem
 							label="Auto zoom on clicks"
 							description="Automatically generate zoom segments around mouse clicks during Studio Mode recordings. This helps highlight important interactions in your recordings."
@@ -94,9 +111,130 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 								);
 							}}
 						/>
+						<ToggleSettingItem
+							label="Frame zooms around windows"
+							description="When a generated zoom starts with a click inside the focused window, frame that whole window instead of following the cursor."
+							value={!!settings.snapZoomToWindows}
+							onChange={(value) => handleChange("snapZoomToWindows", value)}
+						/>
+						<ToggleSettingItem
+							label="Zoom on narration cues"
+							description="When the project has captions, phrases like 'click here' or 'as you can see' hold the generated zoom they fall in a little longer, or start one of their own."
+							value={!!settings.zoomOnNarrationCues}
+							onChange={(value) => handleChange("zoomOnNarrationCues", value)}
+						/>
+						<Show when={settings.zoomOnNarrationCues}>
+							<SettingItem
+								label="Narration cue keywords"
+								description="Extra phrases to zoom on, separated by commas."
+							>
+								<Input
+									class="bg-gray-3"
+									placeholder="dashboard, settings"
+									value={(settings.zoomCueKeywords ?? []).join(", ")}
+									onChange={(e) =>
+										handleChange(
+											"zoomCueKeywords",
+											e.currentTarget.value
+												.split(",")
+												.map((keyword) => keyword.trim())
+												.filter(Boolean),
+										)
+									}
+								/>
+							</SettingItem>
+						</Show>
+						<AvSyncCalibration />
 					</div>
 				</div>
 			</div>
 		</div>
 	);
 }
+
+function AvSyncCalibration() {
+	const [calibration, { mutate }] = createResource(() =>
+		commands.getAvSyncCalibration(),
+	);
+	const [running, setRunning] = createSignal(false);
+	const [flashing, setFlashing] = createSignal(false);
+	const [error, setError] = createSignal<string | null>(null);
+
+	let audioContext: AudioContext | undefined;
+
+	const pulse = () => {
+		audioContext ??= new AudioContext();
+		const oscillator = audioContext.createOscillator();
+		oscillator.frequency.value = 1000;
+		oscillator.connect(audioContext.destination);
+		oscillator.start();
+		oscillator.stop(audioContext.currentTime + 0.1);
+
+		setFlashing(true);
+		requestAnimationFrame(() =>
+			requestAnimationFrame(() => setFlashing(false)),
+		);
+	};
+
+	const unlisten = events.avSyncTestPulse.listen(() => pulse());
+	onCleanup(() => {
+		unlisten.then((fn) => fn());
+		audioContext?.close();
+	});
+
+	const run = async () => {
+		setRunning(true);
+		setError(null);
+		try {
+			const result = await commands.runAvSyncTest();
+			mutate(result.offsetSecs);
+		} catch (e) {
+			setError(String(e));
+		} finally {
+			setRunning(false);
+		}
+	};
+
+	const description = () => {
+		if (running()) return "Keep this window visible and your volume up.";
+		if (error()) return error() ?? undefined;
+		const offset = calibration();
+		if (offset === null || offset === undefined)
+			return "Records a flash and beep to measure how far audio drifts from video on this machine. Exports correct for it automatically.";
+		return `Audio is shifted ${Math.round(offset * 1000)}ms in exports.`;
+	};
+
+	return (
+		<>
+			<SettingItem label="A/V sync calibration" description={description()}>
+				<div class="flex gap-2">
+					<Show when={calibration() != null && !running()}>
+						<Button
+							size="sm"
+							variant="gray"
+							onClick={async () => {
+								await commands.clearAvSyncCalibration();
+								mutate(null);
+							}}
+						>
+							Reset
+						</Button>
+					</Show>
+					<Button size="sm" variant="dark" disabled={running()} onClick={run}>
+						{running() ? "Testing..." : "Run test"}
+					</Button>
+				</div>
+			</SettingItem>
+			<Show when={running()}>
+				<Portal>
+					<div
+						class="fixed inset-0 z-50 bg-white pointer-events-none"
+						classList={{
+							"opacity-0 transition-opacity duration-500": !flashing(),
+						}}
+					/>
+				</Portal>
+			</Show>
+		</>
+	);
+}
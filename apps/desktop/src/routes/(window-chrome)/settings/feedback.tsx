@@ -5,7 +5,11 @@ import { type as ostype } from "@tauri-apps/plugin-os";
 import { createResource, createSignal, For, Show } from "solid-js";
 import toast from "solid-toast";
 
-import { commands, type SystemDiagnostics } from "~/utils/tauri";
+import {
+	commands,
+	type DisplayAdapterDiag,
+	type SystemDiagnostics,
+} from "~/utils/tauri";
 import { apiClient, protectedHeaders } from "~/utils/web-api";
 
 const sendFeedbackAction = action(async (feedback: string) => {
@@ -155,6 +159,12 @@ export default function FeedbackTab() {
 										: "windowsVersion" in d
 											? (d.windowsVersion as { displayName: string } | null)
 											: null;
+								const crossAdapterDisplays =
+									"displayAdapters" in d
+										? (d.displayAdapters as DisplayAdapterDiag[]).filter(
+												(a) => a.requiresCrossAdapterCopy,
+											)
+										: [];
 								const captureSupported =
 									"screenCaptureSupported" in d
 										? (d.screenCaptureSupported as boolean)
@@ -192,6 +202,23 @@ export default function FeedbackTab() {
 											</div>
 										</div>
 
+										<Show when={crossAdapterDisplays.length > 0}>
+											<div class="space-y-1">
+												<p class="text-gray-11 font-medium">Graphics</p>
+												<For each={crossAdapterDisplays}>
+													{(adapter) => (
+														<p class="px-2 py-1.5 rounded text-xs bg-yellow-500/20 text-yellow-400">
+															{adapter.displayName} is connected to{" "}
+															{adapter.adapterDescription}, which isn't the GPU
+															used for encoding. If recordings of it are black,
+															set this app to use that GPU in Windows graphics
+															settings.
+														</p>
+													)}
+												</For>
+											</div>
+										</Show>
+
 										<Show when={(d.availableEncoders as string[])?.length > 0}>
 											<div class="space-y-1">
 												<p class="text-gray-11 font-medium">
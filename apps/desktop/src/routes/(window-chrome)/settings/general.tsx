@@ -31,10 +31,12 @@ import {
 	type CaptureWindow,
 	commands,
 	events,
+	type ExportCompletionActions,
 	type GeneralSettingsStore,
 	type MainWindowRecordingStartBehaviour,
 	type PostDeletionBehaviour,
 	type PostStudioRecordingBehaviour,
+	type WebhookUrls,
 	type WindowExclusion,
 } from "~/utils/tauri";
 import IconLucidePlus from "~icons/lucide/plus";
@@ -243,6 +245,29 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 		generalSettingsStore.set({ [key]: value, ...(extra ?? {}) });
 	};
 
+	const [cursorThemes] = createResource(() => commands.listCursorThemes());
+	const [hasBetaChannel] = createResource(() => commands.hasBetaChannel());
+	const [effectPlugins, { refetch: refetchEffectPlugins }] = createResource(
+		() => commands.listEffectPlugins(),
+	);
+
+	const handleCompletionActionChange = <
+		K extends keyof ExportCompletionActions,
+	>(
+		key: K,
+		value: ExportCompletionActions[K],
+	) =>
+		handleChange("exportCompletionActions", {
+			...settings.exportCompletionActions,
+			[key]: value,
+		});
+
+	const handleWebhookChange = (key: keyof WebhookUrls, value: string) =>
+		handleChange("webhooks", {
+			...settings.webhooks,
+			[key]: value.trim() || null,
+		});
+
 	const ostype: OsType = type();
 	const excludedWindows = createMemo(() => settings.excludedWindows ?? []);
 
@@ -351,7 +376,9 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 			| MainWindowRecordingStartBehaviour
 			| PostStudioRecordingBehaviour
 			| PostDeletionBehaviour
-			| number,
+			| number
+			| string
+			| null,
 	>(props: {
 		label: string;
 		description: string;
@@ -445,6 +472,30 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 					</SettingGroup>
 				)}
 
+				<Show when={hasBetaChannel()}>
+					<SettingGroup title="Updates">
+						<SelectSettingItem
+							label="Release channel"
+							description="Beta builds get new features sooner but may be less stable. Switching back to Stable takes effect with the next stable release."
+							value={settings.updateChannel ?? "stable"}
+							onChange={(value) => handleChange("updateChannel", value)}
+							options={[
+								{ text: "Stable", value: "stable" },
+								{ text: "Beta", value: "beta" },
+							]}
+						/>
+					</SettingGroup>
+				</Show>
+
+				<SettingGroup title="Privacy">
+					<ToggleSettingItem
+						label="Share anonymous usage statistics"
+						description="Send counts of which features are used and which kinds of errors occur, to help improve Drift. No recordings, file names or personal details are sent."
+						value={!!settings.telemetryEnabled}
+						onChange={(v) => handleChange("telemetryEnabled", v)}
+					/>
+				</SettingGroup>
+
 				<SettingGroup title="Recording">
 					<SelectSettingItem
 						label="Instant mode max resolution"
@@ -480,6 +531,10 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 						options={[
 							{ text: "Close", value: "close" },
 							{ text: "Minimise", value: "minimise" },
+							{
+								text: "Minimise if on captured display",
+								value: "minimiseIfCaptured",
+							},
 						]}
 					/>
 					<SelectSettingItem
@@ -524,6 +579,47 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 						value={settings.crashRecoveryRecording ?? true}
 						onChange={(value) => handleChange("crashRecoveryRecording", value)}
 					/>
+					<ToggleSettingItem
+						label="Anonymize input"
+						description="Stores only approximate cursor positions and the number of keys pressed each second, never which keys. Intended for recording in regulated environments."
+						value={settings.anonymizeInput ?? false}
+						onChange={(value) => handleChange("anonymizeInput", value)}
+					/>
+					<SelectSettingItem
+						label="Record keystrokes"
+						description="Records the keys you press so they can be shown over the recording. Masked keeps modifiers and keys like Enter, but hides every key that types something. Has no effect with anonymized input."
+						value={settings.keystrokeCapture ?? "off"}
+						onChange={(value) => handleChange("keystrokeCapture", value)}
+						options={[
+							{ text: "Off", value: "off" },
+							{ text: "Masked", value: "masked" },
+							{ text: "All keys", value: "full" },
+						]}
+					/>
+					<ToggleSettingItem
+						label="Prevent sleep while recording"
+						description="Keeps your display on and stops your computer from going to sleep while a recording is in progress."
+						value={settings.preventSleepWhileRecording ?? true}
+						onChange={(value) =>
+							handleChange("preventSleepWhileRecording", value)
+						}
+					/>
+					<ToggleSettingItem
+						label="Do Not Disturb while recording"
+						description="Hides notification banners while recording and restores your previous setting afterwards. On macOS, create shortcuts named 'Drift Focus On' and 'Drift Focus Off' in the Shortcuts app that turn your Focus on and off."
+						value={settings.suppressNotificationsWhileRecording ?? false}
+						onChange={(value) =>
+							handleChange("suppressNotificationsWhileRecording", value)
+						}
+					/>
+					<ToggleSettingItem
+						label="Control recordings with media keys"
+						description="While recording, the play/pause key pauses or resumes the recording and the stop key stops it. Media players won't receive these keys until the recording ends."
+						value={settings.mediaKeysControlRecording ?? false}
+						onChange={(value) =>
+							handleChange("mediaKeysControlRecording", value)
+						}
+					/>
 					<div class="flex flex-col gap-1">
 						<SelectSettingItem
 							label="Max capture framerate"
@@ -556,6 +652,186 @@ function Inner(props: { initialStore: GeneralSettingsStore | null }) {
 					/>
 				</SettingGroup>
 
+				<SettingGroup title="Export">
+					<SelectSettingItem
+						label="Save exports to"
+						description={
+							settings.exportDirectoryMode === "custom" &&
+							settings.exportDirectory
+								? settings.exportDirectory
+								: "Projects can choose their own folder from the export dialog."
+						}
+						value={settings.exportDirectoryMode ?? "askEveryTime"}
+						onChange={async (value) => {
+							if (value !== "custom") {
+								handleChange("exportDirectoryMode", value);
+								return;
+							}
+							const dir = await commands.chooseDefaultDirectory();
+							if (!dir) return;
+							setSettings("exportDirectoryMode", "custom");
+							setSettings("exportDirectory", dir);
+						}}
+						options={[
+							{ text: "Ask every time", value: "askEveryTime" },
+							{ text: "Same folder as project", value: "sameAsProject" },
+							{ text: "Choose folder...", value: "custom" },
+						]}
+					/>
+					<SelectSettingItem
+						label="Export priority"
+						description="Background exports take longer but leave the computer usable while they run."
+						value={settings.exportPriority ?? "normal"}
+						onChange={(value) => handleChange("exportPriority", value)}
+						options={[
+							{ text: "Normal", value: "normal" },
+							{ text: "Background", value: "background" },
+							{
+								text: "Background, paused while I'm using the computer",
+								value: "backgroundWhenIdle",
+							},
+						]}
+					/>
+					<SelectSettingItem
+						label="Cursor theme"
+						description="Cursor pack used instead of the system cursors. Add packs to the cursor-themes folder in Drift's app data."
+						value={settings.cursorTheme ?? null}
+						onChange={async (value) => {
+							await commands.setCursorTheme(value);
+							setSettings("cursorTheme", value);
+						}}
+						options={[
+							{ text: "System", value: null },
+							...(cursorThemes() ?? []).map((theme) => ({
+								text: theme.name,
+								value: theme.id,
+							})),
+						]}
+					/>
+					<For each={effectPlugins()}>
+						{(effect) => (
+							<ToggleSettingItem
+								label={`Effect: ${effect.name}`}
+								description={
+									effect.description ??
+									"Effect plugin from the effect-plugins folder in Drift's app data."
+								}
+								value={effect.enabled}
+								onChange={async (enabled) => {
+									await commands.enableEffect(effect.id, enabled);
+									refetchEffectPlugins();
+								}}
+							/>
+						)}
+					</For>
+					<SettingItem
+						label="Run command before export"
+						description={`Runs in your shell with the project folder as ${
+							ostype === "windows" ? "%1" : "$1"
+						} and DRIFT_PROJECT_PATH. The export stops if it fails.`}
+					>
+						<Input
+							class="bg-gray-3"
+							placeholder="Leave empty to skip"
+							value={settings.preExportCommand ?? ""}
+							onChange={(e) =>
+								handleChange(
+									"preExportCommand",
+									e.currentTarget.value.trim() || null,
+								)
+							}
+						/>
+					</SettingItem>
+				</SettingGroup>
+
+				<SettingGroup title="After Export">
+					<ToggleSettingItem
+						label="Show in file manager"
+						description="Reveal the exported file in Finder or Explorer."
+						value={!!settings.exportCompletionActions?.revealInFileManager}
+						onChange={(v) =>
+							handleCompletionActionChange("revealInFileManager", v)
+						}
+					/>
+					<ToggleSettingItem
+						label="Copy path to clipboard"
+						value={!!settings.exportCompletionActions?.copyPath}
+						onChange={(v) => handleCompletionActionChange("copyPath", v)}
+					/>
+					<ToggleSettingItem
+						label="Copy file to clipboard"
+						description="Takes priority over copying the path."
+						value={!!settings.exportCompletionActions?.copyFile}
+						onChange={(v) => handleCompletionActionChange("copyFile", v)}
+					/>
+					<ToggleSettingItem
+						label="Open in default player"
+						value={!!settings.exportCompletionActions?.openWithDefaultPlayer}
+						onChange={(v) =>
+							handleCompletionActionChange("openWithDefaultPlayer", v)
+						}
+					/>
+					<SettingItem
+						label="Run command"
+						description={`Runs in your shell with the exported file as ${
+							ostype === "windows" ? "%1" : "$1"
+						} and DRIFT_EXPORT_PATH, and the project folder as DRIFT_PROJECT_PATH.`}
+					>
+						<Input
+							class="bg-gray-3"
+							placeholder="Leave empty to skip"
+							value={settings.exportCompletionActions?.runCommand ?? ""}
+							onChange={(e) =>
+								handleCompletionActionChange(
+									"runCommand",
+									e.currentTarget.value.trim() || null,
+								)
+							}
+						/>
+					</SettingItem>
+				</SettingGroup>
+
+				<SettingGroup title="Webhooks">
+					<For
+						each={
+							[
+								{
+									key: "recordingStopped",
+									label: "Recording stopped",
+									description: "Sent once a recording is saved.",
+								},
+								{
+									key: "exportComplete",
+									label: "Export complete",
+									description: "Sent for each exported file.",
+								},
+								{
+									key: "exportFailed",
+									label: "Export failed",
+									description: "Sent with the error when an export fails.",
+								},
+							] as const
+						}
+					>
+						{(webhook) => (
+							<SettingItem
+								label={webhook.label}
+								description={`${webhook.description} Drift POSTs JSON with the event, path, duration and size.`}
+							>
+								<Input
+									class="bg-gray-3"
+									type="url"
+									placeholder="https://"
+									value={settings.webhooks?.[webhook.key] ?? ""}
+									onChange={(e) =>
+										handleWebhookChange(webhook.key, e.currentTarget.value)
+									}
+								/>
+							</SettingItem>
+						)}
+					</For>
+				</SettingGroup>
+
 				<DefaultProjectNameCard
 					onChange={(value) =>
 						handleChange("defaultProjectNameTemplate", value)
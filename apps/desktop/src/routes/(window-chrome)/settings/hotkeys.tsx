@@ -34,6 +34,9 @@ const ACTION_TEXT = {
 	screenshotDisplay: "Screenshot current display",
 	screenshotWindow: "Screenshot current window",
 	screenshotArea: "Screenshot area picker",
+	toggleTeleprompter: "Play/pause teleprompter",
+	teleprompterFaster: "Speed up teleprompter",
+	teleprompterSlower: "Slow down teleprompter",
 } satisfies { [K in HotkeyAction]?: string };
 
 export default function () {
@@ -22,17 +22,22 @@ import {
 	Show,
 } from "solid-js";
 import { createStore, produce } from "solid-js/store";
+import toast from "solid-toast";
 import CapTooltip from "~/components/Tooltip";
 import { Input } from "~/routes/editor/ui";
+import { formatTime } from "~/routes/editor/utils";
 import { trackEvent } from "~/utils/analytics";
 import { createTauriEventListener } from "~/utils/createEventListener";
 import {
 	commands,
 	events,
 	type RecordingMetaWithMetadata,
+	type ScreenTextSpan,
 	type UploadProgress,
 } from "~/utils/tauri";
+import IconLucideScanText from "~icons/lucide/scan-text";
 import IconLucideSearch from "~icons/lucide/search";
+import IconLucideShieldAlert from "~icons/lucide/shield-alert";
 
 type Recording = {
 	meta: RecordingMetaWithMetadata;
@@ -109,6 +114,21 @@ export default function Recordings() {
 		Record</* video_id */ string, number>
 	>({});
 	const recordings = createQuery(() => recordingsQuery);
+	const screenTextResults = createQuery(() => ({
+		queryKey: ["screenTextSearch", trimmedSearch()],
+		queryFn: () => commands.searchRecordings(trimmedSearch()),
+		enabled: trimmedSearch().length > 0,
+	}));
+
+	const screenTextMatches = createMemo(
+		() =>
+			new Map(
+				(screenTextResults.data ?? []).map((result) => [
+					result.path,
+					result.matches[0],
+				]),
+			),
+	);
 
 	createTauriEventListener(events.uploadProgressEvent, (e) => {
 		if (e.uploaded === "0" && e.total === "0") {
@@ -140,8 +160,10 @@ export default function Recordings() {
 				: data.filter((recording) => recording.meta.mode === activeTab());
 		const query = normalizedSearch();
 		if (!query) return scopedRecordings;
-		return scopedRecordings.filter((recording) =>
-			recording.prettyName.toLowerCase().includes(query),
+		return scopedRecordings.filter(
+			(recording) =>
+				recording.prettyName.toLowerCase().includes(query) ||
+				screenTextMatches().has(recording.path),
 		);
 	});
 
@@ -253,6 +275,7 @@ export default function Recordings() {
 							{(recording) => (
 								<RecordingItem
 									recording={recording}
+									screenTextMatch={screenTextMatches().get(recording.path)}
 									onClick={() => handleRecordingClick(recording)}
 									onOpenFolder={() => handleOpenFolder(recording.path)}
 									onOpenEditor={() => handleOpenEditor(recording.path)}
@@ -293,6 +316,7 @@ export default function Recordings() {
 
 function RecordingItem(props: {
 	recording: Recording;
+	screenTextMatch?: ScreenTextSpan;
 	onClick: () => void;
 	onOpenFolder: () => void;
 	onOpenEditor: () => void;
@@ -308,6 +332,30 @@ function RecordingItem(props: {
 	const studioCompleteCheck = () =>
 		mode() === "studio" && props.recording.meta.status.status === "Complete";
 
+	const indexScreenText = createMutation(() => ({
+		mutationFn: () => commands.indexScreenText(props.recording.path),
+		onSuccess: () =>
+			queryClient.invalidateQueries({ queryKey: ["screenTextSearch"] }),
+	}));
+
+	const scanSensitiveContent = createMutation(() => ({
+		mutationFn: () =>
+			commands.scanForSensitiveContent(props.recording.path, true),
+		onSuccess: ({ findings }) => {
+			if (findings.length === 0) {
+				toast.success("No sensitive content found");
+				return;
+			}
+			const first = findings[0];
+			toast(
+				`Found ${findings.length} sensitive item${
+					findings.length === 1 ? "" : "s"
+				}, first at ${formatTime(first.start)}. Blur masks were added.`,
+			);
+		},
+		onError: (error) => toast.error(String(error)),
+	}));
+
 	return (
 		<li
 			onClick={() => {
@@ -338,6 +386,13 @@ function RecordingItem(props: {
 				</Show>
 				<div class="flex flex-col gap-2">
 					<span>{props.recording.prettyName}</span>
+					<Show when={props.screenTextMatch}>
+						{(match) => (
+							<span class="text-xs text-gray-10 truncate max-w-[280px]">
+								On screen at {formatTime(match().start)}: "{match().text}"
+							</span>
+						)}
+					</Show>
 					<div class="flex space-x-1">
 						<div
 							class={cx(
@@ -428,6 +483,20 @@ function RecordingItem(props: {
 					>
 						<IconLucideEdit class="size-4" />
 					</TooltipIconButton>
+					<TooltipIconButton
+						tooltipText={
+							scanSensitiveContent.isPending
+								? "Checking for sensitive content..."
+								: "Check for emails, keys and card numbers"
+						}
+						onClick={() => scanSensitiveContent.mutate()}
+						disabled={
+							scanSensitiveContent.isPending ||
+							props.recording.meta.status.status === "InProgress"
+						}
+					>
+						<IconLucideShieldAlert class="size-4" />
+					</TooltipIconButton>
 				</Show>
 				<Show when={mode() === "instant"}>
 					{(_) => {
@@ -475,6 +544,20 @@ function RecordingItem(props: {
 						);
 					}}
 				</Show>
+				<TooltipIconButton
+					tooltipText={
+						indexScreenText.isPending
+							? "Reading text on screen..."
+							: "Make text on screen searchable"
+					}
+					onClick={() => indexScreenText.mutate()}
+					disabled={
+						indexScreenText.isPending ||
+						props.recording.meta.status.status === "InProgress"
+					}
+				>
+					<IconLucideScanText class="size-4" />
+				</TooltipIconButton>
 				<TooltipIconButton
 					tooltipText="Open recording bundle"
 					onClick={() => {
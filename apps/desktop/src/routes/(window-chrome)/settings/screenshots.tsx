@@ -16,6 +16,7 @@ import {
 	type ParentProps,
 	Show,
 } from "solid-js";
+import toast from "solid-toast";
 import { Input } from "~/routes/editor/ui";
 import { trackEvent } from "~/utils/analytics";
 import { createTauriEventListener } from "~/utils/createEventListener";
@@ -23,6 +24,7 @@ import { commands, events, type RecordingMeta } from "~/utils/tauri";
 
 // Icons
 import IconCapTrash from "~icons/cap/trash";
+import IconLucideClipboardPaste from "~icons/lucide/clipboard-paste";
 import IconLucideCopy from "~icons/lucide/copy";
 import IconLucideEdit from "~icons/lucide/edit";
 import IconLucideFolder from "~icons/lucide/folder";
@@ -113,13 +115,33 @@ export default function Screenshots() {
 		commands.copyScreenshotToClipboard(path);
 	};
 
+	const handlePasteFromClipboard = async () => {
+		trackEvent("screenshot_paste_clicked");
+		try {
+			await commands.captureFromClipboard();
+		} catch (error) {
+			toast.error(String(error));
+		}
+	};
+
 	return (
 		<div class="flex relative flex-col p-4 space-y-4 w-full h-full">
-			<div class="flex flex-col">
-				<h2 class="text-lg font-medium text-gray-12">Screenshots</h2>
-				<p class="text-sm text-gray-10">
-					Manage your screenshots and perform actions.
-				</p>
+			<div class="flex justify-between items-start gap-4">
+				<div class="flex flex-col">
+					<h2 class="text-lg font-medium text-gray-12">Screenshots</h2>
+					<p class="text-sm text-gray-10">
+						Manage your screenshots and perform actions.
+					</p>
+				</div>
+				<Button
+					variant="gray"
+					size="sm"
+					class="flex gap-1.5 items-center shrink-0"
+					onClick={handlePasteFromClipboard}
+				>
+					<IconLucideClipboardPaste class="size-3.5" />
+					Paste from clipboard
+				</Button>
 			</div>
 			<Show
 				when={screenshots.data && screenshots.data.length > 0}
@@ -2,8 +2,15 @@ import { Button } from "@cap/ui-solid";
 import { useNavigate } from "@solidjs/router";
 import { getCurrentWindow, UserAttentionType } from "@tauri-apps/api/window";
 import { relaunch } from "@tauri-apps/plugin-process";
-import { check, type Update } from "@tauri-apps/plugin-updater";
-import { createResource, createSignal, Match, Show, Switch } from "solid-js";
+import {
+	createResource,
+	createSignal,
+	Match,
+	onCleanup,
+	Show,
+	Switch,
+} from "solid-js";
+import { commands, events } from "~/utils/tauri";
 
 export default function () {
 	const navigate = useNavigate();
@@ -11,7 +18,7 @@ export default function () {
 
 	const [update] = createResource(async () => {
 		try {
-			const update = await check();
+			const update = await commands.checkForUpdates();
 			if (!update) return;
 			return update;
 		} catch (e) {
@@ -45,51 +52,37 @@ export default function () {
 				}
 				keyed
 			>
-				{(update) => {
+				{() => {
 					type UpdateStatus =
-						| { type: "downloading"; progress: number; contentLength?: number }
+						| { type: "downloading"; progress: number }
 						| { type: "done" };
 
-					const [updateStatus, updateStatusActions] =
-						createResource<UpdateStatus>(
-							() =>
-								new Promise<UpdateStatus>((resolve) => {
-									update
-										.downloadAndInstall((e) => {
-											if (e.event === "Started") {
-												resolve({
-													type: "downloading",
-													progress: 0,
-													contentLength: e.data.contentLength,
-												});
-											} else if (e.event === "Progress") {
-												const status = updateStatus();
-												if (
-													!status ||
-													status.type !== "downloading" ||
-													status.contentLength === undefined
-												)
-													return;
-												updateStatusActions.mutate({
-													...status,
-													progress: e.data.chunkLength + status.progress,
-												});
-											}
-										})
-										.then(async () => {
-											updateStatusActions.mutate({ type: "done" });
-											getCurrentWindow().requestUserAttention(
-												UserAttentionType.Informational,
-											);
-										})
-										.catch((e) => {
-											console.error("Failed to download/install update:", e);
-											setUpdateError(
-												"Failed to download or install the update.",
-											);
-										});
-								}),
-						);
+					const [updateStatus, setUpdateStatus] =
+						createSignal<UpdateStatus | null>(null);
+
+					const unlisten = events.updateDownloadProgress.listen((e) => {
+						if (updateStatus()?.type === "done") return;
+						setUpdateStatus({
+							type: "downloading",
+							progress: e.payload.progress,
+						});
+					});
+					onCleanup(() => {
+						unlisten.then((fn) => fn());
+					});
+
+					commands
+						.installUpdate()
+						.then(() => {
+							setUpdateStatus({ type: "done" });
+							getCurrentWindow().requestUserAttention(
+								UserAttentionType.Informational,
+							);
+						})
+						.catch((e) => {
+							console.error("Failed to download/install update:", e);
+							setUpdateError("Failed to download or install the update.");
+						});
 
 					return (
 						<div>
@@ -110,12 +103,7 @@ export default function () {
 								<Match
 									when={(() => {
 										const s = updateStatus();
-										if (
-											s &&
-											s.type === "downloading" &&
-											s.contentLength !== undefined
-										)
-											return s;
+										if (s && s.type === "downloading") return s;
 									})()}
 								>
 									{(status) => (
@@ -128,12 +116,7 @@ export default function () {
 												<div
 													class="bg-blue-9 h-2.5 rounded-full"
 													style={{
-														width: `${Math.min(
-															((status()?.progress ?? 0) /
-																(status()?.contentLength ?? 0)) *
-																100,
-															100,
-														)}%`,
+														width: `${Math.min(status().progress * 100, 100)}%`,
 													}}
 												/>
 											</div>
@@ -7,6 +7,7 @@ import { cx } from "cva";
 import {
 	createEffect,
 	createMemo,
+	createResource,
 	createSignal,
 	For,
 	on,
@@ -171,6 +172,14 @@ export function CaptionsTab() {
 	const { project, setProject, editorInstance, editorState, setEditorState } =
 		useEditorContext();
 
+	const [systemFonts] = createResource(() => commands.listSystemFonts());
+	const fontFamilies = createMemo(() => [
+		...fontOptions.map((f) => f.value),
+		...(systemFonts() ?? []),
+	]);
+	const fontLabel = (value: string | undefined) =>
+		fontOptions.find((f) => f.value === value)?.label ?? value;
+
 	const getSetting = <K extends keyof CaptionSettings>(
 		key: K,
 	): NonNullable<CaptionSettings[K]> =>
@@ -626,7 +635,7 @@ export function CaptionsTab() {
 								<div class="flex flex-col gap-2">
 									<span class="text-gray-11 text-sm">Font Family</span>
 									<KSelect<string>
-										options={fontOptions.map((f) => f.value)}
+										options={fontFamilies()}
 										value={getSetting("font")}
 										onChange={(value) => {
 											if (value === null) return;
@@ -639,22 +648,14 @@ export function CaptionsTab() {
 												item={props.item}
 											>
 												<KSelect.ItemLabel class="flex-1">
-													{
-														fontOptions.find(
-															(f) => f.value === props.item.rawValue,
-														)?.label
-													}
+													{fontLabel(props.item.rawValue)}
 												</KSelect.ItemLabel>
 											</MenuItem>
 										)}
 									>
 										<KSelect.Trigger class="w-full flex items-center justify-between rounded-lg px-3 py-2 bg-gray-2 border border-gray-3 text-gray-12 hover:border-gray-4 hover:bg-gray-3 focus:border-blue-9 focus:ring-1 focus:ring-blue-9 transition-colors">
 											<KSelect.Value<string>>
-												{(state) =>
-													fontOptions.find(
-														(f) => f.value === state.selectedOption(),
-													)?.label
-												}
+												{(state) => fontLabel(state.selectedOption())}
 											</KSelect.Value>
 											<KSelect.Icon>
 												<IconCapChevronDown />
@@ -32,7 +32,7 @@ import {
 	Suspense,
 	type ValidComponent,
 } from "solid-js";
-import { createStore, produce } from "solid-js/store";
+import { createStore, produce, unwrap } from "solid-js/store";
 import { Dynamic } from "solid-js/web";
 import toast from "solid-toast";
 import colorBg from "~/assets/illustrations/color.webp";
@@ -43,24 +43,40 @@ import { Toggle } from "~/components/Toggle";
 import { generalSettingsStore } from "~/store";
 import {
 	type BackgroundSource,
+	type BrandColor,
+	type CameraPosition,
 	type CameraShape,
+	type CardConfiguration,
+	type ClickSound,
 	type ClipOffsets,
+	type ConfettiBurst,
 	type CursorAnimationStyle,
 	type CursorType,
 	commands,
+	type EffectPluginInfo,
+	type FitMode,
+	type HotkeysConfiguration,
+	type KeystrokeStyle,
+	type MagnifierConfiguration,
 	type SceneSegment,
+	type SpringScenario,
 	type StereoMode,
 	type TimelineSegment,
+	type ZoomEasing,
 	type ZoomSegment,
 } from "~/utils/tauri";
 import IconLucideBoxSelect from "~icons/lucide/box-select";
 import IconLucideGauge from "~icons/lucide/gauge";
 import IconLucideGrid from "~icons/lucide/grid";
 import IconLucideMonitor from "~icons/lucide/monitor";
+import IconLucideMousePointerClick from "~icons/lucide/mouse-pointer-click";
 import IconLucideMoon from "~icons/lucide/moon";
 import IconLucidePalette from "~icons/lucide/palette";
+import IconLucidePartyPopper from "~icons/lucide/party-popper";
 import IconLucideRabbit from "~icons/lucide/rabbit";
+import IconLucideSearch from "~icons/lucide/search";
 import IconLucideSparkles from "~icons/lucide/sparkles";
+import IconLucideSpline from "~icons/lucide/spline";
 import IconLucideTimer from "~icons/lucide/timer";
 import IconLucideType from "~icons/lucide/type";
 import IconLucideWind from "~icons/lucide/wind";
@@ -72,6 +88,7 @@ import {
 	rgbToHex,
 } from "./color-utils";
 import { type CornerRoundingType, useEditorContext } from "./context";
+import type { CursorSegment } from "./cursorSegments";
 import { GradientEditor } from "./GradientEditor";
 import { evaluateMask, type MaskKind, type MaskSegment } from "./masks";
 import {
@@ -79,13 +96,14 @@ import {
 	DEFAULT_GRADIENT_TO,
 	type RGBColor,
 } from "./projectConfig";
+import { type ReactionSegment, reactionEmoji } from "./reactions";
 import ShadowSettings from "./ShadowSettings";
 import { TextInput } from "./TextInput";
 import type { TextSegment } from "./text";
 import {
-	ComingSoonTooltip,
 	EditorButton,
 	Field,
+	Input,
 	MenuItem,
 	MenuItemList,
 	PopperContent,
@@ -93,6 +111,7 @@ import {
 	Subfield,
 	topSlideAnimateClasses,
 } from "./ui";
+import { formatTime } from "./utils";
 
 const BACKGROUND_SOURCES = {
 	wallpaper: "Wallpaper",
@@ -210,6 +229,11 @@ const CORNER_STYLE_OPTIONS = [
 	{ name: "Rounded", value: "rounded" },
 ] satisfies Array<{ name: string; value: CornerRoundingType }>;
 
+const FIT_MODE_OPTIONS = [
+	{ name: "Fit inside (letterbox)", value: "contain" },
+	{ name: "Fill frame (crop)", value: "cover" },
+] satisfies Array<{ name: string; value: FitMode }>;
+
 const BACKGROUND_THEMES = {
 	macOS: "macOS",
 	dark: "Dark",
@@ -226,6 +250,21 @@ type CursorPresetValues = {
 
 const DEFAULT_CURSOR_MOTION_BLUR = 0.5;
 
+const DEFAULT_MAGNIFIER: Required<MagnifierConfiguration> = {
+	enabled: false,
+	amount: 2,
+	size: 35,
+	position: { x: "right", y: "top" },
+};
+
+const DEFAULT_HOTKEYS: Required<HotkeysConfiguration> = {
+	show: false,
+	style: "shortcuts",
+	shortcutsOnly: true,
+	size: 3.5,
+	position: { x: "center", y: "bottom" },
+};
+
 const CURSOR_TYPE_OPTIONS = [
 	{
 		value: "auto" as CursorType,
@@ -239,6 +278,59 @@ const CURSOR_TYPE_OPTIONS = [
 	},
 ];
 
+const CLICK_SOUND_OPTIONS = [
+	{
+		value: "soft" as ClickSound,
+		label: "Soft",
+		description: "A muted tap that sits under narration.",
+	},
+	{
+		value: "crisp" as ClickSound,
+		label: "Crisp",
+		description: "A short, bright click that's easy to hear.",
+	},
+];
+
+const KEYSTROKE_STYLE_OPTIONS = [
+	{
+		value: "shortcuts" as KeystrokeStyle,
+		label: "Shortcuts",
+		description: "Pops up each key combination as it's pressed.",
+	},
+	{
+		value: "typing" as KeystrokeStyle,
+		label: "Typing",
+		description: "Writes out typed text with a caret, fading after a pause.",
+	},
+];
+
+type CubicBezierPoints = { x1: number; y1: number; x2: number; y2: number };
+
+const DEFAULT_ZOOM_BEZIER: CubicBezierPoints = {
+	x1: 0.25,
+	y1: 0.1,
+	x2: 0.25,
+	y2: 1,
+};
+
+const ZOOM_EASING_OPTIONS = [
+	{
+		value: "spring",
+		label: "Spring",
+		description: "A natural spring that settles into place.",
+	},
+	{
+		value: "instant",
+		label: "Instant",
+		description: "Cut straight to the zoomed view.",
+	},
+	{
+		value: "cubicBezier",
+		label: "Custom Curve",
+		description: "Shape the motion with a cubic bezier curve.",
+	},
+] as const;
+
 const CURSOR_ANIMATION_STYLE_OPTIONS = [
 	{
 		value: "slow",
@@ -314,6 +406,21 @@ export function ConfigSidebar() {
 	const clampIdleDelay = (value: number) =>
 		Math.round(Math.min(5, Math.max(0.5, value)) * 10) / 10;
 
+	const updateMagnifier = (update: Partial<MagnifierConfiguration>) =>
+		setProject("magnifier", (magnifier) => ({
+			...(magnifier ?? DEFAULT_MAGNIFIER),
+			...update,
+		}));
+
+	const updateHotkeys = (update: Partial<HotkeysConfiguration>) =>
+		setProject("hotkeys", (hotkeys) => ({
+			...DEFAULT_HOTKEYS,
+			...hotkeys,
+			...update,
+		}));
+
+	const keystrokeStyle = () => project.hotkeys.style ?? DEFAULT_HOTKEYS.style;
+
 	type CursorPhysicsKey = "tension" | "mass" | "friction";
 
 	const setCursorPhysics = (key: CursorPhysicsKey, value: number) => {
@@ -389,7 +496,7 @@ export function ConfigSidebar() {
 							id: "captions" as const,
 							icon: IconCapMessageBubble,
 						},
-						// { id: "hotkeys" as const, icon: IconCapHotkeys },
+						{ id: "hotkeys" as const, icon: IconCapHotkeys },
 					].filter(Boolean)}
 				>
 					{(item) => (
@@ -563,6 +670,60 @@ export function ConfigSidebar() {
 							/>
 						</Field>
 					)}
+					<Field
+						name="Click Sounds"
+						icon={<IconLucideMousePointerClick class="size-4" />}
+						value={
+							<Toggle
+								checked={project.audio.clickSounds.enabled}
+								onChange={(v) =>
+									setProject("audio", "clickSounds", "enabled", v)
+								}
+							/>
+						}
+					/>
+					<Show when={project.audio.clickSounds.enabled}>
+						<RadioGroup
+							class="flex flex-col gap-2"
+							value={project.audio.clickSounds.sound}
+							onChange={(value) =>
+								setProject("audio", "clickSounds", "sound", value as ClickSound)
+							}
+						>
+							{CLICK_SOUND_OPTIONS.map((option) => (
+								<RadioGroup.Item
+									value={option.value}
+									class="rounded-lg border border-gray-3 transition-colors ui-checked:border-blue-8 ui-checked:bg-blue-3/40"
+								>
+									<RadioGroup.ItemInput class="sr-only" />
+									<RadioGroup.ItemLabel class="flex cursor-pointer items-start gap-3 p-3">
+										<RadioGroup.ItemControl class="mt-1 size-4 rounded-full border border-gray-7 ui-checked:border-blue-9 ui-checked:bg-blue-9" />
+										<div class="flex flex-col text-left">
+											<span class="text-sm font-medium text-gray-12">
+												{option.label}
+											</span>
+											<span class="text-xs text-gray-11">
+												{option.description}
+											</span>
+										</div>
+									</RadioGroup.ItemLabel>
+								</RadioGroup.Item>
+							))}
+						</RadioGroup>
+						<Subfield name="Volume" class="gap-4 items-center">
+							<Slider
+								class="flex-1"
+								value={[project.audio.clickSounds.volume * 100]}
+								onChange={(v) =>
+									setProject("audio", "clickSounds", "volume", v[0] / 100)
+								}
+								minValue={0}
+								maxValue={100}
+								step={1}
+								formatTooltip="%"
+							/>
+						</Subfield>
+					</Show>
 				</KTabs.Content>
 				<KTabs.Content
 					value="cursor"
@@ -727,6 +888,11 @@ export function ConfigSidebar() {
 											step={0.01}
 										/>
 									</Field>
+									<SpringResponseGraph
+										tension={project.cursor.tension}
+										mass={project.cursor.mass}
+										friction={project.cursor.friction}
+									/>
 								</div>
 							</KCollapsible.Content>
 						</KCollapsible>
@@ -743,6 +909,53 @@ export function ConfigSidebar() {
 							}
 						/>
 					</Show>
+					<Field
+						name="Magnifier"
+						icon={<IconLucideSearch class="size-4" />}
+						value={
+							<Toggle
+								checked={project.magnifier?.enabled ?? false}
+								onChange={(enabled) => updateMagnifier({ enabled })}
+							/>
+						}
+					>
+						<span class="text-xs text-gray-11">
+							Shows the area around the cursor magnified in a corner while the
+							screen isn't zoomed in, so nothing else goes out of view.
+						</span>
+					</Field>
+					<Show when={project.magnifier?.enabled && project.magnifier}>
+						{(magnifier) => (
+							<>
+								<Field name="Magnification" icon={<IconLucideSearch />}>
+									<Slider
+										value={[magnifier().amount ?? DEFAULT_MAGNIFIER.amount]}
+										onChange={(v) => updateMagnifier({ amount: v[0] })}
+										minValue={1.5}
+										maxValue={4}
+										step={0.1}
+										formatTooltip={(v) => `${v.toFixed(1)}×`}
+									/>
+								</Field>
+								<Field name="Size" icon={<IconCapEnlarge />}>
+									<Slider
+										value={[magnifier().size ?? DEFAULT_MAGNIFIER.size]}
+										onChange={(v) => updateMagnifier({ size: v[0] })}
+										minValue={20}
+										maxValue={50}
+										step={0.1}
+										formatTooltip="%"
+									/>
+								</Field>
+								<Field name="Position" icon={<IconLucideMove />}>
+									<PositionPicker
+										value={magnifier().position ?? DEFAULT_MAGNIFIER.position}
+										onChange={(position) => updateMagnifier({ position })}
+									/>
+								</Field>
+							</>
+						)}
+					</Show>
 
 					{/* <Field name="Animation Style" icon={<IconLucideRabbit />}>
             <RadioGroup
@@ -790,14 +1003,85 @@ export function ConfigSidebar() {
             </RadioGroup>
           </Field> */}
 				</KTabs.Content>
-				<KTabs.Content value="hotkeys" class="flex flex-1 p-4 min-h-0">
-					<Field name="Hotkeys" icon={<IconCapHotkeys />}>
-						<ComingSoonTooltip>
-							<Subfield name="Show hotkeys">
-								<Toggle disabled />
-							</Subfield>
-						</ComingSoonTooltip>
+				<KTabs.Content
+					value="hotkeys"
+					class="flex flex-col flex-1 gap-6 p-4 min-h-0"
+				>
+					<Field
+						name="Keystrokes"
+						icon={<IconCapHotkeys />}
+						value={
+							<Toggle
+								checked={project.hotkeys.show ?? false}
+								onChange={(show) => updateHotkeys({ show })}
+							/>
+						}
+					>
+						<span class="text-xs text-gray-11">
+							Shows the keyboard shortcuts pressed during the recording. Turn on
+							recording keystrokes in settings to capture them.
+						</span>
 					</Field>
+					<Show when={project.hotkeys.show}>
+						<RadioGroup
+							class="flex flex-col gap-2"
+							value={keystrokeStyle()}
+							onChange={(style) =>
+								updateHotkeys({ style: style as KeystrokeStyle })
+							}
+						>
+							{KEYSTROKE_STYLE_OPTIONS.map((option) => (
+								<RadioGroup.Item
+									value={option.value}
+									class="rounded-lg border border-gray-3 transition-colors ui-checked:border-blue-8 ui-checked:bg-blue-3/40"
+								>
+									<RadioGroup.ItemInput class="sr-only" />
+									<RadioGroup.ItemLabel class="flex cursor-pointer items-start gap-3 p-3">
+										<RadioGroup.ItemControl class="mt-1 size-4 rounded-full border border-gray-7 ui-checked:border-blue-9 ui-checked:bg-blue-9" />
+										<div class="flex flex-col text-left">
+											<span class="text-sm font-medium text-gray-12">
+												{option.label}
+											</span>
+											<span class="text-xs text-gray-11">
+												{option.description}
+											</span>
+										</div>
+									</RadioGroup.ItemLabel>
+								</RadioGroup.Item>
+							))}
+						</RadioGroup>
+						<Show when={keystrokeStyle() === "shortcuts"}>
+							<Field name="Shortcuts Only" icon={<IconCapHotkeys />}>
+								<Subfield name="Leave out typing">
+									<Toggle
+										checked={
+											project.hotkeys.shortcutsOnly ??
+											DEFAULT_HOTKEYS.shortcutsOnly
+										}
+										onChange={(shortcutsOnly) =>
+											updateHotkeys({ shortcutsOnly })
+										}
+									/>
+								</Subfield>
+							</Field>
+						</Show>
+						<Field name="Size" icon={<IconCapEnlarge />}>
+							<Slider
+								value={[project.hotkeys.size ?? DEFAULT_HOTKEYS.size]}
+								onChange={(v) => updateHotkeys({ size: v[0] })}
+								minValue={2}
+								maxValue={8}
+								step={0.1}
+								formatTooltip="%"
+							/>
+						</Field>
+						<Field name="Position" icon={<IconLucideMove />}>
+							<PositionPicker
+								value={project.hotkeys.position ?? DEFAULT_HOTKEYS.position}
+								onChange={(position) => updateHotkeys({ position })}
+							/>
+						</Field>
+					</Show>
 				</KTabs.Content>
 				<KTabs.Content
 					value="captions"
@@ -954,6 +1238,144 @@ export function ConfigSidebar() {
 									</div>
 								)}
 							</Show>
+							<Show
+								when={(() => {
+									const reactionSelection = selection();
+									if (reactionSelection.type !== "reaction") return;
+
+									const segments = reactionSelection.indices
+										.map((index) => ({
+											index,
+											segment: project.timeline?.reactionSegments?.[index],
+										}))
+										.filter(
+											(
+												item,
+											): item is { index: number; segment: ReactionSegment } =>
+												item.segment !== undefined,
+										);
+
+									if (segments.length === 0) {
+										setEditorState("timeline", "selection", null);
+										return;
+									}
+									return { selection: reactionSelection, segments };
+								})()}
+							>
+								{(value) => (
+									<div class="space-y-4">
+										<div class="flex flex-row justify-between items-center">
+											<div class="flex gap-2 items-center">
+												<EditorButton
+													onClick={() =>
+														setEditorState("timeline", "selection", null)
+													}
+													leftIcon={<IconLucideCheck />}
+												>
+													Done
+												</EditorButton>
+												<span class="text-sm text-gray-10">
+													{value().segments.length}{" "}
+													{value().segments.length === 1
+														? "reaction"
+														: "reactions"}{" "}
+													selected
+												</span>
+											</div>
+											<EditorButton
+												variant="danger"
+												onClick={() =>
+													projectActions.deleteReactionSegments(
+														value().segments.map((s) => s.index),
+													)
+												}
+												leftIcon={<IconCapTrash />}
+											>
+												Delete
+											</EditorButton>
+										</div>
+										<For each={value().segments}>
+											{(item) => (
+												<div class="p-4 rounded-lg border border-gray-200">
+													<ReactionSegmentConfig
+														segment={item.segment}
+														segmentIndex={item.index}
+													/>
+												</div>
+											)}
+										</For>
+									</div>
+								)}
+							</Show>
+							<Show
+								when={(() => {
+									const cursorSelection = selection();
+									if (cursorSelection.type !== "cursor") return;
+
+									const segments = cursorSelection.indices
+										.map((index) => ({
+											index,
+											segment: project.timeline?.cursorSegments?.[index],
+										}))
+										.filter(
+											(
+												item,
+											): item is { index: number; segment: CursorSegment } =>
+												item.segment !== undefined,
+										);
+
+									if (segments.length === 0) {
+										setEditorState("timeline", "selection", null);
+										return;
+									}
+									return { selection: cursorSelection, segments };
+								})()}
+							>
+								{(value) => (
+									<div class="space-y-4">
+										<div class="flex flex-row justify-between items-center">
+											<div class="flex gap-2 items-center">
+												<EditorButton
+													onClick={() =>
+														setEditorState("timeline", "selection", null)
+													}
+													leftIcon={<IconLucideCheck />}
+												>
+													Done
+												</EditorButton>
+												<span class="text-sm text-gray-10">
+													{value().segments.length} cursor{" "}
+													{value().segments.length === 1
+														? "segment"
+														: "segments"}{" "}
+													selected
+												</span>
+											</div>
+											<EditorButton
+												variant="danger"
+												onClick={() =>
+													projectActions.deleteCursorSegments(
+														value().segments.map((s) => s.index),
+													)
+												}
+												leftIcon={<IconCapTrash />}
+											>
+												Delete
+											</EditorButton>
+										</div>
+										<For each={value().segments}>
+											{(item) => (
+												<div class="p-4 rounded-lg border border-gray-200">
+													<CursorSegmentConfig
+														segment={item.segment}
+														segmentIndex={item.index}
+													/>
+												</div>
+											)}
+										</For>
+									</div>
+								)}
+							</Show>
 							<Show
 								when={(() => {
 									const zoomSelection = selection();
@@ -1180,7 +1602,79 @@ export function ConfigSidebar() {
 }
 
 function BackgroundConfig(props: { scrollRef: HTMLDivElement }) {
-	const { project, setProject, projectHistory } = useEditorContext();
+	const { project, setProject, projectHistory, editorState } =
+		useEditorContext();
+
+	const addBackgroundKeyframe = () => {
+		const time = editorState.previewTime ?? editorState.playbackTime;
+		const startMs = Math.round(time * 1000);
+
+		setProject("backgroundKeyframes", (keyframes = []) =>
+			[
+				...keyframes.filter((k) => k.startMs !== startMs),
+				{ startMs, config: structuredClone(unwrap(project.background)) },
+			].sort((a, b) => a.startMs - b.startMs),
+		);
+	};
+
+	const addLayoutKeyframe = () => {
+		const time = editorState.previewTime ?? editorState.playbackTime;
+		const startMs = Math.round(time * 1000);
+		const { padding, rounding, shadow } = project.background;
+
+		setProject("layoutKeyframes", (keyframes = []) =>
+			[
+				...keyframes.filter((k) => k.startMs !== startMs),
+				{ startMs, padding, rounding, shadow },
+			].sort((a, b) => a.startMs - b.startMs),
+		);
+	};
+
+	const addConfettiBurst = (burst: ConfettiBurst) => {
+		setProject("confetti", (bursts = []) =>
+			[...bursts.filter((b) => b.time !== burst.time), burst].sort(
+				(a, b) => a.time - b.time,
+			),
+		);
+	};
+
+	const addConfettiAtPlayhead = () => {
+		const time = editorState.previewTime ?? editorState.playbackTime;
+		addConfettiBurst({ time, origin: { x: 0.5, y: 0.5 } });
+	};
+
+	const addConfettiAtNextClick = async () => {
+		const time = editorState.previewTime ?? editorState.playbackTime;
+		const clicks = await commands.getTimelineClicks().catch(() => []);
+		const click = clicks.find((c) => c.time >= time);
+		if (!click) {
+			toast.error("No clicks after the playhead");
+			return;
+		}
+		addConfettiBurst({ time: click.time, origin: click.position });
+	};
+
+	const [effectPlugins] = createResource(() => commands.listEffectPlugins());
+
+	const configurableEffects = () =>
+		(effectPlugins() ?? []).filter((e) => e.enabled && e.params.length > 0);
+
+	const effectParam = (effect: EffectPluginInfo, index: number) =>
+		project.effectParams?.[effect.id]?.[index] ?? effect.params[index].default;
+
+	const setEffectParam = (
+		effect: EffectPluginInfo,
+		index: number,
+		value: number,
+	) => {
+		setProject("effectParams", (params = {}) => {
+			const values = effect.params.map(
+				(param, i) => params[effect.id]?.[i] ?? param.default,
+			);
+			values[index] = value;
+			return { ...params, [effect.id]: values };
+		});
+	};
 
 	// Background tabs
 	const [backgroundTab, setBackgroundTab] =
@@ -1834,6 +2328,16 @@ function BackgroundConfig(props: { scrollRef: HTMLDivElement }) {
 					formatTooltip="%"
 				/>
 			</Field>
+			<Field name="Dim While Zoomed" icon={<IconLucideSunDim class="size-4" />}>
+				<Slider
+					value={[project.background.zoomDim]}
+					onChange={(v) => setProject("background", "zoomDim", v[0])}
+					minValue={0}
+					maxValue={100}
+					step={0.1}
+					formatTooltip="%"
+				/>
+			</Field>
 			{/** Dashed divider */}
 			<div class="w-full border-t border-gray-300 border-dashed" />
 			<Field name="Padding" icon={<IconCapPadding class="size-4" />}>
@@ -1846,19 +2350,88 @@ function BackgroundConfig(props: { scrollRef: HTMLDivElement }) {
 					formatTooltip="%"
 				/>
 			</Field>
-			<Field name="Rounded Corners" icon={<IconCapCorners class="size-4" />}>
-				<div class="flex flex-col gap-3">
-					<Slider
-						value={[project.background.rounding]}
-						onChange={(v) => setProject("background", "rounding", v[0])}
-						minValue={0}
-						maxValue={100}
-						step={0.1}
-						formatTooltip="%"
-					/>
-					<CornerStyleSelect
-						label="Corner Style"
-						value={project.background.roundingType}
+			<Show when={project.aspectRatio}>
+				<Field name="Fit" icon={<IconCapLayout class="size-4" />}>
+					<div class="flex flex-col gap-3">
+						<KSelect<{ name: string; value: FitMode }>
+							options={FIT_MODE_OPTIONS}
+							optionValue="value"
+							optionTextValue="name"
+							value={FIT_MODE_OPTIONS.find(
+								(option) => option.value === project.background.fit.mode,
+							)}
+							onChange={(option) =>
+								option && setProject("background", "fit", "mode", option.value)
+							}
+							disallowEmptySelection
+							itemComponent={(props) => (
+								<MenuItem<typeof KSelect.Item>
+									as={KSelect.Item}
+									item={props.item}
+								>
+									<KSelect.ItemLabel class="flex-1">
+										{props.item.rawValue.name}
+									</KSelect.ItemLabel>
+								</MenuItem>
+							)}
+						>
+							<KSelect.Trigger class="flex flex-row gap-2 items-center px-2 w-full h-8 rounded-lg transition-colors bg-gray-3 disabled:text-gray-11">
+								<KSelect.Value<{
+									name: string;
+									value: FitMode;
+								}> class="flex-1 text-sm text-left truncate text-[--gray-500] font-normal">
+									{(state) => <span>{state.selectedOption().name}</span>}
+								</KSelect.Value>
+								<KSelect.Icon<ValidComponent>
+									as={(iconProps) => (
+										<IconCapChevronDown
+											{...iconProps}
+											class="size-4 shrink-0 transform transition-transform ui-expanded:rotate-180 text-[--gray-500]"
+										/>
+									)}
+								/>
+							</KSelect.Trigger>
+							<KSelect.Portal>
+								<PopperContent<typeof KSelect.Content>
+									as={KSelect.Content}
+									class={cx(topSlideAnimateClasses, "z-50")}
+								>
+									<MenuItemList<typeof KSelect.Listbox>
+										class="overflow-y-auto max-h-32"
+										as={KSelect.Listbox}
+									/>
+								</PopperContent>
+							</KSelect.Portal>
+						</KSelect>
+						<Slider
+							value={[project.background.fit.anchor.x * 100]}
+							onChange={(v) =>
+								setProject("background", "fit", "anchor", {
+									x: v[0] / 100,
+									y: v[0] / 100,
+								})
+							}
+							minValue={0}
+							maxValue={100}
+							step={1}
+							formatTooltip="%"
+						/>
+					</div>
+				</Field>
+			</Show>
+			<Field name="Rounded Corners" icon={<IconCapCorners class="size-4" />}>
+				<div class="flex flex-col gap-3">
+					<Slider
+						value={[project.background.rounding]}
+						onChange={(v) => setProject("background", "rounding", v[0])}
+						minValue={0}
+						maxValue={100}
+						step={0.1}
+						formatTooltip="%"
+					/>
+					<CornerStyleSelect
+						label="Corner Style"
+						value={project.background.roundingType}
 						onChange={(value) =>
 							setProject("background", "roundingType", value)
 						}
@@ -2038,6 +2611,148 @@ function BackgroundConfig(props: { scrollRef: HTMLDivElement }) {
 					}}
 				/>
 			</Field>
+			<Field
+				name="Background Changes"
+				icon={<IconLucideTimer class="size-4" />}
+			>
+				<div class="flex flex-col gap-2">
+					<For each={project.backgroundKeyframes ?? []}>
+						{(keyframe, index) => (
+							<div class="flex items-center gap-2 h-8 px-2 rounded-lg bg-gray-2 text-sm text-gray-12">
+								<span class="flex-1 tabular-nums">
+									Changes at {formatTime(keyframe.startMs / 1000)}
+								</span>
+								<button
+									type="button"
+									class="text-gray-11 hover:text-gray-12"
+									onClick={() =>
+										setProject(
+											"backgroundKeyframes",
+											(keyframes = []) =>
+												keyframes.filter((_, i) => i !== index()),
+										)
+									}
+								>
+									<IconCapTrash class="size-4" />
+								</button>
+							</div>
+						)}
+					</For>
+					<EditorButton onClick={addBackgroundKeyframe}>
+						Add current background at playhead
+					</EditorButton>
+					<span class="text-xs text-gray-11">
+						The video switches to the current background settings at the
+						playhead. Earlier parts keep whatever background you set next.
+					</span>
+				</div>
+			</Field>
+			<Field name="Layout Changes" icon={<IconCapPadding class="size-4" />}>
+				<div class="flex flex-col gap-2">
+					<For each={project.layoutKeyframes ?? []}>
+						{(keyframe, index) => (
+							<div class="flex items-center gap-2 h-8 px-2 rounded-lg bg-gray-2 text-sm text-gray-12">
+								<span class="flex-1 tabular-nums">
+									{formatTime(keyframe.startMs / 1000)}
+									<span class="text-gray-11">
+										{"⋅"}
+										{Math.round(keyframe.padding)}% padding
+									</span>
+								</span>
+								<button
+									type="button"
+									class="text-gray-11 hover:text-gray-12"
+									onClick={() =>
+										setProject("layoutKeyframes", (keyframes = []) =>
+											keyframes.filter((_, i) => i !== index()),
+										)
+									}
+								>
+									<IconCapTrash class="size-4" />
+								</button>
+							</div>
+						)}
+					</For>
+					<EditorButton onClick={addLayoutKeyframe}>
+						Add current layout at playhead
+					</EditorButton>
+					<span class="text-xs text-gray-11">
+						Padding, rounded corners and shadow spring to their current values
+						at the playhead.
+					</span>
+				</div>
+			</Field>
+			<Field name="Confetti" icon={<IconLucidePartyPopper class="size-4" />}>
+				<div class="flex flex-col gap-2">
+					<For each={project.confetti ?? []}>
+						{(burst, index) => (
+							<div class="flex items-center gap-2 h-8 px-2 rounded-lg bg-gray-2 text-sm text-gray-12">
+								<span class="flex-1 tabular-nums">
+									Bursts at {formatTime(burst.time)}
+								</span>
+								<button
+									type="button"
+									class="text-gray-11 hover:text-gray-12"
+									onClick={() =>
+										setProject("confetti", (bursts = []) =>
+											bursts.filter((_, i) => i !== index()),
+										)
+									}
+								>
+									<IconCapTrash class="size-4" />
+								</button>
+							</div>
+						)}
+					</For>
+					<div class="flex gap-2">
+						<EditorButton class="flex-1" onClick={addConfettiAtPlayhead}>
+							Add at playhead
+						</EditorButton>
+						<EditorButton class="flex-1" onClick={addConfettiAtNextClick}>
+							Add at next click
+						</EditorButton>
+					</div>
+					<span class="text-xs text-gray-11">
+						Throws confetti from the middle of the screen, or from where you
+						clicked, for celebrating a finished signup or a shipped feature.
+					</span>
+				</div>
+			</Field>
+			<CardConfig
+				name="Title Card"
+				cardKey="titleCard"
+				titlePlaceholder="Project name"
+			/>
+			<CardConfig
+				name="Outro Card"
+				cardKey="outroCard"
+				titlePlaceholder="Title"
+			/>
+			<For each={configurableEffects()}>
+				{(effect) => (
+					<Field
+						name={effect.name}
+						icon={<IconLucideSparkles class="size-4" />}
+					>
+						<div class="flex flex-col gap-2">
+							<For each={effect.params}>
+								{(param, index) => (
+									<div class="flex flex-col gap-1">
+										<span class="text-xs text-gray-11">{param.name}</span>
+										<Slider
+											value={[effectParam(effect, index())]}
+											onChange={(v) => setEffectParam(effect, index(), v[0])}
+											minValue={param.min ?? 0}
+											maxValue={param.max ?? 1}
+											step={0.01}
+										/>
+									</div>
+								)}
+							</For>
+						</div>
+					</Field>
+				)}
+			</For>
 			{/* <ComingSoonTooltip>
             <Field name="Inset" icon={<IconCapInset />}>
               <Slider
@@ -2053,6 +2768,119 @@ function BackgroundConfig(props: { scrollRef: HTMLDivElement }) {
 	);
 }
 
+function CardConfig(props: {
+	name: string;
+	cardKey: "titleCard" | "outroCard";
+	titlePlaceholder: string;
+}) {
+	const { project, setProject } = useEditorContext();
+	const [brandKit] = createResource(() => commands.getBrandKit());
+
+	const card = () => project[props.cardKey];
+	const brandColors = () => brandKit()?.colors ?? [];
+
+	const updateCard = (update: Partial<CardConfiguration>) =>
+		setProject(props.cardKey, (card) => ({ ...card, ...update }));
+
+	const textColor = (): [number, number, number] => {
+		const rgb = hexToRgb(card()?.textColor ?? "#ffffff");
+		return rgb ? [rgb[0], rgb[1], rgb[2]] : [255, 255, 255];
+	};
+
+	const setBackgroundColor = (hex: string) => {
+		const rgb = hexToRgb(hex);
+		if (!rgb) return;
+		const [r, g, b] = rgb;
+		updateCard({ background: { type: "color", value: [r, g, b] } });
+	};
+
+	return (
+		<Field
+			name={props.name}
+			icon={<IconLucideType class="size-4" />}
+			value={
+				<Toggle
+					checked={card()?.enabled ?? false}
+					onChange={(enabled) => updateCard({ enabled })}
+				/>
+			}
+		>
+			<Show when={card()?.enabled}>
+				<div class="flex flex-col gap-2">
+					<Input
+						placeholder={props.titlePlaceholder}
+						value={card()?.title ?? ""}
+						onChange={(e) => updateCard({ title: e.currentTarget.value })}
+					/>
+					<Input
+						placeholder="Subtitle"
+						value={card()?.subtitle ?? ""}
+						onChange={(e) => updateCard({ subtitle: e.currentTarget.value })}
+					/>
+					<Input
+						placeholder="Author"
+						value={card()?.author ?? ""}
+						onChange={(e) => updateCard({ author: e.currentTarget.value })}
+					/>
+					<Subfield name="Duration" class="gap-4 items-center">
+						<Slider
+							value={[card()?.duration ?? 2]}
+							onChange={(v) => updateCard({ duration: v[0] })}
+							minValue={0.5}
+							maxValue={10}
+							step={0.1}
+							formatTooltip="s"
+						/>
+					</Subfield>
+					<Subfield name="Text Color">
+						<RgbInput
+							value={textColor()}
+							onChange={(value) => updateCard({ textColor: rgbToHex(value) })}
+						/>
+					</Subfield>
+					<Show when={brandColors().length > 0}>
+						<Subfield name="Brand Text">
+							<BrandSwatches
+								colors={brandColors()}
+								onSelect={(textColor) => updateCard({ textColor })}
+							/>
+						</Subfield>
+						<Subfield name="Brand Background">
+							<BrandSwatches colors={brandColors()} onSelect={setBackgroundColor} />
+						</Subfield>
+					</Show>
+					<Show when={card()?.background}>
+						<EditorButton onClick={() => updateCard({ background: null })}>
+							Use project background
+						</EditorButton>
+					</Show>
+				</div>
+			</Show>
+		</Field>
+	);
+}
+
+function BrandSwatches(props: {
+	colors: BrandColor[];
+	onSelect: (value: string) => void;
+}) {
+	return (
+		<div class="flex flex-wrap gap-2 justify-end">
+			<For each={props.colors}>
+				{(color) => (
+					<button
+						type="button"
+						title={color.name}
+						class="rounded-lg size-6 ring-1 ring-gray-5 hover:opacity-70"
+						style={{ background: color.value }}
+						onClick={() => props.onSelect(color.value)}
+					/>
+				)}
+			</For>
+		</div>
+	);
+}
+
 function CameraConfig(props: { scrollRef: HTMLDivElement }) {
 	const { project, setProject } = useEditorContext();
 
@@ -2065,44 +2893,12 @@ function CameraConfig(props: { scrollRef: HTMLDivElement }) {
 				<div class="flex flex-col gap-6">
 					<div>
 						<Subfield name="Position" />
-						<KRadioGroup
-							value={`${project.camera.position.x}:${project.camera.position.y}`}
-							onChange={(v) => {
-								const [x, y] = v.split(":");
-								setProject("camera", "position", { x, y } as any);
-							}}
-							class="mt-[0.75rem] rounded-[0.5rem] border border-gray-3 bg-gray-2 w-full h-[7.5rem] relative"
-						>
-							<For
-								each={[
-									{ x: "left", y: "top" } as const,
-									{ x: "center", y: "top" } as const,
-									{ x: "right", y: "top" } as const,
-									{ x: "left", y: "bottom" } as const,
-									{ x: "center", y: "bottom" } as const,
-									{ x: "right", y: "bottom" } as const,
-								]}
-							>
-								{(item) => (
-									<RadioGroup.Item value={`${item.x}:${item.y}`}>
-										<RadioGroup.ItemInput class="peer" />
-										<RadioGroup.ItemControl
-											class={cx(
-												"cursor-pointer size-6 shrink-0 rounded-[0.375rem] bg-gray-5 absolute flex justify-center items-center ui-checked:bg-blue-9 focus-visible:outline peer-focus-visible:outline outline-2 outline-blue-9 outline-offset-2 transition-colors duration-100",
-												item.x === "left"
-													? "left-2"
-													: item.x === "right"
-														? "right-2"
-														: "left-1/2 transform -translate-x-1/2",
-												item.y === "top" ? "top-2" : "bottom-2",
-											)}
-										>
-											<div class="size-[0.5rem] shrink-0 bg-solid-white rounded-full" />
-										</RadioGroup.ItemControl>
-									</RadioGroup.Item>
-								)}
-							</For>
-						</KRadioGroup>
+						<PositionPicker
+							value={project.camera.position}
+							onChange={(position) =>
+								setProject("camera", "position", position)
+							}
+						/>
 					</div>
 					<Subfield name="Hide Camera">
 						<Toggle
@@ -2285,6 +3081,52 @@ function CameraConfig(props: { scrollRef: HTMLDivElement }) {
 	);
 }
 
+function PositionPicker(props: {
+	value: CameraPosition;
+	onChange: (position: CameraPosition) => void;
+}) {
+	return (
+		<KRadioGroup
+			value={`${props.value.x}:${props.value.y}`}
+			onChange={(v) => {
+				const [x, y] = v.split(":");
+				props.onChange({ x, y } as CameraPosition);
+			}}
+			class="mt-[0.75rem] rounded-[0.5rem] border border-gray-3 bg-gray-2 w-full h-[7.5rem] relative"
+		>
+			<For
+				each={[
+					{ x: "left", y: "top" } as const,
+					{ x: "center", y: "top" } as const,
+					{ x: "right", y: "top" } as const,
+					{ x: "left", y: "bottom" } as const,
+					{ x: "center", y: "bottom" } as const,
+					{ x: "right", y: "bottom" } as const,
+				]}
+			>
+				{(item) => (
+					<RadioGroup.Item value={`${item.x}:${item.y}`}>
+						<RadioGroup.ItemInput class="peer" />
+						<RadioGroup.ItemControl
+							class={cx(
+								"cursor-pointer size-6 shrink-0 rounded-[0.375rem] bg-gray-5 absolute flex justify-center items-center ui-checked:bg-blue-9 focus-visible:outline peer-focus-visible:outline outline-2 outline-blue-9 outline-offset-2 transition-colors duration-100",
+								item.x === "left"
+									? "left-2"
+									: item.x === "right"
+										? "right-2"
+										: "left-1/2 transform -translate-x-1/2",
+								item.y === "top" ? "top-2" : "bottom-2",
+							)}
+						>
+							<div class="size-[0.5rem] shrink-0 bg-solid-white rounded-full" />
+						</RadioGroup.ItemControl>
+					</RadioGroup.Item>
+				)}
+			</For>
+		</KRadioGroup>
+	);
+}
+
 function CornerStyleSelect(props: {
 	label?: string;
 	value: CornerRoundingType;
@@ -2603,6 +3445,172 @@ function TextSegmentConfig(props: {
 	);
 }
 
+function CursorSegmentConfig(props: {
+	segmentIndex: number;
+	segment: CursorSegment;
+}) {
+	const { setProject } = useEditorContext();
+
+	return (
+		<Field
+			name={`Hidden Cursor ${props.segmentIndex + 1}`}
+			icon={<IconLucideMousePointer2Off class="size-4" />}
+		>
+			<Subfield name="Fade Duration" />
+			<Slider
+				value={[clampNumber(props.segment.fadeDuration, 0, 1)]}
+				onChange={([value]) =>
+					setProject(
+						"timeline",
+						"cursorSegments",
+						props.segmentIndex,
+						"fadeDuration",
+						clampNumber(value, 0, 1),
+					)
+				}
+				minValue={0}
+				maxValue={1}
+				step={0.01}
+				formatTooltip="s"
+			/>
+		</Field>
+	);
+}
+
+function ReactionSegmentConfig(props: {
+	segmentIndex: number;
+	segment: ReactionSegment;
+}) {
+	const { setProject } = useEditorContext();
+
+	const updateSegment = (fn: (segment: ReactionSegment) => void) => {
+		setProject(
+			"timeline",
+			"reactionSegments",
+			produce((segments) => {
+				const target = segments?.[props.segmentIndex];
+				if (!target) return;
+				fn(target);
+			}),
+		);
+	};
+
+	return (
+		<div class="space-y-4">
+			<Field
+				name={`Reaction ${props.segmentIndex + 1}`}
+				icon={<IconLucideSmilePlus class="size-4" />}
+			>
+				<div class="flex flex-wrap gap-2">
+					<For each={reactionEmoji}>
+						{(emoji) => (
+							<button
+								type="button"
+								class={cx(
+									"size-9 rounded-lg border text-lg transition-colors",
+									props.segment.emoji === emoji
+										? "border-blue-8 bg-blue-3/40"
+										: "border-gray-3 hover:bg-gray-3",
+								)}
+								onClick={() =>
+									updateSegment((segment) => {
+										segment.emoji = emoji;
+									})
+								}
+							>
+								{emoji}
+							</button>
+						)}
+					</For>
+					<TextInput
+						class="w-16 px-2 rounded-lg border border-gray-3 bg-gray-2 text-center text-lg text-gray-12"
+						value={props.segment.emoji}
+						onChange={(e) => {
+							const emoji = e.currentTarget.value.trim();
+							if (!emoji) return;
+							updateSegment((segment) => {
+								segment.emoji = emoji;
+							});
+						}}
+					/>
+				</div>
+			</Field>
+			<Field name="Animation" icon={<IconLucideSparkles class="size-4" />}>
+				<RadioGroup
+					class="grid grid-cols-3 gap-2"
+					value={props.segment.animation}
+					onChange={(value) =>
+						updateSegment((segment) => {
+							segment.animation = value as ReactionSegment["animation"];
+						})
+					}
+				>
+					{[
+						{ value: "pop", label: "Pop" },
+						{ value: "slideUp", label: "Slide up" },
+						{ value: "none", label: "None" },
+					].map((option) => (
+						<RadioGroup.Item
+							value={option.value}
+							class="rounded-lg border border-gray-3 transition-colors ui-checked:border-blue-8 ui-checked:bg-blue-3/40"
+						>
+							<RadioGroup.ItemInput class="sr-only" />
+							<RadioGroup.ItemLabel class="flex cursor-pointer items-center gap-2 p-2 text-sm text-gray-12">
+								<RadioGroup.ItemControl class="size-4 rounded-full border border-gray-7 ui-checked:border-blue-9 ui-checked:bg-blue-9" />
+								{option.label}
+							</RadioGroup.ItemLabel>
+						</RadioGroup.Item>
+					))}
+				</RadioGroup>
+			</Field>
+			<Field name="Size" icon={<IconCapEnlarge class="size-4" />}>
+				<Slider
+					value={[props.segment.scale * 100]}
+					onChange={([value]) =>
+						updateSegment((segment) => {
+							segment.scale = value / 100;
+						})
+					}
+					minValue={5}
+					maxValue={50}
+					step={1}
+					formatTooltip="%"
+				/>
+			</Field>
+			<Field name="Position" icon={<IconLucideMove class="size-4" />}>
+				<div class="space-y-3">
+					<Subfield name="Horizontal" />
+					<Slider
+						value={[props.segment.center.x * 100]}
+						onChange={([value]) =>
+							updateSegment((segment) => {
+								segment.center.x = value / 100;
+							})
+						}
+						minValue={0}
+						maxValue={100}
+						step={1}
+						formatTooltip="%"
+					/>
+					<Subfield name="Vertical" />
+					<Slider
+						value={[props.segment.center.y * 100]}
+						onChange={([value]) =>
+							updateSegment((segment) => {
+								segment.center.y = value / 100;
+							})
+						}
+						minValue={0}
+						maxValue={100}
+						step={1}
+						formatTooltip="%"
+					/>
+				</div>
+			</Field>
+		</div>
+	);
+}
+
 function MaskSegmentConfig(props: {
 	segmentIndex: number;
 	segment: MaskSegment;
@@ -2909,6 +3917,26 @@ function ZoomSegmentConfig(props: {
 		projectHistory,
 	} = useEditorContext();
 
+	const easingKind = () => {
+		if (props.segment.instantAnimation) return "instant";
+		const easing = props.segment.easing ?? "spring";
+		return typeof easing === "string" ? easing : "cubicBezier";
+	};
+	const bezier = () => {
+		const easing = props.segment.easing;
+		return typeof easing === "object" ? easing.cubicBezier : DEFAULT_ZOOM_BEZIER;
+	};
+	const setEasing = (easing: ZoomEasing) =>
+		setProject(
+			"timeline",
+			"zoomSegments",
+			props.segmentIndex,
+			produce((segment) => {
+				segment.easing = easing;
+				segment.instantAnimation = false;
+			}),
+		);
+
 	const states = {
 		manual:
 			props.segment.mode === "auto"
@@ -3194,10 +4222,265 @@ function ZoomSegmentConfig(props: {
 					</KTabs.Content>
 				</KTabs>
 			</Field>
+			<Field name="Zoom From Click" icon={<IconLucideMousePointerClick />}>
+				<Subfield name="Start the zoom at the click that triggered it">
+					<Toggle
+						checked={props.segment.zoomFromClick ?? false}
+						onChange={(v) =>
+							setProject(
+								"timeline",
+								"zoomSegments",
+								props.segmentIndex,
+								"zoomFromClick",
+								v,
+							)
+						}
+					/>
+				</Subfield>
+			</Field>
+			<Field name="Easing" icon={<IconLucideSpline />}>
+				<RadioGroup
+					class="flex flex-col gap-2"
+					value={easingKind()}
+					onChange={(value) =>
+						setEasing(
+							value === "cubicBezier"
+								? { cubicBezier: bezier() }
+								: (value as "spring" | "instant"),
+						)
+					}
+				>
+					{ZOOM_EASING_OPTIONS.map((option) => (
+						<RadioGroup.Item
+							value={option.value}
+							class="rounded-lg border border-gray-3 transition-colors ui-checked:border-blue-8 ui-checked:bg-blue-3/40"
+						>
+							<RadioGroup.ItemInput class="sr-only" />
+							<RadioGroup.ItemLabel class="flex cursor-pointer items-start gap-3 p-3">
+								<RadioGroup.ItemControl class="mt-1 size-4 rounded-full border border-gray-7 ui-checked:border-blue-9 ui-checked:bg-blue-9" />
+								<div class="flex flex-col text-left">
+									<span class="text-sm font-medium text-gray-12">
+										{option.label}
+									</span>
+									<span class="text-xs text-gray-11">
+										{option.description}
+									</span>
+								</div>
+							</RadioGroup.ItemLabel>
+						</RadioGroup.Item>
+					))}
+				</RadioGroup>
+				<Show when={easingKind() === "cubicBezier"}>
+					<EasingCurveEditor
+						value={bezier()}
+						onChange={(value) => setEasing({ cubicBezier: value })}
+					/>
+				</Show>
+			</Field>
 		</>
 	);
 }
 
+const CURVE_Y_MIN = -0.5;
+const CURVE_Y_MAX = 1.5;
+
+function EasingCurveEditor(props: {
+	value: CubicBezierPoints;
+	onChange: (value: CubicBezierPoints) => void;
+}) {
+	let svgRef!: SVGSVGElement;
+
+	const toSvg = (x: number, y: number) => ({
+		x: x * 100,
+		y: ((CURVE_Y_MAX - y) / (CURVE_Y_MAX - CURVE_Y_MIN)) * 100,
+	});
+
+	const start = () => toSvg(0, 0);
+	const end = () => toSvg(1, 1);
+	const first = () => toSvg(props.value.x1, props.value.y1);
+	const second = () => toSvg(props.value.x2, props.value.y2);
+
+	const round = (v: number) => Math.round(v * 100) / 100;
+
+	const startDrag = (handle: "first" | "second") => {
+		const bounds = svgRef.getBoundingClientRect();
+
+		createRoot((dispose) =>
+			createEventListenerMap(window, {
+				mouseup: () => dispose(),
+				mousemove: (moveEvent) => {
+					const x = round(
+						Math.min(
+							Math.max((moveEvent.clientX - bounds.left) / bounds.width, 0),
+							1,
+						),
+					);
+					const y = round(
+						Math.min(
+							Math.max(
+								CURVE_Y_MAX -
+									((moveEvent.clientY - bounds.top) / bounds.height) *
+										(CURVE_Y_MAX - CURVE_Y_MIN),
+								CURVE_Y_MIN,
+							),
+							CURVE_Y_MAX,
+						),
+					);
+
+					props.onChange(
+						handle === "first"
+							? { ...props.value, x1: x, y1: y }
+							: { ...props.value, x2: x, y2: y },
+					);
+				},
+			}),
+		);
+	};
+
+	return (
+		<div class="flex flex-col gap-2 items-center">
+			<svg
+				ref={svgRef}
+				viewBox="0 0 100 100"
+				class="w-full max-w-[220px] aspect-square rounded-lg border border-gray-3 bg-gray-2 overflow-visible"
+			>
+				<rect
+					x={start().x}
+					y={end().y}
+					width={end().x - start().x}
+					height={start().y - end().y}
+					class="fill-gray-3/50"
+				/>
+				<line
+					x1={start().x}
+					y1={start().y}
+					x2={first().x}
+					y2={first().y}
+					class="stroke-gray-8"
+					stroke-width={0.75}
+				/>
+				<line
+					x1={end().x}
+					y1={end().y}
+					x2={second().x}
+					y2={second().y}
+					class="stroke-gray-8"
+					stroke-width={0.75}
+				/>
+				<path
+					d={`M ${start().x} ${start().y} C ${first().x} ${first().y}, ${second().x} ${second().y}, ${end().x} ${end().y}`}
+					class="fill-none stroke-blue-9"
+					stroke-width={2}
+				/>
+				<circle
+					cx={first().x}
+					cy={first().y}
+					r={4}
+					class="cursor-grab fill-gray-1 stroke-blue-9"
+					stroke-width={1.5}
+					onMouseDown={() => startDrag("first")}
+				/>
+				<circle
+					cx={second().x}
+					cy={second().y}
+					r={4}
+					class="cursor-grab fill-gray-1 stroke-blue-9"
+					stroke-width={1.5}
+					onMouseDown={() => startDrag("second")}
+				/>
+			</svg>
+			<span class="text-xs font-mono text-gray-11">
+				cubic-bezier({props.value.x1}, {props.value.y1}, {props.value.x2},{" "}
+				{props.value.y2})
+			</span>
+		</div>
+	);
+}
+
+const SPRING_SCENARIO_OPTIONS = [
+	{ label: "Step", value: "step" },
+	{ label: "Ramp", value: "ramp" },
+	{ label: "Jitter", value: "jitter" },
+] satisfies Array<{ label: string; value: SpringScenario }>;
+
+const SPRING_Y_MIN = -0.25;
+const SPRING_Y_MAX = 1.25;
+
+function SpringResponseGraph(props: {
+	tension: number;
+	mass: number;
+	friction: number;
+}) {
+	const [scenario, setScenario] = createSignal<SpringScenario>("step");
+	const [samples] = createResource(
+		() => ({
+			config: {
+				tension: props.tension,
+				mass: props.mass,
+				friction: props.friction,
+			},
+			scenario: scenario(),
+		}),
+		({ config, scenario }) => commands.simulateSpring(config, scenario),
+	);
+
+	const path = (key: "target" | "position") => {
+		const points = samples.latest ?? [];
+		const duration = points[points.length - 1]?.timeMs || 1;
+
+		return points
+			.map((sample, i) => {
+				const value = Math.min(Math.max(sample[key], SPRING_Y_MIN), SPRING_Y_MAX);
+				const x = (sample.timeMs / duration) * 100;
+				const y = ((SPRING_Y_MAX - value) / (SPRING_Y_MAX - SPRING_Y_MIN)) * 50;
+				return `${i === 0 ? "M" : "L"} ${x} ${y}`;
+			})
+			.join(" ");
+	};
+
+	return (
+		<div class="flex flex-col gap-2">
+			<div class="flex gap-1.5">
+				<For each={SPRING_SCENARIO_OPTIONS}>
+					{(option) => (
+						<button
+							type="button"
+							class={cx(
+								"flex-1 py-1 text-xs font-medium rounded-lg border transition-colors",
+								scenario() === option.value
+									? "bg-gray-3 border-gray-5 text-gray-12"
+									: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+							)}
+							onClick={() => setScenario(option.value)}
+						>
+							{option.label}
+						</button>
+					)}
+				</For>
+			</div>
+			<svg
+				viewBox="0 0 100 50"
+				preserveAspectRatio="none"
+				class="w-full h-24 rounded-lg border border-gray-3 bg-gray-2"
+			>
+				<path
+					d={path("target")}
+					class="fill-none stroke-gray-8"
+					stroke-width={1}
+					stroke-dasharray="3 2"
+					vector-effect="non-scaling-stroke"
+				/>
+				<path
+					d={path("position")}
+					class="fill-none stroke-blue-9"
+					stroke-width={2}
+					vector-effect="non-scaling-stroke"
+				/>
+			</svg>
+		</div>
+	);
+}
+
 function ClipSegmentConfig(props: {
 	segmentIndex: number;
 	segment: TimelineSegment;
@@ -586,26 +586,33 @@ function Dialogs() {
 								const [crop, setCrop] = createSignal(CROP_ZERO);
 								const [aspect, setAspect] = createSignal<Ratio | null>(null);
 
-								const [frameBlobUrl, setFrameBlobUrl] = createSignal<
-									string | null
-								>(null);
+								const [frameUrl, setFrameUrl] = createSignal<string | null>(
+									null,
+								);
 
 								commands
-									.getDisplayFrameForCropping(FPS)
-									.then((pngBytes) => {
-										const blob = new Blob([new Uint8Array(pngBytes)], {
-											type: "image/png",
+									.getDisplayFrameForCropping(FPS, {
+										maxSize: 2560,
+										jpegQuality: 90,
+										toFile: true,
+									})
+									.then((frame) => {
+										if (frame.type === "file") {
+											setFrameUrl(convertFileSrc(frame.path));
+											return;
+										}
+										const blob = new Blob([new Uint8Array(frame.data)], {
+											type: "image/jpeg",
 										});
-										const url = URL.createObjectURL(blob);
-										setFrameBlobUrl(url);
+										setFrameUrl(URL.createObjectURL(blob));
 									})
 									.catch((error: unknown) => {
 										console.warn("Display frame fetch failed:", error);
 									});
 
 								onCleanup(() => {
-									const url = frameBlobUrl();
-									if (url) {
+									const url = frameUrl();
+									if (url?.startsWith("blob:")) {
 										URL.revokeObjectURL(url);
 									}
 								});
@@ -774,7 +781,7 @@ function Dialogs() {
 															class="shadow pointer-events-none max-h-[70vh]"
 															alt="Current frame"
 															src={
-																frameBlobUrl() ??
+																frameUrl() ??
 																convertFileSrc(
 																	`${editorInstance.path}/screenshots/display.jpg`,
 																)
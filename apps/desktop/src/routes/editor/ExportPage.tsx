@@ -4,12 +4,17 @@ import { makePersisted } from "@solid-primitives/storage";
 import { createMutation } from "@tanstack/solid-query";
 import { Channel } from "@tauri-apps/api/core";
 import { CheckMenuItem, Menu } from "@tauri-apps/api/menu";
-import { ask, save as saveDialog } from "@tauri-apps/plugin-dialog";
+import {
+	ask,
+	open as openDialog,
+	save as saveDialog,
+} from "@tauri-apps/plugin-dialog";
 import { remove } from "@tauri-apps/plugin-fs";
 import { type as ostype } from "@tauri-apps/plugin-os";
 import { cx } from "cva";
 import {
 	createEffect,
+	createResource,
 	createSignal,
 	For,
 	Match,
@@ -27,13 +32,18 @@ import Tooltip from "~/components/Tooltip";
 import CaptionControlsWindows11 from "~/components/titlebar/controls/CaptionControlsWindows11";
 import { authStore } from "~/store";
 import { trackEvent } from "~/utils/analytics";
-import { createExportTask } from "~/utils/export";
+import { createExportPartsTask, createExportTask } from "~/utils/export";
+import { formatMessage, MessageError } from "~/utils/messages";
 import { createOrganizationsQuery } from "~/utils/queries";
 import {
 	commands,
 	type ExportCompression,
+	type ExportResult,
 	type ExportSettings,
+	type ExportSplit,
 	type FramesRendered,
+	type RenderTimings,
+	type TimelapseSettings,
 	type UploadProgress,
 } from "~/utils/tauri";
 import { type RenderState, useEditorContext } from "./context";
@@ -64,6 +74,7 @@ export const FPS_OPTIONS = [
 	{ label: "15 FPS", value: 15 },
 	{ label: "30 FPS", value: 30 },
 	{ label: "60 FPS", value: 60 },
+	{ label: "120 FPS", value: 120 },
 ] satisfies Array<{ label: string; value: number }>;
 
 export const GIF_FPS_OPTIONS = [
@@ -74,6 +85,18 @@ export const GIF_FPS_OPTIONS = [
 	{ label: "30 FPS", value: 30 },
 ] satisfies Array<{ label: string; value: number }>;
 
+const GIF_MAX_WIDTH_OPTIONS = [
+	{ label: "Full", value: null },
+	{ label: "800px", value: 800 },
+	{ label: "640px", value: 640 },
+	{ label: "480px", value: 480 },
+] satisfies Array<{ label: string; value: number | null }>;
+
+const GIF_LOOP_OPTIONS = [
+	{ label: "Forever", value: null },
+	{ label: "Play once", value: 0 },
+] satisfies Array<{ label: string; value: number | null }>;
+
 export const EXPORT_TO_OPTIONS = [
 	{
 		label: "File",
@@ -111,8 +134,45 @@ interface Settings {
 	resolution: { label: string; value: string; width: number; height: number };
 	compression: ExportCompression;
 	organizationId?: string | null;
+	audioStems?: boolean;
+	split?: ExportSplit | null;
+	timelapse?: TimelapseSettings | null;
+	frameInterpolation?: boolean;
+	leadInMs?: number;
+	leadOutMs?: number;
+	gifMaxWidth?: number | null;
+	gifRepeat?: number | null;
 }
 
+const HOLD_OPTIONS = [
+	{ label: "Off", value: 0 },
+	{ label: "0.5s", value: 500 },
+	{ label: "1s", value: 1000 },
+	{ label: "2s", value: 2000 },
+];
+
+const HOLD_ROWS = [
+	{ label: "Start", key: "leadInMs" },
+	{ label: "End", key: "leadOutMs" },
+] as const;
+
+const SPLIT_OPTIONS: { label: string; value: ExportSplit | null }[] = [
+	{ label: "Single file", value: null },
+	{ label: "15 min", value: { type: "duration", minutes: 15 } },
+	{ label: "30 min", value: { type: "duration", minutes: 30 } },
+	{ label: "60 min", value: { type: "duration", minutes: 60 } },
+	{ label: "At cuts", value: { type: "clips" } },
+	{ label: "At markers", value: { type: "markers" } },
+];
+
+const TIMELAPSE_SPEEDS: { label: string; value: number | null }[] = [
+	{ label: "Off", value: null },
+	{ label: "4×", value: 4 },
+	{ label: "10×", value: 10 },
+	{ label: "30×", value: 30 },
+	{ label: "60×", value: 60 },
+];
+
 export function ExportPage() {
 	const {
 		setDialog,
@@ -122,6 +182,8 @@ export function ExportPage() {
 		exportState,
 		meta,
 		refetchMeta,
+		project,
+		setProject,
 	} = useEditorContext();
 
 	const handleBack = () => {
@@ -145,6 +207,8 @@ export function ExportPage() {
 
 	const isCancellationError = (error: unknown) =>
 		error instanceof SilentError ||
+		(error instanceof MessageError &&
+			error.userMessage.key === "export.cancelled") ||
 		error === "Export cancelled" ||
 		(error instanceof Error && error.message === "Export cancelled");
 
@@ -352,42 +416,89 @@ export function ExportPage() {
 	});
 
 	let cancelCurrentExport: (() => void) | null = null;
+	const [pausedJob, setPausedJob] = createSignal<number | null>(null);
 
-	const exportWithSettings = (
-		onProgress: (progress: FramesRendered) => void,
-	) => {
+	const exportSettings = (): ExportSettings => {
 		const customBpp = advancedMode() && isCustomBpp() ? compressionBpp() : null;
-		const { promise, cancel } = createExportTask(
-			projectPath,
-			settings.format === "Mp4"
-				? {
-						format: "Mp4",
-						fps: settings.fps,
-						resolution_base: {
-							x: settings.resolution.width,
-							y: settings.resolution.height,
-						},
-						compression: settings.compression,
-						custom_bpp: customBpp,
-						force_ffmpeg_decoder: forceFfmpegDecoder(),
-					}
-				: {
-						format: "Gif",
-						fps: settings.fps,
-						resolution_base: {
-							x: settings.resolution.width,
-							y: settings.resolution.height,
-						},
-						quality: null,
+		return settings.format === "Mp4"
+			? {
+					format: "Mp4",
+					fps: settings.fps,
+					resolution_base: {
+						x: settings.resolution.width,
+						y: settings.resolution.height,
 					},
-			onProgress,
-		);
-		cancelCurrentExport = cancel;
-		return promise.finally(() => {
-			if (cancelCurrentExport === cancel) cancelCurrentExport = null;
+					compression: settings.compression,
+					custom_bpp: customBpp,
+					force_ffmpeg_decoder: forceFfmpegDecoder(),
+					lead_in_ms: settings.leadInMs ?? 0,
+					lead_out_ms: settings.leadOutMs ?? 0,
+					audio_stems:
+						settings.exportTo === "file" && (settings.audioStems ?? false),
+					timelapse: settings.timelapse ?? null,
+					frame_interpolation: settings.frameInterpolation ?? false,
+				}
+			: {
+					format: "Gif",
+					fps: settings.fps,
+					resolution_base: {
+						x: settings.resolution.width,
+						y: settings.resolution.height,
+					},
+					quality: null,
+					max_width: settings.gifMaxWidth ?? null,
+					repeat: settings.gifRepeat ?? null,
+				};
+	};
+
+	const [validation] = createResource(exportSettings, (settings) =>
+		commands.validateExport(projectPath, settings).catch(() => null),
+	);
+	const hasProblems = () => (validation.latest?.problems.length ?? 0) > 0;
+
+	const trackCancel = <T,>(task: {
+		promise: Promise<T>;
+		cancel: () => void;
+	}) => {
+		cancelCurrentExport = task.cancel;
+		setPausedJob(null);
+		return task.promise.finally(() => {
+			if (cancelCurrentExport === task.cancel) cancelCurrentExport = null;
+			setPausedJob(null);
 		});
 	};
 
+	const notifyAdjustment = <T extends ExportResult | ExportResult[]>(
+		result: T,
+	) => {
+		const results = Array.isArray(result) ? result : [result];
+		const adjustment = results.find((r) => r.adjustment)?.adjustment;
+		if (adjustment) toast(formatMessage(adjustment));
+		return result;
+	};
+
+	const exportWithSettings = (
+		onProgress: (progress: FramesRendered) => void,
+	) =>
+		trackCancel(
+			createExportTask(projectPath, exportSettings(), onProgress),
+		).then(notifyAdjustment);
+
+	const exportPartsWithSettings = (
+		split: ExportSplit,
+		destination: string,
+		onProgress: (progress: FramesRendered) => void,
+	) =>
+		trackCancel(
+			createExportPartsTask(
+				projectPath,
+				exportSettings(),
+				split,
+				destination,
+				onProgress,
+			),
+		).then(notifyAdjustment);
+
 	const [outputPath, setOutputPath] = createSignal<string | null>(null);
 	const [isCancelled, setIsCancelled] = createSignal(false);
 
@@ -401,6 +512,11 @@ export function ExportPage() {
 			setIsCancelled(true);
 			cancelCurrentExport?.();
 			cancelCurrentExport = null;
+			const job = pausedJob();
+			if (job !== null) {
+				setPausedJob(null);
+				await commands.resumeExport(job).catch(() => {});
+			}
 			setExportState({ type: "idle" });
 			const path = outputPath();
 			if (path) {
@@ -413,15 +529,59 @@ export function ExportPage() {
 		}
 	};
 
+	const togglePause = async (jobId: number) => {
+		if (pausedJob() === jobId) {
+			await commands.resumeExport(jobId);
+			setPausedJob(null);
+		} else {
+			await commands.pauseExport(jobId);
+			setPausedJob(jobId);
+		}
+	};
+
+	const RenderActions = (props: { state: RenderState }) => (
+		<div class="flex gap-2 justify-center mt-4">
+			<Show when={props.state.type === "rendering" && props.state.progress}>
+				{(progress) => (
+					<Button
+						variant="ghost"
+						size="sm"
+						onClick={() => togglePause(progress().jobId)}
+					>
+						{pausedJob() === progress().jobId ? "Resume" : "Pause"}
+					</Button>
+				)}
+			</Show>
+			<Button
+				variant="ghost"
+				size="sm"
+				onClick={handleCancel}
+				class="hover:bg-red-500 hover:text-white"
+			>
+				Cancel
+			</Button>
+		</div>
+	);
+
+	const renderProgress = (progress: FramesRendered) =>
+		setExportState({
+			type: "rendering",
+			progress,
+			warnings: [
+				...(exportState.type === "rendering" ? exportState.warnings : []),
+				...progress.warnings,
+			],
+		});
+
 	const copy = createMutation(() => ({
 		mutationFn: async () => {
 			setIsCancelled(false);
 			if (exportState.type !== "idle") return;
 			setExportState(reconcile({ action: "copy", type: "starting" }));
 
-			const outputPath = await exportWithSettings((progress) => {
+			const { path: outputPath } = await exportWithSettings((progress) => {
 				if (isCancelled()) throw new SilentError("Cancelled");
-				setExportState({ type: "rendering", progress });
+				renderProgress(progress);
 			});
 
 			if (isCancelled()) throw new SilentError("Cancelled");
@@ -456,15 +616,17 @@ export function ExportPage() {
 			if (exportState.type !== "idle") return;
 
 			const extension = settings.format === "Gif" ? "gif" : "mp4";
-			const savePath = await saveDialog({
-				filters: [
-					{
-						name: `${extension.toUpperCase()} filter`,
-						extensions: [extension],
-					},
-				],
-				defaultPath: `~/Desktop/${meta().prettyName}.${extension}`,
-			});
+			const savePath =
+				(await commands.resolveOutputPath(projectPath, extension)) ??
+				(await saveDialog({
+					filters: [
+						{
+							name: `${extension.toUpperCase()} filter`,
+							extensions: [extension],
+						},
+					],
+					defaultPath: `~/Desktop/${meta().prettyName}.${extension}`,
+				}));
 			if (!savePath) {
 				throw new SilentError("Save dialog cancelled");
 			}
@@ -479,10 +641,25 @@ export function ExportPage() {
 				path: savePath,
 			});
 
-			const videoPath = await exportWithSettings((progress) => {
+			const onProgress = (progress: FramesRendered) => {
 				if (isCancelled()) throw new SilentError("Cancelled");
-				setExportState({ type: "rendering", progress });
-			});
+				renderProgress(progress);
+			};
+
+			const split =
+				settings.format === "Mp4" && !settings.timelapse ? settings.split : null;
+			if (split) {
+				const parts = await exportPartsWithSettings(
+					split,
+					savePath,
+					onProgress,
+				);
+				setOutputPath(parts[0]?.path ?? null);
+				setExportState({ type: "done" });
+				return;
+			}
+
+			const { path: videoPath } = await exportWithSettings(onProgress);
 
 			if (isCancelled()) throw new SilentError("Cancelled");
 
@@ -536,7 +713,7 @@ export function ExportPage() {
 
 			await exportWithSettings((progress) => {
 				if (isCancelled()) throw new SilentError("Cancelled");
-				setExportState({ type: "rendering", progress });
+				renderProgress(progress);
 			});
 
 			if (isCancelled()) throw new SilentError("Cancelled");
@@ -831,6 +1008,34 @@ export function ExportPage() {
 									</button>
 								</Show>
 							</Suspense>
+
+							<Show when={settings.exportTo === "file"}>
+								<button
+									type="button"
+									class="w-full flex items-center justify-between gap-2 px-3 py-2 rounded-lg bg-gray-3 hover:bg-gray-4 transition-colors text-sm"
+									onClick={async () => {
+										const dir = await openDialog({
+											directory: true,
+											defaultPath: project.exportDirectory ?? undefined,
+										});
+										if (typeof dir === "string") setProject("exportDirectory", dir);
+									}}
+								>
+									<span class="text-gray-11 shrink-0">Save to</span>
+									<span class="text-gray-12 truncate">
+										{project.exportDirectory ?? "Default folder"}
+									</span>
+								</button>
+								<Show when={project.exportDirectory}>
+									<button
+										type="button"
+										class="text-xs text-gray-11 hover:text-gray-12"
+										onClick={() => setProject("exportDirectory", null)}
+									>
+										Use default folder
+									</button>
+								</Show>
+							</Show>
 						</Field>
 
 						<Field name="Format" icon={<IconLucideVideo class="size-4" />}>
@@ -969,6 +1174,275 @@ export function ExportPage() {
 							</div>
 						</Field>
 
+						<Show when={settings.format === "Gif"}>
+							<Field
+								name="Max Width"
+								icon={<IconLucideMoveHorizontal class="size-4" />}
+							>
+								<div class="flex gap-1.5">
+									<For each={GIF_MAX_WIDTH_OPTIONS}>
+										{(option) => (
+											<button
+												type="button"
+												class={cx(
+													"flex-1 py-2 text-sm font-medium rounded-lg border transition-colors",
+													(settings.gifMaxWidth ?? null) === option.value
+														? "bg-gray-3 border-gray-5 text-gray-12"
+														: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+												)}
+												onClick={() =>
+													updateSettings("gifMaxWidth", option.value)
+												}
+											>
+												{option.label}
+											</button>
+										)}
+									</For>
+								</div>
+							</Field>
+
+							<Field name="Loop" icon={<IconLucideRepeat class="size-4" />}>
+								<div class="flex gap-1.5">
+									<For each={GIF_LOOP_OPTIONS}>
+										{(option) => (
+											<button
+												type="button"
+												class={cx(
+													"flex-1 py-2 text-sm font-medium rounded-lg border transition-colors",
+													(settings.gifRepeat ?? null) === option.value
+														? "bg-gray-3 border-gray-5 text-gray-12"
+														: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+												)}
+												onClick={() =>
+													updateSettings("gifRepeat", option.value)
+												}
+											>
+												{option.label}
+											</button>
+										)}
+									</For>
+								</div>
+							</Field>
+						</Show>
+
+						<Show when={settings.format === "Mp4" && settings.fps > 30}>
+							<Field
+								name="Motion"
+								icon={<IconLucideWind class="size-4" />}
+							>
+								<div class="flex flex-col gap-1.5">
+									<div class="flex gap-1.5">
+										<For
+											each={[
+												{ label: "Repeat frames", value: false },
+												{ label: "Interpolate", value: true },
+											]}
+										>
+											{(option) => (
+												<button
+													type="button"
+													class={cx(
+														"flex-1 py-2 text-sm font-medium rounded-lg border transition-colors",
+														(settings.frameInterpolation ?? false) ===
+															option.value
+															? "bg-gray-3 border-gray-5 text-gray-12"
+															: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+													)}
+													onClick={() =>
+														updateSettings("frameInterpolation", option.value)
+													}
+												>
+													{option.label}
+												</button>
+											)}
+										</For>
+									</div>
+									<Show when={settings.frameInterpolation}>
+										<p class="text-[10px] text-amber-11">
+											Fills in the frames between those recorded at a lower
+											frame rate. Exports take several times longer.
+										</p>
+									</Show>
+								</div>
+							</Field>
+						</Show>
+
+						<Show when={settings.format === "Mp4"}>
+							<Field
+								name="Hold"
+								icon={<IconLucideTimer class="size-4" />}
+							>
+								<div class="flex flex-col gap-1.5">
+									<For each={HOLD_ROWS}>
+										{(row) => (
+											<div class="flex gap-1.5 items-center">
+												<span class="w-10 text-xs text-gray-11">
+													{row.label}
+												</span>
+												<For each={HOLD_OPTIONS}>
+													{(option) => (
+														<button
+															type="button"
+															class={cx(
+																"flex-1 py-2 text-sm font-medium rounded-lg border transition-colors",
+																(settings[row.key] ?? 0) === option.value
+																	? "bg-gray-3 border-gray-5 text-gray-12"
+																	: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+															)}
+															onClick={() =>
+																updateSettings(row.key, option.value)
+															}
+														>
+															{option.label}
+														</button>
+													)}
+												</For>
+											</div>
+										)}
+									</For>
+								</div>
+							</Field>
+						</Show>
+
+						<Show
+							when={settings.format === "Mp4" && settings.exportTo === "file"}
+						>
+							<Field
+								name="Audio"
+								icon={<IconLucideAudioLines class="size-4" />}
+							>
+								<div class="flex gap-1.5">
+									<For
+										each={[
+											{ label: "Mixed", value: false },
+											{ label: "Mixed + WAV stems", value: true },
+										]}
+									>
+										{(option) => (
+											<button
+												type="button"
+												class={cx(
+													"flex-1 py-2 text-sm font-medium rounded-lg border transition-colors",
+													(settings.audioStems ?? false) === option.value
+														? "bg-gray-3 border-gray-5 text-gray-12"
+														: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+												)}
+												onClick={() =>
+													updateSettings("audioStems", option.value)
+												}
+											>
+												{option.label}
+											</button>
+										)}
+									</For>
+								</div>
+							</Field>
+						</Show>
+
+						<Show
+							when={
+								settings.format === "Mp4" &&
+								settings.exportTo === "file" &&
+								!settings.timelapse
+							}
+						>
+							<Field
+								name="Parts"
+								icon={<IconLucideScissors class="size-4" />}
+							>
+								<div class="flex gap-1.5">
+									<For each={SPLIT_OPTIONS}>
+										{(option) => (
+											<button
+												type="button"
+												class={cx(
+													"flex-1 py-2 text-xs font-medium rounded-lg border transition-colors",
+													JSON.stringify(settings.split ?? null) ===
+														JSON.stringify(option.value)
+														? "bg-gray-3 border-gray-5 text-gray-12"
+														: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+												)}
+												onClick={() => updateSettings("split", option.value)}
+											>
+												{option.label}
+											</button>
+										)}
+									</For>
+								</div>
+							</Field>
+						</Show>
+
+						<Show when={settings.format === "Mp4"}>
+							<Field
+								name="Timelapse"
+								icon={<IconLucideFastForward class="size-4" />}
+							>
+								<div class="flex flex-col gap-1.5">
+									<div class="flex gap-1.5">
+										<For each={TIMELAPSE_SPEEDS}>
+											{(option) => (
+												<button
+													type="button"
+													class={cx(
+														"flex-1 py-2 text-xs font-medium rounded-lg border transition-colors",
+														(settings.timelapse?.speed ?? null) === option.value
+															? "bg-gray-3 border-gray-5 text-gray-12"
+															: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+													)}
+													onClick={() =>
+														updateSettings(
+															"timelapse",
+															option.value === null
+																? null
+																: {
+																		speed: option.value,
+																		keepClicks:
+																			settings.timelapse?.keepClicks ?? false,
+																	},
+														)
+													}
+												>
+													{option.label}
+												</button>
+											)}
+										</For>
+									</div>
+									<Show when={settings.timelapse}>
+										{(timelapse) => (
+											<div class="flex gap-1.5">
+												<For
+													each={[
+														{ label: "Speed up everything", value: false },
+														{ label: "Keep clicks at normal speed", value: true },
+													]}
+												>
+													{(option) => (
+														<button
+															type="button"
+															class={cx(
+																"flex-1 py-2 text-xs font-medium rounded-lg border transition-colors",
+																(timelapse().keepClicks ?? false) === option.value
+																	? "bg-gray-3 border-gray-5 text-gray-12"
+																	: "bg-transparent border-transparent text-gray-11 hover:bg-gray-3 hover:border-gray-4",
+															)}
+															onClick={() =>
+																updateSettings("timelapse", {
+																	...timelapse(),
+																	keepClicks: option.value,
+																})
+															}
+														>
+															{option.label}
+														</button>
+													)}
+												</For>
+											</div>
+										)}
+									</Show>
+								</div>
+							</Field>
+						</Show>
+
 						<Show when={settings.format === "Mp4"}>
 							<Field
 								name="Quality"
@@ -1110,6 +1584,22 @@ export function ExportPage() {
 					</div>
 
 					<div class="p-4 border-t border-gray-3">
+						<Show when={validation.latest}>
+							{(validation) => (
+								<ul class="flex flex-col gap-1 mb-3 text-xs empty:hidden">
+									<For each={validation().problems}>
+										{(problem) => (
+											<li class="text-red-11">{formatMessage(problem)}</li>
+										)}
+									</For>
+									<For each={validation().warnings}>
+										{(warning) => (
+											<li class="text-amber-11">{formatMessage(warning)}</li>
+										)}
+									</For>
+								</ul>
+							)}
+						</Show>
 						{settings.exportTo === "link" && !auth.data ? (
 							<Button class="w-full justify-center gap-2 h-12 text-base" variant="gray" size="lg" disabled>
 								<IconCapLink class="size-4" />
@@ -1120,6 +1610,7 @@ export function ExportPage() {
 								class="w-full gap-2 h-12 text-base"
 								variant="blue"
 								size="lg"
+								disabled={hasProblems()}
 								onClick={() => {
 									if (settings.exportTo === "file") save.mutate();
 									else if (settings.exportTo === "link") upload.mutate();
@@ -1247,18 +1738,8 @@ export function ExportPage() {
 												>
 													{(copyState) => (
 														<>
-															<RenderProgress
-																state={copyState}
-																format={settings.format}
-															/>
-															<Button
-																variant="ghost"
-																size="sm"
-																onClick={handleCancel}
-																class="mt-4 hover:bg-red-500 hover:text-white"
-															>
-																Cancel
-															</Button>
+															<RenderProgress state={copyState} />
+															<RenderActions state={copyState} />
 														</>
 													)}
 												</Show>
@@ -1298,18 +1779,8 @@ export function ExportPage() {
 															>
 																{(copyState) => (
 																	<>
-																		<RenderProgress
-																			state={copyState}
-																			format={settings.format}
-																		/>
-																		<Button
-																			variant="ghost"
-																			size="sm"
-																			onClick={handleCancel}
-																			class="mt-4 hover:bg-red-500 hover:text-white"
-																		>
-																			Cancel
-																		</Button>
+																		<RenderProgress state={copyState} />
+																		<RenderActions state={copyState} />
 																	</>
 																)}
 															</Show>
@@ -1378,18 +1849,8 @@ export function ExportPage() {
 																>
 																	{(renderState) => (
 																		<>
-																			<RenderProgress
-																				state={renderState}
-																				format={settings.format}
-																			/>
-																			<Button
-																				variant="ghost"
-																				size="sm"
-																				onClick={handleCancel}
-																				class="mt-4 hover:bg-red-500 hover:text-white"
-																			>
-																				Cancel
-																			</Button>
+																			<RenderProgress state={renderState} />
+																			<RenderActions state={renderState} />
 																		</>
 																	)}
 																</Match>
@@ -1534,7 +1995,7 @@ export function ExportPage() {
 	);
 }
 
-function RenderProgress(props: { state: RenderState; format?: ExportFormat }) {
+function RenderProgress(props: { state: RenderState }) {
 	return (
 		<ProgressView
 			amount={
@@ -1546,16 +2007,52 @@ function RenderProgress(props: { state: RenderState; format?: ExportFormat }) {
 			}
 			label={
 				props.state.type === "rendering"
-					? `Rendering ${props.format === "Gif" ? "GIF" : "video"} (${
-							props.state.progress.renderedCount
-						}/${props.state.progress.totalFrames} frames)`
+					? formatMessage(props.state.progress.message)
 					: "Preparing to render..."
 			}
+			detail={
+				props.state.type === "rendering" && props.state.progress.timings
+					? formatRenderTimings(props.state.progress.timings)
+					: undefined
+			}
+			warning={
+				props.state.type === "rendering"
+					? formatEncoderWarnings(props.state.warnings)
+					: undefined
+			}
 		/>
 	);
 }
 
-function ProgressView(props: { amount: number; label?: string }) {
+function formatEncoderWarnings(warnings: string[]) {
+	const last = warnings.at(-1);
+	if (last === undefined) return undefined;
+	const count =
+		warnings.length === 1
+			? "1 encoder warning"
+			: `${warnings.length} encoder warnings`;
+	return `${count}, latest: ${last}`;
+}
+
+function formatRenderTimings(timings: RenderTimings) {
+	const ms = (us: number) => `${(us / 1000).toFixed(1)}ms`;
+	const stages = [
+		`decode ${ms(timings.decodeUs)}`,
+		`composite ${ms(timings.compositeUs)}`,
+		`readback ${ms(timings.readbackUs)}`,
+		`encode ${ms(timings.encodeUs)}`,
+	].join(" · ");
+	const eta =
+		timings.etaSecs === null ? "" : ` · ${Math.ceil(timings.etaSecs)}s left`;
+	return `${timings.fps.toFixed(1)} fps${eta} — ${stages}`;
+}
+
+function ProgressView(props: {
+	amount: number;
+	label?: string;
+	detail?: string;
+	warning?: string;
+}) {
 	return (
 		<>
 			<div class="w-full bg-gray-3 rounded-full h-2.5">
@@ -1565,6 +2062,14 @@ function ProgressView(props: { amount: number; label?: string }) {
 				/>
 			</div>
 			<p class="text-xs tabular-nums">{props.label}</p>
+			<Show when={props.detail}>
+				<p class="text-[11px] text-gray-10 tabular-nums">{props.detail}</p>
+			</Show>
+			<Show when={props.warning}>
+				<p class="text-[11px] text-amber-11 truncate" title={props.warning}>
+					{props.warning}
+				</p>
+			</Show>
 		</>
 	);
 }
@@ -0,0 +1,86 @@
+import { Select as KSelect } from "@kobalte/core/select";
+import { createSignal } from "solid-js";
+import Tooltip from "~/components/Tooltip";
+import { commands, type PreviewGuides } from "~/utils/tauri";
+import {
+	EditorButton,
+	MenuItem,
+	MenuItemList,
+	PopperContent,
+	topLeftAnimateClasses,
+} from "./ui";
+
+type Guide = "safeArea" | "verticalCrop" | "grid";
+
+const GUIDES: Record<Guide, { name: string; detail: string }> = {
+	safeArea: { name: "Title safe", detail: "10% margin" },
+	verticalCrop: { name: "Vertical crop", detail: "9:16" },
+	grid: { name: "Grid", detail: "Thirds" },
+};
+
+function toPreviewGuides(guides: Guide[]): PreviewGuides {
+	return {
+		safeAreaMargin: guides.includes("safeArea") ? 0.1 : null,
+		cropAspect: guides.includes("verticalCrop") ? 9 / 16 : null,
+		gridDivisions: guides.includes("grid") ? 3 : null,
+	};
+}
+
+function GuidesSelect() {
+	const [guides, setGuides] = createSignal<Guide[]>([]);
+
+	return (
+		<Tooltip content="Preview Guides">
+			<KSelect<Guide>
+				value={guides()}
+				onChange={(value) => {
+					setGuides(value);
+					commands.setPreviewGuides(toPreviewGuides(value));
+				}}
+				options={["safeArea", "verticalCrop", "grid"] as const}
+				multiple={true}
+				itemComponent={(props) => (
+					<MenuItem<typeof KSelect.Item> as={KSelect.Item} item={props.item}>
+						<KSelect.ItemLabel class="flex-1">
+							{GUIDES[props.item.rawValue].name}
+							<span class="text-gray-11">
+								{"⋅"}
+								{GUIDES[props.item.rawValue].detail}
+							</span>
+						</KSelect.ItemLabel>
+						<KSelect.ItemIndicator class="ml-auto">
+							<IconCapCircleCheck />
+						</KSelect.ItemIndicator>
+					</MenuItem>
+				)}
+				placement="top-start"
+			>
+				<EditorButton<typeof KSelect.Trigger>
+					as={KSelect.Trigger}
+					leftIcon={<IconLucideGrid3x3 class="w-4" />}
+					rightIcon={
+						<KSelect.Icon>
+							<IconCapChevronDown />
+						</KSelect.Icon>
+					}
+					rightIconEnd={true}
+				>
+					Guides
+				</EditorButton>
+				<KSelect.Portal>
+					<PopperContent<typeof KSelect.Content>
+						as={KSelect.Content}
+						class={topLeftAnimateClasses}
+					>
+						<MenuItemList<typeof KSelect.Listbox>
+							as={KSelect.Listbox}
+							class="w-[12.5rem]"
+						/>
+					</PopperContent>
+				</KSelect.Portal>
+			</KSelect>
+		</Tooltip>
+	);
+}
+
+export default GuidesSelect;
@@ -12,6 +12,7 @@ import {
 	onMount,
 	Show,
 } from "solid-js";
+import toast from "solid-toast";
 import Tooltip from "~/components/Tooltip";
 import CaptionControlsWindows11 from "~/components/titlebar/controls/CaptionControlsWindows11";
 import { trackEvent } from "~/utils/analytics";
@@ -52,7 +53,9 @@ export function Header() {
 		customDomain,
 		editorState,
 		setEditorState,
+		projectActions,
 	} = useEditorContext();
+	const [tightening, setTightening] = createSignal(false);
 
 	let unlistenTitlebar: UnlistenFn | undefined;
 	onMount(async () => {
@@ -104,6 +107,36 @@ export function Header() {
 					<span class="text-sm text-gray-11">.cap</span>
 				</div>
 				<div data-tauri-drag-region class="flex-1 h-full" />
+				<EditorButton
+					onClick={async () => {
+						clearTimelineSelection();
+						if (tightening()) return;
+
+						setTightening(true);
+						try {
+							const ranges = await commands.detectIdleSections(
+								editorInstance.path,
+								null,
+							);
+							if (ranges.length === 0) {
+								toast("No idle sections found");
+								return;
+							}
+
+							projectActions.cutRecordingRanges(ranges);
+							toast.success(
+								`Cut ${ranges.length} idle ${ranges.length === 1 ? "section" : "sections"}`,
+							);
+						} catch (e) {
+							toast.error(`Failed to detect idle sections: ${e}`);
+						} finally {
+							setTightening(false);
+						}
+					}}
+					disabled={tightening()}
+					tooltipText="Tighten recording"
+					leftIcon={<IconLucideScissors class="w-5" />}
+				/>
 				<EditorButton
 					onClick={() => {
 						if (clearTimelineSelection()) return;
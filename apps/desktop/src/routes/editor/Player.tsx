@@ -2,12 +2,22 @@ import { Select as KSelect } from "@kobalte/core/select";
 import { ToggleButton as KToggleButton } from "@kobalte/core/toggle-button";
 import { createElementBounds } from "@solid-primitives/bounds";
 import { debounce } from "@solid-primitives/scheduled";
+import { convertFileSrc } from "@tauri-apps/api/core";
 import { Menu } from "@tauri-apps/api/menu";
+import { getCurrentWindow } from "@tauri-apps/api/window";
 import { type as ostype } from "@tauri-apps/plugin-os";
 import { cx } from "cva";
-import { createEffect, createSignal, onMount, Show } from "solid-js";
+import {
+	createEffect,
+	createSignal,
+	on,
+	onCleanup,
+	onMount,
+	Show,
+} from "solid-js";
 
 import Tooltip from "~/components/Tooltip";
+import { generalSettingsStore } from "~/store";
 import { captionsStore } from "~/store/captions";
 import { commands } from "~/utils/tauri";
 import AspectRatioSelect from "./AspectRatioSelect";
@@ -18,6 +28,8 @@ import {
 	useEditorContext,
 } from "./context";
 import { preloadCropVideoFull } from "./cropVideoPreloader";
+import GuidesSelect from "./GuidesSelect";
+import { toggleMarker } from "./markers";
 import { MaskOverlay } from "./MaskOverlay";
 import { PerformanceOverlay } from "./PerformanceOverlay";
 import { TextOverlay } from "./TextOverlay";
@@ -210,6 +222,19 @@ export function PlayerContent() {
 					editorState.timeline.interactMode === "split" ? "seek" : "split",
 				),
 		},
+		{
+			combo: "M",
+			when: () => !!project.timeline,
+			handler: () =>
+				setProject(
+					"timeline",
+					"markers",
+					toggleMarker(
+						project.timeline?.markers ?? [],
+						editorState.playbackTime,
+					),
+				),
+		},
 		{
 			combo: "Mod+=",
 			handler: () =>
@@ -256,6 +281,7 @@ export function PlayerContent() {
 					>
 						Crop
 					</EditorButton>
+					<GuidesSelect />
 				</div>
 				<div class="flex items-center gap-2">
 					<span class="text-xs font-medium text-gray-11">Preview quality</span>
@@ -440,6 +466,8 @@ export function PlayerContent() {
 	);
 }
 
+const PROXY_SETTLE_MS = 250;
+
 // CSS for checkerboard grid (adaptive to light/dark mode)
 const gridStyle = {
 	"background-image":
@@ -453,8 +481,14 @@ const gridStyle = {
 };
 
 function PreviewCanvas() {
-	const { latestFrame, canvasControls, performanceMode, setPerformanceMode } =
-		useEditorContext();
+	const {
+		latestFrame,
+		canvasControls,
+		performanceMode,
+		setPerformanceMode,
+		editorState,
+		previewProxy,
+	} = useEditorContext();
 
 	const hasRenderedFrame = () => canvasControls()?.hasRenderedFrame() ?? false;
 
@@ -559,6 +593,76 @@ function PreviewCanvas() {
 
 	const hasFrame = () => !!latestFrame();
 
+	const generalSettings = generalSettingsStore.createQuery();
+	const nativePreview = () =>
+		ostype() !== "windows" && !!generalSettings.data?.nativeEditorPreview;
+
+	createEffect(
+		on(
+			[canvasRef, nativePreview, hasFrame, size],
+			([canvas, enabled, frame]) => {
+				if (!canvas || !enabled || !frame) return;
+
+				const show = () => {
+					const rect = canvas.getBoundingClientRect();
+					commands.showNativePreview({
+						x: rect.left,
+						y: rect.top,
+						width: rect.width,
+						height: rect.height,
+					});
+				};
+				show();
+
+				const unlisten = getCurrentWindow().onMoved(show);
+				onCleanup(() => unlisten.then((f) => f()));
+			},
+		),
+	);
+
+	createEffect(
+		on(
+			nativePreview,
+			(enabled) => {
+				if (!enabled) commands.hideNativePreview();
+			},
+			{ defer: true },
+		),
+	);
+
+	onCleanup(() => {
+		if (nativePreview()) commands.hideNativePreview();
+	});
+
+	const [scrubbing, setScrubbing] = createSignal(false);
+	let scrubTimeout: ReturnType<typeof setTimeout> | undefined;
+	createEffect(
+		on(
+			() => editorState.previewTime,
+			(time) => {
+				clearTimeout(scrubTimeout);
+				if (time === null || editorState.playing) return setScrubbing(false);
+
+				setScrubbing(true);
+				scrubTimeout = setTimeout(() => setScrubbing(false), PROXY_SETTLE_MS);
+			},
+			{ defer: true },
+		),
+	);
+	onCleanup(() => clearTimeout(scrubTimeout));
+
+	const proxyFrameSrc = () => {
+		const proxy = previewProxy();
+		const time = editorState.previewTime;
+		if (!proxy || !scrubbing() || time === null) return;
+
+		const { fps, frameCount } = proxy.manifest;
+		const frame = Math.min(Math.round(time * fps), frameCount - 1);
+		if (frame < 0) return;
+
+		return convertFileSrc(`${proxy.dir}/${String(frame).padStart(6, "0")}.jpg`);
+	};
+
 	return (
 		<div
 			ref={setCanvasContainerRef}
@@ -589,6 +693,19 @@ function PreviewCanvas() {
 						ref={setCanvasRef}
 						id="canvas"
 					/>
+					<Show when={proxyFrameSrc()}>
+						{(src) => (
+							<img
+								src={src()}
+								alt=""
+								class="absolute inset-0 pointer-events-none"
+								style={{
+									width: `${size().width}px`,
+									height: `${size().height}px`,
+								}}
+							/>
+						)}
+					</Show>
 					<Show when={hasFrame()}>
 						<MaskOverlay size={size()} />
 						<TextOverlay size={size()} />
@@ -9,6 +9,7 @@ import {
 	createMemo,
 	createRoot,
 	createSignal,
+	For,
 	Index,
 	Match,
 	onCleanup,
@@ -18,7 +19,7 @@ import {
 } from "solid-js";
 import { produce } from "solid-js/store";
 
-import type { TimelineSegment } from "~/utils/tauri";
+import type { TimelineSegment, VoiceActivity } from "~/utils/tauri";
 import { useEditorContext } from "../context";
 import { useSegmentContext, useTimelineContext } from "./context";
 import { getSectionMarker } from "./sectionMarker";
@@ -318,6 +319,7 @@ export function ClipTrack(
 		totalDuration,
 		micWaveforms,
 		systemAudioWaveforms,
+		voiceActivity,
 	} = useEditorContext();
 
 	const { secsPerPixel, duration, isSegmentVisible } = useTimelineContext();
@@ -651,6 +653,12 @@ export function ClipTrack(
 								)}
 
 								<Markings segment={segment()} prevDuration={prevDuration()} />
+								<VoiceActivityMarkers
+									segment={segment()}
+									activity={
+										voiceActivity()?.[segment().recordingSegment ?? i()] ?? []
+									}
+								/>
 
 								<SegmentHandle
 									position="start"
@@ -913,6 +921,36 @@ export function ClipTrack(
 	);
 }
 
+function VoiceActivityMarkers(props: {
+	segment: TimelineSegment;
+	activity: VoiceActivity[];
+}) {
+	const visible = () =>
+		props.activity.filter(
+			(a) => a.end > props.segment.start && a.start < props.segment.end,
+		);
+
+	const percent = (time: number) =>
+		((Math.min(Math.max(time, props.segment.start), props.segment.end) -
+			props.segment.start) /
+			(props.segment.end - props.segment.start)) *
+		100;
+
+	return (
+		<For each={visible()}>
+			{(activity) => (
+				<div
+					class="absolute bottom-1 z-10 h-1 rounded-full bg-green-9/70 pointer-events-none"
+					style={{
+						left: `${percent(activity.start)}%`,
+						width: `${percent(activity.end) - percent(activity.start)}%`,
+					}}
+				/>
+			)}
+		</For>
+	);
+}
+
 function Markings(props: { segment: TimelineSegment; prevDuration: number }) {
 	const { editorState } = useEditorContext();
 	const { secsPerPixel, markingResolution } = useTimelineContext();
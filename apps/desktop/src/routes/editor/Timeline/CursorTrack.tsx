@@ -0,0 +1,336 @@
+import { createEventListenerMap } from "@solid-primitives/event-listener";
+import { cx } from "cva";
+import { createMemo, createRoot, createSignal, For, Show } from "solid-js";
+import { produce } from "solid-js/store";
+
+import { useEditorContext } from "../context";
+import { type CursorSegment, defaultCursorSegment } from "../cursorSegments";
+import { useTimelineContext } from "./context";
+import {
+	SegmentContent,
+	SegmentHandle,
+	SegmentRoot,
+	TrackRoot,
+	useSetPreviewTime,
+} from "./Track";
+
+export type CursorSegmentDragState =
+	| { type: "idle" }
+	| { type: "movePending" }
+	| { type: "moving" };
+
+const DEFAULT_SEGMENT_SECS = 2;
+const MIN_SEGMENT_SECS = 0.5;
+const MIN_SEGMENT_PIXELS = 40;
+
+const sortSegments = (segments: CursorSegment[]) =>
+	segments.sort((a, b) => a.start - b.start);
+
+export function CursorTrack(props: {
+	onDragStateChanged: (v: CursorSegmentDragState) => void;
+	handleUpdatePlayhead: (e: MouseEvent) => void;
+}) {
+	const {
+		project,
+		setProject,
+		editorState,
+		setEditorState,
+		totalDuration,
+		projectHistory,
+	} = useEditorContext();
+	const { secsPerPixel, timelineBounds } = useTimelineContext();
+	const [draggingSegment, setDraggingSegment] = createSignal(false);
+	const [hoveringTrack, setHoveringTrack] = createSignal(false);
+	const setPreviewTime = useSetPreviewTime();
+
+	const minDuration = () =>
+		Math.max(MIN_SEGMENT_SECS, secsPerPixel() * MIN_SEGMENT_PIXELS);
+
+	const segments = () => project.timeline?.cursorSegments ?? [];
+
+	const neighborBounds = (index: number) => ({
+		prevEnd: segments()[index - 1]?.end ?? 0,
+		nextStart: segments()[index + 1]?.start ?? totalDuration(),
+	});
+
+	const findPlacement = (time: number) => {
+		const length = Math.min(
+			Math.max(DEFAULT_SEGMENT_SECS, minDuration()),
+			totalDuration(),
+		);
+		if (length <= 0) return;
+
+		const nextIndex = segments().findIndex((segment) => segment.start > time);
+		const prevIndex = (nextIndex === -1 ? segments().length : nextIndex) - 1;
+		const gapStart = segments()[prevIndex]?.end ?? 0;
+		const gapEnd = segments()[nextIndex]?.start ?? totalDuration();
+		if (time < gapStart || gapEnd - gapStart < minDuration()) return;
+
+		const start = Math.max(gapStart, Math.min(time, gapEnd - length));
+		return { start, end: Math.min(start + length, gapEnd) };
+	};
+
+	const newSegmentDetails = createMemo(() => {
+		if (!hoveringTrack() || editorState.previewTime === null) return;
+		return findPlacement(editorState.previewTime);
+	});
+
+	const handleBackgroundMouseDown = (e: MouseEvent) => {
+		if (e.button !== 0) return;
+		if ((e.target as HTMLElement).closest("[data-cursor-segment]")) return;
+		const time =
+			editorState.previewTime ??
+			editorState.playbackTime ??
+			secsPerPixel() * (e.clientX - (timelineBounds.left ?? 0));
+		const placement = findPlacement(time);
+		if (!placement) return;
+
+		setProject(
+			"timeline",
+			"cursorSegments",
+			produce((segments) => {
+				segments.push(defaultCursorSegment(placement.start, placement.end));
+				sortSegments(segments);
+			}),
+		);
+		const index = segments().findIndex(
+			(segment) => segment.start === placement.start,
+		);
+		setEditorState("timeline", "selection", {
+			type: "cursor",
+			indices: [index],
+		});
+	};
+
+	function createMouseDownDrag<T>(
+		segmentIndex: () => number,
+		setup: () => T,
+		update: (e: MouseEvent, value: T, initialMouseX: number) => void,
+	) {
+		return (downEvent: MouseEvent) => {
+			if (editorState.timeline.interactMode !== "seek") return;
+			downEvent.stopPropagation();
+			const initial = setup();
+			let moved = false;
+			let initialMouseX: number | null = null;
+
+			const resumeHistory = projectHistory.pause();
+			setDraggingSegment(true);
+			props.onDragStateChanged({ type: "movePending" });
+
+			function finish(e: MouseEvent) {
+				resumeHistory();
+				if (!moved) {
+					e.stopPropagation();
+					const index = segmentIndex();
+					const selection = editorState.timeline.selection;
+
+					if ((e.ctrlKey || e.metaKey) && selection?.type === "cursor") {
+						const next = selection.indices.includes(index)
+							? selection.indices.filter((i) => i !== index)
+							: [...selection.indices, index];
+						setEditorState(
+							"timeline",
+							"selection",
+							next.length > 0 ? { type: "cursor", indices: next } : null,
+						);
+					} else {
+						setEditorState("timeline", "selection", {
+							type: "cursor",
+							indices: [index],
+						});
+					}
+					props.handleUpdatePlayhead(e);
+				}
+				props.onDragStateChanged({ type: "idle" });
+				setDraggingSegment(false);
+			}
+
+			function handleUpdate(event: MouseEvent) {
+				if (Math.abs(event.clientX - downEvent.clientX) > 2 && !moved) {
+					moved = true;
+					initialMouseX = event.clientX;
+					props.onDragStateChanged({ type: "moving" });
+				}
+
+				if (initialMouseX === null) return;
+				update(event, initial, initialMouseX);
+			}
+
+			createRoot((dispose) => {
+				createEventListenerMap(window, {
+					mousemove: (e) => handleUpdate(e),
+					mouseup: (e) => {
+						handleUpdate(e);
+						finish(e);
+						dispose();
+					},
+				});
+			});
+		};
+	}
+
+	return (
+		<TrackRoot
+			onMouseEnter={() => {
+				setHoveringTrack(true);
+				setEditorState("timeline", "hoveredTrack", "cursor");
+			}}
+			onMouseLeave={() => {
+				setHoveringTrack(false);
+				setEditorState("timeline", "hoveredTrack", null);
+			}}
+			onMouseDown={handleBackgroundMouseDown}
+		>
+			<For
+				each={segments()}
+				fallback={
+					<Show
+						when={!newSegmentDetails()}
+						fallback={<div class="w-full rounded-xl bg-transparent" />}
+					>
+						<div class="text-center text-sm text-[--text-tertiary] flex flex-col justify-center items-center inset-0 w-full bg-gray-3/20 dark:bg-gray-3/10 hover:bg-gray-3/30 dark:hover:bg-gray-3/20 transition-colors rounded-xl pointer-events-none">
+							<div>Click to hide the cursor</div>
+							<div class="text-[10px] text-[--text-tertiary]/40 mt-0.5">
+								(Fades the cursor out for a stretch of the video)
+							</div>
+						</div>
+					</Show>
+				}
+			>
+				{(segment, index) => {
+					const isSelected = createMemo(() => {
+						const selection = editorState.timeline.selection;
+						if (!selection || selection.type !== "cursor") return false;
+						return selection.indices.includes(index());
+					});
+
+					return (
+						<SegmentRoot
+							data-cursor-segment
+							class={cx(
+								"border duration-200 hover:border-gray-12 transition-colors group",
+								"bg-gradient-to-r from-[#1b1f2b] via-[#293044] to-[#1b1f2b]",
+								isSelected() ? "border-gray-11" : "border-transparent",
+							)}
+							innerClass="ring-blue-5"
+							segment={segment}
+							onMouseDown={(e) => e.stopPropagation()}
+						>
+							<SegmentHandle
+								position="start"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										return {
+											start: segment.start,
+											minValue: bounds.prevEnd,
+											maxValue: Math.max(
+												bounds.prevEnd,
+												segment.end - minDuration(),
+											),
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const next = Math.max(
+											value.minValue,
+											Math.min(value.maxValue, value.start + delta),
+										);
+										setProject(
+											"timeline",
+											"cursorSegments",
+											index(),
+											"start",
+											next,
+										);
+										setPreviewTime(next);
+									},
+								)}
+							/>
+							<SegmentContent
+								class="flex justify-center items-center cursor-grab px-2 overflow-hidden"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										return {
+											original: { ...segment },
+											minDelta: bounds.prevEnd - segment.start,
+											maxDelta: bounds.nextStart - segment.end,
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const lowerBound = Math.min(value.minDelta, value.maxDelta);
+										const upperBound = Math.max(value.minDelta, value.maxDelta);
+										const clampedDelta = Math.min(
+											upperBound,
+											Math.max(lowerBound, delta),
+										);
+										setProject("timeline", "cursorSegments", index(), {
+											...value.original,
+											start: value.original.start + clampedDelta,
+											end: value.original.end + clampedDelta,
+										});
+									},
+								)}
+							>
+								<span class="flex gap-1.5 items-center text-xs text-gray-11 truncate">
+									<IconLucideMousePointer2Off class="size-3.5 shrink-0" />
+									Hidden
+								</span>
+							</SegmentContent>
+							<SegmentHandle
+								position="end"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										const minValue = segment.start + minDuration();
+										return {
+											end: segment.end,
+											minValue,
+											maxValue: Math.max(minValue, bounds.nextStart),
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const next = Math.max(
+											value.minValue,
+											Math.min(value.maxValue, value.end + delta),
+										);
+										setProject(
+											"timeline",
+											"cursorSegments",
+											index(),
+											"end",
+											next,
+										);
+										setPreviewTime(next);
+									},
+								)}
+							/>
+						</SegmentRoot>
+					);
+				}}
+			</For>
+			<Show when={!draggingSegment() && newSegmentDetails()}>
+				{(details) => (
+					<SegmentRoot
+						class="pointer-events-none z-10 border border-transparent"
+						innerClass="ring-blue-300"
+						segment={details()}
+					>
+						<SegmentContent class="bg-gradient-to-r from-[#1b1f2b] via-[#293044] to-[#1b1f2b] shadow-[inset_0_8px_12px_3px_rgba(255,255,255,0.16)]">
+							<p class="w-full text-center text-gray-1 dark:text-gray-12 text-md">
+								+
+							</p>
+						</SegmentContent>
+					</SegmentRoot>
+				)}
+			</Show>
+		</TrackRoot>
+	);
+}
@@ -0,0 +1,335 @@
+import { createEventListenerMap } from "@solid-primitives/event-listener";
+import { cx } from "cva";
+import { createMemo, createRoot, createSignal, For, Show } from "solid-js";
+import { produce } from "solid-js/store";
+
+import { useEditorContext } from "../context";
+import { defaultReactionSegment, type ReactionSegment } from "../reactions";
+import { useTimelineContext } from "./context";
+import {
+	SegmentContent,
+	SegmentHandle,
+	SegmentRoot,
+	TrackRoot,
+	useSetPreviewTime,
+} from "./Track";
+
+export type ReactionSegmentDragState =
+	| { type: "idle" }
+	| { type: "movePending" }
+	| { type: "moving" };
+
+const DEFAULT_SEGMENT_SECS = 2;
+const MIN_SEGMENT_SECS = 0.5;
+const MIN_SEGMENT_PIXELS = 40;
+
+const sortSegments = (segments: ReactionSegment[]) =>
+	segments.sort((a, b) => a.start - b.start);
+
+export function ReactionTrack(props: {
+	onDragStateChanged: (v: ReactionSegmentDragState) => void;
+	handleUpdatePlayhead: (e: MouseEvent) => void;
+}) {
+	const {
+		project,
+		setProject,
+		editorState,
+		setEditorState,
+		totalDuration,
+		projectHistory,
+	} = useEditorContext();
+	const { secsPerPixel, timelineBounds } = useTimelineContext();
+	const [draggingSegment, setDraggingSegment] = createSignal(false);
+	const [hoveringTrack, setHoveringTrack] = createSignal(false);
+	const setPreviewTime = useSetPreviewTime();
+
+	const minDuration = () =>
+		Math.max(MIN_SEGMENT_SECS, secsPerPixel() * MIN_SEGMENT_PIXELS);
+
+	const segments = () => project.timeline?.reactionSegments ?? [];
+
+	const neighborBounds = (index: number) => ({
+		prevEnd: segments()[index - 1]?.end ?? 0,
+		nextStart: segments()[index + 1]?.start ?? totalDuration(),
+	});
+
+	const findPlacement = (time: number) => {
+		const length = Math.min(
+			Math.max(DEFAULT_SEGMENT_SECS, minDuration()),
+			totalDuration(),
+		);
+		if (length <= 0) return;
+
+		const nextIndex = segments().findIndex((segment) => segment.start > time);
+		const prevIndex = (nextIndex === -1 ? segments().length : nextIndex) - 1;
+		const gapStart = segments()[prevIndex]?.end ?? 0;
+		const gapEnd = segments()[nextIndex]?.start ?? totalDuration();
+		if (time < gapStart || gapEnd - gapStart < minDuration()) return;
+
+		const start = Math.max(gapStart, Math.min(time, gapEnd - length));
+		return { start, end: Math.min(start + length, gapEnd) };
+	};
+
+	const newSegmentDetails = createMemo(() => {
+		if (!hoveringTrack() || editorState.previewTime === null) return;
+		return findPlacement(editorState.previewTime);
+	});
+
+	const handleBackgroundMouseDown = (e: MouseEvent) => {
+		if (e.button !== 0) return;
+		if ((e.target as HTMLElement).closest("[data-reaction-segment]")) return;
+		const time =
+			editorState.previewTime ??
+			editorState.playbackTime ??
+			secsPerPixel() * (e.clientX - (timelineBounds.left ?? 0));
+		const placement = findPlacement(time);
+		if (!placement) return;
+
+		setProject(
+			"timeline",
+			"reactionSegments",
+			produce((segments) => {
+				segments.push(defaultReactionSegment(placement.start, placement.end));
+				sortSegments(segments);
+			}),
+		);
+		const index = segments().findIndex(
+			(segment) => segment.start === placement.start,
+		);
+		setEditorState("timeline", "selection", {
+			type: "reaction",
+			indices: [index],
+		});
+	};
+
+	function createMouseDownDrag<T>(
+		segmentIndex: () => number,
+		setup: () => T,
+		update: (e: MouseEvent, value: T, initialMouseX: number) => void,
+	) {
+		return (downEvent: MouseEvent) => {
+			if (editorState.timeline.interactMode !== "seek") return;
+			downEvent.stopPropagation();
+			const initial = setup();
+			let moved = false;
+			let initialMouseX: number | null = null;
+
+			const resumeHistory = projectHistory.pause();
+			setDraggingSegment(true);
+			props.onDragStateChanged({ type: "movePending" });
+
+			function finish(e: MouseEvent) {
+				resumeHistory();
+				if (!moved) {
+					e.stopPropagation();
+					const index = segmentIndex();
+					const selection = editorState.timeline.selection;
+
+					if ((e.ctrlKey || e.metaKey) && selection?.type === "reaction") {
+						const next = selection.indices.includes(index)
+							? selection.indices.filter((i) => i !== index)
+							: [...selection.indices, index];
+						setEditorState(
+							"timeline",
+							"selection",
+							next.length > 0 ? { type: "reaction", indices: next } : null,
+						);
+					} else {
+						setEditorState("timeline", "selection", {
+							type: "reaction",
+							indices: [index],
+						});
+					}
+					props.handleUpdatePlayhead(e);
+				}
+				props.onDragStateChanged({ type: "idle" });
+				setDraggingSegment(false);
+			}
+
+			function handleUpdate(event: MouseEvent) {
+				if (Math.abs(event.clientX - downEvent.clientX) > 2 && !moved) {
+					moved = true;
+					initialMouseX = event.clientX;
+					props.onDragStateChanged({ type: "moving" });
+				}
+
+				if (initialMouseX === null) return;
+				update(event, initial, initialMouseX);
+			}
+
+			createRoot((dispose) => {
+				createEventListenerMap(window, {
+					mousemove: (e) => handleUpdate(e),
+					mouseup: (e) => {
+						handleUpdate(e);
+						finish(e);
+						dispose();
+					},
+				});
+			});
+		};
+	}
+
+	return (
+		<TrackRoot
+			onMouseEnter={() => {
+				setHoveringTrack(true);
+				setEditorState("timeline", "hoveredTrack", "reaction");
+			}}
+			onMouseLeave={() => {
+				setHoveringTrack(false);
+				setEditorState("timeline", "hoveredTrack", null);
+			}}
+			onMouseDown={handleBackgroundMouseDown}
+		>
+			<For
+				each={segments()}
+				fallback={
+					<Show
+						when={!newSegmentDetails()}
+						fallback={<div class="w-full rounded-xl bg-transparent" />}
+					>
+						<div class="text-center text-sm text-[--text-tertiary] flex flex-col justify-center items-center inset-0 w-full bg-gray-3/20 dark:bg-gray-3/10 hover:bg-gray-3/30 dark:hover:bg-gray-3/20 transition-colors rounded-xl pointer-events-none">
+							<div>Click to add a reaction</div>
+							<div class="text-[10px] text-[--text-tertiary]/40 mt-0.5">
+								(Drop an emoji onto a moment)
+							</div>
+						</div>
+					</Show>
+				}
+			>
+				{(segment, index) => {
+					const isSelected = createMemo(() => {
+						const selection = editorState.timeline.selection;
+						if (!selection || selection.type !== "reaction") return false;
+						return selection.indices.includes(index());
+					});
+
+					return (
+						<SegmentRoot
+							data-reaction-segment
+							class={cx(
+								"border duration-200 hover:border-amber-6 transition-colors group",
+								"bg-gradient-to-r from-[#2a2111] via-[#3a2e16] to-[#2a2111]",
+								isSelected() ? "border-amber-7" : "border-transparent",
+							)}
+							innerClass="ring-amber-6"
+							segment={segment}
+							onMouseDown={(e) => e.stopPropagation()}
+						>
+							<SegmentHandle
+								position="start"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										return {
+											start: segment.start,
+											minValue: bounds.prevEnd,
+											maxValue: Math.max(
+												bounds.prevEnd,
+												segment.end - minDuration(),
+											),
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const next = Math.max(
+											value.minValue,
+											Math.min(value.maxValue, value.start + delta),
+										);
+										setProject(
+											"timeline",
+											"reactionSegments",
+											index(),
+											"start",
+											next,
+										);
+										setPreviewTime(next);
+									},
+								)}
+							/>
+							<SegmentContent
+								class="flex justify-center items-center cursor-grab px-2 overflow-hidden"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										return {
+											original: { ...segment },
+											minDelta: bounds.prevEnd - segment.start,
+											maxDelta: bounds.nextStart - segment.end,
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const lowerBound = Math.min(value.minDelta, value.maxDelta);
+										const upperBound = Math.max(value.minDelta, value.maxDelta);
+										const clampedDelta = Math.min(
+											upperBound,
+											Math.max(lowerBound, delta),
+										);
+										setProject("timeline", "reactionSegments", index(), {
+											...value.original,
+											start: value.original.start + clampedDelta,
+											end: value.original.end + clampedDelta,
+										});
+									},
+								)}
+							>
+								<span class="text-lg leading-none truncate">
+									{segment.emoji}
+								</span>
+							</SegmentContent>
+							<SegmentHandle
+								position="end"
+								onMouseDown={createMouseDownDrag(
+									index,
+									() => {
+										const bounds = neighborBounds(index());
+										const minValue = segment.start + minDuration();
+										return {
+											end: segment.end,
+											minValue,
+											maxValue: Math.max(minValue, bounds.nextStart),
+										};
+									},
+									(e, value, initialMouseX) => {
+										const delta = (e.clientX - initialMouseX) * secsPerPixel();
+										const next = Math.max(
+											value.minValue,
+											Math.min(value.maxValue, value.end + delta),
+										);
+										setProject(
+											"timeline",
+											"reactionSegments",
+											index(),
+											"end",
+											next,
+										);
+										setPreviewTime(next);
+									},
+								)}
+							/>
+						</SegmentRoot>
+					);
+				}}
+			</For>
+			<Show when={!draggingSegment() && newSegmentDetails()}>
+				{(details) => (
+					<SegmentRoot
+						class="pointer-events-none z-10 border border-transparent"
+						innerClass="ring-amber-300"
+						segment={details()}
+					>
+						<SegmentContent class="bg-gradient-to-r from-[#2a2111] via-[#3a2e16] to-[#2a2111] shadow-[inset_0_8px_12px_3px_rgba(255,255,255,0.16)]">
+							<p class="w-full text-center text-gray-1 dark:text-gray-12 text-md">
+								+
+							</p>
+						</SegmentContent>
+					</SegmentRoot>
+				)}
+			</Show>
+		</TrackRoot>
+	);
+}
@@ -1,5 +1,6 @@
 import { createEventListenerMap } from "@solid-primitives/event-listener";
 import { Menu } from "@tauri-apps/api/menu";
+import * as dialog from "@tauri-apps/plugin-dialog";
 import { cx } from "cva";
 import { Array, Option } from "effect";
 import {
@@ -57,9 +58,11 @@ export function ZoomTrack(props: {
 	const [creatingSegmentViaDrag, setCreatingSegmentViaDrag] =
 		createSignal(false);
 
-	const handleGenerateZoomSegments = async () => {
+	const handleGenerateZoomSegments = async (musicPath?: string) => {
 		try {
-			const zoomSegments = await commands.generateZoomSegmentsFromClicks();
+			const zoomSegments = musicPath
+				? await commands.generateBeatSyncedZoomSegments(musicPath)
+				: await commands.generateZoomSegmentsFromClicks();
 			setProject("timeline", "zoomSegments", zoomSegments);
 			if (zoomSegments.length > 0) {
 				const currentSize = project.cursor?.size ?? 0;
@@ -158,7 +161,23 @@ export function ZoomTrack(props: {
 						{
 							id: "generateZoomSegments",
 							text: "Generate zoom segments from clicks",
-							action: handleGenerateZoomSegments,
+							action: () => handleGenerateZoomSegments(),
+						},
+						{
+							id: "generateBeatSyncedZoomSegments",
+							text: "Generate zoom segments synced to music...",
+							action: async () => {
+								const path = await dialog.open({
+									filters: [
+										{
+											name: "Audio",
+											extensions: ["mp3", "wav", "m4a", "aac", "flac", "ogg"],
+										},
+									],
+									multiple: false,
+								});
+								if (typeof path === "string") handleGenerateZoomSegments(path);
+							},
 						},
 					],
 				});
@@ -21,13 +21,16 @@ import "./styles.css";
 import Tooltip from "~/components/Tooltip";
 import { commands } from "~/utils/tauri";
 import { FPS, type TimelineTrackType, useEditorContext } from "../context";
+import { removeMarker } from "../markers";
 import type { MaskSegment } from "../masks";
 import type { TextSegment } from "../text";
 import { getTrackRowsWithCount, getUsedTrackCount } from "../timelineTracks";
 import { formatTime } from "../utils";
 import { ClipTrack } from "./ClipTrack";
 import { TimelineContextProvider, useTimelineContext } from "./context";
+import { type CursorSegmentDragState, CursorTrack } from "./CursorTrack";
 import { type MaskSegmentDragState, MaskTrack } from "./MaskTrack";
+import { type ReactionSegmentDragState, ReactionTrack } from "./ReactionTrack";
 import { type SceneSegmentDragState, SceneTrack } from "./SceneTrack";
 import { type TextSegmentDragState, TextTrack } from "./TextTrack";
 import { TrackIcon, TrackManager } from "./TrackManager";
@@ -41,6 +44,8 @@ const trackIcons: Record<TimelineTrackType, () => JSX.Element> = {
 	clip: () => <IconLucideClapperboard class="size-4" />,
 	text: () => <IconLucideType class="size-4" />,
 	mask: () => <IconLucideBoxSelect class="size-4" />,
+	reaction: () => <IconLucideSmilePlus class="size-4" />,
+	cursor: () => <IconLucideMousePointer2Off class="size-4" />,
 	zoom: () => <IconLucideSearch class="size-4" />,
 	scene: () => <IconLucideVideo class="size-4" />,
 };
@@ -71,6 +76,18 @@ const trackDefinitions: TrackDefinition[] = [
 		icon: trackIcons.mask,
 		locked: false,
 	},
+	{
+		type: "reaction",
+		label: "Reaction",
+		icon: trackIcons.reaction,
+		locked: false,
+	},
+	{
+		type: "cursor",
+		label: "Cursor",
+		icon: trackIcons.cursor,
+		locked: false,
+	},
 	{
 		type: "zoom",
 		label: "Zoom",
@@ -132,7 +149,11 @@ export function Timeline() {
 						? trackState().mask > 0
 						: definition.type === "text"
 							? trackState().text > 0
-							: true,
+							: definition.type === "reaction"
+								? trackState().reaction
+								: definition.type === "cursor"
+									? trackState().cursor
+									: true,
 			available: definition.type === "scene" ? sceneAvailable() : true,
 			supportsMultiple:
 				definition.type === "mask" || definition.type === "text",
@@ -152,6 +173,8 @@ export function Timeline() {
 		2 +
 		textTrackRows().length +
 		maskTrackRows().length +
+		(trackState().reaction ? 1 : 0) +
+		(trackState().cursor ? 1 : 0) +
 		(sceneTrackVisible() ? 1 : 0);
 	const trackHeight = () => (visibleTrackCount() > 2 ? "3rem" : "3.25rem");
 
@@ -161,6 +184,22 @@ export function Timeline() {
 			return;
 		}
 
+		if (type === "reaction") {
+			setEditorState("timeline", "tracks", "reaction", next);
+			if (!next && editorState.timeline.selection?.type === "reaction") {
+				setEditorState("timeline", "selection", null);
+			}
+			return;
+		}
+
+		if (type === "cursor") {
+			setEditorState("timeline", "tracks", "cursor", next);
+			if (!next && editorState.timeline.selection?.type === "cursor") {
+				setEditorState("timeline", "selection", null);
+			}
+			return;
+		}
+
 		if (type === "text") {
 			setEditorState(
 				"timeline",
@@ -285,6 +324,8 @@ export function Timeline() {
 				sceneSegments: [],
 				maskSegments: [],
 				textSegments: [],
+				reactionSegments: [],
+				cursorSegments: [],
 			});
 			resume();
 		}
@@ -310,7 +351,9 @@ export function Timeline() {
 		!project.timeline?.zoomSegments ||
 		project.timeline.zoomSegments.length < 1 ||
 		!project.timeline?.maskSegments ||
-		!project.timeline?.textSegments
+		!project.timeline?.textSegments ||
+		!project.timeline?.reactionSegments ||
+		!project.timeline?.cursorSegments
 	) {
 		setProject(
 			produce((project) => {
@@ -326,10 +369,14 @@ export function Timeline() {
 					sceneSegments: [],
 					maskSegments: [],
 					textSegments: [],
+					reactionSegments: [],
+					cursorSegments: [],
 				};
 				project.timeline.sceneSegments ??= [];
 				project.timeline.maskSegments ??= [];
 				project.timeline.textSegments ??= [];
+				project.timeline.reactionSegments ??= [];
+				project.timeline.cursorSegments ??= [];
 				project.timeline.zoomSegments ??= [];
 			}),
 		);
@@ -339,6 +386,8 @@ export function Timeline() {
 	let sceneSegmentDragState = { type: "idle" } as SceneSegmentDragState;
 	let maskSegmentDragState = { type: "idle" } as MaskSegmentDragState;
 	let textSegmentDragState = { type: "idle" } as TextSegmentDragState;
+	let reactionSegmentDragState = { type: "idle" } as ReactionSegmentDragState;
+	let cursorSegmentDragState = { type: "idle" } as CursorSegmentDragState;
 
 	let pendingZoomDelta = 0;
 	let pendingZoomOrigin: number | null = null;
@@ -397,7 +446,9 @@ export function Timeline() {
 			zoomSegmentDragState.type !== "moving" &&
 			sceneSegmentDragState.type !== "moving" &&
 			maskSegmentDragState.type !== "moving" &&
-			textSegmentDragState.type !== "moving"
+			textSegmentDragState.type !== "moving" &&
+			reactionSegmentDragState.type !== "moving" &&
+			cursorSegmentDragState.type !== "moving"
 		) {
 			// Guard against missing bounds and clamp computed time to [0, totalDuration()]
 			if (left == null) return;
@@ -451,6 +502,10 @@ export function Timeline() {
 				projectActions.deleteMaskSegments(selection.indices);
 			} else if (selection.type === "text") {
 				projectActions.deleteTextSegments(selection.indices);
+			} else if (selection.type === "reaction") {
+				projectActions.deleteReactionSegments(selection.indices);
+			} else if (selection.type === "cursor") {
+				projectActions.deleteCursorSegments(selection.indices);
 			} else if (selection.type === "clip") {
 				// Delete all selected clips in reverse order
 				[...selection.indices]
@@ -594,6 +649,7 @@ export function Timeline() {
 					<div class="absolute inset-0 flex items-end">
 						<TimelineMarkings />
 					</div>
+					<ChapterMarkers />
 					<div class="absolute bottom-0">
 						<Tooltip content="Add track">
 							<TrackManager
@@ -680,6 +736,26 @@ export function Timeline() {
 									</TrackRow>
 								)}
 							</For>
+							<Show when={trackState().reaction}>
+								<TrackRow icon={trackIcons.reaction}>
+									<ReactionTrack
+										onDragStateChanged={(v) => {
+											reactionSegmentDragState = v;
+										}}
+										handleUpdatePlayhead={handleUpdatePlayhead}
+									/>
+								</TrackRow>
+							</Show>
+							<Show when={trackState().cursor}>
+								<TrackRow icon={trackIcons.cursor}>
+									<CursorTrack
+										onDragStateChanged={(v) => {
+											cursorSegmentDragState = v;
+										}}
+										handleUpdatePlayhead={handleUpdatePlayhead}
+									/>
+								</TrackRow>
+							</Show>
 							<For each={maskTrackRows()}>
 								{(laneIndex) => (
 									<TrackRow
@@ -766,6 +842,51 @@ function TrackRow(props: {
 	);
 }
 
+function ChapterMarkers() {
+	const { project, setProject, editorState } = useEditorContext();
+	const { secsPerPixel } = useTimelineContext();
+	const transform = () => editorState.timeline.transform;
+
+	return (
+		<div
+			class="absolute inset-y-0 right-0 pointer-events-none"
+			style={{ left: `${TRACK_GUTTER}px` }}
+		>
+			<Index each={project.timeline?.markers ?? []}>
+				{(marker, index) => (
+					<Show when={marker().time >= transform().position}>
+						<div
+							class="absolute top-0 z-20 -ml-1.5 pointer-events-auto"
+							style={{
+								transform: `translateX(${
+									(marker().time - transform().position) / secsPerPixel()
+								}px)`,
+							}}
+						>
+							<Tooltip content="Remove marker">
+								<button
+									type="button"
+									class="text-gray-11 hover:text-blue-9"
+									onMouseDown={(e) => e.stopPropagation()}
+									onClick={() =>
+										setProject(
+											"timeline",
+											"markers",
+											removeMarker(project.timeline?.markers ?? [], index),
+										)
+									}
+								>
+									<IconLucideFlag class="size-3" />
+								</button>
+							</Tooltip>
+						</div>
+					</Show>
+				)}
+			</Index>
+		</div>
+	);
+}
+
 function TimelineMarkings() {
 	const { editorState } = useEditorContext();
 	const { secsPerPixel, markingResolution } = useTimelineContext();
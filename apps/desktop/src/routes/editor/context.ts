@@ -22,6 +22,7 @@ import { createStore, produce, reconcile, unwrap } from "solid-js/store";
 
 import { generalSettingsStore } from "~/store";
 
+import { createTauriEventListener } from "~/utils/createEventListener";
 import { createPresets } from "~/utils/createPresets";
 import { createCustomDomainQuery } from "~/utils/queries";
 import {
@@ -35,6 +36,7 @@ import {
 	type EditorPreviewQuality,
 	events,
 	type FramesRendered,
+	type IdleRange,
 	type MultipleSegments,
 	type ProjectConfiguration,
 	type RecordingMeta,
@@ -48,7 +50,9 @@ import {
 	cleanup as cleanupCropVideoPreloader,
 	preloadCropVideoMetadata,
 } from "./cropVideoPreloader";
+import { type CursorSegment, defaultCursorSegment } from "./cursorSegments";
 import type { MaskSegment } from "./masks";
+import { defaultReactionSegment, type ReactionSegment } from "./reactions";
 import type { TextSegment } from "./text";
 import {
 	getUsedTrackCount,
@@ -91,14 +95,21 @@ export const getPreviewResolution = (
 	return { x: width, y: height };
 };
 
-export type TimelineTrackType = "clip" | "text" | "zoom" | "scene" | "mask";
+export type TimelineTrackType =
+	| "clip"
+	| "text"
+	| "zoom"
+	| "scene"
+	| "mask"
+	| "reaction"
+	| "cursor";
 
 export const MAX_ZOOM_IN = 3;
 const PROJECT_SAVE_DEBOUNCE_MS = 250;
 
 export type RenderState =
 	| { type: "starting" }
-	| { type: "rendering"; progress: FramesRendered };
+	| { type: "rendering"; progress: FramesRendered; warnings: string[] };
 
 export type CustomDomainResponse = {
 	custom_domain: string | null;
@@ -111,11 +122,13 @@ type WithCornerStyle<T> = T & { roundingType: CornerRoundingType };
 
 type EditorTimelineConfiguration = Omit<
 	TimelineConfiguration,
-	"sceneSegments" | "maskSegments"
+	"sceneSegments" | "maskSegments" | "reactionSegments" | "cursorSegments"
 > & {
 	sceneSegments?: SceneSegment[];
 	maskSegments: MaskSegment[];
 	textSegments: TextSegment[];
+	reactionSegments: ReactionSegment[];
+	cursorSegments: CursorSegment[];
 };
 
 export type EditorProjectConfiguration = Omit<
@@ -162,6 +175,18 @@ export function normalizeProject(
 						}
 					).textSegments ?? [],
 				),
+				reactionSegments: (config.timeline.reactionSegments ?? []).map(
+					(segment) => ({
+						...defaultReactionSegment(segment.start, segment.end),
+						...segment,
+					}),
+				),
+				cursorSegments: (config.timeline.cursorSegments ?? []).map(
+					(segment) => ({
+						...defaultCursorSegment(segment.start, segment.end),
+						...segment,
+					}),
+				),
 			}
 		: undefined;
 
@@ -186,6 +211,8 @@ export function serializeProjectConfiguration(
 				...project.timeline,
 				maskSegments: project.timeline.maskSegments ?? [],
 				textSegments: project.timeline.textSegments ?? [],
+				reactionSegments: project.timeline.reactionSegments ?? [],
+				cursorSegments: project.timeline.cursorSegments ?? [],
 			}
 		: project.timeline;
 
@@ -273,6 +300,38 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 					setEditorState("timeline", "selection", null);
 				});
 			},
+			cutRecordingRanges: (ranges: IdleRange[]) => {
+				if (!project.timeline || ranges.length === 0) return;
+
+				const segments = project.timeline.segments.flatMap((segment) =>
+					ranges
+						.filter(
+							(range) =>
+								range.recordingSegment === (segment.recordingSegment ?? 0),
+						)
+						.reduce(
+							(pieces, range) =>
+								pieces.flatMap((piece) => {
+									if (range.end <= piece.start || range.start >= piece.end)
+										return [piece];
+
+									const kept = [];
+									if (range.start > piece.start)
+										kept.push({ ...piece, end: range.start });
+									if (range.end < piece.end)
+										kept.push({ ...piece, start: range.end });
+									return kept;
+								}),
+							[{ ...segment }],
+						),
+				);
+				if (segments.length === 0) return;
+
+				batch(() => {
+					setProject("timeline", "segments", segments);
+					setEditorState("timeline", "selection", null);
+				});
+			},
 			splitZoomSegment: (index: number, time: number) => {
 				setProject(
 					"timeline",
@@ -395,6 +454,42 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 					setEditorState("timeline", "selection", null);
 				});
 			},
+			deleteReactionSegments: (segmentIndices: number[]) => {
+				batch(() => {
+					setProject(
+						"timeline",
+						"reactionSegments",
+						produce((segments) => {
+							if (!segments) return;
+							const sorted = [...new Set(segmentIndices)]
+								.filter(
+									(i) => Number.isInteger(i) && i >= 0 && i < segments.length,
+								)
+								.sort((a, b) => b - a);
+							for (const i of sorted) segments.splice(i, 1);
+						}),
+					);
+					setEditorState("timeline", "selection", null);
+				});
+			},
+			deleteCursorSegments: (segmentIndices: number[]) => {
+				batch(() => {
+					setProject(
+						"timeline",
+						"cursorSegments",
+						produce((segments) => {
+							if (!segments) return;
+							const sorted = [...new Set(segmentIndices)]
+								.filter(
+									(i) => Number.isInteger(i) && i >= 0 && i < segments.length,
+								)
+								.sort((a, b) => b - a);
+							for (const i of sorted) segments.splice(i, 1);
+						}),
+					);
+					setEditorState("timeline", "selection", null);
+				});
+			},
 			splitSceneSegment: (index: number, time: number) => {
 				setProject(
 					"timeline",
@@ -472,6 +567,16 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 							textSegment.end += diff(textSegment.end);
 						}
 
+						for (const reactionSegment of timeline.reactionSegments ?? []) {
+							reactionSegment.start += diff(reactionSegment.start);
+							reactionSegment.end += diff(reactionSegment.end);
+						}
+
+						for (const cursorSegment of timeline.cursorSegments ?? []) {
+							cursorSegment.start += diff(cursorSegment.start);
+							cursorSegment.end += diff(cursorSegment.end);
+						}
+
 						segment.timescale = timescale;
 					}),
 				);
@@ -666,7 +771,9 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 					| { type: "clip"; indices: number[] }
 					| { type: "scene"; indices: number[] }
 					| { type: "mask"; indices: number[] }
-					| { type: "text"; indices: number[] },
+					| { type: "text"; indices: number[] }
+					| { type: "reaction"; indices: number[] }
+					| { type: "cursor"; indices: number[] },
 				transform: {
 					// visible seconds
 					zoom: zoomOutLimit(),
@@ -709,6 +816,9 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 					scene: true,
 					mask: initialMaskTrackCount,
 					text: initialTextTrackCount,
+					reaction:
+						(project.timeline?.reactionSegments?.length ?? 0) > 0,
+					cursor: (project.timeline?.cursorSegments?.length ?? 0) > 0,
 				},
 				hoveredTrack: null as null | TimelineTrackType,
 			},
@@ -718,6 +828,24 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 		const [systemAudioWaveforms] = createResource(() =>
 			commands.getSystemAudioWaveforms(),
 		);
+		const [voiceActivity] = createResource(() => commands.getVoiceActivity());
+		const [
+			previewProxy,
+			{ mutate: setPreviewProxy, refetch: refetchPreviewProxy },
+		] = createResource(() => commands.getPreviewProxy());
+
+		createEffect(
+			on(
+				() => {
+					trackStore(project);
+				},
+				() => setPreviewProxy(null),
+				{ defer: true },
+			),
+		);
+		createTauriEventListener(events.previewProxyReady, () =>
+			refetchPreviewProxy(),
+		);
 		const customDomain = createCustomDomainQuery();
 
 		return {
@@ -742,6 +870,8 @@ export const [EditorContextProvider, useEditorContext] = createContextProvider(
 			setExportState,
 			micWaveforms,
 			systemAudioWaveforms,
+			voiceActivity,
+			previewProxy,
 			previewQuality,
 			setPreviewQuality,
 			previewResolutionBase,
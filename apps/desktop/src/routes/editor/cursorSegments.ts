@@ -0,0 +1,12 @@
+import type { HiddenCursorSegment } from "~/utils/tauri";
+
+export type CursorSegment = Required<HiddenCursorSegment>;
+
+export const defaultCursorSegment = (
+	start: number,
+	end: number,
+): CursorSegment => ({
+	start,
+	end,
+	fadeDuration: 0.3,
+});
@@ -0,0 +1,26 @@
+import type { TimelineMarker } from "~/utils/tauri";
+
+const MARKER_TOGGLE_SECS = 0.25;
+
+export const addMarker = (
+	markers: TimelineMarker[],
+	time: number,
+): TimelineMarker[] =>
+	[...markers, { time, label: "" }].sort((a, b) => a.time - b.time);
+
+export const removeMarker = (
+	markers: TimelineMarker[],
+	index: number,
+): TimelineMarker[] => markers.filter((_, i) => i !== index);
+
+export const toggleMarker = (
+	markers: TimelineMarker[],
+	time: number,
+): TimelineMarker[] => {
+	const existing = markers.findIndex(
+		(marker) => Math.abs(marker.time - time) < MARKER_TOGGLE_SECS,
+	);
+	return existing === -1
+		? addMarker(markers, time)
+		: removeMarker(markers, existing);
+};
@@ -0,0 +1,17 @@
+import type { ReactionSegment as SerializedReactionSegment } from "~/utils/tauri";
+
+export type ReactionSegment = Required<SerializedReactionSegment>;
+
+export const reactionEmoji = ["🎉", "⚠️", "👀", "🔥", "✅", "❌", "💡", "👍"];
+
+export const defaultReactionSegment = (
+	start: number,
+	end: number,
+): ReactionSegment => ({
+	start,
+	end,
+	emoji: "🎉",
+	center: { x: 0.85, y: 0.2 },
+	scale: 0.15,
+	animation: "pop",
+});
@@ -0,0 +1,6 @@
+export default function () {
+	document.documentElement.setAttribute("data-transparent-window", "true");
+	document.body.style.background = "transparent";
+
+	return null;
+}
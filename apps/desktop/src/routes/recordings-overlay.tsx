@@ -544,7 +544,7 @@ function createRecordingMutations(media: MediaEntry) {
 			try {
 				if (isRecording) {
 					// First try to get existing rendered video
-					const outputPath = await exportWithDefaultSettings(
+					const { path: outputPath } = await exportWithDefaultSettings(
 						createRenderProgressCallback("copy", setActionState),
 					);
 
@@ -629,7 +629,7 @@ function createRecordingMutations(media: MediaEntry) {
 			});
 
 			if (isRecording) {
-				const outputPath = await exportWithDefaultSettings(
+				const { path: outputPath } = await exportWithDefaultSettings(
 					createRenderProgressCallback("save", setActionState),
 				);
 
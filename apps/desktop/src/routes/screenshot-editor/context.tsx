@@ -109,6 +109,7 @@ const DEFAULT_AUDIO: AudioConfiguration = {
 	micVolumeDb: 0,
 	micStereoMode: "stereo",
 	systemVolumeDb: 0,
+	clickSounds: { enabled: false, sound: "soft", volume: 0.5 },
 };
 
 const DEFAULT_CURSOR: CursorConfiguration = {
@@ -146,6 +147,8 @@ const DEFAULT_PROJECT: ScreenshotProject = {
 		shadow: 0,
 		advancedShadow: null,
 		border: null,
+		zoomDim: 0,
+		fit: { mode: "contain", anchor: { x: 0.5, y: 0.5 } },
 	},
 	aspectRatio: null,
 	camera: DEFAULT_CAMERA,
@@ -0,0 +1,148 @@
+import { getCurrentWindow } from "@tauri-apps/api/window";
+import { createEffect, createSignal, onCleanup, onMount, Show } from "solid-js";
+import { createStore, reconcile } from "solid-js/store";
+
+import { createTauriEventListener } from "~/utils/createEventListener";
+import { commands, events, type TeleprompterState } from "~/utils/tauri";
+
+const SPEED_STEP = 10;
+
+export default function Teleprompter() {
+	const [state, setState] = createStore<TeleprompterState>({
+		script: "",
+		speed: 40,
+		playing: false,
+	});
+	const [draft, setDraft] = createSignal("");
+	let scrollRef: HTMLDivElement | undefined;
+
+	onMount(async () => {
+		setState(reconcile(await commands.getTeleprompter()));
+		getCurrentWindow().show();
+	});
+
+	createTauriEventListener(events.teleprompterState, (payload) =>
+		setState(reconcile(payload)),
+	);
+	createTauriEventListener(events.teleprompterRewind, () =>
+		scrollRef?.scrollTo({ top: 0 }),
+	);
+
+	createEffect(() => {
+		if (!state.playing) return;
+
+		let position = scrollRef?.scrollTop ?? 0;
+		let last = performance.now();
+		let frame = requestAnimationFrame(function step(now) {
+			if (scrollRef) {
+				position += (state.speed * (now - last)) / 1000;
+				scrollRef.scrollTop = position;
+				if (
+					scrollRef.scrollTop + scrollRef.clientHeight >=
+					scrollRef.scrollHeight
+				) {
+					commands.setTeleprompterPlaying(false);
+				}
+			}
+			last = now;
+			frame = requestAnimationFrame(step);
+		});
+
+		onCleanup(() => cancelAnimationFrame(frame));
+	});
+
+	return (
+		<div class="flex flex-col w-screen h-screen rounded-xl overflow-hidden bg-gray-1 text-gray-12">
+			<div
+				data-tauri-drag-region
+				class="flex flex-row gap-2 items-center px-3 h-10 shrink-0 border-b border-gray-3 text-sm"
+			>
+				<button
+					type="button"
+					class="px-2 py-1 rounded-md hover:bg-gray-3 disabled:opacity-50"
+					disabled={!state.script}
+					onClick={() => commands.setTeleprompterPlaying(!state.playing)}
+				>
+					{state.playing ? "Pause" : "Play"}
+				</button>
+				<button
+					type="button"
+					class="px-2 py-1 rounded-md hover:bg-gray-3"
+					onClick={() => commands.rewindTeleprompter()}
+				>
+					Restart
+				</button>
+				<div class="flex flex-row gap-1 items-center ml-auto">
+					<button
+						type="button"
+						class="px-2 py-1 rounded-md hover:bg-gray-3"
+						onClick={() =>
+							commands.setTeleprompterSpeed(state.speed - SPEED_STEP)
+						}
+					>
+						−
+					</button>
+					<span class="w-14 text-center tabular-nums text-gray-11">
+						{state.speed} px/s
+					</span>
+					<button
+						type="button"
+						class="px-2 py-1 rounded-md hover:bg-gray-3"
+						onClick={() =>
+							commands.setTeleprompterSpeed(state.speed + SPEED_STEP)
+						}
+					>
+						+
+					</button>
+				</div>
+				<Show when={state.script}>
+					<button
+						type="button"
+						class="px-2 py-1 rounded-md hover:bg-gray-3"
+						onClick={() => {
+							setDraft(state.script);
+							commands.setTeleprompterScript("");
+						}}
+					>
+						Edit
+					</button>
+				</Show>
+				<button
+					type="button"
+					class="px-2 py-1 rounded-md hover:bg-gray-3"
+					onClick={() => commands.closeTeleprompter()}
+				>
+					Close
+				</button>
+			</div>
+			<Show
+				when={state.script}
+				fallback={
+					<div class="flex flex-col flex-1 gap-2 p-3">
+						<textarea
+							class="flex-1 p-2 rounded-lg resize-none bg-gray-2 outline-none text-base"
+							placeholder="Paste your script here"
+							value={draft()}
+							onInput={(e) => setDraft(e.currentTarget.value)}
+						/>
+						<button
+							type="button"
+							class="self-end px-3 py-1 rounded-md bg-blue-9 text-white disabled:opacity-50"
+							disabled={!draft().trim()}
+							onClick={() => commands.setTeleprompterScript(draft())}
+						>
+							Load script
+						</button>
+					</div>
+				}
+			>
+				<div
+					ref={scrollRef}
+					class="flex-1 overflow-y-auto px-6 whitespace-pre-wrap text-3xl leading-relaxed"
+				>
+					<div class="py-[40vh]">{state.script}</div>
+				</div>
+			</Show>
+		</div>
+	);
+}
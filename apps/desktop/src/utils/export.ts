@@ -1,9 +1,16 @@
 import { Channel } from "@tauri-apps/api/core";
-import { commands, type ExportSettings, type FramesRendered } from "./tauri";
+import { isUserMessage, MessageError } from "./messages";
+import {
+	commands,
+	type ComparisonExportSettings,
+	type ExportCompletionActions,
+	type ExportPreset,
+	type ExportSettings,
+	type ExportSplit,
+	type FramesRendered,
+} from "./tauri";
 
-export function createExportTask(
-	projectPath: string,
-	settings: ExportSettings,
+function createProgressChannel(
 	onProgress: (progress: FramesRendered) => void,
 ) {
 	const progress = new Channel<FramesRendered>((e) => {
@@ -20,8 +27,67 @@ export function createExportTask(
 		).__TAURI_INTERNALS__;
 		internals?.unregisterCallback?.(progress.id);
 	};
+	return { progress, cancel };
+}
+
+function rethrowUserMessage(error: unknown): never {
+	throw isUserMessage(error) ? new MessageError(error) : error;
+}
+
+export function createExportTask(
+	projectPath: string,
+	settings: ExportSettings,
+	onProgress: (progress: FramesRendered) => void,
+	completionActions?: ExportCompletionActions,
+) {
+	const { progress, cancel } = createProgressChannel(onProgress);
+	const promise = commands
+		.exportVideo(projectPath, progress, settings, completionActions ?? null)
+		.catch(rethrowUserMessage)
+		.finally(cancel);
+	return { promise, cancel };
+}
+
+export function createExportPartsTask(
+	projectPath: string,
+	settings: ExportSettings,
+	split: ExportSplit,
+	destination: string,
+	onProgress: (progress: FramesRendered) => void,
+) {
+	const { progress, cancel } = createProgressChannel(onProgress);
+	const promise = commands
+		.exportVideoParts(projectPath, progress, settings, split, destination)
+		.catch(rethrowUserMessage)
+		.finally(cancel);
+	return { promise, cancel };
+}
+
+export function createExportPresetsTask(
+	projectPath: string,
+	presets: ExportPreset[],
+	destination: string,
+	onProgress: (progress: FramesRendered) => void,
+) {
+	const { progress, cancel } = createProgressChannel(onProgress);
+	const promise = commands
+		.exportVideoPresets(projectPath, progress, presets, destination)
+		.catch(rethrowUserMessage)
+		.finally(cancel);
+	return { promise, cancel };
+}
+
+export function createExportComparisonTask(
+	before: string,
+	after: string,
+	settings: ComparisonExportSettings,
+	destination: string,
+	onProgress: (progress: FramesRendered) => void,
+) {
+	const { progress, cancel } = createProgressChannel(onProgress);
 	const promise = commands
-		.exportVideo(projectPath, progress, settings)
+		.exportComparison(before, after, progress, settings, destination)
+		.catch(rethrowUserMessage)
 		.finally(cancel);
 	return { promise, cancel };
 }
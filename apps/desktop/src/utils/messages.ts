@@ -0,0 +1,59 @@
+import type { MessageKey, UserMessage } from "./tauri";
+
+const en: Record<MessageKey, string> = {
+	"export.renderingFrame": "Rendering frame {current}/{total}",
+	"export.cancelled": "Export cancelled",
+	"export.notEnoughSpace":
+		"Not enough disk space to export: {availableMb}MB available, about {requiredMb}MB needed",
+	"export.decodeFailed":
+		"Failed to decode video frames. The recording may be corrupted or incomplete. Try re-recording or contact support if the issue persists.",
+	"export.prepareOutputFailed": "Failed to prepare export output: {detail}",
+	"export.finalizeFailed": "Failed to finalize export: {detail}",
+	"export.failed": "Failed to export recording: {detail}",
+	"export.renderDownscaled":
+		"Your GPU is short on memory, so frames were rendered at {renderWidth}x{renderHeight} and upscaled to {outputWidth}x{outputHeight}",
+	"export.requiresLicense":
+		"Exporting above 1440p requires a license. Activate one in Settings to continue.",
+	"export.preExportCommandFailed": "Pre-export command failed: {detail}",
+	"export.encoderUnavailable":
+		"No {codec} encoder is available, so this export can't be encoded",
+	"export.outputNotWritable": "Can't write the export to {path}: {detail}",
+	"export.emptyTimeline": "There's nothing to export: the timeline is empty",
+	"export.resolutionCompatibility":
+		"{width}x{height} is larger than many players support for H.264 video, so the export may not play everywhere",
+	"export.gifFrameRate":
+		"Most viewers play GIFs above {maxFps} fps slower than they should",
+	"export.paused":
+		"Paused at frame {current}/{total} while you're using your computer",
+	"export.pausedByUser": "Paused at frame {current}/{total}",
+};
+
+export function isUserMessage(value: unknown): value is UserMessage {
+	return (
+		typeof value === "object" &&
+		value !== null &&
+		"key" in value &&
+		"params" in value &&
+		"text" in value
+	);
+}
+
+export function formatMessage(
+	message: UserMessage,
+	catalog: Partial<Record<MessageKey, string>> = en,
+) {
+	const template = catalog[message.key];
+	if (!template) return message.text;
+
+	return template.replace(/\{(\w+)\}/g, (placeholder, name: string) => {
+		const value = message.params[name];
+		if (value === undefined) return placeholder;
+		return typeof value === "number" ? value.toLocaleString() : value;
+	});
+}
+
+export class MessageError extends Error {
+	constructor(public userMessage: UserMessage) {
+		super(formatMessage(userMessage));
+	}
+}
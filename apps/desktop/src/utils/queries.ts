@@ -45,8 +45,7 @@ export const listScreens = queryOptions({
 	queryKey: ["capture", "displays"] as const,
 	queryFn: () => commands.listCaptureDisplays(),
 	reconcile: "id",
-	refetchInterval: 10_000,
-	staleTime: 5_000,
+	refetchInterval: false,
 });
 
 export const listWindowsWithThumbnails = queryOptions({
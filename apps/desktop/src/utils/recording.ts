@@ -37,7 +37,13 @@ export function handleRecordingResult(
 					commands.setRecordingMode("studio");
 				}
 			} else if (result === "UpgradeRequired") commands.showWindow("Upgrade");
-			else
+			else if (typeof result === "object" && "InsufficientDiskSpace" in result) {
+				const { available_mb, required_mb } = result.InsufficientDiskSpace;
+				await dialog.message(
+					`There's only ${available_mb}MB of free disk space, but at least ${required_mb}MB is needed to start recording. Free up some space and try again.`,
+					{ title: "Not enough disk space", kind: "error" },
+				);
+			} else
 				await dialog.message(`Error: ${result}`, {
 					title: "Error starting recording",
 				});
@@ -44,6 +44,9 @@ async deleteRecording() : Promise<null> {
 async takeScreenshot(target: ScreenCaptureTarget) : Promise<string> {
     return await TAURI_INVOKE("take_screenshot", { target });
 },
+async captureFromClipboard() : Promise<string> {
+    return await TAURI_INVOKE("capture_from_clipboard");
+},
 async listCameras() : Promise<CameraInfo[]> {
     return await TAURI_INVOKE("list_cameras");
 },
@@ -89,18 +92,87 @@ async focusCapturesPanel() : Promise<void> {
 async getCurrentRecording() : Promise<JsonValue<CurrentRecording | null>> {
     return await TAURI_INVOKE("get_current_recording");
 },
-async exportVideo(projectPath: string, progress: TAURI_CHANNEL<FramesRendered>, settings: ExportSettings) : Promise<string> {
-    return await TAURI_INVOKE("export_video", { projectPath, progress, settings });
+async exportVideo(projectPath: string, progress: TAURI_CHANNEL<FramesRendered>, settings: ExportSettings, completionActions: ExportCompletionActions | null) : Promise<ExportResult> {
+    return await TAURI_INVOKE("export_video", { projectPath, progress, settings, completionActions });
+},
+async exportVideoParts(projectPath: string, progress: TAURI_CHANNEL<FramesRendered>, settings: ExportSettings, split: ExportSplit, destination: string) : Promise<ExportResult[]> {
+    return await TAURI_INVOKE("export_video_parts", { projectPath, progress, settings, split, destination });
+},
+async exportVideoPresets(projectPath: string, progress: TAURI_CHANNEL<FramesRendered>, presets: ExportPreset[], destination: string) : Promise<ExportResult[]> {
+    return await TAURI_INVOKE("export_video_presets", { projectPath, progress, presets, destination });
+},
+async exportComparison(before: string, after: string, progress: TAURI_CHANNEL<FramesRendered>, settings: ComparisonExportSettings, destination: string) : Promise<ExportResult> {
+    return await TAURI_INVOKE("export_comparison", { before, after, progress, settings, destination });
+},
+async pauseExport(jobId: number) : Promise<null> {
+    return await TAURI_INVOKE("pause_export", { jobId });
+},
+async resumeExport(jobId: number) : Promise<null> {
+    return await TAURI_INVOKE("resume_export", { jobId });
 },
 async getExportEstimates(path: string, settings: ExportSettings) : Promise<ExportEstimates> {
     return await TAURI_INVOKE("get_export_estimates", { path, settings });
 },
+async verifyExport(path: string) : Promise<ExportVerification> {
+    return await TAURI_INVOKE("verify_export", { path });
+},
+async validateExport(projectPath: string, settings: ExportSettings) : Promise<ExportValidation> {
+    return await TAURI_INVOKE("validate_export", { projectPath, settings });
+},
 async generateExportPreview(projectPath: string, frameTime: number, settings: ExportPreviewSettings) : Promise<ExportPreviewResult> {
     return await TAURI_INVOKE("generate_export_preview", { projectPath, frameTime, settings });
 },
 async generateExportPreviewFast(frameTime: number, settings: ExportPreviewSettings) : Promise<ExportPreviewResult> {
     return await TAURI_INVOKE("generate_export_preview_fast", { frameTime, settings });
 },
+async resolveOutputPath(projectPath: string, extension: string) : Promise<string | null> {
+    return await TAURI_INVOKE("resolve_output_path", { projectPath, extension });
+},
+async chooseDefaultDirectory() : Promise<string | null> {
+    return await TAURI_INVOKE("choose_default_directory");
+},
+async runAvSyncTest() : Promise<AvSyncTestResult> {
+    return await TAURI_INVOKE("run_av_sync_test");
+},
+async getAvSyncCalibration() : Promise<number | null> {
+    return await TAURI_INVOKE("get_av_sync_calibration");
+},
+async clearAvSyncCalibration() : Promise<null> {
+    return await TAURI_INVOKE("clear_av_sync_calibration");
+},
+async getFrameInfo(index: number, generation: number | null) : Promise<FrameInfo> {
+    return await TAURI_INVOKE("get_frame_info", { index, generation });
+},
+async dumpFramePng(index: number, generation: number | null, path: string) : Promise<null> {
+    return await TAURI_INVOKE("dump_frame_png", { index, generation, path });
+},
+async dumpFrameHistory(dir: string) : Promise<FrameHistoryDump> {
+    return await TAURI_INVOKE("dump_frame_history", { dir });
+},
+async clearFrameHistory() : Promise<number> {
+    return await TAURI_INVOKE("clear_frame_history");
+},
+async listCursorThemes() : Promise<CursorThemeInfo[]> {
+    return await TAURI_INVOKE("list_cursor_themes");
+},
+async setCursorTheme(id: string | null) : Promise<null> {
+    return await TAURI_INVOKE("set_cursor_theme", { id });
+},
+async analyzeRecording(projectPath: string, heatmap: boolean) : Promise<RecordingAnalysisResult> {
+    return await TAURI_INVOKE("analyze_recording", { projectPath, heatmap });
+},
+async indexScreenText(projectPath: string) : Promise<ScreenTextIndex> {
+    return await TAURI_INVOKE("index_screen_text", { projectPath });
+},
+async searchRecordings(query: string) : Promise<RecordingSearchResult[]> {
+    return await TAURI_INVOKE("search_recordings", { query });
+},
+async scanForSensitiveContent(projectPath: string, createMasks: boolean) : Promise<SensitiveContentScan> {
+    return await TAURI_INVOKE("scan_for_sensitive_content", { projectPath, createMasks });
+},
+async detectIdleSections(projectPath: string, minIdleSecs: number | null) : Promise<IdleRange[]> {
+    return await TAURI_INVOKE("detect_idle_sections", { projectPath, minIdleSecs });
+},
 async startVideoImport(sourcePath: string) : Promise<string> {
     return await TAURI_INVOKE("start_video_import", { sourcePath });
 },
@@ -152,6 +224,18 @@ async setProjectConfig(config: ProjectConfiguration) : Promise<null> {
 async updateProjectConfigInMemory(config: ProjectConfiguration, frameNumber: number | null, fps: number | null, resolutionBase: XY<number> | null) : Promise<null> {
     return await TAURI_INVOKE("update_project_config_in_memory", { config, frameNumber, fps, resolutionBase });
 },
+async setPreviewGuides(guides: PreviewGuides) : Promise<null> {
+    return await TAURI_INVOKE("set_preview_guides", { guides });
+},
+async simulateSpring(config: SpringMassDamperSimulationConfig, scenario: SpringScenario) : Promise<SpringSample[]> {
+    return await TAURI_INVOKE("simulate_spring", { config, scenario });
+},
+async showNativePreview(bounds: NativePreviewBounds) : Promise<null> {
+    return await TAURI_INVOKE("show_native_preview", { bounds });
+},
+async hideNativePreview() : Promise<null> {
+    return await TAURI_INVOKE("hide_native_preview");
+},
 async generateZoomSegmentsFromClicks() : Promise<ZoomSegment[]> {
     return await TAURI_INVOKE("generate_zoom_segments_from_clicks");
 },
@@ -179,6 +263,9 @@ async createScreenshotEditorInstance() : Promise<SerializedScreenshotEditorInsta
 async updateScreenshotConfig(config: ProjectConfiguration, save: boolean) : Promise<null> {
     return await TAURI_INVOKE("update_screenshot_config", { config, save });
 },
+async beautifyScreenshot(source: ScreenshotSource, background: BackgroundConfiguration) : Promise<number[]> {
+    return await TAURI_INVOKE("beautify_screenshot", { source, background });
+},
 async getRecordingMeta(path: string, fileType: FileType) : Promise<RecordingMetaWithMetadata> {
     return await TAURI_INVOKE("get_recording_meta", { path, fileType });
 },
@@ -215,8 +302,8 @@ async isCameraWindowOpen() : Promise<boolean> {
 async seekTo(frameNumber: number) : Promise<null> {
     return await TAURI_INVOKE("seek_to", { frameNumber });
 },
-async getDisplayFrameForCropping(fps: number) : Promise<number[]> {
-    return await TAURI_INVOKE("get_display_frame_for_cropping", { fps });
+async getDisplayFrameForCropping(fps: number, options: FrameImageOptions | null) : Promise<FrameImage> {
+    return await TAURI_INVOKE("get_display_frame_for_cropping", { fps, options });
 },
 async positionTrafficLights(controlsInset: [number, number] | null) : Promise<void> {
     await TAURI_INVOKE("position_traffic_lights", { controlsInset });
@@ -339,6 +426,96 @@ async recoverRecording(projectPath: string) : Promise<string> {
 },
 async discardIncompleteRecording(projectPath: string) : Promise<null> {
     return await TAURI_INVOKE("discard_incomplete_recording", { projectPath });
+},
+async checkForUpdates() : Promise<AvailableUpdate | null> {
+    return await TAURI_INVOKE("check_for_updates");
+},
+async hasBetaChannel() : Promise<boolean> {
+    return await TAURI_INVOKE("has_beta_channel");
+},
+async installUpdate() : Promise<null> {
+    return await TAURI_INVOKE("install_update");
+},
+async getLicenseStatus() : Promise<LicenseStatus> {
+    return await TAURI_INVOKE("get_license_status");
+},
+async activateLicense(licenseKey: string) : Promise<LicenseStatus> {
+    return await TAURI_INVOKE("activate_license", { licenseKey });
+},
+async listEffectPlugins() : Promise<EffectPluginInfo[]> {
+    return await TAURI_INVOKE("list_effect_plugins");
+},
+async enableEffect(id: string, enabled: boolean) : Promise<null> {
+    return await TAURI_INVOKE("enable_effect", { id, enabled });
+},
+async listProjectTemplates() : Promise<ProjectTemplate[]> {
+    return await TAURI_INVOKE("list_project_templates");
+},
+async saveProjectTemplate(projectPath: string, name: string, exportSettings: ExportSettings | null) : Promise<ProjectTemplate> {
+    return await TAURI_INVOKE("save_project_template", { projectPath, name, exportSettings });
+},
+async newProjectFromTemplate(projectPath: string, templateId: string) : Promise<ProjectConfiguration> {
+    return await TAURI_INVOKE("new_project_from_template", { projectPath, templateId });
+},
+async getBrandKit() : Promise<BrandKit> {
+    return await TAURI_INVOKE("get_brand_kit");
+},
+async addBrandLogo(path: string) : Promise<BrandKit> {
+    return await TAURI_INVOKE("add_brand_logo", { path });
+},
+async addBrandFont(path: string) : Promise<BrandKit> {
+    return await TAURI_INVOKE("add_brand_font", { path });
+},
+async removeBrandAsset(id: string) : Promise<BrandKit> {
+    return await TAURI_INVOKE("remove_brand_asset", { id });
+},
+async setBrandColors(colors: BrandColor[]) : Promise<BrandKit> {
+    return await TAURI_INVOKE("set_brand_colors", { colors });
+},
+async setBrandWatermark(watermark: BrandWatermark) : Promise<BrandKit> {
+    return await TAURI_INVOKE("set_brand_watermark", { watermark });
+},
+async listSystemFonts() : Promise<string[]> {
+    return await TAURI_INVOKE("list_system_fonts");
+},
+async getRecorderState() : Promise<RecorderState> {
+    return await TAURI_INVOKE("get_recorder_state");
+},
+async getWindowFocusChanges() : Promise<WindowFocusEvent[]> {
+    return await TAURI_INVOKE("get_window_focus_changes");
+},
+async getTimelineClicks() : Promise<TimelineClick[]> {
+    return await TAURI_INVOKE("get_timeline_clicks");
+},
+async getTeleprompter() : Promise<TeleprompterState> {
+    return await TAURI_INVOKE("get_teleprompter");
+},
+async showTeleprompter(script: string | null) : Promise<null> {
+    return await TAURI_INVOKE("show_teleprompter", { script });
+},
+async closeTeleprompter() : Promise<void> {
+    await TAURI_INVOKE("close_teleprompter");
+},
+async setTeleprompterScript(script: string) : Promise<TeleprompterState> {
+    return await TAURI_INVOKE("set_teleprompter_script", { script });
+},
+async setTeleprompterSpeed(speed: number) : Promise<TeleprompterState> {
+    return await TAURI_INVOKE("set_teleprompter_speed", { speed });
+},
+async setTeleprompterPlaying(playing: boolean) : Promise<TeleprompterState> {
+    return await TAURI_INVOKE("set_teleprompter_playing", { playing });
+},
+async rewindTeleprompter() : Promise<void> {
+    await TAURI_INVOKE("rewind_teleprompter");
+},
+async getVoiceActivity() : Promise<VoiceActivity[][]> {
+    return await TAURI_INVOKE("get_voice_activity");
+},
+async generateBeatSyncedZoomSegments(musicPath: string) : Promise<ZoomSegment[]> {
+    return await TAURI_INVOKE("generate_beat_synced_zoom_segments", { musicPath });
+},
+async getPreviewProxy() : Promise<PreviewProxy | null> {
+    return await TAURI_INVOKE("get_preview_proxy");
 }
 }
 
@@ -347,14 +524,19 @@ async discardIncompleteRecording(projectPath: string) : Promise<null> {
 
 export const events = __makeEvents__<{
 audioInputLevelChange: AudioInputLevelChange,
+avSyncTestPulse: AvSyncTestPulse,
 currentRecordingChanged: CurrentRecordingChanged,
 devicesUpdated: DevicesUpdated,
+displaysChanged: DisplaysChanged,
 downloadProgress: DownloadProgress,
 editorStateChanged: EditorStateChanged,
+exportCompleted: ExportCompleted,
 newNotification: NewNotification,
 newScreenshotAdded: NewScreenshotAdded,
 newStudioRecordingAdded: NewStudioRecordingAdded,
 onEscapePress: OnEscapePress,
+previewProxyReady: PreviewProxyReady,
+recorderStateChanged: RecorderStateChanged,
 recordingDeleted: RecordingDeleted,
 recordingEvent: RecordingEvent,
 recordingOptionsChanged: RecordingOptionsChanged,
@@ -368,18 +550,27 @@ requestSetTargetMode: RequestSetTargetMode,
 requestStartRecording: RequestStartRecording,
 setCaptureAreaPending: SetCaptureAreaPending,
 targetUnderCursor: TargetUnderCursor,
+teleprompterRewind: TeleprompterRewind,
+teleprompterState: TeleprompterState,
+updateDownloadProgress: UpdateDownloadProgress,
 uploadProgressEvent: UploadProgressEvent,
-videoImportProgress: VideoImportProgress
+videoImportProgress: VideoImportProgress,
+windowFullscreenChanged: WindowFullscreenChanged
 }>({
 audioInputLevelChange: "audio-input-level-change",
+avSyncTestPulse: "av-sync-test-pulse",
 currentRecordingChanged: "current-recording-changed",
 devicesUpdated: "devices-updated",
+displaysChanged: "displays-changed",
 downloadProgress: "download-progress",
 editorStateChanged: "editor-state-changed",
+exportCompleted: "export-completed",
 newNotification: "new-notification",
 newScreenshotAdded: "new-screenshot-added",
 newStudioRecordingAdded: "new-studio-recording-added",
 onEscapePress: "on-escape-press",
+previewProxyReady: "preview-proxy-ready",
+recorderStateChanged: "recorder-state-changed",
 recordingDeleted: "recording-deleted",
 recordingEvent: "recording-event",
 recordingOptionsChanged: "recording-options-changed",
@@ -393,8 +584,12 @@ requestSetTargetMode: "request-set-target-mode",
 requestStartRecording: "request-start-recording",
 setCaptureAreaPending: "set-capture-area-pending",
 targetUnderCursor: "target-under-cursor",
+teleprompterRewind: "teleprompter-rewind",
+teleprompterState: "teleprompter-state",
+updateDownloadProgress: "update-download-progress",
 uploadProgressEvent: "upload-progress-event",
-videoImportProgress: "video-import-progress"
+videoImportProgress: "video-import-progress",
+windowFullscreenChanged: "window-fullscreen-changed"
 })
 
 /** user-defined constants **/
@@ -409,14 +604,23 @@ export type AnnotationType = "arrow" | "circle" | "rectangle" | "text" | "mask"
 export type AppTheme = "system" | "light" | "dark"
 export type AspectRatio = "wide" | "vertical" | "square" | "classic" | "tall"
 export type Audio = { duration: number; sample_rate: number; channels: number; start_time: number }
-export type AudioConfiguration = { mute: boolean; improve: boolean; micVolumeDb: number; micStereoMode: StereoMode; systemVolumeDb: number }
+export type AudioConfiguration = { mute: boolean; improve: boolean; micVolumeDb: number; micStereoMode: StereoMode; systemVolumeDb: number; clickSounds: ClickSoundsConfiguration }
 export type AudioInputLevelChange = number
 export type AudioMeta = { path: string; start_time?: number | null; device_id?: string | null }
 export type AuthSecret = { api_key: string } | { token: string; expires: number }
 export type AuthStore = { secret: AuthSecret; user_id: string | null; plan: Plan | null; organizations?: Organization[] }
-export type BackgroundConfiguration = { source: BackgroundSource; blur: number; padding: number; rounding: number; roundingType: CornerStyle; inset: number; crop: Crop | null; shadow: number; advancedShadow: ShadowConfiguration | null; border: BorderConfiguration | null }
+export type AvSyncTestPulse = { index: number }
+export type AvSyncTestResult = { offsetSecs: number; confidence: number; pulsesDetected: number }
+export type AvailableUpdate = { version: string; currentVersion: string; notes: string | null }
+export type BackgroundConfiguration = { source: BackgroundSource; blur: number; padding: number; rounding: number; roundingType: CornerStyle; inset: number; crop: Crop | null; shadow: number; advancedShadow: ShadowConfiguration | null; border: BorderConfiguration | null; zoomDim: number; fit: DisplayFit }
+export type BackgroundKeyframe = { startMs: number; config: BackgroundConfiguration }
 export type BackgroundSource = { type: "wallpaper"; path: string | null } | { type: "image"; path: string | null } | { type: "color"; value: [number, number, number]; alpha?: number } | { type: "gradient"; from: [number, number, number]; to: [number, number, number]; angle?: number; noise_intensity?: number | null; noise_scale?: number | null; animated?: boolean | null; animation_speed?: number | null }
 export type BorderConfiguration = { enabled: boolean; width: number; color: [number, number, number]; opacity: number }
+export type BrandColor = { name: string; value: string }
+export type BrandFont = { id: string; name: string; families: string[]; path: string }
+export type BrandKit = { logos?: BrandLogo[]; fonts?: BrandFont[]; colors?: BrandColor[]; watermark?: BrandWatermark }
+export type BrandLogo = { id: string; name: string; path: string }
+export type BrandWatermark = { enabled?: boolean; logoId?: string | null; corner?: WatermarkCorner; scale?: number; opacity?: number }
 export type Camera = { hide: boolean; mirror: boolean; position: CameraPosition; size: number; zoomSize: number | null; rounding: number; shadow: number; advancedShadow: ShadowConfiguration | null; shape: CameraShape; roundingType: CornerStyle; scaleDuringZoom?: number }
 export type CameraFormatInfo = { width: number; height: number; frameRate: number }
 export type CameraInfo = { device_id: string; model_id: ModelIDType | null; display_name: string }
@@ -436,10 +640,16 @@ export type CaptureDisplay = { id: DisplayId; name: string; refresh_rate: number
 export type CaptureDisplayWithThumbnail = { id: DisplayId; name: string; refresh_rate: number; thumbnail: string | null }
 export type CaptureWindow = { id: WindowId; owner_name: string; name: string; bounds: LogicalBounds; refresh_rate: number; bundle_identifier: string | null }
 export type CaptureWindowWithThumbnail = { id: WindowId; owner_name: string; name: string; bounds: LogicalBounds; refresh_rate: number; thumbnail: string | null; app_icon: string | null; bundle_identifier: string | null }
+export type CardConfiguration = { enabled?: boolean; title?: string | null; subtitle?: string | null; author?: string | null; duration?: number; background?: BackgroundSource | null; textColor?: string; fontFamily?: string }
+export type ClickSound = "soft" | "crisp"
+export type ClickSoundsConfiguration = { enabled: boolean; sound: ClickSound; volume: number }
 export type ClickSpringConfig = { tension: number; mass: number; friction: number }
 export type ClipConfiguration = { index: number; offsets: ClipOffsets }
 export type ClipOffsets = { camera?: number; mic?: number; system_audio?: number }
 export type CommercialLicense = { licenseKey: string; expiryDate: number | null; refresh: number; activatedOn: number }
+export type ComparisonExportSettings = { fps: number; resolutionBase: XY<number>; layout: ComparisonLayout; compressionBpp: number }
+export type ComparisonLayout = { type: "sideBySide" } | { type: "wipe"; from: number; to: number }
+export type ConfettiBurst = { time: number; origin: XY<number>; count?: number }
 export type CornerStyle = "squircle" | "rounded"
 export type Crop = { position: XY<number>; size: XY<number> }
 export type CurrentRecording = { target: CurrentRecordingTarget; mode: RecordingMode; status: RecordingStatus }
@@ -448,25 +658,49 @@ export type CurrentRecordingTarget = { window: { id: WindowId; bounds: LogicalBo
 export type CursorAnimationStyle = "slow" | "mellow" | "custom"
 export type CursorConfiguration = { hide: boolean; hideWhenIdle: boolean; hideWhenIdleDelay: number; size: number; type: CursorType; animationStyle: CursorAnimationStyle; tension: number; mass: number; friction: number; raw: boolean; motionBlur: number; useSvg: boolean; rotationAmount?: number; baseRotation?: number; clickSpring?: ClickSpringConfig | null; stopMovementInLastSeconds?: number | null }
 export type CursorMeta = { imagePath: string; hotspot: XY<number>; shape?: string | null }
+export type CursorRole = "arrow" | "pointer" | "ibeam" | "hand"
+export type CursorThemeInfo = { id: string; name: string; roles: CursorRole[]; active: boolean }
 export type CursorType = "auto" | "pointer" | "circle"
 export type Cursors = { [key in string]: string } | { [key in string]: CursorMeta }
 export type DeviceOrModelID = { DeviceID: string } | { ModelID: ModelIDType }
 export type DevicesUpdated = { cameras: CameraInfo[]; microphones: string[]; permissions: OSPermissionsCheck }
+export type DisplayAdapterDiag = { displayName: string; adapterDescription: string; adapterIndex: number; requiresCrossAdapterCopy: boolean }
+export type DisplayFit = { mode: FitMode; anchor: XY<number> }
 export type DisplayId = string
 export type DisplayInformation = { name: string | null; physical_size: PhysicalSize | null; logical_size: LogicalSize | null; logical_bounds: LogicalBounds | null; refresh_rate: string }
+export type DisplaysChanged = { displays: CaptureDisplay[] }
 export type DownloadProgress = { progress: number; message: string }
 export type EditorPreviewQuality = "quarter" | "half" | "full"
 export type EditorStateChanged = { playhead_position: number }
+export type EffectParamInfo = { name: string; default: number; min: number | null; max: number | null }
+export type EffectPluginInfo = { id: string; name: string; description: string | null; params: EffectParamInfo[]; enabled: boolean }
+export type ExportCompleted = { path: string }
+export type ExportCompletionActions = { revealInFileManager?: boolean; copyPath?: boolean; copyFile?: boolean; openWithDefaultPlayer?: boolean; runCommand?: string | null }
 export type ExportCompression = "Maximum" | "Social" | "Web" | "Potato"
+export type ExportDirectoryMode = "askEveryTime" | "sameAsProject" | "custom"
 export type ExportEstimates = { duration_seconds: number; estimated_time_seconds: number; estimated_size_mb: number }
+export type ExportPreset = { settings: ExportSettings; vertical?: boolean }
 export type ExportPreviewResult = { jpeg_base64: string; estimated_size_mb: number; actual_width: number; actual_height: number; frame_render_time_ms: number; total_frames: number }
 export type ExportPreviewSettings = { fps: number; resolution_base: XY<number>; compression_bpp: number }
+export type ExportPriority = "normal" | "background" | "backgroundWhenIdle"
+export type ExportResult = { path: string; sha256: string; verification: ExportVerification; adjustment: UserMessage | null }
 export type ExportSettings = ({ format: "Mp4" } & Mp4ExportSettings) | ({ format: "Gif" } & GifExportSettings)
+export type ExportSplit = { type: "duration"; minutes: number } | { type: "clips" } | { type: "markers" }
+export type ExportValidation = { problems: UserMessage[]; warnings: UserMessage[] }
+export type ExportVerification = { duration_seconds: number; width: number; height: number; video_streams: number; audio_streams: number }
 export type FileType = "recording" | "screenshot"
+export type FitMode = "contain" | "cover"
 export type Flags = { captions: boolean }
-export type FramesRendered = { renderedCount: number; totalFrames: number; type: "FramesRendered" }
-export type GeneralSettingsStore = { instanceId?: string; uploadIndividualFiles?: boolean; hideDockIcon?: boolean; autoCreateShareableLink?: boolean; enableNotifications?: boolean; disableAutoOpenLinks?: boolean; hasCompletedStartup?: boolean; theme?: AppTheme; commercialLicense?: CommercialLicense | null; lastVersion?: string | null; windowTransparency?: boolean; postStudioRecordingBehaviour?: PostStudioRecordingBehaviour; mainWindowRecordingStartBehaviour?: MainWindowRecordingStartBehaviour; custom_cursor_capture2?: boolean; serverUrl?: string; recordingCountdown?: number | null; enableNativeCameraPreview: boolean; autoZoomOnClicks?: boolean; postDeletionBehaviour?: PostDeletionBehaviour; excludedWindows?: WindowExclusion[]; deleteInstantRecordingsAfterUpload?: boolean; instantModeMaxResolution?: number; defaultProjectNameTemplate?: string | null; crashRecoveryRecording?: boolean; maxFps?: number; editorPreviewQuality?: EditorPreviewQuality; mainWindowPosition?: WindowPosition | null; cameraWindowPosition?: WindowPosition | null; cameraWindowPositionsByMonitorName?: { [key in string]: WindowPosition } }
-export type GifExportSettings = { fps: number; resolution_base: XY<number>; quality: GifQuality | null }
+export type FrameHistoryDump = { generation: number; paths: string[] }
+export type FrameHistoryError = { type: "notBuffered"; frameNumber: number; buffered: number[] } | { type: "stale"; expected: number; current: number } | { type: "playing" } | { type: "disabled" } | { type: "failed"; message: string }
+export type FrameImage = { type: "bytes"; data: number[] } | { type: "file"; path: string }
+export type FrameImageOptions = { maxSize?: number | null; jpegQuality?: number | null; toFile?: boolean }
+export type FrameInfo = { generation: number; frameNumber: number; width: number; height: number; stride: number; format: FramePixelFormat; timestampSecs: number; dataLen: number; averageColor: [number, number, number]; blackFraction: number }
+export type FramePixelFormat = "rgba" | "nv12"
+export type FramesRendered = { jobId: number; renderedCount: number; totalFrames: number; message: UserMessage; timings: RenderTimings | null; warnings: string[]; type: "FramesRendered" }
+export type FullscreenTransition = "willEnter" | "didEnter" | "willExit" | "didExit"
+export type GeneralSettingsStore = { instanceId?: string; uploadIndividualFiles?: boolean; hideDockIcon?: boolean; autoCreateShareableLink?: boolean; enableNotifications?: boolean; disableAutoOpenLinks?: boolean; hasCompletedStartup?: boolean; theme?: AppTheme; commercialLicense?: CommercialLicense | null; lastVersion?: string | null; windowTransparency?: boolean; postStudioRecordingBehaviour?: PostStudioRecordingBehaviour; mainWindowRecordingStartBehaviour?: MainWindowRecordingStartBehaviour; custom_cursor_capture2?: boolean; serverUrl?: string; recordingCountdown?: number | null; enableNativeCameraPreview: boolean; autoZoomOnClicks?: boolean; snapZoomToWindows?: boolean; zoomOnNarrationCues?: boolean; zoomCueKeywords?: string[]; postDeletionBehaviour?: PostDeletionBehaviour; excludedWindows?: WindowExclusion[]; deleteInstantRecordingsAfterUpload?: boolean; instantModeMaxResolution?: number; defaultProjectNameTemplate?: string | null; crashRecoveryRecording?: boolean; anonymizeInput?: boolean; keystrokeCapture?: KeystrokeCapture; preventSleepWhileRecording?: boolean; suppressNotificationsWhileRecording?: boolean; mediaKeysControlRecording?: boolean; updateChannel?: UpdateChannel; telemetryEnabled?: boolean; maxFps?: number; editorPreviewQuality?: EditorPreviewQuality; nativeEditorPreview?: boolean; frameInspection?: boolean; mainWindowPosition?: WindowPosition | null; cameraWindowPosition?: WindowPosition | null; cameraWindowPositionsByMonitorName?: { [key in string]: WindowPosition }; exportCompletionActions?: ExportCompletionActions; preExportCommand?: string | null; webhooks?: WebhookUrls; cursorTheme?: string | null; enabledEffects?: string[]; exportDirectoryMode?: ExportDirectoryMode; exportDirectory?: string | null; exportPriority?: ExportPriority }
+export type GifExportSettings = { fps: number; resolution_base: XY<number>; quality: GifQuality | null; max_width?: number | null; repeat?: number | null }
 export type GifQuality = { 
 /**
  * Encoding quality from 1-100 (default: 90)
@@ -480,29 +714,39 @@ export type GlideDirection = "none" | "left" | "right" | "up" | "down"
 export type GpuInfoDiag = { vendor: string; description: string; dedicatedVideoMemoryMb: number; adapterIndex: number; isSoftwareAdapter: boolean; isBasicRenderDriver: boolean; supportsHardwareEncoding: boolean }
 export type HapticPattern = "alignment" | "levelChange" | "generic"
 export type HapticPerformanceTime = "default" | "now" | "drawCompleted"
+export type HiddenCursorSegment = { start: number; end: number; fadeDuration?: number }
 export type Hotkey = { code: string; meta: boolean; ctrl: boolean; alt: boolean; shift: boolean }
-export type HotkeyAction = "startStudioRecording" | "startInstantRecording" | "stopRecording" | "restartRecording" | "togglePauseRecording" | "cycleRecordingMode" | "openRecordingPicker" | "openRecordingPickerDisplay" | "openRecordingPickerWindow" | "openRecordingPickerArea" | "screenshotDisplay" | "screenshotWindow" | "screenshotArea" | "other"
-export type HotkeysConfiguration = { show: boolean }
+export type HotkeyAction = "startStudioRecording" | "startInstantRecording" | "stopRecording" | "restartRecording" | "togglePauseRecording" | "cycleRecordingMode" | "openRecordingPicker" | "openRecordingPickerDisplay" | "openRecordingPickerWindow" | "openRecordingPickerArea" | "screenshotDisplay" | "screenshotWindow" | "screenshotArea" | "toggleTeleprompter" | "teleprompterFaster" | "teleprompterSlower" | "other"
+export type HotkeysConfiguration = { show?: boolean; style?: KeystrokeStyle; shortcutsOnly?: boolean; size?: number; position?: CameraPosition }
 export type HotkeysStore = { hotkeys: { [key in HotkeyAction]: Hotkey } }
+export type IdleRange = { recordingSegment: number; start: number; end: number }
 export type ImportStage = "Probing" | "Converting" | "Finalizing" | "Complete" | "Failed"
 export type IncompleteRecordingInfo = { projectPath: string; prettyName: string; segmentCount: number; estimatedDurationSecs: number }
 export type InstantRecordingMeta = { recording: boolean } | { error: string } | { fps: number; sample_rate: number | null }
 export type JsonValue<T> = [T]
+export type KeystrokeCapture = "off" | "masked" | "full"
+export type KeystrokeStyle = "shortcuts" | "typing"
+export type LayoutKeyframe = { startMs: number; padding: number; rounding: number; shadow: number }
+export type LicenseStatus = { type: "licensed"; expiresAt: number | null } | { type: "trial"; daysRemaining: number } | { type: "unlicensed" }
 export type LogicalBounds = { position: LogicalPosition; size: LogicalSize }
 export type LogicalPosition = { x: number; y: number }
 export type LogicalSize = { width: number; height: number }
-export type MainWindowRecordingStartBehaviour = "close" | "minimise"
+export type MagnifierConfiguration = { enabled?: boolean; amount?: number; size?: number; position?: CameraPosition }
+export type MainWindowRecordingStartBehaviour = "close" | "minimise" | "minimiseIfCaptured"
 export type MaskKeyframes = { position?: MaskVectorKeyframe[]; size?: MaskVectorKeyframe[]; intensity?: MaskScalarKeyframe[] }
 export type MaskKind = "sensitive" | "highlight"
 export type MaskScalarKeyframe = { time: number; value: number }
 export type MaskSegment = { start: number; end: number; track?: number; enabled?: boolean; maskType: MaskKind; center: XY<number>; size: XY<number>; feather?: number; opacity?: number; pixelation?: number; darkness?: number; fadeDuration?: number; keyframes?: MaskKeyframes }
 export type MaskType = "blur" | "pixelate"
 export type MaskVectorKeyframe = { time: number; x: number; y: number }
+export type MessageKey = "export.renderingFrame" | "export.cancelled" | "export.notEnoughSpace" | "export.decodeFailed" | "export.prepareOutputFailed" | "export.finalizeFailed" | "export.failed" | "export.renderDownscaled" | "export.requiresLicense" | "export.preExportCommandFailed" | "export.encoderUnavailable" | "export.outputNotWritable" | "export.emptyTimeline" | "export.resolutionCompatibility" | "export.gifFrameRate" | "export.paused" | "export.pausedByUser"
+export type MessageParam = number | string
 export type MicrophoneInfo = { name: string; sampleRate: number; channels: number }
 export type ModelIDType = string
-export type Mp4ExportSettings = { fps: number; resolution_base: XY<number>; compression: ExportCompression; custom_bpp: number | null; force_ffmpeg_decoder?: boolean }
+export type Mp4ExportSettings = { fps: number; resolution_base: XY<number>; compression: ExportCompression; custom_bpp: number | null; force_ffmpeg_decoder?: boolean; lead_in_ms?: number; lead_out_ms?: number; audio_stems?: boolean; timelapse?: TimelapseSettings | null; frame_interpolation?: boolean }
 export type MultipleSegment = { display: VideoMeta; camera?: VideoMeta | null; mic?: AudioMeta | null; system_audio?: AudioMeta | null; cursor?: string | null }
 export type MultipleSegments = { segments: MultipleSegment[]; cursors: Cursors; status?: StudioRecordingStatus | null }
+export type NativePreviewBounds = { x: number; y: number; width: number; height: number }
 export type NewNotification = { title: string; body: string; is_error: boolean }
 export type NewScreenshotAdded = { path: string }
 export type NewStudioRecordingAdded = { path: string }
@@ -518,9 +762,20 @@ export type PostDeletionBehaviour = "doNothing" | "reopenRecordingWindow"
 export type PostStudioRecordingBehaviour = "openEditor" | "showOverlay"
 export type Preset = { name: string; config: ProjectConfiguration }
 export type PresetsStore = { presets: Preset[]; default: number | null }
-export type ProjectConfiguration = { aspectRatio: AspectRatio | null; background: BackgroundConfiguration; camera: Camera; audio: AudioConfiguration; cursor: CursorConfiguration; hotkeys: HotkeysConfiguration; timeline: TimelineConfiguration | null; captions: CaptionsData | null; clips: ClipConfiguration[]; annotations: Annotation[]; screenMotionBlur?: number; screenMovementSpring?: ScreenMovementSpring }
+export type PreviewGuides = { safeAreaMargin: number | null; cropAspect: number | null; gridDivisions: number | null }
+export type PreviewProxy = { dir: string; manifest: PreviewProxyManifest }
+export type PreviewProxyManifest = { fps: number; width: number; height: number; frameCount: number; configHash: number }
+export type PreviewProxyReady = { projectPath: string }
+export type ProjectConfiguration = { aspectRatio: AspectRatio | null; background: BackgroundConfiguration; backgroundKeyframes?: BackgroundKeyframe[]; layoutKeyframes?: LayoutKeyframe[]; confetti?: ConfettiBurst[]; effectParams?: { [key in string]: number[] }; camera: Camera; audio: AudioConfiguration; cursor: CursorConfiguration; magnifier?: MagnifierConfiguration; hotkeys: HotkeysConfiguration; timeline: TimelineConfiguration | null; captions: CaptionsData | null; clips: ClipConfiguration[]; annotations: Annotation[]; screenMotionBlur?: number; screenMovementSpring?: ScreenMovementSpring; titleCard: CardConfiguration | null; outroCard: CardConfiguration | null; exportDirectory?: string | null }
 export type ProjectRecordingsMeta = { segments: SegmentRecordings[] }
-export type RecordingAction = "Started" | "InvalidAuthentication" | "UpgradeRequired"
+export type ProjectTemplate = { id: string; name: string; aspectRatio: AspectRatio | null; background: BackgroundConfiguration; camera: Camera; cursor: CursorConfiguration; screenMotionBlur: number; screenMovementSpring: ScreenMovementSpring; autoZoomOnClicks?: boolean; exportSettings?: ExportSettings | null }
+export type ReactionAnimation = "none" | "pop" | "slideUp"
+export type ReactionSegment = { start: number; end: number; emoji?: string; center?: XY<number>; scale?: number; animation?: ReactionAnimation }
+export type RecorderState = "idle" | "countdown" | "recording" | "paused" | "stopped" | "exporting"
+export type RecorderStateChanged = { state: RecorderState }
+export type RecordingAction = "Started" | "InvalidAuthentication" | "UpgradeRequired" | { InsufficientDiskSpace: { available_mb: number; required_mb: number } }
+export type RecordingAnalysis = { durationSecs: number; clickCount: number; clicksPerMinute: number; cursorTravelPx: number; gridSize: number; clickDensity: number[]; dwellSecs: number[]; clicks: XY<number>[]; zoom: ZoomCoverage }
+export type RecordingAnalysisResult = { report: RecordingAnalysis; reportPath: string; heatmapPath: string | null }
 export type RecordingDeleted = { path: string }
 export type RecordingEvent = { variant: "Countdown"; value: number } | { variant: "Started" } | { variant: "Stopped" } | { variant: "Paused" } | { variant: "Resumed" } | { variant: "Failed"; error: string } | { variant: "InputLost"; input: RecordingInputKind } | { variant: "InputRestored"; input: RecordingInputKind } | { variant: "Degraded"; reason: string } | { variant: "Recovered" }
 export type RecordingInputKind = "microphone" | "camera"
@@ -528,12 +783,14 @@ export type RecordingMeta = (StudioRecordingMeta | InstantRecordingMeta) & { pla
 export type RecordingMetaWithMetadata = ((StudioRecordingMeta | InstantRecordingMeta) & { platform?: Platform | null; pretty_name: string; sharing?: SharingMeta | null; upload?: UploadMeta | null }) & { mode: RecordingMode; status: StudioRecordingStatus }
 export type RecordingMode = "studio" | "instant" | "screenshot"
 export type RecordingOptionsChanged = null
+export type RecordingSearchResult = { path: string; prettyName: string; matches: ScreenTextSpan[] }
 export type RecordingSettingsStore = { target: ScreenCaptureTarget | null; micName: string | null; cameraId: DeviceOrModelID | null; mode: RecordingMode | null; systemAudio: boolean; organizationId: string | null }
 export type RecordingStarted = null
 export type RecordingStatus = "pending" | "recording"
 export type RecordingStopped = null
 export type RecordingTargetMode = "display" | "window" | "area" | "camera"
 export type RenderFrameEvent = { frame_number: number; fps: number; resolution_base: XY<number> }
+export type RenderTimings = { decodeUs: number; compositeUs: number; readbackUs: number; encodeUs: number; fps: number; etaSecs: number | null }
 export type RenderingStatus = { isUsingSoftwareRendering: boolean; isUsingBasicRenderDriver: boolean; hardwareEncodingAvailable: boolean; warningMessage: string | null }
 export type RequestOpenRecordingPicker = { target_mode: RecordingTargetMode | null }
 export type RequestOpenSettings = { page: string }
@@ -545,41 +802,67 @@ export type SceneMode = "default" | "cameraOnly" | "hideCamera"
 export type SceneSegment = { start: number; end: number; mode?: SceneMode }
 export type ScreenCaptureTarget = { variant: "window"; id: WindowId } | { variant: "display"; id: DisplayId } | { variant: "area"; screen: DisplayId; bounds: LogicalBounds } | { variant: "cameraOnly" }
 export type ScreenMovementSpring = { stiffness: number; damping: number; mass: number }
+export type ScreenTextIndex = { sampleIntervalSecs: number; spans: ScreenTextSpan[] }
+export type ScreenTextSpan = { start: number; end: number; text: string }
+export type ScreenshotSource = { type: "path"; path: string } | { type: "bytes"; data: number[] }
 export type SegmentRecordings = { display: Video; camera: Video | null; mic: Audio | null; system_audio: Audio | null }
+export type SensitiveContentScan = { findings: SensitiveFinding[]; masks: MaskSegment[] }
+export type SensitiveFinding = { kind: SensitiveKind; recordingSegment: number; start: number; end: number; preview: string; bounds: TextBounds }
+export type SensitiveKind = "email" | "apiKey" | "cardNumber"
 export type SerializedEditorInstance = { framesSocketUrl: string; recordingDuration: number; savedProjectConfig: ProjectConfiguration; recordings: ProjectRecordingsMeta; path: string }
 export type SerializedScreenshotEditorInstance = { framesSocketUrl: string; path: string; config: ProjectConfiguration | null; prettyName: string; imageWidth: number; imageHeight: number }
 export type SetCaptureAreaPending = boolean
 export type ShadowConfiguration = { size: number; opacity: number; blur: number }
 export type SharingMeta = { id: string; link: string }
-export type ShowCapWindow = "Setup" | { Main: { init_target_mode: RecordingTargetMode | null } } | { Settings: { page: string | null } } | { Editor: { project_path: string } } | "RecordingsOverlay" | { WindowCaptureOccluder: { screen_id: DisplayId } } | { TargetSelectOverlay: { display_id: DisplayId; target_mode: RecordingTargetMode | null } } | { CaptureArea: { screen_id: DisplayId } } | { Camera: { centered: boolean } } | { InProgressRecording: { countdown: number | null } } | "Upgrade" | "ModeSelect" | { ScreenshotEditor: { path: string } }
+export type ShowCapWindow = "Setup" | { Main: { init_target_mode: RecordingTargetMode | null } } | { Settings: { page: string | null } } | { Editor: { project_path: string } } | "RecordingsOverlay" | { WindowCaptureOccluder: { screen_id: DisplayId } } | { TargetSelectOverlay: { display_id: DisplayId; target_mode: RecordingTargetMode | null } } | { CaptureArea: { screen_id: DisplayId } } | { Camera: { centered: boolean } } | { InProgressRecording: { countdown: number | null } } | "Upgrade" | "ModeSelect" | { ScreenshotEditor: { path: string } } | "Teleprompter"
 export type SingleSegment = { display: VideoMeta; camera?: VideoMeta | null; audio?: AudioMeta | null; cursor?: string | null }
+export type SpringMassDamperSimulationConfig = { tension: number; mass: number; friction: number }
+export type SpringSample = { timeMs: number; target: number; position: number }
+export type SpringScenario = "step" | "ramp" | "jitter"
 export type StartRecordingInputs = { capture_target: ScreenCaptureTarget; capture_system_audio?: boolean; mode: RecordingMode; organization_id?: string | null }
 export type StereoMode = "stereo" | "monoL" | "monoR"
 export type StudioRecordingMeta = { segment: SingleSegment } | { inner: MultipleSegments }
 export type StudioRecordingStatus = { status: "InProgress" } | { status: "NeedsRemux" } | { status: "Failed"; error: string } | { status: "Complete" }
-export type SystemDiagnostics = { windowsVersion: WindowsVersionInfo | null; gpuInfo: GpuInfoDiag | null; allGpus: AllGpusInfo | null; renderingStatus: RenderingStatus; availableEncoders: string[]; graphicsCaptureSupported: boolean; d3D11VideoProcessorAvailable: boolean }
+export type SystemDiagnostics = { windowsVersion: WindowsVersionInfo | null; gpuInfo: GpuInfoDiag | null; allGpus: AllGpusInfo | null; displayAdapters: DisplayAdapterDiag[]; renderingStatus: RenderingStatus; availableEncoders: string[]; graphicsCaptureSupported: boolean; d3D11VideoProcessorAvailable: boolean }
 export type TargetUnderCursor = { display_id: DisplayId | null; window: WindowUnderCursor | null }
+export type TeleprompterRewind = null
+export type TeleprompterState = { script: string; speed: number; playing: boolean }
+export type TextBounds = { x: number; y: number; width: number; height: number }
 export type TextSegment = { start: number; end: number; track?: number; enabled?: boolean; content?: string; center?: XY<number>; size?: XY<number>; fontFamily?: string; fontSize?: number; fontWeight?: number; italic?: boolean; color?: string; fadeDuration?: number }
-export type TimelineConfiguration = { segments: TimelineSegment[]; zoomSegments: ZoomSegment[]; sceneSegments?: SceneSegment[]; maskSegments?: MaskSegment[]; textSegments?: TextSegment[] }
+export type TimelapseSettings = { speed: number; keepClicks?: boolean }
+export type TimelineClick = { time: number; position: XY<number> }
+export type TimelineConfiguration = { segments: TimelineSegment[]; zoomSegments: ZoomSegment[]; sceneSegments?: SceneSegment[]; maskSegments?: MaskSegment[]; textSegments?: TextSegment[]; reactionSegments?: ReactionSegment[]; cursorSegments?: HiddenCursorSegment[]; markers?: TimelineMarker[] }
+export type TimelineMarker = { time: number; label?: string }
 export type TimelineSegment = { recordingSegment?: number; timescale: number; start: number; end: number }
+export type UpdateChannel = "stable" | "beta"
+export type UpdateDownloadProgress = { progress: number }
 export type UploadMeta = { state: "MultipartUpload"; video_id: string; file_path: string; pre_created_video: VideoUploadInfo; recording_dir: string } | { state: "SinglePartUpload"; video_id: string; recording_dir: string; file_path: string; screenshot_path: string } | { state: "Failed"; error: string } | { state: "Complete" }
 export type UploadMode = { Initial: { pre_created_video: VideoUploadInfo | null } } | "Reupload"
 export type UploadProgress = { progress: number }
 export type UploadProgressEvent = { video_id: string; uploaded: string; total: string }
 export type UploadResult = { Success: string } | "NotAuthenticated" | "PlanCheckFailed" | "UpgradeRequired"
+export type UserMessage = { key: MessageKey; params: { [key in string]: MessageParam }; text: string }
 export type Video = { duration: number; width: number; height: number; fps: number; start_time: number }
 export type VideoImportProgress = { project_path: string; stage: ImportStage; progress: number; message: string }
 export type VideoMeta = { path: string; fps?: number; start_time?: number | null; device_id?: string | null }
 export type VideoRecordingMetadata = { duration: number; size: number }
 export type VideoUploadInfo = { id: string; link: string; config: S3UploadMeta }
+export type VoiceActivity = { start: number; end: number }
+export type WatermarkCorner = "topLeft" | "topRight" | "bottomLeft" | "bottomRight"
+export type WebhookUrls = { recordingStopped?: string | null; exportComplete?: string | null; exportFailed?: string | null }
 export type WindowExclusion = { bundleIdentifier?: string | null; ownerName?: string | null; windowTitle?: string | null }
+export type WindowFocusBounds = { x: number; y: number; width: number; height: number }
+export type WindowFocusEvent = { time_ms: number; app_name: string | null; title: string | null; bounds: WindowFocusBounds | null }
+export type WindowFullscreenChanged = { transition: FullscreenTransition }
 export type WindowId = string
 export type WindowPosition = { x: number; y: number; displayId?: DisplayId | null }
 export type WindowUnderCursor = { id: WindowId; app_name: string; bounds: LogicalBounds }
 export type WindowsVersionInfo = { major: number; minor: number; build: number; displayName: string; meetsRequirements: boolean; isWindows11: boolean }
 export type XY<T> = { x: T; y: T }
+export type ZoomCoverage = { segmentCount: number; zoomedSecs: number; coverage: number }
+export type ZoomEasing = "spring" | "instant" | { cubicBezier: { x1: number; y1: number; x2: number; y2: number } }
 export type ZoomMode = "auto" | { manual: { x: number; y: number } }
-export type ZoomSegment = { start: number; end: number; amount: number; mode: ZoomMode; glideDirection?: GlideDirection; glideSpeed?: number; instantAnimation?: boolean; edgeSnapRatio?: number }
+export type ZoomSegment = { start: number; end: number; amount: number; mode: ZoomMode; glideDirection?: GlideDirection; glideSpeed?: number; instantAnimation?: boolean; edgeSnapRatio?: number; zoomFromClick?: boolean; easing?: ZoomEasing }
 
 /** tauri-specta globals **/
 
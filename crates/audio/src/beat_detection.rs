@@ -0,0 +1,100 @@
+const FRAME_SECS: f64 = 0.02;
+const HOP_SECS: f64 = 0.01;
+const THRESHOLD_RADIUS: usize = 8;
+const THRESHOLD_MULTIPLIER: f64 = 1.5;
+const THRESHOLD_OFFSET: f64 = 0.1;
+const MIN_INTER_ONSET_SECS: f64 = 0.12;
+
+pub fn detect_beats(samples: &[f32], channels: u16, sample_rate: u32) -> Vec<f64> {
+    let channels = channels.max(1) as usize;
+    let frame_len = ((sample_rate as f64 * FRAME_SECS) as usize).max(1) * channels;
+    let hop_len = ((sample_rate as f64 * HOP_SECS) as usize).max(1) * channels;
+
+    if samples.len() < frame_len {
+        return vec![];
+    }
+
+    let energies = (0..=(samples.len() - frame_len) / hop_len)
+        .map(|i| {
+            let frame = &samples[i * hop_len..i * hop_len + frame_len];
+            let energy =
+                frame.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / frame.len() as f64;
+            (energy + 1e-10).ln()
+        })
+        .collect::<Vec<_>>();
+
+    let flux = std::iter::once(0.0)
+        .chain(energies.windows(2).map(|w| (w[1] - w[0]).max(0.0)))
+        .collect::<Vec<_>>();
+
+    let mut beats: Vec<f64> = vec![];
+    for i in 0..flux.len() {
+        let window =
+            &flux[i.saturating_sub(THRESHOLD_RADIUS)..(i + THRESHOLD_RADIUS + 1).min(flux.len())];
+        let mut sorted = window.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let threshold = sorted[sorted.len() / 2] * THRESHOLD_MULTIPLIER + THRESHOLD_OFFSET;
+
+        let is_peak = flux[i] > threshold
+            && flux[i] >= flux[i.saturating_sub(1)]
+            && flux[i] >= *flux.get(i + 1).unwrap_or(&0.0);
+        if !is_peak {
+            continue;
+        }
+
+        let time =
+            (i * hop_len + frame_len - hop_len) as f64 / (sample_rate as f64 * channels as f64);
+        if beats
+            .last()
+            .is_none_or(|last| time - last >= MIN_INTER_ONSET_SECS)
+        {
+            beats.push(time);
+        }
+    }
+
+    beats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 48_000;
+
+    fn drum_track(secs: f64, hits: &[f64]) -> Vec<f32> {
+        let mut samples = vec![0.0005; (secs * SAMPLE_RATE as f64) as usize];
+        for hit in hits {
+            let start = (hit * SAMPLE_RATE as f64) as usize;
+            for (i, sample) in samples[start..]
+                .iter_mut()
+                .take(SAMPLE_RATE as usize / 5)
+                .enumerate()
+            {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                *sample += (t * 120.0 * std::f32::consts::TAU).sin() * 0.8 * (-t * 25.0).exp();
+            }
+        }
+        samples
+    }
+
+    #[test]
+    fn finds_each_hit() {
+        let hits = [0.5, 1.0, 1.5, 2.0, 2.5];
+        let beats = detect_beats(&drum_track(3.0, &hits), 1, SAMPLE_RATE);
+
+        assert_eq!(beats.len(), hits.len(), "{beats:?}");
+        for (beat, hit) in beats.iter().zip(hits) {
+            assert!((beat - hit).abs() < 0.03, "{beat} isn't close to {hit}");
+        }
+    }
+
+    #[test]
+    fn steady_tones_have_no_beats() {
+        let samples = (0..SAMPLE_RATE * 2)
+            .map(|i| (i as f32 / SAMPLE_RATE as f32 * 440.0 * std::f32::consts::TAU).sin() * 0.5)
+            .collect::<Vec<_>>();
+
+        assert!(detect_beats(&samples[SAMPLE_RATE as usize / 2..], 1, SAMPLE_RATE).is_empty());
+        assert!(detect_beats(&[], 2, SAMPLE_RATE).is_empty());
+    }
+}
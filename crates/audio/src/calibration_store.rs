@@ -7,6 +7,8 @@ use std::path::Path;
 pub struct CalibrationStore {
     calibrations: HashMap<String, StoredCalibration>,
     #[serde(default)]
+    capture_offset_secs: Option<f64>,
+    #[serde(default)]
     version: u32,
 }
 
@@ -28,6 +30,7 @@ impl CalibrationStore {
     pub fn new() -> Self {
         Self {
             calibrations: HashMap::new(),
+            capture_offset_secs: None,
             version: Self::CURRENT_VERSION,
         }
     }
@@ -115,6 +118,14 @@ impl CalibrationStore {
     pub fn clear(&mut self) {
         self.calibrations.clear();
     }
+
+    pub fn capture_offset(&self) -> Option<f64> {
+        self.capture_offset_secs
+    }
+
+    pub fn set_capture_offset(&mut self, offset_secs: Option<f64>) {
+        self.capture_offset_secs = offset_secs;
+    }
 }
 
 pub fn apply_calibration_to_offset(
@@ -186,4 +197,17 @@ mod tests {
         store.update_calibration(&high_conf);
         assert!(store.get_offset("cam", "mic").is_some());
     }
+
+    #[test]
+    fn test_capture_offset_save_load() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut store = CalibrationStore::new();
+        assert!(store.capture_offset().is_none());
+        store.set_capture_offset(Some(0.035));
+        store.save(temp_dir.path()).unwrap();
+
+        let loaded = CalibrationStore::load(temp_dir.path());
+        assert_eq!(loaded.capture_offset(), Some(0.035));
+    }
 }
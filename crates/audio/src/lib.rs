@@ -1,14 +1,18 @@
 mod audio_data;
+mod beat_detection;
 mod calibration_store;
 mod latency;
 mod renderer;
 mod sync_analysis;
+mod voice_activity;
 
 pub use audio_data::*;
+pub use beat_detection::*;
 pub use calibration_store::*;
 pub use latency::*;
 pub use renderer::*;
 pub use sync_analysis::*;
+pub use voice_activity::*;
 
 pub trait FromSampleBytes: cpal::SizedSample + std::fmt::Debug + Send + 'static {
     const BYTE_SIZE: usize;
@@ -0,0 +1,132 @@
+const FRAME_SECS: f64 = 0.02;
+const NOISE_MARGIN_DB: f64 = 10.0;
+const MIN_SPEECH_DB: f64 = -50.0;
+const MAX_PAUSE_SECS: f64 = 0.3;
+const MIN_SPEECH_SECS: f64 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoiceSegment {
+    pub start: f64,
+    pub end: f64,
+}
+
+pub fn detect_voice_activity(
+    samples: &[f32],
+    channels: u16,
+    sample_rate: u32,
+) -> Vec<VoiceSegment> {
+    let channels = channels.max(1) as usize;
+    let frame_len = ((sample_rate as f64 * FRAME_SECS) as usize).max(1) * channels;
+
+    let levels = samples
+        .chunks(frame_len)
+        .map(|frame| {
+            let power = frame.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / frame.len() as f64;
+            10.0 * power.max(1e-10).log10()
+        })
+        .collect::<Vec<_>>();
+
+    if levels.is_empty() {
+        return vec![];
+    }
+
+    let mut sorted = levels.clone();
+    sorted.sort_by(f64::total_cmp);
+    let noise_floor = sorted[sorted.len() / 10];
+    let threshold = (noise_floor + NOISE_MARGIN_DB).max(MIN_SPEECH_DB);
+
+    let mut segments: Vec<VoiceSegment> = vec![];
+    for (i, level) in levels.iter().enumerate() {
+        if *level < threshold {
+            continue;
+        }
+
+        let start = i as f64 * FRAME_SECS;
+        let end = start + FRAME_SECS;
+        match segments.last_mut() {
+            Some(last) if start - last.end < MAX_PAUSE_SECS => last.end = end,
+            _ => segments.push(VoiceSegment { start, end }),
+        }
+    }
+
+    let duration = samples.len() as f64 / (sample_rate as f64 * channels as f64);
+    segments.retain_mut(|segment| {
+        segment.end = segment.end.min(duration);
+        segment.end - segment.start >= MIN_SPEECH_SECS
+    });
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 48_000;
+
+    fn track(sections: &[(f64, f32)]) -> Vec<f32> {
+        let mut samples = vec![];
+        for (secs, amplitude) in sections {
+            for _ in 0..(*secs * SAMPLE_RATE as f64) as usize {
+                let t = samples.len() as f32 / SAMPLE_RATE as f32;
+                let noise = if samples.len() % 2 == 0 {
+                    0.001
+                } else {
+                    -0.001
+                };
+                samples.push((t * 220.0 * std::f32::consts::TAU).sin() * amplitude + noise);
+            }
+        }
+        samples
+    }
+
+    fn assert_close(segment: VoiceSegment, start: f64, end: f64) {
+        assert!(
+            (segment.start - start).abs() < 0.05 && (segment.end - end).abs() < 0.05,
+            "{segment:?} isn't close to {start}..{end}"
+        );
+    }
+
+    #[test]
+    fn finds_speech_between_silence() {
+        let samples = track(&[(1.0, 0.0), (1.5, 0.3), (1.0, 0.0), (0.5, 0.2), (1.0, 0.0)]);
+        let segments = detect_voice_activity(&samples, 1, SAMPLE_RATE);
+
+        assert_eq!(segments.len(), 2);
+        assert_close(segments[0], 1.0, 2.5);
+        assert_close(segments[1], 3.5, 4.0);
+    }
+
+    #[test]
+    fn bridges_short_pauses_and_drops_clicks() {
+        let samples = track(&[
+            (1.0, 0.0),
+            (0.5, 0.3),
+            (0.1, 0.0),
+            (0.5, 0.3),
+            (1.0, 0.0),
+            (0.04, 0.5),
+            (1.0, 0.0),
+        ]);
+        let segments = detect_voice_activity(&samples, 1, SAMPLE_RATE);
+
+        assert_eq!(segments.len(), 1);
+        assert_close(segments[0], 1.0, 2.1);
+    }
+
+    #[test]
+    fn silent_tracks_have_no_speech() {
+        assert!(detect_voice_activity(&track(&[(2.0, 0.0)]), 1, SAMPLE_RATE).is_empty());
+        assert!(detect_voice_activity(&[], 2, SAMPLE_RATE).is_empty());
+    }
+
+    #[test]
+    fn handles_interleaved_channels() {
+        let mono = track(&[(1.0, 0.0), (1.0, 0.3), (1.0, 0.0)]);
+        let stereo = mono.iter().flat_map(|s| [*s, *s]).collect::<Vec<_>>();
+        let segments = detect_voice_activity(&stereo, 2, SAMPLE_RATE);
+
+        assert_eq!(segments.len(), 1);
+        assert_close(segments[0], 1.0, 2.0);
+    }
+}
@@ -539,6 +539,11 @@ async fn benchmark_export(recording_path: &Path) -> Result<ExportMetrics> {
         compression: ExportCompression::Social,
         custom_bpp: None,
         force_ffmpeg_decoder: false,
+        lead_in_ms: 0,
+        lead_out_ms: 0,
+        audio_stems: false,
+        timelapse: None,
+        frame_interpolation: false,
     };
 
     let total_frames = exporter_base.total_frames(settings.fps);
@@ -19,6 +19,14 @@ impl RawCursorPosition {
         }
     }
 
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
     pub fn relative_to_display(&self, display: Display) -> Option<RelativeCursorPosition> {
         RelativeCursorPosition::from_raw(*self, display)
     }
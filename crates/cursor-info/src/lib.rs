@@ -34,6 +34,37 @@ impl CursorShape {
             CursorShape::Windows(cursor) => cursor.resolve(),
         }
     }
+
+    pub fn role(&self) -> Option<CursorRole> {
+        match self {
+            CursorShape::MacOS(cursor) => cursor.role(),
+            CursorShape::Windows(cursor) => cursor.role(),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Type,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum CursorRole {
+    Arrow,
+    Pointer,
+    IBeam,
+    Hand,
+}
+
+impl CursorRole {
+    pub const ALL: [CursorRole; 4] = [Self::Arrow, Self::Pointer, Self::IBeam, Self::Hand];
+
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::Pointer => "pointer",
+            Self::IBeam => "ibeam",
+            Self::Hand => "hand",
+        }
+    }
 }
 
 impl fmt::Display for CursorShape {
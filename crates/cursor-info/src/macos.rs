@@ -1,6 +1,6 @@
 use strum::{EnumString, IntoStaticStr};
 
-use crate::{CursorShape, ResolvedCursor};
+use crate::{CursorRole, CursorShape, ResolvedCursor};
 
 /// macOS Cursors
 /// https://developer.apple.com/documentation/appkit/nscursor
@@ -224,6 +224,21 @@ impl CursorShapeMacOS {
         })
     }
 
+    pub fn role(&self) -> Option<CursorRole> {
+        Some(match self {
+            Self::Arrow | Self::TahoeArrow => CursorRole::Arrow,
+            Self::PointingHand | Self::TahoePointingHand => CursorRole::Pointer,
+            Self::IBeam
+            | Self::IBeamVerticalForVerticalLayout
+            | Self::TahoeIBeam
+            | Self::TahoeIBeamVerticalForVerticalLayout => CursorRole::IBeam,
+            Self::OpenHand | Self::ClosedHand | Self::TahoeOpenHand | Self::TahoeClosedHand => {
+                CursorRole::Hand
+            }
+            _ => return None,
+        })
+    }
+
     /// Derive the cursor type from a hash
     /// macOS doesn't allow comparing `NSCursor` instances directly so we hash the image data.
     /// macOS cursor are also resolution-independent so this works.
@@ -1,6 +1,6 @@
 use strum::{EnumString, IntoStaticStr};
 
-use crate::{CursorShape, ResolvedCursor};
+use crate::{CursorRole, CursorShape, ResolvedCursor};
 
 // https://learn.microsoft.com/en-us/windows/win32/menurc/about-cursors
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, IntoStaticStr)]
@@ -151,6 +151,15 @@ impl CursorShapeWindows {
             _ => return None,
         })
     }
+
+    pub fn role(&self) -> Option<CursorRole> {
+        Some(match self {
+            Self::Arrow => CursorRole::Arrow,
+            Self::Hand => CursorRole::Pointer,
+            Self::IBeam => CursorRole::IBeam,
+            _ => return None,
+        })
+    }
 }
 
 #[cfg(target_os = "windows")]
@@ -6,9 +6,16 @@ use std::path::PathBuf;
 async fn main() {
     let path: PathBuf = std::env::args().collect::<Vec<_>>().swap_remove(1).into();
 
-    let editor = EditorInstance::new(path, |_s| {}, Box::new(|_| {}), None)
-        .await
-        .unwrap();
+    let editor = EditorInstance::new(
+        path,
+        |_s| {},
+        Box::new(|_| {}),
+        None,
+        vec![],
+        Default::default(),
+    )
+    .await
+    .unwrap();
 
     editor.start_playback(30, XY::new(1920, 1080)).await;
 
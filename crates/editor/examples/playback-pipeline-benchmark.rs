@@ -150,6 +150,9 @@ async fn load_recording(
                 scene_segments: Vec::new(),
                 mask_segments: Vec::new(),
                 text_segments: Vec::new(),
+                reaction_segments: Vec::new(),
+                cursor_segments: Vec::new(),
+                markers: Vec::new(),
             });
         }
     }
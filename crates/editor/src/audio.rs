@@ -158,15 +158,16 @@ impl AudioRenderer {
         project: &ProjectConfiguration,
     ) -> Option<FFAudio> {
         self.render_frame_raw(requested_samples, project)
-            .map(move |(samples, data)| {
-                let mut raw_frame =
-                    FFAudio::new(AudioData::SAMPLE_FORMAT, samples, ChannelLayout::STEREO);
-                raw_frame.set_rate(AudioData::SAMPLE_RATE);
-                raw_frame.data_mut(0)[0..data.len() * f32::BYTE_SIZE]
-                    .copy_from_slice(unsafe { cast_f32_slice_to_bytes(&data) });
-
-                raw_frame
-            })
+            .map(move |(samples, data)| Self::frame_from_samples(samples, &data))
+    }
+
+    pub fn frame_from_samples(samples: usize, data: &[f32]) -> FFAudio {
+        let mut raw_frame = FFAudio::new(AudioData::SAMPLE_FORMAT, samples, ChannelLayout::STEREO);
+        raw_frame.set_rate(AudioData::SAMPLE_RATE);
+        raw_frame.data_mut(0)[0..data.len() * f32::BYTE_SIZE]
+            .copy_from_slice(unsafe { cast_f32_slice_to_bytes(data) });
+
+        raw_frame
     }
 
     pub fn render_frame_raw(
@@ -3,10 +3,10 @@ use std::time::Instant;
 
 use cap_project::{CursorEvents, RecordingMeta, StudioRecordingMeta};
 use cap_rendering::{
-    DecodedSegmentFrames, FrameRenderer, Nv12RenderedFrame, ProjectRecordingsMeta, ProjectUniforms,
-    RenderVideoConstants, RenderedFrame, RendererLayers,
+    DecodedSegmentFrames, FrameRenderer, Nv12RenderedFrame, PreviewGuides, PreviewSurface,
+    ProjectRecordingsMeta, ProjectUniforms, RenderVideoConstants, RenderedFrame, RendererLayers,
 };
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, watch};
 
 #[allow(clippy::large_enum_variant)]
 pub enum RendererMessage {
@@ -16,6 +16,13 @@ pub enum RendererMessage {
         finished: oneshot::Sender<()>,
         cursor: Arc<CursorEvents>,
     },
+    SetSurface {
+        surface: Option<PreviewSurface>,
+        finished: oneshot::Sender<()>,
+    },
+    ResizeSurface {
+        size: (u32, u32),
+    },
     Stop {
         finished: oneshot::Sender<()>,
     },
@@ -33,6 +40,8 @@ pub struct Renderer {
     frame_cb: Box<dyn FnMut(EditorFrameOutput) + Send>,
     render_constants: Arc<RenderVideoConstants>,
     layers_rx: RendererLayersReceiver,
+    guides: watch::Receiver<PreviewGuides>,
+    surface: Option<PreviewSurface>,
     #[allow(unused)]
     total_frames: u32,
 }
@@ -67,6 +76,7 @@ impl Renderer {
         recording_meta: &RecordingMeta,
         meta: &StudioRecordingMeta,
         layers_rx: RendererLayersReceiver,
+        guides: watch::Receiver<PreviewGuides>,
     ) -> Result<RendererHandle, String> {
         let recordings = Arc::new(ProjectRecordingsMeta::new(
             &recording_meta.project_path,
@@ -90,6 +100,8 @@ impl Renderer {
             frame_cb,
             render_constants,
             layers_rx,
+            guides,
+            surface: None,
             total_frames,
         };
 
@@ -138,6 +150,17 @@ impl Renderer {
                         finished,
                         cursor,
                     }),
+                    Some(RendererMessage::SetSurface { surface, finished }) => {
+                        self.surface = surface;
+                        let _ = finished.send(());
+                        None
+                    }
+                    Some(RendererMessage::ResizeSurface { size }) => {
+                        if let Some(surface) = &mut self.surface {
+                            surface.resize(&self.render_constants.device, size);
+                        }
+                        None
+                    }
                     Some(RendererMessage::Stop { finished }) => {
                         let _ = finished.send(());
                         return;
@@ -167,6 +190,15 @@ impl Renderer {
                             cursor,
                         };
                     }
+                    RendererMessage::SetSurface { surface, finished } => {
+                        self.surface = surface;
+                        let _ = finished.send(());
+                    }
+                    RendererMessage::ResizeSurface { size } => {
+                        if let Some(surface) = &mut self.surface {
+                            surface.resize(&self.render_constants.device, size);
+                        }
+                    }
                     RendererMessage::Stop { finished } => {
                         let _ = current.finished.send(());
                         let _ = finished.send(());
@@ -178,6 +210,26 @@ impl Renderer {
                 }
             }
 
+            current.uniforms.guides = Some(*self.guides.borrow());
+
+            if let Some(surface) = &self.surface {
+                if let Err(e) = frame_renderer
+                    .render_to_surface(
+                        current.segment_frames,
+                        current.uniforms,
+                        &current.cursor,
+                        &mut layers,
+                        surface,
+                    )
+                    .await
+                {
+                    tracing::error!(error = %e, "Failed to render frame to preview surface");
+                }
+
+                let _ = current.finished.send(());
+                continue;
+            }
+
             match frame_renderer
                 .render_immediate_nv12(
                     current.segment_frames,
@@ -217,6 +269,26 @@ impl RendererHandle {
         });
     }
 
+    pub async fn set_surface(&self, surface: Option<PreviewSurface>) {
+        let (tx, rx) = oneshot::channel();
+        if self
+            .tx
+            .send(RendererMessage::SetSurface {
+                surface,
+                finished: tx,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!("Failed to send preview surface to renderer");
+        }
+        let _ = rx.await;
+    }
+
+    pub async fn resize_surface(&self, size: (u32, u32)) {
+        let _ = self.tx.send(RendererMessage::ResizeSurface { size }).await;
+    }
+
     pub async fn stop(&self) {
         let (tx, rx) = oneshot::channel();
         if self
@@ -7,8 +7,9 @@ use cap_project::{
     TimelineSegment, XY,
 };
 use cap_rendering::{
-    ProjectRecordingsMeta, ProjectUniforms, RecordingSegmentDecoders, RenderVideoConstants,
-    SegmentVideoPaths, SharedWgpuDevice, Video, ZoomFocusInterpolator, get_duration,
+    BrandAssets, EffectPlugin, PreviewGuides, PreviewSurface, ProjectRecordingsMeta,
+    ProjectUniforms, RecordingSegmentDecoders, RenderVideoConstants, SegmentVideoPaths,
+    SharedWgpuDevice, Video, ZoomFocusInterpolator, get_duration,
     spring_mass_damper::SpringMassDamperSimulationConfig,
 };
 use std::{
@@ -79,6 +80,7 @@ pub struct EditorInstance {
     pub state: Arc<Mutex<EditorState>>,
     on_state_change: Box<dyn Fn(&EditorState) + Send + Sync + 'static>,
     pub preview_tx: watch::Sender<Option<PreviewFrameInstruction>>,
+    preview_guides: watch::Sender<PreviewGuides>,
     pub project_config: (
         watch::Sender<ProjectConfiguration>,
         watch::Receiver<ProjectConfiguration>,
@@ -96,6 +98,8 @@ impl EditorInstance {
         on_state_change: impl Fn(&EditorState) + Send + Sync + 'static,
         frame_cb: Box<dyn FnMut(editor::EditorFrameOutput) + Send>,
         shared_device: Option<SharedWgpuDevice>,
+        effects: Vec<Arc<EffectPlugin>>,
+        brand: BrandAssets,
     ) -> Result<Arc<Self>, String> {
         if !project_path.exists() {
             return Err(format!("Video path {} not found!", project_path.display()));
@@ -201,6 +205,9 @@ impl EditorInstance {
                     scene_segments: Vec::new(),
                     mask_segments: Vec::new(),
                     text_segments: Vec::new(),
+                    reaction_segments: Vec::new(),
+                    cursor_segments: Vec::new(),
+                    markers: Vec::new(),
                 });
 
                 if let Err(e) = project.write(&recording_meta.project_path) {
@@ -258,7 +265,7 @@ impl EditorInstance {
                 (**meta).clone(),
             )
             .map_err(|e| format!("Failed to create render constants: {e}"))?;
-            Arc::new(rc)
+            Arc::new(rc.with_effects(effects).with_brand(brand))
         } else {
             let rc = RenderVideoConstants::new(
                 &recordings.segments,
@@ -267,19 +274,22 @@ impl EditorInstance {
             )
             .await
             .map_err(|e| format!("Failed to create render constants: {e}"))?;
-            Arc::new(rc)
+            Arc::new(rc.with_effects(effects).with_brand(brand))
         };
 
         let segments = create_segments(&recording_meta, meta.as_ref(), false).await?;
 
         let layers_rx = editor::start_renderer_layers_creation(&render_constants);
 
+        let (preview_guides_tx, preview_guides_rx) = watch::channel(PreviewGuides::default());
+
         let renderer = Arc::new(editor::Renderer::spawn(
             render_constants.clone(),
             frame_cb,
             &recording_meta,
             meta,
             layers_rx,
+            preview_guides_rx,
         )?);
 
         let (preview_tx, preview_rx) = watch::channel(None);
@@ -297,6 +307,7 @@ impl EditorInstance {
             })),
             on_state_change: Box::new(on_state_change),
             preview_tx,
+            preview_guides: preview_guides_tx,
             project_config: watch::channel(project),
             segment_medias: Arc::new(segments),
             meta: recording_meta,
@@ -316,6 +327,21 @@ impl EditorInstance {
         &self.meta
     }
 
+    pub fn set_preview_guides(&self, guides: PreviewGuides) {
+        self.preview_guides.send_replace(guides);
+        self.preview_tx.send_modify(|_| {});
+    }
+
+    pub async fn set_preview_surface(&self, surface: Option<PreviewSurface>) {
+        self.renderer.set_surface(surface).await;
+        self.preview_tx.send_modify(|_| {});
+    }
+
+    pub async fn resize_preview_surface(&self, size: (u32, u32)) {
+        self.renderer.resize_surface(size).await;
+        self.preview_tx.send_modify(|_| {});
+    }
+
     pub async fn dispose(&self) {
         let mut state = self.state.lock().await;
 
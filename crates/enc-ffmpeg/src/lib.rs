@@ -9,6 +9,7 @@ pub use video::*;
 mod mux;
 pub use mux::*;
 
+pub mod log;
 pub mod remux;
 pub mod segmented_audio {
     pub use crate::mux::segmented_audio::*;
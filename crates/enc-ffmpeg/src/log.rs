@@ -0,0 +1,154 @@
+use std::{
+    cell::RefCell,
+    ffi::{CStr, c_char, c_int, c_void},
+    marker::PhantomData,
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, SyncSender, TrySendError},
+    },
+};
+
+use ffmpeg::ffi;
+
+const LINE_SIZE: usize = 1024;
+const QUEUE_CAPACITY: usize = 512;
+
+static SENDER: OnceLock<SyncSender<LogLine>> = OnceLock::new();
+static DROPPED: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static CURRENT_LOG: RefCell<Option<EncoderLog>> = const { RefCell::new(None) };
+}
+
+struct LogLine {
+    level: c_int,
+    message: String,
+}
+
+pub fn install_log_forwarder() {
+    let mut installed = false;
+
+    SENDER.get_or_init(|| {
+        let (tx, rx) = mpsc::sync_channel::<LogLine>(QUEUE_CAPACITY);
+
+        std::thread::Builder::new()
+            .name("ffmpeg-log".to_string())
+            .spawn(move || {
+                while let Ok(line) = rx.recv() {
+                    emit(line);
+                }
+            })
+            .expect("failed to spawn ffmpeg log thread");
+
+        installed = true;
+        tx
+    });
+
+    if installed {
+        unsafe { ffi::av_log_set_callback(Some(log_callback)) };
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct EncoderLog(Arc<Mutex<Vec<String>>>);
+
+impl EncoderLog {
+    pub fn enter(&self) -> EncoderLogGuard {
+        let previous = CURRENT_LOG.with(|current| current.replace(Some(self.clone())));
+        EncoderLogGuard {
+            previous,
+            _thread: PhantomData,
+        }
+    }
+
+    pub fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+#[must_use]
+pub struct EncoderLogGuard {
+    previous: Option<EncoderLog>,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for EncoderLogGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        let _ = CURRENT_LOG.try_with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+pub fn dropped_count() -> u64 {
+    DROPPED.load(Ordering::Relaxed)
+}
+
+fn emit(line: LogLine) {
+    let message = line.message.trim_end();
+    if message.is_empty() {
+        return;
+    }
+
+    match line.level {
+        l if l <= ffi::AV_LOG_ERROR => tracing::error!(target: "ffmpeg", "{message}"),
+        l if l <= ffi::AV_LOG_WARNING => tracing::warn!(target: "ffmpeg", "{message}"),
+        l if l <= ffi::AV_LOG_INFO => tracing::info!(target: "ffmpeg", "{message}"),
+        l if l <= ffi::AV_LOG_VERBOSE => tracing::debug!(target: "ffmpeg", "{message}"),
+        _ => tracing::trace!(target: "ffmpeg", "{message}"),
+    }
+}
+
+unsafe extern "C" fn log_callback(
+    avcl: *mut c_void,
+    level: c_int,
+    fmt: *const c_char,
+    vl: ffi::va_list,
+) {
+    if level > unsafe { ffi::av_log_get_level() } {
+        return;
+    }
+
+    let Some(sender) = SENDER.get() else {
+        return;
+    };
+
+    let mut buf = [0 as c_char; LINE_SIZE];
+    let mut print_prefix: c_int = 1;
+
+    let written = unsafe {
+        ffi::av_log_format_line2(
+            avcl,
+            level,
+            fmt,
+            vl,
+            buf.as_mut_ptr(),
+            LINE_SIZE as c_int,
+            &mut print_prefix,
+        )
+    };
+    if written < 0 {
+        return;
+    }
+
+    let message = unsafe { CStr::from_ptr(buf.as_ptr()) }
+        .to_string_lossy()
+        .into_owned();
+
+    if level <= ffi::AV_LOG_WARNING {
+        let _ = CURRENT_LOG.try_with(|current| {
+            if let Some(log) = &*current.borrow()
+                && let Ok(mut warnings) = log.0.lock()
+            {
+                warnings.push(message.trim_end().to_string());
+            }
+        });
+    }
+
+    match sender.try_send(LogLine { level, message }) {
+        Ok(()) | Err(TrySendError::Disconnected(_)) => {}
+        Err(TrySendError::Full(_)) => {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
@@ -260,7 +260,11 @@ impl H264EncoderBuilder {
             );
             None
         } else if needs_pixel_conversion || needs_scaling {
-            let flags = if needs_scaling {
+            let upscaling =
+                output_width > input_config.width || output_height > input_config.height;
+            let flags = if upscaling {
+                ffmpeg::software::scaling::flag::Flags::LANCZOS
+            } else if needs_scaling {
                 ffmpeg::software::scaling::flag::Flags::BICUBIC
             } else {
                 ffmpeg::software::scaling::flag::Flags::FAST_BILINEAR
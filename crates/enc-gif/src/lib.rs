@@ -67,6 +67,17 @@ impl GifEncoderWrapper {
         height: u32,
         fps: u32,
         quality: GifQuality,
+    ) -> Result<Self, GifEncodingError> {
+        Self::new_with_repeat(path, width, height, fps, quality, None)
+    }
+
+    pub fn new_with_repeat<P: AsRef<Path>>(
+        path: P,
+        width: u32,
+        height: u32,
+        fps: u32,
+        quality: GifQuality,
+        repeat: Option<u16>,
     ) -> Result<Self, GifEncodingError> {
         if fps == 0 || width == 0 || height == 0 {
             return Err(GifEncodingError::InvalidFrameData);
@@ -76,7 +87,7 @@ impl GifEncoderWrapper {
             height: Some(height),
             quality: quality.quality,
             fast: quality.fast,
-            repeat: Repeat::Infinite,
+            repeat: repeat.map_or(Repeat::Infinite, Repeat::Finite),
         };
         let (collector, writer) =
             gifski::new(settings).map_err(|e| GifEncodingError::Gifski(e.to_string()))?;
@@ -428,6 +428,11 @@ async fn run_mp4_export(
         compression,
         custom_bpp: None,
         force_ffmpeg_decoder: false,
+        lead_in_ms: 0,
+        lead_out_ms: 0,
+        audio_stems: false,
+        timelapse: None,
+        frame_interpolation: false,
     };
 
     let total_frames = exporter_base.total_frames(fps);
@@ -481,6 +486,8 @@ async fn run_gif_export(
         fps,
         resolution_base: XY::new(width, height),
         quality: None,
+        max_width: None,
+        repeat: None,
     };
 
     let total_frames = exporter_base.total_frames(fps);
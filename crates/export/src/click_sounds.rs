@@ -0,0 +1,196 @@
+use std::f32::consts::TAU;
+
+use cap_editor::{AudioRenderer, SegmentMedia};
+use cap_project::{ClickSound, CursorClickEvent, ProjectConfiguration};
+
+pub struct ClickSoundMixer {
+    sample: Vec<f32>,
+    gain: f32,
+    positions: Vec<u64>,
+}
+
+impl ClickSoundMixer {
+    pub fn new(project: &ProjectConfiguration, segments: &[SegmentMedia]) -> Option<Self> {
+        let clicks = segments
+            .iter()
+            .map(|segment| segment.cursor.clicks.as_slice())
+            .collect::<Vec<_>>();
+        Self::from_clicks(project, &clicks)
+    }
+
+    fn from_clicks(project: &ProjectConfiguration, clicks: &[&[CursorClickEvent]]) -> Option<Self> {
+        let config = &project.audio.click_sounds;
+        if !config.enabled || config.volume <= 0.0 {
+            return None;
+        }
+
+        let sample_rate = f64::from(AudioRenderer::SAMPLE_RATE);
+        let mut positions = clicks
+            .iter()
+            .enumerate()
+            .flat_map(|(clip_index, clicks)| {
+                clicks
+                    .iter()
+                    .filter(|click| click.down)
+                    .flat_map(move |click| {
+                        project.timeline_times(clip_index as u32, click.time_ms / 1000.0)
+                    })
+            })
+            .map(|time| (time * sample_rate) as u64)
+            .collect::<Vec<_>>();
+
+        if positions.is_empty() {
+            return None;
+        }
+        positions.sort_unstable();
+
+        Some(Self {
+            sample: synthesize(config.sound, AudioRenderer::SAMPLE_RATE),
+            gain: config.volume.min(1.0),
+            positions,
+        })
+    }
+
+    pub fn mix(&self, start: u64, data: &mut [f32]) {
+        let channels = usize::from(AudioRenderer::CHANNELS);
+        let frames = (data.len() / channels) as u64;
+        let sample_len = self.sample.len() as u64;
+
+        let first = self.positions.partition_point(|&p| p + sample_len <= start);
+
+        for &position in self.positions[first..]
+            .iter()
+            .take_while(|&&p| p < start + frames)
+        {
+            let from = start.max(position);
+            let to = (start + frames).min(position + sample_len);
+
+            for i in from..to {
+                let value = self.sample[(i - position) as usize] * self.gain;
+                let frame = (i - start) as usize * channels;
+                for out in &mut data[frame..frame + channels] {
+                    *out = (*out + value).clamp(-1.0, 1.0);
+                }
+            }
+        }
+    }
+}
+
+fn synthesize(sound: ClickSound, sample_rate: u32) -> Vec<f32> {
+    let (duration, decay, tone_hz, tone_mix) = match sound {
+        ClickSound::Soft => (0.03, 0.004, 1800.0, 0.6),
+        ClickSound::Crisp => (0.02, 0.0015, 3800.0, 0.35),
+    };
+
+    let rate = sample_rate as f32;
+    let len = (duration * rate) as usize;
+    let attack = 0.0005 * rate;
+
+    let mut seed = 0x2545_f491_u32;
+    let mut noise = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as f32 / u32::MAX as f32 * 2.0 - 1.0
+    };
+
+    let mut filtered = 0.0;
+    let mut sample = (0..len)
+        .map(|i| {
+            let t = i as f32 / rate;
+            let envelope = (i as f32 / attack).min(1.0) * (-t / decay).exp();
+
+            filtered += (noise() - filtered) * 0.5;
+            let tone = (TAU * tone_hz * t).sin();
+
+            envelope * (tone * tone_mix + filtered * (1.0 - tone_mix))
+        })
+        .collect::<Vec<_>>();
+
+    let peak = sample.iter().fold(0.0_f32, |peak, v| peak.max(v.abs()));
+    if peak > 0.0 {
+        for v in &mut sample {
+            *v *= 0.8 / peak;
+        }
+    }
+
+    sample
+}
+
+#[cfg(test)]
+mod tests {
+    use cap_project::{TimelineConfiguration, TimelineSegment};
+
+    use super::*;
+
+    fn mixer(positions: Vec<u64>) -> ClickSoundMixer {
+        ClickSoundMixer {
+            sample: vec![0.5; 4],
+            gain: 1.0,
+            positions,
+        }
+    }
+
+    #[test]
+    fn mixes_clicks_spanning_buffer_boundaries() {
+        let mixer = mixer(vec![2, 10]);
+
+        let mut first = vec![0.0; 4 * 2];
+        mixer.mix(0, &mut first);
+        assert_eq!(first, [0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 0.5]);
+
+        let mut second = vec![0.0; 4 * 2];
+        mixer.mix(4, &mut second);
+        assert_eq!(second, [0.5, 0.5, 0.5, 0.5, 0.0, 0.0, 0.0, 0.0]);
+
+        let mut third = vec![0.0; 4 * 2];
+        mixer.mix(8, &mut third);
+        assert_eq!(third, [0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn sounds_down_clicks_where_they_play_on_the_timeline() {
+        let mut project = ProjectConfiguration {
+            timeline: Some(TimelineConfiguration {
+                segments: vec![TimelineSegment {
+                    recording_clip: 0,
+                    timescale: 1.0,
+                    start: 2.0,
+                    end: 4.0,
+                }],
+                zoom_segments: vec![],
+                scene_segments: vec![],
+                mask_segments: vec![],
+                text_segments: vec![],
+                reaction_segments: vec![],
+                cursor_segments: vec![],
+                markers: vec![],
+            }),
+            ..Default::default()
+        };
+        project.audio.click_sounds.enabled = true;
+
+        let click = |time_ms: f64, down: bool| CursorClickEvent {
+            active_modifiers: vec![],
+            cursor_num: 0,
+            cursor_id: "0".to_string(),
+            time_ms,
+            down,
+        };
+        let clicks = [
+            click(1000.0, true),
+            click(2500.0, true),
+            click(2700.0, false),
+        ];
+        let mixer = ClickSoundMixer::from_clicks(&project, &[&clicks]).unwrap();
+
+        let rate = AudioRenderer::SAMPLE_RATE as usize;
+        let channels = usize::from(AudioRenderer::CHANNELS);
+        let mut data = vec![0.0; rate * channels];
+        mixer.mix(0, &mut data);
+
+        let first_sound = data.iter().position(|v| *v != 0.0).unwrap() / channels;
+        assert!((rate / 2..rate / 2 + 8).contains(&first_sound));
+        assert!(data[rate * 6 / 10 * channels..].iter().all(|v| *v == 0.0));
+    }
+}
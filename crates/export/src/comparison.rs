@@ -0,0 +1,430 @@
+use std::{path::PathBuf, time::Duration};
+
+use cap_enc_ffmpeg::{h264::H264Encoder, mp4::*};
+use cap_media_info::{RawVideoFormat, VideoInfo};
+use cap_project::XY;
+use cap_rendering::{Nv12RenderedFrame, RenderSegment};
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tokio::sync::mpsc;
+use tracing::info;
+
+use crate::{ExporterBase, mp4::ensure_nv12_data};
+
+const BLACK_Y: u8 = 16;
+const WHITE_Y: u8 = 235;
+const NEUTRAL_UV: u8 = 128;
+const DIVIDER_WIDTH: u32 = 4;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ComparisonLayout {
+    SideBySide,
+    Wipe { from: f32, to: f32 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonExportSettings {
+    pub fps: u32,
+    pub resolution_base: XY<u32>,
+    pub layout: ComparisonLayout,
+    pub compression_bpp: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Panel {
+    x: u32,
+    width: u32,
+}
+
+impl ComparisonExportSettings {
+    pub fn output_size(&self) -> (u32, u32) {
+        let width = match self.layout {
+            ComparisonLayout::SideBySide => self.resolution_base.x & !3,
+            ComparisonLayout::Wipe { .. } => self.resolution_base.x & !1,
+        };
+        (width.max(4), (self.resolution_base.y & !1).max(2))
+    }
+
+    fn panels(&self) -> [Panel; 2] {
+        let (width, _) = self.output_size();
+        match self.layout {
+            ComparisonLayout::SideBySide => [
+                Panel {
+                    x: 0,
+                    width: width / 2,
+                },
+                Panel {
+                    x: width / 2,
+                    width: width / 2,
+                },
+            ],
+            ComparisonLayout::Wipe { .. } => [Panel { x: 0, width }; 2],
+        }
+    }
+
+    fn split_at(&self, progress: f64) -> Option<u32> {
+        let ComparisonLayout::Wipe { from, to } = self.layout else {
+            return None;
+        };
+        let (width, _) = self.output_size();
+        let position = f64::from(from) + f64::from(to - from) * progress.clamp(0.0, 1.0);
+        Some((position.clamp(0.0, 1.0) * f64::from(width)) as u32 & !1)
+    }
+
+    pub fn duration(before: &ExporterBase, after: &ExporterBase) -> f64 {
+        before.duration().max(after.duration())
+    }
+
+    pub async fn export(
+        self,
+        before: ExporterBase,
+        after: ExporterBase,
+        output_path: PathBuf,
+        mut on_progress: impl FnMut(u32) -> bool + Send + 'static,
+    ) -> Result<PathBuf, String> {
+        let fps = self.fps;
+        let (width, height) = self.output_size();
+        let total_frames = before.total_frames(fps).max(after.total_frames(fps));
+        let [before_panel, after_panel] = self.panels();
+
+        info!(
+            layout = ?self.layout,
+            width,
+            height,
+            total_frames,
+            "Exporting comparison"
+        );
+
+        let timings = before.timings.clone();
+        let encoder_log = before.encoder_log.clone();
+        let (before_tx, mut before_rx) = mpsc::channel(8);
+        let (after_tx, mut after_rx) = mpsc::channel(8);
+
+        let encoder_thread = tokio::task::spawn_blocking(move || {
+            let _encoder_log = encoder_log.enter();
+            let mut video_info = VideoInfo::from_raw(RawVideoFormat::Nv12, width, height, fps);
+            video_info.time_base = ffmpeg::Rational::new(1, fps as i32);
+
+            let mut encoder = MP4File::init(
+                "output",
+                output_path.clone(),
+                |o| {
+                    H264Encoder::builder(video_info)
+                        .with_bpp(self.compression_bpp)
+                        .with_export_priority()
+                        .with_export_settings()
+                        .build(o)
+                },
+                |_| None,
+            )
+            .map_err(|v| v.to_string())?;
+
+            let mut frame = ffmpeg::frame::Video::new(ffmpeg::format::Pixel::NV12, width, height);
+            let mut converted_frame = None;
+            let mut composite = vec![0u8; (width * height * 3 / 2) as usize];
+            let mut last_before: Option<PanelFrame> = None;
+            let mut last_after: Option<PanelFrame> = None;
+
+            for frame_number in 0..total_frames {
+                if let Some(next) = before_rx.blocking_recv() {
+                    last_before = Some(next);
+                }
+                if let Some(next) = after_rx.blocking_recv() {
+                    last_after = Some(next);
+                }
+                if last_before.is_none() && last_after.is_none() {
+                    break;
+                }
+
+                timings.record_frame(Default::default());
+                if !(on_progress)(frame_number) {
+                    return Err("Export cancelled".to_string());
+                }
+
+                let start = std::time::Instant::now();
+
+                let progress = f64::from(frame_number) / f64::from(total_frames.max(2) - 1);
+                let split = self.split_at(progress);
+                clear(&mut composite, width, height);
+                if let Some(before) = &last_before {
+                    let clip = (0, split.unwrap_or(width));
+                    blit(&mut composite, (width, height), before, before_panel, clip);
+                }
+                if let Some(after) = &last_after {
+                    let clip = (split.unwrap_or(0), width);
+                    blit(&mut composite, (width, height), after, after_panel, clip);
+                }
+                if let Some(split) = split {
+                    draw_divider(&mut composite, (width, height), split);
+                }
+
+                fill_frame(&mut frame, &composite, width, height);
+                frame.set_pts(Some(i64::from(frame_number)));
+                encoder
+                    .queue_video_frame_reusable(&mut frame, &mut converted_frame, Duration::MAX)
+                    .map_err(|err| err.to_string())?;
+                timings.record_encode(start.elapsed());
+            }
+
+            let res = encoder
+                .finish()
+                .map_err(|e| format!("Failed to finish encoding: {e}"))?;
+            if let Err(e) = res.video_finish {
+                return Err(format!("Video encoding failed: {e}"));
+            }
+
+            Ok(output_path)
+        })
+        .then(|r| async { r.map_err(|e| e.to_string()).and_then(|v| v) });
+
+        let (output_path, _, _) = tokio::try_join!(
+            encoder_thread,
+            render_panel(&before, fps, XY::new(before_panel.width, height), before_tx),
+            render_panel(&after, fps, XY::new(after_panel.width, height), after_tx),
+        )?;
+
+        Ok(output_path)
+    }
+}
+
+async fn render_panel(
+    base: &ExporterBase,
+    fps: u32,
+    resolution_base: XY<u32>,
+    tx: mpsc::Sender<PanelFrame>,
+) -> Result<(), String> {
+    let (frames_tx, mut frames_rx) = mpsc::channel::<(Nv12RenderedFrame, u32)>(4);
+    let forward = async move {
+        while let Some((frame, _)) = frames_rx.recv().await {
+            let frame = PanelFrame {
+                width: frame.width,
+                height: frame.height,
+                data: ensure_nv12_data(frame),
+            };
+            if tx.send(frame).await.is_err() {
+                break;
+            }
+        }
+        Ok::<_, String>(())
+    };
+
+    let render = cap_rendering::render_video_to_channel_nv12(
+        &base.render_constants,
+        &base.project_config,
+        frames_tx,
+        &base.recording_meta,
+        &base.studio_meta,
+        base.segments
+            .iter()
+            .map(|s| RenderSegment {
+                cursor: s.cursor.clone(),
+                decoders: s.decoders.clone(),
+            })
+            .collect(),
+        fps,
+        resolution_base,
+        &base.recordings,
+    )
+    .then(|v| async { v.map_err(|e| e.to_string()) });
+
+    tokio::try_join!(render, forward).map(|_| ())
+}
+
+struct PanelFrame {
+    data: std::sync::Arc<Vec<u8>>,
+    width: u32,
+    height: u32,
+}
+
+fn clear(composite: &mut [u8], width: u32, height: u32) {
+    let (y, uv) = composite.split_at_mut((width * height) as usize);
+    y.fill(BLACK_Y);
+    uv.fill(NEUTRAL_UV);
+}
+
+fn blit(
+    composite: &mut [u8],
+    (width, height): (u32, u32),
+    frame: &PanelFrame,
+    panel: Panel,
+    (clip_start, clip_end): (u32, u32),
+) {
+    let origin_x =
+        (i64::from(panel.x) + (i64::from(panel.width) - i64::from(frame.width)) / 2) & !1;
+    let origin_y = ((i64::from(height) - i64::from(frame.height)) / 2) & !1;
+
+    let start_x = i64::from(panel.x.max(clip_start)).max(origin_x);
+    let end_x = i64::from((panel.x + panel.width).min(clip_end).min(width))
+        .min(origin_x + i64::from(frame.width));
+    let start_y = origin_y.max(0);
+    let end_y = (origin_y + i64::from(frame.height)).min(i64::from(height));
+    if start_x >= end_x || start_y >= end_y {
+        return;
+    }
+
+    let columns = (end_x - start_x) as usize;
+    let src_x = (start_x - origin_x) as usize;
+    let (stride, dst_stride) = (frame.width as usize, width as usize);
+
+    for y in start_y..end_y {
+        let src = (y - origin_y) as usize * stride + src_x;
+        let dst = y as usize * dst_stride + start_x as usize;
+        if let Some(src) = frame.data.get(src..src + columns) {
+            composite[dst..dst + columns].copy_from_slice(src);
+        }
+    }
+
+    let src_uv = stride * frame.height as usize;
+    let dst_uv = dst_stride * height as usize;
+    let columns = columns & !1;
+    for y in (start_y / 2)..(end_y + 1) / 2 {
+        let src = src_uv + (y - origin_y / 2) as usize * stride + src_x;
+        let dst = dst_uv + y as usize * dst_stride + start_x as usize;
+        if let Some(src) = frame.data.get(src..src + columns) {
+            composite[dst..dst + columns].copy_from_slice(src);
+        }
+    }
+}
+
+fn draw_divider(composite: &mut [u8], (width, height): (u32, u32), split: u32) {
+    let start = split.saturating_sub(DIVIDER_WIDTH / 2).min(width) as usize;
+    let end = (split + DIVIDER_WIDTH / 2).min(width) as usize;
+    let (y_plane, uv_plane) = composite.split_at_mut((width * height) as usize);
+    for row in y_plane.chunks_exact_mut(width as usize) {
+        row[start..end].fill(WHITE_Y);
+    }
+    for row in uv_plane.chunks_exact_mut(width as usize) {
+        row[start..end].fill(NEUTRAL_UV);
+    }
+}
+
+fn fill_frame(frame: &mut ffmpeg::frame::Video, composite: &[u8], width: u32, height: u32) {
+    let (width, height) = (width as usize, height as usize);
+    let (y_plane, uv_plane) = composite.split_at(width * height);
+
+    for (plane, data, rows) in [(0, y_plane, height), (1, uv_plane, height / 2)] {
+        let stride = frame.stride(plane);
+        let dst = frame.data_mut(plane);
+        for row in 0..rows {
+            dst[row * stride..row * stride + width]
+                .copy_from_slice(&data[row * width..(row + 1) * width]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, y: u8, uv: u8) -> PanelFrame {
+        let mut data = vec![y; (width * height) as usize];
+        data.resize((width * height * 3 / 2) as usize, uv);
+        PanelFrame {
+            data: data.into(),
+            width,
+            height,
+        }
+    }
+
+    fn settings(layout: ComparisonLayout) -> ComparisonExportSettings {
+        ComparisonExportSettings {
+            fps: 30,
+            resolution_base: XY::new(16, 8),
+            layout,
+            compression_bpp: 0.1,
+        }
+    }
+
+    #[test]
+    fn side_by_side_centers_each_project_in_its_half() {
+        let settings = settings(ComparisonLayout::SideBySide);
+        let (width, height) = settings.output_size();
+        let [left, right] = settings.panels();
+        let mut composite = vec![0; (width * height * 3 / 2) as usize];
+        clear(&mut composite, width, height);
+
+        blit(
+            &mut composite,
+            (width, height),
+            &solid(8, 8, 50, 60),
+            left,
+            (0, width),
+        );
+        blit(
+            &mut composite,
+            (width, height),
+            &solid(4, 4, 200, 90),
+            right,
+            (0, width),
+        );
+
+        let row = |y: usize| &composite[y * 16..(y + 1) * 16];
+        assert_eq!(row(0)[..8], [50; 8]);
+        assert_eq!(row(0)[8..], [BLACK_Y; 8]);
+        assert_eq!(
+            row(2)[8..],
+            [BLACK_Y, BLACK_Y, 200, 200, 200, 200, BLACK_Y, BLACK_Y]
+        );
+        assert_eq!(row(6)[8..], [BLACK_Y; 8]);
+
+        let uv_row = |y: usize| &composite[128 + y * 16..128 + (y + 1) * 16];
+        assert_eq!(uv_row(1)[..8], [60; 8]);
+        assert_eq!(
+            uv_row(1)[8..],
+            [
+                NEUTRAL_UV, NEUTRAL_UV, 90, 90, 90, 90, NEUTRAL_UV, NEUTRAL_UV
+            ]
+        );
+    }
+
+    #[test]
+    fn wipe_moves_across_the_export() {
+        let wipe = settings(ComparisonLayout::Wipe {
+            from: 0.25,
+            to: 0.75,
+        });
+        assert_eq!(wipe.split_at(0.0), Some(4));
+        assert_eq!(wipe.split_at(0.5), Some(8));
+        assert_eq!(wipe.split_at(1.0), Some(12));
+        assert_eq!(wipe.split_at(2.0), Some(12));
+        assert_eq!(settings(ComparisonLayout::SideBySide).split_at(0.5), None);
+
+        let (width, height) = wipe.output_size();
+        let [before, after] = wipe.panels();
+        let mut composite = vec![0; (width * height * 3 / 2) as usize];
+        blit(
+            &mut composite,
+            (width, height),
+            &solid(16, 8, 10, 128),
+            before,
+            (0, 6),
+        );
+        blit(
+            &mut composite,
+            (width, height),
+            &solid(16, 8, 20, 128),
+            after,
+            (6, 16),
+        );
+
+        assert_eq!(
+            composite[..16],
+            [
+                10, 10, 10, 10, 10, 10, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20
+            ]
+        );
+    }
+
+    #[test]
+    fn side_by_side_halves_stay_even() {
+        let settings = ComparisonExportSettings {
+            resolution_base: XY::new(1918, 1081),
+            ..settings(ComparisonLayout::SideBySide)
+        };
+        assert_eq!(settings.output_size(), (1916, 1080));
+        assert_eq!(settings.panels()[1], Panel { x: 958, width: 958 });
+    }
+}
@@ -1,14 +1,14 @@
 use cap_project::XY;
 use cap_rendering::{ProjectUniforms, RenderSegment, RenderedFrame};
 use futures::FutureExt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::path::PathBuf;
 use tracing::trace;
 
 use crate::{ExportError, ExporterBase};
 
-#[derive(Deserialize, Clone, Copy, Debug, Type)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Type)]
 pub struct GifQuality {
     /// Encoding quality from 1-100 (default: 90)
     pub quality: Option<u8>,
@@ -16,11 +16,15 @@ pub struct GifQuality {
     pub fast: Option<bool>,
 }
 
-#[derive(Deserialize, Clone, Copy, Debug, Type)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Type)]
 pub struct GifExportSettings {
     pub fps: u32,
     pub resolution_base: XY<u32>,
     pub quality: Option<GifQuality>,
+    #[serde(default)]
+    pub max_width: Option<u32>,
+    #[serde(default)]
+    pub repeat: Option<u16>,
 }
 
 impl Default for GifExportSettings {
@@ -29,11 +33,34 @@ impl Default for GifExportSettings {
             fps: 30,
             resolution_base: XY { x: 1920, y: 1080 },
             quality: None,
+            max_width: None,
+            repeat: None,
         }
     }
 }
 
 impl GifExportSettings {
+    pub fn render_resolution_base(&self) -> XY<u32> {
+        match self.max_width {
+            Some(max_width) if max_width > 0 && self.resolution_base.x > max_width => XY {
+                x: max_width,
+                y: (self.resolution_base.y as u64 * max_width as u64
+                    / self.resolution_base.x as u64)
+                    .max(1) as u32,
+            },
+            _ => self.resolution_base,
+        }
+    }
+
+    pub fn encoder_quality(&self) -> cap_enc_gif::GifQuality {
+        self.quality
+            .map(|q| cap_enc_gif::GifQuality {
+                quality: q.quality.unwrap_or(90),
+                fast: q.fast.unwrap_or(false),
+            })
+            .unwrap_or_default()
+    }
+
     pub async fn export(
         self,
         base: ExporterBase,
@@ -44,11 +71,12 @@ impl GifExportSettings {
         let (tx_image_data, mut video_rx) = tokio::sync::mpsc::channel::<(RenderedFrame, u32)>(4);
 
         let fps = self.fps;
+        let resolution_base = self.render_resolution_base();
 
         let output_size = ProjectUniforms::get_output_size(
             &base.render_constants.options,
             &base.project_config,
-            self.resolution_base,
+            resolution_base,
         );
 
         // Ensure the output path has .gif extension
@@ -64,32 +92,27 @@ impl GifExportSettings {
             gif_output_path.display()
         );
 
-        // Create GIF encoder with quality settings
-        let quality = self
-            .quality
-            .map(|q| cap_enc_gif::GifQuality {
-                quality: q.quality.unwrap_or(90),
-                fast: q.fast.unwrap_or(false),
-            })
-            .unwrap_or_default();
-
-        let mut gif_encoder = cap_enc_gif::GifEncoderWrapper::new_with_quality(
+        let mut gif_encoder = cap_enc_gif::GifEncoderWrapper::new_with_repeat(
             &gif_output_path,
             output_size.0,
             output_size.1,
             fps,
-            quality,
+            self.encoder_quality(),
+            self.repeat,
         )
         .map_err(|e| format!("Failed to create GIF encoder: {e}"))?;
 
+        let timings = base.timings.clone();
         let encoder_thread = tokio::task::spawn_blocking(move || {
             let mut frame_count = 0;
 
             while let Some((frame, _frame_number)) = video_rx.blocking_recv() {
+                timings.record_frame(Default::default());
                 if !(on_progress)(frame_count) {
                     return Err(ExportError::Other("Export cancelled".to_string()));
                 }
 
+                let start = std::time::Instant::now();
                 if let Err(e) =
                     gif_encoder.add_frame(&frame.data, frame.padded_bytes_per_row as usize)
                 {
@@ -97,6 +120,7 @@ impl GifExportSettings {
                         "Failed to add frame to GIF: {e}"
                     )));
                 }
+                timings.record_encode(start.elapsed());
 
                 frame_count += 1;
             }
@@ -126,7 +150,7 @@ impl GifExportSettings {
                 })
                 .collect(),
             fps,
-            self.resolution_base,
+            resolution_base,
             &base.recordings,
         )
         .then(|f| async { f.map_err(|v| v.to_string()) });
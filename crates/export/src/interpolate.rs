@@ -0,0 +1,92 @@
+use tracing::debug;
+
+pub struct FrameInterpolator {
+    source: ffmpeg::filter::Context,
+    sink: ffmpeg::filter::Context,
+    filtered: ffmpeg::frame::Video,
+    _minterpolate: ffmpeg::filter::Context,
+    _format: ffmpeg::filter::Context,
+    _graph: ffmpeg::filter::Graph,
+}
+
+impl FrameInterpolator {
+    pub fn new(size: (u32, u32), from_fps: u32, to_fps: u32) -> Result<Self, ffmpeg::Error> {
+        let mut graph = ffmpeg::filter::Graph::new();
+
+        let mut source = graph.add(
+            &ffmpeg::filter::find("buffer").ok_or(ffmpeg::Error::FilterNotFound)?,
+            "in",
+            &format!(
+                "video_size={}x{}:pix_fmt=nv12:time_base=1/{from_fps}:frame_rate={from_fps}/1:pixel_aspect=1/1",
+                size.0, size.1
+            ),
+        )?;
+
+        let mut minterpolate = graph.add(
+            &ffmpeg::filter::find("minterpolate").ok_or(ffmpeg::Error::FilterNotFound)?,
+            "minterpolate",
+            &format!("fps={to_fps}:mi_mode=mci:mc_mode=aobmc:me_mode=bidir:vsbmc=1"),
+        )?;
+
+        let mut format = graph.add(
+            &ffmpeg::filter::find("format").ok_or(ffmpeg::Error::FilterNotFound)?,
+            "format",
+            "pix_fmts=nv12",
+        )?;
+
+        let mut sink = graph.add(
+            &ffmpeg::filter::find("buffersink").ok_or(ffmpeg::Error::FilterNotFound)?,
+            "out",
+            "",
+        )?;
+
+        source.link(0, &mut minterpolate, 0);
+        minterpolate.link(0, &mut format, 0);
+        format.link(0, &mut sink, 0);
+
+        graph.validate()?;
+        debug!(?size, from_fps, to_fps, "Set up frame interpolation");
+
+        Ok(Self {
+            source,
+            sink,
+            filtered: ffmpeg::frame::Video::empty(),
+            _minterpolate: minterpolate,
+            _format: format,
+            _graph: graph,
+        })
+    }
+
+    pub fn push(
+        &mut self,
+        frame: &ffmpeg::frame::Video,
+        emit: impl FnMut(&mut ffmpeg::frame::Video) -> Result<(), String>,
+    ) -> Result<(), String> {
+        self.source
+            .source()
+            .add(frame)
+            .map_err(|e| format!("Failed to queue frame for interpolation: {e}"))?;
+        self.drain(emit)
+    }
+
+    pub fn finish(
+        &mut self,
+        emit: impl FnMut(&mut ffmpeg::frame::Video) -> Result<(), String>,
+    ) -> Result<(), String> {
+        self.source
+            .source()
+            .flush()
+            .map_err(|e| format!("Failed to flush frame interpolation: {e}"))?;
+        self.drain(emit)
+    }
+
+    fn drain(
+        &mut self,
+        mut emit: impl FnMut(&mut ffmpeg::frame::Video) -> Result<(), String>,
+    ) -> Result<(), String> {
+        while self.sink.sink().frame(&mut self.filtered).is_ok() {
+            emit(&mut self.filtered)?;
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,22 @@
+mod click_sounds;
+pub mod comparison;
 pub mod gif;
+mod interpolate;
 pub mod mp4;
+pub mod multi;
+pub mod preview_proxy;
+mod stems;
+pub mod timings;
 
 use cap_editor::SegmentMedia;
-use cap_project::{ProjectConfiguration, RecordingMeta, StudioRecordingMeta};
-use cap_rendering::{ProjectRecordingsMeta, RenderVideoConstants};
+use cap_project::{
+    ClipConfiguration, ProjectConfiguration, RecordingMeta, StudioRecordingMeta,
+    TimelineConfiguration, TimelineSegment, XY, timelapse::TimelapseSettings,
+};
+use cap_rendering::{
+    BrandAssets, CursorTheme, EffectPlugin, ProjectRecordingsMeta, ProjectUniforms,
+    RenderVideoConstants,
+};
 use std::{path::PathBuf, sync::Arc};
 
 #[derive(thiserror::Error, Debug)]
@@ -52,7 +65,13 @@ pub struct ExporterBuilder {
     project_path: PathBuf,
     config: Option<ProjectConfiguration>,
     output_path: Option<PathBuf>,
+    output_target: Option<PathBuf>,
     force_ffmpeg_decoder: bool,
+    cursor_theme: Option<Arc<CursorTheme>>,
+    effects: Vec<Arc<EffectPlugin>>,
+    brand: BrandAssets,
+    av_sync_offset: Option<f32>,
+    timelapse: Option<TimelapseSettings>,
 }
 
 impl ExporterBuilder {
@@ -66,19 +85,52 @@ impl ExporterBuilder {
         self
     }
 
+    pub fn with_output_target(mut self, output_target: PathBuf) -> Self {
+        self.output_target = Some(output_target);
+        self
+    }
+
     pub fn with_force_ffmpeg_decoder(mut self, force: bool) -> Self {
         self.force_ffmpeg_decoder = force;
         self
     }
 
+    pub fn with_cursor_theme(mut self, theme: Option<Arc<CursorTheme>>) -> Self {
+        self.cursor_theme = theme;
+        self
+    }
+
+    pub fn with_effects(mut self, effects: Vec<Arc<EffectPlugin>>) -> Self {
+        self.effects = effects;
+        self
+    }
+
+    pub fn with_brand(mut self, brand: BrandAssets) -> Self {
+        self.brand = brand;
+        self
+    }
+
+    pub fn with_av_sync_offset(mut self, offset_secs: Option<f32>) -> Self {
+        self.av_sync_offset = offset_secs;
+        self
+    }
+
+    pub fn with_timelapse(mut self, timelapse: Option<TimelapseSettings>) -> Self {
+        self.timelapse = timelapse;
+        self
+    }
+
     pub async fn build(self) -> Result<ExporterBase, ExporterBuildError> {
         type Error = ExporterBuildError;
 
-        let project_config = serde_json::from_reader(
-            std::fs::File::open(self.project_path.join("project-config.json"))
-                .map_err(|v| Error::ConfigLoad(v.into()))?,
-        )
-        .map_err(|v| Error::ConfigLoad(v.into()))?;
+        let mut project_config: ProjectConfiguration = match self.config {
+            Some(config) => config,
+            None => serde_json::from_reader(
+                std::fs::File::open(self.project_path.join("project-config.json"))
+                    .map_err(|v| Error::ConfigLoad(v.into()))?,
+            )
+            .map_err(|v| Error::ConfigLoad(v.into()))?,
+        };
 
         let recording_meta =
             RecordingMeta::load_for_project(&self.project_path).map_err(Error::MetaLoad)?;
@@ -91,6 +143,10 @@ impl ExporterBuilder {
                 .map_err(Error::RecordingsMeta)?,
         );
 
+        if let Some(offset_secs) = self.av_sync_offset {
+            apply_av_sync_offset(&mut project_config, recordings.segments.len(), offset_secs);
+        }
+
         let render_constants = Arc::new(
             RenderVideoConstants::new(
                 &recordings.segments,
@@ -98,7 +154,10 @@ impl ExporterBuilder {
                 studio_meta.clone(),
             )
             .await
-            .map_err(Error::RendererSetup)?,
+            .map_err(Error::RendererSetup)?
+            .with_cursor_theme(self.cursor_theme)
+            .with_effects(self.effects)
+            .with_brand(self.brand),
         );
 
         let segments =
@@ -106,6 +165,30 @@ impl ExporterBuilder {
                 .await
                 .map_err(Error::MediaLoad)?;
 
+        if let Some(timelapse) = self.timelapse {
+            let timeline = project_config
+                .timeline
+                .get_or_insert_with(|| whole_recording_timeline(&recordings));
+            let clicks = segments
+                .iter()
+                .enumerate()
+                .flat_map(|(clip, segment)| {
+                    segment
+                        .cursor
+                        .clicks
+                        .iter()
+                        .filter(|click| click.down)
+                        .map(move |click| (clip as u32, click.time_ms / 1000.0))
+                })
+                .collect::<Vec<_>>();
+            tracing::info!(
+                speed = timelapse.speed,
+                edited_secs = timeline.duration(),
+                "Exporting a timelapse"
+            );
+            project_config = project_config.timelapse(timelapse, &clicks);
+        }
+
         let output_path = self
             .output_path
             .unwrap_or_else(|| recording_meta.output_path());
@@ -116,6 +199,7 @@ impl ExporterBuilder {
         }
 
         Ok(ExporterBase {
+            output_target: self.output_target.unwrap_or_else(|| output_path.clone()),
             output_path,
             studio_meta: studio_meta.clone(),
             recordings,
@@ -124,6 +208,8 @@ impl ExporterBuilder {
             recording_meta,
             project_config,
             project_path: self.project_path,
+            timings: Default::default(),
+            encoder_log: Default::default(),
         })
     }
 }
@@ -137,18 +223,61 @@ pub struct ExporterBase {
     render_constants: Arc<RenderVideoConstants>,
     segments: Vec<SegmentMedia>,
     output_path: PathBuf,
+    output_target: PathBuf,
+    timings: timings::ExportTimings,
+    encoder_log: cap_enc_ffmpeg::log::EncoderLog,
 }
 
 impl ExporterBase {
     pub fn total_frames(&self, fps: u32) -> u32 {
-        let duration = cap_rendering::get_duration(
+        (fps as f64 * self.duration()).ceil() as u32
+    }
+
+    pub fn duration(&self) -> f64 {
+        cap_rendering::get_duration(
             &self.recordings,
             &self.recording_meta,
             &self.studio_meta,
             &self.project_config,
+        )
+    }
+
+    pub fn render_size(&self, resolution_base: XY<u32>) -> RenderSize {
+        let output_size = |resolution_base| {
+            ProjectUniforms::get_output_size(
+                &self.render_constants.options,
+                &self.project_config,
+                resolution_base,
+            )
+        };
+        let output = output_size(resolution_base);
+
+        let scale = self.render_constants.render_budget().scale_for(output);
+        if scale >= 1.0 {
+            return RenderSize {
+                output,
+                render: output,
+                resolution_base,
+            };
+        }
+
+        let resolution_base = XY::new(
+            (f64::from(resolution_base.x) * scale) as u32,
+            (f64::from(resolution_base.y) * scale) as u32,
         );
+        RenderSize {
+            output,
+            render: output_size(resolution_base),
+            resolution_base,
+        }
+    }
+
+    pub fn timings(&self) -> timings::ExportTimings {
+        self.timings.clone()
+    }
 
-        (fps as f64 * duration).ceil() as u32
+    pub fn encoder_log(&self) -> cap_enc_ffmpeg::log::EncoderLog {
+        self.encoder_log.clone()
     }
 
     pub fn builder(project_path: PathBuf) -> ExporterBuilder {
@@ -156,7 +285,102 @@ impl ExporterBase {
             project_path,
             config: None,
             output_path: None,
+            output_target: None,
             force_ffmpeg_decoder: false,
+            cursor_theme: None,
+            effects: vec![],
+            brand: BrandAssets::default(),
+            av_sync_offset: None,
+            timelapse: None,
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderSize {
+    pub output: (u32, u32),
+    pub render: (u32, u32),
+    pub resolution_base: XY<u32>,
+}
+
+impl RenderSize {
+    pub fn is_reduced(&self) -> bool {
+        self.render != self.output
+    }
+}
+
+fn apply_av_sync_offset(project: &mut ProjectConfiguration, clip_count: usize, offset_secs: f32) {
+    for index in 0..clip_count as u32 {
+        if !project.clips.iter().any(|c| c.index == index) {
+            project.clips.push(ClipConfiguration {
+                index,
+                offsets: Default::default(),
+            });
+        }
+    }
+
+    for clip in &mut project.clips {
+        clip.offsets.mic += offset_secs;
+        clip.offsets.system_audio += offset_secs;
+    }
+}
+
+fn whole_recording_timeline(recordings: &ProjectRecordingsMeta) -> TimelineConfiguration {
+    TimelineConfiguration {
+        segments: recordings
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(clip, recording)| TimelineSegment {
+                recording_clip: clip as u32,
+                timescale: 1.0,
+                start: 0.0,
+                end: recording.display.duration,
+            })
+            .collect(),
+        zoom_segments: vec![],
+        scene_segments: vec![],
+        mask_segments: vec![],
+        text_segments: vec![],
+        reaction_segments: vec![],
+        cursor_segments: vec![],
+        markers: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cap_project::ClipOffsets;
+
+    use super::*;
+
+    #[test]
+    fn av_sync_offset_applies_to_every_clip() {
+        let mut project = ProjectConfiguration {
+            clips: vec![ClipConfiguration {
+                index: 1,
+                offsets: ClipOffsets {
+                    camera: 0.5,
+                    mic: 0.1,
+                    system_audio: 0.0,
+                },
+            }],
+            ..Default::default()
+        };
+
+        apply_av_sync_offset(&mut project, 2, 0.04);
+
+        let offsets = |index| {
+            project
+                .clips
+                .iter()
+                .find(|c| c.index == index)
+                .map(|c| c.offsets)
+                .unwrap()
+        };
+        assert_eq!(offsets(0).mic, 0.04);
+        assert_eq!(offsets(0).system_audio, 0.04);
+        assert_eq!(offsets(1).camera, 0.5);
+        assert!((offsets(1).mic - 0.14).abs() < 1e-6);
+    }
+}
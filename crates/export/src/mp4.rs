@@ -1,17 +1,20 @@
-use crate::ExporterBase;
+use crate::{
+    ExporterBase, RenderSize, click_sounds::ClickSoundMixer, interpolate::FrameInterpolator,
+    stems::AudioStems,
+};
 use cap_editor::{AudioRenderer, get_audio_segments};
 use cap_enc_ffmpeg::{AudioEncoder, aac::AACEncoder, h264::H264Encoder, mp4::*};
 use cap_media_info::{RawVideoFormat, VideoInfo};
-use cap_project::XY;
-use cap_rendering::{Nv12RenderedFrame, ProjectUniforms, RenderSegment};
+use cap_project::{CardConfiguration, ProjectConfiguration, XY, timelapse::TimelapseSettings};
+use cap_rendering::{Nv12RenderedFrame, RenderSegment};
 use futures::FutureExt;
 use image::ImageBuffer;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 use tracing::{info, trace, warn};
 
-#[derive(Deserialize, Type, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, Type, Clone, Copy, Debug)]
 pub enum ExportCompression {
     Maximum,
     Social,
@@ -30,7 +33,7 @@ impl ExportCompression {
     }
 }
 
-#[derive(Deserialize, Type, Clone, Copy, Debug)]
+#[derive(Serialize, Deserialize, Type, Clone, Copy, Debug)]
 pub struct Mp4ExportSettings {
     pub fps: u32,
     pub resolution_base: XY<u32>,
@@ -38,6 +41,16 @@ pub struct Mp4ExportSettings {
     pub custom_bpp: Option<f32>,
     #[serde(default)]
     pub force_ffmpeg_decoder: bool,
+    #[serde(default)]
+    pub lead_in_ms: u32,
+    #[serde(default)]
+    pub lead_out_ms: u32,
+    #[serde(default)]
+    pub audio_stems: bool,
+    #[serde(default)]
+    pub timelapse: Option<TimelapseSettings>,
+    #[serde(default)]
+    pub frame_interpolation: bool,
 }
 
 impl Mp4ExportSettings {
@@ -45,6 +58,47 @@ impl Mp4ExportSettings {
         self.custom_bpp
             .unwrap_or_else(|| self.compression.bits_per_pixel())
     }
+
+    pub fn lead_in_frames(&self) -> u32 {
+        ms_to_frames(self.lead_in_ms, self.fps)
+    }
+
+    pub fn lead_out_frames(&self) -> u32 {
+        ms_to_frames(self.lead_out_ms, self.fps)
+    }
+
+    pub fn render_fps(&self, base: &ExporterBase) -> u32 {
+        if !self.frame_interpolation {
+            return self.fps;
+        }
+
+        base.recordings
+            .segments
+            .iter()
+            .map(|segment| segment.display.fps)
+            .max()
+            .filter(|&source_fps| source_fps > 0 && source_fps < self.fps)
+            .unwrap_or(self.fps)
+    }
+
+    pub fn output_duration(&self, base: &ExporterBase) -> f64 {
+        let held_frames = self.lead_in_frames() + self.lead_out_frames();
+        let cards = [
+            &base.project_config.title_card,
+            &base.project_config.outro_card,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|card| card.is_active() && !base.segments.is_empty())
+        .map(|card| (card.duration * f64::from(self.fps)).round())
+        .sum::<f64>();
+
+        base.duration() + (f64::from(held_frames) + cards) / f64::from(self.fps)
+    }
+}
+
+fn ms_to_frames(ms: u32, fps: u32) -> u32 {
+    ((u64::from(ms) * u64::from(fps) + 500) / 1000) as u32
 }
 
 impl Mp4ExportSettings {
@@ -58,37 +112,98 @@ impl Mp4ExportSettings {
 
         let fps = self.fps;
 
-        let output_size = ProjectUniforms::get_output_size(
-            &base.render_constants.options,
-            &base.project_config,
-            self.resolution_base,
-        );
+        let size = base.render_size(self.resolution_base);
+        check_frame_size(size.output)?;
+        check_frame_size(size.render)?;
+        if size.is_reduced() {
+            warn!(
+                output = ?size.output,
+                render = ?size.render,
+                "GPU can't composite at the export size, compositing smaller and upscaling"
+            );
+        }
 
         info!(
-            width = output_size.0,
-            height = output_size.1,
+            width = size.output.0,
+            height = size.output.1,
             "Exporting with NV12 pipeline (GPU when possible, CPU fallback otherwise)"
         );
-        self.export_nv12(base, output_size, fps, on_progress).await
+
+        let render_fps = self.render_fps(&base);
+        if render_fps == fps {
+            return self.export_nv12(base, size, fps, on_progress).await;
+        }
+
+        info!(
+            render_fps,
+            output_fps = fps,
+            "Rendering at the recording's frame rate and interpolating the rest"
+        );
+        let mut on_progress = on_progress;
+        let on_rendered = move |frame: u32| {
+            on_progress((u64::from(frame) * u64::from(fps) / u64::from(render_fps)) as u32)
+        };
+        Self {
+            fps: render_fps,
+            ..self
+        }
+        .export_nv12(base, size, fps, on_rendered)
+        .await
     }
 
     async fn export_nv12(
         self,
         base: ExporterBase,
-        output_size: (u32, u32),
-        fps: u32,
+        size: RenderSize,
+        output_fps: u32,
         mut on_progress: impl FnMut(u32) -> bool + Send + 'static,
     ) -> Result<PathBuf, String> {
+        let fps = self.fps;
         let output_path = base.output_path.clone();
         let meta = &base.studio_meta;
 
+        let lead_in_frames = self.lead_in_frames();
+        let lead_out_frames = self.lead_out_frames();
+        if lead_in_frames > 0 || lead_out_frames > 0 {
+            info!(
+                lead_in_frames,
+                lead_out_frames, "Padding export with held first/last frames"
+            );
+        }
+
+        let render_size = size.render;
+        let resolution_base = size.resolution_base;
+        let upscale_to = size.is_reduced().then_some(size.output);
+
+        let title_card = self
+            .render_card(
+                &base,
+                base.project_config.title_card.as_ref(),
+                &base.recording_meta.pretty_name,
+                resolution_base,
+            )
+            .await?;
+        let outro_card = self
+            .render_card(
+                &base,
+                base.project_config.outro_card.as_ref(),
+                "",
+                resolution_base,
+            )
+            .await?;
+        let (lead_in, lead_out) = self.render_lead_frames(&base, resolution_base).await?;
+
         let (tx_image_data, mut video_rx) =
             tokio::sync::mpsc::channel::<(Nv12RenderedFrame, u32)>(32);
         let (frame_tx, frame_rx) = std::sync::mpsc::sync_channel::<Nv12ExportFrame>(32);
 
-        let mut video_info =
-            VideoInfo::from_raw(RawVideoFormat::Nv12, output_size.0, output_size.1, fps);
-        video_info.time_base = ffmpeg::Rational::new(1, fps as i32);
+        let mut video_info = VideoInfo::from_raw(
+            RawVideoFormat::Nv12,
+            render_size.0,
+            render_size.1,
+            output_fps,
+        );
+        video_info.time_base = ffmpeg::Rational::new(1, output_fps as i32);
 
         let audio_segments = get_audio_segments(&base.segments);
 
@@ -96,21 +211,33 @@ impl Mp4ExportSettings {
             .first()
             .filter(|_| !base.project_config.audio.mute)
             .map(|_| AudioRenderer::new(audio_segments.clone()));
-        let has_audio = audio_renderer.is_some();
+        let click_sounds = ClickSoundMixer::new(&base.project_config, &base.segments);
+        let has_audio = audio_renderer.is_some() || click_sounds.is_some();
+
+        let stems = if self.audio_stems && has_audio {
+            AudioStems::new(&base.output_target, &base.project_config, &base.segments)
+                .map_err(|e| format!("Failed to create audio stems: {e}"))?
+        } else {
+            None
+        };
 
+        let timings = base.timings.clone();
         let encoder_thread = tokio::task::spawn_blocking(move || {
+            let _encoder_log = base.encoder_log.enter();
             trace!("Creating MP4File encoder (NV12 path)");
 
             let mut encoder = MP4File::init(
                 "output",
                 base.output_path.clone(),
                 |o| {
-                    H264Encoder::builder(video_info)
+                    let encoder = H264Encoder::builder(video_info)
                         .with_bpp(self.effective_bpp())
                         .with_export_priority()
-                        .with_export_settings()
-                        .with_external_conversion()
-                        .build(o)
+                        .with_export_settings();
+                    match upscale_to {
+                        Some((width, height)) => encoder.with_output_size(width, height)?.build(o),
+                        None => encoder.with_external_conversion().build(o),
+                    }
                 },
                 |o| {
                     has_audio.then(|| {
@@ -122,32 +249,49 @@ impl Mp4ExportSettings {
             )
             .map_err(|v| v.to_string())?;
 
-            info!("Created MP4File encoder (NV12, external conversion, export settings)");
+            info!("Created MP4File encoder (NV12, export settings)");
 
             let mut reusable_frame = ffmpeg::frame::Video::new(
                 ffmpeg::format::Pixel::NV12,
-                output_size.0,
-                output_size.1,
+                render_size.0,
+                render_size.1,
             );
             let mut converted_frame: Option<ffmpeg::frame::Video> = None;
+            let mut conformer = FrameConformer::default();
+            let mut interpolator = (output_fps != fps)
+                .then(|| FrameInterpolator::new(render_size, fps, output_fps))
+                .transpose()
+                .map_err(|e| format!("Failed to set up frame interpolation: {e}"))?;
             let mut encoded_frames = 0u32;
             let encode_start = std::time::Instant::now();
 
             while let Ok(input) = frame_rx.recv() {
-                fill_nv12_frame(&mut reusable_frame, &input);
-                encoder
-                    .queue_video_frame_reusable(
-                        &mut reusable_frame,
-                        &mut converted_frame,
-                        Duration::MAX,
-                    )
-                    .map_err(|err| err.to_string())?;
+                let frame_start = std::time::Instant::now();
+                conformer.fill(&mut reusable_frame, &input)?;
+                let mut queue = |frame: &mut ffmpeg::frame::Video| {
+                    encoder
+                        .queue_video_frame_reusable(frame, &mut converted_frame, Duration::MAX)
+                        .map_err(|err| err.to_string())
+                };
+                match &mut interpolator {
+                    Some(interpolator) => interpolator.push(&reusable_frame, queue)?,
+                    None => queue(&mut reusable_frame)?,
+                }
                 if let Some(audio) = input.audio {
                     encoder.queue_audio_frame(audio);
                 }
+                timings.record_encode(frame_start.elapsed());
                 encoded_frames += 1;
             }
 
+            if let Some(interpolator) = &mut interpolator {
+                interpolator.finish(|frame| {
+                    encoder
+                        .queue_video_frame_reusable(frame, &mut converted_frame, Duration::MAX)
+                        .map_err(|err| err.to_string())
+                })?;
+            }
+
             let encode_elapsed = encode_start.elapsed();
             if encoded_frames > 0 {
                 let encode_fps = encoded_frames as f64 / encode_elapsed.as_secs_f64().max(0.001);
@@ -177,15 +321,32 @@ impl Mp4ExportSettings {
         let render_task = tokio::spawn({
             let project = base.project_config.clone();
             let project_path = base.project_path.clone();
+            let timings = base.timings.clone();
             async move {
                 let mut frame_count = 0;
                 let mut first_frame_data: Option<FirstFrameNv12> = None;
                 let sample_rate = u64::from(AudioRenderer::SAMPLE_RATE);
                 let fps_u64 = u64::from(fps);
                 let mut audio_sample_cursor = 0u64;
+                let mut held = HeldFrameWriter::new(&frame_tx, has_audio, fps_u64, stems);
                 let mut consecutive_timeouts = 0u32;
                 const MAX_CONSECUTIVE_TIMEOUTS: u32 = 3;
 
+                if let Some((card, count)) = &title_card
+                    && !held.send(card, *count)?
+                {
+                    warn!("Renderer task sender dropped. Exiting");
+                    return Ok(None);
+                }
+
+                if let Some(frame) = &lead_in
+                    && !held.send(frame, lead_in_frames)?
+                {
+                    warn!("Renderer task sender dropped. Exiting");
+                    return Ok(None);
+                }
+                let content_offset = (held.pts, held.audio_pts);
+
                 loop {
                     let timeout_secs = if frame_count == 0 { 120 } else { 90 };
                     let (frame, frame_number) = match tokio::time::timeout(
@@ -231,6 +392,7 @@ impl Mp4ExportSettings {
                         }
                     };
 
+                    timings.record_frame(frame.timings);
                     if !(on_progress)(frame_count) {
                         return Err("Export cancelled".to_string());
                     }
@@ -251,21 +413,42 @@ impl Mp4ExportSettings {
                         }
                     }
 
-                    let audio_frame = audio_renderer.as_mut().and_then(|audio| {
-                        let n = u64::from(frame_number);
-                        let end = ((n + 1) * sample_rate) / fps_u64;
-                        if end <= audio_sample_cursor {
-                            return None;
-                        }
-                        let pts = audio_sample_cursor as i64;
-                        let samples = (end - audio_sample_cursor) as usize;
-                        audio_sample_cursor = end;
-                        audio.render_frame(samples, &project).map(|mut frame| {
-                            frame.set_pts(Some(pts));
-                            frame
+                    let audio_range = has_audio
+                        .then(|| {
+                            let n = u64::from(frame_number);
+                            let end = ((n + 1) * sample_rate) / fps_u64;
+                            if end <= audio_sample_cursor {
+                                return None;
+                            }
+                            let start = audio_sample_cursor;
+                            audio_sample_cursor = end;
+                            Some((start, (end - start) as usize))
                         })
+                        .flatten();
+
+                    if let Some((start, samples)) = audio_range
+                        && let Some(stems) = &mut held.stems
+                    {
+                        stems
+                            .render(start, samples)
+                            .map_err(|e| format!("Failed to write audio stems: {e}"))?;
+                    }
+
+                    let audio_frame = audio_range.and_then(|(start, samples)| {
+                        let mut frame = render_audio_frame(
+                            audio_renderer.as_mut(),
+                            click_sounds.as_ref(),
+                            &project,
+                            start,
+                            samples,
+                        )?;
+                        frame.set_pts(Some(start as i64 + content_offset.1));
+                        Some(frame)
                     });
 
+                    let pts = i64::from(frame_number) + content_offset.0;
+                    held.pts = pts + 1;
+
                     if frame_tx
                         .send(Nv12ExportFrame {
                             audio: audio_frame,
@@ -273,17 +456,34 @@ impl Mp4ExportSettings {
                             width: frame_width,
                             height: frame_height,
                             y_stride: frame_width,
-                            pts: frame_number as i64,
+                            pts,
                         })
                         .is_err()
                     {
                         warn!("Renderer task sender dropped. Exiting");
-                        return Ok(());
+                        return Ok(None);
                     }
 
                     frame_count += 1;
                 }
 
+                held.audio_pts = audio_sample_cursor as i64 + content_offset.1;
+
+                if let Some(frame) = &lead_out
+                    && !held.send(frame, lead_out_frames)?
+                {
+                    warn!("Renderer task sender dropped. Exiting");
+                    return Ok(None);
+                }
+
+                if let Some((card, count)) = &outro_card
+                    && !held.send(card, *count)?
+                {
+                    warn!("Renderer task sender dropped. Exiting");
+                    return Ok(None);
+                }
+
+                let stems = held.stems.take();
                 drop(frame_tx);
 
                 if let Some(first) = first_frame_data {
@@ -305,7 +505,7 @@ impl Mp4ExportSettings {
                     warn!("No frames were processed, cannot save screenshot or thumbnail");
                 }
 
-                Ok::<_, String>(())
+                Ok::<_, String>(stems)
             }
         })
         .then(|r| async {
@@ -327,15 +527,230 @@ impl Mp4ExportSettings {
                 })
                 .collect(),
             fps,
-            self.resolution_base,
+            resolution_base,
             &base.recordings,
         )
         .then(|v| async { v.map_err(|e| e.to_string()) });
 
-        tokio::try_join!(encoder_thread, render_video_task, render_task)?;
+        let (_, _, stems) = tokio::try_join!(encoder_thread, render_video_task, render_task)?;
+
+        if let Some(stems) = stems {
+            let paths = stems
+                .finish()
+                .map_err(|e| format!("Failed to finish audio stems: {e}"))?;
+            info!(?paths, "Wrote audio stems");
+        }
 
         Ok(output_path)
     }
+
+    async fn render_card(
+        &self,
+        base: &ExporterBase,
+        card: Option<&CardConfiguration>,
+        fallback_title: &str,
+        resolution_base: XY<u32>,
+    ) -> Result<Option<(HeldFrame, u32)>, String> {
+        let Some(card) = card.filter(|card| card.is_active()) else {
+            return Ok(None);
+        };
+        let Some(segment) = base.segments.first() else {
+            return Ok(None);
+        };
+
+        let duration = cap_rendering::get_duration(
+            &base.recordings,
+            &base.recording_meta,
+            &base.studio_meta,
+            &base.project_config,
+        );
+
+        let frame = cap_rendering::render_card_frame_nv12(
+            &base.render_constants,
+            &base.project_config,
+            &RenderSegment {
+                cursor: segment.cursor.clone(),
+                decoders: segment.decoders.clone(),
+            },
+            card,
+            fallback_title,
+            self.fps,
+            resolution_base,
+            duration,
+        )
+        .await
+        .map_err(|e| format!("Failed to render card: {e}"))?;
+
+        let width = frame.width;
+        let height = frame.height;
+        let count = (card.duration * f64::from(self.fps)).round() as u32;
+
+        Ok(Some((
+            HeldFrame {
+                data: ensure_nv12_data(frame),
+                width,
+                height,
+            },
+            count,
+        )))
+    }
+
+    async fn render_lead_frames(
+        &self,
+        base: &ExporterBase,
+        resolution_base: XY<u32>,
+    ) -> Result<(Option<HeldFrame>, Option<HeldFrame>), String> {
+        let duration = cap_rendering::get_duration(
+            &base.recordings,
+            &base.recording_meta,
+            &base.studio_meta,
+            &base.project_config,
+        );
+        let last_frame = ((f64::from(self.fps) * duration).ceil() as u32).saturating_sub(1);
+        let render_segments = base
+            .segments
+            .iter()
+            .map(|s| RenderSegment {
+                cursor: s.cursor.clone(),
+                decoders: s.decoders.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut frames = [None, None];
+        for (held, (count, frame_number)) in frames.iter_mut().zip([
+            (self.lead_in_frames(), 0),
+            (self.lead_out_frames(), last_frame),
+        ]) {
+            if count == 0 {
+                continue;
+            }
+
+            let frame = cap_rendering::render_settled_frame_nv12(
+                &base.render_constants,
+                &base.project_config,
+                &render_segments,
+                frame_number,
+                self.fps,
+                resolution_base,
+                duration,
+            )
+            .await
+            .map_err(|e| format!("Failed to render held frame: {e}"))?;
+
+            *held = Some(HeldFrame {
+                width: frame.width,
+                height: frame.height,
+                data: ensure_nv12_data(frame),
+            });
+        }
+
+        let [lead_in, lead_out] = frames;
+        Ok((lead_in, lead_out))
+    }
+}
+
+struct HeldFrame {
+    data: Arc<Vec<u8>>,
+    width: u32,
+    height: u32,
+}
+
+struct HeldFrameWriter<'a> {
+    frame_tx: &'a std::sync::mpsc::SyncSender<Nv12ExportFrame>,
+    has_audio: bool,
+    fps: u64,
+    pts: i64,
+    audio_pts: i64,
+    stems: Option<AudioStems>,
+}
+
+impl<'a> HeldFrameWriter<'a> {
+    fn new(
+        frame_tx: &'a std::sync::mpsc::SyncSender<Nv12ExportFrame>,
+        has_audio: bool,
+        fps: u64,
+        stems: Option<AudioStems>,
+    ) -> Self {
+        Self {
+            frame_tx,
+            has_audio,
+            fps,
+            pts: 0,
+            audio_pts: 0,
+            stems,
+        }
+    }
+
+    fn send(&mut self, frame: &HeldFrame, count: u32) -> Result<bool, String> {
+        for _ in 0..count {
+            let audio = if self.has_audio {
+                let (audio, samples) = silent_audio_frame(self.pts, self.fps, self.audio_pts);
+                self.audio_pts += samples as i64;
+                if let Some(stems) = &mut self.stems {
+                    stems
+                        .write_silence(samples)
+                        .map_err(|e| format!("Failed to write audio stems: {e}"))?;
+                }
+                Some(audio)
+            } else {
+                None
+            };
+
+            if self
+                .frame_tx
+                .send(Nv12ExportFrame {
+                    audio,
+                    nv12_data: frame.data.clone(),
+                    width: frame.width,
+                    height: frame.height,
+                    y_stride: frame.width,
+                    pts: self.pts,
+                })
+                .is_err()
+            {
+                return Ok(false);
+            }
+
+            self.pts += 1;
+        }
+
+        Ok(true)
+    }
+}
+
+pub(crate) fn render_audio_frame(
+    audio_renderer: Option<&mut AudioRenderer>,
+    click_sounds: Option<&ClickSoundMixer>,
+    project: &ProjectConfiguration,
+    start: u64,
+    samples: usize,
+) -> Option<ffmpeg::frame::Audio> {
+    let rendered = audio_renderer.and_then(|audio| audio.render_frame_raw(samples, project));
+    let (samples, data) = match click_sounds {
+        Some(clicks) => {
+            let mut data = rendered.map(|(_, data)| data).unwrap_or_default();
+            data.resize(samples * usize::from(AudioRenderer::CHANNELS), 0.0);
+            clicks.mix(start, &mut data);
+            (samples, data)
+        }
+        None => rendered?,
+    };
+
+    Some(AudioRenderer::frame_from_samples(samples, &data))
+}
+
+fn silent_audio_frame(frame_pts: i64, fps: u64, pts: i64) -> (ffmpeg::frame::Audio, usize) {
+    let sample_rate = u64::from(AudioRenderer::SAMPLE_RATE);
+    let n = frame_pts.max(0) as u64;
+    let samples = (((n + 1) * sample_rate) / fps - (n * sample_rate) / fps) as usize;
+
+    let mut frame = AudioRenderer::info().empty_frame(samples);
+    for plane in 0..frame.planes() {
+        frame.data_mut(plane).fill(0);
+    }
+    frame.set_pts(Some(pts));
+
+    (frame, samples)
 }
 
 struct FirstFrameNv12 {
@@ -354,7 +769,7 @@ struct Nv12ExportFrame {
     audio: Option<ffmpeg::frame::Audio>,
 }
 
-fn ensure_nv12_data(frame: Nv12RenderedFrame) -> Arc<Vec<u8>> {
+pub(crate) fn ensure_nv12_data(frame: Nv12RenderedFrame) -> Arc<Vec<u8>> {
     use cap_rendering::GpuOutputFormat;
 
     if frame.format != GpuOutputFormat::Rgba {
@@ -395,11 +810,13 @@ fn ensure_nv12_data(frame: Nv12RenderedFrame) -> Arc<Vec<u8>> {
     ) {
         let mut nv12_frame = ffmpeg::frame::Video::new(ffmpeg::format::Pixel::NV12, width, height);
         if converter.run(&rgba_frame, &mut nv12_frame).is_ok() {
+            let (uv_row, uv_rows) = nv12_uv_size(width, height);
             let y_size = nv12_frame.stride(0) * height as usize;
-            let uv_size = nv12_frame.stride(1) * (height as usize / 2);
+            let uv_size = nv12_frame.stride(1) * uv_rows;
             let y_data = &nv12_frame.data(0)[..y_size];
             let uv_data = &nv12_frame.data(1)[..uv_size];
-            let mut result = Vec::with_capacity(width as usize * height as usize * 3 / 2);
+            let mut result =
+                Vec::with_capacity(width as usize * height as usize + uv_row * uv_rows);
 
             if nv12_frame.stride(0) == width as usize {
                 result.extend_from_slice(y_data);
@@ -410,12 +827,12 @@ fn ensure_nv12_data(frame: Nv12RenderedFrame) -> Arc<Vec<u8>> {
                 }
             }
 
-            if nv12_frame.stride(1) == width as usize {
+            if nv12_frame.stride(1) == uv_row {
                 result.extend_from_slice(uv_data);
             } else {
-                for row in 0..(height as usize / 2) {
+                for row in 0..uv_rows {
                     let start = row * nv12_frame.stride(1);
-                    result.extend_from_slice(&uv_data[start..start + width as usize]);
+                    result.extend_from_slice(&uv_data[start..start + uv_row]);
                 }
             }
 
@@ -427,7 +844,93 @@ fn ensure_nv12_data(frame: Nv12RenderedFrame) -> Arc<Vec<u8>> {
         frame_number = frame.frame_number,
         "swscale RGBA to NV12 conversion failed, using zeroed NV12"
     );
-    Arc::new(vec![0u8; width as usize * height as usize * 3 / 2])
+    let (uv_row, uv_rows) = nv12_uv_size(width, height);
+    Arc::new(vec![
+        0u8;
+        width as usize * height as usize + uv_row * uv_rows
+    ])
+}
+
+fn nv12_uv_size(width: u32, height: u32) -> (usize, usize) {
+    (width.div_ceil(2) as usize * 2, height.div_ceil(2) as usize)
+}
+
+const MAX_FRAME_DIMENSION: u32 = 8192;
+
+fn check_frame_size((width, height): (u32, u32)) -> Result<(), String> {
+    if width < 2 || height < 2 {
+        return Err(format!(
+            "Export resolution {width}x{height} is too small to encode"
+        ));
+    }
+    if width % 2 != 0 || height % 2 != 0 {
+        return Err(format!(
+            "Export resolution {width}x{height} must have an even width and height"
+        ));
+    }
+    if width.max(height) > MAX_FRAME_DIMENSION {
+        return Err(format!(
+            "Export resolution {width}x{height} is larger than the {MAX_FRAME_DIMENSION}px H.264 allows"
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct FrameConformer {
+    scaler: Option<(ffmpeg::software::scaling::Context, ffmpeg::frame::Video)>,
+}
+
+impl FrameConformer {
+    fn fill(
+        &mut self,
+        frame: &mut ffmpeg::frame::Video,
+        input: &Nv12ExportFrame,
+    ) -> Result<(), String> {
+        if (input.width, input.height) == (frame.width(), frame.height()) {
+            fill_nv12_frame(frame, input);
+            return Ok(());
+        }
+
+        let (scaler, source) = match &mut self.scaler {
+            Some((scaler, source))
+                if (source.width(), source.height()) == (input.width, input.height) =>
+            {
+                (scaler, source)
+            }
+            scaler => {
+                warn!(
+                    frame = ?(input.width, input.height),
+                    encoder = ?(frame.width(), frame.height()),
+                    "Frame doesn't match the export size, scaling it to fit"
+                );
+                let context = ffmpeg::software::scaling::Context::get(
+                    ffmpeg::format::Pixel::NV12,
+                    input.width,
+                    input.height,
+                    ffmpeg::format::Pixel::NV12,
+                    frame.width(),
+                    frame.height(),
+                    ffmpeg::software::scaling::flag::Flags::BILINEAR,
+                )
+                .map_err(|e| format!("Failed to scale frame to the export size: {e}"))?;
+                let source = ffmpeg::frame::Video::new(
+                    ffmpeg::format::Pixel::NV12,
+                    input.width,
+                    input.height,
+                );
+                let (scaler, source) = scaler.insert((context, source));
+                (scaler, source)
+            }
+        };
+
+        fill_nv12_frame(source, input);
+        scaler
+            .run(source, frame)
+            .map_err(|e| format!("Failed to scale frame to the export size: {e}"))?;
+        frame.set_pts(Some(input.pts));
+        Ok(())
+    }
 }
 
 fn fill_nv12_frame(frame: &mut ffmpeg::frame::Video, input: &Nv12ExportFrame) {
@@ -463,16 +966,16 @@ fn fill_nv12_frame(frame: &mut ffmpeg::frame::Video, input: &Nv12ExportFrame) {
         }
     }
 
-    let uv_height = height / 2;
+    let (uv_row, uv_height) = nv12_uv_size(input.width, input.height);
     let dst_uv_stride = frame.stride(1);
-    if dst_uv_stride == width {
+    if dst_uv_stride == uv_row {
         let copy_len = uv_src.len().min(frame.data_mut(1).len());
         frame.data_mut(1)[..copy_len].copy_from_slice(&uv_src[..copy_len]);
     } else {
         for row in 0..uv_height {
-            let src_start = row * width;
+            let src_start = row * uv_row;
             let dst_start = row * dst_uv_stride;
-            let copy_width = width.min(dst_uv_stride);
+            let copy_width = uv_row.min(dst_uv_stride);
             if src_start + copy_width <= uv_src.len()
                 && dst_start + copy_width <= frame.data_mut(1).len()
             {
@@ -549,6 +1052,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lead_frames_round_to_nearest_frame() {
+        assert_eq!(ms_to_frames(0, 60), 0);
+        assert_eq!(ms_to_frames(1000, 30), 30);
+        assert_eq!(ms_to_frames(500, 60), 30);
+        assert_eq!(ms_to_frames(10, 30), 0);
+        assert_eq!(ms_to_frames(20, 30), 1);
+    }
+
     #[test]
     fn fill_nv12_frame_preserves_data_layout() {
         ffmpeg::init().unwrap();
@@ -597,6 +1109,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fill_nv12_frame_keeps_chroma_aligned_for_odd_sizes() {
+        ffmpeg::init().unwrap();
+
+        let (width, height) = (5u32, 3u32);
+        let (uv_row, uv_rows) = nv12_uv_size(width, height);
+        assert_eq!((uv_row, uv_rows), (6, 2));
+
+        let y_size = (width * height) as usize;
+        let nv12_data = (0..y_size + uv_row * uv_rows)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+        let input = Nv12ExportFrame {
+            nv12_data: Arc::new(nv12_data.clone()),
+            width,
+            height,
+            y_stride: width,
+            pts: 0,
+            audio: None,
+        };
+
+        let mut frame = ffmpeg::frame::Video::new(ffmpeg::format::Pixel::NV12, width, height);
+        fill_nv12_frame(&mut frame, &input);
+
+        for row in 0..uv_rows {
+            for col in 0..uv_row {
+                assert_eq!(
+                    nv12_data[y_size + row * uv_row + col],
+                    frame.data(1)[row * frame.stride(1) + col],
+                    "UV mismatch at ({col}, {row})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn conformer_scales_mismatched_frames_to_the_encoder_size() {
+        ffmpeg::init().unwrap();
+
+        let (width, height) = (6u32, 4u32);
+        let (uv_row, uv_rows) = nv12_uv_size(width, height);
+        let mut nv12_data = vec![200u8; (width * height) as usize];
+        nv12_data.resize(nv12_data.len() + uv_row * uv_rows, 128);
+        let input = Nv12ExportFrame {
+            nv12_data: Arc::new(nv12_data),
+            width,
+            height,
+            y_stride: width,
+            pts: 7,
+            audio: None,
+        };
+
+        let mut frame = ffmpeg::frame::Video::new(ffmpeg::format::Pixel::NV12, 16, 8);
+        let mut conformer = FrameConformer::default();
+        conformer.fill(&mut frame, &input).unwrap();
+
+        assert_eq!(frame.pts(), Some(7));
+        for row in 0..8 {
+            for col in 0..16 {
+                let luma = frame.data(0)[row * frame.stride(0) + col];
+                assert!(luma.abs_diff(200) <= 2, "luma {luma} at ({col}, {row})");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_frame_sizes_the_encoder_cant_take() {
+        assert!(check_frame_size((1920, 1080)).is_ok());
+        assert!(check_frame_size((0, 1080)).is_err());
+        assert!(check_frame_size((1921, 1080)).is_err());
+        assert!(check_frame_size((1920, 1081)).is_err());
+        assert!(check_frame_size((8194, 1080)).is_err());
+    }
+
     #[test]
     fn ensure_nv12_data_passthrough_for_nv12_format() {
         use cap_rendering::{GpuOutputFormat, Nv12RenderedFrame};
@@ -610,6 +1196,7 @@ mod tests {
             frame_number: 0,
             target_time_ns: 0,
             format: GpuOutputFormat::Nv12,
+            timings: Default::default(),
         };
 
         let result = ensure_nv12_data(frame);
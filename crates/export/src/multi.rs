@@ -0,0 +1,477 @@
+use crate::{
+    ExporterBase,
+    click_sounds::ClickSoundMixer,
+    mp4::{ensure_nv12_data, render_audio_frame},
+    timings::ExportTimings,
+};
+use cap_editor::{AudioRenderer, get_audio_segments};
+use cap_enc_ffmpeg::{AudioEncoder, aac::AACEncoder, h264::H264Encoder, mp4::*};
+use cap_media_info::{RawVideoFormat, VideoInfo};
+use cap_project::XY;
+use cap_rendering::{ProjectUniforms, RenderSegment};
+use futures::FutureExt;
+use std::{
+    path::PathBuf,
+    sync::{Arc, mpsc},
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+#[derive(Clone, Debug)]
+pub enum ExportTargetFormat {
+    Mp4 {
+        bpp: f32,
+    },
+    Gif {
+        quality: cap_enc_gif::GifQuality,
+        repeat: Option<u16>,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct ExportTarget {
+    pub format: ExportTargetFormat,
+    pub fps: u32,
+    pub resolution_base: XY<u32>,
+    pub vertical: bool,
+    pub output_path: PathBuf,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Crop {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+struct Sink {
+    target: ExportTarget,
+    crop: Crop,
+    output_size: (u32, u32),
+}
+
+struct SinkFrame {
+    data: Arc<Vec<u8>>,
+    width: u32,
+    height: u32,
+    frame_number: u32,
+    audio: Option<ffmpeg::frame::Audio>,
+}
+
+pub async fn export(
+    base: ExporterBase,
+    targets: Vec<ExportTarget>,
+    mut on_progress: impl FnMut(u32) -> bool + Send + 'static,
+) -> Result<Vec<PathBuf>, String> {
+    let Some(fps) = targets.iter().map(|target| target.fps).max() else {
+        return Ok(vec![]);
+    };
+    let resolution_base = targets
+        .iter()
+        .map(|target| target.resolution_base)
+        .max_by_key(|base| u64::from(base.x) * u64::from(base.y))
+        .unwrap_or(XY::new(1920, 1080));
+
+    let output_size = |resolution_base| {
+        let (width, height) = ProjectUniforms::get_output_size(
+            &base.render_constants.options,
+            &base.project_config,
+            resolution_base,
+        );
+        (even(width), even(height))
+    };
+    let size = base.render_size(resolution_base);
+    if size.is_reduced() {
+        warn!(
+            output = ?size.output,
+            render = ?size.render,
+            "GPU can't composite at the largest target size, compositing smaller and upscaling"
+        );
+    }
+    let resolution_base = size.resolution_base;
+    let frame_size = (even(size.render.0), even(size.render.1));
+
+    info!(
+        targets = targets.len(),
+        fps,
+        width = frame_size.0,
+        height = frame_size.1,
+        "Exporting multiple targets from one render"
+    );
+
+    let sinks = targets
+        .into_iter()
+        .map(|target| {
+            let target_size = output_size(target.resolution_base);
+            let (crop, output_size) = if target.vertical {
+                let crop = vertical_crop(frame_size);
+                let scale = |v: u32| {
+                    even((u64::from(v) * u64::from(target_size.1) / u64::from(frame_size.1)) as u32)
+                        .max(2)
+                };
+                (crop, (scale(crop.width), scale(crop.height)))
+            } else {
+                (full_frame(frame_size), target_size)
+            };
+
+            Sink {
+                target,
+                crop,
+                output_size,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let wants_audio = sinks
+        .iter()
+        .any(|sink| matches!(sink.target.format, ExportTargetFormat::Mp4 { .. }));
+    let audio_segments = get_audio_segments(&base.segments);
+    let mut audio_renderer = audio_segments
+        .first()
+        .filter(|_| wants_audio && !base.project_config.audio.mute)
+        .map(|_| AudioRenderer::new(audio_segments.clone()));
+    let click_sounds =
+        ClickSoundMixer::new(&base.project_config, &base.segments).filter(|_| wants_audio);
+    let has_audio = audio_renderer.is_some() || click_sounds.is_some();
+
+    let mut senders = Vec::with_capacity(sinks.len());
+    let mut encoder_threads = Vec::with_capacity(sinks.len());
+    for sink in sinks {
+        let (tx, rx) = mpsc::sync_channel::<SinkFrame>(8);
+        let audio = has_audio && matches!(sink.target.format, ExportTargetFormat::Mp4 { .. });
+        senders.push((tx, audio));
+        let timings = base.timings.clone();
+        let encoder_log = base.encoder_log.clone();
+        encoder_threads.push(
+            tokio::task::spawn_blocking(move || {
+                let _encoder_log = encoder_log.enter();
+                run_sink(sink, fps, audio, rx, timings)
+            })
+            .then(|r| async { r.map_err(|e| e.to_string()).and_then(|v| v) }),
+        );
+    }
+
+    let (tx_image_data, mut video_rx) = tokio::sync::mpsc::channel(32);
+
+    let render_task = tokio::spawn({
+        let project = base.project_config.clone();
+        let timings = base.timings.clone();
+        async move {
+            let sample_rate = u64::from(AudioRenderer::SAMPLE_RATE);
+            let mut audio_sample_cursor = 0u64;
+            let mut frame_count = 0;
+
+            while let Some((frame, frame_number)) = video_rx.recv().await {
+                timings.record_frame(frame.timings);
+                if !(on_progress)(frame_count) {
+                    return Err("Export cancelled".to_string());
+                }
+
+                if frame_count == 0
+                    && let Some(audio) = &mut audio_renderer
+                {
+                    audio.set_playhead(0.0, &project);
+                }
+
+                let audio = if has_audio {
+                    let end = ((u64::from(frame_number) + 1) * sample_rate) / u64::from(fps);
+                    let start = audio_sample_cursor;
+                    audio_sample_cursor = audio_sample_cursor.max(end);
+                    (end > start)
+                        .then(|| {
+                            render_audio_frame(
+                                audio_renderer.as_mut(),
+                                click_sounds.as_ref(),
+                                &project,
+                                start,
+                                (end - start) as usize,
+                            )
+                        })
+                        .flatten()
+                        .map(|mut audio| {
+                            audio.set_pts(Some(start as i64));
+                            audio
+                        })
+                } else {
+                    None
+                };
+
+                let width = frame.width;
+                let height = frame.height;
+                let data = ensure_nv12_data(frame);
+
+                for (tx, wants_audio) in &senders {
+                    let frame = SinkFrame {
+                        data: data.clone(),
+                        width,
+                        height,
+                        frame_number,
+                        audio: audio.as_ref().filter(|_| *wants_audio).cloned(),
+                    };
+                    if tx.send(frame).is_err() {
+                        warn!("Encoder for an export target went away. Exiting");
+                        return Ok(());
+                    }
+                }
+
+                frame_count += 1;
+            }
+
+            Ok::<_, String>(())
+        }
+    })
+    .then(|r| async {
+        r.map_err(|e| e.to_string())
+            .and_then(|v| v.map_err(|e| e.to_string()))
+    });
+
+    let render_video_task = cap_rendering::render_video_to_channel_nv12(
+        &base.render_constants,
+        &base.project_config,
+        tx_image_data,
+        &base.recording_meta,
+        &base.studio_meta,
+        base.segments
+            .iter()
+            .map(|s| RenderSegment {
+                cursor: s.cursor.clone(),
+                decoders: s.decoders.clone(),
+            })
+            .collect(),
+        fps,
+        resolution_base,
+        &base.recordings,
+    )
+    .then(|v| async { v.map_err(|e| e.to_string()) });
+
+    let (paths, _, _) = tokio::try_join!(
+        futures::future::try_join_all(encoder_threads),
+        render_video_task,
+        render_task
+    )?;
+
+    Ok(paths)
+}
+
+fn run_sink(
+    sink: Sink,
+    source_fps: u32,
+    has_audio: bool,
+    frames: mpsc::Receiver<SinkFrame>,
+    timings: ExportTimings,
+) -> Result<PathBuf, String> {
+    let Sink {
+        target,
+        crop,
+        output_size,
+    } = sink;
+    let fps = target.fps;
+    let mut cropped =
+        ffmpeg::frame::Video::new(ffmpeg::format::Pixel::NV12, crop.width, crop.height);
+    let mut next_frame = 0;
+
+    match target.format {
+        ExportTargetFormat::Mp4 { bpp } => {
+            let mut video_info =
+                VideoInfo::from_raw(RawVideoFormat::Nv12, crop.width, crop.height, fps);
+            video_info.time_base = ffmpeg::Rational::new(1, fps as i32);
+
+            let mut encoder = MP4File::init(
+                "output",
+                target.output_path.clone(),
+                |o| {
+                    H264Encoder::builder(video_info)
+                        .with_bpp(bpp)
+                        .with_export_priority()
+                        .with_export_settings()
+                        .with_output_size(output_size.0, output_size.1)?
+                        .build(o)
+                },
+                |o| {
+                    has_audio.then(|| {
+                        AACEncoder::init(AudioRenderer::info(), o)
+                            .map(|v| v.boxed())
+                            .map_err(Into::into)
+                    })
+                },
+            )
+            .map_err(|v| v.to_string())?;
+            let mut converted_frame = None;
+
+            while let Ok(frame) = frames.recv() {
+                if let Some(pts) = take_frame(&mut next_frame, frame.frame_number, source_fps, fps)
+                {
+                    let start = Instant::now();
+                    fill_cropped_nv12_frame(&mut cropped, &frame, crop);
+                    cropped.set_pts(Some(pts));
+                    encoder
+                        .queue_video_frame_reusable(
+                            &mut cropped,
+                            &mut converted_frame,
+                            Duration::MAX,
+                        )
+                        .map_err(|err| err.to_string())?;
+                    timings.record_encode(start.elapsed());
+                }
+                if let Some(audio) = frame.audio {
+                    encoder.queue_audio_frame(audio);
+                }
+            }
+
+            let res = encoder
+                .finish()
+                .map_err(|e| format!("Failed to finish encoding: {e}"))?;
+            if let Err(e) = res.video_finish {
+                return Err(format!("Video encoding failed: {e}"));
+            }
+            if let Err(e) = res.audio_finish {
+                return Err(format!("Audio encoding failed: {e}"));
+            }
+        }
+        ExportTargetFormat::Gif { quality, repeat } => {
+            let mut encoder = cap_enc_gif::GifEncoderWrapper::new_with_repeat(
+                &target.output_path,
+                output_size.0,
+                output_size.1,
+                fps,
+                quality,
+                repeat,
+            )
+            .map_err(|e| format!("Failed to create GIF encoder: {e}"))?;
+            let mut scaler = ffmpeg::software::scaling::Context::get(
+                ffmpeg::format::Pixel::NV12,
+                crop.width,
+                crop.height,
+                ffmpeg::format::Pixel::RGBA,
+                output_size.0,
+                output_size.1,
+                ffmpeg::software::scaling::flag::Flags::BILINEAR,
+            )
+            .map_err(|e| e.to_string())?;
+            let mut rgba = ffmpeg::frame::Video::empty();
+
+            while let Ok(frame) = frames.recv() {
+                if take_frame(&mut next_frame, frame.frame_number, source_fps, fps).is_none() {
+                    continue;
+                }
+
+                let start = Instant::now();
+                fill_cropped_nv12_frame(&mut cropped, &frame, crop);
+                scaler.run(&cropped, &mut rgba).map_err(|e| e.to_string())?;
+                encoder
+                    .add_frame(rgba.data(0), rgba.stride(0))
+                    .map_err(|e| format!("Failed to add frame to GIF: {e}"))?;
+                timings.record_encode(start.elapsed());
+            }
+
+            encoder
+                .finish()
+                .map_err(|e| format!("Failed to finish GIF: {e}"))?;
+        }
+    }
+
+    Ok(target.output_path)
+}
+
+fn take_frame(next_frame: &mut i64, frame_number: u32, source_fps: u32, fps: u32) -> Option<i64> {
+    let frame = (u64::from(frame_number) * u64::from(fps) / u64::from(source_fps)) as i64;
+    if frame < *next_frame {
+        return None;
+    }
+
+    *next_frame = frame + 1;
+    Some(frame)
+}
+
+fn even(value: u32) -> u32 {
+    value & !1
+}
+
+fn full_frame((width, height): (u32, u32)) -> Crop {
+    Crop {
+        x: 0,
+        y: 0,
+        width,
+        height,
+    }
+}
+
+fn vertical_crop((width, height): (u32, u32)) -> Crop {
+    let (crop_width, crop_height) = if height * 9 / 16 <= width {
+        (even(height * 9 / 16).max(2), even(height))
+    } else {
+        (even(width), even(width * 16 / 9).max(2))
+    };
+
+    Crop {
+        x: even((width - crop_width) / 2),
+        y: even((height - crop_height) / 2),
+        width: crop_width,
+        height: crop_height,
+    }
+}
+
+fn fill_cropped_nv12_frame(frame: &mut ffmpeg::frame::Video, input: &SinkFrame, crop: Crop) {
+    let stride = input.width as usize;
+    let (x, y) = (crop.x as usize, crop.y as usize);
+    let (width, height) = (crop.width as usize, crop.height as usize);
+    let uv_start = stride * input.height as usize;
+
+    for row in 0..height {
+        let src = (y + row) * stride + x;
+        let dst = row * frame.stride(0);
+        if let Some(src) = input.data.get(src..src + width) {
+            frame.data_mut(0)[dst..dst + width].copy_from_slice(src);
+        }
+    }
+
+    for row in 0..height / 2 {
+        let src = uv_start + (y / 2 + row) * stride + x;
+        let dst = row * frame.stride(1);
+        if let Some(src) = input.data.get(src..src + width) {
+            frame.data_mut(1)[dst..dst + width].copy_from_slice(src);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_frame_rates_take_evenly_spaced_frames() {
+        let taken = |fps| {
+            let mut next_frame = 0;
+            (0..8)
+                .filter(|&n| take_frame(&mut next_frame, n, 60, fps).is_some())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(taken(60), (0..8).collect::<Vec<_>>());
+        assert_eq!(taken(30), vec![0, 2, 4, 6]);
+        assert_eq!(taken(15), vec![0, 4]);
+    }
+
+    #[test]
+    fn vertical_crop_is_centered_and_even() {
+        assert_eq!(
+            vertical_crop((1920, 1080)),
+            Crop {
+                x: 656,
+                y: 0,
+                width: 606,
+                height: 1080,
+            }
+        );
+        assert_eq!(
+            vertical_crop((1080, 1920)),
+            Crop {
+                x: 0,
+                y: 0,
+                width: 1080,
+                height: 1920,
+            }
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use cap_project::{ProjectConfiguration, XY};
+use cap_rendering::{ProjectUniforms, RenderSegment, RenderedFrame};
+use futures::FutureExt;
+use image::codecs::jpeg::JpegEncoder;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::ExporterBase;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+const MAX_QUALITY: u8 = 80;
+const MIN_QUALITY: u8 = 35;
+const QUALITY_STEP: u8 = 15;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Type)]
+pub struct PreviewProxySettings {
+    pub fps: u32,
+    pub resolution_base: XY<u32>,
+    pub max_frame_bytes: usize,
+}
+
+impl Default for PreviewProxySettings {
+    fn default() -> Self {
+        Self {
+            fps: 15,
+            resolution_base: XY { x: 854, y: 480 },
+            max_frame_bytes: 48 * 1024,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewProxyManifest {
+    pub fps: u32,
+    pub width: u32,
+    pub height: u32,
+    pub frame_count: u32,
+    pub config_hash: u64,
+}
+
+impl PreviewProxyManifest {
+    pub fn load(dir: &Path) -> Option<Self> {
+        let file = std::fs::File::open(dir.join(MANIFEST_FILE)).ok()?;
+        serde_json::from_reader(file).ok()
+    }
+
+    pub fn frame_at(&self, dir: &Path, time: f64) -> Option<PathBuf> {
+        if self.frame_count == 0 || time < 0.0 {
+            return None;
+        }
+
+        let frame = ((time * self.fps as f64).round() as u32).min(self.frame_count - 1);
+        Some(frame_path(dir, frame))
+    }
+}
+
+pub fn frame_path(dir: &Path, frame: u32) -> PathBuf {
+    dir.join(format!("{frame:06}.jpg"))
+}
+
+pub fn config_hash(config: &ProjectConfiguration) -> u64 {
+    let ProjectConfiguration {
+        aspect_ratio,
+        background,
+        background_keyframes,
+        layout_keyframes,
+        confetti,
+        effect_params,
+        camera,
+        audio: _,
+        cursor,
+        magnifier,
+        hotkeys,
+        timeline,
+        captions,
+        clips,
+        annotations,
+        hidden_text_segments,
+        screen_motion_blur,
+        screen_movement_spring,
+        title_card,
+        outro_card,
+        export_directory: _,
+    } = config;
+
+    let rendered = serde_json::json!({
+        "aspectRatio": aspect_ratio,
+        "background": background,
+        "backgroundKeyframes": background_keyframes,
+        "layoutKeyframes": layout_keyframes,
+        "confetti": confetti,
+        "effectParams": effect_params.iter().collect::<BTreeMap<_, _>>(),
+        "camera": camera,
+        "cursor": cursor,
+        "magnifier": magnifier,
+        "hotkeys": hotkeys,
+        "timeline": timeline,
+        "captions": captions,
+        "clips": clips,
+        "annotations": annotations,
+        "hiddenTextSegments": hidden_text_segments,
+        "screenMotionBlur": screen_motion_blur,
+        "screenMovementSpring": screen_movement_spring,
+        "titleCard": title_card,
+        "outroCard": outro_card,
+    });
+
+    fnv1a(&serde_json::to_vec(&rendered).unwrap_or_default())
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+impl PreviewProxySettings {
+    pub async fn export(
+        self,
+        base: ExporterBase,
+        mut on_progress: impl FnMut(u32) -> bool + Send + 'static,
+    ) -> Result<PathBuf, String> {
+        let dir = base.output_path.clone();
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        if let Err(e) = std::fs::remove_file(dir.join(MANIFEST_FILE))
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            return Err(e.to_string());
+        }
+
+        let (width, height) = ProjectUniforms::get_output_size(
+            &base.render_constants.options,
+            &base.project_config,
+            self.resolution_base,
+        );
+        let config_hash = config_hash(&base.project_config);
+        let (tx_image_data, mut video_rx) = tokio::sync::mpsc::channel::<(RenderedFrame, u32)>(4);
+
+        let encoder_thread = tokio::task::spawn_blocking({
+            let dir = dir.clone();
+            move || {
+                let mut frame_count = 0;
+
+                while let Some((frame, _)) = video_rx.blocking_recv() {
+                    if !(on_progress)(frame_count) {
+                        return Err("Preview proxy cancelled".to_string());
+                    }
+
+                    let jpeg = encode_frame(&frame, self.max_frame_bytes)?;
+                    std::fs::write(frame_path(&dir, frame_count), jpeg)
+                        .map_err(|e| e.to_string())?;
+                    frame_count += 1;
+                }
+
+                let manifest = PreviewProxyManifest {
+                    fps: self.fps,
+                    width,
+                    height,
+                    frame_count,
+                    config_hash,
+                };
+                let json = serde_json::to_vec(&manifest).map_err(|e| e.to_string())?;
+                std::fs::write(dir.join(MANIFEST_FILE), json).map_err(|e| e.to_string())?;
+
+                Ok(dir)
+            }
+        })
+        .then(|f| async { f.map_err(|e| e.to_string()).and_then(|v| v) });
+
+        let render_video_task = cap_rendering::render_video_to_channel(
+            &base.render_constants,
+            &base.project_config,
+            tx_image_data,
+            &base.recording_meta,
+            &base.studio_meta,
+            base.segments
+                .iter()
+                .map(|s| RenderSegment {
+                    cursor: s.cursor.clone(),
+                    decoders: s.decoders.clone(),
+                })
+                .collect(),
+            self.fps,
+            self.resolution_base,
+            &base.recordings,
+        )
+        .then(|f| async { f.map_err(|v| v.to_string()) });
+
+        let (dir, _) = tokio::try_join!(encoder_thread, render_video_task)?;
+
+        Ok(dir)
+    }
+}
+
+fn encode_frame(frame: &RenderedFrame, max_bytes: usize) -> Result<Vec<u8>, String> {
+    let rgb = frame
+        .data
+        .chunks(frame.padded_bytes_per_row as usize)
+        .flat_map(|row| {
+            row[0..(frame.width * 4) as usize]
+                .chunks(4)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+        })
+        .collect::<Vec<u8>>();
+
+    encode_rgb(&rgb, frame.width, frame.height, max_bytes)
+}
+
+fn encode_rgb(rgb: &[u8], width: u32, height: u32, max_bytes: usize) -> Result<Vec<u8>, String> {
+    let mut quality = MAX_QUALITY;
+    loop {
+        let mut jpeg = Vec::new();
+        JpegEncoder::new_with_quality(&mut jpeg, quality)
+            .encode(rgb, width, height, image::ExtendedColorType::Rgb8)
+            .map_err(|e| e.to_string())?;
+
+        if jpeg.len() <= max_bytes || quality == MIN_QUALITY {
+            return Ok(jpeg);
+        }
+        quality = quality.saturating_sub(QUALITY_STEP).max(MIN_QUALITY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowers_quality_for_busy_frames() {
+        let (width, height) = (320, 180);
+        let noise = (0..width * height * 3)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect::<Vec<_>>();
+
+        let unlimited = encode_rgb(&noise, width, height, usize::MAX).unwrap();
+        let budgeted = encode_rgb(&noise, width, height, unlimited.len() / 2).unwrap();
+        assert!(budgeted.len() < unlimited.len());
+
+        let flat = vec![128; (width * height * 3) as usize];
+        let flat_jpeg = encode_rgb(&flat, width, height, 4 * 1024).unwrap();
+        assert_eq!(
+            flat_jpeg,
+            encode_rgb(&flat, width, height, usize::MAX).unwrap()
+        );
+    }
+
+    #[test]
+    fn picks_the_nearest_frame() {
+        let manifest = PreviewProxyManifest {
+            fps: 10,
+            width: 854,
+            height: 480,
+            frame_count: 50,
+            config_hash: 0,
+        };
+        let dir = Path::new("proxy");
+
+        assert_eq!(manifest.frame_at(dir, 1.24), Some(frame_path(dir, 12)));
+        assert_eq!(manifest.frame_at(dir, 1.26), Some(frame_path(dir, 13)));
+        assert_eq!(manifest.frame_at(dir, 60.0), Some(frame_path(dir, 49)));
+        assert_eq!(manifest.frame_at(dir, -1.0), None);
+    }
+
+    #[test]
+    fn config_hash_changes_with_the_config() {
+        let config = ProjectConfiguration::default();
+        let mut edited = config.clone();
+        edited.background.padding += 10.0;
+
+        assert_eq!(config_hash(&config), config_hash(&config.clone()));
+        assert_ne!(config_hash(&config), config_hash(&edited));
+    }
+
+    #[test]
+    fn config_hash_ignores_what_isnt_rendered() {
+        let mut config = ProjectConfiguration::default();
+        config
+            .effect_params
+            .extend((0..16).map(|i| (format!("effect-{i}"), vec![i as f32])));
+        let hash = config_hash(&config);
+
+        let mut edited = config.clone();
+        edited.audio.mute = !edited.audio.mute;
+        edited.export_directory = Some(PathBuf::from("exports"));
+        edited.effect_params = config.effect_params.clone().into_iter().rev().collect();
+
+        assert_eq!(config_hash(&edited), hash);
+    }
+
+    #[test]
+    fn fnv1a_matches_the_reference() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use cap_editor::{AudioRenderer, SegmentMedia, get_audio_segments};
+use cap_project::ProjectConfiguration;
+use cap_utils::atomic_file::AtomicFile;
+
+use crate::click_sounds::ClickSoundMixer;
+
+pub(crate) struct AudioStems {
+    stems: Vec<Stem>,
+}
+
+struct Stem {
+    source: StemSource,
+    file: AtomicFile,
+    writer: WavWriter,
+}
+
+enum StemSource {
+    Track {
+        renderer: AudioRenderer,
+        project: ProjectConfiguration,
+    },
+    Clicks(ClickSoundMixer),
+}
+
+impl AudioStems {
+    pub fn new(
+        target: &Path,
+        project: &ProjectConfiguration,
+        segments: &[SegmentMedia],
+    ) -> io::Result<Option<Self>> {
+        let mut sources = Vec::new();
+
+        if !project.audio.mute {
+            let audio_segments = get_audio_segments(segments);
+
+            let mut track = |name: &'static str, mute_others: fn(&mut ProjectConfiguration)| {
+                let mut project = project.clone();
+                mute_others(&mut project);
+                let mut renderer = AudioRenderer::new(audio_segments.clone());
+                renderer.set_playhead(0.0, &project);
+                sources.push((name, StemSource::Track { renderer, project }));
+            };
+
+            if segments.iter().any(|s| s.audio.is_some()) {
+                track("mic", |p| p.audio.system_volume_db = f32::NEG_INFINITY);
+            }
+            if segments.iter().any(|s| s.system_audio.is_some()) {
+                track("system-audio", |p| {
+                    p.audio.mic_volume_db = f32::NEG_INFINITY
+                });
+            }
+        }
+
+        if let Some(clicks) = ClickSoundMixer::new(project, segments) {
+            sources.push(("clicks", StemSource::Clicks(clicks)));
+        }
+
+        if sources.is_empty() {
+            return Ok(None);
+        }
+
+        let stems = sources
+            .into_iter()
+            .map(|(name, source)| {
+                let file = AtomicFile::new(stem_path(target, name))?;
+                let writer = WavWriter::create(file.path())?;
+                Ok(Stem {
+                    source,
+                    file,
+                    writer,
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Some(Self { stems }))
+    }
+
+    pub fn write_silence(&mut self, samples: usize) -> io::Result<()> {
+        let silence = vec![0.0; samples * usize::from(AudioRenderer::CHANNELS)];
+        for stem in &mut self.stems {
+            stem.writer.write(&silence)?;
+        }
+        Ok(())
+    }
+
+    pub fn render(&mut self, start: u64, samples: usize) -> io::Result<()> {
+        let len = samples * usize::from(AudioRenderer::CHANNELS);
+
+        for stem in &mut self.stems {
+            let data = match &mut stem.source {
+                StemSource::Track { renderer, project } => {
+                    let mut data = renderer
+                        .render_frame_raw(samples, project)
+                        .map(|(_, data)| data)
+                        .unwrap_or_default();
+                    data.resize(len, 0.0);
+                    data
+                }
+                StemSource::Clicks(clicks) => {
+                    let mut data = vec![0.0; len];
+                    clicks.mix(start, &mut data);
+                    data
+                }
+            };
+            stem.writer.write(&data)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> io::Result<Vec<PathBuf>> {
+        self.stems
+            .into_iter()
+            .map(|stem| {
+                stem.writer.finish()?;
+                stem.file.commit()
+            })
+            .collect()
+    }
+}
+
+fn stem_path(target: &Path, name: &str) -> PathBuf {
+    let video_name = target
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!("{video_name}.{name}.wav"))
+}
+
+struct WavWriter {
+    writer: BufWriter<File>,
+    data_len: u32,
+}
+
+impl WavWriter {
+    const HEADER_LEN: u32 = 44;
+
+    fn create(path: &Path) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        let channels = AudioRenderer::CHANNELS;
+        let block_align = channels * 4;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&3u16.to_le_bytes())?;
+        writer.write_all(&channels.to_le_bytes())?;
+        writer.write_all(&AudioRenderer::SAMPLE_RATE.to_le_bytes())?;
+        writer.write_all(&(AudioRenderer::SAMPLE_RATE * u32::from(block_align)).to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&32u16.to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        Ok(Self {
+            writer,
+            data_len: 0,
+        })
+    }
+
+    fn write(&mut self, samples: &[f32]) -> io::Result<()> {
+        for sample in samples {
+            self.writer.write_all(&sample.to_le_bytes())?;
+        }
+        self.data_len = self
+            .data_len
+            .checked_add((samples.len() * 4) as u32)
+            .ok_or_else(|| io::Error::other("Audio stem exceeds the WAV size limit"))?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        self.writer.seek(SeekFrom::Start(4))?;
+        self.writer
+            .write_all(&(Self::HEADER_LEN - 8 + self.data_len).to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(40))?;
+        self.writer.write_all(&self.data_len.to_le_bytes())?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stems_are_named_after_the_video() {
+        assert_eq!(
+            stem_path(Path::new("/exports/My Recording.mp4"), "mic"),
+            Path::new("/exports/My Recording.mic.wav")
+        );
+    }
+
+    #[test]
+    fn wav_sizes_are_patched_on_finish() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stem.wav");
+
+        let mut writer = WavWriter::create(&path).unwrap();
+        writer.write(&[0.5; 8]).unwrap();
+        writer.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(bytes.len(), 44 + 32);
+        assert_eq!(&bytes[4..8], &(36u32 + 32).to_le_bytes());
+        assert_eq!(&bytes[40..44], &32u32.to_le_bytes());
+        assert_eq!(&bytes[44..48], &0.5f32.to_le_bytes());
+    }
+}
@@ -0,0 +1,129 @@
+use cap_rendering::FrameTimings;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+const WINDOW_FRAMES: usize = 60;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimingBreakdown {
+    pub decode_us: u32,
+    pub composite_us: u32,
+    pub readback_us: u32,
+    pub encode_us: u32,
+    pub fps: f64,
+    pub eta_secs: Option<f64>,
+}
+
+#[derive(Clone, Default)]
+pub struct ExportTimings(Arc<Mutex<Window>>);
+
+#[derive(Default)]
+struct Window {
+    frames: VecDeque<(Instant, FrameTimings)>,
+    encodes: VecDeque<Duration>,
+}
+
+impl ExportTimings {
+    pub fn record_frame(&self, timings: FrameTimings) {
+        self.lock().record_frame(Instant::now(), timings);
+    }
+
+    pub fn record_encode(&self, elapsed: Duration) {
+        let mut window = self.lock();
+        window.encodes.push_back(elapsed);
+        if window.encodes.len() > WINDOW_FRAMES {
+            window.encodes.pop_front();
+        }
+    }
+
+    pub fn breakdown(&self, remaining_frames: u32) -> TimingBreakdown {
+        self.lock().breakdown(remaining_frames)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Window> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Window {
+    fn record_frame(&mut self, at: Instant, timings: FrameTimings) {
+        self.frames.push_back((at, timings));
+        if self.frames.len() > WINDOW_FRAMES {
+            self.frames.pop_front();
+        }
+    }
+
+    fn breakdown(&self, remaining_frames: u32) -> TimingBreakdown {
+        let average = |total: Duration, count: usize| {
+            (total.as_micros() / count.max(1) as u128).min(u32::MAX as u128) as u32
+        };
+        let stage = |f: fn(&FrameTimings) -> Duration| {
+            average(
+                self.frames.iter().map(|(_, timings)| f(timings)).sum(),
+                self.frames.len(),
+            )
+        };
+
+        let fps = match (self.frames.front(), self.frames.back()) {
+            (Some((first, _)), Some((last, _))) if last > first => {
+                (self.frames.len() - 1) as f64 / last.duration_since(*first).as_secs_f64()
+            }
+            _ => 0.0,
+        };
+
+        TimingBreakdown {
+            decode_us: stage(|t| t.decode),
+            composite_us: stage(|t| t.composite),
+            readback_us: stage(|t| t.readback),
+            encode_us: average(self.encodes.iter().sum(), self.encodes.len()),
+            fps,
+            eta_secs: (fps > 0.0).then(|| f64::from(remaining_frames) / fps),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_averages_the_latest_frames() {
+        let start = Instant::now();
+        let mut window = Window::default();
+
+        for i in 0..(WINDOW_FRAMES as u64 + 10) {
+            let decode = if i < 10 { 10_000 } else { 2_000 };
+            window.record_frame(
+                start + Duration::from_millis(i * 20),
+                FrameTimings {
+                    decode: Duration::from_micros(decode),
+                    composite: Duration::from_micros(4_000),
+                    readback: Duration::from_micros(1_000),
+                },
+            );
+        }
+
+        let breakdown = window.breakdown(100);
+
+        assert_eq!(breakdown.decode_us, 2_000);
+        assert_eq!(breakdown.composite_us, 4_000);
+        assert_eq!(breakdown.readback_us, 1_000);
+        assert_eq!(breakdown.encode_us, 0);
+        assert!((breakdown.fps - 50.0).abs() < 1e-6);
+        assert!((breakdown.eta_secs.unwrap() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn breakdown_has_no_eta_before_two_frames() {
+        let mut window = Window::default();
+        window.record_frame(Instant::now(), FrameTimings::default());
+
+        let breakdown = window.breakdown(100);
+
+        assert_eq!(breakdown.fps, 0.0);
+        assert_eq!(breakdown.eta_secs, None);
+    }
+}
@@ -27,6 +27,11 @@ async fn run_export(project_path: PathBuf) -> Result<(PathBuf, Duration, u32), S
         compression: ExportCompression::Maximum,
         custom_bpp: None,
         force_ffmpeg_decoder: false,
+        lead_in_ms: 0,
+        lead_out_ms: 0,
+        audio_stems: false,
+        timelapse: None,
+        frame_interpolation: false,
     };
 
     let start = Instant::now();
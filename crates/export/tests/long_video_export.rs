@@ -133,6 +133,11 @@ async fn run_export(project_path: PathBuf, fps: u32) -> Result<(PathBuf, Duratio
         compression: ExportCompression::Potato,
         custom_bpp: None,
         force_ffmpeg_decoder: false,
+        lead_in_ms: 0,
+        lead_out_ms: 0,
+        audio_stems: false,
+        timelapse: None,
+        frame_interpolation: false,
     };
 
     let total_frames = exporter_base.total_frames(fps);
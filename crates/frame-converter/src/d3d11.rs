@@ -35,6 +35,7 @@ use windows::{
                 CreateDXGIFactory1, DXGI_SHARED_RESOURCE_READ, DXGI_SHARED_RESOURCE_WRITE,
                 IDXGIAdapter, IDXGIDevice, IDXGIFactory1, IDXGIResource1,
             },
+            Gdi::HMONITOR,
         },
     },
     core::Interface,
@@ -214,6 +215,38 @@ fn enumerate_all_gpus() -> Vec<GpuInfo> {
     gpus
 }
 
+pub fn adapter_for_monitor(monitor: HMONITOR) -> Option<GpuInfo> {
+    let adapter_index = unsafe {
+        let factory: IDXGIFactory1 = CreateDXGIFactory1().ok()?;
+
+        let mut adapter_index = 0u32;
+        loop {
+            let adapter: IDXGIAdapter = factory.EnumAdapters(adapter_index).ok()?;
+
+            let mut output_index = 0u32;
+            let owns_monitor = loop {
+                let Ok(output) = adapter.EnumOutputs(output_index) else {
+                    break false;
+                };
+                if output.GetDesc().is_ok_and(|desc| desc.Monitor == monitor) {
+                    break true;
+                }
+                output_index += 1;
+            };
+
+            if owns_monitor {
+                break adapter_index;
+            }
+            adapter_index += 1;
+        }
+    };
+
+    get_all_gpus()
+        .iter()
+        .find(|gpu| gpu.adapter_index == adapter_index)
+        .cloned()
+}
+
 fn select_best_gpu(gpus: &[GpuInfo]) -> Option<GpuInfo> {
     if gpus.is_empty() {
         return None;
@@ -0,0 +1,223 @@
+use serde::Serialize;
+use specta::Type;
+
+use crate::{CursorEvents, CursorMoveEvent, ProjectConfiguration, XY};
+
+pub const ANALYSIS_GRID_SIZE: u32 = 8;
+
+pub struct AnalysisSegment<'a> {
+    pub cursor: &'a CursorEvents,
+    pub duration_secs: f64,
+    pub display_size: XY<u32>,
+}
+
+#[derive(Serialize, Type, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingAnalysis {
+    pub duration_secs: f64,
+    pub click_count: u32,
+    pub clicks_per_minute: f64,
+    pub cursor_travel_px: f64,
+    pub grid_size: u32,
+    pub click_density: Vec<u32>,
+    pub dwell_secs: Vec<f64>,
+    pub clicks: Vec<XY<f64>>,
+    pub zoom: ZoomCoverage,
+}
+
+#[derive(Serialize, Type, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoomCoverage {
+    pub segment_count: u32,
+    pub zoomed_secs: f64,
+    pub coverage: f64,
+}
+
+pub fn analyze(segments: &[AnalysisSegment], project: &ProjectConfiguration) -> RecordingAnalysis {
+    let cells = (ANALYSIS_GRID_SIZE * ANALYSIS_GRID_SIZE) as usize;
+    let mut click_density = vec![0u32; cells];
+    let mut dwell_secs = vec![0.0; cells];
+    let mut clicks = vec![];
+    let mut cursor_travel_px = 0.0;
+
+    for segment in segments {
+        let moves = &segment.cursor.moves;
+        let size = XY::new(
+            f64::from(segment.display_size.x),
+            f64::from(segment.display_size.y),
+        );
+
+        for click in segment.cursor.clicks.iter().filter(|c| c.down) {
+            let Some(position) = position_at(moves, click.time_ms) else {
+                continue;
+            };
+            if let Some(cell) = grid_cell(position) {
+                click_density[cell] += 1;
+            }
+            clicks.push(position);
+        }
+
+        for pair in moves.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+
+            cursor_travel_px += ((to.x - from.x) * size.x).hypot((to.y - from.y) * size.y);
+
+            if let Some(cell) = grid_cell(XY::new(from.x, from.y)) {
+                dwell_secs[cell] += (to.time_ms - from.time_ms).max(0.0) / 1000.0;
+            }
+        }
+
+        if let Some(last) = moves.last()
+            && let Some(cell) = grid_cell(XY::new(last.x, last.y))
+        {
+            dwell_secs[cell] += (segment.duration_secs - last.time_ms / 1000.0).max(0.0);
+        }
+    }
+
+    let duration_secs = segments.iter().map(|s| s.duration_secs).sum::<f64>();
+    let click_count = clicks.len() as u32;
+
+    RecordingAnalysis {
+        duration_secs,
+        click_count,
+        clicks_per_minute: if duration_secs > 0.0 {
+            f64::from(click_count) / duration_secs * 60.0
+        } else {
+            0.0
+        },
+        cursor_travel_px,
+        grid_size: ANALYSIS_GRID_SIZE,
+        click_density,
+        dwell_secs,
+        clicks,
+        zoom: zoom_coverage(project, duration_secs),
+    }
+}
+
+fn zoom_coverage(project: &ProjectConfiguration, recording_duration: f64) -> ZoomCoverage {
+    let Some(timeline) = &project.timeline else {
+        return ZoomCoverage::default();
+    };
+
+    let mut ranges = timeline
+        .zoom_segments
+        .iter()
+        .map(|z| (z.start, z.end))
+        .collect::<Vec<_>>();
+    ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f64, f64)> = vec![];
+    for (start, end) in ranges {
+        if let Some(last) = merged.last_mut()
+            && start <= last.1
+        {
+            last.1 = last.1.max(end);
+        } else {
+            merged.push((start, end));
+        }
+    }
+    let zoomed_secs = merged.iter().map(|(start, end)| end - start).sum::<f64>();
+
+    let duration = if timeline.segments.is_empty() {
+        recording_duration
+    } else {
+        timeline.duration()
+    };
+
+    ZoomCoverage {
+        segment_count: timeline.zoom_segments.len() as u32,
+        zoomed_secs,
+        coverage: if duration > 0.0 {
+            (zoomed_secs / duration).clamp(0.0, 1.0)
+        } else {
+            0.0
+        },
+    }
+}
+
+fn position_at(moves: &[CursorMoveEvent], time_ms: f64) -> Option<XY<f64>> {
+    let index = moves.partition_point(|m| m.time_ms <= time_ms);
+    let event = index
+        .checked_sub(1)
+        .and_then(|i| moves.get(i))
+        .or(moves.first())?;
+    Some(XY::new(event.x, event.y))
+}
+
+fn grid_cell(position: XY<f64>) -> Option<usize> {
+    if !(0.0..=1.0).contains(&position.x) || !(0.0..=1.0).contains(&position.y) {
+        return None;
+    }
+
+    let max = ANALYSIS_GRID_SIZE - 1;
+    let col = ((position.x * f64::from(ANALYSIS_GRID_SIZE)) as u32).min(max);
+    let row = ((position.y * f64::from(ANALYSIS_GRID_SIZE)) as u32).min(max);
+    Some((row * ANALYSIS_GRID_SIZE + col) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CursorClickEvent;
+
+    fn move_event(time_ms: f64, x: f64, y: f64) -> CursorMoveEvent {
+        CursorMoveEvent {
+            active_modifiers: vec![],
+            cursor_id: "0".into(),
+            time_ms,
+            x,
+            y,
+        }
+    }
+
+    fn click_event(time_ms: f64, down: bool) -> CursorClickEvent {
+        CursorClickEvent {
+            active_modifiers: vec![],
+            cursor_num: 0,
+            cursor_id: "0".into(),
+            time_ms,
+            down,
+        }
+    }
+
+    #[test]
+    fn aggregates_clicks_travel_and_dwell() {
+        let cursor = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
+            moves: vec![move_event(0.0, 0.05, 0.05), move_event(1000.0, 0.95, 0.05)],
+            clicks: vec![
+                click_event(500.0, true),
+                click_event(600.0, false),
+                click_event(1500.0, true),
+            ],
+        };
+        let segments = [AnalysisSegment {
+            cursor: &cursor,
+            duration_secs: 3.0,
+            display_size: XY::new(1000, 500),
+        }];
+
+        let report = analyze(&segments, &ProjectConfiguration::default());
+
+        assert_eq!(report.click_count, 2);
+        assert!((report.clicks_per_minute - 40.0).abs() < 1e-9);
+        assert!((report.cursor_travel_px - 900.0).abs() < 1e-9);
+        assert_eq!(report.click_density[0], 1);
+        assert_eq!(report.click_density[7], 1);
+        assert_eq!(report.dwell_secs[0], 1.0);
+        assert_eq!(report.dwell_secs[7], 2.0);
+        assert_eq!(report.zoom.segment_count, 0);
+    }
+
+    #[test]
+    fn grid_cell_clamps_right_and_bottom_edges() {
+        assert_eq!(grid_cell(XY::new(0.0, 0.0)), Some(0));
+        assert_eq!(
+            grid_cell(XY::new(1.0, 1.0)),
+            Some((ANALYSIS_GRID_SIZE * ANALYSIS_GRID_SIZE - 1) as usize)
+        );
+        assert_eq!(grid_cell(XY::new(-0.1, 0.5)), None);
+    }
+}
@@ -1,8 +1,9 @@
 use std::{
+    collections::HashMap,
     env::temp_dir,
     fmt,
     ops::{Add, Div, Mul, Sub, SubAssign},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use serde::{Deserialize, Serialize};
@@ -20,6 +21,30 @@ pub enum AspectRatio {
     Tall,
 }
 
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FitMode {
+    #[default]
+    Contain,
+    Cover,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DisplayFit {
+    pub mode: FitMode,
+    pub anchor: XY<f64>,
+}
+
+impl Default for DisplayFit {
+    fn default() -> Self {
+        Self {
+            mode: FitMode::default(),
+            anchor: XY::new(0.5, 0.5),
+        }
+    }
+}
+
 pub type Color = [u16; 3];
 
 #[derive(Type, Serialize, Deserialize, Clone, Debug)]
@@ -242,6 +267,41 @@ pub struct BackgroundConfiguration {
     pub shadow: f32,
     pub advanced_shadow: Option<ShadowConfiguration>,
     pub border: Option<BorderConfiguration>,
+    pub zoom_dim: f32,
+    pub fit: DisplayFit,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundKeyframe {
+    pub start_ms: f64,
+    pub config: BackgroundConfiguration,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutKeyframe {
+    pub start_ms: f64,
+    pub padding: f64,
+    pub rounding: f64,
+    pub shadow: f32,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfettiBurst {
+    pub time: f64,
+    pub origin: XY<f64>,
+    #[serde(default = "ConfettiBurst::default_count")]
+    pub count: u32,
+}
+
+impl ConfettiBurst {
+    pub const DEFAULT_COUNT: u32 = 120;
+
+    fn default_count() -> u32 {
+        Self::DEFAULT_COUNT
+    }
 }
 
 impl Default for BorderConfiguration {
@@ -268,6 +328,8 @@ impl Default for BackgroundConfiguration {
             shadow: 73.6,
             advanced_shadow: Some(ShadowConfiguration::default()),
             border: None, // Border is disabled by default for backwards compatibility
+            zoom_dim: 0.0,
+            fit: DisplayFit::default(),
         }
     }
 }
@@ -387,6 +449,7 @@ pub struct AudioConfiguration {
     pub mic_volume_db: f32,
     pub mic_stereo_mode: StereoMode,
     pub system_volume_db: f32,
+    pub click_sounds: ClickSoundsConfiguration,
 }
 
 impl Default for AudioConfiguration {
@@ -397,6 +460,33 @@ impl Default for AudioConfiguration {
             mic_volume_db: 0.0,
             mic_stereo_mode: StereoMode::default(),
             system_volume_db: 0.0,
+            click_sounds: ClickSoundsConfiguration::default(),
+        }
+    }
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ClickSound {
+    #[default]
+    Soft,
+    Crisp,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ClickSoundsConfiguration {
+    pub enabled: bool,
+    pub sound: ClickSound,
+    pub volume: f32,
+}
+
+impl Default for ClickSoundsConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sound: ClickSound::default(),
+            volume: 0.5,
         }
     }
 }
@@ -453,13 +543,17 @@ pub struct ScreenMovementSpring {
     pub mass: f32,
 }
 
+impl ScreenMovementSpring {
+    pub const DEFAULT: Self = Self {
+        stiffness: 200.0,
+        damping: 40.0,
+        mass: 2.25,
+    };
+}
+
 impl Default for ScreenMovementSpring {
     fn default() -> Self {
-        Self {
-            stiffness: 200.0,
-            damping: 40.0,
-            mass: 2.25,
-        }
+        Self::DEFAULT
     }
 }
 
@@ -555,10 +649,60 @@ impl CursorConfiguration {
     }
 }
 
-#[derive(Type, Serialize, Deserialize, Clone, Debug, Default)]
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MagnifierConfiguration {
+    pub enabled: bool,
+    pub amount: f64,
+    pub size: f64,
+    pub position: CameraPosition,
+}
+
+impl Default for MagnifierConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            amount: 2.0,
+            size: 35.0,
+            position: CameraPosition {
+                x: CameraXPosition::Right,
+                y: CameraYPosition::Top,
+            },
+        }
+    }
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum KeystrokeStyle {
+    #[default]
+    Shortcuts,
+    Typing,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase", default)]
 pub struct HotkeysConfiguration {
-    show: bool,
+    pub show: bool,
+    pub style: KeystrokeStyle,
+    pub shortcuts_only: bool,
+    pub size: f64,
+    pub position: CameraPosition,
+}
+
+impl Default for HotkeysConfiguration {
+    fn default() -> Self {
+        Self {
+            show: false,
+            style: KeystrokeStyle::Shortcuts,
+            shortcuts_only: true,
+            size: 3.5,
+            position: CameraPosition {
+                x: CameraXPosition::Center,
+                y: CameraYPosition::Bottom,
+            },
+        }
+    }
 }
 
 #[derive(Type, Serialize, Deserialize, Clone, Debug, Default)]
@@ -612,6 +756,10 @@ pub struct ZoomSegment {
     pub instant_animation: bool,
     #[serde(default = "ZoomSegment::default_edge_snap_ratio")]
     pub edge_snap_ratio: f64,
+    #[serde(default)]
+    pub zoom_from_click: bool,
+    #[serde(default)]
+    pub easing: ZoomEasing,
 }
 
 impl ZoomSegment {
@@ -631,6 +779,20 @@ pub enum ZoomMode {
     Manual { x: f32, y: f32 },
 }
 
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ZoomEasing {
+    #[default]
+    Spring,
+    Instant,
+    CubicBezier {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    },
+}
+
 #[derive(Type, Serialize, Deserialize, Clone, Copy, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum MaskKind {
@@ -771,7 +933,110 @@ impl TextSegment {
     }
 }
 
-#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default)]
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReactionAnimation {
+    None,
+    #[default]
+    Pop,
+    SlideUp,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionSegment {
+    pub start: f64,
+    pub end: f64,
+    #[serde(default = "ReactionSegment::default_emoji")]
+    pub emoji: String,
+    #[serde(default = "ReactionSegment::default_center")]
+    pub center: XY<f64>,
+    #[serde(default = "ReactionSegment::default_scale")]
+    pub scale: f64,
+    #[serde(default)]
+    pub animation: ReactionAnimation,
+}
+
+impl ReactionSegment {
+    fn default_emoji() -> String {
+        "🎉".to_string()
+    }
+
+    fn default_center() -> XY<f64> {
+        XY::new(0.85, 0.2)
+    }
+
+    fn default_scale() -> f64 {
+        0.15
+    }
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HiddenCursorSegment {
+    pub start: f64,
+    pub end: f64,
+    #[serde(default = "HiddenCursorSegment::default_fade_duration")]
+    pub fade_duration: f64,
+}
+
+impl HiddenCursorSegment {
+    fn default_fade_duration() -> f64 {
+        0.3
+    }
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CardConfiguration {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub subtitle: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default = "CardConfiguration::default_duration")]
+    pub duration: f64,
+    #[serde(default)]
+    pub background: Option<BackgroundSource>,
+    #[serde(default = "CardConfiguration::default_text_color")]
+    pub text_color: String,
+    #[serde(default = "TextSegment::default_font_family")]
+    pub font_family: String,
+}
+
+impl CardConfiguration {
+    fn default_duration() -> f64 {
+        2.0
+    }
+
+    fn default_text_color() -> String {
+        "#ffffff".to_string()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.duration > 0.0
+    }
+}
+
+impl Default for CardConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            title: None,
+            subtitle: None,
+            author: None,
+            duration: Self::default_duration(),
+            background: None,
+            text_color: Self::default_text_color(),
+            font_family: TextSegment::default_font_family(),
+        }
+    }
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum SceneMode {
     #[default]
@@ -789,6 +1054,14 @@ pub struct SceneSegment {
     pub mode: SceneMode,
 }
 
+#[derive(Type, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineMarker {
+    pub time: f64,
+    #[serde(default)]
+    pub label: String,
+}
+
 #[derive(Type, Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TimelineConfiguration {
@@ -800,6 +1073,12 @@ pub struct TimelineConfiguration {
     pub mask_segments: Vec<MaskSegment>,
     #[serde(default)]
     pub text_segments: Vec<TextSegment>,
+    #[serde(default)]
+    pub reaction_segments: Vec<ReactionSegment>,
+    #[serde(default)]
+    pub cursor_segments: Vec<HiddenCursorSegment>,
+    #[serde(default)]
+    pub markers: Vec<TimelineMarker>,
 }
 
 impl TimelineConfiguration {
@@ -822,6 +1101,11 @@ impl TimelineConfiguration {
     pub fn duration(&self) -> f64 {
         self.segments.iter().map(|s| s.duration()).sum()
     }
+
+    pub fn insert_marker(&mut self, marker: TimelineMarker) {
+        let index = self.markers.partition_point(|m| m.time <= marker.time);
+        self.markers.insert(index, marker);
+    }
 }
 
 pub const WALLPAPERS_PATH: &str = "assets/backgrounds/macOS";
@@ -1089,9 +1373,19 @@ impl Annotation {
 pub struct ProjectConfiguration {
     pub aspect_ratio: Option<AspectRatio>,
     pub background: BackgroundConfiguration,
+    #[serde(default)]
+    pub background_keyframes: Vec<BackgroundKeyframe>,
+    #[serde(default)]
+    pub layout_keyframes: Vec<LayoutKeyframe>,
+    #[serde(default)]
+    pub confetti: Vec<ConfettiBurst>,
+    #[serde(default)]
+    pub effect_params: HashMap<String, Vec<f32>>,
     pub camera: Camera,
     pub audio: AudioConfiguration,
     pub cursor: CursorConfiguration,
+    #[serde(default)]
+    pub magnifier: MagnifierConfiguration,
     pub hotkeys: HotkeysConfiguration,
     pub timeline: Option<TimelineConfiguration>,
     pub captions: Option<CaptionsData>,
@@ -1103,6 +1397,10 @@ pub struct ProjectConfiguration {
     pub screen_motion_blur: f32,
     #[serde(default)]
     pub screen_movement_spring: ScreenMovementSpring,
+    pub title_card: Option<CardConfiguration>,
+    pub outro_card: Option<CardConfiguration>,
+    #[serde(default)]
+    pub export_directory: Option<PathBuf>,
 }
 
 fn camera_config_needs_migration(value: &Value) -> bool {
@@ -1129,6 +1427,32 @@ impl ProjectConfiguration {
         Ok(())
     }
 
+    pub fn timeline_times(&self, recording_clip: u32, clip_time: f64) -> Vec<f64> {
+        let Some(timeline) = &self.timeline else {
+            return if recording_clip == 0 {
+                vec![clip_time]
+            } else {
+                vec![]
+            };
+        };
+
+        let mut times = vec![];
+        let mut accum_duration = 0.0;
+
+        for segment in &timeline.segments {
+            if segment.recording_clip == recording_clip
+                && clip_time >= segment.start
+                && clip_time < segment.end
+            {
+                times.push(accum_duration + (clip_time - segment.start) / segment.timescale);
+            }
+
+            accum_duration += segment.duration();
+        }
+
+        times
+    }
+
     pub fn load(project_path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
         let project_path = project_path.as_ref();
         let config_path = project_path.join("project-config.json");
@@ -1189,3 +1513,42 @@ pub const FAST_SMOOTHING_SAMPLES: usize = 10;
 pub const SLOW_VELOCITY_THRESHOLD: f64 = 0.003;
 pub const REGULAR_VELOCITY_THRESHOLD: f64 = 0.008;
 pub const FAST_VELOCITY_THRESHOLD: f64 = 0.015;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_clip_times_through_timeline() {
+        let project = ProjectConfiguration {
+            timeline: Some(TimelineConfiguration {
+                segments: vec![
+                    TimelineSegment {
+                        recording_clip: 0,
+                        timescale: 1.0,
+                        start: 2.0,
+                        end: 4.0,
+                    },
+                    TimelineSegment {
+                        recording_clip: 0,
+                        timescale: 2.0,
+                        start: 0.0,
+                        end: 4.0,
+                    },
+                ],
+                zoom_segments: vec![],
+                scene_segments: vec![],
+                mask_segments: vec![],
+                text_segments: vec![],
+                reaction_segments: vec![],
+                cursor_segments: vec![],
+                markers: vec![],
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(project.timeline_times(0, 3.0), vec![1.0, 3.5]);
+        assert_eq!(project.timeline_times(0, 1.0), vec![2.5]);
+        assert!(project.timeline_times(1, 3.0).is_empty());
+    }
+}
@@ -63,10 +63,58 @@ impl CursorData {
     }
 }
 
+pub const KEY_PRESS_BUCKET_MS: f64 = 1000.0;
+
+#[derive(Serialize, Deserialize, Clone, Type, Debug, PartialEq)]
+pub struct KeyPressBucket {
+    pub time_ms: f64,
+    pub presses: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Type, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum KeystrokeCapture {
+    #[default]
+    Off,
+    Masked,
+    Full,
+}
+
+pub const MASKED_KEY: &str = "•";
+
+#[derive(Serialize, Deserialize, Clone, Type, Debug, PartialEq)]
+pub struct KeyEvent {
+    pub key: String,
+    pub time_ms: f64,
+    pub down: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Type, Debug, PartialEq)]
+pub struct WindowFocusBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Type, Debug, PartialEq)]
+pub struct WindowFocusEvent {
+    pub time_ms: f64,
+    pub app_name: Option<String>,
+    pub title: Option<String>,
+    pub bounds: Option<WindowFocusBounds>,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct CursorEvents {
     pub clicks: Vec<CursorClickEvent>,
     pub moves: Vec<CursorMoveEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_presses: Vec<KeyPressBucket>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<KeyEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub focus_changes: Vec<WindowFocusEvent>,
 }
 
 impl CursorEvents {
@@ -75,6 +123,21 @@ impl CursorEvents {
         serde_json::from_reader(file).map_err(|e| format!("Failed to parse cursor data: {e}"))
     }
 
+    pub fn keys_down_at(&self, time_ms: f64) -> Vec<&str> {
+        let mut held: Vec<&str> = Vec::new();
+        for event in self
+            .keys
+            .iter()
+            .take_while(|event| event.time_ms <= time_ms)
+        {
+            held.retain(|key| *key != event.key);
+            if event.down {
+                held.push(&event.key);
+            }
+        }
+        held
+    }
+
     pub fn stabilize_short_lived_cursor_shapes(
         &mut self,
         pointer_ids: Option<&HashSet<String>>,
@@ -262,6 +325,9 @@ impl From<CursorData> for CursorEvents {
         Self {
             clicks: value.clicks,
             moves: value.moves,
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
         }
     }
 }
@@ -305,6 +371,9 @@ mod tests {
         pointer_ids.insert("pointer".to_string());
 
         let mut events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             moves: vec![
                 move_event(0.0, "pointer"),
                 move_event(200.0, "ibeam"),
@@ -339,6 +408,9 @@ mod tests {
         pointer_ids.insert("pointer".to_string());
 
         let mut events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             moves: vec![
                 move_event(0.0, "pointer"),
                 move_event(200.0, "ibeam"),
@@ -359,6 +431,9 @@ mod tests {
     #[test]
     fn falls_back_to_dominant_cursor_without_pointer_metadata() {
         let mut events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             moves: vec![
                 move_event(0.0, "pointer"),
                 move_event(200.0, "ibeam"),
@@ -383,4 +458,33 @@ mod tests {
                 .all(|event| event.cursor_id == "pointer")
         );
     }
+
+    fn key_event(time_ms: f64, key: &str, down: bool) -> KeyEvent {
+        KeyEvent {
+            key: key.to_string(),
+            time_ms,
+            down,
+        }
+    }
+
+    #[test]
+    fn keys_down_follow_presses_and_releases() {
+        let events = CursorEvents {
+            keys: vec![
+                key_event(100.0, "LControl", true),
+                key_event(150.0, "C", true),
+                key_event(200.0, "C", false),
+                key_event(250.0, "V", true),
+                key_event(300.0, "LControl", false),
+                key_event(320.0, "V", false),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(events.keys_down_at(50.0), Vec::<&str>::new());
+        assert_eq!(events.keys_down_at(160.0), vec!["LControl", "C"]);
+        assert_eq!(events.keys_down_at(260.0), vec!["LControl", "V"]);
+        assert_eq!(events.keys_down_at(310.0), vec!["V"]);
+        assert!(events.keys_down_at(400.0).is_empty());
+    }
 }
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::CursorEvents;
+
+pub const DEFAULT_MIN_IDLE_SECS: f64 = 3.0;
+const IDLE_PADDING_SECS: f64 = 0.5;
+const MOVE_THRESHOLD: f64 = 0.002;
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleRange {
+    #[serde(rename = "recordingSegment")]
+    pub recording_clip: u32,
+    pub start: f64,
+    pub end: f64,
+}
+
+pub fn cursor_activity(cursor: &CursorEvents) -> Vec<f64> {
+    let clicks = cursor.clicks.iter().map(|c| c.time_ms / 1000.0);
+
+    let moves = cursor.moves.windows(2).filter_map(|pair| {
+        let (from, to) = (&pair[0], &pair[1]);
+        ((to.x - from.x).hypot(to.y - from.y) > MOVE_THRESHOLD).then_some(to.time_ms / 1000.0)
+    });
+
+    let key_presses = cursor
+        .key_presses
+        .iter()
+        .filter(|bucket| bucket.presses > 0)
+        .map(|bucket| bucket.time_ms / 1000.0);
+
+    let keys = cursor
+        .keys
+        .iter()
+        .filter(|event| event.down)
+        .map(|event| event.time_ms / 1000.0);
+
+    clicks.chain(moves).chain(key_presses).chain(keys).collect()
+}
+
+pub fn detect_idle_ranges(
+    recording_clip: u32,
+    mut activity: Vec<f64>,
+    duration_secs: f64,
+    min_idle_secs: f64,
+) -> Vec<IdleRange> {
+    activity.retain(|t| t.is_finite());
+    activity.sort_by(f64::total_cmp);
+
+    let mut bounds = Vec::with_capacity(activity.len() + 2);
+    bounds.push((0.0, 0.0));
+    bounds.extend(
+        activity
+            .into_iter()
+            .map(|t| (t.clamp(0.0, duration_secs), IDLE_PADDING_SECS)),
+    );
+    bounds.push((duration_secs, 0.0));
+
+    bounds
+        .windows(2)
+        .filter_map(|pair| {
+            let ((from, from_padding), (to, to_padding)) = (pair[0], pair[1]);
+            let (start, end) = (from + from_padding, to - to_padding);
+
+            (end - start >= min_idle_secs).then_some(IdleRange {
+                recording_clip,
+                start,
+                end,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CursorClickEvent, CursorMoveEvent, KeyPressBucket};
+
+    #[test]
+    fn finds_gaps_between_activity() {
+        let ranges = detect_idle_ranges(1, vec![10.0, 2.0, 11.0], 20.0, 3.0);
+
+        assert_eq!(
+            ranges,
+            vec![
+                IdleRange {
+                    recording_clip: 1,
+                    start: 2.5,
+                    end: 9.5,
+                },
+                IdleRange {
+                    recording_clip: 1,
+                    start: 11.5,
+                    end: 20.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn whole_clip_is_idle_without_activity() {
+        assert_eq!(
+            detect_idle_ranges(0, vec![], 5.0, 3.0),
+            vec![IdleRange {
+                recording_clip: 0,
+                start: 0.0,
+                end: 5.0,
+            }]
+        );
+        assert!(detect_idle_ranges(0, vec![], 2.0, 3.0).is_empty());
+    }
+
+    #[test]
+    fn cursor_activity_ignores_jitter_and_counts_key_presses() {
+        let move_event = |time_ms: f64, x: f64| CursorMoveEvent {
+            active_modifiers: vec![],
+            cursor_id: "0".into(),
+            time_ms,
+            x,
+            y: 0.5,
+        };
+        let cursor = CursorEvents {
+            moves: vec![
+                move_event(0.0, 0.5),
+                move_event(1000.0, 0.5005),
+                move_event(2000.0, 0.6),
+            ],
+            clicks: vec![CursorClickEvent {
+                active_modifiers: vec![],
+                cursor_num: 0,
+                cursor_id: "0".into(),
+                time_ms: 3000.0,
+                down: true,
+            }],
+            key_presses: vec![
+                KeyPressBucket {
+                    time_ms: 5000.0,
+                    presses: 3,
+                },
+                KeyPressBucket {
+                    time_ms: 6000.0,
+                    presses: 0,
+                },
+            ],
+            keys: vec![],
+            focus_changes: vec![],
+        };
+
+        assert_eq!(cursor_activity(&cursor), vec![3.0, 2.0, 5.0]);
+    }
+}
@@ -1,10 +1,17 @@
+pub mod analysis;
 mod configuration;
 pub mod cursor;
+pub mod idle;
 mod meta;
+mod parts;
+pub mod screen_text;
+pub mod sensitive;
+pub mod timelapse;
 
 pub use configuration::*;
 pub use cursor::*;
 pub use meta::*;
+pub use parts::*;
 
 use serde::{Deserialize, Serialize};
 use specta::Type;
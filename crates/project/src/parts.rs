@@ -0,0 +1,351 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::{ProjectConfiguration, TimelineConfiguration};
+
+const MIN_PART_SECS: f64 = 1.0;
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ExportSplit {
+    Duration { minutes: u32 },
+    Clips,
+    Markers,
+}
+
+impl ExportSplit {
+    pub fn ranges(&self, timeline: &TimelineConfiguration) -> Vec<(f64, f64)> {
+        let duration = timeline.duration();
+        if duration <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut boundaries = match self {
+            Self::Duration { minutes } => {
+                let length = f64::from((*minutes).max(1)) * 60.0;
+                (1..)
+                    .map(|n| n as f64 * length)
+                    .take_while(|t| *t < duration)
+                    .collect::<Vec<_>>()
+            }
+            Self::Clips => timeline
+                .segments
+                .iter()
+                .scan(0.0, |end, segment| {
+                    *end += segment.duration();
+                    Some(*end)
+                })
+                .filter(|t| *t < duration)
+                .collect(),
+            Self::Markers => {
+                let mut times = timeline
+                    .markers
+                    .iter()
+                    .map(|marker| marker.time)
+                    .filter(|t| *t > 0.0 && *t < duration)
+                    .collect::<Vec<_>>();
+                times.sort_by(f64::total_cmp);
+                times.dedup();
+                times
+            }
+        };
+        boundaries.push(duration);
+
+        let mut ranges: Vec<(f64, f64)> = Vec::new();
+        let mut start = 0.0;
+        for end in boundaries {
+            if end - start < MIN_PART_SECS
+                && let Some(last) = ranges.last_mut()
+            {
+                last.1 = end;
+            } else {
+                ranges.push((start, end));
+            }
+            start = end;
+        }
+        ranges
+    }
+}
+
+fn clip(start: f64, end: f64, range: (f64, f64)) -> Option<(f64, f64)> {
+    let clipped_start = start.max(range.0);
+    let clipped_end = end.min(range.1);
+    (clipped_end > clipped_start).then_some((clipped_start - range.0, clipped_end - range.0))
+}
+
+impl TimelineConfiguration {
+    pub fn slice(&self, range: (f64, f64)) -> Self {
+        let mut segments = Vec::new();
+        let mut timeline_start = 0.0;
+        for segment in &self.segments {
+            let duration = segment.duration();
+            if let Some((from, to)) = clip(timeline_start, timeline_start + duration, range) {
+                let offset = range.0 - timeline_start;
+                let mut segment = segment.clone();
+                let source_start = segment.start;
+                segment.start = source_start + (from + offset) * segment.timescale;
+                segment.end = source_start + (to + offset) * segment.timescale;
+                segments.push(segment);
+            }
+            timeline_start += duration;
+        }
+
+        Self {
+            segments,
+            zoom_segments: self
+                .zoom_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    Some(crate::ZoomSegment {
+                        start,
+                        end,
+                        ..s.clone()
+                    })
+                })
+                .collect(),
+            scene_segments: self
+                .scene_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    Some(crate::SceneSegment {
+                        start,
+                        end,
+                        ..s.clone()
+                    })
+                })
+                .collect(),
+            mask_segments: self
+                .mask_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    let cut = (range.0 - s.start).max(0.0);
+                    let mut segment = s.clone();
+                    segment.start = start;
+                    segment.end = end;
+                    for keyframe in segment
+                        .keyframes
+                        .position
+                        .iter_mut()
+                        .chain(segment.keyframes.size.iter_mut())
+                    {
+                        keyframe.time -= cut;
+                    }
+                    for keyframe in &mut segment.keyframes.intensity {
+                        keyframe.time -= cut;
+                    }
+                    Some(segment)
+                })
+                .collect(),
+            text_segments: self
+                .text_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    Some(crate::TextSegment {
+                        start,
+                        end,
+                        ..s.clone()
+                    })
+                })
+                .collect(),
+            reaction_segments: self
+                .reaction_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    Some(crate::ReactionSegment {
+                        start,
+                        end,
+                        ..s.clone()
+                    })
+                })
+                .collect(),
+            cursor_segments: self
+                .cursor_segments
+                .iter()
+                .filter_map(|s| {
+                    let (start, end) = clip(s.start, s.end, range)?;
+                    Some(crate::HiddenCursorSegment {
+                        start,
+                        end,
+                        ..s.clone()
+                    })
+                })
+                .collect(),
+            markers: self
+                .markers
+                .iter()
+                .filter(|m| m.time >= range.0 && m.time < range.1)
+                .map(|m| crate::TimelineMarker {
+                    time: m.time - range.0,
+                    ..m.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ProjectConfiguration {
+    pub fn slice(&self, range: (f64, f64)) -> Self {
+        let mut project = self.clone();
+        let Some(timeline) = &self.timeline else {
+            return project;
+        };
+        let offset_ms = range.0 * 1000.0;
+
+        if range.0 > 0.0 {
+            project.title_card = None;
+        }
+        if range.1 < timeline.duration() {
+            project.outro_card = None;
+        }
+
+        for keyframe in &mut project.background_keyframes {
+            keyframe.start_ms -= offset_ms;
+        }
+        for keyframe in &mut project.layout_keyframes {
+            keyframe.start_ms -= offset_ms;
+        }
+        for burst in &mut project.confetti {
+            burst.time -= range.0;
+        }
+
+        if let Some(captions) = &mut project.captions {
+            let offset = range.0 as f32;
+            captions.segments.retain_mut(|segment| {
+                let Some((start, end)) = clip(segment.start as f64, segment.end as f64, range)
+                else {
+                    return false;
+                };
+                segment.start = start as f32;
+                segment.end = end as f32;
+                for word in &mut segment.words {
+                    word.start -= offset;
+                    word.end -= offset;
+                }
+                true
+            });
+        }
+
+        project.timeline = Some(timeline.slice(range));
+        project
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TimelineSegment, ZoomSegment};
+
+    fn timeline(clips: &[(f64, f64, f64)]) -> TimelineConfiguration {
+        TimelineConfiguration {
+            segments: clips
+                .iter()
+                .map(|&(start, end, timescale)| TimelineSegment {
+                    recording_clip: 0,
+                    timescale,
+                    start,
+                    end,
+                })
+                .collect(),
+            zoom_segments: vec![],
+            scene_segments: vec![],
+            mask_segments: vec![],
+            text_segments: vec![],
+            reaction_segments: vec![],
+            cursor_segments: vec![],
+            markers: vec![],
+        }
+    }
+
+    #[test]
+    fn splits_by_duration_and_folds_short_tail() {
+        let timeline = timeline(&[(0.0, 600.5, 1.0)]);
+
+        let ranges = ExportSplit::Duration { minutes: 5 }.ranges(&timeline);
+
+        assert_eq!(ranges, vec![(0.0, 300.0), (300.0, 600.5)]);
+    }
+
+    #[test]
+    fn splits_at_clip_cuts() {
+        let timeline = timeline(&[(0.0, 10.0, 1.0), (20.0, 40.0, 2.0), (50.0, 55.0, 1.0)]);
+
+        let ranges = ExportSplit::Clips.ranges(&timeline);
+
+        assert_eq!(ranges, vec![(0.0, 10.0), (10.0, 20.0), (20.0, 25.0)]);
+    }
+
+    #[test]
+    fn splits_at_markers_in_order() {
+        let mut timeline = timeline(&[(0.0, 60.0, 1.0)]);
+        for time in [40.0, 0.0, 20.0, 20.5, 90.0] {
+            timeline.insert_marker(crate::TimelineMarker {
+                time,
+                label: String::new(),
+            });
+        }
+
+        let ranges = ExportSplit::Markers.ranges(&timeline);
+
+        assert_eq!(ranges, vec![(0.0, 20.5), (20.5, 40.0), (40.0, 60.0)]);
+
+        let part = timeline.slice((20.0, 40.0));
+        let times = part.markers.iter().map(|m| m.time).collect::<Vec<_>>();
+        assert_eq!(times, vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn splits_at_unsorted_and_repeated_markers() {
+        let mut timeline = timeline(&[(0.0, 60.0, 1.0)]);
+        timeline.markers = [40.0, 20.0, 40.0, 10.0]
+            .into_iter()
+            .map(|time| crate::TimelineMarker {
+                time,
+                label: String::new(),
+            })
+            .collect();
+
+        let ranges = ExportSplit::Markers.ranges(&timeline);
+
+        assert_eq!(
+            ranges,
+            vec![(0.0, 10.0), (10.0, 20.0), (20.0, 40.0), (40.0, 60.0)]
+        );
+    }
+
+    #[test]
+    fn slice_maps_clips_and_segments_into_part() {
+        let mut timeline = timeline(&[(0.0, 10.0, 1.0), (20.0, 40.0, 2.0)]);
+        timeline.zoom_segments.push(ZoomSegment {
+            start: 8.0,
+            end: 12.0,
+            amount: 1.5,
+            mode: crate::ZoomMode::Auto,
+            glide_direction: Default::default(),
+            glide_speed: 0.5,
+            instant_animation: false,
+            edge_snap_ratio: 0.25,
+            zoom_from_click: false,
+            easing: Default::default(),
+        });
+
+        let part = timeline.slice((5.0, 15.0));
+
+        let clips = part
+            .segments
+            .iter()
+            .map(|s| (s.start, s.end))
+            .collect::<Vec<_>>();
+        assert_eq!(clips, vec![(5.0, 10.0), (20.0, 30.0)]);
+        assert_eq!(part.duration(), 10.0);
+        assert_eq!(part.zoom_segments.len(), 1);
+        assert_eq!(
+            (part.zoom_segments[0].start, part.zoom_segments[0].end),
+            (3.0, 7.0)
+        );
+    }
+}
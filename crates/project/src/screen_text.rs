@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+pub const SCREEN_TEXT_INDEX_PATH: &str = "search/screen-text.json";
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenTextSpan {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+#[derive(Type, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenTextIndex {
+    pub sample_interval_secs: f64,
+    pub spans: Vec<ScreenTextSpan>,
+}
+
+impl ScreenTextIndex {
+    pub fn new(sample_interval_secs: f64) -> Self {
+        Self {
+            sample_interval_secs,
+            spans: Vec::new(),
+        }
+    }
+
+    pub fn add_sample<'a>(&mut self, time: f64, lines: impl IntoIterator<Item = &'a str>) {
+        let end = time + self.sample_interval_secs;
+
+        for line in lines {
+            let text = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                continue;
+            }
+
+            let continues_from = time - self.sample_interval_secs - f64::EPSILON;
+            match self
+                .spans
+                .iter_mut()
+                .rev()
+                .find(|span| span.text == text && span.end >= continues_from)
+            {
+                Some(span) => span.end = end,
+                None => self.spans.push(ScreenTextSpan {
+                    start: time,
+                    end,
+                    text,
+                }),
+            }
+        }
+    }
+
+    pub fn search(&self, query: &str) -> Vec<&ScreenTextSpan> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.spans
+            .iter()
+            .filter(|span| span.text.to_lowercase().contains(&query))
+            .collect()
+    }
+
+    pub fn load(project_path: &Path) -> Result<Self, std::io::Error> {
+        let json = std::fs::read_to_string(project_path.join(SCREEN_TEXT_INDEX_PATH))?;
+        serde_json::from_str(&json)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn write(&self, project_path: &Path) -> Result<(), std::io::Error> {
+        let path = project_path.join(SCREEN_TEXT_INDEX_PATH);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_on_consecutive_samples_share_a_span() {
+        let mut index = ScreenTextIndex::new(2.0);
+
+        index.add_sample(0.0, ["Settings", "Save  changes"]);
+        index.add_sample(2.0, ["Settings"]);
+        index.add_sample(4.0, ["Settings"]);
+        index.add_sample(6.0, ["Save changes"]);
+
+        assert_eq!(
+            index.spans,
+            vec![
+                ScreenTextSpan {
+                    start: 0.0,
+                    end: 6.0,
+                    text: "Settings".to_string(),
+                },
+                ScreenTextSpan {
+                    start: 0.0,
+                    end: 2.0,
+                    text: "Save changes".to_string(),
+                },
+                ScreenTextSpan {
+                    start: 6.0,
+                    end: 8.0,
+                    text: "Save changes".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn samples_that_land_late_still_continue_a_span() {
+        let mut index = ScreenTextIndex::new(2.0);
+
+        index.add_sample(0.0, ["Settings"]);
+        index.add_sample(2.04, ["Settings"]);
+        index.add_sample(4.1, ["Settings"]);
+
+        assert_eq!(
+            index.spans,
+            vec![ScreenTextSpan {
+                start: 0.0,
+                end: 6.1,
+                text: "Settings".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn search_ignores_case_and_blank_queries() {
+        let mut index = ScreenTextIndex::new(1.0);
+        index.add_sample(0.0, ["Deploy to Production", "Cancel"]);
+
+        let matches = index.search("production");
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].text, "Deploy to Production");
+        assert!(index.search("  ").is_empty());
+    }
+}
@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::{ProjectConfiguration, TimelineConfiguration, TimelineSegment};
+
+const CLICK_PADDING_SECS: f64 = 1.0;
+const MIN_STRETCH_SECS: f64 = 0.25;
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelapseSettings {
+    pub speed: f64,
+    #[serde(default)]
+    pub keep_clicks: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Stretch {
+    from: f64,
+    to: f64,
+    start: f64,
+    speed: f64,
+}
+
+struct TimeMap(Vec<Stretch>);
+
+impl TimeMap {
+    fn map(&self, time: f64) -> f64 {
+        let Some(stretch) = self.0.iter().find(|s| time < s.to).or(self.0.last()) else {
+            return time;
+        };
+        stretch.start + (time - stretch.from).max(0.0) / stretch.speed
+    }
+}
+
+impl ProjectConfiguration {
+    pub fn timelapse(&self, settings: TimelapseSettings, clicks: &[(u32, f64)]) -> Self {
+        let mut project = self.clone();
+        let Some(timeline) = &self.timeline else {
+            return project;
+        };
+
+        let clicks = if settings.keep_clicks { clicks } else { &[] };
+        let (segments, map) = timelapse_segments(&timeline.segments, settings.speed, clicks);
+
+        for keyframe in &mut project.background_keyframes {
+            keyframe.start_ms = map.map(keyframe.start_ms / 1000.0) * 1000.0;
+        }
+        for keyframe in &mut project.layout_keyframes {
+            keyframe.start_ms = map.map(keyframe.start_ms / 1000.0) * 1000.0;
+        }
+        for burst in &mut project.confetti {
+            burst.time = map.map(burst.time);
+        }
+
+        if let Some(captions) = &mut project.captions {
+            let map_f32 = |time: f32| map.map(f64::from(time)) as f32;
+            for segment in &mut captions.segments {
+                segment.start = map_f32(segment.start);
+                segment.end = map_f32(segment.end);
+                for word in &mut segment.words {
+                    word.start = map_f32(word.start);
+                    word.end = map_f32(word.end);
+                }
+            }
+        }
+
+        project.timeline = Some(timeline.remap(segments, &map));
+        project
+    }
+}
+
+impl TimelineConfiguration {
+    fn remap(&self, segments: Vec<TimelineSegment>, map: &TimeMap) -> Self {
+        Self {
+            segments,
+            zoom_segments: self
+                .zoom_segments
+                .iter()
+                .map(|s| crate::ZoomSegment {
+                    start: map.map(s.start),
+                    end: map.map(s.end),
+                    ..s.clone()
+                })
+                .collect(),
+            scene_segments: self
+                .scene_segments
+                .iter()
+                .map(|s| crate::SceneSegment {
+                    start: map.map(s.start),
+                    end: map.map(s.end),
+                    ..s.clone()
+                })
+                .collect(),
+            mask_segments: self
+                .mask_segments
+                .iter()
+                .map(|s| {
+                    let mut segment = s.clone();
+                    segment.start = map.map(s.start);
+                    segment.end = map.map(s.end);
+                    let relative = |time: f64| map.map(s.start + time) - segment.start;
+                    for keyframe in segment
+                        .keyframes
+                        .position
+                        .iter_mut()
+                        .chain(segment.keyframes.size.iter_mut())
+                    {
+                        keyframe.time = relative(keyframe.time);
+                    }
+                    for keyframe in &mut segment.keyframes.intensity {
+                        keyframe.time = relative(keyframe.time);
+                    }
+                    segment
+                })
+                .collect(),
+            text_segments: self
+                .text_segments
+                .iter()
+                .map(|s| crate::TextSegment {
+                    start: map.map(s.start),
+                    end: map.map(s.end),
+                    ..s.clone()
+                })
+                .collect(),
+            reaction_segments: self
+                .reaction_segments
+                .iter()
+                .map(|s| crate::ReactionSegment {
+                    start: map.map(s.start),
+                    end: map.map(s.end),
+                    ..s.clone()
+                })
+                .collect(),
+            cursor_segments: self
+                .cursor_segments
+                .iter()
+                .map(|s| crate::HiddenCursorSegment {
+                    start: map.map(s.start),
+                    end: map.map(s.end),
+                    ..s.clone()
+                })
+                .collect(),
+            markers: self
+                .markers
+                .iter()
+                .map(|m| crate::TimelineMarker {
+                    time: map.map(m.time),
+                    ..m.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+fn timelapse_segments(
+    segments: &[TimelineSegment],
+    speed: f64,
+    clicks: &[(u32, f64)],
+) -> (Vec<TimelineSegment>, TimeMap) {
+    let speed = speed.max(1.0);
+
+    let mut timelapse = Vec::new();
+    let mut stretches = Vec::new();
+    let mut edited_start = 0.0;
+    let mut timelapse_start = 0.0;
+
+    for segment in segments {
+        let padding = CLICK_PADDING_SECS * segment.timescale;
+        let mut kept = clicks
+            .iter()
+            .filter(|(clip, _)| *clip == segment.recording_clip)
+            .map(|(_, time)| {
+                (
+                    (time - padding).max(segment.start),
+                    (time + padding).min(segment.end),
+                )
+            })
+            .filter(|(start, end)| end > start)
+            .collect::<Vec<_>>();
+        kept.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut pieces: Vec<(f64, f64, bool)> = Vec::new();
+        let mut cursor = segment.start;
+        for (start, end) in kept {
+            let start = if start - cursor < MIN_STRETCH_SECS {
+                cursor
+            } else {
+                start
+            };
+            if start > cursor {
+                pieces.push((cursor, start, false));
+            }
+            match pieces.last_mut() {
+                Some(last) if last.2 && start <= last.1 => last.1 = last.1.max(end),
+                _ => pieces.push((start, end, true)),
+            }
+            cursor = cursor.max(end);
+        }
+        if segment.end > cursor {
+            match pieces.last_mut() {
+                Some(last) if last.2 && segment.end - cursor < MIN_STRETCH_SECS => {
+                    last.1 = segment.end
+                }
+                _ => pieces.push((cursor, segment.end, false)),
+            }
+        }
+
+        for (start, end, keep) in pieces {
+            let stretch_speed = if keep { 1.0 } else { speed };
+            let piece = TimelineSegment {
+                recording_clip: segment.recording_clip,
+                timescale: segment.timescale * stretch_speed,
+                start,
+                end,
+            };
+            let edited_duration = (end - start) / segment.timescale;
+
+            stretches.push(Stretch {
+                from: edited_start,
+                to: edited_start + edited_duration,
+                start: timelapse_start,
+                speed: stretch_speed,
+            });
+            edited_start += edited_duration;
+            timelapse_start += piece.duration();
+            timelapse.push(piece);
+        }
+    }
+
+    (timelapse, TimeMap(stretches))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ZoomMode, ZoomSegment};
+
+    fn timeline(clips: &[(f64, f64, f64)]) -> TimelineConfiguration {
+        TimelineConfiguration {
+            segments: clips
+                .iter()
+                .map(|&(start, end, timescale)| TimelineSegment {
+                    recording_clip: 0,
+                    timescale,
+                    start,
+                    end,
+                })
+                .collect(),
+            zoom_segments: vec![],
+            scene_segments: vec![],
+            mask_segments: vec![],
+            text_segments: vec![],
+            reaction_segments: vec![],
+            cursor_segments: vec![],
+            markers: vec![],
+        }
+    }
+
+    fn project(timeline: TimelineConfiguration) -> ProjectConfiguration {
+        ProjectConfiguration {
+            timeline: Some(timeline),
+            ..Default::default()
+        }
+    }
+
+    fn settings(speed: f64, keep_clicks: bool) -> TimelapseSettings {
+        TimelapseSettings { speed, keep_clicks }
+    }
+
+    #[test]
+    fn speeds_up_every_clip() {
+        let project = project(timeline(&[(0.0, 600.0, 1.0), (700.0, 900.0, 2.0)]));
+
+        let timelapse = project.timelapse(settings(10.0, false), &[(0, 30.0)]);
+
+        let timeline = timelapse.timeline.unwrap();
+        let scales = timeline
+            .segments
+            .iter()
+            .map(|s| s.timescale)
+            .collect::<Vec<_>>();
+        assert_eq!(scales, vec![10.0, 20.0]);
+        assert_eq!(timeline.duration(), 70.0);
+    }
+
+    #[test]
+    fn keeps_clicks_at_edited_speed() {
+        let project = project(timeline(&[(0.0, 100.0, 1.0)]));
+
+        let timelapse = project.timelapse(
+            settings(10.0, true),
+            &[(0, 50.0), (0, 50.5), (0, 99.9), (1, 20.0)],
+        );
+
+        let timeline = timelapse.timeline.unwrap();
+        let pieces = timeline
+            .segments
+            .iter()
+            .map(|s| (s.start, s.end, s.timescale))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pieces,
+            vec![
+                (0.0, 49.0, 10.0),
+                (49.0, 51.5, 1.0),
+                (51.5, 98.9, 10.0),
+                (98.9, 100.0, 1.0),
+            ]
+        );
+        assert!((timeline.duration() - (4.9 + 2.5 + 4.74 + 1.1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn moves_zooms_and_keyframes_with_the_clips() {
+        let mut timeline = timeline(&[(0.0, 100.0, 1.0)]);
+        timeline.zoom_segments.push(ZoomSegment {
+            start: 40.0,
+            end: 60.0,
+            amount: 1.5,
+            mode: ZoomMode::Auto,
+            glide_direction: Default::default(),
+            glide_speed: 0.5,
+            instant_animation: false,
+            edge_snap_ratio: 0.25,
+            zoom_from_click: false,
+            easing: Default::default(),
+        });
+        let mut project = project(timeline);
+        project.layout_keyframes.push(crate::LayoutKeyframe {
+            start_ms: 80_000.0,
+            padding: 0.0,
+            rounding: 0.0,
+            shadow: 0.0,
+        });
+
+        let timelapse = project.timelapse(settings(10.0, true), &[(0, 50.0)]);
+
+        let zoom = &timelapse.timeline.unwrap().zoom_segments[0];
+        assert!((zoom.start - 4.0).abs() < 1e-9);
+        assert!((zoom.end - 7.8).abs() < 1e-9);
+        assert!((timelapse.layout_keyframes[0].start_ms - 9_800.0).abs() < 1e-6);
+    }
+}
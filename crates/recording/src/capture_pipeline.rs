@@ -338,59 +338,137 @@ pub fn target_to_display_and_crop(
 #[cfg(windows)]
 pub fn create_d3d_device()
 -> windows::core::Result<windows::Win32::Graphics::Direct3D11::ID3D11Device> {
-    use windows::Win32::Graphics::{
-        Direct3D::{D3D_DRIVER_TYPE, D3D_DRIVER_TYPE_HARDWARE},
-        Direct3D11::{D3D11_CREATE_DEVICE_FLAG, ID3D11Device},
-    };
+    use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_HARDWARE;
 
     let mut device = None;
-    let flags = {
-        use windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_BGRA_SUPPORT;
-
-        let mut flags = D3D11_CREATE_DEVICE_BGRA_SUPPORT;
-        if cfg!(feature = "d3ddebug") {
-            use windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_DEBUG;
-
-            flags |= D3D11_CREATE_DEVICE_DEBUG;
-        }
-        flags
-    };
-    let mut result = create_d3d_device_with_type(D3D_DRIVER_TYPE_HARDWARE, flags, &mut device);
+    let flags = d3d_device_flags();
+    let mut result =
+        create_d3d_device_with_type(None, D3D_DRIVER_TYPE_HARDWARE, flags, &mut device);
     if let Err(error) = &result {
         use windows::Win32::Graphics::Dxgi::DXGI_ERROR_UNSUPPORTED;
 
         if error.code() == DXGI_ERROR_UNSUPPORTED {
             use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_WARP;
 
-            result = create_d3d_device_with_type(D3D_DRIVER_TYPE_WARP, flags, &mut device);
+            result = create_d3d_device_with_type(None, D3D_DRIVER_TYPE_WARP, flags, &mut device);
         }
     }
     result?;
 
-    fn create_d3d_device_with_type(
-        driver_type: D3D_DRIVER_TYPE,
-        flags: D3D11_CREATE_DEVICE_FLAG,
-        device: *mut Option<ID3D11Device>,
-    ) -> windows::core::Result<()> {
-        unsafe {
-            use windows::Win32::{
-                Foundation::HMODULE,
-                Graphics::Direct3D11::{D3D11_SDK_VERSION, D3D11CreateDevice},
-            };
-
-            D3D11CreateDevice(
-                None,
-                driver_type,
-                HMODULE(std::ptr::null_mut()),
-                flags,
-                None,
-                D3D11_SDK_VERSION,
-                Some(device),
-                None,
-                None,
-            )
+    Ok(device.unwrap())
+}
+
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+pub struct DisplayAdapter {
+    pub gpu: cap_frame_converter::GpuInfo,
+    pub requires_cross_adapter_copy: bool,
+}
+
+#[cfg(windows)]
+pub fn display_adapter(display: &scap_targets::Display) -> Option<DisplayAdapter> {
+    let gpu = cap_frame_converter::adapter_for_monitor(display.raw_handle().inner())?;
+    let requires_cross_adapter_copy = cap_frame_converter::detect_primary_gpu()
+        .is_some_and(|primary| primary.adapter_index != gpu.adapter_index);
+
+    Some(DisplayAdapter {
+        gpu,
+        requires_cross_adapter_copy,
+    })
+}
+
+#[cfg(windows)]
+pub fn create_d3d_device_for_display(
+    display: &scap_targets::Display,
+) -> windows::core::Result<windows::Win32::Graphics::Direct3D11::ID3D11Device> {
+    use windows::Win32::Graphics::{
+        Direct3D::D3D_DRIVER_TYPE_UNKNOWN,
+        Dxgi::{CreateDXGIFactory1, IDXGIAdapter, IDXGIFactory1},
+    };
+
+    let Some(display_adapter) = display_adapter(display) else {
+        tracing::warn!("Couldn't find the GPU driving the captured display, using the default");
+        return create_d3d_device();
+    };
+
+    if display_adapter.requires_cross_adapter_copy {
+        tracing::warn!(
+            adapter = display_adapter.gpu.description,
+            "Captured display is driven by a different GPU than the one used for encoding. \
+            If recordings come out black, set this app to use the same GPU in Windows \
+            graphics settings."
+        );
+    } else {
+        tracing::info!(
+            adapter = display_adapter.gpu.description,
+            "Capturing display on its own GPU"
+        );
+    }
+
+    let adapter = unsafe {
+        CreateDXGIFactory1::<IDXGIFactory1>()
+            .and_then(|factory| factory.EnumAdapters(display_adapter.gpu.adapter_index))
+    };
+
+    let mut device = None;
+    let result = adapter.and_then(|adapter: IDXGIAdapter| {
+        create_d3d_device_with_type(
+            Some(&adapter),
+            D3D_DRIVER_TYPE_UNKNOWN,
+            d3d_device_flags(),
+            &mut device,
+        )
+    });
+
+    match (result, device) {
+        (Ok(()), Some(device)) => Ok(device),
+        (result, _) => {
+            tracing::warn!(
+                adapter = display_adapter.gpu.description,
+                "Failed to create D3D11 device on the display's GPU, using the default: {:?}",
+                result.err()
+            );
+            create_d3d_device()
         }
     }
+}
+
+#[cfg(windows)]
+fn d3d_device_flags() -> windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_FLAG {
+    use windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_BGRA_SUPPORT;
+
+    let mut flags = D3D11_CREATE_DEVICE_BGRA_SUPPORT;
+    if cfg!(feature = "d3ddebug") {
+        use windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_DEBUG;
+
+        flags |= D3D11_CREATE_DEVICE_DEBUG;
+    }
+    flags
+}
 
-    Ok(device.unwrap())
+#[cfg(windows)]
+fn create_d3d_device_with_type(
+    adapter: Option<&windows::Win32::Graphics::Dxgi::IDXGIAdapter>,
+    driver_type: windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE,
+    flags: windows::Win32::Graphics::Direct3D11::D3D11_CREATE_DEVICE_FLAG,
+    device: *mut Option<windows::Win32::Graphics::Direct3D11::ID3D11Device>,
+) -> windows::core::Result<()> {
+    unsafe {
+        use windows::Win32::{
+            Foundation::HMODULE,
+            Graphics::Direct3D11::{D3D11_SDK_VERSION, D3D11CreateDevice},
+        };
+
+        D3D11CreateDevice(
+            adapter,
+            driver_type,
+            HMODULE(std::ptr::null_mut()),
+            flags,
+            None,
+            D3D11_SDK_VERSION,
+            Some(device),
+            None,
+            None,
+        )
+    }
 }
@@ -1,6 +1,10 @@
+use crate::window_tracking::{FocusTracker, WindowRect, WindowTracker};
 use cap_cursor_capture::CursorCropBounds;
 use cap_cursor_info::CursorShape;
-use cap_project::{CursorClickEvent, CursorEvents, CursorMoveEvent, XY};
+use cap_project::{
+    CursorClickEvent, CursorEvents, CursorMoveEvent, KEY_PRESS_BUCKET_MS, KeyEvent, KeyPressBucket,
+    KeystrokeCapture, MASKED_KEY, WindowFocusEvent, XY,
+};
 use cap_timestamp::Timestamps;
 use futures::{FutureExt, future::Shared};
 use std::{
@@ -28,6 +32,9 @@ pub struct CursorActorResponse {
     pub next_cursor_id: u32,
     pub moves: Vec<CursorMoveEvent>,
     pub clicks: Vec<CursorClickEvent>,
+    pub key_presses: Vec<KeyPressBucket>,
+    pub keys: Vec<KeyEvent>,
+    pub focus_changes: Vec<WindowFocusEvent>,
 }
 
 pub struct CursorActor {
@@ -42,11 +49,16 @@ impl CursorActor {
 }
 
 const CURSOR_FLUSH_INTERVAL_SECS: u64 = 5;
+const FOCUS_POLL_INTERVAL_MS: u64 = 250;
+const ANONYMIZED_GRID_CELLS: f64 = 32.0;
 
-fn flush_cursor_data(output_path: &Path, moves: &[CursorMoveEvent], clicks: &[CursorClickEvent]) {
+fn flush_cursor_data(output_path: &Path, response: &CursorActorResponse) {
     let events = CursorEvents {
-        clicks: clicks.to_vec(),
-        moves: moves.to_vec(),
+        clicks: response.clicks.clone(),
+        moves: response.moves.clone(),
+        key_presses: response.key_presses.clone(),
+        keys: response.keys.clone(),
+        focus_changes: response.focus_changes.clone(),
     };
     if let Ok(json) = serde_json::to_string_pretty(&events)
         && let Err(e) = std::fs::write(output_path, json)
@@ -59,18 +71,86 @@ fn flush_cursor_data(output_path: &Path, moves: &[CursorMoveEvent], clicks: &[Cu
     }
 }
 
+fn anonymize_position(position: XY<f64>) -> XY<f64> {
+    XY::new(
+        (position.x * ANONYMIZED_GRID_CELLS).round() / ANONYMIZED_GRID_CELLS,
+        (position.y * ANONYMIZED_GRID_CELLS).round() / ANONYMIZED_GRID_CELLS,
+    )
+}
+
+fn count_key_presses(buckets: &mut Vec<KeyPressBucket>, time_ms: f64, presses: u32) {
+    let bucket_start = (time_ms / KEY_PRESS_BUCKET_MS).floor() * KEY_PRESS_BUCKET_MS;
+
+    match buckets.last_mut() {
+        Some(bucket) if bucket.time_ms == bucket_start => bucket.presses += presses,
+        _ => buckets.push(KeyPressBucket {
+            time_ms: bucket_start,
+            presses,
+        }),
+    }
+}
+
+const NAMED_KEYS: &[&str] = &[
+    "LShift",
+    "RShift",
+    "LControl",
+    "RControl",
+    "LAlt",
+    "RAlt",
+    "LOption",
+    "ROption",
+    "Command",
+    "RCommand",
+    "LMeta",
+    "RMeta",
+    "CapsLock",
+    "Escape",
+    "Enter",
+    "Tab",
+    "Backspace",
+    "Delete",
+    "Insert",
+    "Home",
+    "End",
+    "PageUp",
+    "PageDown",
+    "Up",
+    "Down",
+    "Left",
+    "Right",
+];
+
+fn recorded_key_name(name: String, keystrokes: KeystrokeCapture) -> String {
+    let is_function_key = name
+        .strip_prefix('F')
+        .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()));
+
+    if keystrokes == KeystrokeCapture::Masked
+        && !is_function_key
+        && !NAMED_KEYS.contains(&name.as_str())
+    {
+        MASKED_KEY.to_string()
+    } else {
+        name
+    }
+}
+
 #[tracing::instrument(name = "cursor", skip_all)]
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_cursor_recorder(
     crop_bounds: CursorCropBounds,
     display: scap_targets::Display,
+    mut window_tracker: Option<WindowTracker>,
     cursors_dir: PathBuf,
     prev_cursors: Cursors,
     next_cursor_id: u32,
     start_time: Timestamps,
     output_path: Option<PathBuf>,
+    anonymize_input: bool,
+    keystrokes: KeystrokeCapture,
 ) -> CursorActor {
     use cap_utils::spawn_actor;
-    use device_query::{DeviceQuery, DeviceState};
+    use device_query::{DeviceQuery, DeviceState, Keycode};
     use futures::future::Either;
     use sha2::{Digest, Sha256};
     use std::{pin::pin, time::Duration};
@@ -83,6 +163,8 @@ pub fn spawn_cursor_recorder(
     spawn_actor(async move {
         let device_state = DeviceState::new();
         let mut last_mouse_state = device_state.get_mouse();
+        let mut last_keys: Vec<Keycode> = vec![];
+        let mut last_anonymized_position = None;
 
         let mut last_position = cap_cursor_capture::RawCursorPosition::get();
 
@@ -93,8 +175,15 @@ pub fn spawn_cursor_recorder(
             next_cursor_id,
             moves: vec![],
             clicks: vec![],
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
         };
 
+        let mut focus_tracker = FocusTracker::default();
+        let mut last_focus_poll: Option<Instant> = None;
+        let focus_poll_interval = Duration::from_millis(FOCUS_POLL_INTERVAL_MS);
+
         let mut last_flush = Instant::now();
         let flush_interval = Duration::from_secs(CURSOR_FLUSH_INTERVAL_SECS);
         let mut last_cursor_id: Option<String> = None;
@@ -111,7 +200,10 @@ pub fn spawn_cursor_recorder(
             let mouse_state = device_state.get_mouse();
 
             let position = cap_cursor_capture::RawCursorPosition::get();
-            let position_changed = position != last_position;
+            let window_changed = window_tracker
+                .as_mut()
+                .is_some_and(|tracker| tracker.sample(elapsed));
+            let position_changed = position != last_position || window_changed;
 
             if position_changed {
                 last_position = position;
@@ -165,18 +257,30 @@ pub fn spawn_cursor_recorder(
             };
 
             if position_changed {
-                let cropped_norm_pos = position
-                    .relative_to_display(display)
-                    .and_then(|p| p.normalize())
-                    .map(|p| p.with_crop(crop_bounds));
+                let cropped_norm_pos = match &window_tracker {
+                    Some(tracker) => tracker.map(elapsed, position),
+                    None => position
+                        .relative_to_display(display)
+                        .and_then(|p| p.normalize())
+                        .map(|p| p.with_crop(crop_bounds))
+                        .map(|p| XY::new(p.x(), p.y())),
+                };
+
+                let cropped_norm_pos = if anonymize_input {
+                    cropped_norm_pos
+                        .map(anonymize_position)
+                        .filter(|pos| last_anonymized_position.replace(*pos) != Some(*pos))
+                } else {
+                    cropped_norm_pos
+                };
 
                 if let Some(pos) = cropped_norm_pos {
                     let mouse_event = CursorMoveEvent {
                         active_modifiers: vec![],
                         cursor_id: cursor_id.clone(),
                         time_ms: elapsed,
-                        x: pos.x(),
-                        y: pos.y(),
+                        x: pos.x,
+                        y: pos.y,
                     };
                     response.moves.push(mouse_event);
                 }
@@ -203,10 +307,46 @@ pub fn spawn_cursor_recorder(
 
             last_mouse_state = mouse_state;
 
+            if anonymize_input {
+                let keys = device_state.get_keys();
+                let presses = keys.iter().filter(|key| !last_keys.contains(key)).count();
+                if presses > 0 {
+                    count_key_presses(&mut response.key_presses, elapsed, presses as u32);
+                }
+                last_keys = keys;
+            } else if keystrokes != KeystrokeCapture::Off {
+                let keys = device_state.get_keys();
+                let released = last_keys.iter().filter(|key| !keys.contains(key));
+                let pressed = keys.iter().filter(|key| !last_keys.contains(key));
+                for (key, down) in released
+                    .map(|key| (key, false))
+                    .chain(pressed.map(|key| (key, true)))
+                {
+                    response.keys.push(KeyEvent {
+                        key: recorded_key_name(format!("{key:?}"), keystrokes),
+                        time_ms: elapsed,
+                        down,
+                    });
+                }
+                last_keys = keys;
+            }
+
+            if last_focus_poll.is_none_or(|poll| poll.elapsed() >= focus_poll_interval) {
+                last_focus_poll = Some(Instant::now());
+
+                let area = match &window_tracker {
+                    Some(tracker) => tracker.rect_at(elapsed),
+                    None => WindowRect::of_display_crop(&display, crop_bounds),
+                };
+                if let Some(event) = focus_tracker.sample(elapsed, area, !anonymize_input) {
+                    response.focus_changes.push(event);
+                }
+            }
+
             if let Some(ref path) = output_path
                 && last_flush.elapsed() >= flush_interval
             {
-                flush_cursor_data(path, &response.moves, &response.clicks);
+                flush_cursor_data(path, &response);
                 last_flush = Instant::now();
             }
         }
@@ -214,7 +354,7 @@ pub fn spawn_cursor_recorder(
         info!("cursor recorder done");
 
         if let Some(ref path) = output_path {
-            flush_cursor_data(path, &response.moves, &response.clicks);
+            flush_cursor_data(path, &response);
         }
 
         let _ = tx.send(response);
@@ -568,3 +708,55 @@ fn get_cursor_data() -> Option<CursorData> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anonymized_positions_snap_to_grid() {
+        let position = anonymize_position(XY::new(0.1234, 0.9876));
+
+        assert_eq!(position, XY::new(4.0 / 32.0, 1.0));
+    }
+
+    #[test]
+    fn masking_hides_keys_that_type() {
+        let masked = |name: &str| recorded_key_name(name.to_string(), KeystrokeCapture::Masked);
+
+        assert_eq!(masked("LControl"), "LControl");
+        assert_eq!(masked("Enter"), "Enter");
+        assert_eq!(masked("F12"), "F12");
+        assert_eq!(masked("A"), MASKED_KEY);
+        assert_eq!(masked("Key7"), MASKED_KEY);
+        assert_eq!(masked("Space"), MASKED_KEY);
+        assert_eq!(masked("F"), MASKED_KEY);
+        assert_eq!(
+            recorded_key_name("A".to_string(), KeystrokeCapture::Full),
+            "A"
+        );
+    }
+
+    #[test]
+    fn key_presses_are_counted_per_bucket() {
+        let mut buckets = vec![];
+
+        count_key_presses(&mut buckets, 120.0, 1);
+        count_key_presses(&mut buckets, 980.0, 2);
+        count_key_presses(&mut buckets, 2500.0, 1);
+
+        assert_eq!(
+            buckets,
+            vec![
+                KeyPressBucket {
+                    time_ms: 0.0,
+                    presses: 3,
+                },
+                KeyPressBucket {
+                    time_ms: 2000.0,
+                    presses: 1,
+                },
+            ]
+        );
+    }
+}
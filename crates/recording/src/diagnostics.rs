@@ -143,6 +143,15 @@ mod windows_impl {
         pub has_discrete_gpu: bool,
     }
 
+    #[derive(Debug, Clone, Serialize, Type)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DisplayAdapterDiag {
+        pub display_name: String,
+        pub adapter_description: String,
+        pub adapter_index: u32,
+        pub requires_cross_adapter_copy: bool,
+    }
+
     #[derive(Debug, Clone, Serialize, Type)]
     #[serde(rename_all = "camelCase")]
     pub struct RenderingStatus {
@@ -158,6 +167,7 @@ mod windows_impl {
         pub windows_version: Option<WindowsVersionInfo>,
         pub gpu_info: Option<GpuInfoDiag>,
         pub all_gpus: Option<AllGpusInfo>,
+        pub display_adapters: Vec<DisplayAdapterDiag>,
         pub rendering_status: RenderingStatus,
         pub available_encoders: Vec<String>,
         pub graphics_capture_supported: bool,
@@ -169,6 +179,7 @@ mod windows_impl {
         let windows_version = get_windows_version_info();
         let gpu_info = get_gpu_info();
         let all_gpus = get_all_gpus_info();
+        let display_adapters = get_display_adapters();
         let rendering_status = get_rendering_status(&gpu_info);
         let available_encoders = get_available_encoders();
         let graphics_capture_supported = check_graphics_capture_support();
@@ -195,6 +206,15 @@ mod windows_impl {
                 all.has_discrete_gpu
             );
         }
+        for adapter in &display_adapters {
+            if adapter.requires_cross_adapter_copy {
+                tracing::warn!(
+                    "  Display {} is driven by {}, not the encoding GPU",
+                    adapter.display_name,
+                    adapter.adapter_description
+                );
+            }
+        }
         tracing::info!(
             "  Rendering: SoftwareRendering={}, HardwareEncoding={}",
             rendering_status.is_using_software_rendering,
@@ -214,6 +234,7 @@ mod windows_impl {
             windows_version,
             gpu_info,
             all_gpus,
+            display_adapters,
             rendering_status,
             available_encoders,
             graphics_capture_supported,
@@ -283,6 +304,21 @@ mod windows_impl {
         })
     }
 
+    fn get_display_adapters() -> Vec<DisplayAdapterDiag> {
+        scap_targets::Display::list()
+            .into_iter()
+            .filter_map(|display| {
+                let adapter = crate::capture_pipeline::display_adapter(&display)?;
+                Some(DisplayAdapterDiag {
+                    display_name: display.name().unwrap_or_else(|| "Unknown".to_string()),
+                    adapter_description: adapter.gpu.description,
+                    adapter_index: adapter.gpu.adapter_index,
+                    requires_cross_adapter_copy: adapter.requires_cross_adapter_copy,
+                })
+            })
+            .collect()
+    }
+
     fn get_rendering_status(gpu_info: &Option<GpuInfoDiag>) -> RenderingStatus {
         let (is_software, is_basic_render, hw_encoding, warning) = match gpu_info {
             Some(gpu) => {
@@ -13,14 +13,10 @@ use crate::{
 use anyhow::Context as _;
 use cap_media_info::{AudioInfo, VideoInfo};
 use cap_project::InstantRecordingMeta;
-use cap_timestamp::Timestamps;
+use cap_timestamp::{SessionClock, Timestamp, Timestamps};
 use cap_utils::ensure_dir;
 use kameo::{Actor as _, prelude::*};
-use std::{
-    path::PathBuf,
-    sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::{path::PathBuf, sync::Arc};
 use tracing::*;
 
 struct Pipeline {
@@ -418,12 +414,12 @@ pub async fn spawn_instant_recording_actor(
             )
         }
         _ => {
-            #[cfg(windows)]
-            let d3d_device = crate::capture_pipeline::create_d3d_device()?;
-
             let (display, crop_bounds) = target_to_display_and_crop(&inputs.capture_target)
                 .context("target_display_crop")?;
 
+            #[cfg(windows)]
+            let d3d_device = crate::capture_pipeline::create_d3d_device_for_display(&display)?;
+
             let screen_source = ScreenCaptureConfig::<ScreenCaptureMethod>::init(
                 display,
                 crop_bounds,
@@ -461,7 +457,7 @@ pub async fn spawn_instant_recording_actor(
         }
     };
 
-    let segment_start_time = current_time_f64();
+    let segment_start_time = SessionClock::new(timestamps).unix_secs(Timestamp::now());
 
     trace!("spawning recording actor");
 
@@ -492,13 +488,6 @@ pub async fn spawn_instant_recording_actor(
     Ok(actor_handle)
 }
 
-fn current_time_f64() -> f64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs_f64()
-}
-
 fn clamp_size(input: (u32, u32), max: (u32, u32)) -> (u32, u32) {
     // 16/9-ish
     if input.0 >= input.1 && (input.0 as f64 / input.1 as f64) <= 16.0 / 9.0 {
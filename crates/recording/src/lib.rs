@@ -12,6 +12,7 @@ pub mod screenshot;
 pub mod sources;
 pub mod studio_recording;
 pub mod sync_calibration;
+pub mod window_tracking;
 
 pub use resolution_limits::{H264_MAX_DIMENSION, calculate_gpu_compatible_size};
 
@@ -1479,7 +1479,11 @@ async fn setup_audio_sources(
             let (tx, rx) = mpsc::channel(128);
             let source = (audio_source_setup)(tx, setup_ctx).await?;
 
-            audio_mixer.add_source(source.audio_info, rx);
+            if source.loopback {
+                audio_mixer.add_loopback_source(source.audio_info, rx);
+            } else {
+                audio_mixer.add_source(source.audio_info, rx);
+            }
             erased_audio_sources.push(source);
         }
 
@@ -1726,6 +1730,7 @@ pub trait VideoSource: Send + 'static {
 struct ErasedAudioSource {
     inner: Box<dyn Any + Send>,
     audio_info: AudioInfo,
+    loopback: bool,
     start_fn: fn(&mut dyn Any) -> BoxFuture<'_, anyhow::Result<()>>,
     stop_fn: fn(&mut dyn Any) -> BoxFuture<'_, anyhow::Result<()>>,
 }
@@ -1734,6 +1739,7 @@ impl ErasedAudioSource {
     pub fn new<TAudio: AudioSource>(source: TAudio) -> Self {
         Self {
             audio_info: source.audio_info(),
+            loopback: TAudio::LOOPBACK,
             start_fn: |raw| {
                 raw.downcast_mut::<TAudio>()
                     .expect("Wrong type")
@@ -1754,6 +1760,8 @@ impl ErasedAudioSource {
 pub trait AudioSource: Send + 'static {
     type Config: Send;
 
+    const LOOPBACK: bool = false;
+
     fn setup(
         config: Self::Config,
         tx: mpsc::Sender<AudioFrame>,
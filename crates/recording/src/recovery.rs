@@ -952,6 +952,9 @@ impl RecoveryManager {
             scene_segments: Vec::new(),
             mask_segments: Vec::new(),
             text_segments: Vec::new(),
+            reaction_segments: Vec::new(),
+            cursor_segments: Vec::new(),
+            markers: Vec::new(),
         });
 
         config
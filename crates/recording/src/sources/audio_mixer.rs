@@ -1,8 +1,6 @@
 use cap_media_info::AudioInfo;
 use cap_timestamp::{Timestamp, Timestamps};
 use futures::channel::{mpsc, oneshot};
-#[cfg(not(any(target_os = "macos", windows)))]
-use std::time::Instant;
 use std::{
     collections::VecDeque,
     sync::{
@@ -20,6 +18,8 @@ const MIN_BUFFER_TIMEOUT_WIRED: Duration = Duration::from_millis(20);
 const MIN_BUFFER_TIMEOUT_WIRELESS: Duration = Duration::from_millis(90);
 const MAX_BUFFER_TIMEOUT: Duration = Duration::from_millis(250);
 const BUFFER_TIMEOUT_HEADROOM: f64 = 2.5;
+const ECHO_CANCELLER_ORDER: u32 = 2048;
+const ECHO_CANCELLER_STEP: f32 = 0.5;
 
 // Wait TICK_MS for frames to arrive
 // Assume all sources' frames for that tick have arrived after TICK_MS
@@ -34,6 +34,7 @@ struct MixerSource {
     buffer: VecDeque<AudioFrame>,
     buffer_last: Option<(Timestamp, Duration)>,
     last_input_timestamp: Option<Timestamp>,
+    loopback: bool,
 }
 
 pub struct AudioMixerBuilder {
@@ -65,6 +66,14 @@ impl AudioMixerBuilder {
     }
 
     pub fn add_source(&mut self, info: AudioInfo, rx: mpsc::Receiver<AudioFrame>) {
+        self.push_source(info, rx, false);
+    }
+
+    pub fn add_loopback_source(&mut self, info: AudioInfo, rx: mpsc::Receiver<AudioFrame>) {
+        self.push_source(info, rx, true);
+    }
+
+    fn push_source(&mut self, info: AudioInfo, rx: mpsc::Receiver<AudioFrame>, loopback: bool) {
         let buffer_timeout = buffer_timeout_for(&info);
 
         self.sources.push(MixerSource {
@@ -74,6 +83,7 @@ impl AudioMixerBuilder {
             buffer: VecDeque::new(),
             buffer_last: None,
             last_input_timestamp: None,
+            loopback,
         });
     }
 
@@ -142,8 +152,60 @@ impl AudioMixerBuilder {
             "",
         )?;
 
-        for (i, resample) in resamplers.iter_mut().enumerate() {
-            resample.link(0, &mut amix, i as u32);
+        let echo_reference = self.sources.iter().position(|source| source.loopback);
+        let echo_targets = self
+            .sources
+            .iter()
+            .enumerate()
+            .filter(|(_, source)| !source.loopback)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let mut echo_cancellers = Vec::new();
+
+        match echo_reference {
+            Some(reference) if !echo_targets.is_empty() => {
+                debug!(
+                    reference,
+                    ?echo_targets,
+                    "audio mixer cancelling loopback echo"
+                );
+
+                let mut split = filter_graph.add(
+                    &ffmpeg::filter::find("asplit").expect("Failed to find asplit filter"),
+                    "echo_reference",
+                    &format!("outputs={}", echo_targets.len() + 1),
+                )?;
+                resamplers[reference].link(0, &mut split, 0);
+                split.link(0, &mut amix, reference as u32);
+
+                for (n, &i) in echo_targets.iter().enumerate() {
+                    let mut anlms = filter_graph.add(
+                        &ffmpeg::filter::find("anlms").expect("Failed to find anlms filter"),
+                        &format!("echo_cancel{i}"),
+                        &format!(
+                            "order={ECHO_CANCELLER_ORDER}:mu={ECHO_CANCELLER_STEP}:out_mode=e"
+                        ),
+                    )?;
+                    split.link(n as u32 + 1, &mut anlms, 0);
+                    resamplers[i].link(0, &mut anlms, 1);
+                    anlms.link(0, &mut amix, i as u32);
+                    echo_cancellers.push(anlms);
+                }
+
+                for (i, resample) in resamplers.iter_mut().enumerate() {
+                    if i != reference && !echo_targets.contains(&i) {
+                        resample.link(0, &mut amix, i as u32);
+                    }
+                }
+
+                echo_cancellers.push(split);
+            }
+            _ => {
+                for (i, resample) in resamplers.iter_mut().enumerate() {
+                    resample.link(0, &mut amix, i as u32);
+                }
+            }
         }
 
         amix.link(0, &mut aformat, 0);
@@ -166,6 +228,7 @@ impl AudioMixerBuilder {
             abuffersink,
             output,
             _resamplers: resamplers,
+            _echo_cancellers: echo_cancellers,
             _filter_graph: filter_graph,
             _amix: amix,
             _aformat: aformat,
@@ -183,8 +246,6 @@ impl AudioMixerBuilder {
         ready_tx: oneshot::Sender<anyhow::Result<()>>,
         stop_flag: Arc<AtomicBool>,
     ) {
-        let start = Timestamps::now();
-
         let mut mixer = match self.build(output) {
             Ok(mixer) => mixer,
             Err(e) => {
@@ -202,15 +263,7 @@ impl AudioMixerBuilder {
                 break;
             }
 
-            #[cfg(target_os = "macos")]
-            let now = Timestamp::MachAbsoluteTime(cap_timestamp::MachAbsoluteTimestamp::now());
-            #[cfg(windows)]
-            let now =
-                Timestamp::PerformanceCounter(cap_timestamp::PerformanceCounterTimestamp::now());
-            #[cfg(not(any(target_os = "macos", windows)))]
-            let now = Timestamp::Instant(Instant::now());
-
-            if let Err(()) = mixer.tick(start, now) {
+            if let Err(()) = mixer.tick(Timestamp::now()) {
                 info!("Mixer tick errored");
                 break;
             }
@@ -228,6 +281,7 @@ pub struct AudioMixer {
     abuffers: Vec<ffmpeg::filter::Context>,
     abuffersink: ffmpeg::filter::Context,
     _resamplers: Vec<ffmpeg::filter::Context>,
+    _echo_cancellers: Vec<ffmpeg::filter::Context>,
     _filter_graph: ffmpeg::filter::Graph,
     _amix: ffmpeg::filter::Context,
     _aformat: ffmpeg::filter::Context,
@@ -442,7 +496,7 @@ impl AudioMixer {
         }
     }
 
-    fn tick(&mut self, _start: Timestamps, now: Timestamp) -> Result<(), ()> {
+    fn tick(&mut self, now: Timestamp) -> Result<(), ()> {
         self.buffer_sources(now);
 
         let Some(start_timestamp) = self.start_timestamp else {
@@ -591,10 +645,9 @@ mod test {
         .await
         .unwrap();
 
-        let _ = mixer.tick(
-            start,
-            Timestamp::Instant(start.instant() + Duration::from_secs_f64(4.0 / SAMPLE_RATE as f64)),
-        );
+        let _ = mixer.tick(Timestamp::Instant(
+            start.instant() + Duration::from_secs_f64(4.0 / SAMPLE_RATE as f64),
+        ));
 
         let frame = output_rx.next().await.expect("No output frame");
 
@@ -608,6 +661,38 @@ mod test {
         assert_eq!(samples[4], samples[5]);
     }
 
+    #[tokio::test]
+    async fn mix_loopback_source() {
+        let (tx, mut output_rx) = mpsc::channel(4);
+        let mut mixer = AudioMixerBuilder::new();
+
+        let (mut mic_tx, rx) = mpsc::channel(4);
+        mixer.add_source(SOURCE_INFO, rx);
+
+        let (mut loopback_tx, rx) = mpsc::channel(4);
+        mixer.add_loopback_source(SOURCE_INFO, rx);
+
+        let mut mixer = mixer.build(tx).unwrap();
+        assert_eq!(mixer._echo_cancellers.len(), 2);
+        let start = mixer.timestamps;
+
+        for tx in [&mut mic_tx, &mut loopback_tx] {
+            tx.send(AudioFrame::new(
+                SOURCE_INFO.wrap_frame(&[128; 4]),
+                Timestamp::Instant(start.instant()),
+            ))
+            .await
+            .unwrap();
+        }
+
+        let _ = mixer.tick(Timestamp::Instant(
+            start.instant() + Duration::from_secs_f64(4.0 / SAMPLE_RATE as f64),
+        ));
+
+        let frame = output_rx.next().await.expect("No output frame");
+        assert_eq!(frame.channels(), 2);
+    }
+
     mod source_buffer {
         use super::*;
 
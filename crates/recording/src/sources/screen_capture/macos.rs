@@ -769,6 +769,8 @@ pub struct SystemAudioSource {
 impl output_pipeline::AudioSource for SystemAudioSource {
     type Config = SystemAudioSourceConfig;
 
+    const LOOPBACK: bool = true;
+
     fn setup(
         config: Self::Config,
         tx: mpsc::Sender<AudioFrame>,
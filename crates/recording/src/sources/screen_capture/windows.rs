@@ -982,6 +982,8 @@ fn create_system_audio_capturer(
 impl output_pipeline::AudioSource for SystemAudioSource {
     type Config = SystemAudioSourceConfig;
 
+    const LOOPBACK: bool = true;
+
     fn setup(
         _: Self::Config,
         tx: mpsc::Sender<AudioFrame>,
@@ -17,6 +17,7 @@ use crate::{
     output_pipeline::{DoneFut, FinishedOutputPipeline, OutputPipeline, PipelineDoneError},
     screen_capture::ScreenCaptureConfig,
     sources::{self, screen_capture},
+    window_tracking::WindowTracker,
 };
 
 #[cfg(windows)]
@@ -27,17 +28,17 @@ use crate::output_pipeline::{
 use anyhow::{Context as _, anyhow, bail};
 use cap_media_info::VideoInfo;
 use cap_project::{
-    CursorEvents, MultipleSegments, Platform, RecordingMeta, RecordingMetaInner,
+    CursorEvents, KeystrokeCapture, MultipleSegments, Platform, RecordingMeta, RecordingMetaInner,
     StudioRecordingMeta, StudioRecordingStatus,
 };
-use cap_timestamp::{Timestamp, Timestamps};
+use cap_timestamp::{SessionClock, Timestamp, Timestamps};
 use futures::{FutureExt, StreamExt, future::OptionFuture, stream::FuturesUnordered};
 use kameo::{Actor as _, prelude::*};
 use relative_path::RelativePathBuf;
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
-    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 use tokio::sync::watch;
 use tracing::{Instrument, debug, error_span, info, trace, warn};
@@ -48,8 +49,6 @@ enum ActorState {
         pipeline: Pipeline,
         // pipeline_done_rx: oneshot::Receiver<Result<(), String>>,
         index: u32,
-        segment_start_time: f64,
-        segment_start_instant: Instant,
     },
     Paused {
         next_index: u32,
@@ -73,21 +72,21 @@ pub struct Actor {
     segment_factory: SegmentPipelineFactory,
     segments: Vec<RecordingSegment>,
     completion_tx: watch::Sender<Option<Result<(), PipelineDoneError>>>,
+    clock: SessionClock,
 }
 
 impl Actor {
-    async fn stop_pipeline(
-        &mut self,
-        pipeline: Pipeline,
-        segment_start_time: f64,
-    ) -> anyhow::Result<(Cursors, u32)> {
+    async fn stop_pipeline(&mut self, pipeline: Pipeline) -> anyhow::Result<(Cursors, u32)> {
         tracing::info!("pipeline shuting down");
 
         let mut pipeline = pipeline.stop().await?;
 
         tracing::info!("pipeline shutdown");
 
-        let segment_stop_time = current_time_f64();
+        let segment_start_time = self
+            .clock
+            .unix_secs(Timestamp::Instant(pipeline.start_time.instant()));
+        let segment_stop_time = self.clock.unix_secs(Timestamp::now());
 
         let cursors = if let Some(cursor) = pipeline.cursor.as_mut()
             && let Ok(res) = cursor.actor.rx.clone().await
@@ -97,6 +96,9 @@ impl Actor {
                 serde_json::to_string_pretty(&CursorEvents {
                     clicks: res.clicks,
                     moves: res.moves,
+                    key_presses: res.key_presses,
+                    keys: res.keys,
+                    focus_changes: res.focus_changes,
                 })?,
             )?;
 
@@ -131,17 +133,14 @@ impl Message<Stop> for Actor {
 
     async fn handle(&mut self, _: Stop, ctx: &mut Context<Self, Self::Reply>) -> Self::Reply {
         let cursors = match self.state.take() {
-            Some(ActorState::Recording {
-                pipeline,
-                segment_start_time,
-                segment_start_instant,
-                ..
-            }) => {
+            Some(ActorState::Recording { pipeline, .. }) => {
                 // Wait for minimum segment duration
-                tokio::time::sleep_until((segment_start_instant + Duration::from_secs(1)).into())
-                    .await;
+                tokio::time::sleep_until(
+                    (pipeline.start_time.instant() + Duration::from_secs(1)).into(),
+                )
+                .await;
 
-                let (cursors, _) = self.stop_pipeline(pipeline, segment_start_time).await?;
+                let (cursors, _) = self.stop_pipeline(pipeline).await?;
 
                 cursors
             }
@@ -173,13 +172,10 @@ impl Message<Pause> for Actor {
     async fn handle(&mut self, _: Pause, _: &mut Context<Self, Self::Reply>) -> Self::Reply {
         self.state = match self.state.take() {
             Some(ActorState::Recording {
-                pipeline,
-                segment_start_time,
-                index,
-                ..
+                pipeline, index, ..
             }) => {
                 let (cursors, next_cursor_id) = self
-                    .stop_pipeline(pipeline, segment_start_time)
+                    .stop_pipeline(pipeline)
                     .await
                     .context("stop_pipeline")?;
 
@@ -213,13 +209,9 @@ impl Message<Resume> for Actor {
                     .create_next(cursors, next_cursor_id)
                     .await?;
 
-                let new_segment_start_time = current_time_f64();
-
                 Some(ActorState::Recording {
                     pipeline,
                     index: next_index,
-                    segment_start_time: new_segment_start_time,
-                    segment_start_instant: Instant::now(),
                 })
             }
             state => state,
@@ -478,6 +470,8 @@ pub struct ActorBuilder {
     mic_feed: Option<Arc<MicrophoneFeedLock>>,
     camera_feed: Option<Arc<CameraFeedLock>>,
     custom_cursor: bool,
+    anonymize_input: bool,
+    keystrokes: KeystrokeCapture,
     fragmented: bool,
     max_fps: u32,
     #[cfg(target_os = "macos")]
@@ -493,6 +487,8 @@ impl ActorBuilder {
             mic_feed: None,
             camera_feed: None,
             custom_cursor: false,
+            anonymize_input: false,
+            keystrokes: KeystrokeCapture::Off,
             fragmented: false,
             max_fps: 60,
             #[cfg(target_os = "macos")]
@@ -520,6 +516,16 @@ impl ActorBuilder {
         self
     }
 
+    pub fn with_anonymized_input(mut self, anonymize_input: bool) -> Self {
+        self.anonymize_input = anonymize_input;
+        self
+    }
+
+    pub fn with_keystrokes(mut self, keystrokes: KeystrokeCapture) -> Self {
+        self.keystrokes = keystrokes;
+        self
+    }
+
     pub fn with_fragmented(mut self, fragmented: bool) -> Self {
         self.fragmented = fragmented;
         self
@@ -553,6 +559,8 @@ impl ActorBuilder {
                 excluded_windows: self.excluded_windows,
             },
             self.custom_cursor,
+            self.anonymize_input,
+            self.keystrokes,
             self.fragmented,
             self.max_fps,
         )
@@ -565,6 +573,8 @@ async fn spawn_studio_recording_actor(
     recording_dir: PathBuf,
     base_inputs: RecordingBaseInputs,
     custom_cursor_capture: bool,
+    anonymize_input: bool,
+    keystrokes: KeystrokeCapture,
     fragmented: bool,
     max_fps: u32,
 ) -> anyhow::Result<ActorHandle> {
@@ -594,6 +604,8 @@ async fn spawn_studio_recording_actor(
         cursors_dir,
         base_inputs.clone(),
         custom_cursor_capture,
+        anonymize_input,
+        keystrokes,
         fragmented,
         max_fps,
         completion_tx.clone(),
@@ -603,6 +615,8 @@ async fn spawn_studio_recording_actor(
         write_in_progress_meta(&recording_dir)?;
     }
 
+    let clock = SessionClock::new(Timestamps::now());
+
     let index = 0;
     let pipeline = segment_pipeline_factory
         .create_next(Default::default(), 0)
@@ -610,8 +624,6 @@ async fn spawn_studio_recording_actor(
 
     let done_fut = completion_rx_to_done_fut(completion_rx);
 
-    let segment_start_time = current_time_f64();
-
     trace!("spawning recording actor");
 
     let base_inputs = base_inputs.clone();
@@ -622,12 +634,11 @@ async fn spawn_studio_recording_actor(
             pipeline,
             /*pipeline_done_rx,*/
             index,
-            segment_start_time,
-            segment_start_instant: Instant::now(),
         }),
         segment_factory: segment_pipeline_factory,
         segments: Vec::new(),
         completion_tx: completion_tx.clone(),
+        clock,
     });
 
     Ok(ActorHandle {
@@ -852,6 +863,8 @@ struct SegmentPipelineFactory {
     cursors_dir: PathBuf,
     base_inputs: RecordingBaseInputs,
     custom_cursor_capture: bool,
+    anonymize_input: bool,
+    keystrokes: KeystrokeCapture,
     fragmented: bool,
     max_fps: u32,
     index: u32,
@@ -867,6 +880,8 @@ impl SegmentPipelineFactory {
         cursors_dir: PathBuf,
         base_inputs: RecordingBaseInputs,
         custom_cursor_capture: bool,
+        anonymize_input: bool,
+        keystrokes: KeystrokeCapture,
         fragmented: bool,
         max_fps: u32,
         completion_tx: watch::Sender<Option<Result<(), PipelineDoneError>>>,
@@ -876,6 +891,8 @@ impl SegmentPipelineFactory {
             cursors_dir,
             base_inputs,
             custom_cursor_capture,
+            anonymize_input,
+            keystrokes,
             fragmented,
             max_fps,
             index: 0,
@@ -899,6 +916,8 @@ impl SegmentPipelineFactory {
             cursors,
             next_cursors_id,
             self.custom_cursor_capture,
+            self.anonymize_input,
+            self.keystrokes,
             self.fragmented,
             self.max_fps,
             segment_start_time,
@@ -983,14 +1002,19 @@ async fn create_segment_pipeline(
     prev_cursors: Cursors,
     next_cursors_id: u32,
     custom_cursor_capture: bool,
+    anonymize_input: bool,
+    keystrokes: KeystrokeCapture,
     fragmented: bool,
     max_fps: u32,
     start_time: Timestamps,
     #[cfg(windows)] encoder_preferences: crate::capture_pipeline::EncoderPreferences,
 ) -> anyhow::Result<Pipeline> {
     #[cfg(windows)]
-    let d3d_device = crate::capture_pipeline::create_d3d_device()
-        .context("D3D11 device creation failed - this may happen in VMs, RDP sessions, or systems without GPU drivers")?;
+    let d3d_device = match base_inputs.capture_target.display() {
+        Some(display) => crate::capture_pipeline::create_d3d_device_for_display(&display),
+        None => crate::capture_pipeline::create_d3d_device(),
+    }
+    .context("D3D11 device creation failed - this may happen in VMs, RDP sessions, or systems without GPU drivers")?;
 
     let dir = ensure_dir(&segments_dir.join(format!("segment-{index}")))?;
 
@@ -1234,14 +1258,22 @@ async fn create_segment_pipeline(
 
                 let cursor_display = cursor_display.ok_or(CreateSegmentPipelineError::NoDisplay)?;
 
+                let window_tracker = base_inputs
+                    .capture_target
+                    .window()
+                    .and_then(|id| WindowTracker::new(&id));
+
                 let cursor = spawn_cursor_recorder(
                     cursor_crop_bounds,
                     cursor_display,
+                    window_tracker,
                     cursors_dir.to_path_buf(),
                     prev_cursors,
                     next_cursors_id,
                     start_time,
                     incremental_output,
+                    anonymize_input,
+                    keystrokes,
                 );
 
                 Ok::<_, CreateSegmentPipelineError>(CursorPipeline {
@@ -1269,13 +1301,6 @@ fn ensure_dir(path: &PathBuf) -> Result<PathBuf, MediaError> {
     Ok(path.clone())
 }
 
-fn current_time_f64() -> f64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs_f64()
-}
-
 fn write_in_progress_meta(recording_dir: &Path) -> anyhow::Result<()> {
     use chrono::Local;
 
@@ -0,0 +1,227 @@
+use cap_cursor_capture::{CursorCropBounds, RawCursorPosition};
+use cap_project::{WindowFocusBounds, WindowFocusEvent, XY};
+use scap_targets::{Display, Window, WindowId};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl WindowRect {
+    pub fn normalize(&self, x: f64, y: f64) -> Option<XY<f64>> {
+        if self.width <= 0.0 || self.height <= 0.0 {
+            return None;
+        }
+
+        Some(XY::new(
+            (x - self.x) / self.width,
+            (y - self.y) / self.height,
+        ))
+    }
+
+    pub fn relative_to(&self, area: &WindowRect) -> Option<WindowFocusBounds> {
+        let top_left = area.normalize(self.x, self.y)?;
+
+        Some(WindowFocusBounds {
+            x: top_left.x,
+            y: top_left.y,
+            width: self.width / area.width,
+            height: self.height / area.height,
+        })
+    }
+
+    fn of_window(window: &Window) -> Option<Self> {
+        #[cfg(target_os = "macos")]
+        let bounds = window.raw_handle().logical_bounds()?;
+        #[cfg(windows)]
+        let bounds = window.raw_handle().physical_bounds()?;
+
+        Some(Self {
+            x: bounds.position().x(),
+            y: bounds.position().y(),
+            width: bounds.size().width(),
+            height: bounds.size().height(),
+        })
+    }
+
+    pub fn of_display_crop(display: &Display, crop: CursorCropBounds) -> Option<Self> {
+        #[cfg(target_os = "macos")]
+        let origin = display.raw_handle().logical_bounds()?.position();
+        #[cfg(windows)]
+        let origin = display.raw_handle().physical_bounds()?.position();
+
+        Some(Self {
+            x: origin.x() + crop.x(),
+            y: origin.y() + crop.y(),
+            width: crop.width(),
+            height: crop.height(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowFrame {
+    pub time_ms: f64,
+    pub rect: WindowRect,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct WindowTrack {
+    frames: Vec<WindowFrame>,
+}
+
+impl WindowTrack {
+    pub fn push(&mut self, frame: WindowFrame) -> bool {
+        if let Some(last) = self.frames.last()
+            && last.rect == frame.rect
+        {
+            return false;
+        }
+
+        self.frames.push(frame);
+        true
+    }
+
+    pub fn frame_at(&self, time_ms: f64) -> Option<&WindowFrame> {
+        let index = self.frames.partition_point(|f| f.time_ms <= time_ms);
+        self.frames.get(index.saturating_sub(1))
+    }
+
+    pub fn map(&self, time_ms: f64, x: f64, y: f64) -> Option<XY<f64>> {
+        self.frame_at(time_ms)?.rect.normalize(x, y)
+    }
+}
+
+pub struct WindowTracker {
+    window: Window,
+    track: WindowTrack,
+}
+
+impl WindowTracker {
+    pub fn new(id: &WindowId) -> Option<Self> {
+        Some(Self {
+            window: Window::from_id(id)?,
+            track: WindowTrack::default(),
+        })
+    }
+
+    pub fn sample(&mut self, time_ms: f64) -> bool {
+        let Some(rect) = self.current_rect() else {
+            return false;
+        };
+
+        self.track.push(WindowFrame { time_ms, rect })
+    }
+
+    pub fn map(&self, time_ms: f64, position: RawCursorPosition) -> Option<XY<f64>> {
+        self.track
+            .map(time_ms, position.x() as f64, position.y() as f64)
+    }
+
+    pub fn rect_at(&self, time_ms: f64) -> Option<WindowRect> {
+        self.track.frame_at(time_ms).map(|frame| frame.rect)
+    }
+
+    fn current_rect(&self) -> Option<WindowRect> {
+        WindowRect::of_window(&self.window)
+    }
+}
+
+#[derive(Default)]
+pub struct FocusTracker {
+    focused: Option<WindowId>,
+}
+
+impl FocusTracker {
+    pub fn sample(
+        &mut self,
+        time_ms: f64,
+        area: Option<WindowRect>,
+        include_title: bool,
+    ) -> Option<WindowFocusEvent> {
+        let window = Window::get_focused()?;
+        let id = window.id();
+        if self.focused.as_ref() == Some(&id) {
+            return None;
+        }
+        self.focused = Some(id);
+
+        Some(WindowFocusEvent {
+            time_ms,
+            app_name: window.owner_name(),
+            title: include_title.then(|| window.name()).flatten(),
+            bounds: WindowRect::of_window(&window)
+                .zip(area)
+                .and_then(|(rect, area)| rect.relative_to(&area)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(time_ms: f64, x: f64, y: f64, width: f64, height: f64) -> WindowFrame {
+        WindowFrame {
+            time_ms,
+            rect: WindowRect {
+                x,
+                y,
+                width,
+                height,
+            },
+        }
+    }
+
+    #[test]
+    fn maps_into_window_as_it_moves() {
+        let mut track = WindowTrack::default();
+        assert!(track.push(frame(0.0, 100.0, 100.0, 400.0, 200.0)));
+        assert!(!track.push(frame(500.0, 100.0, 100.0, 400.0, 200.0)));
+        assert!(track.push(frame(1000.0, 300.0, 200.0, 400.0, 200.0)));
+
+        assert_eq!(track.frame_at(750.0).map(|frame| frame.time_ms), Some(0.0));
+
+        assert_eq!(track.map(750.0, 300.0, 200.0), Some(XY::new(0.5, 0.5)));
+        assert_eq!(track.map(1000.0, 300.0, 200.0), Some(XY::new(0.0, 0.0)));
+        assert_eq!(track.map(-10.0, 100.0, 300.0), Some(XY::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn rejects_empty_windows() {
+        let mut track = WindowTrack::default();
+        assert_eq!(track.map(0.0, 0.0, 0.0), None);
+
+        track.push(frame(0.0, 0.0, 0.0, 0.0, 100.0));
+        assert_eq!(track.map(0.0, 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn focus_bounds_are_relative_to_the_capture() {
+        let area = WindowRect {
+            x: 100.0,
+            y: 50.0,
+            width: 800.0,
+            height: 400.0,
+        };
+        let window = WindowRect {
+            x: 500.0,
+            y: 50.0,
+            width: 800.0,
+            height: 200.0,
+        };
+
+        assert_eq!(
+            window.relative_to(&area),
+            Some(WindowFocusBounds {
+                x: 0.5,
+                y: 0.0,
+                width: 1.0,
+                height: 0.5,
+            })
+        );
+    }
+}
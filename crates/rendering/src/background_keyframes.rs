@@ -0,0 +1,243 @@
+use cap_project::{BackgroundConfiguration, BackgroundSource, Color, ProjectConfiguration};
+
+const TRANSITION_MS: f64 = 500.0;
+
+pub(crate) fn background_at(
+    project: &ProjectConfiguration,
+    time: f64,
+) -> Option<BackgroundConfiguration> {
+    if project.background_keyframes.is_empty() {
+        return None;
+    }
+
+    let time_ms = time * 1000.0;
+    let mut keyframes = project.background_keyframes.iter().collect::<Vec<_>>();
+    keyframes.sort_by(|a, b| a.start_ms.total_cmp(&b.start_ms));
+
+    let index = keyframes.iter().rposition(|k| k.start_ms <= time_ms)?;
+    let current = keyframes[index];
+    let previous = match index {
+        0 => &project.background,
+        _ => &keyframes[index - 1].config,
+    };
+
+    let t = ((time_ms - current.start_ms) / TRANSITION_MS).clamp(0.0, 1.0);
+    let mut background = lerp_background(previous, &current.config, t * t * (3.0 - 2.0 * t));
+    background.crop = project.background.crop.clone();
+
+    Some(background)
+}
+
+pub(crate) fn settled_background_at(
+    project: &ProjectConfiguration,
+    time: f64,
+) -> BackgroundConfiguration {
+    let time_ms = time * 1000.0;
+    let mut background = project
+        .background_keyframes
+        .iter()
+        .filter(|k| k.start_ms <= time_ms)
+        .max_by(|a, b| a.start_ms.total_cmp(&b.start_ms))
+        .map_or_else(|| project.background.clone(), |k| k.config.clone());
+    background.crop = project.background.crop.clone();
+
+    background
+}
+
+fn lerp_background(
+    from: &BackgroundConfiguration,
+    to: &BackgroundConfiguration,
+    t: f64,
+) -> BackgroundConfiguration {
+    if t >= 1.0 {
+        return to.clone();
+    }
+
+    let discrete = if t < 0.5 { from } else { to };
+    let lerp = |a: f64, b: f64| a + (b - a) * t;
+
+    BackgroundConfiguration {
+        source: lerp_source(&from.source, &to.source, t),
+        blur: lerp(from.blur, to.blur),
+        padding: lerp(from.padding, to.padding),
+        rounding: lerp(from.rounding, to.rounding),
+        rounding_type: discrete.rounding_type,
+        inset: lerp(from.inset as f64, to.inset as f64).round() as u32,
+        crop: discrete.crop.clone(),
+        shadow: lerp(from.shadow as f64, to.shadow as f64) as f32,
+        advanced_shadow: discrete.advanced_shadow.clone(),
+        border: discrete.border.clone(),
+        zoom_dim: lerp(from.zoom_dim as f64, to.zoom_dim as f64) as f32,
+        fit: discrete.fit,
+    }
+}
+
+fn lerp_source(from: &BackgroundSource, to: &BackgroundSource, t: f64) -> BackgroundSource {
+    let lerp_color = |a: Color, b: Color| {
+        [0, 1, 2].map(|i| (a[i] as f64 + (b[i] as f64 - a[i] as f64) * t).round() as u16)
+    };
+
+    if let (
+        BackgroundSource::Color {
+            value: from_value,
+            alpha: from_alpha,
+        },
+        BackgroundSource::Color { value, alpha },
+    ) = (from, to)
+    {
+        return BackgroundSource::Color {
+            value: lerp_color(*from_value, *value),
+            alpha: (*from_alpha as f64 + (*alpha as f64 - *from_alpha as f64) * t).round() as u8,
+        };
+    }
+
+    if let (Some((from_a, from_b, from_angle)), Some((to_a, to_b, to_angle))) =
+        (gradient_stops(from, to), gradient_stops(to, from))
+        && let Some(BackgroundSource::Gradient {
+            noise_intensity,
+            noise_scale,
+            animated,
+            animation_speed,
+            ..
+        }) = [to, from]
+            .into_iter()
+            .find(|s| matches!(s, BackgroundSource::Gradient { .. }))
+    {
+        return BackgroundSource::Gradient {
+            from: lerp_color(from_a, to_a),
+            to: lerp_color(from_b, to_b),
+            angle: (from_angle as f64 + (to_angle as f64 - from_angle as f64) * t).round() as u16,
+            noise_intensity: *noise_intensity,
+            noise_scale: *noise_scale,
+            animated: *animated,
+            animation_speed: *animation_speed,
+        };
+    }
+
+    if t < 0.5 { from.clone() } else { to.clone() }
+}
+
+fn gradient_stops(
+    source: &BackgroundSource,
+    other: &BackgroundSource,
+) -> Option<(Color, Color, u16)> {
+    match source {
+        BackgroundSource::Gradient {
+            from, to, angle, ..
+        } => Some((*from, *to, *angle)),
+        BackgroundSource::Color { value, .. } => {
+            let angle = match other {
+                BackgroundSource::Gradient { angle, .. } => *angle,
+                _ => 90,
+            };
+            Some((*value, *value, angle))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cap_project::BackgroundKeyframe;
+
+    use super::*;
+
+    fn color(value: Color) -> BackgroundConfiguration {
+        BackgroundConfiguration {
+            source: BackgroundSource::Color { value, alpha: 255 },
+            ..Default::default()
+        }
+    }
+
+    fn source_color(background: &BackgroundConfiguration) -> Color {
+        match background.source {
+            BackgroundSource::Color { value, .. } => value,
+            _ => panic!("expected a color background"),
+        }
+    }
+
+    #[test]
+    fn blends_into_keyframes() {
+        let project = ProjectConfiguration {
+            background: color([0, 0, 0]),
+            background_keyframes: vec![
+                BackgroundKeyframe {
+                    start_ms: 5000.0,
+                    config: BackgroundConfiguration {
+                        padding: 20.0,
+                        ..color([200, 100, 0])
+                    },
+                },
+                BackgroundKeyframe {
+                    start_ms: 2000.0,
+                    config: color([100, 100, 100]),
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert!(background_at(&project, 1.0).is_none());
+        assert_eq!(
+            source_color(&background_at(&project, 2.25).unwrap()),
+            [50, 50, 50]
+        );
+        assert_eq!(
+            source_color(&background_at(&project, 3.0).unwrap()),
+            [100, 100, 100]
+        );
+
+        let mid = background_at(&project, 5.25).unwrap();
+        assert_eq!(source_color(&mid), [150, 100, 50]);
+        assert_eq!(mid.padding, 10.0);
+    }
+
+    #[test]
+    fn settles_on_the_last_keyframe_reached() {
+        let project = ProjectConfiguration {
+            background: color([0, 0, 0]),
+            background_keyframes: vec![BackgroundKeyframe {
+                start_ms: 2000.0,
+                config: color([100, 100, 100]),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            source_color(&settled_background_at(&project, 1.0)),
+            [0, 0, 0]
+        );
+        assert_eq!(
+            source_color(&settled_background_at(&project, 2.1)),
+            [100, 100, 100]
+        );
+    }
+
+    #[test]
+    fn blends_colors_into_gradients() {
+        let gradient = BackgroundSource::Gradient {
+            from: [200, 0, 0],
+            to: [0, 0, 200],
+            angle: 45,
+            noise_intensity: None,
+            noise_scale: None,
+            animated: None,
+            animation_speed: None,
+        };
+
+        let BackgroundSource::Gradient {
+            from, to, angle, ..
+        } = lerp_source(
+            &BackgroundSource::Color {
+                value: [0, 0, 0],
+                alpha: 255,
+            },
+            &gradient,
+            0.5,
+        )
+        else {
+            panic!("expected a gradient background");
+        };
+
+        assert_eq!((from, to, angle), ([100, 0, 0], [0, 0, 100], 45));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use glyphon::fontdb;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Default)]
+pub struct BrandAssets {
+    pub fonts: Vec<PathBuf>,
+    pub watermark: Option<Watermark>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Watermark {
+    pub image: Arc<image::RgbaImage>,
+    pub corner: WatermarkCorner,
+    pub scale: f32,
+    pub opacity: f32,
+}
+
+#[derive(Serialize, Deserialize, Type, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WatermarkCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl Watermark {
+    pub fn rect(&self, output_size: (u32, u32)) -> [f32; 4] {
+        let (output_width, output_height) = (output_size.0 as f32, output_size.1 as f32);
+        let width = output_width * self.scale.clamp(0.01, 1.0);
+        let height = width * self.image.height() as f32 / self.image.width().max(1) as f32;
+        let margin = output_width.min(output_height) * 0.03;
+
+        let x = match self.corner {
+            WatermarkCorner::TopLeft | WatermarkCorner::BottomLeft => margin,
+            WatermarkCorner::TopRight | WatermarkCorner::BottomRight => {
+                output_width - width - margin
+            }
+        };
+        let y = match self.corner {
+            WatermarkCorner::TopLeft | WatermarkCorner::TopRight => margin,
+            WatermarkCorner::BottomLeft | WatermarkCorner::BottomRight => {
+                output_height - height - margin
+            }
+        };
+
+        [x, y, width, height]
+    }
+}
+
+pub fn font_families(path: &Path) -> std::io::Result<Vec<String>> {
+    let mut db = fontdb::Database::new();
+    db.load_font_file(path)?;
+
+    let mut families = db
+        .faces()
+        .flat_map(|face| face.families.iter().map(|(name, _)| name.clone()))
+        .collect::<Vec<_>>();
+    families.dedup();
+    Ok(families)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watermark(corner: WatermarkCorner) -> Watermark {
+        Watermark {
+            image: Arc::new(image::RgbaImage::new(200, 100)),
+            corner,
+            scale: 0.1,
+            opacity: 1.0,
+        }
+    }
+
+    #[test]
+    fn rect_is_inset_in_its_corner_and_keeps_aspect_ratio() {
+        let size = (1920, 1080);
+        let margin = 1080.0 * 0.03;
+        let width = 1920.0 * 0.1;
+        let height = width / 2.0;
+
+        assert_eq!(
+            watermark(WatermarkCorner::TopLeft).rect(size),
+            [margin, margin, width, height]
+        );
+        assert_eq!(
+            watermark(WatermarkCorner::BottomRight).rect(size),
+            [
+                1920.0 - width - margin,
+                1080.0 - height - margin,
+                width,
+                height
+            ]
+        );
+    }
+}
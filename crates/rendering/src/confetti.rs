@@ -0,0 +1,231 @@
+use bytemuck::{Pod, Zeroable};
+use cap_project::ConfettiBurst;
+
+use crate::spring_mass_damper::solve_spring_1d;
+
+const LIFETIME_SECS: f64 = 3.0;
+const FADE_SECS: f64 = 0.6;
+const MAX_PARTICLES_PER_BURST: u32 = 500;
+
+const SPREAD: f32 = 0.6;
+const MIN_SPEED: f32 = 0.9;
+const MAX_SPEED: f32 = 1.6;
+const GRAVITY: f32 = 1.4;
+const MIN_DRAG: f32 = 1.4;
+const MAX_DRAG: f32 = 2.6;
+
+const SWAY_FREQUENCY: f32 = 7.0;
+const SWAY_DAMPING: f32 = 0.06;
+const MAX_SWAY_VELOCITY: f32 = 0.25;
+
+const PALETTE: [[f32; 3]; 6] = [
+    [0.98, 0.33, 0.40],
+    [1.00, 0.76, 0.20],
+    [0.30, 0.78, 0.47],
+    [0.25, 0.60, 0.98],
+    [0.66, 0.40, 0.96],
+    [1.00, 0.55, 0.80],
+];
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct ConfettiParticle {
+    pub center: [f32; 2],
+    pub size: [f32; 2],
+    pub rotation: f32,
+    pub color: [f32; 4],
+}
+
+pub(crate) fn prepare_confetti(
+    bursts: &[ConfettiBurst],
+    time: f64,
+    display_bounds: [f32; 4],
+    frame_height: f32,
+) -> Vec<ConfettiParticle> {
+    let mut particles = Vec::new();
+
+    for burst in bursts {
+        let elapsed = time - burst.time;
+        if !(0.0..LIFETIME_SECS).contains(&elapsed) {
+            continue;
+        }
+
+        let opacity = ((LIFETIME_SECS - elapsed) / FADE_SECS).min(1.0) as f32;
+        let origin = [
+            display_bounds[0] + (display_bounds[2] - display_bounds[0]) * burst.origin.x as f32,
+            display_bounds[1] + (display_bounds[3] - display_bounds[1]) * burst.origin.y as f32,
+        ];
+
+        let seed = burst.time.to_bits() ^ burst.origin.x.to_bits().rotate_left(21);
+        for index in 0..burst.count.min(MAX_PARTICLES_PER_BURST) {
+            let mut random = Random::new(seed ^ u64::from(index).wrapping_mul(0x9E37_79B9));
+            let particle = Particle::new(&mut random);
+            let (offset, rotation, flip) = particle.at(elapsed as f32);
+
+            particles.push(ConfettiParticle {
+                center: [
+                    origin[0] + offset[0] * frame_height,
+                    origin[1] + offset[1] * frame_height,
+                ],
+                size: [
+                    particle.size[0] * frame_height * flip,
+                    particle.size[1] * frame_height,
+                ],
+                rotation,
+                color: [
+                    particle.color[0],
+                    particle.color[1],
+                    particle.color[2],
+                    opacity,
+                ],
+            });
+        }
+    }
+
+    particles
+}
+
+struct Particle {
+    velocity: [f32; 2],
+    drag: f32,
+    sway_velocity: f32,
+    spin: f32,
+    rotation: f32,
+    flip_rate: f32,
+    flip_phase: f32,
+    size: [f32; 2],
+    color: [f32; 3],
+}
+
+impl Particle {
+    fn new(random: &mut Random) -> Self {
+        let angle = random.range(-SPREAD, SPREAD);
+        let speed = random.range(MIN_SPEED, MAX_SPEED);
+        let width = random.range(0.010, 0.018);
+
+        Self {
+            velocity: [speed * angle.sin(), -speed * angle.cos()],
+            drag: random.range(MIN_DRAG, MAX_DRAG),
+            sway_velocity: random.range(-MAX_SWAY_VELOCITY, MAX_SWAY_VELOCITY),
+            spin: random.range(-8.0, 8.0),
+            rotation: random.range(0.0, std::f32::consts::TAU),
+            flip_rate: random.range(4.0, 12.0),
+            flip_phase: random.range(0.0, std::f32::consts::TAU),
+            size: [width, width * random.range(0.4, 0.7)],
+            color: PALETTE[(random.next_u64() % PALETTE.len() as u64) as usize],
+        }
+    }
+
+    fn at(&self, t: f32) -> ([f32; 2], f32, f32) {
+        let k = self.drag;
+        let slowed = (1.0 - (-k * t).exp()) / k;
+        let terminal = GRAVITY / k;
+        let x = self.velocity[0] * slowed;
+        let y = terminal * t + (self.velocity[1] - terminal) * slowed;
+
+        let zeta = SWAY_DAMPING;
+        let (sway, _) = solve_spring_1d(0.0, self.sway_velocity, t, SWAY_FREQUENCY, zeta);
+
+        let rotation = self.rotation + self.spin * t;
+        let flip = (self.flip_phase + self.flip_rate * t).cos().abs().max(0.15);
+
+        ([x + sway, y], rotation, flip)
+    }
+}
+
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        let unit = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        min + (max - min) * unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cap_project::XY;
+
+    const BOUNDS: [f32; 4] = [0.0, 0.0, 1920.0, 1080.0];
+
+    fn burst(time: f64) -> ConfettiBurst {
+        ConfettiBurst {
+            time,
+            origin: XY::new(0.5, 0.8),
+            count: 50,
+        }
+    }
+
+    fn mean_height(time: f64) -> f32 {
+        let particles = prepare_confetti(&[burst(1.0)], time, BOUNDS, 1080.0);
+        particles.iter().map(|p| p.center[1]).sum::<f32>() / particles.len() as f32
+    }
+
+    #[test]
+    fn only_shows_during_a_burst() {
+        let bursts = [burst(1.0)];
+
+        assert!(prepare_confetti(&bursts, 0.9, BOUNDS, 1080.0).is_empty());
+        assert_eq!(prepare_confetti(&bursts, 1.0, BOUNDS, 1080.0).len(), 50);
+        assert!(prepare_confetti(&bursts, 1.0 + LIFETIME_SECS, BOUNDS, 1080.0).is_empty());
+    }
+
+    #[test]
+    fn starts_at_the_origin_within_the_display() {
+        let bounds = [100.0, 50.0, 1100.0, 650.0];
+
+        let particles = prepare_confetti(&[burst(1.0)], 1.0, bounds, 1080.0);
+
+        for particle in particles {
+            assert!((particle.center[0] - 600.0).abs() < 1e-3);
+            assert!((particle.center[1] - 530.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn rises_then_falls() {
+        let launch = mean_height(1.0);
+        let peak = mean_height(1.5);
+        let end = mean_height(3.9);
+
+        assert!(peak < launch);
+        assert!(end > peak);
+    }
+
+    #[test]
+    fn fades_out_at_the_end() {
+        let bursts = [burst(1.0)];
+
+        let early = prepare_confetti(&bursts, 1.5, BOUNDS, 1080.0);
+        let late = prepare_confetti(&bursts, 3.8, BOUNDS, 1080.0);
+
+        assert_eq!(early[0].color[3], 1.0);
+        assert!(late[0].color[3] < 0.5);
+    }
+
+    #[test]
+    fn draws_the_same_confetti_every_time() {
+        let bursts = [burst(1.0)];
+
+        let first = prepare_confetti(&bursts, 2.0, BOUNDS, 1080.0);
+        let second = prepare_confetti(&bursts, 2.0, BOUNDS, 1080.0);
+
+        assert_eq!(
+            bytemuck::cast_slice::<_, u8>(&first),
+            bytemuck::cast_slice::<_, u8>(&second)
+        );
+    }
+}
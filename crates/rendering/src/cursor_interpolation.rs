@@ -11,6 +11,8 @@ const CLICK_REACTION_WINDOW_MS: f64 = 160.0;
 const MIN_MASS: f32 = 0.1;
 const SHAKE_THRESHOLD_UV: f64 = 0.015;
 const SHAKE_DETECTION_WINDOW_MS: f64 = 100.0;
+const CURSOR_SAMPLE_INTERVAL_MS: f64 = 16.0;
+const MAX_INTERPOLATED_GAP_MS: f64 = CURSOR_SAMPLE_INTERVAL_MS * 3.0;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum SpringProfile {
@@ -182,34 +184,77 @@ pub fn interpolate_cursor_with_click_spring(
         );
         interpolate_smoothed_position(&events, time_secs as f64, smoothing_config)
     } else {
-        let (pos, cursor_id, velocity) = cursor.moves.windows(2).find_map(|chunk| {
-            if time_ms >= chunk[0].time_ms && time_ms < chunk[1].time_ms {
-                let c = &chunk[0];
-                let next = &chunk[1];
-                let delta_ms = (next.time_ms - c.time_ms) as f32;
-                let dt = (delta_ms / 1000.0).max(0.000_1);
-                let velocity = XY::new(((next.x - c.x) as f32) / dt, ((next.y - c.y) as f32) / dt);
-                Some((
-                    XY::new(c.x as f32, c.y as f32),
-                    c.cursor_id.clone(),
-                    velocity,
-                ))
-            } else {
-                None
-            }
-        })?;
+        let index = cursor
+            .moves
+            .windows(2)
+            .position(|chunk| time_ms >= chunk[0].time_ms && time_ms < chunk[1].time_ms)?;
+        let (position, velocity) = hermite_cursor(&cursor.moves, index, time_ms);
 
         Some(InterpolatedCursorPosition {
-            position: Coord::new(XY {
-                x: pos.x as f64,
-                y: pos.y as f64,
-            }),
+            position: Coord::new(position),
             velocity,
-            cursor_id,
+            cursor_id: cursor.moves[index].cursor_id.clone(),
         })
     }
 }
 
+fn hermite_cursor(moves: &[CursorMoveEvent], index: usize, time_ms: f64) -> (XY<f64>, XY<f32>) {
+    let is_motion = |from: &CursorMoveEvent, to: &CursorMoveEvent| {
+        to.time_ms - from.time_ms <= MAX_INTERPOLATED_GAP_MS
+    };
+    let slope = |i: usize| {
+        let (from, to) = (&moves[i], &moves[i + 1]);
+        if !is_motion(from, to) {
+            return XY::new(0.0, 0.0);
+        }
+        let dt = ((to.time_ms - from.time_ms) / 1000.0).max(0.000_1);
+        XY::new((to.x - from.x) / dt, (to.y - from.y) / dt)
+    };
+    let tangent = |i: usize| {
+        if i == 0 || i + 1 >= moves.len() {
+            XY::new(0.0, 0.0)
+        } else {
+            let (before, after) = (slope(i - 1), slope(i));
+            XY::new(
+                monotone_tangent(before.x, after.x),
+                monotone_tangent(before.y, after.y),
+            )
+        }
+    };
+
+    let (from, to) = (&moves[index], &moves[index + 1]);
+    if !is_motion(from, to) {
+        return (XY::new(from.x, from.y), XY::new(0.0, 0.0));
+    }
+
+    let h = ((to.time_ms - from.time_ms) / 1000.0).max(0.000_1);
+    let s = ((time_ms - from.time_ms) / 1000.0 / h).clamp(0.0, 1.0);
+    let (p0, p1) = (XY::new(from.x, from.y), XY::new(to.x, to.y));
+    let (m0, m1) = (tangent(index) * h, tangent(index + 1) * h);
+
+    let (s2, s3) = (s * s, s * s * s);
+    let position = p0 * (2.0 * s3 - 3.0 * s2 + 1.0)
+        + m0 * (s3 - 2.0 * s2 + s)
+        + p1 * (-2.0 * s3 + 3.0 * s2)
+        + m1 * (s3 - s2);
+    let velocity = (p0 * (6.0 * s2 - 6.0 * s)
+        + m0 * (3.0 * s2 - 4.0 * s + 1.0)
+        + p1 * (-6.0 * s2 + 6.0 * s)
+        + m1 * (3.0 * s2 - 2.0 * s))
+        / h;
+
+    (position, velocity.map(|v| v as f32))
+}
+
+fn monotone_tangent(before: f64, after: f64) -> f64 {
+    if before * after <= 0.0 {
+        return 0.0;
+    }
+
+    let tangent = (before + after) * 0.5;
+    tangent.signum() * tangent.abs().min(3.0 * before.abs().min(after.abs()))
+}
+
 #[allow(dead_code)]
 fn get_smoothed_cursor_events(
     cursor: &CursorEvents,
@@ -547,4 +592,94 @@ mod tests {
         context.advance_to(340.0);
         assert_eq!(context.profile(340.0), SpringProfile::Default);
     }
+
+    #[test]
+    fn unsmoothed_cursor_velocity_is_continuous_across_moves() {
+        let cursor = CursorEvents {
+            clicks: vec![],
+            moves: vec![
+                cursor_move(0.0, 0.1, 0.5),
+                cursor_move(16.0, 0.2, 0.5),
+                cursor_move(32.0, 0.5, 0.5),
+                cursor_move(48.0, 0.6, 0.5),
+            ],
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
+        };
+        let at = |time_secs: f32| interpolate_cursor(&cursor, time_secs, None).unwrap();
+
+        let sample = at(0.032);
+        assert!((sample.position.coord.x - 0.5).abs() < 1e-6);
+
+        let before = at(0.03199).velocity.x;
+        let after = at(0.03201).velocity.x;
+        assert!(
+            (before - after).abs() < 0.1,
+            "velocity jumped from {before} to {after}"
+        );
+        assert!((sample.velocity.x - 12.5).abs() < 1e-3);
+
+        assert!(at(0.0).velocity.x.abs() < 0.1);
+        assert!(at(0.04795).velocity.x.abs() < 0.1);
+    }
+
+    #[test]
+    fn unsmoothed_cursor_holds_still_across_idle_gaps() {
+        let cursor = CursorEvents {
+            clicks: vec![],
+            moves: vec![
+                cursor_move(0.0, 0.1, 0.5),
+                cursor_move(16.0, 0.2, 0.5),
+                cursor_move(5000.0, 0.3, 0.5),
+                cursor_move(5016.0, 0.4, 0.5),
+            ],
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
+        };
+        let at = |time_secs: f32| interpolate_cursor(&cursor, time_secs, None).unwrap();
+
+        for time_secs in [0.02, 1.0, 2.5, 4.99] {
+            let sample = at(time_secs);
+            assert_eq!(sample.position.coord.x, 0.2, "drifted at {time_secs}s");
+            assert_eq!(sample.velocity.x, 0.0);
+        }
+    }
+
+    #[test]
+    fn unsmoothed_cursor_does_not_overshoot() {
+        let cursor = CursorEvents {
+            clicks: vec![],
+            moves: vec![
+                cursor_move(0.0, 0.2, 0.5),
+                cursor_move(16.0, 0.2, 0.5),
+                cursor_move(32.0, 0.8, 0.5),
+                cursor_move(48.0, 0.8, 0.5),
+                cursor_move(64.0, 0.9, 0.5),
+            ],
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
+        };
+
+        for step in 0..640 {
+            let time_secs = step as f32 / 10_000.0;
+            let x = interpolate_cursor(&cursor, time_secs, None)
+                .unwrap()
+                .position
+                .coord
+                .x;
+            let (low, high) = match time_secs {
+                t if t < 0.016 => (0.2, 0.2),
+                t if t < 0.032 => (0.2, 0.8),
+                t if t < 0.048 => (0.8, 0.8),
+                _ => (0.8, 0.9),
+            };
+            assert!(
+                (low - 1e-9..=high + 1e-9).contains(&x),
+                "{x} is outside {low}..={high} at {time_secs}s"
+            );
+        }
+    }
 }
@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use cap_cursor_info::CursorRole;
+use cap_project::XY;
+use serde::Deserialize;
+
+const MANIFEST_FILE: &str = "theme.json";
+const SPRITE_EXTENSIONS: [&str; 2] = ["svg", "png"];
+
+#[derive(Debug, Clone)]
+pub struct CursorTheme {
+    pub id: String,
+    pub name: String,
+    pub sprites: HashMap<CursorRole, CursorSprite>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CursorSprite {
+    pub path: PathBuf,
+    pub hotspot: XY<f64>,
+}
+
+impl CursorSprite {
+    pub fn is_svg(&self) -> bool {
+        self.path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct Manifest {
+    name: Option<String>,
+    #[serde(default)]
+    hotspots: HashMap<CursorRole, (f64, f64)>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CursorThemeError {
+    #[error("IO: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid {MANIFEST_FILE}: {0}")]
+    Manifest(#[from] serde_json::Error),
+    #[error("Theme '{0}' contains no cursor sprites")]
+    Empty(String),
+}
+
+impl CursorTheme {
+    pub fn load(dir: &Path) -> Result<Self, CursorThemeError> {
+        let id = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let manifest: Manifest = if manifest_path.exists() {
+            serde_json::from_slice(&std::fs::read(manifest_path)?)?
+        } else {
+            Manifest::default()
+        };
+
+        let sprites = CursorRole::ALL
+            .into_iter()
+            .filter_map(|role| {
+                let path = SPRITE_EXTENSIONS
+                    .iter()
+                    .map(|ext| dir.join(role.file_stem()).with_extension(ext))
+                    .find(|path| path.is_file())?;
+                let hotspot = manifest
+                    .hotspots
+                    .get(&role)
+                    .copied()
+                    .unwrap_or_else(|| default_hotspot(role));
+
+                Some((
+                    role,
+                    CursorSprite {
+                        path,
+                        hotspot: hotspot.into(),
+                    },
+                ))
+            })
+            .collect::<HashMap<_, _>>();
+
+        if sprites.is_empty() {
+            return Err(CursorThemeError::Empty(id));
+        }
+
+        Ok(Self {
+            name: manifest.name.unwrap_or_else(|| id.clone()),
+            id,
+            sprites,
+        })
+    }
+
+    pub fn list(root: &Path) -> Vec<Self> {
+        let Ok(entries) = std::fs::read_dir(root) else {
+            return vec![];
+        };
+
+        let mut themes = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                Self::load(&entry.path())
+                    .map_err(|e| {
+                        tracing::warn!("Skipping cursor theme {}: {e}", entry.path().display())
+                    })
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        themes.sort_by(|a, b| a.name.cmp(&b.name));
+        themes
+    }
+
+    pub fn sprite(&self, role: CursorRole) -> Option<&CursorSprite> {
+        self.sprites.get(&role)
+    }
+}
+
+fn default_hotspot(role: CursorRole) -> (f64, f64) {
+    match role {
+        CursorRole::Arrow => (0.0, 0.0),
+        CursorRole::Pointer => (0.4, 0.0),
+        CursorRole::IBeam | CursorRole::Hand => (0.5, 0.5),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_sprites_and_manifest_hotspots() {
+        let dir = tempfile::tempdir().unwrap();
+        let theme_dir = dir.path().join("outlined");
+        std::fs::create_dir(&theme_dir).unwrap();
+        std::fs::write(theme_dir.join("arrow.svg"), "<svg/>").unwrap();
+        std::fs::write(theme_dir.join("ibeam.png"), b"").unwrap();
+        std::fs::write(
+            theme_dir.join(MANIFEST_FILE),
+            r#"{ "name": "Outlined", "hotspots": { "arrow": [0.25, 0.1] } }"#,
+        )
+        .unwrap();
+
+        let theme = CursorTheme::load(&theme_dir).unwrap();
+
+        assert_eq!(theme.id, "outlined");
+        assert_eq!(theme.name, "Outlined");
+        assert_eq!(theme.sprites.len(), 2);
+        let arrow = theme.sprite(CursorRole::Arrow).unwrap();
+        assert!(arrow.is_svg());
+        assert_eq!((arrow.hotspot.x, arrow.hotspot.y), (0.25, 0.1));
+        assert!(!theme.sprite(CursorRole::IBeam).unwrap().is_svg());
+        assert!(theme.sprite(CursorRole::Hand).is_none());
+    }
+
+    #[test]
+    fn list_skips_empty_themes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("empty")).unwrap();
+        std::fs::create_dir(dir.path().join("hands")).unwrap();
+        std::fs::write(dir.path().join("hands/hand.png"), b"").unwrap();
+
+        let themes = CursorTheme::list(dir.path());
+
+        assert_eq!(themes.len(), 1);
+        assert_eq!(themes[0].id, "hands");
+    }
+}
@@ -435,6 +435,21 @@ impl DecodedFrame {
     pub fn uv_stride(&self) -> u32 {
         self.uv_stride
     }
+
+    pub fn same_pixels(&self, other: &Self) -> bool {
+        #[cfg(target_os = "windows")]
+        if self.d3d11_texture_backing.is_some() || other.d3d11_texture_backing.is_some() {
+            return false;
+        }
+
+        self.width == other.width
+            && self.height == other.height
+            && self.format == other.format
+            && self.y_stride == other.y_stride
+            && self.uv_stride == other.uv_stride
+            && !self.data.is_empty()
+            && (Arc::ptr_eq(&self.data, &other.data) || self.data == other.data)
+    }
 }
 
 pub enum VideoDecoderMessage {
@@ -0,0 +1,269 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+const MANIFEST_FILE: &str = "effect.json";
+const SHADER_FILE: &str = "effect.wgsl";
+
+pub const MAX_EFFECT_PARAMS: usize = 16;
+
+const PRELUDE: &str = r#"
+struct EffectUniforms {
+    output_size: vec2<f32>,
+    time: f32,
+    _padding: f32,
+    uv_rect: vec4<f32>,
+    params: array<vec4<f32>, 4>,
+}
+
+@group(0) @binding(0) var<uniform> effect_uniforms: EffectUniforms;
+@group(0) @binding(1) var source_texture: texture_2d<f32>;
+@group(0) @binding(2) var source_sampler: sampler;
+
+fn param(index: u32) -> f32 {
+    return effect_uniforms.params[index / 4u][index % 4u];
+}
+
+fn sample_frame(uv: vec2<f32>) -> vec4<f32> {
+    let rect = effect_uniforms.uv_rect;
+    return textureSample(source_texture, source_sampler, (uv - rect.xy) / rect.zw);
+}
+
+struct EffectVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> EffectVertexOutput {
+    var positions = array<vec2<f32>, 3>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(3.0, -1.0),
+        vec2<f32>(-1.0, 3.0),
+    );
+
+    let pos = positions[vertex_index];
+    var out: EffectVertexOutput;
+    out.position = vec4<f32>(pos, 0.0, 1.0);
+    out.uv = vec2<f32>(pos.x * 0.5 + 0.5, 1.0 - (pos.y * 0.5 + 0.5));
+    return out;
+}
+
+@fragment
+fn fs_main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    let rect = effect_uniforms.uv_rect;
+    let color = textureSample(source_texture, source_sampler, uv);
+    return apply_effect(color, rect.xy + uv * rect.zw);
+}
+"#;
+
+#[derive(Debug, Clone)]
+pub struct EffectPlugin {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub params: Vec<EffectParam>,
+    pub shader_source: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct EffectParam {
+    pub name: String,
+    pub default: f32,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+}
+
+#[derive(Deserialize, Default)]
+struct Manifest {
+    name: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    params: Vec<EffectParam>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum EffectPluginError {
+    #[error("IO: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid {MANIFEST_FILE}: {0}")]
+    Manifest(#[from] serde_json::Error),
+    #[error("Effects can have at most {MAX_EFFECT_PARAMS} params, '{0}' has {1}")]
+    TooManyParams(String, usize),
+    #[error("Invalid {SHADER_FILE}: {0}")]
+    Shader(String),
+}
+
+impl EffectPlugin {
+    pub fn load(dir: &Path) -> Result<Self, EffectPluginError> {
+        let id = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let manifest: Manifest = if manifest_path.exists() {
+            serde_json::from_slice(&std::fs::read(manifest_path)?)?
+        } else {
+            Manifest::default()
+        };
+
+        if manifest.params.len() > MAX_EFFECT_PARAMS {
+            return Err(EffectPluginError::TooManyParams(id, manifest.params.len()));
+        }
+
+        let snippet = std::fs::read_to_string(dir.join(SHADER_FILE))?;
+        let shader_source = format!("{PRELUDE}\n{snippet}");
+        validate(&shader_source)?;
+
+        Ok(Self {
+            name: manifest.name.unwrap_or_else(|| id.clone()),
+            id,
+            description: manifest.description,
+            params: manifest.params,
+            shader_source,
+        })
+    }
+
+    pub fn list(root: &Path) -> Vec<Self> {
+        let Ok(entries) = std::fs::read_dir(root) else {
+            return vec![];
+        };
+
+        let mut effects = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                Self::load(&entry.path())
+                    .map_err(|e| tracing::warn!("Skipping effect {}: {e}", entry.path().display()))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        effects.sort_by(|a, b| a.name.cmp(&b.name));
+        effects
+    }
+
+    pub fn packed_params(&self, values: &[f32]) -> [[f32; 4]; MAX_EFFECT_PARAMS / 4] {
+        let mut packed = [[0.0; 4]; MAX_EFFECT_PARAMS / 4];
+        for (index, param) in self.params.iter().enumerate() {
+            let mut value = values.get(index).copied().unwrap_or(param.default);
+            if let Some(min) = param.min {
+                value = value.max(min);
+            }
+            if let Some(max) = param.max {
+                value = value.min(max);
+            }
+            packed[index / 4][index % 4] = value;
+        }
+        packed
+    }
+}
+
+fn validate(source: &str) -> Result<(), EffectPluginError> {
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|e| EffectPluginError::Shader(e.emit_to_string(source)))?;
+
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::empty(),
+    )
+    .validate(&module)
+    .map_err(|e| EffectPluginError::Shader(e.emit_to_string(source)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_effect(root: &Path, id: &str, shader: &str, manifest: Option<&str>) {
+        let dir = root.join(id);
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join(SHADER_FILE), shader).unwrap();
+        if let Some(manifest) = manifest {
+            std::fs::write(dir.join(MANIFEST_FILE), manifest).unwrap();
+        }
+    }
+
+    const VIGNETTE: &str = r#"
+fn apply_effect(color: vec4<f32>, uv: vec2<f32>) -> vec4<f32> {
+    let falloff = 1.0 - param(0u) * length(uv - vec2<f32>(0.5));
+    return vec4<f32>(color.rgb * falloff, color.a);
+}
+"#;
+
+    #[test]
+    fn loads_shader_and_manifest_params() {
+        let dir = tempfile::tempdir().unwrap();
+        write_effect(
+            dir.path(),
+            "vignette",
+            VIGNETTE,
+            Some(
+                r#"{ "name": "Vignette", "params": [{ "name": "strength", "default": 1.5, "max": 1 }] }"#,
+            ),
+        );
+
+        let effect = EffectPlugin::load(&dir.path().join("vignette")).unwrap();
+
+        assert_eq!(effect.id, "vignette");
+        assert_eq!(effect.name, "Vignette");
+        assert_eq!(effect.params.len(), 1);
+        assert_eq!(effect.packed_params(&[])[0], [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn packs_configured_params_within_their_range() {
+        let dir = tempfile::tempdir().unwrap();
+        write_effect(
+            dir.path(),
+            "vignette",
+            VIGNETTE,
+            Some(
+                r#"{ "params": [
+                    { "name": "strength", "default": 0.5, "max": 1 },
+                    { "name": "radius", "default": 0.25, "min": 0 },
+                    { "name": "softness", "default": 0.75 }
+                ] }"#,
+            ),
+        );
+
+        let effect = EffectPlugin::load(&dir.path().join("vignette")).unwrap();
+
+        assert_eq!(effect.packed_params(&[2.0, -1.0])[0], [1.0, 0.0, 0.75, 0.0]);
+    }
+
+    #[test]
+    fn rejects_shaders_that_dont_compile() {
+        let dir = tempfile::tempdir().unwrap();
+        write_effect(
+            dir.path(),
+            "missing-entry",
+            "fn tint(color: vec4<f32>) -> vec4<f32> { return color; }",
+            None,
+        );
+        write_effect(dir.path(), "syntax", "fn apply_effect(", None);
+
+        for id in ["missing-entry", "syntax"] {
+            assert!(matches!(
+                EffectPlugin::load(&dir.path().join(id)),
+                Err(EffectPluginError::Shader(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn list_skips_invalid_effects() {
+        let dir = tempfile::tempdir().unwrap();
+        write_effect(dir.path(), "vignette", VIGNETTE, None);
+        write_effect(dir.path(), "broken", "not wgsl", None);
+        std::fs::create_dir(dir.path().join("empty")).unwrap();
+
+        let effects = EffectPlugin::list(dir.path());
+
+        assert_eq!(effects.len(), 1);
+        assert_eq!(effects[0].id, "vignette");
+    }
+}
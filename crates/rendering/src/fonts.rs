@@ -0,0 +1,107 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use glyphon::{
+    Cache, Family, FontSystem, Resolution, SwashCache, TextAtlas, TextRenderer, Viewport, fontdb,
+};
+use log::warn;
+use wgpu::{Device, Queue};
+
+fn system_fonts() -> &'static (String, fontdb::Database) {
+    static SYSTEM_FONTS: OnceLock<(String, fontdb::Database)> = OnceLock::new();
+    SYSTEM_FONTS.get_or_init(|| FontSystem::new().into_locale_and_db())
+}
+
+pub fn list_system_fonts() -> Vec<String> {
+    let (_, db) = system_fonts();
+    db.faces()
+        .flat_map(|face| face.families.iter().map(|(name, _)| name.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+pub fn family(name: &str) -> Family<'_> {
+    match name.trim() {
+        "" => Family::SansSerif,
+        name => match name.to_ascii_lowercase().as_str() {
+            "sans" | "sans-serif" | "system sans" | "system sans-serif" => Family::SansSerif,
+            "serif" | "system serif" => Family::Serif,
+            "mono" | "monospace" | "system mono" | "system monospace" => Family::Monospace,
+            _ => Family::Name(name),
+        },
+    }
+}
+
+pub struct TextSystem {
+    pub font_system: FontSystem,
+    pub swash_cache: SwashCache,
+    pub atlas: TextAtlas,
+    pub viewport: Viewport,
+    loaded_fonts: HashSet<PathBuf>,
+}
+
+impl TextSystem {
+    pub fn new(device: &Device, queue: &Queue) -> Self {
+        let (locale, db) = system_fonts();
+        let cache = Cache::new(device);
+
+        Self {
+            font_system: FontSystem::new_with_locale_and_db(locale.clone(), db.clone()),
+            swash_cache: SwashCache::new(),
+            atlas: TextAtlas::new(device, queue, &cache, wgpu::TextureFormat::Rgba8Unorm),
+            viewport: Viewport::new(device, &cache),
+            loaded_fonts: HashSet::new(),
+        }
+    }
+
+    pub fn create_renderer(&mut self, device: &Device) -> TextRenderer {
+        TextRenderer::new(
+            &mut self.atlas,
+            device,
+            wgpu::MultisampleState::default(),
+            None,
+        )
+    }
+
+    pub fn load_fonts(&mut self, fonts: &[PathBuf]) {
+        for font in fonts {
+            if self.loaded_fonts.insert(font.clone())
+                && let Err(e) = self.font_system.db_mut().load_font_file(font)
+            {
+                warn!("Failed to load font {}: {e}", font.display());
+            }
+        }
+    }
+
+    pub fn update_viewport(&mut self, queue: &Queue, output_size: (u32, u32)) {
+        self.viewport.update(
+            queue,
+            Resolution {
+                width: output_size.0,
+                height: output_size.1,
+            },
+        );
+    }
+
+    pub fn trim(&mut self) {
+        self.atlas.trim();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generic_names_resolve_to_generic_families() {
+        assert_eq!(family(""), Family::SansSerif);
+        assert_eq!(family("System Sans-Serif"), Family::SansSerif);
+        assert_eq!(family("System Serif"), Family::Serif);
+        assert_eq!(family("monospace"), Family::Monospace);
+        assert_eq!(family(" Inter "), Family::Name("Inter"));
+    }
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::sync::oneshot;
 use wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
 
@@ -364,6 +364,7 @@ impl PendingNv12Readback {
             frame_number: self.frame_number,
             target_time_ns,
             format: GpuOutputFormat::Nv12,
+            timings: FrameTimings::default(),
         })
     }
 }
@@ -374,6 +375,13 @@ pub enum GpuOutputFormat {
     Rgba,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    pub decode: Duration,
+    pub composite: Duration,
+    pub readback: Duration,
+}
+
 pub struct Nv12RenderedFrame {
     pub data: Arc<Vec<u8>>,
     pub width: u32,
@@ -382,6 +390,7 @@ pub struct Nv12RenderedFrame {
     pub frame_number: u32,
     pub target_time_ns: u64,
     pub format: GpuOutputFormat,
+    pub timings: FrameTimings,
 }
 
 impl Nv12RenderedFrame {
@@ -394,6 +403,7 @@ impl Nv12RenderedFrame {
             frame_number: self.frame_number,
             target_time_ns: self.target_time_ns,
             format: self.format,
+            timings: self.timings,
         }
     }
 
@@ -882,7 +892,10 @@ pub async fn finish_encoder_nv12(
     let height = uniforms.output_size.1;
 
     let previous_frame = if let Some(prev) = nv12_converter.take_pending() {
-        Some(prev.wait(device).await?)
+        let start = Instant::now();
+        let mut frame = prev.wait(device).await?;
+        frame.timings.readback = start.elapsed();
+        Some(frame)
     } else {
         None
     };
@@ -913,7 +926,9 @@ pub async fn finish_encoder_nv12(
         queue.submit(std::iter::once(encoder.finish()));
         Ok(Some(prev_frame))
     } else {
+        let start = Instant::now();
         let rgba_frame = finish_encoder(session, device, queue, uniforms, encoder).await?;
+        let readback = start.elapsed();
         Ok(rgba_frame.map(|f| Nv12RenderedFrame {
             data: f.data,
             width: f.width,
@@ -922,6 +937,10 @@ pub async fn finish_encoder_nv12(
             frame_number: f.frame_number,
             target_time_ns: f.target_time_ns,
             format: GpuOutputFormat::Rgba,
+            timings: FrameTimings {
+                readback,
+                ..Default::default()
+            },
         }))
     }
 }
@@ -0,0 +1,276 @@
+use std::path::PathBuf;
+
+use cap_project::{
+    CursorEvents, CursorMoveEvent, ProjectConfiguration, RecordingMeta, RecordingMetaInner,
+    StudioRecordingMeta, TimelineConfiguration, TimelineSegment, XY, ZoomMode, ZoomSegment,
+};
+
+use crate::{
+    DecodedFrame, DecodedSegmentFrames, FrameRenderer, ProjectUniforms, RenderOptions,
+    RenderVideoConstants, RendererLayers, SharedWgpuDevice, ZoomFocusInterpolator,
+};
+
+const WIDTH: u32 = 320;
+const HEIGHT: u32 = 180;
+const FPS: u32 = 30;
+const DURATION_SECS: f64 = 2.0;
+
+const CHANNEL_TOLERANCE: u8 = 3;
+const MAX_MISMATCHED_FRACTION: f64 = 0.005;
+
+async fn software_device() -> Option<SharedWgpuDevice> {
+    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: true,
+            compatible_surface: None,
+        })
+        .await
+        .ok()?;
+    let (device, queue) = adapter
+        .request_device(&wgpu::DeviceDescriptor::default())
+        .await
+        .ok()?;
+
+    Some(SharedWgpuDevice {
+        instance,
+        adapter,
+        device,
+        queue,
+        is_software_adapter: true,
+    })
+}
+
+fn studio_meta() -> StudioRecordingMeta {
+    serde_json::from_value(serde_json::json!({
+        "segments": [{ "display": { "path": "display.mp4", "fps": FPS } }],
+        "cursors": {
+            "0": {
+                "imagePath": "cursor_0.png",
+                "hotspot": { "x": 0.0, "y": 0.0 },
+                "shape": "MacOS|Arrow"
+            }
+        }
+    }))
+    .unwrap()
+}
+
+fn screen_frame() -> DecodedFrame {
+    let mut data = Vec::with_capacity((WIDTH * HEIGHT * 4) as usize);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let grid = x % 40 == 0 || y % 40 == 0;
+            let (r, g, b) = if grid {
+                (255, 255, 255)
+            } else {
+                ((x * 255 / WIDTH) as u8, (y * 255 / HEIGHT) as u8, 160)
+            };
+            data.extend_from_slice(&[r, g, b, 255]);
+        }
+    }
+    DecodedFrame::new(data, WIDTH, HEIGHT)
+}
+
+fn base_project() -> ProjectConfiguration {
+    let mut project = ProjectConfiguration::default();
+    project.background.source = cap_project::BackgroundSource::Color {
+        value: [40, 90, 160],
+        alpha: 255,
+    };
+    project.camera.hide = true;
+    project.cursor.hide = true;
+    project.cursor.raw = true;
+    project.cursor.motion_blur = 0.0;
+    project.screen_motion_blur = 0.0;
+    project.timeline = Some(TimelineConfiguration {
+        segments: vec![TimelineSegment {
+            recording_clip: 0,
+            timescale: 1.0,
+            start: 0.0,
+            end: DURATION_SECS,
+        }],
+        zoom_segments: vec![],
+        scene_segments: vec![],
+        mask_segments: vec![],
+        text_segments: vec![],
+        reaction_segments: vec![],
+        cursor_segments: vec![],
+        markers: vec![],
+    });
+    project
+}
+
+fn cursor_at(x: f64, y: f64) -> CursorEvents {
+    CursorEvents {
+        key_presses: vec![],
+        keys: vec![],
+        focus_changes: vec![],
+        clicks: vec![],
+        moves: vec![CursorMoveEvent {
+            active_modifiers: vec![],
+            cursor_id: "0".to_string(),
+            time_ms: 0.0,
+            x,
+            y,
+        }],
+    }
+}
+
+async fn render(
+    project: &ProjectConfiguration,
+    cursor: &CursorEvents,
+    frame_number: u32,
+) -> image::RgbaImage {
+    let device = software_device()
+        .await
+        .expect("Golden image tests need a software wgpu adapter");
+
+    let meta = studio_meta();
+    let recording_meta = RecordingMeta {
+        platform: None,
+        project_path: PathBuf::new(),
+        pretty_name: "golden".to_string(),
+        sharing: None,
+        inner: RecordingMetaInner::Studio(Box::new(meta.clone())),
+        upload: None,
+    };
+    let constants = RenderVideoConstants::from_shared_device(
+        device,
+        RenderOptions {
+            camera_size: None,
+            screen_size: XY::new(WIDTH, HEIGHT),
+        },
+        meta,
+        recording_meta,
+    );
+
+    let time = frame_number as f32 / FPS as f32;
+    let segment_frames = DecodedSegmentFrames {
+        screen_frame: screen_frame(),
+        camera_frame: None,
+        segment_time: time,
+        recording_time: time,
+    };
+
+    let zoom_focus_interpolator =
+        ZoomFocusInterpolator::new(cursor, None, project.screen_movement_spring, DURATION_SECS);
+    let uniforms = ProjectUniforms::new(
+        &constants,
+        project,
+        frame_number,
+        FPS,
+        XY::new(WIDTH, HEIGHT),
+        cursor,
+        &segment_frames,
+        DURATION_SECS,
+        &zoom_focus_interpolator,
+    );
+
+    let mut layers = RendererLayers::new_with_options(&constants.device, &constants.queue, true);
+    layers.prepare_for_video_dimensions(&constants.device, WIDTH, HEIGHT, None, None);
+
+    let frame = FrameRenderer::new(&constants)
+        .render_immediate(segment_frames, uniforms, cursor, &mut layers)
+        .await
+        .unwrap();
+
+    let row_len = frame.width as usize * 4;
+    let data = frame
+        .data
+        .chunks(frame.padded_bytes_per_row as usize)
+        .take(frame.height as usize)
+        .flat_map(|row| &row[..row_len])
+        .copied()
+        .collect();
+
+    image::RgbaImage::from_raw(frame.width, frame.height, data).unwrap()
+}
+
+fn assert_matches_golden(name: &str, actual: &image::RgbaImage) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"));
+
+    if !cap_utils::snapshot::require_reference(&path, "UPDATE_GOLDEN", |path| {
+        actual.save(path).unwrap()
+    }) {
+        return;
+    }
+
+    let expected = image::open(&path).unwrap().to_rgba8();
+    assert_eq!(
+        expected.dimensions(),
+        actual.dimensions(),
+        "{name}: output size changed"
+    );
+
+    let mismatched = expected
+        .pixels()
+        .zip(actual.pixels())
+        .filter(|(a, b)| {
+            a.0.iter()
+                .zip(b.0)
+                .any(|(a, b)| a.abs_diff(b) > CHANNEL_TOLERANCE)
+        })
+        .count();
+    let fraction = mismatched as f64 / expected.pixels().len() as f64;
+
+    if fraction > MAX_MISMATCHED_FRACTION {
+        let actual_path = std::env::temp_dir().join(format!("{name}.actual.png"));
+        actual.save(&actual_path).unwrap();
+        panic!(
+            "{name}: {:.2}% of pixels differ from {}. Output saved to {}",
+            fraction * 100.0,
+            path.display(),
+            actual_path.display()
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a software wgpu adapter"]
+async fn golden_shadow_and_rounding() {
+    let mut project = base_project();
+    project.background.padding = 10.0;
+    project.background.rounding = 40.0;
+    project.background.shadow = 80.0;
+
+    let frame = render(&project, &cursor_at(0.5, 0.5), 0).await;
+    assert_matches_golden("shadow_and_rounding", &frame);
+}
+
+#[tokio::test]
+#[ignore = "needs a software wgpu adapter"]
+async fn golden_manual_zoom() {
+    let mut project = base_project();
+    project.background.padding = 10.0;
+    if let Some(timeline) = &mut project.timeline {
+        timeline.zoom_segments.push(ZoomSegment {
+            start: 0.0,
+            end: DURATION_SECS,
+            amount: 2.0,
+            mode: ZoomMode::Manual { x: 0.25, y: 0.25 },
+            glide_direction: Default::default(),
+            glide_speed: 0.5,
+            instant_animation: true,
+            edge_snap_ratio: 0.25,
+            zoom_from_click: false,
+            easing: Default::default(),
+        });
+    }
+
+    let frame = render(&project, &cursor_at(0.5, 0.5), FPS).await;
+    assert_matches_golden("manual_zoom", &frame);
+}
+
+#[tokio::test]
+#[ignore = "needs a software wgpu adapter"]
+async fn golden_cursor() {
+    let mut project = base_project();
+    project.cursor.hide = false;
+    project.cursor.use_svg = true;
+
+    let frame = render(&project, &cursor_at(0.4, 0.6), 0).await;
+    assert_matches_golden("cursor", &frame);
+}
@@ -0,0 +1,76 @@
+use cap_project::XY;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewGuides {
+    pub safe_area_margin: Option<f32>,
+    pub crop_aspect: Option<f32>,
+    pub grid_divisions: Option<u32>,
+}
+
+impl PreviewGuides {
+    pub fn is_visible(&self) -> bool {
+        self.safe_area_margin.is_some_and(|m| m > 0.0)
+            || self.crop_aspect.is_some_and(|a| a > 0.0)
+            || self.grid_divisions.is_some_and(|d| d > 1)
+    }
+
+    pub fn crop_size(&self, output_size: XY<f32>) -> Option<XY<f32>> {
+        let aspect = self.crop_aspect.filter(|a| *a > 0.0)?;
+
+        Some(if output_size.x / output_size.y > aspect {
+            XY::new(output_size.y * aspect, output_size.y)
+        } else {
+            XY::new(output_size.x, output_size.x / aspect)
+        })
+    }
+
+    pub fn safe_area_inset(&self, output_size: XY<f32>) -> Option<XY<f32>> {
+        let margin = self.safe_area_margin.filter(|m| *m > 0.0)?.min(0.5);
+
+        Some(XY::new(output_size.x * margin, output_size.y * margin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vertical_crop_fits_landscape_output() {
+        let guides = PreviewGuides {
+            crop_aspect: Some(9.0 / 16.0),
+            ..Default::default()
+        };
+
+        let crop = guides.crop_size(XY::new(1920.0, 1080.0)).unwrap();
+        assert_eq!(crop.y, 1080.0);
+        assert!((crop.x - 607.5).abs() < 0.01);
+
+        let crop = guides.crop_size(XY::new(1080.0, 1920.0)).unwrap();
+        assert!((crop.x - 1080.0).abs() < 0.01);
+        assert!((crop.y - 1920.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn hidden_guides_are_not_visible() {
+        assert!(!PreviewGuides::default().is_visible());
+        assert!(
+            !PreviewGuides {
+                grid_divisions: Some(1),
+                safe_area_margin: Some(0.0),
+                ..Default::default()
+            }
+            .is_visible()
+        );
+        assert!(
+            PreviewGuides {
+                grid_divisions: Some(3),
+                ..Default::default()
+            }
+            .is_visible()
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use cap_project::{
+    CameraXPosition, CameraYPosition, HotkeysConfiguration, KeyEvent, KeystrokeStyle,
+};
+
+use crate::text::ease_out_back;
+
+const SHOW_MS: f64 = 1500.0;
+const FADE_OUT_MS: f64 = 300.0;
+const POP_IN_MS: f64 = 200.0;
+const CARET_BLINK_MS: f64 = 500.0;
+const TYPED_CHARS: usize = 40;
+const PADDING: f32 = 50.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedKeystroke {
+    pub label: String,
+    pub anchor: [f32; 2],
+    pub align: [f32; 2],
+    pub font_size: f32,
+    pub opacity: f32,
+    pub caret: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Modifier {
+    Control,
+    Alt,
+    Shift,
+    Command,
+}
+
+fn modifier(key: &str) -> Option<(Modifier, &'static str)> {
+    Some(match key {
+        "LControl" | "RControl" => (Modifier::Control, "Ctrl"),
+        "LAlt" | "RAlt" => (Modifier::Alt, "Alt"),
+        "LOption" | "ROption" => (Modifier::Alt, "Option"),
+        "LShift" | "RShift" => (Modifier::Shift, "Shift"),
+        "Command" | "RCommand" => (Modifier::Command, "Cmd"),
+        "LMeta" | "RMeta" => (Modifier::Command, "Win"),
+        _ => return None,
+    })
+}
+
+fn key_label(key: &str) -> &str {
+    match key {
+        "Escape" => "Esc",
+        "Backspace" => "⌫",
+        "Up" => "↑",
+        "Down" => "↓",
+        "Left" => "←",
+        "Right" => "→",
+        "Minus" => "-",
+        "Equal" => "=",
+        "Comma" => ",",
+        "Dot" => ".",
+        "Slash" => "/",
+        "BackSlash" => "\\",
+        "Semicolon" => ";",
+        "Apostrophe" => "'",
+        "LeftBracket" => "[",
+        "RightBracket" => "]",
+        "Grave" => "`",
+        _ => key
+            .strip_prefix("Key")
+            .or_else(|| key.strip_prefix("Numpad"))
+            .filter(|rest| rest.chars().count() == 1)
+            .unwrap_or(key),
+    }
+}
+
+fn latest_shortcut(keys: &[KeyEvent], time_ms: f64, shortcuts_only: bool) -> Option<(String, f64)> {
+    let mut held: Vec<(Modifier, &str, &'static str)> = vec![];
+    let mut latest = None;
+
+    for event in keys.iter().take_while(|event| event.time_ms <= time_ms) {
+        if let Some((modifier, label)) = modifier(&event.key) {
+            held.retain(|(_, key, _)| *key != event.key);
+            if event.down {
+                held.push((modifier, &event.key, label));
+            }
+            continue;
+        }
+
+        let is_shortcut = held
+            .iter()
+            .any(|(modifier, ..)| *modifier != Modifier::Shift);
+        if !event.down || shortcuts_only && !is_shortcut {
+            continue;
+        }
+
+        let mut modifiers = held.clone();
+        modifiers.sort_by_key(|(modifier, ..)| *modifier);
+        modifiers.dedup_by_key(|(modifier, ..)| *modifier);
+
+        let label = modifiers
+            .iter()
+            .map(|(.., label)| *label)
+            .chain([key_label(&event.key)])
+            .collect::<Vec<_>>()
+            .join("+");
+        latest = Some((label, event.time_ms));
+    }
+
+    latest
+}
+
+fn typed_char(key: &str, shifted: bool) -> Option<char> {
+    const UNSHIFTED: &str = "1234567890-=[]\\;',./`";
+    const SHIFTED: &str = "!@#$%^&*()_+{}|:\"<>?~";
+
+    if key == "Space" {
+        return Some(' ');
+    }
+    if matches!(key, "Backspace" | "Up" | "Down" | "Left" | "Right") {
+        return None;
+    }
+
+    let mut chars = key_label(key).chars();
+    let label = chars.next().filter(|_| chars.next().is_none())?;
+    if label.is_ascii_alphabetic() {
+        return Some(if shifted {
+            label.to_ascii_uppercase()
+        } else {
+            label.to_ascii_lowercase()
+        });
+    }
+
+    Some(
+        UNSHIFTED
+            .chars()
+            .position(|unshifted| shifted && unshifted == label)
+            .and_then(|index| SHIFTED.chars().nth(index))
+            .unwrap_or(label),
+    )
+}
+
+fn typed_line(keys: &[KeyEvent], time_ms: f64) -> Option<(String, f64, f64)> {
+    let mut held: Vec<(Modifier, &str)> = vec![];
+    let mut line = String::new();
+    let mut started_ms = 0.0;
+    let mut typed_ms = None;
+    let mut ended = false;
+
+    for event in keys.iter().take_while(|event| event.time_ms <= time_ms) {
+        if let Some((modifier, _)) = modifier(&event.key) {
+            held.retain(|(_, key)| *key != event.key);
+            if event.down {
+                held.push((modifier, &event.key));
+            }
+            continue;
+        }
+
+        let is_shortcut = held
+            .iter()
+            .any(|(modifier, _)| *modifier != Modifier::Shift);
+        if !event.down || is_shortcut {
+            continue;
+        }
+
+        let typed = match event.key.as_str() {
+            "Backspace" => None,
+            "Enter" | "NumpadEnter" => {
+                ended = true;
+                typed_ms = Some(event.time_ms);
+                continue;
+            }
+            key => match typed_char(key, !held.is_empty()) {
+                Some(typed) => Some(typed),
+                None => continue,
+            },
+        };
+
+        if ended || typed_ms.is_none_or(|typed_ms| event.time_ms - typed_ms >= SHOW_MS) {
+            line.clear();
+            started_ms = event.time_ms;
+            ended = false;
+        }
+        match typed {
+            Some(typed) => line.push(typed),
+            None => {
+                line.pop();
+            }
+        }
+        typed_ms = Some(event.time_ms);
+    }
+
+    let skip = line.chars().count().saturating_sub(TYPED_CHARS);
+    let line = line.chars().skip(skip).collect::<String>();
+    Some((line, started_ms, typed_ms?)).filter(|(line, ..)| !line.is_empty())
+}
+
+pub(crate) fn prepare_keystroke(
+    config: &HotkeysConfiguration,
+    keys: &[KeyEvent],
+    time_ms: f64,
+    output_size: (u32, u32),
+) -> Option<PreparedKeystroke> {
+    if !config.show {
+        return None;
+    }
+
+    let (label, shown_ms, pressed_ms) = match config.style {
+        KeystrokeStyle::Shortcuts => {
+            let (label, pressed_ms) = latest_shortcut(keys, time_ms, config.shortcuts_only)?;
+            (label, pressed_ms, pressed_ms)
+        }
+        KeystrokeStyle::Typing => typed_line(keys, time_ms)?,
+    };
+    let age = time_ms - pressed_ms;
+    if age >= SHOW_MS {
+        return None;
+    }
+
+    let scale = 0.8 + 0.2 * ease_out_back(((time_ms - shown_ms) / POP_IN_MS).min(1.0));
+    let caret = (config.style == KeystrokeStyle::Typing)
+        .then(|| ((age / CARET_BLINK_MS) as u64).is_multiple_of(2));
+    let opacity = ((SHOW_MS - age) / FADE_OUT_MS).min(1.0) as f32;
+
+    let output_size = [output_size.0 as f32, output_size.1 as f32];
+    let padding = PADDING * output_size[1] / 1080.0;
+
+    let (x, align_x) = match config.position.x {
+        CameraXPosition::Left => (padding, 0.0),
+        CameraXPosition::Center => (output_size[0] / 2.0, 0.5),
+        CameraXPosition::Right => (output_size[0] - padding, 1.0),
+    };
+    let (y, align_y) = match config.position.y {
+        CameraYPosition::Top => (padding, 0.0),
+        CameraYPosition::Bottom => (output_size[1] - padding, 1.0),
+    };
+
+    Some(PreparedKeystroke {
+        label,
+        anchor: [x, y],
+        align: [align_x, align_y],
+        font_size: (output_size[1] as f64 * config.size / 100.0 * scale) as f32,
+        opacity,
+        caret,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: &str, time_ms: f64, down: bool) -> KeyEvent {
+        KeyEvent {
+            key: key.to_string(),
+            time_ms,
+            down,
+        }
+    }
+
+    #[test]
+    fn shortcuts_list_held_modifiers_in_order() {
+        let keys = [
+            key("LShift", 0.0, true),
+            key("LControl", 10.0, true),
+            key("KeyP", 20.0, true),
+            key("KeyP", 30.0, false),
+            key("LControl", 40.0, false),
+            key("LShift", 50.0, false),
+            key("KeyA", 60.0, true),
+        ];
+
+        assert_eq!(
+            latest_shortcut(&keys, 100.0, true),
+            Some(("Ctrl+Shift+P".to_string(), 20.0))
+        );
+        assert_eq!(
+            latest_shortcut(&keys, 100.0, false),
+            Some(("A".to_string(), 60.0))
+        );
+        assert_eq!(latest_shortcut(&keys, 15.0, true), None);
+    }
+
+    #[test]
+    fn shifted_typing_is_not_a_shortcut() {
+        let keys = [key("RShift", 0.0, true), key("Key1", 10.0, true)];
+
+        assert_eq!(latest_shortcut(&keys, 20.0, true), None);
+        assert_eq!(
+            latest_shortcut(&keys, 20.0, false),
+            Some(("Shift+1".to_string(), 10.0))
+        );
+    }
+
+    #[test]
+    fn keystrokes_fade_out_after_being_pressed() {
+        let config = HotkeysConfiguration {
+            show: true,
+            ..Default::default()
+        };
+        let keys = [key("Command", 0.0, true), key("KeyS", 100.0, true)];
+        let at = |time_ms| prepare_keystroke(&config, &keys, time_ms, (1920, 1080));
+
+        let shown = at(600.0).unwrap();
+        assert_eq!(shown.label, "Cmd+S");
+        assert_eq!(shown.opacity, 1.0);
+        assert_eq!(shown.anchor, [960.0, 1030.0]);
+        assert!(at(100.0 + SHOW_MS - FADE_OUT_MS / 2.0).unwrap().opacity < 1.0);
+        assert_eq!(at(100.0 + SHOW_MS), None);
+        assert_eq!(at(50.0), None);
+    }
+
+    #[test]
+    fn typing_builds_up_a_line() {
+        let keys = [
+            key("LShift", 0.0, true),
+            key("H", 10.0, true),
+            key("LShift", 20.0, false),
+            key("I", 30.0, true),
+            key("X", 40.0, true),
+            key("Backspace", 50.0, true),
+            key("Space", 60.0, true),
+            key("RShift", 70.0, true),
+            key("Key1", 80.0, true),
+            key("RShift", 90.0, false),
+            key("Command", 100.0, true),
+            key("S", 110.0, true),
+            key("Command", 120.0, false),
+        ];
+
+        assert_eq!(
+            typed_line(&keys, 200.0),
+            Some(("Hi !".to_string(), 10.0, 80.0))
+        );
+        assert_eq!(typed_line(&keys, 5.0), None);
+    }
+
+    #[test]
+    fn typing_starts_a_new_line_after_enter_or_a_pause() {
+        let keys = [
+            key("A", 0.0, true),
+            key("Enter", 100.0, true),
+            key("B", 200.0, true),
+            key("C", 200.0 + SHOW_MS, true),
+        ];
+
+        assert_eq!(
+            typed_line(&keys, 150.0),
+            Some(("a".to_string(), 0.0, 100.0))
+        );
+        assert_eq!(
+            typed_line(&keys, 300.0),
+            Some(("b".to_string(), 200.0, 200.0))
+        );
+        assert_eq!(
+            typed_line(&keys, 200.0 + SHOW_MS),
+            Some(("c".to_string(), 200.0 + SHOW_MS, 200.0 + SHOW_MS))
+        );
+    }
+
+    #[test]
+    fn typed_lines_blink_their_caret_then_fade() {
+        let config = HotkeysConfiguration {
+            show: true,
+            style: KeystrokeStyle::Typing,
+            ..Default::default()
+        };
+        let keys = [key("O", 0.0, true), key("K", 100.0, true)];
+        let at = |time_ms| prepare_keystroke(&config, &keys, time_ms, (1920, 1080));
+
+        let typing = at(300.0).unwrap();
+        assert_eq!(typing.label, "ok");
+        assert_eq!(typing.caret, Some(true));
+        assert_eq!(at(100.0 + CARET_BLINK_MS).unwrap().caret, Some(false));
+        assert!(at(100.0 + SHOW_MS - FADE_OUT_MS / 2.0).unwrap().opacity < 1.0);
+        assert_eq!(at(100.0 + SHOW_MS), None);
+    }
+}
@@ -7,6 +7,8 @@ use wgpu::{include_wgsl, util::DeviceExt};
 
 use crate::{ProjectUniforms, RenderVideoConstants, RenderingError, create_shader_render_pipeline};
 
+const FULL_FRAME_UV: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
 #[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize, Type)]
 pub struct Gradient {
     start: [f32; 4],
@@ -84,11 +86,11 @@ impl From<BackgroundSource> for Background {
 pub enum Inner {
     Image {
         path: String,
+        buffer: wgpu::Buffer,
         bind_group: wgpu::BindGroup,
     },
     ColorOrGradient {
         value: ColorOrGradient,
-        #[allow(unused)]
         buffer: wgpu::Buffer,
         bind_group: wgpu::BindGroup,
     },
@@ -195,8 +197,8 @@ impl BackgroundLayer {
                             }
                         };
 
-                        let output_ar =
-                            uniforms.output_size.1 as f32 / uniforms.output_size.0 as f32;
+                        let (output_width, output_height) = uniforms.frame_size();
+                        let output_ar = output_height as f32 / output_width as f32;
                         let image_ar = texture.height() as f32 / texture.width() as f32;
 
                         let y_height = if output_ar < image_ar {
@@ -215,14 +217,13 @@ impl BackgroundLayer {
                         };
 
                         let image_uniforms = ImageBackgroundUniforms {
-                            output_size: [
-                                uniforms.output_size.0 as f32,
-                                uniforms.output_size.1 as f32,
-                            ],
+                            output_size: [output_width as f32, output_height as f32],
                             padding: 0.0,
                             x_width,
                             y_height,
-                            _padding: 0.0,
+                            dim: 0.0,
+                            _padding: [0.0; 2],
+                            uv_rect: FULL_FRAME_UV,
                         };
 
                         let uniform_buffer =
@@ -242,6 +243,7 @@ impl BackgroundLayer {
                                 &uniform_buffer,
                                 &texture_view,
                             ),
+                            buffer: uniform_buffer,
                         });
                     }
                 };
@@ -276,6 +278,36 @@ impl BackgroundLayer {
             },
         }
 
+        let uv_rect = uniforms.tile.map_or(FULL_FRAME_UV, |tile| tile.uv_rect());
+        let dim = zoom_dim(uniforms);
+        match &self.inner {
+            Some(Inner::Image { buffer, .. }) => {
+                queue.write_buffer(
+                    buffer,
+                    std::mem::offset_of!(ImageBackgroundUniforms, uv_rect) as u64,
+                    bytemuck::bytes_of(&uv_rect),
+                );
+                queue.write_buffer(
+                    buffer,
+                    std::mem::offset_of!(ImageBackgroundUniforms, dim) as u64,
+                    bytemuck::bytes_of(&dim),
+                );
+            }
+            Some(Inner::ColorOrGradient { buffer, .. }) => {
+                queue.write_buffer(
+                    buffer,
+                    std::mem::offset_of!(GradientOrColorUniforms, uv_rect) as u64,
+                    bytemuck::bytes_of(&uv_rect),
+                );
+                queue.write_buffer(
+                    buffer,
+                    std::mem::offset_of!(GradientOrColorUniforms, dim) as u64,
+                    bytemuck::bytes_of(&dim),
+                );
+            }
+            None => {}
+        }
+
         Ok(())
     }
 
@@ -294,6 +326,15 @@ impl BackgroundLayer {
     }
 }
 
+fn zoom_dim(uniforms: &ProjectUniforms) -> f32 {
+    if uniforms.is_card {
+        return 0.0;
+    }
+
+    let strength = (uniforms.project.background.zoom_dim / 100.0).clamp(0.0, 1.0);
+    strength * (uniforms.zoom.t as f32).clamp(0.0, 1.0)
+}
+
 pub struct ImageBackgroundPipeline {
     pub bind_group_layout: wgpu::BindGroupLayout,
     pub render_pipeline: wgpu::RenderPipeline,
@@ -306,7 +347,9 @@ pub struct ImageBackgroundUniforms {
     padding: f32,
     x_width: f32,
     y_height: f32,
-    _padding: f32, // For alignment
+    dim: f32,
+    _padding: [f32; 2], // For alignment
+    uv_rect: [f32; 4],
 }
 
 impl ImageBackgroundPipeline {
@@ -446,7 +489,8 @@ pub struct GradientOrColorUniforms {
     pub angle: f32,
     pub noise_intensity: f32,
     pub noise_scale: f32,
-    _padding: f32,
+    dim: f32,
+    uv_rect: [f32; 4],
 }
 
 impl GradientOrColorUniforms {
@@ -480,7 +524,8 @@ impl From<Background> for GradientOrColorUniforms {
                 angle: 0.0,
                 noise_intensity: 0.0,
                 noise_scale: 0.0,
-                _padding: 0.0,
+                dim: 0.0,
+                uv_rect: FULL_FRAME_UV,
             },
             Background::Gradient(Gradient {
                 start,
@@ -499,7 +544,8 @@ impl From<Background> for GradientOrColorUniforms {
                 angle,
                 noise_intensity,
                 noise_scale,
-                _padding: 0.0,
+                dim: 0.0,
+                uv_rect: FULL_FRAME_UV,
             },
             Background::Image { .. } => {
                 unreachable!("Image backgrounds should be handled separately")
@@ -1,14 +1,15 @@
 use bytemuck::{Pod, Zeroable};
 use cap_project::XY;
 use glyphon::cosmic_text::LayoutRunIter;
-use glyphon::{
-    Attrs, Buffer, Cache, Color, Family, FontSystem, Metrics, Resolution, Shaping, SwashCache,
-    TextArea, TextAtlas, TextBounds, TextRenderer, Viewport, Weight,
-};
+use glyphon::{Attrs, Buffer, Color, Metrics, Shaping, TextArea, TextBounds, TextRenderer, Weight};
 use log::warn;
 use wgpu::{Device, Queue, include_wgsl, util::DeviceExt};
 
-use crate::{DecodedSegmentFrames, ProjectUniforms, RenderVideoConstants, parse_color_component};
+use crate::{
+    DecodedSegmentFrames, ProjectUniforms, RenderVideoConstants,
+    fonts::{self, TextSystem},
+    parse_color_component,
+};
 
 #[derive(Debug, Clone)]
 pub struct CaptionWord {
@@ -235,15 +236,11 @@ fn calculate_word_highlight(
 
 pub struct CaptionsLayer {
     _settings_buffer: wgpu::Buffer,
-    font_system: FontSystem,
-    swash_cache: SwashCache,
-    text_atlas: TextAtlas,
     text_renderer: TextRenderer,
     text_buffer: Buffer,
     current_text: Option<String>,
     current_segment_start: f32,
     current_segment_end: f32,
-    viewport: Viewport,
     background_pipeline: wgpu::RenderPipeline,
     background_bind_group: wgpu::BindGroup,
     background_uniform_buffer: wgpu::Buffer,
@@ -253,7 +250,7 @@ pub struct CaptionsLayer {
 }
 
 impl CaptionsLayer {
-    pub fn new(device: &Device, queue: &Queue) -> Self {
+    pub fn new(device: &Device, text_system: &mut TextSystem) -> Self {
         let settings = CaptionSettings::default();
         let settings_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Caption Settings Buffer"),
@@ -261,17 +258,7 @@ impl CaptionsLayer {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        let font_system = FontSystem::new();
-        let swash_cache = SwashCache::new();
-        let cache = Cache::new(device);
-        let viewport = Viewport::new(device, &cache);
-        let mut text_atlas = TextAtlas::new(device, queue, &cache, wgpu::TextureFormat::Rgba8Unorm);
-        let text_renderer = TextRenderer::new(
-            &mut text_atlas,
-            device,
-            wgpu::MultisampleState::default(),
-            None,
-        );
+        let text_renderer = text_system.create_renderer(device);
 
         let metrics = Metrics::new(24.0, 24.0 * 1.2);
         let text_buffer = Buffer::new_empty(metrics);
@@ -356,15 +343,11 @@ impl CaptionsLayer {
 
         Self {
             _settings_buffer: settings_buffer,
-            font_system,
-            swash_cache,
-            text_atlas,
             text_renderer,
             text_buffer,
             current_text: None,
             current_segment_start: 0.0,
             current_segment_end: 0.0,
-            viewport,
             background_pipeline,
             background_bind_group,
             background_uniform_buffer,
@@ -428,10 +411,14 @@ impl CaptionsLayer {
         _segment_frames: &DecodedSegmentFrames,
         output_size: XY<u32>,
         constants: &RenderVideoConstants,
+        text_system: &mut TextSystem,
     ) {
         self.has_caption = false;
         self.background_scissor = None;
-        self.output_size = (output_size.x, output_size.y);
+        self.output_size = uniforms.output_size;
+        let tile_offset = uniforms
+            .tile
+            .map_or(XY::new(0.0, 0.0), |tile| tile.offset());
 
         let Some(caption_data) = &uniforms.project.captions else {
             self.current_text = None;
@@ -530,16 +517,12 @@ impl CaptionsLayer {
         let font_size = caption_data.settings.size as f32 * (height as f32 / 1080.0);
         let metrics = Metrics::new(font_size, font_size * 1.2);
 
-        let mut updated_buffer = Buffer::new(&mut self.font_system, metrics);
+        let mut updated_buffer = Buffer::new(&mut text_system.font_system, metrics);
         let wrap_width = (width as f32 - margin * 2.0).max(font_size);
-        updated_buffer.set_size(&mut self.font_system, Some(wrap_width), None);
-        updated_buffer.set_wrap(&mut self.font_system, glyphon::Wrap::Word);
+        updated_buffer.set_size(&mut text_system.font_system, Some(wrap_width), None);
+        updated_buffer.set_wrap(&mut text_system.font_system, glyphon::Wrap::Word);
 
-        let font_family = match caption_data.settings.font.as_str() {
-            "System Serif" => Family::Serif,
-            "System Monospace" => Family::Monospace,
-            _ => Family::SansSerif,
-        };
+        let font_family = fonts::family(&caption_data.settings.font);
 
         let weight = if caption_data.settings.font_weight >= 700 {
             Weight::BOLD
@@ -629,7 +612,7 @@ impl CaptionsLayer {
             }
 
             updated_buffer.set_rich_text(
-                &mut self.font_system,
+                &mut text_system.font_system,
                 rich_text,
                 &Attrs::new().family(font_family).weight(weight),
                 Shaping::Advanced,
@@ -644,7 +627,7 @@ impl CaptionsLayer {
             );
             let attrs = Attrs::new().family(font_family).weight(weight).color(color);
             updated_buffer.set_text(
-                &mut self.font_system,
+                &mut text_system.font_system,
                 caption_text.as_str(),
                 &attrs,
                 Shaping::Advanced,
@@ -685,6 +668,8 @@ impl CaptionsLayer {
         let background_top =
             (base_background_top + bounce_offset).clamp(0.0, (height as f32 - box_height).max(0.0));
 
+        let background_left = background_left - tile_offset.x;
+        let background_top = background_top - tile_offset.y;
         let text_left = background_left + padding;
         let text_top = background_top + padding;
 
@@ -696,7 +681,6 @@ impl CaptionsLayer {
         };
 
         self.text_buffer = updated_buffer;
-        self.viewport.update(queue, Resolution { width, height });
 
         let mut text_areas = Vec::new();
 
@@ -757,11 +741,11 @@ impl CaptionsLayer {
         match self.text_renderer.prepare(
             device,
             queue,
-            &mut self.font_system,
-            &mut self.text_atlas,
-            &self.viewport,
+            &mut text_system.font_system,
+            &mut text_system.atlas,
+            &text_system.viewport,
             text_areas,
-            &mut self.swash_cache,
+            &mut text_system.swash_cache,
         ) {
             Ok(_) => {}
             Err(e) => warn!("Error preparing text: {e:?}"),
@@ -769,8 +753,8 @@ impl CaptionsLayer {
 
         let rect = CaptionBackgroundUniforms {
             rect: [
-                background_left.max(0.0),
-                background_top.max(0.0),
+                background_left.max(-tile_offset.x),
+                background_top.max(-tile_offset.y),
                 box_width,
                 box_height,
             ],
@@ -794,8 +778,8 @@ impl CaptionsLayer {
         let scissor_padding = 4.0;
         let scissor_x = (background_left - scissor_padding).max(0.0).floor() as u32;
         let scissor_y = (background_top - scissor_padding).max(0.0).floor() as u32;
-        let max_width = width.saturating_sub(scissor_x);
-        let max_height = height.saturating_sub(scissor_y);
+        let max_width = self.output_size.0.saturating_sub(scissor_x);
+        let max_height = self.output_size.1.saturating_sub(scissor_y);
 
         if max_width == 0 || max_height == 0 {
             self.has_caption = false;
@@ -824,7 +808,7 @@ impl CaptionsLayer {
         self.has_caption
     }
 
-    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+    pub fn render<'a>(&'a self, text_system: &'a TextSystem, pass: &mut wgpu::RenderPass<'a>) {
         if !self.has_caption {
             return;
         }
@@ -841,7 +825,7 @@ impl CaptionsLayer {
 
         match self
             .text_renderer
-            .render(&self.text_atlas, &self.viewport, pass)
+            .render(&text_system.atlas, &text_system.viewport, pass)
         {
             Ok(_) => {}
             Err(e) => warn!("Error rendering text: {e:?}"),
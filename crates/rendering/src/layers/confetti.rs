@@ -0,0 +1,147 @@
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use crate::{ProjectUniforms, confetti::ConfettiParticle};
+
+pub struct ConfettiLayer {
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline: wgpu::RenderPipeline,
+    instances: Option<(wgpu::Buffer, u32)>,
+    bind_group: Option<wgpu::BindGroup>,
+}
+
+impl ConfettiLayer {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Confetti Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Confetti Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/confetti.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Confetti Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Confetti Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<ConfettiParticle>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32, 3 => Float32x4],
+                }],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            bind_group_layout,
+            pipeline,
+            instances: None,
+            bind_group: None,
+        }
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.instances.is_some()
+    }
+
+    pub fn prepare(&mut self, device: &wgpu::Device, uniforms: &ProjectUniforms) {
+        if uniforms.confetti.is_empty() {
+            self.instances = None;
+            return;
+        }
+
+        let instances = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Confetti Instance Buffer"),
+            contents: bytemuck::cast_slice(&uniforms.confetti),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        self.instances = Some((instances, uniforms.confetti.len() as u32));
+
+        let confetti_uniforms = ConfettiUniforms {
+            output_size: [uniforms.output_size.0 as f32, uniforms.output_size.1 as f32],
+            _padding: [0.0; 2],
+        };
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Confetti Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[confetti_uniforms]),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+
+        self.bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Confetti Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        }));
+    }
+
+    pub fn render(&self, pass: &mut wgpu::RenderPass<'_>) {
+        let (Some((instances, count)), Some(bind_group)) = (&self.instances, &self.bind_group)
+        else {
+            return;
+        };
+
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.set_vertex_buffer(0, instances.slice(..));
+        pass.draw(0..6, 0..*count);
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct ConfettiUniforms {
+    output_size: [f32; 2],
+    _padding: [f32; 2],
+}
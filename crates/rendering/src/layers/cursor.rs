@@ -7,8 +7,10 @@ use tracing::error;
 use wgpu::{BindGroup, FilterMode, include_wgsl, util::DeviceExt};
 
 use crate::{
-    Coord, DecodedSegmentFrames, FrameSpace, ProjectUniforms, RenderVideoConstants,
-    STANDARD_CURSOR_HEIGHT, zoom::InterpolatedZoom,
+    Coord, CursorSprite, DecodedSegmentFrames, FrameSpace, ProjectUniforms, RenderVideoConstants,
+    STANDARD_CURSOR_HEIGHT,
+    svg::{self, SvgImage},
+    zoom::InterpolatedZoom,
 };
 
 const CURSOR_CLICK_DURATION: f64 = 0.25;
@@ -24,14 +26,14 @@ const CURSOR_MULTIPLIER: f32 = 3.0;
 const CURSOR_MAX_STRENGTH: f32 = 5.0;
 const VELOCITY_BLEND_RATIO: f32 = 0.7;
 
-/// The size to render the svg to.
-static SVG_CURSOR_RASTERIZED_HEIGHT: u32 = 200;
+static SVG_CURSOR_RASTERIZED_HEIGHT: u32 = 256;
 
 const CIRCLE_CURSOR_SIZE: u32 = 256;
 
 pub struct CursorLayer {
     statics: Statics,
     bind_group: Option<BindGroup>,
+    magnifier_bind_group: Option<BindGroup>,
     cursors: HashMap<String, CursorTexture>,
     circle_cursor: Option<CursorTexture>,
     prev_is_svg_assets_enabled: Option<bool>,
@@ -159,6 +161,7 @@ impl Statics {
     fn create_bind_group(
         &self,
         device: &wgpu::Device,
+        uniform_buffer: &wgpu::Buffer,
         cursor_texture: &wgpu::Texture,
     ) -> wgpu::BindGroup {
         device.create_bind_group(&wgpu::BindGroupDescriptor {
@@ -166,7 +169,7 @@ impl Statics {
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
-                    resource: self.uniform_buffer.as_entire_binding(),
+                    resource: uniform_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 1,
@@ -191,6 +194,7 @@ impl CursorLayer {
         Self {
             statics,
             bind_group: None,
+            magnifier_bind_group: None,
             cursors: Default::default(),
             circle_cursor: None,
             prev_is_svg_assets_enabled: None,
@@ -250,6 +254,7 @@ impl CursorLayer {
         uniforms: &ProjectUniforms,
         constants: &RenderVideoConstants,
     ) {
+        self.magnifier_bind_group = None;
         if uniforms.project.cursor.hide {
             self.bind_group = None;
             return;
@@ -316,23 +321,11 @@ impl CursorLayer {
             XY::new(0.0, 0.0)
         };
 
-        let mut cursor_opacity = 1.0f32;
-        if uniforms.project.cursor.hide_when_idle && !cursor.moves.is_empty() {
-            let hide_delay_secs = uniforms
-                .project
-                .cursor
-                .hide_when_idle_delay
-                .max((CURSOR_IDLE_MIN_DELAY_MS / 1000.0) as f32);
-            let hide_delay_ms = (hide_delay_secs as f64 * 1000.0).max(CURSOR_IDLE_MIN_DELAY_MS);
-            cursor_opacity = compute_cursor_idle_opacity(
-                cursor,
-                segment_frames.recording_time as f64 * 1000.0,
-                hide_delay_ms,
-            );
-            if cursor_opacity <= f32::EPSILON {
-                cursor_opacity = 0.0;
-            }
-        }
+        let cursor_opacity = cursor_idle_opacity(
+            &uniforms.project.cursor,
+            cursor,
+            segment_frames.recording_time as f64 * 1000.0,
+        ) * uniforms.cursor_opacity;
 
         let cursor_type = uniforms.project.cursor.cursor_type().clone();
 
@@ -371,7 +364,20 @@ impl CursorLayer {
                     _ => None,
                 };
 
+                if let Some(theme) = &constants.cursor_theme
+                    && let Some(sprite) = cursor_shape
+                        .and_then(|shape| shape.role())
+                        .and_then(|role| theme.sprite(role))
+                {
+                    loaded_cursor = CursorTexture::prepare_sprite(constants, sprite)
+                        .map_err(|err| {
+                            error!("Error loading cursor theme sprite {:?}: {err}", sprite.path)
+                        })
+                        .ok();
+                }
+
                 if let Some(cursor_shape) = cursor_shape
+                    && loaded_cursor.is_none()
                     && uniforms.project.cursor.use_svg
                     && let Some(info) = cursor_shape.resolve()
                 {
@@ -416,7 +422,7 @@ impl CursorLayer {
 
         let size = {
             let base_size_px = STANDARD_CURSOR_HEIGHT / constants.options.screen_size.y as f32
-                * uniforms.output_size.1 as f32;
+                * uniforms.frame_size().1 as f32;
 
             let cursor_size_factor = if uniforms.cursor_size <= 0.0 {
                 100.0
@@ -472,11 +478,14 @@ impl CursorLayer {
         ) - zoomed_position;
 
         let effective_strength = if has_motion { cursor_strength } else { 0.0 };
+        let tile_offset = uniforms
+            .tile
+            .map_or(XY::new(0.0, 0.0), |tile| tile.offset());
 
         let cursor_uniforms = CursorUniforms {
             position_size: [
-                zoomed_position.x as f32,
-                zoomed_position.y as f32,
+                zoomed_position.x as f32 - tile_offset.x,
+                zoomed_position.y as f32 - tile_offset.y,
                 zoomed_size.x as f32,
                 zoomed_size.y as f32,
             ],
@@ -507,10 +516,68 @@ impl CursorLayer {
             bytemuck::cast_slice(&[cursor_uniforms]),
         );
 
-        self.bind_group = Some(
-            self.statics
-                .create_bind_group(&constants.device, &cursor_texture.texture),
-        );
+        let cursor_texture = if cursor_type == CursorType::Circle {
+            self.circle_cursor.as_ref()
+        } else {
+            self.cursors
+                .get_mut(&interpolated_cursor.cursor_id)
+                .map(|tex| {
+                    tex.match_drawn_height(constants, zoomed_size.y as f32);
+                    &*tex
+                })
+        };
+        let Some(cursor_texture) = cursor_texture else {
+            return;
+        };
+
+        self.bind_group = Some(self.statics.create_bind_group(
+            &constants.device,
+            &self.statics.uniform_buffer,
+            &cursor_texture.texture,
+        ));
+
+        if let Some(magnifier) = &uniforms.magnifier {
+            let hotspot_position = interpolated_cursor
+                .position
+                .to_raw_display_space(&constants.options);
+            let scale = (magnifier.target_bounds[2] - magnifier.target_bounds[0])
+                / (magnifier.crop_bounds[2] - magnifier.crop_bounds[0]);
+            let amount = uniforms.project.magnifier.amount.max(1.0) as f32;
+            let magnified_size = XY::new(size.x as f32 * amount, size.y as f32 * amount);
+
+            let magnifier_uniforms = CursorUniforms {
+                position_size: [
+                    magnifier.target_bounds[0]
+                        + (hotspot_position.x as f32 - magnifier.crop_bounds[0]) * scale
+                        - hotspot.x as f32 * amount,
+                    magnifier.target_bounds[1]
+                        + (hotspot_position.y as f32 - magnifier.crop_bounds[1]) * scale
+                        - hotspot.y as f32 * amount,
+                    magnified_size.x,
+                    magnified_size.y,
+                ],
+                screen_bounds: magnifier.target_bounds,
+                motion_vector_strength: [
+                    scaled_motion.x,
+                    scaled_motion.y,
+                    effective_strength,
+                    cursor_opacity * magnifier.opacity,
+                ],
+                ..cursor_uniforms
+            };
+            let buffer = constants
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Magnified Cursor Uniform Buffer"),
+                    contents: bytemuck::cast_slice(&[magnifier_uniforms]),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+            self.magnifier_bind_group = Some(self.statics.create_bind_group(
+                &constants.device,
+                &buffer,
+                &cursor_texture.texture,
+            ));
+        }
     }
 
     pub fn render(&self, pass: &mut wgpu::RenderPass<'_>) {
@@ -520,6 +587,14 @@ impl CursorLayer {
             pass.draw(0..4, 0..1);
         }
     }
+
+    pub fn render_magnified(&self, pass: &mut wgpu::RenderPass<'_>) {
+        if let Some(bind_group) = &self.magnifier_bind_group {
+            pass.set_pipeline(&self.statics.render_pipeline);
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.draw(0..4, 0..1);
+        }
+    }
 }
 
 fn combine_cursor_motion(parent: XY<f32>, child: XY<f32>) -> XY<f32> {
@@ -559,6 +634,44 @@ pub struct CursorUniforms {
     rotation_params: [f32; 4],
 }
 
+pub(crate) fn cursor_idle_opacity(
+    config: &CursorConfiguration,
+    cursor: &CursorEvents,
+    time_ms: f64,
+) -> f32 {
+    if !config.hide_when_idle || cursor.moves.is_empty() {
+        return 1.0;
+    }
+
+    let hide_delay_secs = config
+        .hide_when_idle_delay
+        .max((CURSOR_IDLE_MIN_DELAY_MS / 1000.0) as f32);
+    let hide_delay_ms = (hide_delay_secs as f64 * 1000.0).max(CURSOR_IDLE_MIN_DELAY_MS);
+    let opacity = compute_cursor_idle_opacity(cursor, time_ms, hide_delay_ms);
+    if opacity <= f32::EPSILON {
+        0.0
+    } else {
+        opacity
+    }
+}
+
+pub(crate) fn cursor_segments_opacity(segments: &[HiddenCursorSegment], time: f64) -> f32 {
+    segments
+        .iter()
+        .map(|segment| {
+            let fade = segment.fade_duration.max(0.0);
+            let distance = (segment.start - time).max(time - segment.end);
+            if distance <= 0.0 {
+                0.0
+            } else if distance >= fade {
+                1.0
+            } else {
+                distance / fade
+            }
+        })
+        .fold(1.0_f64, f64::min) as f32
+}
+
 fn compute_cursor_idle_opacity(
     cursor: &CursorEvents,
     current_time_ms: f64,
@@ -628,7 +741,7 @@ fn compute_cursor_fade_in(cursor: &CursorEvents, current_time_ms: f64, hide_dela
     smoothstep64(0.0, CURSOR_IDLE_FADE_OUT_MS, time_since_resume) as f32
 }
 
-fn get_click_t(clicks: &[CursorClickEvent], time_ms: f64) -> f32 {
+pub(crate) fn get_click_t(clicks: &[CursorClickEvent], time_ms: f64) -> f32 {
     fn smoothstep(low: f32, high: f32, v: f32) -> f32 {
         let t = f32::clamp((v - low) / (high - low), 0.0, 1.0);
         t * t * (3.0 - 2.0 * t)
@@ -682,6 +795,7 @@ fn get_click_t(clicks: &[CursorClickEvent], time_ms: f64) -> f32 {
 struct CursorTexture {
     texture: wgpu::Texture,
     hotspot: XY<f64>,
+    svg: Option<SvgImage>,
 }
 
 impl CursorTexture {
@@ -727,7 +841,29 @@ impl CursorTexture {
             },
         );
 
-        Self { texture, hotspot }
+        Self {
+            texture,
+            hotspot,
+            svg: None,
+        }
+    }
+
+    fn prepare_sprite(
+        constants: &RenderVideoConstants,
+        sprite: &CursorSprite,
+    ) -> Result<Self, String> {
+        if sprite.is_svg() {
+            let svg_data = std::fs::read_to_string(&sprite.path).map_err(|e| e.to_string())?;
+            return Self::prepare_svg(constants, &svg_data, sprite.hotspot);
+        }
+
+        let img = image::open(&sprite.path).map_err(|e| e.to_string())?;
+        Ok(Self::prepare(
+            constants,
+            &img.to_rgba8(),
+            img.dimensions(),
+            sprite.hotspot,
+        ))
     }
 
     /// Prepare a cursor texture on the GPU from a raw SVG file
@@ -736,40 +872,41 @@ impl CursorTexture {
         svg_data: &str,
         hotspot: XY<f64>,
     ) -> Result<Self, String> {
-        let rtree = resvg::usvg::Tree::from_str(svg_data, &resvg::usvg::Options::default())
-            .map_err(|e| format!("Failed to parse SVG: {e}"))?;
-
-        // Although we could probably determine the size that the cursor is going to be render,
-        // that would depend on the cursor size the user selects.
-        //
-        // This would require reinitializing the texture every time that changes which would be more complicated.
-        // So we trade a small about VRAM for only initializing it once.
-        let aspect_ratio = rtree.size().width() / rtree.size().height();
-        let width = (aspect_ratio * SVG_CURSOR_RASTERIZED_HEIGHT as f32) as u32;
-
-        let mut pixmap = tiny_skia::Pixmap::new(width, SVG_CURSOR_RASTERIZED_HEIGHT)
-            .ok_or("Failed to create pixmap")?;
-
-        // Calculate scale to fit the SVG into the target size while maintaining aspect ratio
-        let scale_x = width as f32 / rtree.size().width();
-        let scale_y = SVG_CURSOR_RASTERIZED_HEIGHT as f32 / rtree.size().height();
-        let scale = scale_x.min(scale_y);
-        let transform = tiny_skia::Transform::from_scale(scale, scale);
-
-        resvg::render(&rtree, transform, &mut pixmap.as_mut());
-
-        let rgba: Vec<u8> = pixmap
-            .pixels()
-            .iter()
-            .flat_map(|p| [p.red(), p.green(), p.blue(), p.alpha()])
-            .collect();
+        let svg = SvgImage::parse(svg_data)?;
+        let raster = svg.rasterize(SVG_CURSOR_RASTERIZED_HEIGHT)?;
+
+        Ok(Self {
+            svg: Some(svg),
+            ..Self::prepare(
+                constants,
+                &raster.rgba,
+                (raster.width, raster.height),
+                hotspot,
+            )
+        })
+    }
 
-        Ok(Self::prepare(
-            constants,
-            &rgba,
-            (pixmap.width(), pixmap.height()),
-            hotspot,
-        ))
+    fn match_drawn_height(&mut self, constants: &RenderVideoConstants, drawn_height: f32) {
+        let Some(svg) = self.svg.take() else {
+            return;
+        };
+
+        let height = svg::raster_height(drawn_height);
+        if height != self.texture.height() {
+            match svg.rasterize(height) {
+                Ok(raster) => {
+                    *self = Self::prepare(
+                        constants,
+                        &raster.rgba,
+                        (raster.width, raster.height),
+                        self.hotspot,
+                    );
+                }
+                Err(err) => error!("Failed to re-rasterize SVG cursor: {err}"),
+            }
+        }
+
+        self.svg = Some(svg);
     }
 }
 
@@ -789,6 +926,9 @@ mod tests {
 
     fn cursor_events(times: &[(f64, f64, f64)]) -> CursorEvents {
         CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             moves: times
                 .iter()
                 .map(|(time, x, y)| move_event(*time, *x, *y))
@@ -797,6 +937,32 @@ mod tests {
         }
     }
 
+    fn hidden(start: f64, end: f64) -> HiddenCursorSegment {
+        HiddenCursorSegment {
+            start,
+            end,
+            fade_duration: 0.5,
+        }
+    }
+
+    #[test]
+    fn segments_hide_the_cursor_with_fades() {
+        let segments = [hidden(2.0, 4.0)];
+
+        assert_eq!(cursor_segments_opacity(&segments, 1.0), 1.0);
+        assert_eq!(cursor_segments_opacity(&segments, 1.75), 0.5);
+        assert_eq!(cursor_segments_opacity(&segments, 3.0), 0.0);
+        assert_eq!(cursor_segments_opacity(&segments, 4.25), 0.5);
+        assert_eq!(cursor_segments_opacity(&segments, 5.0), 1.0);
+    }
+
+    #[test]
+    fn nearby_segments_keep_the_cursor_partly_hidden() {
+        let segments = [hidden(1.0, 2.0), hidden(2.4, 3.0)];
+
+        assert!(cursor_segments_opacity(&segments, 2.2) < 0.5);
+    }
+
     #[test]
     fn opacity_stays_visible_with_recent_move() {
         let cursor = cursor_events(&[(0.0, 0.0, 0.0), (1500.0, 0.1, 0.1)]);
@@ -21,6 +21,8 @@ pub struct DisplayLayer {
     uniforms_buffer: wgpu::Buffer,
     pipeline: std::sync::Arc<CompositeVideoFramePipeline>,
     bind_groups: [Option<wgpu::BindGroup>; 2],
+    magnifier_uniforms_buffer: wgpu::Buffer,
+    magnifier_bind_group: Option<wgpu::BindGroup>,
     last_recording_time: Option<f32>,
     yuv_converter: YuvToRgbaConverter,
     pending_copy: Option<PendingTextureCopy>,
@@ -72,6 +74,8 @@ impl DisplayLayer {
             uniforms_buffer,
             pipeline: composite_pipeline,
             bind_groups: [bind_group_0, bind_group_1],
+            magnifier_uniforms_buffer: CompositeVideoFrameUniforms::default().to_buffer(device),
+            magnifier_bind_group: None,
             last_recording_time: None,
             yuv_converter,
             pending_copy: None,
@@ -836,6 +840,34 @@ impl DisplayLayer {
         );
     }
 
+    pub fn prepare_magnifier(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        uniforms: Option<CompositeVideoFrameUniforms>,
+    ) {
+        self.magnifier_bind_group = uniforms.map(|uniforms| {
+            uniforms.write_to_buffer(queue, &self.magnifier_uniforms_buffer);
+            self.pipeline.bind_group(
+                device,
+                &self.magnifier_uniforms_buffer,
+                &self.frame_texture_views[self.current_texture],
+            )
+        });
+    }
+
+    pub fn has_magnifier(&self) -> bool {
+        self.magnifier_bind_group.is_some()
+    }
+
+    pub fn render_magnifier(&self, pass: &mut wgpu::RenderPass<'_>) {
+        if let Some(bind_group) = &self.magnifier_bind_group {
+            pass.set_pipeline(&self.pipeline.render_pipeline);
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+    }
+
     pub fn render(&self, pass: &mut wgpu::RenderPass<'_>) {
         if let Some(bind_group) = &self.bind_groups[self.current_texture] {
             pass.set_pipeline(&self.pipeline.render_pipeline);
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use crate::{
+    ProjectUniforms, RenderSession, RenderVideoConstants,
+    effect_plugin::{EffectPlugin, MAX_EFFECT_PARAMS},
+};
+
+pub struct EffectsLayer {
+    sampler: wgpu::Sampler,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    pipelines: HashMap<String, wgpu::RenderPipeline>,
+    prepared: Vec<(String, wgpu::Buffer)>,
+}
+
+impl EffectsLayer {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Effect Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Effect Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        Self {
+            sampler: device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: wgpu::FilterMode::Linear,
+                min_filter: wgpu::FilterMode::Linear,
+                mipmap_filter: wgpu::FilterMode::Nearest,
+                ..Default::default()
+            }),
+            bind_group_layout,
+            pipeline_layout,
+            pipelines: HashMap::new(),
+            prepared: vec![],
+        }
+    }
+
+    pub fn has_content(&self) -> bool {
+        !self.prepared.is_empty()
+    }
+
+    pub fn prepare(&mut self, constants: &RenderVideoConstants, uniforms: &ProjectUniforms) {
+        self.prepared.clear();
+
+        let frame_size = uniforms.frame_size();
+        let uv_rect = uniforms
+            .tile
+            .map(|tile| tile.uv_rect())
+            .unwrap_or([0.0, 0.0, 1.0, 1.0]);
+        let time = uniforms.frame_number as f32 / uniforms.frame_rate.max(1) as f32;
+
+        for effect in &constants.effects {
+            if !self.pipelines.contains_key(&effect.id) {
+                let pipeline = self.create_pipeline(&constants.device, effect);
+                self.pipelines.insert(effect.id.clone(), pipeline);
+            }
+
+            let effect_uniforms = EffectUniforms {
+                output_size: [frame_size.0 as f32, frame_size.1 as f32],
+                time,
+                _padding: 0.0,
+                uv_rect,
+                params: effect.packed_params(
+                    uniforms
+                        .project
+                        .effect_params
+                        .get(&effect.id)
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                ),
+            };
+
+            let buffer = constants
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Effect Uniform Buffer"),
+                    contents: bytemuck::cast_slice(&[effect_uniforms]),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+
+            self.prepared.push((effect.id.clone(), buffer));
+        }
+    }
+
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        session: &mut RenderSession,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        for (id, uniforms_buffer) in &self.prepared {
+            let Some(pipeline) = self.pipelines.get(id) else {
+                continue;
+            };
+
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Effect Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: uniforms_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(
+                            session.current_texture_view(),
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                ],
+            });
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Effect Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: session.other_texture_view(),
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+
+            drop(pass);
+            session.swap_textures();
+        }
+    }
+
+    fn create_pipeline(
+        &self,
+        device: &wgpu::Device,
+        effect: &EffectPlugin,
+    ) -> wgpu::RenderPipeline {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(&format!("Effect Shader ({})", effect.id)),
+            source: wgpu::ShaderSource::Wgsl(effect.shader_source.as_str().into()),
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("Effect Pipeline ({})", effect.id)),
+            layout: Some(&self.pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct EffectUniforms {
+    output_size: [f32; 2],
+    time: f32,
+    _padding: f32,
+    uv_rect: [f32; 4],
+    params: [[f32; 4]; MAX_EFFECT_PARAMS / 4],
+}
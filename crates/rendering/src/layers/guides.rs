@@ -0,0 +1,242 @@
+use bytemuck::{Pod, Zeroable};
+use cap_project::XY;
+use wgpu::util::DeviceExt;
+
+use crate::{PreviewGuides, RenderSession};
+
+const SAFE_AREA_FLAG: u32 = 1;
+const CROP_FLAG: u32 = 1 << 1;
+
+pub struct GuidesLayer {
+    sampler: wgpu::Sampler,
+    pipeline: GuidesPipeline,
+}
+
+impl GuidesLayer {
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self {
+            sampler: device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: wgpu::FilterMode::Nearest,
+                min_filter: wgpu::FilterMode::Nearest,
+                mipmap_filter: wgpu::FilterMode::Nearest,
+                ..Default::default()
+            }),
+            pipeline: GuidesPipeline::new(device),
+        }
+    }
+
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        session: &mut RenderSession,
+        encoder: &mut wgpu::CommandEncoder,
+        guides: &PreviewGuides,
+        output_size: (u32, u32),
+    ) {
+        let uniforms = GuidesUniforms::new(guides, XY::new(output_size.0, output_size.1));
+        let uniforms_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Guides Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[uniforms]),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+
+        let bind_group = self.pipeline.bind_group(
+            device,
+            &uniforms_buffer,
+            session.current_texture_view(),
+            &self.sampler,
+        );
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Guides Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: session.other_texture_view(),
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        pass.set_pipeline(&self.pipeline.render_pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+
+        drop(pass);
+        session.swap_textures();
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable, PartialEq)]
+struct GuidesUniforms {
+    output_size: [f32; 2],
+    crop_size: [f32; 2],
+    safe_area_inset: [f32; 2],
+    line_width: f32,
+    grid_divisions: u32,
+    flags: u32,
+    padding0: u32,
+    padding1: [u32; 2],
+}
+
+impl GuidesUniforms {
+    fn new(guides: &PreviewGuides, output_size: XY<u32>) -> Self {
+        let size = XY::new(output_size.x as f32, output_size.y as f32);
+        let crop_size = guides.crop_size(size);
+        let safe_area_inset = guides.safe_area_inset(size);
+
+        let mut flags = 0;
+        if safe_area_inset.is_some() {
+            flags |= SAFE_AREA_FLAG;
+        }
+        if crop_size.is_some() {
+            flags |= CROP_FLAG;
+        }
+
+        let crop_size = crop_size.unwrap_or(size);
+        let safe_area_inset = safe_area_inset.unwrap_or(XY::new(0.0, 0.0));
+
+        Self {
+            output_size: [size.x, size.y],
+            crop_size: [crop_size.x, crop_size.y],
+            safe_area_inset: [safe_area_inset.x, safe_area_inset.y],
+            line_width: (size.y / 1080.0).max(1.0),
+            grid_divisions: guides.grid_divisions.unwrap_or(0),
+            flags,
+            padding0: 0,
+            padding1: [0; 2],
+        }
+    }
+}
+
+pub struct GuidesPipeline {
+    bind_group_layout: wgpu::BindGroupLayout,
+    render_pipeline: wgpu::RenderPipeline,
+}
+
+impl GuidesPipeline {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Guides Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Guides Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/guides.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Guides Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Guides Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions {
+                    constants: &[],
+                    zero_initialize_workgroup_memory: false,
+                },
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            bind_group_layout,
+            render_pipeline,
+        }
+    }
+
+    pub fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        uniform_buffer: &wgpu::Buffer,
+        texture_view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Guides Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(texture_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        })
+    }
+}
@@ -0,0 +1,241 @@
+use bytemuck::{Pod, Zeroable};
+use glyphon::cosmic_text::LayoutRunIter;
+use glyphon::{
+    Attrs, Buffer, Color, Family, Metrics, Shaping, TextArea, TextBounds, TextRenderer, Weight,
+};
+use log::warn;
+use wgpu::{Device, Queue, include_wgsl, util::DeviceExt};
+
+use crate::{fonts::TextSystem, keystrokes::PreparedKeystroke};
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable, Debug, Default)]
+struct PillUniforms {
+    rect: [f32; 4],
+    color: [f32; 4],
+    radius: f32,
+    _padding: [f32; 3],
+    _padding2: [f32; 4],
+}
+
+pub struct KeystrokesLayer {
+    text_renderer: TextRenderer,
+    text_buffer: Buffer,
+    pill_pipeline: wgpu::RenderPipeline,
+    pill_bind_group: wgpu::BindGroup,
+    pill_uniform_buffer: wgpu::Buffer,
+    scissor: Option<[u32; 4]>,
+    output_size: (u32, u32),
+}
+
+impl KeystrokesLayer {
+    pub fn new(device: &Device, text_system: &mut TextSystem) -> Self {
+        let pill_uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Keystrokes Pill Uniform Buffer"),
+            contents: bytemuck::bytes_of(&PillUniforms::default()),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Keystrokes Pill Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let pill_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Keystrokes Pill Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: pill_uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let shader = device.create_shader_module(include_wgsl!("../shaders/caption_bg.wgsl"));
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Keystrokes Pill Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pill_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Keystrokes Pill Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            text_renderer: text_system.create_renderer(device),
+            text_buffer: Buffer::new_empty(Metrics::new(24.0, 24.0 * 1.2)),
+            pill_pipeline,
+            pill_bind_group,
+            pill_uniform_buffer,
+            scissor: None,
+            output_size: (0, 0),
+        }
+    }
+
+    pub fn prepare(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        text_system: &mut TextSystem,
+        keystroke: Option<&PreparedKeystroke>,
+        output_size: (u32, u32),
+    ) {
+        self.scissor = None;
+        self.output_size = output_size;
+
+        let Some(keystroke) = keystroke.filter(|keystroke| keystroke.font_size >= 1.0) else {
+            return;
+        };
+
+        let font_size = keystroke.font_size;
+        let mut buffer = Buffer::new(
+            &mut text_system.font_system,
+            Metrics::new(font_size, font_size * 1.2),
+        );
+        buffer.set_size(&mut text_system.font_system, None, None);
+
+        let alpha = (keystroke.opacity.clamp(0.0, 1.0) * 255.0) as u8;
+        let color = Color::rgba(255, 255, 255, alpha);
+        let attrs = Attrs::new()
+            .family(Family::SansSerif)
+            .weight(Weight::SEMIBOLD)
+            .color(color);
+        let caret = keystroke.caret.map(|visible| {
+            let alpha = if visible { alpha } else { 0 };
+            ("|", attrs.clone().color(Color::rgba(255, 255, 255, alpha)))
+        });
+        buffer.set_rich_text(
+            &mut text_system.font_system,
+            [(keystroke.label.as_str(), attrs.clone())]
+                .into_iter()
+                .chain(caret),
+            &attrs,
+            Shaping::Advanced,
+            None,
+        );
+        buffer.shape_until_scroll(&mut text_system.font_system, false);
+
+        let text_width = LayoutRunIter::new(&buffer)
+            .map(|run| run.line_w)
+            .fold(0.0, f32::max);
+        let text_height = font_size * 1.2;
+
+        let padding = [font_size * 0.7, font_size * 0.35];
+        let size = [
+            text_width + padding[0] * 2.0,
+            text_height + padding[1] * 2.0,
+        ];
+        let left = keystroke.anchor[0] - size[0] * keystroke.align[0];
+        let top = keystroke.anchor[1] - size[1] * keystroke.align[1];
+        let text_left = left + padding[0];
+        let text_top = top + padding[1];
+
+        self.text_buffer = buffer;
+
+        let text_area = TextArea {
+            buffer: &self.text_buffer,
+            left: text_left,
+            top: text_top,
+            scale: 1.0,
+            bounds: TextBounds {
+                left: (text_left - 2.0).floor() as i32,
+                top: (text_top - 2.0).floor() as i32,
+                right: (text_left + text_width + 2.0).ceil() as i32,
+                bottom: (text_top + text_height + 2.0).ceil() as i32,
+            },
+            default_color: color,
+            custom_glyphs: &[],
+        };
+
+        if let Err(e) = self.text_renderer.prepare(
+            device,
+            queue,
+            &mut text_system.font_system,
+            &mut text_system.atlas,
+            &text_system.viewport,
+            [text_area],
+            &mut text_system.swash_cache,
+        ) {
+            warn!("Error preparing keystroke text: {e:?}");
+            return;
+        }
+
+        queue.write_buffer(
+            &self.pill_uniform_buffer,
+            0,
+            bytemuck::bytes_of(&PillUniforms {
+                rect: [left, top, size[0], size[1]],
+                color: [0.08, 0.08, 0.1, 0.8 * keystroke.opacity.clamp(0.0, 1.0)],
+                radius: size[1] / 2.0,
+                ..Default::default()
+            }),
+        );
+
+        let x = left.floor().max(0.0) as u32;
+        let y = top.floor().max(0.0) as u32;
+        let right = ((left + size[0]).ceil().max(0.0) as u32).min(output_size.0);
+        let bottom = ((top + size[1]).ceil().max(0.0) as u32).min(output_size.1);
+        if right > x && bottom > y {
+            self.scissor = Some([x, y, right - x, bottom - y]);
+        }
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.scissor.is_some()
+    }
+
+    pub fn render<'a>(&'a self, text_system: &'a TextSystem, pass: &mut wgpu::RenderPass<'a>) {
+        let Some([x, y, width, height]) = self.scissor else {
+            return;
+        };
+
+        pass.set_scissor_rect(x, y, width, height);
+        pass.set_pipeline(&self.pill_pipeline);
+        pass.set_bind_group(0, &self.pill_bind_group, &[]);
+        pass.draw(0..6, 0..1);
+
+        if let Err(e) = self
+            .text_renderer
+            .render(&text_system.atlas, &text_system.viewport, pass)
+        {
+            warn!("Error rendering keystroke text: {e:?}");
+        }
+
+        pass.set_scissor_rect(0, 0, self.output_size.0, self.output_size.1);
+    }
+}
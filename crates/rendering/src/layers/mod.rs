@@ -2,16 +2,26 @@ mod background;
 mod blur;
 mod camera;
 mod captions;
+mod confetti;
 mod cursor;
 mod display;
+mod effects;
+mod guides;
+mod keystrokes;
 mod mask;
 mod text;
+mod watermark;
 
 pub use background::*;
 pub use blur::*;
 pub use camera::*;
 pub use captions::*;
+pub use confetti::*;
 pub use cursor::*;
 pub use display::*;
+pub use effects::*;
+pub use guides::*;
+pub use keystrokes::*;
 pub use mask::*;
 pub use text::*;
+pub use watermark::*;
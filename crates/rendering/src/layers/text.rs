@@ -1,42 +1,24 @@
 use glyphon::cosmic_text::Align;
 use glyphon::{
-    Attrs, Buffer, Cache, Color, Family, FontSystem, Metrics, Resolution, Shaping, Style,
-    SwashCache, TextArea, TextAtlas, TextBounds, TextRenderer, Viewport, Weight,
+    Attrs, Buffer, Color, Metrics, Shaping, Style, TextArea, TextBounds, TextRenderer, Weight,
 };
 use log::warn;
 use wgpu::{Device, Queue};
 
-use crate::text::PreparedText;
+use crate::{
+    fonts::{self, TextSystem},
+    text::PreparedText,
+};
 
 pub struct TextLayer {
-    font_system: FontSystem,
-    swash_cache: SwashCache,
-    text_atlas: TextAtlas,
     text_renderer: TextRenderer,
-    viewport: Viewport,
     buffers: Vec<Buffer>,
 }
 
 impl TextLayer {
-    pub fn new(device: &Device, queue: &Queue) -> Self {
-        let font_system = FontSystem::new();
-        let swash_cache = SwashCache::new();
-        let cache = Cache::new(device);
-        let viewport = Viewport::new(device, &cache);
-        let mut text_atlas = TextAtlas::new(device, queue, &cache, wgpu::TextureFormat::Rgba8Unorm);
-        let text_renderer = TextRenderer::new(
-            &mut text_atlas,
-            device,
-            wgpu::MultisampleState::default(),
-            None,
-        );
-
+    pub fn new(device: &Device, text_system: &mut TextSystem) -> Self {
         Self {
-            font_system,
-            swash_cache,
-            text_atlas,
-            text_renderer,
-            viewport,
+            text_renderer: text_system.create_renderer(device),
             buffers: Vec::new(),
         }
     }
@@ -45,9 +27,11 @@ impl TextLayer {
         &mut self,
         device: &Device,
         queue: &Queue,
-        output_size: (u32, u32),
+        text_system: &mut TextSystem,
         texts: &[PreparedText],
     ) {
+        let font_system = &mut text_system.font_system;
+
         self.buffers.clear();
         self.buffers.reserve(texts.len());
         let mut text_area_data = Vec::with_capacity(texts.len());
@@ -65,21 +49,11 @@ impl TextLayer {
             let height = (text.bounds[3] - text.bounds[1]).max(1.0);
 
             let metrics = Metrics::new(text.font_size, text.font_size * 1.2);
-            let mut buffer = Buffer::new(&mut self.font_system, metrics);
-            buffer.set_size(&mut self.font_system, Some(width), Some(height));
-            buffer.set_wrap(&mut self.font_system, glyphon::Wrap::Word);
-
-            let family = match text.font_family.trim() {
-                "" => Family::SansSerif,
-                name => match name.to_ascii_lowercase().as_str() {
-                    "sans" | "sans-serif" | "system sans" | "system sans-serif" => {
-                        Family::SansSerif
-                    }
-                    "serif" | "system serif" => Family::Serif,
-                    "mono" | "monospace" | "system mono" | "system monospace" => Family::Monospace,
-                    _ => Family::Name(name),
-                },
-            };
+            let mut buffer = Buffer::new(font_system, metrics);
+            buffer.set_size(font_system, Some(width), Some(height));
+            buffer.set_wrap(font_system, glyphon::Wrap::Word);
+
+            let family = fonts::family(&text.font_family);
             let weight = Weight(text.font_weight.round().clamp(100.0, 900.0) as u16);
             let attrs = Attrs::new()
                 .family(family)
@@ -91,18 +65,13 @@ impl TextLayer {
                     Style::Normal
                 });
 
-            buffer.set_text(
-                &mut self.font_system,
-                &text.content,
-                &attrs,
-                Shaping::Advanced,
-            );
+            buffer.set_text(font_system, &text.content, &attrs, Shaping::Advanced);
 
             for line in buffer.lines.iter_mut() {
                 line.set_align(Some(Align::Center));
             }
 
-            buffer.shape_until_scroll(&mut self.font_system, false);
+            buffer.shape_until_scroll(font_system, false);
 
             let bounds = TextBounds {
                 left: text.bounds[0].floor() as i32,
@@ -130,31 +99,23 @@ impl TextLayer {
             })
             .collect::<Vec<_>>();
 
-        self.viewport.update(
-            queue,
-            Resolution {
-                width: output_size.0,
-                height: output_size.1,
-            },
-        );
-
         if let Err(error) = self.text_renderer.prepare(
             device,
             queue,
-            &mut self.font_system,
-            &mut self.text_atlas,
-            &self.viewport,
+            &mut text_system.font_system,
+            &mut text_system.atlas,
+            &text_system.viewport,
             text_areas,
-            &mut self.swash_cache,
+            &mut text_system.swash_cache,
         ) {
             warn!("Failed to prepare text: {error:?}");
         }
     }
 
-    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
-        if let Err(error) = self
-            .text_renderer
-            .render(&self.text_atlas, &self.viewport, pass)
+    pub fn render<'a>(&'a self, text_system: &'a TextSystem, pass: &mut wgpu::RenderPass<'a>) {
+        if let Err(error) =
+            self.text_renderer
+                .render(&text_system.atlas, &text_system.viewport, pass)
         {
             warn!("Failed to render text: {error:?}");
         }
@@ -0,0 +1,123 @@
+use cap_project::{BackgroundConfiguration, LayoutKeyframe};
+
+use crate::spring_mass_damper::solve_spring_1d;
+
+const SPRING_STIFFNESS: f32 = 100.0;
+const SPRING_DAMPING: f32 = 20.0;
+const SPRING_MASS: f32 = 1.0;
+
+pub(crate) fn apply_layout_keyframes(
+    keyframes: &[LayoutKeyframe],
+    background: &mut BackgroundConfiguration,
+    time: f64,
+) {
+    let time_ms = time * 1000.0;
+    let mut keyframes = keyframes
+        .iter()
+        .filter(|k| k.start_ms <= time_ms)
+        .collect::<Vec<_>>();
+    if keyframes.is_empty() {
+        return;
+    }
+    keyframes.sort_by(|a, b| a.start_ms.total_cmp(&b.start_ms));
+
+    let omega0 = (SPRING_STIFFNESS / SPRING_MASS).sqrt();
+    let zeta = SPRING_DAMPING / (2.0 * (SPRING_STIFFNESS * SPRING_MASS).sqrt());
+
+    let mut values = [
+        background.padding as f32,
+        background.rounding as f32,
+        background.shadow,
+    ];
+    let mut velocities = [0.0; 3];
+
+    for (index, keyframe) in keyframes.iter().enumerate() {
+        let end_ms = keyframes
+            .get(index + 1)
+            .map_or(time_ms, |next| next.start_ms);
+        let elapsed = ((end_ms - keyframe.start_ms) / 1000.0) as f32;
+        let targets = [
+            keyframe.padding as f32,
+            keyframe.rounding as f32,
+            keyframe.shadow,
+        ];
+
+        for ((value, velocity), target) in values.iter_mut().zip(&mut velocities).zip(targets) {
+            let (displacement, new_velocity) =
+                solve_spring_1d(*value - target, *velocity, elapsed, omega0, zeta);
+            *value = target + displacement;
+            *velocity = new_velocity;
+        }
+    }
+
+    let [padding, rounding, shadow] = values;
+    background.padding = padding as f64;
+    background.rounding = rounding as f64;
+    background.shadow = shadow;
+}
+
+pub(crate) fn apply_settled_layout_keyframe(
+    keyframes: &[LayoutKeyframe],
+    background: &mut BackgroundConfiguration,
+    time: f64,
+) {
+    let time_ms = time * 1000.0;
+    if let Some(keyframe) = keyframes
+        .iter()
+        .filter(|k| k.start_ms <= time_ms)
+        .max_by(|a, b| a.start_ms.total_cmp(&b.start_ms))
+    {
+        background.padding = keyframe.padding;
+        background.rounding = keyframe.rounding;
+        background.shadow = keyframe.shadow;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframe(start_ms: f64, padding: f64) -> LayoutKeyframe {
+        LayoutKeyframe {
+            start_ms,
+            padding,
+            rounding: 0.0,
+            shadow: 0.0,
+        }
+    }
+
+    fn padding_at(keyframes: &[LayoutKeyframe], time: f64) -> f64 {
+        let mut background = BackgroundConfiguration {
+            padding: 10.0,
+            rounding: 0.0,
+            shadow: 0.0,
+            ..Default::default()
+        };
+        apply_layout_keyframes(keyframes, &mut background, time);
+        background.padding
+    }
+
+    #[test]
+    fn springs_towards_keyframes() {
+        let keyframes = [keyframe(1000.0, 0.0)];
+
+        assert_eq!(padding_at(&keyframes, 0.5), 10.0);
+        assert_eq!(padding_at(&keyframes, 1.0), 10.0);
+
+        let early = padding_at(&keyframes, 1.1);
+        let later = padding_at(&keyframes, 1.3);
+        assert!(early < 10.0 && later < early && later > 0.0);
+
+        assert!(padding_at(&keyframes, 3.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn carries_motion_between_keyframes() {
+        let keyframes = [keyframe(100.0, 10.0), keyframe(0.0, 0.0)];
+
+        let turning = padding_at(&keyframes, 0.1);
+        assert!(turning > 0.0 && turning < 10.0);
+        assert!(padding_at(&keyframes, 0.12) < turning);
+        assert!((padding_at(&keyframes, 3.0) - 10.0).abs() < 0.01);
+    }
+}
@@ -1,53 +1,89 @@
 use anyhow::Result;
 use cap_project::{
-    AspectRatio, CameraShape, CameraXPosition, CameraYPosition, ClipOffsets, CornerStyle, Crop,
-    CursorEvents, MaskKind, ProjectConfiguration, RecordingMeta, StudioRecordingMeta, XY,
+    AspectRatio, CameraShape, CameraXPosition, CameraYPosition, CardConfiguration, ClipOffsets,
+    CornerStyle, Crop, CursorEvents, DisplayFit, FitMode, MaskKind, ProjectConfiguration,
+    RecordingMeta, StudioRecordingMeta, XY, ZoomSegment,
 };
 use composite_frame::CompositeVideoFrameUniforms;
+use confetti::{ConfettiParticle, prepare_confetti};
 use core::f64;
 use cursor_interpolation::{InterpolatedCursorPosition, interpolate_cursor};
 use decoder::{AsyncVideoDecoderHandle, spawn_decoder};
 use frame_pipeline::{RenderSession, finish_encoder, finish_encoder_nv12, flush_pending_readback};
 use futures::future::OptionFuture;
+use keystrokes::{PreparedKeystroke, prepare_keystroke};
 use layers::{
-    Background, BackgroundLayer, BlurLayer, CameraLayer, CaptionsLayer, CursorLayer, DisplayLayer,
-    MaskLayer, TextLayer,
+    Background, BackgroundLayer, BlurLayer, CameraLayer, CaptionsLayer, ConfettiLayer, CursorLayer,
+    DisplayLayer, EffectsLayer, GuidesLayer, KeystrokesLayer, MaskLayer, TextLayer, WatermarkLayer,
+    cursor_segments_opacity,
 };
+use magnifier::magnifier_uniforms;
 use specta::Type;
 use spring_mass_damper::SpringMassDamperSimulationConfig;
 use std::{collections::HashMap, sync::Arc};
-use std::{path::PathBuf, time::Instant};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
 
+mod background_keyframes;
+mod brand;
 pub mod composite_frame;
+mod confetti;
 mod coord;
 pub mod cpu_yuv;
 mod cursor_interpolation;
+mod cursor_theme;
 #[cfg(target_os = "windows")]
 pub mod d3d_texture;
 pub mod decoder;
+mod effect_plugin;
+mod fonts;
 mod frame_pipeline;
+#[cfg(test)]
+mod golden_tests;
+mod guides;
 #[cfg(target_os = "macos")]
 pub mod iosurface_texture;
+mod keystrokes;
 mod layers;
+mod layout_keyframes;
+mod magnifier;
 mod mask;
+mod preview_surface;
 mod project_recordings;
+mod render_budget;
 mod scene;
 pub mod spring_mass_damper;
+mod static_frame;
+pub mod svg;
 mod text;
+mod tiles;
 pub mod yuv_converter;
 mod zoom;
 pub mod zoom_focus_interpolation;
 
+pub use brand::{BrandAssets, Watermark, WatermarkCorner, font_families};
 pub use coord::*;
+pub use cursor_theme::{CursorSprite, CursorTheme, CursorThemeError};
 pub use decoder::{DecodedFrame, DecoderStatus, DecoderType, PixelFormat};
-pub use frame_pipeline::{GpuOutputFormat, Nv12RenderedFrame, RenderedFrame};
+pub use effect_plugin::{EffectParam, EffectPlugin, EffectPluginError, MAX_EFFECT_PARAMS};
+pub use fonts::list_system_fonts;
+pub use frame_pipeline::{FrameTimings, GpuOutputFormat, Nv12RenderedFrame, RenderedFrame};
+pub use guides::PreviewGuides;
+pub use preview_surface::PreviewSurface;
 pub use project_recordings::{ProjectRecordingsMeta, SegmentRecordings, Video};
+pub use render_budget::RenderBudget;
+pub use tiles::RenderTile;
 
+use fonts::TextSystem;
 use mask::interpolate_masks;
 use scene::*;
-use text::{PreparedText, prepare_texts};
+use static_frame::StaticFrameDetector;
+use text::{PreparedText, prepare_reactions, prepare_texts};
 use zoom::*;
+pub use zoom::{InterpolatedZoom, SegmentBounds, SegmentsCursor};
 pub use zoom_focus_interpolation::ZoomFocusInterpolator;
 
 pub fn is_software_wgpu_adapter(info: &wgpu::AdapterInfo) -> bool {
@@ -375,6 +411,12 @@ pub enum RenderingError {
         frame_number: u32,
         consecutive_failures: u32,
     },
+    #[error("The preview window's surface can't be drawn to with this GPU")]
+    SurfaceUnsupported,
+    #[error("Failed to get the preview window's next frame: {0}")]
+    SurfaceFrame(#[from] wgpu::SurfaceError),
+    #[error("The frame is too large to composite in one pass")]
+    FrameTooLarge,
 }
 
 pub struct RenderSegment {
@@ -451,6 +493,8 @@ pub async fn render_video_to_channel(
     const MAX_CONSECUTIVE_FAILURES: u32 = 200;
 
     let mut prefetched_decode: Option<(u32, f64, usize, Option<DecodedSegmentFrames>)> = None;
+    let mut static_frames = StaticFrameDetector::new(constants);
+    let mut reused_frame: Option<RenderedFrame> = None;
 
     loop {
         if frame_number >= total_frames {
@@ -521,6 +565,32 @@ pub async fn render_video_to_channel(
                 zoom_focus_interp,
             );
 
+            if static_frames.is_static(&segment_frames, &uniforms, &render_segment.cursor) {
+                let (reused, pipelined) = match reused_frame.take() {
+                    Some(frame) => (Some(frame), true),
+                    None => match frame_renderer.flush_pipeline().await {
+                        Some(Ok(frame)) => (Some(frame), true),
+                        _ => (last_successful_frame.clone(), false),
+                    },
+                };
+
+                if let Some(mut frame) = reused {
+                    frame.frame_number = current_frame_number;
+                    frame.target_time_ns =
+                        (current_frame_number as u64 * 1_000_000_000) / fps as u64;
+                    last_successful_frame = Some(frame.clone());
+                    if pipelined {
+                        reused_frame = Some(frame.clone());
+                    }
+                    sender.send((frame, current_frame_number)).await?;
+                    continue;
+                }
+            }
+
+            if let Some(frame) = reused_frame.take() {
+                sender.send((frame, current_frame_number)).await?;
+            }
+
             let next_frame_number = frame_number;
             let mut next_prefetch_meta: Option<(f64, usize)> = None;
             let prefetch_future = if next_frame_number < total_frames {
@@ -617,6 +687,7 @@ pub async fn render_video_to_channel(
             }
         } else {
             consecutive_failures += 1;
+            static_frames.reset();
 
             if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
                 tracing::error!(
@@ -655,7 +726,9 @@ pub async fn render_video_to_channel(
         }
     }
 
-    if let Some(Ok(final_frame)) = frame_renderer.flush_pipeline().await
+    if let Some(frame) = reused_frame.take() {
+        sender.send((frame, frame_number.saturating_sub(1))).await?;
+    } else if let Some(Ok(final_frame)) = frame_renderer.flush_pipeline().await
         && final_frame.width > 0
         && final_frame.height > 0
     {
@@ -743,7 +816,9 @@ pub async fn render_video_to_channel_nv12(
     let mut consecutive_failures = 0u32;
     const MAX_CONSECUTIVE_FAILURES: u32 = 200;
 
-    let mut prefetched_decode: Option<(u32, f64, usize, Option<DecodedSegmentFrames>)> = None;
+    let mut prefetched_decode: Option<(u32, usize, Option<DecodedSegmentFrames>, Duration)> = None;
+    let mut static_frames = StaticFrameDetector::new(constants);
+    let mut reused_frame: Option<Nv12RenderedFrame> = None;
 
     loop {
         if frame_number >= total_frames {
@@ -770,32 +845,24 @@ pub async fn render_video_to_channel_nv12(
         let is_initial_frame = current_frame_number == 0 || last_successful_frame.is_none();
         let segment_clip_index = segment.recording_clip as usize;
 
-        let segment_frames =
-            if let Some((pf_num, _pf_time, pf_clip, pf_result)) = prefetched_decode.take() {
-                if pf_num == current_frame_number && pf_clip == segment_clip_index {
-                    pf_result
-                } else {
-                    decode_segment_frames_with_retry(
-                        &render_segment.decoders,
-                        segment_time,
-                        needs_camera,
-                        clip_config.map(|v| v.offsets).unwrap_or_default(),
-                        current_frame_number,
-                        is_initial_frame,
-                    )
-                    .await
-                }
-            } else {
-                decode_segment_frames_with_retry(
+        let (segment_frames, decode_time) = match prefetched_decode.take() {
+            Some((pf_num, pf_clip, pf_result, pf_time))
+                if pf_num == current_frame_number && pf_clip == segment_clip_index =>
+            {
+                (pf_result, pf_time)
+            }
+            _ => {
+                timed(decode_segment_frames_with_retry(
                     &render_segment.decoders,
                     segment_time,
                     needs_camera,
                     clip_config.map(|v| v.offsets).unwrap_or_default(),
                     current_frame_number,
                     is_initial_frame,
-                )
+                ))
                 .await
-            };
+            }
+        };
 
         if let Some(segment_frames) = segment_frames {
             consecutive_failures = 0;
@@ -814,14 +881,49 @@ pub async fn render_video_to_channel_nv12(
                 zoom_focus_interp,
             );
 
+            if static_frames.is_static(&segment_frames, &uniforms, &render_segment.cursor) {
+                let (reused, pipelined) = match reused_frame.take() {
+                    Some(frame) => (Some(frame), true),
+                    None => match frame_renderer.flush_pipeline_nv12().await {
+                        Some(Ok(frame)) => (Some(frame), true),
+                        _ => (
+                            last_successful_frame
+                                .as_ref()
+                                .map(|f| f.clone_metadata_with_data()),
+                            false,
+                        ),
+                    },
+                };
+
+                if let Some(mut frame) = reused {
+                    frame.frame_number = current_frame_number;
+                    frame.timings = FrameTimings {
+                        decode: decode_time,
+                        ..Default::default()
+                    };
+                    frame.target_time_ns =
+                        (current_frame_number as u64 * 1_000_000_000) / fps as u64;
+                    last_successful_frame = Some(frame.clone_metadata_with_data());
+                    if pipelined {
+                        reused_frame = Some(frame.clone_metadata_with_data());
+                    }
+                    sender.send((frame, current_frame_number)).await?;
+                    continue;
+                }
+            }
+
+            if let Some(frame) = reused_frame.take() {
+                sender.send((frame, current_frame_number)).await?;
+            }
+
             let next_frame_number = frame_number;
-            let mut next_prefetch_meta: Option<(f64, usize)> = None;
+            let mut next_prefetch_clip: Option<usize> = None;
             let prefetch_future = if next_frame_number < total_frames {
                 if let Some((next_seg_time, next_segment)) =
                     project.get_segment_time(next_frame_number as f64 / fps as f64)
                 {
                     let next_clip_index = next_segment.recording_clip as usize;
-                    next_prefetch_meta = Some((next_seg_time, next_clip_index));
+                    next_prefetch_clip = Some(next_clip_index);
                     let next_render_segment = &render_segments[next_clip_index];
                     let next_clip_config = project
                         .clips
@@ -829,14 +931,14 @@ pub async fn render_video_to_channel_nv12(
                         .find(|v| v.index == next_segment.recording_clip);
                     let next_is_initial = last_successful_frame.is_none();
 
-                    Some(decode_segment_frames_with_retry(
+                    Some(timed(decode_segment_frames_with_retry(
                         &next_render_segment.decoders,
                         next_seg_time,
                         needs_camera,
                         next_clip_config.map(|v| v.offsets).unwrap_or_default(),
                         next_frame_number,
                         next_is_initial,
-                    ))
+                    )))
                 } else {
                     None
                 }
@@ -845,7 +947,7 @@ pub async fn render_video_to_channel_nv12(
             };
 
             let render_result = if let Some(prefetch) = prefetch_future {
-                let (render, decoded) = tokio::join!(
+                let (render, (decoded, decoded_time)) = tokio::join!(
                     frame_renderer.render_nv12(
                         segment_frames,
                         uniforms,
@@ -855,9 +957,9 @@ pub async fn render_video_to_channel_nv12(
                     prefetch
                 );
 
-                if let Some((next_seg_time, next_clip_index)) = next_prefetch_meta {
+                if let Some(next_clip_index) = next_prefetch_clip {
                     prefetched_decode =
-                        Some((next_frame_number, next_seg_time, next_clip_index, decoded));
+                        Some((next_frame_number, next_clip_index, decoded, decoded_time));
                 }
 
                 render
@@ -873,7 +975,8 @@ pub async fn render_video_to_channel_nv12(
             };
 
             match render_result {
-                Ok(Some(frame)) if frame.width > 0 && frame.height > 0 => {
+                Ok(Some(mut frame)) if frame.width > 0 && frame.height > 0 => {
+                    frame.timings.decode = decode_time;
                     last_successful_frame = Some(frame.clone_metadata_with_data());
                     sender.send((frame, current_frame_number)).await?;
                 }
@@ -910,6 +1013,7 @@ pub async fn render_video_to_channel_nv12(
             }
         } else {
             consecutive_failures += 1;
+            static_frames.reset();
 
             if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
                 tracing::error!(
@@ -948,7 +1052,9 @@ pub async fn render_video_to_channel_nv12(
         }
     }
 
-    if let Some(Ok(final_frame)) = frame_renderer.flush_pipeline_nv12().await
+    if let Some(frame) = reused_frame.take() {
+        sender.send((frame, frame_number.saturating_sub(1))).await?;
+    } else if let Some(Ok(final_frame)) = frame_renderer.flush_pipeline_nv12().await
         && final_frame.width > 0
         && final_frame.height > 0
     {
@@ -967,8 +1073,180 @@ pub async fn render_video_to_channel_nv12(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+pub async fn render_card_frame_nv12(
+    constants: &RenderVideoConstants,
+    project: &ProjectConfiguration,
+    render_segment: &RenderSegment,
+    card: &CardConfiguration,
+    fallback_title: &str,
+    fps: u32,
+    resolution_base: XY<u32>,
+    duration: f64,
+) -> Result<Nv12RenderedFrame, RenderingError> {
+    let segment_frames = decode_segment_frames_with_retry(
+        &render_segment.decoders,
+        0.0,
+        false,
+        ClipOffsets::default(),
+        0,
+        true,
+    )
+    .await
+    .ok_or(RenderingError::FrameDecodeFailed {
+        frame_number: 0,
+        consecutive_failures: DECODE_MAX_RETRIES,
+    })?;
+
+    let zoom_focus_interpolator = ZoomFocusInterpolator::new(
+        &render_segment.cursor,
+        None,
+        project.screen_movement_spring,
+        duration,
+    );
+
+    let uniforms = ProjectUniforms::new(
+        constants,
+        project,
+        0,
+        fps,
+        resolution_base,
+        &render_segment.cursor,
+        &segment_frames,
+        duration,
+        &zoom_focus_interpolator,
+    )
+    .into_card(card, fallback_title);
+
+    let mut frame_renderer = FrameRenderer::new(constants);
+    let mut layers = RendererLayers::new_with_options(
+        &constants.device,
+        &constants.queue,
+        constants.is_software_adapter,
+    );
+
+    let (screen_w, screen_h) = render_segment.decoders.screen_video_dimensions();
+    layers.prepare_for_video_dimensions(&constants.device, screen_w, screen_h, None, None);
+
+    frame_renderer
+        .render_immediate_nv12(
+            segment_frames,
+            uniforms,
+            &render_segment.cursor,
+            &mut layers,
+        )
+        .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn render_settled_frame_nv12(
+    constants: &RenderVideoConstants,
+    project: &ProjectConfiguration,
+    render_segments: &[RenderSegment],
+    frame_number: u32,
+    fps: u32,
+    resolution_base: XY<u32>,
+    duration: f64,
+) -> Result<Nv12RenderedFrame, RenderingError> {
+    let frame_time = frame_number as f64 / fps as f64;
+    let failed = || RenderingError::FrameDecodeFailed {
+        frame_number,
+        consecutive_failures: DECODE_MAX_RETRIES,
+    };
+    let (segment_time, segment) = project.get_segment_time(frame_time).ok_or_else(failed)?;
+    let render_segment = &render_segments[segment.recording_clip as usize];
+    let clip_config = project
+        .clips
+        .iter()
+        .find(|v| v.index == segment.recording_clip);
+
+    let segment_frames = decode_segment_frames_with_retry(
+        &render_segment.decoders,
+        segment_time,
+        !project.camera.hide,
+        clip_config.map(|v| v.offsets).unwrap_or_default(),
+        frame_number,
+        true,
+    )
+    .await
+    .ok_or_else(failed)?;
+
+    let zoom_focus_interpolator = ZoomFocusInterpolator::new(
+        &render_segment.cursor,
+        None,
+        project.screen_movement_spring,
+        duration,
+    );
+
+    let uniforms = ProjectUniforms::new(
+        constants,
+        &settled_project(project, frame_time),
+        frame_number,
+        fps,
+        resolution_base,
+        &render_segment.cursor,
+        &segment_frames,
+        duration,
+        &zoom_focus_interpolator,
+    );
+
+    let mut frame_renderer = FrameRenderer::new(constants);
+    let mut layers = RendererLayers::new_with_options(
+        &constants.device,
+        &constants.queue,
+        constants.is_software_adapter,
+    );
+
+    let (screen_w, screen_h) = render_segment.decoders.screen_video_dimensions();
+    let camera_dims = render_segment.decoders.camera_video_dimensions();
+    layers.prepare_for_video_dimensions(
+        &constants.device,
+        screen_w,
+        screen_h,
+        camera_dims.map(|(w, _)| w),
+        camera_dims.map(|(_, h)| h),
+    );
+
+    frame_renderer
+        .render_immediate_nv12(
+            segment_frames,
+            uniforms,
+            &render_segment.cursor,
+            &mut layers,
+        )
+        .await
+}
+
+fn settled_project(project: &ProjectConfiguration, time: f64) -> ProjectConfiguration {
+    let mut background = background_keyframes::settled_background_at(project, time);
+    layout_keyframes::apply_settled_layout_keyframe(
+        &project.layout_keyframes,
+        &mut background,
+        time,
+    );
+
+    let mut timeline = project.timeline.clone();
+    if let Some(timeline) = &mut timeline {
+        timeline.zoom_segments = settled_zoom_segments(&timeline.zoom_segments, time);
+    }
+
+    ProjectConfiguration {
+        background,
+        background_keyframes: vec![],
+        layout_keyframes: vec![],
+        timeline,
+        ..project.clone()
+    }
+}
+
 const DECODE_MAX_RETRIES: u32 = 5;
 
+async fn timed<T>(future: impl Future<Output = T>) -> (T, Duration) {
+    let start = Instant::now();
+    let output = future.await;
+    (output, start.elapsed())
+}
+
 async fn decode_segment_frames_with_retry(
     decoders: &RecordingSegmentDecoders,
     segment_time: f64,
@@ -1052,6 +1330,9 @@ pub struct RenderVideoConstants {
     pub recording_meta: RecordingMeta,
     pub background_textures: std::sync::Arc<tokio::sync::RwLock<HashMap<String, wgpu::Texture>>>,
     pub is_software_adapter: bool,
+    pub cursor_theme: Option<Arc<CursorTheme>>,
+    pub effects: Vec<Arc<EffectPlugin>>,
+    pub brand: BrandAssets,
     adapter_name: String,
 }
 
@@ -1094,6 +1375,9 @@ impl RenderVideoConstants {
             meta,
             recording_meta,
             is_software_adapter: shared.is_software_adapter,
+            cursor_theme: None,
+            effects: vec![],
+            brand: BrandAssets::default(),
             adapter_name,
         })
     }
@@ -1102,6 +1386,25 @@ impl RenderVideoConstants {
         &self.adapter_name
     }
 
+    pub fn render_budget(&self) -> RenderBudget {
+        RenderBudget::for_device(&self._adapter, &self.device)
+    }
+
+    pub fn with_cursor_theme(mut self, theme: Option<Arc<CursorTheme>>) -> Self {
+        self.cursor_theme = theme;
+        self
+    }
+
+    pub fn with_effects(mut self, effects: Vec<Arc<EffectPlugin>>) -> Self {
+        self.effects = effects;
+        self
+    }
+
+    pub fn with_brand(mut self, brand: BrandAssets) -> Self {
+        self.brand = brand;
+        self
+    }
+
     pub fn from_shared_device(
         shared: SharedWgpuDevice,
         options: RenderOptions,
@@ -1119,6 +1422,9 @@ impl RenderVideoConstants {
             meta,
             recording_meta,
             is_software_adapter: shared.is_software_adapter,
+            cursor_theme: None,
+            effects: vec![],
+            brand: BrandAssets::default(),
             adapter_name,
         }
     }
@@ -1216,6 +1522,9 @@ impl RenderVideoConstants {
             meta,
             recording_meta,
             is_software_adapter,
+            cursor_theme: None,
+            effects: vec![],
+            brand: BrandAssets::default(),
             adapter_name,
         })
     }
@@ -1230,7 +1539,9 @@ pub struct ProjectUniforms {
     display: CompositeVideoFrameUniforms,
     camera: Option<CompositeVideoFrameUniforms>,
     camera_only: Option<CompositeVideoFrameUniforms>,
+    magnifier: Option<CompositeVideoFrameUniforms>,
     interpolated_cursor: Option<InterpolatedCursorPosition>,
+    cursor_opacity: f32,
     pub prev_cursor: Option<InterpolatedCursorPosition>,
     pub project: ProjectConfiguration,
     pub zoom: InterpolatedZoom,
@@ -1239,7 +1550,12 @@ pub struct ProjectUniforms {
     pub display_parent_motion_px: XY<f32>,
     pub motion_blur_amount: f32,
     pub masks: Vec<PreparedMask>,
+    pub confetti: Vec<ConfettiParticle>,
+    pub keystroke: Option<PreparedKeystroke>,
     pub texts: Vec<PreparedText>,
+    pub is_card: bool,
+    pub guides: Option<PreviewGuides>,
+    pub tile: Option<RenderTile>,
 }
 
 #[derive(Debug, Clone)]
@@ -1528,6 +1844,32 @@ impl MotionBlurDescriptor {
     }
 }
 
+fn fit_display(
+    output_size: XY<f64>,
+    content_aspect: f64,
+    padding: f64,
+    fit: &DisplayFit,
+) -> (XY<f64>, XY<f64>) {
+    let (area, padding) = match fit.mode {
+        FitMode::Contain => (output_size - 2.0 * padding, padding),
+        FitMode::Cover => (output_size, 0.0),
+    };
+
+    let fits_height = match fit.mode {
+        FitMode::Contain => content_aspect <= area.x / area.y,
+        FitMode::Cover => content_aspect >= area.x / area.y,
+    };
+    let size = if fits_height {
+        XY::new(area.y * content_aspect, area.y)
+    } else {
+        XY::new(area.x, area.x / content_aspect)
+    };
+
+    let anchor = XY::new(fit.anchor.x.clamp(0.0, 1.0), fit.anchor.y.clamp(0.0, 1.0));
+    let offset = (area - size) * anchor;
+    (XY::new(offset.x + padding, offset.y + padding), size)
+}
+
 impl ProjectUniforms {
     pub fn get_crop(options: &RenderOptions, project: &ProjectConfiguration) -> Crop {
         project.background.crop.as_ref().cloned().unwrap_or(Crop {
@@ -1622,22 +1964,27 @@ impl ProjectUniforms {
         project: &ProjectConfiguration,
         resolution_base: XY<u32>,
     ) -> Coord<FrameSpace> {
+        Coord::new(Self::display_rect(options, project, resolution_base).0)
+    }
+
+    pub fn display_size(
+        options: &RenderOptions,
+        project: &ProjectConfiguration,
+        resolution_base: XY<u32>,
+    ) -> Coord<FrameSpace> {
+        Coord::new(Self::display_rect(options, project, resolution_base).1)
+    }
+
+    fn display_rect(
+        options: &RenderOptions,
+        project: &ProjectConfiguration,
+        resolution_base: XY<u32>,
+    ) -> (XY<f64>, XY<f64>) {
         let output_size = Self::get_output_size(options, project, resolution_base);
         let output_size = XY::new(output_size.0 as f64, output_size.1 as f64);
 
-        let output_aspect = output_size.x / output_size.y;
-
         let crop = Self::get_crop(options, project);
-
-        let crop_start =
-            Coord::<RawDisplaySpace>::new(XY::new(crop.position.x as f64, crop.position.y as f64));
-        let crop_end = Coord::<RawDisplaySpace>::new(XY::new(
-            (crop.position.x + crop.size.x) as f64,
-            (crop.position.y + crop.size.y) as f64,
-        ));
-
-        let cropped_size = crop_end.coord - crop_start.coord;
-
+        let cropped_size = XY::new(crop.size.x as f64, crop.size.y as f64);
         let cropped_aspect = cropped_size.x / cropped_size.y;
 
         let padding = {
@@ -1653,51 +2000,21 @@ impl ProjectUniforms {
             base_padding * output_scale
         };
 
-        let is_height_constrained = cropped_aspect <= output_aspect;
-
-        let available_size = output_size - 2.0 * padding;
-
-        let target_size = if is_height_constrained {
-            XY::new(available_size.y * cropped_aspect, available_size.y)
-        } else {
-            XY::new(available_size.x, available_size.x / cropped_aspect)
+        let fit = match project.aspect_ratio {
+            Some(_) => project.background.fit,
+            None => DisplayFit::default(),
         };
 
-        let target_offset = (output_size - target_size) / 2.0;
-
-        Coord::new(if is_height_constrained {
-            XY::new(target_offset.x, padding)
-        } else {
-            XY::new(padding, target_offset.y)
-        })
-    }
-
-    pub fn display_size(
-        options: &RenderOptions,
-        project: &ProjectConfiguration,
-        resolution_base: XY<u32>,
-    ) -> Coord<FrameSpace> {
-        let output_size = Self::get_output_size(options, project, resolution_base);
-        let output_size = XY::new(output_size.0 as f64, output_size.1 as f64);
-
-        let display_offset = Self::display_offset(options, project, resolution_base);
-
-        let end = Coord::new(output_size) - display_offset;
-
-        end - display_offset
+        fit_display(output_size, cropped_aspect, padding, &fit)
     }
 
     fn display_bounds(
         zoom: &InterpolatedZoom,
         display_offset: Coord<FrameSpace>,
         display_size: Coord<FrameSpace>,
-        output_size: XY<f64>,
     ) -> (Coord<FrameSpace>, Coord<FrameSpace>) {
-        let base_end = Coord::new(output_size) - display_offset;
-        let zoom_start = Coord::new(zoom.bounds.top_left * display_size.coord);
-        let zoom_end = Coord::new((zoom.bounds.bottom_right - 1.0) * display_size.coord);
-        let start = display_offset + zoom_start;
-        let end = base_end + zoom_end;
+        let start = display_offset + Coord::new(zoom.bounds.top_left * display_size.coord);
+        let end = display_offset + Coord::new(zoom.bounds.bottom_right * display_size.coord);
         (start, end)
     }
 
@@ -1860,6 +2177,26 @@ impl ProjectUniforms {
         ))
     }
 
+    fn zoom_click_origin(
+        project: &ProjectConfiguration,
+        cursor_events: &CursorEvents,
+        segment: &ZoomSegment,
+    ) -> Option<XY<f64>> {
+        const CLICK_SEARCH_WINDOW_SECS: f64 = 1.0;
+
+        let (start_secs, _) = project.get_segment_time(segment.start)?;
+        let distance = |time_ms: f64| (time_ms / 1000.0 - start_secs).abs();
+
+        let click = cursor_events
+            .clicks
+            .iter()
+            .filter(|click| click.down && distance(click.time_ms) <= CLICK_SEARCH_WINDOW_SECS)
+            .min_by(|a, b| distance(a.time_ms).total_cmp(&distance(b.time_ms)))?;
+
+        interpolate_cursor(cursor_events, (click.time_ms / 1000.0) as f32, None)
+            .map(|cursor| cursor.position.coord)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         constants: &RenderVideoConstants,
@@ -1876,6 +2213,27 @@ impl ProjectUniforms {
         let output_size = Self::get_output_size(options, project, resolution_base);
         let fps_f32 = fps as f32;
         let frame_time = frame_number as f32 / fps_f32;
+
+        let keyframed_project;
+        let project = if project.background_keyframes.is_empty()
+            && project.layout_keyframes.is_empty()
+        {
+            project
+        } else {
+            let mut background = background_keyframes::background_at(project, frame_time as f64)
+                .unwrap_or_else(|| project.background.clone());
+            layout_keyframes::apply_layout_keyframes(
+                &project.layout_keyframes,
+                &mut background,
+                frame_time as f64,
+            );
+
+            keyframed_project = ProjectConfiguration {
+                background,
+                ..project.clone()
+            };
+            &keyframed_project
+        };
         let prev_frame_time = if frame_number == 0 {
             0.0
         } else {
@@ -1944,14 +2302,18 @@ impl ProjectUniforms {
             .as_ref()
             .map(|c| Coord::<RawDisplayUVSpace>::new(c.position.coord));
 
+        let click_origin =
+            |segment: &ZoomSegment| Self::zoom_click_origin(project, cursor_events, segment);
+
         let zoom = InterpolatedZoom::new_with_cursor(
-            SegmentsCursor::new(frame_time as f64, zoom_segments),
+            SegmentsCursor::new(frame_time as f64, zoom_segments).with_click_origin(click_origin),
             zoom_focus,
             actual_cursor_coord,
         );
 
         let prev_zoom = InterpolatedZoom::new_with_cursor(
-            SegmentsCursor::new(prev_frame_time as f64, zoom_segments),
+            SegmentsCursor::new(prev_frame_time as f64, zoom_segments)
+                .with_click_origin(click_origin),
             prev_zoom_focus,
             prev_actual_cursor_coord,
         );
@@ -1979,10 +2341,9 @@ impl ProjectUniforms {
             let display_offset = Self::display_offset(options, project, resolution_base);
             let display_size = Self::display_size(options, project, resolution_base);
 
-            let (start, end) =
-                Self::display_bounds(&zoom, display_offset, display_size, output_size);
+            let (start, end) = Self::display_bounds(&zoom, display_offset, display_size);
             let (prev_start, prev_end) =
-                Self::display_bounds(&prev_zoom, display_offset, display_size, output_size);
+                Self::display_bounds(&prev_zoom, display_offset, display_size);
 
             let target_size = (end - start).coord;
             let min_target_axis = target_size.x.min(target_size.y);
@@ -2305,6 +2666,23 @@ impl ProjectUniforms {
                 }
             });
 
+        let magnifier = interpolated_cursor
+            .as_ref()
+            .filter(|_| project.magnifier.enabled && scene.should_render_screen())
+            .map(|cursor| {
+                let mut magnifier = magnifier_uniforms(
+                    &project.magnifier,
+                    output_size,
+                    options.screen_size,
+                    &crop,
+                    Self::display_size(options, project, resolution_base).coord,
+                    cursor.position.to_raw_display_space(options).coord,
+                );
+                magnifier.opacity = (scene.screen_opacity * (1.0 - zoom.t)) as f32;
+                magnifier
+            })
+            .filter(|magnifier| magnifier.opacity > 0.01);
+
         let masks = project
             .timeline
             .as_ref()
@@ -2321,15 +2699,42 @@ impl ProjectUniforms {
             .timeline
             .as_ref()
             .map(|timeline| {
-                prepare_texts(
-                    XY::new(output_size.0, output_size.1),
+                let output_size = XY::new(output_size.0, output_size.1);
+                let mut texts = prepare_texts(
+                    output_size,
                     frame_time as f64,
                     &timeline.text_segments,
                     &project.hidden_text_segments,
-                )
+                );
+                texts.extend(prepare_reactions(
+                    output_size,
+                    frame_time as f64,
+                    &timeline.reaction_segments,
+                ));
+                texts
             })
             .unwrap_or_default();
 
+        let cursor_opacity = project
+            .timeline
+            .as_ref()
+            .map(|timeline| cursor_segments_opacity(&timeline.cursor_segments, frame_time as f64))
+            .unwrap_or(1.0);
+
+        let confetti = prepare_confetti(
+            &project.confetti,
+            frame_time as f64,
+            display.target_bounds,
+            output_size.1 as f32,
+        );
+
+        let keystroke = prepare_keystroke(
+            &project.hotkeys,
+            &cursor_events.keys,
+            segment_frames.recording_time as f64 * 1000.0,
+            output_size,
+        );
+
         Self {
             output_size,
             cursor_size: project.cursor.size as f32,
@@ -2337,19 +2742,113 @@ impl ProjectUniforms {
             display,
             camera,
             camera_only,
+            magnifier,
             project: project.clone(),
             zoom,
             scene,
             interpolated_cursor,
+            cursor_opacity,
             frame_rate: fps,
             frame_number,
             prev_cursor: prev_interpolated_cursor,
             display_parent_motion_px: display_motion_parent,
             motion_blur_amount: cursor_motion_blur,
             masks,
+            confetti,
+            keystroke,
             texts,
+            is_card: false,
+            guides: None,
+            tile: None,
         }
     }
+
+    pub fn for_tile(&self, tile: RenderTile) -> Self {
+        let offset = tile.offset();
+        let [uv_x, uv_y, uv_width, uv_height] = tile.uv_rect();
+
+        let shift = |mut frame: CompositeVideoFrameUniforms| {
+            frame.target_bounds[0] -= offset.x;
+            frame.target_bounds[1] -= offset.y;
+            frame.target_bounds[2] -= offset.x;
+            frame.target_bounds[3] -= offset.y;
+            frame.output_size = [tile.width as f32, tile.height as f32];
+            frame
+        };
+
+        Self {
+            output_size: (tile.width, tile.height),
+            display: shift(self.display),
+            camera: self.camera.map(shift),
+            camera_only: self.camera_only.map(shift),
+            magnifier: self.magnifier.map(shift),
+            masks: self
+                .masks
+                .iter()
+                .map(|mask| PreparedMask {
+                    center: XY::new(
+                        (mask.center.x - uv_x) / uv_width,
+                        (mask.center.y - uv_y) / uv_height,
+                    ),
+                    size: XY::new(mask.size.x / uv_width, mask.size.y / uv_height),
+                    feather: mask.feather / uv_width,
+                    output_size: XY::new(tile.width, tile.height),
+                    ..mask.clone()
+                })
+                .collect(),
+            confetti: self
+                .confetti
+                .iter()
+                .map(|particle| ConfettiParticle {
+                    center: [particle.center[0] - offset.x, particle.center[1] - offset.y],
+                    ..*particle
+                })
+                .collect(),
+            keystroke: self.keystroke.clone().map(|keystroke| PreparedKeystroke {
+                anchor: [
+                    keystroke.anchor[0] - offset.x,
+                    keystroke.anchor[1] - offset.y,
+                ],
+                ..keystroke
+            }),
+            texts: self
+                .texts
+                .iter()
+                .map(|text| PreparedText {
+                    bounds: [
+                        text.bounds[0] - offset.x,
+                        text.bounds[1] - offset.y,
+                        text.bounds[2] - offset.x,
+                        text.bounds[3] - offset.y,
+                    ],
+                    ..text.clone()
+                })
+                .collect(),
+            tile: Some(tile),
+            ..self.clone()
+        }
+    }
+
+    pub fn frame_size(&self) -> (u32, u32) {
+        self.tile.map_or(self.output_size, |tile| tile.frame_size)
+    }
+
+    pub fn into_card(mut self, card: &CardConfiguration, fallback_title: &str) -> Self {
+        if let Some(background) = &card.background {
+            self.project.background.source = background.clone();
+        }
+        self.texts = text::prepare_card_texts(
+            XY::new(self.output_size.0, self.output_size.1),
+            card,
+            fallback_title,
+        );
+        self.masks.clear();
+        self.confetti.clear();
+        self.keystroke = None;
+        self.magnifier = None;
+        self.is_card = true;
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -2510,6 +3009,14 @@ impl<'a> FrameRenderer<'a> {
         cursor: &CursorEvents,
         layers: &mut RendererLayers,
     ) -> Result<Option<frame_pipeline::Nv12RenderedFrame>, RenderingError> {
+        let max_dimension = self.constants.device.limits().max_texture_dimension_2d;
+        if let Some(tiles) = tiles::split_into_tiles(uniforms.output_size, max_dimension) {
+            return self
+                .render_tiled(segment_frames, uniforms, cursor, layers, tiles)
+                .await
+                .map(Some);
+        }
+
         let mut last_error = None;
 
         for attempt in 0..Self::MAX_RENDER_RETRIES {
@@ -2543,6 +3050,7 @@ impl<'a> FrameRenderer<'a> {
                 frame_pipeline::RgbaToNv12Converter::new(&self.constants.device)
             });
 
+            let composite_start = Instant::now();
             let mut encoder = self.constants.device.create_command_encoder(
                 &(wgpu::CommandEncoderDescriptor {
                     label: Some("Render Encoder (NV12)"),
@@ -2570,6 +3078,7 @@ impl<'a> FrameRenderer<'a> {
                 session,
                 &uniforms,
             );
+            let composite = composite_start.elapsed();
 
             match finish_encoder_nv12(
                 session,
@@ -2581,7 +3090,12 @@ impl<'a> FrameRenderer<'a> {
             )
             .await
             {
-                Ok(opt_frame) => return Ok(opt_frame),
+                Ok(opt_frame) => {
+                    return Ok(opt_frame.map(|mut frame| {
+                        frame.timings.composite = composite;
+                        frame
+                    }));
+                }
                 Err(RenderingError::BufferMapWaitingFailed) => {
                     last_error = Some(RenderingError::BufferMapWaitingFailed);
                 }
@@ -2594,6 +3108,125 @@ impl<'a> FrameRenderer<'a> {
 
         Err(last_error.unwrap_or(RenderingError::BufferMapWaitingFailed))
     }
+
+    pub async fn render_to_surface(
+        &mut self,
+        segment_frames: DecodedSegmentFrames,
+        uniforms: ProjectUniforms,
+        cursor: &CursorEvents,
+        layers: &mut RendererLayers,
+        surface: &PreviewSurface,
+    ) -> Result<(), RenderingError> {
+        let max_dimension = self.constants.device.limits().max_texture_dimension_2d;
+        if tiles::split_into_tiles(uniforms.output_size, max_dimension).is_some() {
+            return Err(RenderingError::FrameTooLarge);
+        }
+
+        let session = self.session.get_or_insert_with(|| {
+            RenderSession::new(
+                &self.constants.device,
+                uniforms.output_size.0,
+                uniforms.output_size.1,
+            )
+        });
+
+        session.update_texture_size(
+            &self.constants.device,
+            uniforms.output_size.0,
+            uniforms.output_size.1,
+        );
+
+        let mut encoder = self.constants.device.create_command_encoder(
+            &(wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder (Surface)"),
+            }),
+        );
+
+        layers
+            .prepare_with_encoder(
+                self.constants,
+                &uniforms,
+                &segment_frames,
+                cursor,
+                &mut encoder,
+            )
+            .await?;
+
+        layers.render(
+            &self.constants.device,
+            &self.constants.queue,
+            &mut encoder,
+            session,
+            &uniforms,
+        );
+
+        surface.present(
+            self.constants,
+            encoder,
+            session.current_texture_view(),
+            uniforms.output_size,
+        )
+    }
+
+    async fn render_tiled(
+        &mut self,
+        segment_frames: DecodedSegmentFrames,
+        uniforms: ProjectUniforms,
+        cursor: &CursorEvents,
+        layers: &mut RendererLayers,
+        tiles: Vec<RenderTile>,
+    ) -> Result<frame_pipeline::Nv12RenderedFrame, RenderingError> {
+        let device = &self.constants.device;
+        let (width, height) = uniforms.output_size;
+        let stride = width as usize * 4;
+        let mut data = vec![0u8; stride * height as usize];
+        let mut timings = FrameTimings::default();
+
+        for tile in tiles {
+            let session = self
+                .session
+                .get_or_insert_with(|| RenderSession::new(device, tile.width, tile.height));
+            session.update_texture_size(device, tile.width, tile.height);
+
+            let composite_start = Instant::now();
+            produce_frame(
+                self.constants,
+                segment_frames.clone(),
+                uniforms.for_tile(tile),
+                cursor,
+                layers,
+                session,
+            )
+            .await?;
+            timings.composite += composite_start.elapsed();
+
+            let readback_start = Instant::now();
+            let frame = flush_pending_readback(session, device)
+                .await
+                .unwrap_or(Err(RenderingError::BufferMapWaitingFailed))?;
+            timings.readback += readback_start.elapsed();
+
+            tiles::stitch_tile(
+                &mut data,
+                stride,
+                &tile,
+                &frame.data,
+                frame.padded_bytes_per_row as usize,
+            );
+        }
+
+        Ok(frame_pipeline::Nv12RenderedFrame {
+            data: Arc::new(data),
+            width,
+            height,
+            y_stride: stride as u32,
+            frame_number: uniforms.frame_number,
+            target_time_ns: (uniforms.frame_number as u64 * 1_000_000_000)
+                / uniforms.frame_rate.max(1) as u64,
+            format: GpuOutputFormat::Rgba,
+            timings,
+        })
+    }
 }
 
 pub struct RendererLayers {
@@ -2604,8 +3237,14 @@ pub struct RendererLayers {
     camera: CameraLayer,
     camera_only: CameraLayer,
     mask: MaskLayer,
+    confetti: ConfettiLayer,
+    text_system: TextSystem,
     text: TextLayer,
     captions: CaptionsLayer,
+    keystrokes: KeystrokesLayer,
+    effects: EffectsLayer,
+    watermark: WatermarkLayer,
+    guides: GuidesLayer,
 }
 
 impl RendererLayers {
@@ -2621,6 +3260,7 @@ impl RendererLayers {
         let shared_yuv_pipelines = Arc::new(yuv_converter::YuvConverterPipelines::new(device));
         let shared_composite_pipeline =
             Arc::new(composite_frame::CompositeVideoFramePipeline::new(device));
+        let mut text_system = TextSystem::new(device, queue);
 
         Self {
             background: BackgroundLayer::new(device),
@@ -2643,8 +3283,14 @@ impl RendererLayers {
                 shared_composite_pipeline,
             ),
             mask: MaskLayer::new(device),
-            text: TextLayer::new(device, queue),
-            captions: CaptionsLayer::new(device, queue),
+            confetti: ConfettiLayer::new(device),
+            text: TextLayer::new(device, &mut text_system),
+            captions: CaptionsLayer::new(device, &mut text_system),
+            keystrokes: KeystrokesLayer::new(device, &mut text_system),
+            text_system,
+            effects: EffectsLayer::new(device),
+            watermark: WatermarkLayer::new(device),
+            guides: GuidesLayer::new(device),
         }
     }
 
@@ -2698,6 +3344,8 @@ impl RendererLayers {
             constants.options.screen_size,
             uniforms.display,
         );
+        self.display
+            .prepare_magnifier(&constants.device, &constants.queue, uniforms.magnifier);
 
         self.cursor.prepare(
             segment_frames,
@@ -2732,18 +3380,35 @@ impl RendererLayers {
             }),
         );
 
+        self.text_system.load_fonts(&constants.brand.fonts);
+        self.text_system
+            .update_viewport(&constants.queue, uniforms.output_size);
         self.text.prepare(
             &constants.device,
             &constants.queue,
-            uniforms.output_size,
+            &mut self.text_system,
             &uniforms.texts,
         );
 
+        self.confetti.prepare(&constants.device, uniforms);
+        self.effects.prepare(constants, uniforms);
+        self.watermark.prepare(constants, uniforms);
+
+        let (frame_width, frame_height) = uniforms.frame_size();
         self.captions.prepare(
             uniforms,
             segment_frames,
-            XY::new(uniforms.output_size.0, uniforms.output_size.1),
+            XY::new(frame_width, frame_height),
             constants,
+            &mut self.text_system,
+        );
+
+        self.keystrokes.prepare(
+            &constants.device,
+            &constants.queue,
+            &mut self.text_system,
+            uniforms.keystroke.as_ref(),
+            uniforms.output_size,
         );
 
         Ok(())
@@ -2777,6 +3442,8 @@ impl RendererLayers {
             uniforms.display,
             encoder,
         );
+        self.display
+            .prepare_magnifier(&constants.device, &constants.queue, uniforms.magnifier);
 
         self.cursor.prepare(
             segment_frames,
@@ -2813,18 +3480,35 @@ impl RendererLayers {
             encoder,
         );
 
+        self.text_system.load_fonts(&constants.brand.fonts);
+        self.text_system
+            .update_viewport(&constants.queue, uniforms.output_size);
         self.text.prepare(
             &constants.device,
             &constants.queue,
-            uniforms.output_size,
+            &mut self.text_system,
             &uniforms.texts,
         );
 
+        self.confetti.prepare(&constants.device, uniforms);
+        self.effects.prepare(constants, uniforms);
+        self.watermark.prepare(constants, uniforms);
+
+        let (frame_width, frame_height) = uniforms.frame_size();
         self.captions.prepare(
             uniforms,
             segment_frames,
-            XY::new(uniforms.output_size.0, uniforms.output_size.1),
+            XY::new(frame_width, frame_height),
             constants,
+            &mut self.text_system,
+        );
+
+        self.keystrokes.prepare(
+            &constants.device,
+            &constants.queue,
+            &mut self.text_system,
+            uniforms.keystroke.as_ref(),
+            uniforms.output_size,
         );
 
         Ok(())
@@ -2877,7 +3561,7 @@ impl RendererLayers {
             session.swap_textures();
         }
 
-        let should_render = uniforms.scene.should_render_screen();
+        let should_render = uniforms.scene.should_render_screen() && !uniforms.is_card;
 
         if should_render {
             let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
@@ -2889,8 +3573,14 @@ impl RendererLayers {
             self.cursor.render(&mut pass);
         }
 
+        if should_render && self.display.has_magnifier() {
+            let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
+            self.display.render_magnifier(&mut pass);
+            self.cursor.render_magnified(&mut pass);
+        }
+
         // Render camera-only layer when transitioning with CameraOnly mode
-        if uniforms.scene.is_transitioning_camera_only() {
+        if uniforms.scene.is_transitioning_camera_only() && !uniforms.is_card {
             let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
             self.camera_only.render(&mut pass);
         }
@@ -2898,6 +3588,7 @@ impl RendererLayers {
         // Also render regular camera overlay during transitions when its opacity > 0
         if uniforms.scene.should_render_camera()
             && uniforms.scene.regular_camera_transition_opacity() > 0.01
+            && !uniforms.is_card
         {
             let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
             self.camera.render(&mut pass);
@@ -2909,15 +3600,41 @@ impl RendererLayers {
             }
         }
 
+        if self.confetti.has_content() {
+            let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
+            self.confetti.render(&mut pass);
+        }
+
         if !uniforms.texts.is_empty() {
             let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
-            self.text.render(&mut pass);
+            self.text.render(&self.text_system, &mut pass);
+        }
+
+        if self.captions.has_content() && !uniforms.is_card {
+            let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
+            self.captions.render(&self.text_system, &mut pass);
+        }
+
+        if self.keystrokes.has_content() {
+            let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
+            self.keystrokes.render(&self.text_system, &mut pass);
+        }
+
+        if self.effects.has_content() {
+            self.effects.render(device, session, encoder);
         }
 
-        if self.captions.has_content() {
+        if self.watermark.has_content() {
             let mut pass = render_pass!(session.current_texture_view(), wgpu::LoadOp::Load);
-            self.captions.render(&mut pass);
+            self.watermark.render(&mut pass);
+        }
+
+        if let Some(guides) = uniforms.guides.filter(PreviewGuides::is_visible) {
+            self.guides
+                .render(device, session, encoder, &guides, uniforms.output_size);
         }
+
+        self.text_system.trim();
     }
 }
 
@@ -3059,6 +3776,9 @@ mod project_uniforms_tests {
     #[test]
     fn auto_zoom_focus_defaults_without_cursor_data() {
         let events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             clicks: vec![],
             moves: vec![],
         };
@@ -3072,6 +3792,9 @@ mod project_uniforms_tests {
     #[test]
     fn auto_zoom_focus_is_stable_for_slow_motion() {
         let events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             clicks: vec![],
             moves: vec![
                 cursor_move(0.0, 0.5, 0.5),
@@ -3096,6 +3819,9 @@ mod project_uniforms_tests {
     #[test]
     fn auto_zoom_focus_leans_into_velocity_for_fast_motion() {
         let events = CursorEvents {
+            key_presses: vec![],
+            keys: vec![],
+            focus_changes: vec![],
             clicks: vec![],
             moves: vec![cursor_move(0.0, 0.1, 0.5), cursor_move(40.0, 0.9, 0.5)],
         };
@@ -3110,4 +3836,109 @@ mod project_uniforms_tests {
         assert!(delta < 0.2, "focus moved too far ahead: {delta}");
         assert!(delta > -0.25, "focus lagged too far behind: {delta}");
     }
+
+    fn fit(mode: FitMode, anchor: (f64, f64)) -> DisplayFit {
+        DisplayFit {
+            mode,
+            anchor: anchor.into(),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{actual} isn't close to {expected}"
+        );
+    }
+
+    #[test]
+    fn contain_letterboxes_inside_odd_frames() {
+        let output = XY::new(1081.0, 1921.0);
+        let aspect = 1366.0 / 767.0;
+        let (offset, size) = fit_display(output, aspect, 0.0, &DisplayFit::default());
+
+        assert_close(size.x / size.y, aspect);
+        assert_close(size.x, output.x);
+        assert_close(offset.x, 0.0);
+        assert_close(offset.y * 2.0 + size.y, output.y);
+
+        let (offset, size) = fit_display(
+            XY::new(1279.0, 719.0),
+            4.0 / 3.0,
+            12.5,
+            &DisplayFit::default(),
+        );
+        assert_close(offset.y, 12.5);
+        assert_close(size.y, 694.0);
+        assert_close(offset.x * 2.0 + size.x, 1279.0);
+    }
+
+    #[test]
+    fn cover_fills_odd_frames() {
+        for (output, aspect) in [
+            (XY::new(1081.0, 1921.0), 1366.0 / 767.0),
+            (XY::new(1279.0, 719.0), 3.0 / 4.0),
+            (XY::new(999.0, 999.0), 1.0),
+        ] {
+            let (offset, size) =
+                fit_display(output, aspect, 30.0, &fit(FitMode::Cover, (0.5, 0.5)));
+
+            assert_close(size.x / size.y, aspect);
+            assert!(offset.x <= 0.0 && offset.y <= 0.0, "{offset:?}");
+            assert_close(offset.x * 2.0 + size.x, output.x);
+            assert_close(offset.y * 2.0 + size.y, output.y);
+            assert!(
+                (size.x - output.x).abs() < 1e-6 || (size.y - output.y).abs() < 1e-6,
+                "{size:?} doesn't fill {output:?} along either axis"
+            );
+        }
+    }
+
+    #[test]
+    fn anchors_move_the_display_along_the_free_axis() {
+        let output = XY::new(1081.0, 1921.0);
+        let aspect = 16.0 / 9.0;
+
+        let (top, _) = fit_display(output, aspect, 0.0, &fit(FitMode::Contain, (0.5, 0.0)));
+        assert_close(top.y, 0.0);
+        let (bottom, size) = fit_display(output, aspect, 0.0, &fit(FitMode::Contain, (0.5, 1.0)));
+        assert_close(bottom.y + size.y, output.y);
+
+        let (left, _) = fit_display(output, aspect, 0.0, &fit(FitMode::Cover, (0.0, 0.5)));
+        assert_close(left.x, 0.0);
+        let (right, size) = fit_display(output, aspect, 0.0, &fit(FitMode::Cover, (1.0, 0.5)));
+        assert_close(right.x + size.x, output.x);
+
+        let (clamped, _) = fit_display(output, aspect, 0.0, &fit(FitMode::Cover, (-2.0, 0.5)));
+        assert_eq!(clamped, left);
+    }
+
+    #[test]
+    fn cover_maps_the_recording_past_the_frame_edges() {
+        let options = RenderOptions {
+            camera_size: None,
+            screen_size: XY::new(1366, 767),
+        };
+        let mut project = ProjectConfiguration::default();
+        project.aspect_ratio = Some(AspectRatio::Vertical);
+        project.background.fit = fit(FitMode::Cover, (0.5, 0.5));
+        let resolution_base = XY::new(1080, 1920);
+
+        let (width, height) = ProjectUniforms::get_output_size(&options, &project, resolution_base);
+        let offset = ProjectUniforms::display_offset(&options, &project, resolution_base);
+        let size = ProjectUniforms::display_size(&options, &project, resolution_base);
+
+        assert!(offset.x < 0.0, "{offset:?}");
+        assert_close(offset.y, 0.0);
+        assert_close(size.y, height as f64);
+        assert_close(offset.x * 2.0 + size.x, width as f64);
+
+        let corner = Coord::<CroppedDisplaySpace>::new(XY::new(1366.0, 767.0)).to_frame_space(
+            &options,
+            &project,
+            resolution_base,
+        );
+        assert_close(corner.x, offset.x + size.x);
+        assert_close(corner.y, offset.y + size.y);
+    }
 }
@@ -0,0 +1,133 @@
+use cap_project::{CameraXPosition, CameraYPosition, Crop, MagnifierConfiguration, XY};
+
+use crate::composite_frame::CompositeVideoFrameUniforms;
+
+const PADDING: f32 = 50.0;
+const BORDER_WIDTH: f32 = 4.0;
+
+pub(crate) fn magnifier_uniforms(
+    config: &MagnifierConfiguration,
+    output_size: (u32, u32),
+    screen_size: XY<u32>,
+    crop: &Crop,
+    display_size: XY<f64>,
+    cursor: XY<f64>,
+) -> CompositeVideoFrameUniforms {
+    let output_size = [output_size.0 as f32, output_size.1 as f32];
+    let resolution_scale = output_size[1] / 1080.0;
+    let padding = PADDING * resolution_scale;
+
+    let crop_position = XY::new(crop.position.x as f64, crop.position.y as f64);
+    let crop_size = XY::new(crop.size.x as f64, crop.size.y as f64);
+
+    let width = output_size[0].min(output_size[1]) * (config.size / 100.0) as f32;
+    let size = [width, width * (crop_size.y / crop_size.x) as f32];
+
+    let x = match config.position.x {
+        CameraXPosition::Left => padding,
+        CameraXPosition::Center => (output_size[0] - size[0]) / 2.0,
+        CameraXPosition::Right => output_size[0] - padding - size[0],
+    };
+    let y = match config.position.y {
+        CameraYPosition::Top => padding,
+        CameraYPosition::Bottom => output_size[1] - padding - size[1],
+    };
+    let target_bounds = [x, y, x + size[0], y + size[1]];
+
+    let source_scale = crop_size.x / display_size.x.max(1.0) / config.amount.max(1.0);
+    let half = XY::new(
+        (size[0] as f64 * source_scale / 2.0).min(crop_size.x / 2.0),
+        (size[1] as f64 * source_scale / 2.0).min(crop_size.y / 2.0),
+    );
+    let center = XY::new(
+        cursor.x.clamp(
+            crop_position.x + half.x,
+            crop_position.x + crop_size.x - half.x,
+        ),
+        cursor.y.clamp(
+            crop_position.y + half.y,
+            crop_position.y + crop_size.y - half.y,
+        ),
+    );
+
+    CompositeVideoFrameUniforms {
+        output_size,
+        frame_size: [screen_size.x as f32, screen_size.y as f32],
+        crop_bounds: [
+            (center.x - half.x) as f32,
+            (center.y - half.y) as f32,
+            (center.x + half.x) as f32,
+            (center.y + half.y) as f32,
+        ],
+        target_bounds,
+        target_size: size,
+        rounding_px: 0.08 * size[0].min(size[1]),
+        shadow: 60.0,
+        shadow_size: 50.0,
+        shadow_opacity: 18.0,
+        shadow_blur: 50.0,
+        border_enabled: 1.0,
+        border_width: BORDER_WIDTH * resolution_scale,
+        border_color: [1.0, 1.0, 1.0, 0.9],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cap_project::CameraPosition;
+
+    const SCREEN: XY<u32> = XY::new(2000, 1000);
+
+    fn config(x: CameraXPosition, y: CameraYPosition) -> MagnifierConfiguration {
+        MagnifierConfiguration {
+            enabled: true,
+            amount: 2.0,
+            size: 40.0,
+            position: CameraPosition { x, y },
+        }
+    }
+
+    fn uncropped() -> Crop {
+        Crop {
+            position: XY::new(0, 0),
+            size: SCREEN,
+        }
+    }
+
+    #[test]
+    fn magnifies_around_the_cursor() {
+        let uniforms = magnifier_uniforms(
+            &config(CameraXPosition::Right, CameraYPosition::Top),
+            (1920, 1080),
+            SCREEN,
+            &uncropped(),
+            XY::new(1000.0, 500.0),
+            XY::new(1000.0, 500.0),
+        );
+
+        assert_eq!(uniforms.target_bounds, [1438.0, 50.0, 1870.0, 266.0]);
+        assert_eq!(uniforms.crop_bounds, [784.0, 392.0, 1216.0, 608.0]);
+    }
+
+    #[test]
+    fn stays_within_the_crop() {
+        let crop = Crop {
+            position: XY::new(100, 100),
+            size: XY::new(1000, 500),
+        };
+
+        let uniforms = magnifier_uniforms(
+            &config(CameraXPosition::Left, CameraYPosition::Bottom),
+            (1920, 1080),
+            SCREEN,
+            &crop,
+            XY::new(1000.0, 500.0),
+            XY::new(50.0, 900.0),
+        );
+
+        assert_eq!(uniforms.target_bounds, [50.0, 814.0, 482.0, 1030.0]);
+        assert_eq!(uniforms.crop_bounds, [100.0, 492.0, 316.0, 600.0]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::tiles::MAX_TILES_PER_AXIS;
+
+const BYTES_PER_OUTPUT_PIXEL: u64 = 48;
+
+const SHARED_GPU_MEMORY_FRACTION: u64 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderBudget {
+    pub max_texture_dimension: u32,
+    pub max_buffer_size: u64,
+    pub memory_bytes: Option<u64>,
+}
+
+impl RenderBudget {
+    pub fn for_device(adapter: &wgpu::Adapter, device: &wgpu::Device) -> Self {
+        let limits = device.limits();
+        let info = adapter.get_info();
+
+        Self {
+            max_texture_dimension: limits.max_texture_dimension_2d,
+            max_buffer_size: limits.max_buffer_size,
+            memory_bytes: shares_system_memory(&info).then(|| {
+                let mut system = sysinfo::System::new();
+                system.refresh_memory();
+                system.available_memory() / SHARED_GPU_MEMORY_FRACTION
+            }),
+        }
+    }
+
+    pub fn scale_for(&self, (width, height): (u32, u32)) -> f64 {
+        let width = f64::from(width.max(1));
+        let height = f64::from(height.max(1));
+
+        let mut max_pixels = self.max_buffer_size as f64 / 4.0;
+        if let Some(memory_bytes) = self.memory_bytes {
+            max_pixels = max_pixels.min((memory_bytes / BYTES_PER_OUTPUT_PIXEL) as f64);
+        }
+
+        let max_dimension = self
+            .max_texture_dimension
+            .saturating_mul(MAX_TILES_PER_AXIS);
+
+        (f64::from(max_dimension) / width.max(height))
+            .min((max_pixels / (width * height)).sqrt())
+            .min(1.0)
+    }
+}
+
+fn shares_system_memory(info: &wgpu::AdapterInfo) -> bool {
+    info.device_type == wgpu::DeviceType::IntegratedGpu && info.backend != wgpu::Backend::Metal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DISCRETE: RenderBudget = RenderBudget {
+        max_texture_dimension: 8192,
+        max_buffer_size: 1 << 30,
+        memory_bytes: None,
+    };
+
+    #[test]
+    fn discrete_gpus_composite_4k_as_is() {
+        assert_eq!(DISCRETE.scale_for((3840, 2160)), 1.0);
+    }
+
+    #[test]
+    fn integrated_gpus_composite_4k_with_enough_free_memory() {
+        let budget = RenderBudget {
+            memory_bytes: Some((8 << 30) / SHARED_GPU_MEMORY_FRACTION),
+            ..DISCRETE
+        };
+
+        assert_eq!(budget.scale_for((3840, 2160)), 1.0);
+    }
+
+    #[test]
+    fn integrated_gpus_scale_4k_when_short_of_memory() {
+        let memory_bytes = 256 * 1024 * 1024;
+        let budget = RenderBudget {
+            memory_bytes: Some(memory_bytes),
+            ..DISCRETE
+        };
+
+        let scale = budget.scale_for((3840, 2160));
+        let pixels = (3840.0 * scale) * (2160.0 * scale);
+
+        assert!(scale < 1.0);
+        assert!(pixels * BYTES_PER_OUTPUT_PIXEL as f64 <= memory_bytes as f64);
+        assert_eq!(budget.scale_for((1920, 1080)), 1.0);
+    }
+
+    #[test]
+    fn unified_memory_adapters_are_not_limited() {
+        let adapter = |device_type, backend| wgpu::AdapterInfo {
+            name: String::new(),
+            vendor: 0,
+            device: 0,
+            device_type,
+            driver: String::new(),
+            driver_info: String::new(),
+            backend,
+        };
+
+        assert!(!shares_system_memory(&adapter(
+            wgpu::DeviceType::IntegratedGpu,
+            wgpu::Backend::Metal
+        )));
+        assert!(shares_system_memory(&adapter(
+            wgpu::DeviceType::IntegratedGpu,
+            wgpu::Backend::Dx12
+        )));
+        assert!(!shares_system_memory(&adapter(
+            wgpu::DeviceType::DiscreteGpu,
+            wgpu::Backend::Vulkan
+        )));
+    }
+
+    #[test]
+    fn frames_fit_the_tiled_texture_limit() {
+        let budget = RenderBudget {
+            max_texture_dimension: 4096,
+            ..DISCRETE
+        };
+
+        assert_eq!(budget.scale_for((8192, 2048)), 1.0);
+        assert_eq!(budget.scale_for((16384, 2048)), 0.5);
+    }
+}
@@ -49,7 +49,7 @@ impl<'a> SceneSegmentsCursor<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct InterpolatedScene {
     pub camera_opacity: f64,
     pub screen_opacity: f64,
@@ -0,0 +1,47 @@
+struct Uniforms {
+    output_size: vec2<f32>,
+    _padding: vec2<f32>,
+};
+
+struct Particle {
+    @location(0) center: vec2<f32>,
+    @location(1) size: vec2<f32>,
+    @location(2) rotation: f32,
+    @location(3) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, particle: Particle) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-0.5, -0.5),
+        vec2<f32>(0.5, -0.5),
+        vec2<f32>(-0.5, 0.5),
+        vec2<f32>(-0.5, 0.5),
+        vec2<f32>(0.5, -0.5),
+        vec2<f32>(0.5, 0.5)
+    );
+
+    let corner = corners[vertex_index] * particle.size;
+    let c = cos(particle.rotation);
+    let s = sin(particle.rotation);
+    let rotated = vec2<f32>(corner.x * c - corner.y * s, corner.x * s + corner.y * c);
+
+    let clip_pos = ((particle.center + rotated) / uniforms.output_size) * 2.0 - 1.0;
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(clip_pos.x, -clip_pos.y, 0.0, 1.0);
+    out.color = particle.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.color.rgb * in.color.a, in.color.a);
+}
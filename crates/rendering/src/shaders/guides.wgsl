@@ -0,0 +1,85 @@
+struct Uniforms {
+    output_size: vec2<f32>,
+    crop_size: vec2<f32>,
+    safe_area_inset: vec2<f32>,
+    line_width: f32,
+    grid_divisions: u32,
+    flags: u32,
+    padding0: u32,
+    padding1: vec2<u32>,
+}
+
+const SAFE_AREA_FLAG: u32 = 1u;
+const CROP_FLAG: u32 = 2u;
+
+const LINE_COLOR: vec3<f32> = vec3<f32>(1.0, 1.0, 1.0);
+const LINE_OPACITY: f32 = 0.6;
+const CROP_DIMMING: f32 = 0.55;
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var source_texture: texture_2d<f32>;
+@group(0) @binding(2) var source_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var positions = array<vec2<f32>, 3>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(3.0, -1.0),
+        vec2<f32>(-1.0, 3.0),
+    );
+
+    let pos = positions[vertex_index];
+    var out: VertexOutput;
+    out.position = vec4<f32>(pos, 0.0, 1.0);
+    out.uv = vec2<f32>(pos.x * 0.5 + 0.5, 1.0 - (pos.y * 0.5 + 0.5));
+    return out;
+}
+
+fn rect_outline(px: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    let delta = abs(px - uniforms.output_size * 0.5) - half_size;
+    let dist = abs(max(delta.x, delta.y));
+    return 1.0 - smoothstep(uniforms.line_width * 0.5, uniforms.line_width * 0.5 + 1.0, dist);
+}
+
+fn grid_lines(px: vec2<f32>) -> f32 {
+    let divisions = f32(uniforms.grid_divisions);
+    let cell = uniforms.output_size / divisions;
+    let nearest = clamp(round(px / cell), vec2<f32>(1.0), vec2<f32>(divisions - 1.0));
+    let offset = abs(px - nearest * cell);
+    let dist = min(offset.x, offset.y);
+    return 1.0 - smoothstep(uniforms.line_width * 0.5, uniforms.line_width * 0.5 + 1.0, dist);
+}
+
+@fragment
+fn fs_main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    let base = textureSample(source_texture, source_sampler, uv);
+    let px = uv * uniforms.output_size;
+    var color = base.rgb;
+    var line = 0.0;
+
+    if (uniforms.flags & CROP_FLAG) != 0u {
+        let half_crop = uniforms.crop_size * 0.5;
+        let delta = abs(px - uniforms.output_size * 0.5) - half_crop;
+        if max(delta.x, delta.y) > 0.0 {
+            color = color * (1.0 - CROP_DIMMING);
+        }
+        line = max(line, rect_outline(px, half_crop));
+    }
+
+    if (uniforms.flags & SAFE_AREA_FLAG) != 0u {
+        let half_safe = uniforms.output_size * 0.5 - uniforms.safe_area_inset;
+        line = max(line, rect_outline(px, half_safe));
+    }
+
+    if uniforms.grid_divisions > 1u {
+        line = max(line, grid_lines(px));
+    }
+
+    color = mix(color, LINE_COLOR, line * LINE_OPACITY);
+    return vec4<f32>(color, base.a);
+}
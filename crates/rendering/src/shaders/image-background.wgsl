@@ -3,7 +3,9 @@ struct Uniforms {
     padding: f32,
     x_width: f32,
     y_height: f32,
-    _padding: f32,  // Matches the Rust struct alignment padding
+    dim: f32,
+    _padding2: vec2<f32>,
+    uv_rect: vec4<f32>,
 };
 
 @group(0) @binding(0) var<uniform> u: Uniforms;
@@ -20,7 +22,8 @@ fn vs_main(@builtin(vertex_index) in_vertex_index: u32) -> VertexOutput {
     var out: VertexOutput;
     let x = f32(i32(in_vertex_index & 1u) * 4 - 1);
     let y = f32(i32(in_vertex_index & 2u) * 2 - 1);
-    out.tex_coords = vec2<f32>(x * 0.5 + 0.5, 1.0 - (y * 0.5 + 0.5));
+    let uv = vec2<f32>(x * 0.5 + 0.5, 1.0 - (y * 0.5 + 0.5));
+    out.tex_coords = u.uv_rect.xy + uv * u.uv_rect.zw;
     out.clip_position = vec4<f32>(x, y, 0.0, 1.0);
     return out;
 }
@@ -30,7 +33,7 @@ fn fs_main(@location(0) tex_coords: vec2<f32>) -> @location(0) vec4<f32> {
     let x_width = (0.5 - u.x_width) * 2.0;
     let y_height = (0.5 - u.y_height) * 2.0;
 
-    return textureSample(
+    let color = textureSample(
     	t_image,
      	s_image,
       vec2<f32>(
@@ -38,4 +41,12 @@ fn fs_main(@location(0) tex_coords: vec2<f32>) -> @location(0) vec4<f32> {
         u.y_height + y_height * tex_coords.y
       )
     );
+
+    return apply_dim(color, u.dim);
+}
+
+fn apply_dim(color: vec4<f32>, dim: f32) -> vec4<f32> {
+    let luma = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let desaturated = mix(color.rgb, vec3<f32>(luma), dim * 0.6);
+    return vec4<f32>(desaturated * (1.0 - dim * 0.45), color.a);
 }
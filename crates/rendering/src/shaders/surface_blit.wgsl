@@ -0,0 +1,41 @@
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+struct Uniforms {
+    surface_size: vec2<f32>,
+    frame_position: vec2<f32>,
+    frame_size: vec2<f32>,
+    _padding: vec2<f32>,
+};
+
+@group(0) @binding(0) var frame_texture: texture_2d<f32>;
+@group(0) @binding(1) var frame_sampler: sampler;
+@group(0) @binding(2) var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var positions = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0)
+    );
+
+    let uv = positions[vertex_index];
+    let screen_pos = uniforms.frame_position + uv * uniforms.frame_size;
+    let clip_pos = (screen_pos / uniforms.surface_size) * 2.0 - 1.0;
+
+    var out: VertexOutput;
+    out.position = vec4<f32>(clip_pos.x, -clip_pos.y, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(frame_texture, frame_sampler, in.uv);
+}
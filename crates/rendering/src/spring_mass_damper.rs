@@ -1,6 +1,8 @@
 use cap_project::XY;
+use serde::{Deserialize, Serialize};
+use specta::Type;
 
-#[derive(Clone, Copy)]
+#[derive(Deserialize, Type, Clone, Copy, Debug)]
 pub struct SpringMassDamperSimulationConfig {
     pub tension: f32,
     pub mass: f32,
@@ -19,7 +21,27 @@ pub struct SpringMassDamperSimulation {
 const REST_VELOCITY_THRESHOLD: f32 = 0.0001;
 const REST_DISPLACEMENT_THRESHOLD: f32 = 0.00001;
 
-fn solve_spring_1d(displacement: f32, velocity: f32, t: f32, omega0: f32, zeta: f32) -> (f32, f32) {
+const MIN_MASS: f32 = 0.001;
+const MAX_MASS: f32 = 1_000_000.0;
+const MIN_TENSION: f32 = 0.001;
+const MAX_TENSION: f32 = 1_000_000.0;
+const MAX_FRICTION: f32 = 1_000_000.0;
+
+fn clamp_finite(value: f32, min: f32, max: f32, fallback: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(min, max)
+    } else {
+        fallback
+    }
+}
+
+pub(crate) fn solve_spring_1d(
+    displacement: f32,
+    velocity: f32,
+    t: f32,
+    omega0: f32,
+    zeta: f32,
+) -> (f32, f32) {
     const CRITICAL_EPSILON: f32 = 0.01;
 
     if zeta < 1.0 - CRITICAL_EPSILON {
@@ -39,7 +61,7 @@ fn solve_spring_1d(displacement: f32, velocity: f32, t: f32, omega0: f32, zeta:
         (new_disp, new_vel)
     } else if zeta > 1.0 + CRITICAL_EPSILON {
         let sqrt_term = (zeta * zeta - 1.0).sqrt();
-        let s1 = -omega0 * (zeta - sqrt_term);
+        let s1 = -omega0 / (zeta + sqrt_term);
         let s2 = -omega0 * (zeta + sqrt_term);
         let denom = s1 - s2;
 
@@ -109,9 +131,9 @@ impl SpringMassDamperSimulation {
         }
 
         let t = dt_ms / 1000.0;
-        let mass = self.mass.max(0.001);
-        let stiffness = self.tension;
-        let damping = self.friction;
+        let mass = clamp_finite(self.mass, MIN_MASS, MAX_MASS, 1.0);
+        let stiffness = clamp_finite(self.tension, MIN_TENSION, MAX_TENSION, MIN_TENSION);
+        let damping = clamp_finite(self.friction, 0.0, MAX_FRICTION, 0.0);
 
         let omega0 = (stiffness / mass).sqrt();
         let zeta = damping / (2.0 * (stiffness * mass).sqrt());
@@ -139,3 +161,181 @@ impl SpringMassDamperSimulation {
         self.position
     }
 }
+
+#[derive(Deserialize, Type, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SpringScenario {
+    Step,
+    Ramp,
+    Jitter,
+}
+
+#[derive(Serialize, Type, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpringSample {
+    pub time_ms: f32,
+    pub target: f32,
+    pub position: f32,
+}
+
+const SIMULATION_DURATION_MS: f32 = 1500.0;
+const SIMULATION_STEP_MS: f32 = 1000.0 / 60.0;
+const RAMP_DURATION_MS: f32 = 400.0;
+const JITTER_INTERVAL_MS: f32 = 60.0;
+const JITTER_OFFSETS: [f32; 8] = [0.08, -0.06, 0.1, -0.09, 0.05, -0.1, 0.07, -0.04];
+
+impl SpringScenario {
+    fn target_at(self, time_ms: f32) -> f32 {
+        match self {
+            Self::Step => 1.0,
+            Self::Ramp => (time_ms / RAMP_DURATION_MS).min(1.0),
+            Self::Jitter => {
+                let index = (time_ms / JITTER_INTERVAL_MS) as usize;
+                0.5 + JITTER_OFFSETS.get(index).copied().unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+pub fn simulate_spring(
+    config: SpringMassDamperSimulationConfig,
+    scenario: SpringScenario,
+) -> Vec<SpringSample> {
+    let mut spring = SpringMassDamperSimulation::new(config);
+    let mut samples = vec![SpringSample {
+        time_ms: 0.0,
+        target: scenario.target_at(0.0),
+        position: 0.0,
+    }];
+
+    let steps = (SIMULATION_DURATION_MS / SIMULATION_STEP_MS).round() as usize;
+    for step in 0..steps {
+        let time_ms = step as f32 * SIMULATION_STEP_MS;
+        let target = scenario.target_at(time_ms);
+        spring.set_target_position(XY::new(target, 0.0));
+        let position = spring.run(SIMULATION_STEP_MS);
+
+        samples.push(SpringSample {
+            time_ms: time_ms + SIMULATION_STEP_MS,
+            target: scenario.target_at(time_ms + SIMULATION_STEP_MS),
+            position: position.x,
+        });
+    }
+
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn energy(displacement: f32, velocity: f32, omega0: f32) -> f32 {
+        velocity * velocity + omega0 * omega0 * displacement * displacement
+    }
+
+    fn decay_rate(omega0: f32, zeta: f32) -> f32 {
+        if zeta < 1.0 {
+            zeta * omega0
+        } else {
+            omega0 / (zeta + (zeta * zeta - 1.0).max(0.0).sqrt())
+        }
+    }
+
+    #[test]
+    fn simulated_step_settles_on_the_target() {
+        let samples = simulate_spring(
+            SpringMassDamperSimulationConfig {
+                tension: 170.0,
+                mass: 1.0,
+                friction: 26.0,
+            },
+            SpringScenario::Step,
+        );
+
+        assert_eq!(samples.first().map(|s| s.position), Some(0.0));
+        assert!(samples.windows(2).all(|w| w[1].time_ms > w[0].time_ms));
+        let last = samples.last().unwrap();
+        assert!((last.position - last.target).abs() < 1e-3);
+    }
+
+    #[test]
+    fn simulated_ramp_lags_behind_the_target() {
+        let samples = simulate_spring(
+            SpringMassDamperSimulationConfig {
+                tension: 65.0,
+                mass: 1.8,
+                friction: 16.0,
+            },
+            SpringScenario::Ramp,
+        );
+
+        let midway = samples
+            .iter()
+            .find(|s| s.time_ms >= RAMP_DURATION_MS / 2.0)
+            .unwrap();
+        assert!(midway.position < midway.target);
+    }
+
+    proptest! {
+        #[test]
+        fn energy_never_increases(
+            displacement in -100.0f32..100.0,
+            velocity in -100.0f32..100.0,
+            tension in 1.0f32..1000.0,
+            mass in 0.1f32..10.0,
+            friction in 0.0f32..200.0,
+            t in 0.0f32..2.0,
+        ) {
+            let omega0 = (tension / mass).sqrt();
+            let zeta = friction / (2.0 * (tension * mass).sqrt());
+
+            let before = energy(displacement, velocity, omega0);
+            let (x, v) = solve_spring_1d(displacement, velocity, t, omega0, zeta);
+            let after = energy(x, v, omega0);
+
+            prop_assert!(
+                after <= before * 1.001 + 1e-4,
+                "energy rose from {before} to {after} (zeta {zeta})"
+            );
+        }
+
+        #[test]
+        fn converges_for_every_damping_regime(
+            displacement in -1.0f32..1.0,
+            velocity in -10.0f32..10.0,
+            omega0 in 1.0f32..50.0,
+            zeta in prop_oneof![0.1f32..0.99, 0.99f32..1.01, 1.01f32..10.0],
+        ) {
+            let t = 40.0 / decay_rate(omega0, zeta);
+            let (x, v) = solve_spring_1d(displacement, velocity, t, omega0, zeta);
+
+            prop_assert!(x.abs() < 1e-4, "displacement {x} after {t}s (zeta {zeta})");
+            prop_assert!(v.abs() < 1e-3, "velocity {v} after {t}s (zeta {zeta})");
+        }
+
+        #[test]
+        fn any_config_stays_finite(
+            tension in any::<f32>(),
+            mass in any::<f32>(),
+            friction in any::<f32>(),
+            start in -10.0f32..10.0,
+            target in -10.0f32..10.0,
+            dt_ms in 0.0f32..1000.0,
+        ) {
+            let mut spring = SpringMassDamperSimulation::new(SpringMassDamperSimulationConfig {
+                tension,
+                mass,
+                friction,
+            });
+            spring.set_position(XY::new(start, -start));
+            spring.set_target_position(XY::new(target, -target));
+
+            for _ in 0..10 {
+                let position = spring.run(dt_ms);
+                prop_assert!(position.x.is_finite() && position.y.is_finite());
+                prop_assert!(spring.velocity.x.is_finite() && spring.velocity.y.is_finite());
+            }
+        }
+    }
+}
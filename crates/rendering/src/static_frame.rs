@@ -0,0 +1,276 @@
+use cap_project::{CursorEvents, XY};
+
+use crate::{
+    DecodedSegmentFrames, ProjectUniforms, RenderVideoConstants,
+    composite_frame::CompositeVideoFrameUniforms,
+    decoder::DecodedFrame,
+    layers::{cursor_idle_opacity, get_click_t},
+    scene::InterpolatedScene,
+    zoom::InterpolatedZoom,
+};
+
+#[derive(Default)]
+pub(crate) struct StaticFrameDetector {
+    effects_enabled: bool,
+    previous: Option<FrameInputs>,
+}
+
+struct FrameInputs {
+    screen: DecodedFrame,
+    camera: Option<DecodedFrame>,
+    output_size: (u32, u32),
+    composites: [Option<CompositeVideoFrameUniforms>; 3],
+    zoom: InterpolatedZoom,
+    scene: InterpolatedScene,
+    display_parent_motion_px: XY<f32>,
+    cursor: Option<CursorState>,
+    prev_cursor_position: Option<XY<f64>>,
+}
+
+#[derive(PartialEq)]
+struct CursorState {
+    id: String,
+    position: XY<f64>,
+    velocity: XY<f32>,
+    opacity: f32,
+    click_t: f32,
+}
+
+impl StaticFrameDetector {
+    pub fn new(constants: &RenderVideoConstants) -> Self {
+        Self {
+            effects_enabled: !constants.effects.is_empty(),
+            previous: None,
+        }
+    }
+
+    pub fn is_static(
+        &mut self,
+        segment_frames: &DecodedSegmentFrames,
+        uniforms: &ProjectUniforms,
+        cursor: &CursorEvents,
+    ) -> bool {
+        let captions_enabled = uniforms
+            .project
+            .captions
+            .as_ref()
+            .is_some_and(|c| c.settings.enabled);
+
+        if self.effects_enabled
+            || uniforms.is_card
+            || captions_enabled
+            || !uniforms.masks.is_empty()
+            || !uniforms.texts.is_empty()
+            || !uniforms.confetti.is_empty()
+            || uniforms.keystroke.is_some()
+            || !uniforms.project.background_keyframes.is_empty()
+            || !uniforms.project.layout_keyframes.is_empty()
+        {
+            self.previous = None;
+            return false;
+        }
+
+        let time_ms = f64::from(segment_frames.recording_time) * 1000.0;
+        let inputs = FrameInputs {
+            screen: segment_frames.screen_frame.clone(),
+            camera: segment_frames.camera_frame.clone(),
+            output_size: uniforms.output_size,
+            composites: [
+                Some(uniforms.display),
+                uniforms.camera,
+                uniforms.camera_only,
+            ],
+            zoom: uniforms.zoom,
+            scene: uniforms.scene,
+            display_parent_motion_px: uniforms.display_parent_motion_px,
+            cursor: uniforms
+                .interpolated_cursor
+                .as_ref()
+                .map(|interpolated| CursorState {
+                    id: interpolated.cursor_id.clone(),
+                    position: interpolated.position.coord,
+                    velocity: interpolated.velocity,
+                    opacity: cursor_idle_opacity(&uniforms.project.cursor, cursor, time_ms)
+                        * uniforms.cursor_opacity,
+                    click_t: get_click_t(&cursor.clicks, time_ms),
+                }),
+            prev_cursor_position: uniforms.prev_cursor.as_ref().map(|c| c.position.coord),
+        };
+
+        let is_static = self
+            .previous
+            .as_ref()
+            .is_some_and(|previous| previous.matches(&inputs));
+        self.previous = Some(inputs);
+        is_static
+    }
+
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl FrameInputs {
+    fn matches(&self, other: &Self) -> bool {
+        self.output_size == other.output_size
+            && self.zoom == other.zoom
+            && self.scene == other.scene
+            && self.display_parent_motion_px == other.display_parent_motion_px
+            && self.cursor == other.cursor
+            && self.prev_cursor_position == other.prev_cursor_position
+            && self
+                .composites
+                .iter()
+                .zip(&other.composites)
+                .all(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => bytemuck::bytes_of(a) == bytemuck::bytes_of(b),
+                    (None, None) => true,
+                    _ => false,
+                })
+            && frames_match(Some(&self.screen), Some(&other.screen))
+            && frames_match(self.camera.as_ref(), other.camera.as_ref())
+    }
+}
+
+fn frames_match(a: Option<&DecodedFrame>, b: Option<&DecodedFrame>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.same_pixels(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cap_project::{ProjectConfiguration, SceneMode};
+
+    use super::*;
+    use crate::{confetti::ConfettiParticle, keystrokes::PreparedKeystroke, zoom::SegmentBounds};
+
+    fn still_frame() -> DecodedSegmentFrames {
+        DecodedSegmentFrames {
+            screen_frame: DecodedFrame::new(vec![0; 4], 1, 1),
+            camera_frame: None,
+            segment_time: 0.0,
+            recording_time: 0.0,
+        }
+    }
+
+    fn uniforms() -> ProjectUniforms {
+        ProjectUniforms {
+            output_size: (1, 1),
+            cursor_size: 1.0,
+            frame_rate: 30,
+            frame_number: 0,
+            display: CompositeVideoFrameUniforms::default(),
+            camera: None,
+            camera_only: None,
+            magnifier: None,
+            interpolated_cursor: None,
+            cursor_opacity: 1.0,
+            prev_cursor: None,
+            project: ProjectConfiguration::default(),
+            zoom: InterpolatedZoom {
+                t: 0.0,
+                bounds: SegmentBounds {
+                    top_left: XY::new(0.0, 0.0),
+                    bottom_right: XY::new(1.0, 1.0),
+                },
+            },
+            scene: InterpolatedScene {
+                camera_opacity: 1.0,
+                screen_opacity: 1.0,
+                camera_scale: 1.0,
+                scene_mode: SceneMode::default(),
+                transition_progress: 0.0,
+                from_mode: SceneMode::default(),
+                to_mode: SceneMode::default(),
+                screen_blur: 0.0,
+                camera_only_zoom: 1.0,
+                camera_only_blur: 0.0,
+            },
+            resolution_base: XY::new(1, 1),
+            display_parent_motion_px: XY::new(0.0, 0.0),
+            motion_blur_amount: 0.0,
+            masks: vec![],
+            confetti: vec![],
+            keystroke: None,
+            texts: vec![],
+            is_card: false,
+            guides: None,
+            tile: None,
+        }
+    }
+
+    #[test]
+    fn still_frames_are_static() {
+        let mut detector = StaticFrameDetector::default();
+        let cursor = CursorEvents::default();
+
+        assert!(!detector.is_static(&still_frame(), &uniforms(), &cursor));
+        assert!(detector.is_static(&still_frame(), &uniforms(), &cursor));
+    }
+
+    #[test]
+    fn effects_keep_still_frames_changing() {
+        let mut detector = StaticFrameDetector {
+            effects_enabled: true,
+            previous: None,
+        };
+        let cursor = CursorEvents::default();
+
+        assert!(!detector.is_static(&still_frame(), &uniforms(), &cursor));
+        assert!(!detector.is_static(&still_frame(), &uniforms(), &cursor));
+    }
+
+    #[test]
+    fn confetti_keeps_still_frames_changing() {
+        let mut detector = StaticFrameDetector::default();
+        let cursor = CursorEvents::default();
+        let uniforms = ProjectUniforms {
+            confetti: vec![ConfettiParticle {
+                center: [0.5, 0.5],
+                size: [0.1, 0.1],
+                rotation: 0.0,
+                color: [1.0; 4],
+            }],
+            ..uniforms()
+        };
+
+        assert!(!detector.is_static(&still_frame(), &uniforms, &cursor));
+        assert!(!detector.is_static(&still_frame(), &uniforms, &cursor));
+    }
+
+    #[test]
+    fn keystrokes_keep_still_frames_changing() {
+        let mut detector = StaticFrameDetector::default();
+        let cursor = CursorEvents::default();
+        let uniforms = ProjectUniforms {
+            keystroke: Some(PreparedKeystroke {
+                label: "⌘S".to_string(),
+                anchor: [0.5, 0.5],
+                align: [0.5, 0.5],
+                font_size: 12.0,
+                opacity: 1.0,
+                caret: None,
+            }),
+            ..uniforms()
+        };
+
+        assert!(!detector.is_static(&still_frame(), &uniforms, &cursor));
+        assert!(!detector.is_static(&still_frame(), &uniforms, &cursor));
+    }
+
+    #[test]
+    fn frames_match_by_pixels() {
+        let frame = DecodedFrame::new(vec![1, 2, 3, 4], 1, 1);
+        let copy = DecodedFrame::new(vec![1, 2, 3, 4], 1, 1);
+        let changed = DecodedFrame::new(vec![1, 2, 3, 5], 1, 1);
+
+        assert!(frames_match(Some(&frame), Some(&frame.clone())));
+        assert!(frames_match(Some(&frame), Some(&copy)));
+        assert!(!frames_match(Some(&frame), Some(&changed)));
+        assert!(!frames_match(Some(&frame), None));
+        assert!(frames_match(None, None));
+    }
+}
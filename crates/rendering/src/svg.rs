@@ -0,0 +1,82 @@
+use resvg::usvg;
+
+const MIN_RASTER_HEIGHT: u32 = 64;
+const MAX_RASTER_HEIGHT: u32 = 2048;
+
+pub struct SvgImage {
+    tree: usvg::Tree,
+}
+
+pub struct RasterizedSvg {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SvgImage {
+    pub fn parse(data: &str) -> Result<Self, String> {
+        usvg::Tree::from_str(data, &usvg::Options::default())
+            .map(|tree| Self { tree })
+            .map_err(|e| format!("Failed to parse SVG: {e}"))
+    }
+
+    pub fn aspect_ratio(&self) -> f32 {
+        self.tree.size().width() / self.tree.size().height()
+    }
+
+    pub fn rasterize(&self, height: u32) -> Result<RasterizedSvg, String> {
+        let size = self.tree.size();
+        let width = ((self.aspect_ratio() * height as f32).round() as u32).max(1);
+
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or("Failed to create pixmap")?;
+
+        let scale = (width as f32 / size.width()).min(height as f32 / size.height());
+        resvg::render(
+            &self.tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+
+        Ok(RasterizedSvg {
+            width: pixmap.width(),
+            height: pixmap.height(),
+            rgba: pixmap.take(),
+        })
+    }
+}
+
+pub fn raster_height(drawn_height: f32) -> u32 {
+    let height = drawn_height.abs().ceil() as u32;
+    height
+        .clamp(MIN_RASTER_HEIGHT, MAX_RASTER_HEIGHT)
+        .next_power_of_two()
+        .min(MAX_RASTER_HEIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raster_height_buckets_by_power_of_two() {
+        assert_eq!(raster_height(0.0), MIN_RASTER_HEIGHT);
+        assert_eq!(raster_height(65.0), 128);
+        assert_eq!(raster_height(128.0), 128);
+        assert_eq!(raster_height(129.5), 256);
+        assert_eq!(raster_height(10_000.0), MAX_RASTER_HEIGHT);
+    }
+
+    #[test]
+    fn rasterize_keeps_aspect_ratio() {
+        let svg = SvgImage::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="20"><rect width="10" height="20" fill="red"/></svg>"#,
+        )
+        .unwrap();
+
+        let raster = svg.rasterize(256).unwrap();
+
+        assert_eq!((raster.width, raster.height), (128, 256));
+        assert_eq!(raster.rgba.len(), 128 * 256 * 4);
+        assert_eq!(&raster.rgba[..4], &[255, 0, 0, 255]);
+    }
+}
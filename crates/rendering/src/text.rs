@@ -1,7 +1,10 @@
-use cap_project::{TextSegment, XY};
+use cap_project::{CardConfiguration, ReactionAnimation, ReactionSegment, TextSegment, XY};
 
 const BASE_TEXT_HEIGHT: f64 = 0.2;
 const MAX_FONT_SIZE_PX: f32 = 256.0;
+const REACTION_ENTER_SECS: f64 = 0.35;
+const REACTION_EXIT_SECS: f64 = 0.2;
+const REACTION_SLIDE_DISTANCE: f64 = 0.08;
 
 #[derive(Debug, Clone)]
 pub struct PreparedText {
@@ -30,6 +33,49 @@ fn parse_color(hex: &str) -> [f32; 4] {
     [1.0, 1.0, 1.0, 1.0]
 }
 
+pub fn prepare_card_texts(
+    output_size: XY<u32>,
+    card: &CardConfiguration,
+    fallback_title: &str,
+) -> Vec<PreparedText> {
+    let line = |content: String, center_y: f64, height: f64, font_size: f32, font_weight: f32| {
+        TextSegment {
+            start: 0.0,
+            end: 1.0,
+            track: 0,
+            enabled: true,
+            content,
+            center: XY::new(0.5, center_y),
+            size: XY::new(0.8, height),
+            font_family: card.font_family.clone(),
+            font_size,
+            font_weight,
+            italic: false,
+            color: card.text_color.clone(),
+            fade_duration: 0.0,
+        }
+    };
+
+    let title = card
+        .title
+        .as_deref()
+        .filter(|t| !t.is_empty())
+        .unwrap_or(fallback_title);
+
+    let mut segments = Vec::new();
+    if !title.is_empty() {
+        segments.push(line(title.to_string(), 0.45, 0.2, 64.0, 700.0));
+    }
+    if let Some(subtitle) = card.subtitle.as_ref().filter(|s| !s.is_empty()) {
+        segments.push(line(subtitle.clone(), 0.6, 0.1, 32.0, 400.0));
+    }
+    if let Some(author) = card.author.as_ref().filter(|s| !s.is_empty()) {
+        segments.push(line(author.clone(), 0.7, 0.08, 24.0, 400.0));
+    }
+
+    prepare_texts(output_size, 0.5, &segments, &[])
+}
+
 pub fn prepare_texts(
     output_size: XY<u32>,
     frame_time: f64,
@@ -100,3 +146,160 @@ pub fn prepare_texts(
 
     prepared
 }
+
+fn ease_out_cubic(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+pub(crate) fn ease_out_back(t: f64) -> f64 {
+    const C1: f64 = 1.70158;
+    const C3: f64 = C1 + 1.0;
+    1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+}
+
+pub fn prepare_reactions(
+    output_size: XY<u32>,
+    frame_time: f64,
+    segments: &[ReactionSegment],
+) -> Vec<PreparedText> {
+    segments
+        .iter()
+        .filter(|segment| {
+            !segment.emoji.is_empty() && frame_time >= segment.start && frame_time <= segment.end
+        })
+        .filter_map(|segment| {
+            let enter = ((frame_time - segment.start) / REACTION_ENTER_SECS).clamp(0.0, 1.0);
+            let exit = ((segment.end - frame_time) / REACTION_EXIT_SECS).clamp(0.0, 1.0);
+
+            let (scale, drop) = match segment.animation {
+                ReactionAnimation::None => (1.0, 0.0),
+                ReactionAnimation::Pop => (ease_out_back(enter) * ease_out_cubic(exit), 0.0),
+                ReactionAnimation::SlideUp => (
+                    ease_out_cubic(exit),
+                    (1.0 - ease_out_cubic(enter)) * REACTION_SLIDE_DISTANCE,
+                ),
+            };
+
+            let font_size = (segment.scale.clamp(0.02, 1.0) * scale * output_size.y as f64) as f32;
+            if font_size < 1.0 {
+                return None;
+            }
+
+            let center_x = segment.center.x.clamp(0.0, 1.0) as f32 * output_size.x as f32;
+            let center_y = (segment.center.y.clamp(0.0, 1.0) + drop) as f32 * output_size.y as f32;
+            let line_height = font_size * 1.2;
+
+            Some(PreparedText {
+                content: segment.emoji.clone(),
+                bounds: [
+                    center_x - font_size,
+                    center_y - line_height / 2.0,
+                    center_x + font_size,
+                    center_y + line_height / 2.0,
+                ],
+                color: [1.0, 1.0, 1.0, 1.0],
+                font_family: String::new(),
+                font_size,
+                font_weight: 400.0,
+                italic: false,
+                opacity: 1.0,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reaction(animation: ReactionAnimation) -> ReactionSegment {
+        ReactionSegment {
+            start: 1.0,
+            end: 3.0,
+            emoji: "🎉".to_string(),
+            center: XY::new(0.5, 0.5),
+            scale: 0.1,
+            animation,
+        }
+    }
+
+    #[test]
+    fn reactions_only_show_during_their_segment() {
+        let size = XY::new(1920, 1080);
+        let segments = [reaction(ReactionAnimation::None)];
+
+        assert!(prepare_reactions(size, 0.5, &segments).is_empty());
+        assert!(prepare_reactions(size, 3.5, &segments).is_empty());
+
+        let prepared = prepare_reactions(size, 2.0, &segments);
+        assert_eq!(prepared.len(), 1);
+        assert_eq!(prepared[0].font_size, 108.0);
+        assert_eq!(prepared[0].content, "🎉");
+    }
+
+    #[test]
+    fn popping_reactions_grow_in_and_shrink_out() {
+        let size = XY::new(1920, 1080);
+        let segments = [reaction(ReactionAnimation::Pop)];
+        let font_size = |time| {
+            prepare_reactions(size, time, &segments)
+                .first()
+                .map_or(0.0, |text| text.font_size)
+        };
+
+        assert!(font_size(1.05) < 108.0);
+        assert_eq!(font_size(2.0), 108.0);
+        assert!(font_size(2.95) < 108.0);
+    }
+
+    #[test]
+    fn cards_show_the_author_under_the_subtitle() {
+        let card = CardConfiguration {
+            enabled: true,
+            subtitle: Some("Part one".to_string()),
+            author: Some("Sam".to_string()),
+            ..Default::default()
+        };
+
+        let texts = prepare_card_texts(XY::new(1920, 1080), &card, "Release notes");
+
+        let contents = texts.iter().map(|t| t.content.as_str()).collect::<Vec<_>>();
+        assert_eq!(contents, ["Release notes", "Part one", "Sam"]);
+        assert!(texts[2].bounds[1] > texts[1].bounds[1]);
+    }
+
+    #[test]
+    fn cards_prefer_their_own_title() {
+        let card = CardConfiguration {
+            enabled: true,
+            title: Some("Launch day".to_string()),
+            ..Default::default()
+        };
+        let texts = prepare_card_texts(XY::new(1920, 1080), &card, "Release notes");
+        assert_eq!(texts[0].content, "Launch day");
+
+        let untitled = CardConfiguration {
+            title: Some(String::new()),
+            ..card.clone()
+        };
+        let texts = prepare_card_texts(XY::new(1920, 1080), &untitled, "Release notes");
+        assert_eq!(texts[0].content, "Release notes");
+
+        let outro = CardConfiguration {
+            subtitle: Some("Thanks for watching".to_string()),
+            ..untitled
+        };
+        let texts = prepare_card_texts(XY::new(1920, 1080), &outro, "");
+        let contents = texts.iter().map(|t| t.content.as_str()).collect::<Vec<_>>();
+        assert_eq!(contents, ["Thanks for watching"]);
+    }
+
+    #[test]
+    fn sliding_reactions_rise_into_place() {
+        let size = XY::new(1920, 1080);
+        let segments = [reaction(ReactionAnimation::SlideUp)];
+        let top = |time: f64| prepare_reactions(size, time, &segments)[0].bounds[1];
+
+        assert!(top(1.05) > top(2.0));
+    }
+}
@@ -0,0 +1,159 @@
+use cap_project::XY;
+
+pub const MAX_TILES_PER_AXIS: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderTile {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub frame_size: (u32, u32),
+}
+
+impl RenderTile {
+    pub fn offset(&self) -> XY<f32> {
+        XY::new(self.x as f32, self.y as f32)
+    }
+
+    pub fn uv_rect(&self) -> [f32; 4] {
+        let (frame_width, frame_height) = self.frame_size;
+        let frame_width = frame_width.max(1) as f32;
+        let frame_height = frame_height.max(1) as f32;
+
+        [
+            self.x as f32 / frame_width,
+            self.y as f32 / frame_height,
+            self.width as f32 / frame_width,
+            self.height as f32 / frame_height,
+        ]
+    }
+}
+
+pub fn split_into_tiles(
+    (width, height): (u32, u32),
+    max_dimension: u32,
+) -> Option<Vec<RenderTile>> {
+    let max_dimension = max_dimension.max(1);
+    let columns = width.div_ceil(max_dimension).max(1);
+    let rows = height.div_ceil(max_dimension).max(1);
+
+    if columns == 1 && rows == 1 {
+        return None;
+    }
+
+    let edge = |total: u32, parts: u32, index: u32| {
+        (u64::from(total) * u64::from(index) / u64::from(parts)) as u32
+    };
+
+    let mut tiles = Vec::with_capacity((columns * rows) as usize);
+    for row in 0..rows {
+        let (top, bottom) = (edge(height, rows, row), edge(height, rows, row + 1));
+        for column in 0..columns {
+            let (left, right) = (
+                edge(width, columns, column),
+                edge(width, columns, column + 1),
+            );
+            tiles.push(RenderTile {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+                frame_size: (width, height),
+            });
+        }
+    }
+
+    Some(tiles)
+}
+
+pub fn stitch_tile(
+    frame: &mut [u8],
+    frame_stride: usize,
+    tile: &RenderTile,
+    data: &[u8],
+    tile_stride: usize,
+) {
+    let row_bytes = tile.width as usize * 4;
+    let column_offset = tile.x as usize * 4;
+
+    for row in 0..tile.height as usize {
+        let src_start = row * tile_stride;
+        let dst_start = (tile.y as usize + row) * frame_stride + column_offset;
+
+        let (Some(src), Some(dst)) = (
+            data.get(src_start..src_start + row_bytes),
+            frame.get_mut(dst_start..dst_start + row_bytes),
+        ) else {
+            break;
+        };
+
+        dst.copy_from_slice(src);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_within_the_limit_are_not_tiled() {
+        assert_eq!(split_into_tiles((3840, 2160), 8192), None);
+        assert_eq!(split_into_tiles((8192, 8192), 8192), None);
+    }
+
+    #[test]
+    fn wide_frames_split_into_columns() {
+        let tiles = split_into_tiles((5120, 2880), 4096).unwrap();
+
+        assert_eq!(tiles.len(), 2);
+        assert_eq!((tiles[0].x, tiles[0].width), (0, 2560));
+        assert_eq!((tiles[1].x, tiles[1].width), (2560, 2560));
+        assert!(tiles.iter().all(|tile| tile.height == 2880));
+    }
+
+    #[test]
+    fn tiles_cover_the_frame_exactly() {
+        let size = (7681, 4321);
+        let tiles = split_into_tiles(size, 4096).unwrap();
+
+        assert_eq!(tiles.len(), 4);
+        assert!(
+            tiles
+                .iter()
+                .all(|tile| tile.width <= 4096 && tile.height <= 4096)
+        );
+
+        let area: u64 = tiles
+            .iter()
+            .map(|tile| u64::from(tile.width) * u64::from(tile.height))
+            .sum();
+        assert_eq!(area, 7681 * 4321);
+
+        let last = tiles.last().unwrap();
+        assert_eq!((last.x + last.width, last.y + last.height), size);
+    }
+
+    #[test]
+    fn stitching_places_tiles_in_the_frame() {
+        let tiles = split_into_tiles((4, 4), 2).unwrap();
+        let frame_stride = 4 * 4;
+        let mut frame = vec![0u8; frame_stride * 4];
+
+        for (index, tile) in tiles.iter().enumerate() {
+            let tile_stride = tile.width as usize * 4 + 8;
+            let data = vec![index as u8 + 1; tile_stride * tile.height as usize];
+            stitch_tile(&mut frame, frame_stride, tile, &data, tile_stride);
+        }
+
+        let pixel = |x: usize, y: usize| frame[y * frame_stride + x * 4];
+        assert_eq!(
+            [pixel(0, 0), pixel(1, 0), pixel(2, 0), pixel(3, 0)],
+            [1, 1, 2, 2]
+        );
+        assert_eq!(
+            [pixel(0, 3), pixel(1, 3), pixel(2, 3), pixel(3, 3)],
+            [3, 3, 4, 4]
+        );
+    }
+}
@@ -1,12 +1,12 @@
-use cap_project::{XY, ZoomSegment};
+use cap_project::{ScreenMovementSpring, XY, ZoomEasing, ZoomSegment};
 
 use crate::{Coord, RawDisplayUVSpace};
 
 pub const ZOOM_DURATION: f64 = 1.0;
 
-const SCREEN_SPRING_STIFFNESS: f64 = 200.0;
-const SCREEN_SPRING_DAMPING: f64 = 40.0;
-const SCREEN_SPRING_MASS: f64 = 2.25;
+const SCREEN_SPRING_STIFFNESS: f64 = ScreenMovementSpring::DEFAULT.stiffness as f64;
+const SCREEN_SPRING_DAMPING: f64 = ScreenMovementSpring::DEFAULT.damping as f64;
+const SCREEN_SPRING_MASS: f64 = ScreenMovementSpring::DEFAULT.mass as f64;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SegmentsCursor<'a> {
@@ -14,6 +14,7 @@ pub struct SegmentsCursor<'a> {
     segment: Option<&'a ZoomSegment>,
     prev_segment: Option<&'a ZoomSegment>,
     segments: &'a [ZoomSegment],
+    click_origin: Option<XY<f64>>,
 }
 
 impl<'a> SegmentsCursor<'a> {
@@ -31,6 +32,7 @@ impl<'a> SegmentsCursor<'a> {
                     None
                 },
                 segments,
+                click_origin: None,
             },
             None => {
                 let prev = segments
@@ -43,10 +45,34 @@ impl<'a> SegmentsCursor<'a> {
                     segment: None,
                     prev_segment: prev.map(|(_, s)| s),
                     segments,
+                    click_origin: None,
                 }
             }
         }
     }
+
+    pub fn with_click_origin(
+        mut self,
+        resolve: impl FnOnce(&ZoomSegment) -> Option<XY<f64>>,
+    ) -> Self {
+        self.click_origin = self
+            .segment
+            .filter(|segment| segment.zoom_from_click)
+            .and_then(resolve);
+        self
+    }
+}
+
+pub(crate) fn settled_zoom_segments(segments: &[ZoomSegment], time: f64) -> Vec<ZoomSegment> {
+    segments
+        .iter()
+        .filter(|s| s.start <= time && time <= s.end)
+        .take(1)
+        .map(|s| ZoomSegment {
+            start: s.start.min(time - ZOOM_DURATION),
+            ..s.clone()
+        })
+        .collect()
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -211,9 +237,35 @@ impl SegmentBounds {
     pub fn default() -> Self {
         SegmentBounds::new(XY::new(0.0, 0.0), XY::new(1.0, 1.0))
     }
+
+    fn zoom_from_origin(origin: XY<f64>, target: SegmentBounds, t: f64) -> Self {
+        let target_zoom = (target.bottom_right - target.top_left).x;
+        let zoom = 1.0 + (target_zoom - 1.0) * t;
+
+        let anchored = origin * (1.0 - zoom);
+        let anchored_target = origin * (1.0 - target_zoom);
+        let top_left = anchored + (target.top_left - anchored_target) * (t * t);
+
+        SegmentBounds::new(top_left, top_left + XY::new(zoom, zoom))
+    }
+
+    fn zoom_in(
+        origin: Option<XY<f64>>,
+        from: SegmentBounds,
+        target: SegmentBounds,
+        t: f64,
+    ) -> Self {
+        match origin {
+            Some(origin) => Self::zoom_from_origin(origin, target, t),
+            None => SegmentBounds::new(
+                from.top_left * (1.0 - t) + target.top_left * t,
+                from.bottom_right * (1.0 - t) + target.bottom_right * t,
+            ),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct InterpolatedZoom {
     // the ratio of current zoom to the maximum amount for the current segment
     pub t: f64,
@@ -269,6 +321,55 @@ fn instant_ease(t: f32) -> f32 {
     if t <= 0.0 { 0.0 } else { 1.0 }
 }
 
+fn cubic_bezier_ease(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+    if t <= 0.0 {
+        return 0.0;
+    }
+    if t >= 1.0 {
+        return 1.0;
+    }
+
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    let bezier = |p1: f32, p2: f32, s: f32| {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+    };
+    let slope = |p1: f32, p2: f32, s: f32| {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+    };
+
+    let mut s = t;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, s) - t;
+        if error.abs() < 1e-6 {
+            return bezier(y1, y2, s);
+        }
+        let dx = slope(x1, x2, s);
+        if dx.abs() < 1e-6 {
+            break;
+        }
+        s = (s - error / dx).clamp(0.0, 1.0);
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    s = t;
+    for _ in 0..32 {
+        let x = bezier(x1, x2, s);
+        if (x - t).abs() < 1e-6 {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+
+    bezier(y1, y2, s)
+}
+
 impl InterpolatedZoom {
     pub fn new(cursor: SegmentsCursor, interpolated_cursor: Coord<RawDisplayUVSpace>) -> Self {
         Self::new_with_cursor(cursor, interpolated_cursor, None)
@@ -279,23 +380,37 @@ impl InterpolatedZoom {
         zoom_focus: Coord<RawDisplayUVSpace>,
         actual_cursor: Option<Coord<RawDisplayUVSpace>>,
     ) -> Self {
-        let use_instant = cursor.segment.map(|s| s.instant_animation).unwrap_or(false);
-        if use_instant {
-            Self::new_with_easing_and_cursor(
+        let easing = cursor
+            .segment
+            .or(cursor.prev_segment)
+            .map(|s| {
+                if s.instant_animation {
+                    ZoomEasing::Instant
+                } else {
+                    s.easing
+                }
+            })
+            .unwrap_or_default();
+
+        match easing {
+            ZoomEasing::Spring => Self::new_with_easing_and_cursor(
                 cursor,
                 zoom_focus,
                 actual_cursor,
-                instant_ease,
-                instant_ease,
-            )
-        } else {
-            Self::new_with_easing_and_cursor(
+                spring_ease,
+                spring_ease_out,
+            ),
+            ZoomEasing::Instant => Self::new_with_easing_and_cursor(
                 cursor,
                 zoom_focus,
                 actual_cursor,
-                spring_ease,
-                spring_ease_out,
-            )
+                instant_ease,
+                instant_ease,
+            ),
+            ZoomEasing::CubicBezier { x1, y1, x2, y2 } => {
+                let ease = move |t| cubic_bezier_ease(x1, y1, x2, y2, t);
+                Self::new_with_easing_and_cursor(cursor, zoom_focus, actual_cursor, ease, ease)
+            }
         }
     }
 
@@ -355,10 +470,7 @@ impl InterpolatedZoom {
                             actual_cursor,
                         );
 
-                        SegmentBounds::new(
-                            default.top_left * (1.0 - t) + segment_bounds.top_left * t,
-                            default.bottom_right * (1.0 - t) + segment_bounds.bottom_right * t,
-                        )
+                        SegmentBounds::zoom_in(cursor.click_origin, default, segment_bounds, t)
                     },
                 }
             }
@@ -411,10 +523,11 @@ impl InterpolatedZoom {
                 } else {
                     Self {
                         t: zoom_t,
-                        bounds: SegmentBounds::new(
-                            default.top_left * (1.0 - zoom_t) + segment_bounds.top_left * zoom_t,
-                            default.bottom_right * (1.0 - zoom_t)
-                                + segment_bounds.bottom_right * zoom_t,
+                        bounds: SegmentBounds::zoom_in(
+                            cursor.click_origin,
+                            default,
+                            segment_bounds,
+                            zoom_t,
                         ),
                     }
                 }
@@ -617,6 +730,8 @@ mod test {
             glide_speed: 0.05,
             instant_animation: false,
             edge_snap_ratio: 0.075,
+            zoom_from_click: false,
+            easing: ZoomEasing::default(),
         }
     }
 
@@ -821,6 +936,56 @@ mod test {
         );
     }
 
+    fn amount_at(time: f64, segments: &[ZoomSegment]) -> f64 {
+        InterpolatedZoom::new_with_easing_and_cursor(
+            c(time, segments),
+            Default::default(),
+            None,
+            |t| t,
+            |t| t,
+        )
+        .display_amount()
+    }
+
+    #[test]
+    fn segments_zoom_to_their_own_amount() {
+        let segments = vec![test_segment(2.0, 4.0, 3.0, 0.5, 0.5)];
+
+        assert_f64_near!(amount_at(2.0 + ZOOM_DURATION * 0.5, &segments), 2.0);
+        assert_f64_near!(amount_at(2.0 + ZOOM_DURATION, &segments), 3.0);
+        assert_f64_near!(amount_at(4.0, &segments), 3.0);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION * 0.5, &segments), 2.0);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION, &segments), 1.0);
+    }
+
+    #[test]
+    fn back_to_back_segments_ease_between_amounts() {
+        let segments = vec![
+            test_segment(2.0, 4.0, 3.0, 0.5, 0.5),
+            test_segment(4.0, 6.0, 1.25, 0.5, 0.5),
+            test_segment(6.0, 8.0, 2.0, 0.5, 0.5),
+        ];
+
+        assert_f64_near!(amount_at(4.0, &segments), 3.0);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION * 0.5, &segments), 2.125);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION, &segments), 1.25);
+        assert_f64_near!(amount_at(6.0 + ZOOM_DURATION * 0.5, &segments), 1.625);
+        assert_f64_near!(amount_at(6.0 + ZOOM_DURATION, &segments), 2.0);
+        assert_f64_near!(amount_at(8.0 + ZOOM_DURATION * 0.5, &segments), 1.5);
+    }
+
+    #[test]
+    fn small_gaps_ease_from_the_previous_amount() {
+        let segments = vec![
+            test_segment(2.0, 4.0, 3.0, 0.5, 0.5),
+            test_segment(4.0 + ZOOM_DURATION * 0.5, 6.0, 1.5, 0.5, 0.5),
+        ];
+
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION * 0.5, &segments), 2.0);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION, &segments), 1.75);
+        assert_f64_near!(amount_at(4.0 + ZOOM_DURATION * 1.5, &segments), 1.5);
+    }
+
     fn cursor_is_visible_in_zoom(zoom: &InterpolatedZoom, cursor_pos: (f64, f64)) -> bool {
         let current_zoom = zoom.bounds.bottom_right.x - zoom.bounds.top_left.x;
         if current_zoom <= 1.001 {
@@ -843,6 +1008,28 @@ mod test {
             && cursor_pos.1 <= viewport_bottom + margin
     }
 
+    #[test]
+    fn settled_segments_have_finished_zooming() {
+        let segments = vec![
+            test_segment(0.0, 2.0, 2.0, 0.5, 0.5),
+            test_segment(3.0, 5.0, 2.0, 0.5, 0.5),
+        ];
+        let zoomed_in = InterpolatedZoom {
+            t: 1.0,
+            bounds: SegmentBounds::new(XY::new(-0.5, -0.5), XY::new(1.5, 1.5)),
+        };
+
+        test_interp((0.0, &settled_zoom_segments(&segments, 0.0)), zoomed_in);
+        test_interp((3.2, &settled_zoom_segments(&segments, 3.2)), zoomed_in);
+        test_interp(
+            (2.2, &settled_zoom_segments(&segments, 2.2)),
+            InterpolatedZoom {
+                t: 0.0,
+                bounds: SegmentBounds::default(),
+            },
+        );
+    }
+
     #[test]
     fn ensure_cursor_visible_keeps_cursor_in_view() {
         let zoom = InterpolatedZoom {
@@ -906,6 +1093,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn zoom_from_click_scales_around_click() {
+        let mut segment = test_segment(2.0, 4.0, 2.0, 0.5, 0.5);
+        segment.zoom_from_click = true;
+        let segments = vec![segment];
+
+        let cursor = c(2.0 + ZOOM_DURATION * 0.5, &segments)
+            .with_click_origin(|_| Some(XY::new(0.25, 0.25)));
+        let actual = InterpolatedZoom::new_with_easing_and_cursor(
+            cursor,
+            Default::default(),
+            None,
+            |t| t,
+            |t| t,
+        );
+
+        assert_f64_near!(actual.t, 0.5, "t");
+        assert_f64_near!(actual.bounds.top_left.x, -0.1875, "bounds.top_left.x");
+        assert_f64_near!(
+            actual.bounds.bottom_right.x,
+            1.3125,
+            "bounds.bottom_right.x"
+        );
+
+        test_interp(
+            (2.0 + ZOOM_DURATION, &segments),
+            InterpolatedZoom {
+                t: 1.0,
+                bounds: SegmentBounds::new(XY::new(-0.5, -0.5), XY::new(1.5, 1.5)),
+            },
+        );
+    }
+
     #[test]
     fn ensure_cursor_visible_handles_interpolated_bounds() {
         let zoom = InterpolatedZoom {
@@ -920,4 +1140,24 @@ mod test {
             "Cursor should be visible in interpolated zoom state"
         );
     }
+
+    #[test]
+    fn cubic_bezier_matches_css_curves() {
+        for t in [0.1, 0.25, 0.5, 0.9] {
+            assert!((cubic_bezier_ease(0.0, 0.0, 1.0, 1.0, t) - t).abs() < 1e-4);
+        }
+
+        assert!((cubic_bezier_ease(0.25, 0.1, 0.25, 1.0, 0.5) - 0.8024).abs() < 1e-3);
+        assert_eq!(cubic_bezier_ease(0.25, 0.1, 0.25, 1.0, 0.0), 0.0);
+        assert_eq!(cubic_bezier_ease(0.25, 0.1, 0.25, 1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn cubic_bezier_can_overshoot() {
+        let peak = (1..100)
+            .map(|i| cubic_bezier_ease(0.3, 1.6, 0.6, 1.0, i as f32 / 100.0))
+            .fold(0.0_f32, f32::max);
+
+        assert!(peak > 1.0);
+    }
 }
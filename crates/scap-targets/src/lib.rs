@@ -115,6 +115,10 @@ impl Window {
         WindowImpl::get_topmost_at_cursor().map(Self)
     }
 
+    pub fn get_focused() -> Option<Self> {
+        WindowImpl::get_focused().map(Self)
+    }
+
     pub fn id(&self) -> WindowId {
         WindowId(self.0.id())
     }
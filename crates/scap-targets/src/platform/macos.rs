@@ -295,6 +295,30 @@ impl WindowImpl {
         windows_with_level.first().map(|(window, _)| *window)
     }
 
+    pub fn get_focused() -> Option<Self> {
+        use objc::rc::autoreleasepool;
+
+        let pid = autoreleasepool(|| unsafe {
+            use cocoa::base::id;
+            use objc::{class, msg_send, sel, sel_impl};
+
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let app: id = msg_send![workspace, frontmostApplication];
+            let app = (!app.is_null()).then_some(app)?;
+
+            let pid: i32 = msg_send![app, processIdentifier];
+            Some(pid)
+        })?;
+
+        if pid as u32 == std::process::id() {
+            return None;
+        }
+
+        Self::list()
+            .into_iter()
+            .find(|window| window.level() == Some(0) && window.owner_pid() == Some(pid))
+    }
+
     pub fn id(&self) -> WindowIdImpl {
         WindowIdImpl(self.0)
     }
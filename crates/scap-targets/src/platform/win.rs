@@ -38,7 +38,7 @@ use windows::{
             WindowsAndMessaging::{
                 DI_FLAGS, DestroyIcon, DrawIconEx, EnumChildWindows, EnumWindows, GCLP_HICON,
                 GW_HWNDNEXT, GWL_EXSTYLE, GWL_STYLE, GetClassLongPtrW, GetClassNameW,
-                GetClientRect, GetCursorPos, GetDesktopWindow, GetIconInfo,
+                GetClientRect, GetCursorPos, GetDesktopWindow, GetForegroundWindow, GetIconInfo,
                 GetLayeredWindowAttributes, GetWindow, GetWindowLongPtrW, GetWindowLongW,
                 GetWindowRect, GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId,
                 HICON, ICONINFO, IsIconic, IsWindowVisible, PrivateExtractIconsW, SendMessageW,
@@ -388,6 +388,23 @@ impl WindowImpl {
         }
     }
 
+    pub fn get_focused() -> Option<Self> {
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if hwnd == HWND(std::ptr::null_mut()) {
+                return None;
+            }
+
+            let mut process_id = 0u32;
+            GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+            if process_id == GetCurrentProcessId() {
+                return None;
+            }
+
+            Some(Self(hwnd))
+        }
+    }
+
     fn get_topmost_at_cursor_fallback(point: POINT) -> Option<Self> {
         struct HitTestData {
             pt: POINT,
@@ -1,4 +1,4 @@
-use std::time::{Duration, Instant, SystemTime};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 #[cfg(windows)]
 mod win;
@@ -21,6 +21,21 @@ pub enum Timestamp {
 }
 
 impl Timestamp {
+    pub fn now() -> Self {
+        #[cfg(target_os = "macos")]
+        {
+            Self::MachAbsoluteTime(MachAbsoluteTimestamp::now())
+        }
+        #[cfg(windows)]
+        {
+            Self::PerformanceCounter(PerformanceCounterTimestamp::now())
+        }
+        #[cfg(not(any(target_os = "macos", windows)))]
+        {
+            Self::Instant(Instant::now())
+        }
+    }
+
     pub fn duration_since(&self, start: Timestamps) -> Duration {
         match self {
             Self::Instant(instant) => instant.duration_since(start.instant),
@@ -162,3 +177,29 @@ impl Timestamps {
         self.mach_absolute_time
     }
 }
+
+#[derive(Clone, Copy, Debug)]
+pub struct SessionClock {
+    start: Timestamps,
+}
+
+impl SessionClock {
+    pub fn new(start: Timestamps) -> Self {
+        Self { start }
+    }
+
+    pub fn start_time(&self) -> Timestamps {
+        self.start
+    }
+
+    pub fn unix_secs(&self, timestamp: Timestamp) -> f64 {
+        let start = self
+            .start
+            .system_time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+
+        start + timestamp.signed_duration_since_secs(self.start)
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub struct AtomicFile {
+    partial: PathBuf,
+    target: PathBuf,
+    committed: bool,
+}
+
+impl AtomicFile {
+    pub fn new(target: impl Into<PathBuf>) -> io::Result<Self> {
+        let target = target.into();
+        let parent = target.parent().unwrap_or(Path::new("."));
+        std::fs::create_dir_all(parent)?;
+
+        let stem = target
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut name = format!(".{stem}.partial-{}", uuid::Uuid::new_v4().simple());
+        if let Some(extension) = target.extension() {
+            name.push('.');
+            name.push_str(&extension.to_string_lossy());
+        }
+
+        Ok(Self {
+            partial: parent.join(name),
+            target,
+            committed: false,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.partial
+    }
+
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    pub fn commit(mut self) -> io::Result<PathBuf> {
+        std::fs::rename(&self.partial, &self.target)?;
+        self.committed = true;
+        Ok(self.target.clone())
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+
+        if let Err(e) = std::fs::remove_file(&self.partial)
+            && e.kind() != io::ErrorKind::NotFound
+        {
+            tracing::warn!(
+                "Failed to remove partial file {}: {e}",
+                self.partial.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_replaces_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("export.mp4");
+        std::fs::write(&target, b"old").unwrap();
+
+        let file = AtomicFile::new(&target).unwrap();
+        assert_eq!(file.path().parent(), Some(dir.path()));
+        assert_eq!(file.path().extension(), target.extension());
+
+        std::fs::write(file.path(), b"new").unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"old");
+
+        file.commit().unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"new");
+    }
+
+    #[test]
+    fn drop_without_commit_removes_partial() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("export.mp4");
+
+        let file = AtomicFile::new(&target).unwrap();
+        std::fs::write(file.path(), b"partial").unwrap();
+        let partial = file.path().to_path_buf();
+        drop(file);
+
+        assert!(!partial.exists());
+        assert!(!target.exists());
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+const MB: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct InsufficientDiskSpace {
+    pub path: PathBuf,
+    pub available_bytes: u64,
+    pub required_bytes: u64,
+}
+
+impl InsufficientDiskSpace {
+    pub fn available_mb(&self) -> u64 {
+        self.available_bytes / MB
+    }
+
+    pub fn required_mb(&self) -> u64 {
+        self.required_bytes.div_ceil(MB)
+    }
+}
+
+impl fmt::Display for InsufficientDiskSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Not enough disk space at {}: {}MB available, about {}MB needed",
+            self.path.display(),
+            self.available_mb(),
+            self.required_mb()
+        )
+    }
+}
+
+impl std::error::Error for InsufficientDiskSpace {}
+
+pub fn available_space(path: &Path) -> io::Result<u64> {
+    let existing = path
+        .ancestors()
+        .find(|p| p.exists())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No existing ancestor"))?;
+
+    available_space_inner(existing)
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+fn available_space_inner(path: &Path) -> io::Result<u64> {
+    let stat = nix::sys::statvfs::statvfs(path).map_err(io::Error::from)?;
+    Ok((stat.blocks_available() as u64).saturating_mul(stat.fragment_size() as u64))
+}
+
+#[cfg(windows)]
+fn available_space_inner(path: &Path) -> io::Result<u64> {
+    use windows::{Win32::Storage::FileSystem::GetDiskFreeSpaceExW, core::HSTRING};
+
+    let mut available = 0u64;
+    unsafe {
+        GetDiskFreeSpaceExW(
+            &HSTRING::from(path.as_os_str()),
+            Some(&mut available),
+            None,
+            None,
+        )
+    }
+    .map_err(io::Error::from)?;
+
+    Ok(available)
+}
+
+pub fn ensure_available_space(
+    path: &Path,
+    required_bytes: u64,
+) -> Result<Option<u64>, InsufficientDiskSpace> {
+    let available_bytes = match available_space(path) {
+        Ok(available) => available,
+        Err(e) => {
+            tracing::warn!("Couldn't check free space at {}: {e}", path.display());
+            return Ok(None);
+        }
+    };
+
+    if available_bytes < required_bytes {
+        return Err(InsufficientDiskSpace {
+            path: path.to_path_buf(),
+            available_bytes,
+            required_bytes,
+        });
+    }
+
+    Ok(Some(available_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_closest_existing_ancestor() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("not").join("created").join("yet.mp4");
+
+        assert!(available_space(&output).unwrap() > 0);
+        assert!(ensure_available_space(&output, 1).unwrap().is_some());
+
+        let err = ensure_available_space(&output, u64::MAX).unwrap_err();
+        assert_eq!(err.required_bytes, u64::MAX);
+        assert!(err.available_bytes < u64::MAX);
+    }
+}
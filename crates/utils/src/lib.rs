@@ -9,6 +9,11 @@ use std::{
 use aho_corasick::{AhoCorasickBuilder, MatchKind};
 use tracing::Instrument;
 
+pub mod atomic_file;
+pub mod disk_space;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod snapshot;
+
 /// Wrapper around tokio::spawn that inherits the current tracing subscriber and span.
 pub fn spawn_actor<F>(future: F) -> tokio::task::JoinHandle<F::Output>
 where
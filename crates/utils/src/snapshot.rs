@@ -0,0 +1,19 @@
+use std::path::Path;
+
+pub fn require_reference(path: &Path, update_var: &str, write: impl FnOnce(&Path)) -> bool {
+    if std::env::var(update_var).is_ok_and(|value| value == "1") {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        write(path);
+        eprintln!("Wrote reference {}", path.display());
+        return false;
+    }
+
+    assert!(
+        path.exists(),
+        "Missing reference {}. Run with {update_var}=1 to create it, check it and commit it.",
+        path.display()
+    );
+    true
+}